@@ -1,7 +1,9 @@
 #![no_std]
 
 pub mod pausable;
+pub mod quota;
 
+use quota::{AdminAction, RoleQuota};
 use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec};
 
 /// Admin role hierarchy levels
@@ -57,6 +59,12 @@ enum DataKey {
     PauseProposal(u64),
     PauseApproval(u64, Address),
     PauseApprovalCount(u64),
+    /// Minimum role to call an entrypoint of another contract: (contract, fn_name) -> AdminRole
+    FunctionRole(Address, Symbol),
+    /// Per-role action quota: (role, action) -> RoleQuota
+    RoleQuota(AdminRole, AdminAction),
+    /// Per-admin quota usage in the current window: (admin, action) -> QuotaUsage
+    QuotaUsage(Address, AdminAction),
 }
 
 #[contract]
@@ -266,6 +274,7 @@ impl AdminContract {
         if caller_role <= admin_info.role {
             panic!("insufficient privileges to remove admin");
         }
+        quota::consume(&e, &caller, caller_role, AdminAction::Remove);
 
         // Check minimum admin requirements
         let role_admins: Vec<Address> = e
@@ -357,6 +366,8 @@ impl AdminContract {
         if caller == admin_address && Self::get_role(e.clone(), caller.clone()) >= new_role {
             panic!("cannot assign equal or higher role to self");
         }
+        let caller_role = Self::get_role(e.clone(), caller.clone());
+        quota::consume(&e, &caller, caller_role, AdminAction::UpdateRole);
 
         let old_role = admin_info.role;
 
@@ -432,6 +443,7 @@ impl AdminContract {
         if caller_role <= admin_info.role {
             panic!("insufficient privileges to deactivate admin");
         }
+        quota::consume(&e, &caller, caller_role, AdminAction::Deactivate);
 
         if !admin_info.active {
             panic!("admin already deactivated");
@@ -475,6 +487,7 @@ impl AdminContract {
         if caller_role <= admin_info.role {
             panic!("insufficient privileges to reactivate admin");
         }
+        quota::consume(&e, &caller, caller_role, AdminAction::Reactivate);
 
         if admin_info.active {
             panic!("admin already active");
@@ -628,6 +641,151 @@ impl AdminContract {
         (min_admins, max_admins)
     }
 
+    /// Map an entrypoint of another contract to the minimum role allowed to call it.
+    ///
+    /// # Arguments
+    /// * `super_admin` - Caller; must be an active super admin
+    /// * `contract` - Contract exposing the entrypoint (e.g. credence_bond)
+    /// * `fn_name` - Entrypoint name
+    /// * `min_role` - Minimum active role required by `can_call`
+    ///
+    /// # Panics
+    /// * If caller is not an active super admin
+    ///
+    /// # Events
+    /// Emits `function_role_set` with (contract, fn_name, min_role)
+    pub fn set_function_role(
+        e: Env,
+        super_admin: Address,
+        contract: Address,
+        fn_name: Symbol,
+        min_role: AdminRole,
+    ) {
+        pausable::require_not_paused(&e);
+        super_admin.require_auth();
+        Self::require_active_super_admin(&e, &super_admin);
+
+        e.storage().instance().set(
+            &DataKey::FunctionRole(contract.clone(), fn_name.clone()),
+            &min_role,
+        );
+        e.events().publish(
+            (Symbol::new(&e, "function_role_set"),),
+            (contract, fn_name, min_role),
+        );
+    }
+
+    /// Remove an entrypoint mapping; `can_call` returns `false` for it afterwards.
+    ///
+    /// # Panics
+    /// * If caller is not an active super admin
+    ///
+    /// # Events
+    /// Emits `function_role_cleared` with (contract, fn_name)
+    pub fn clear_function_role(e: Env, super_admin: Address, contract: Address, fn_name: Symbol) {
+        pausable::require_not_paused(&e);
+        super_admin.require_auth();
+        Self::require_active_super_admin(&e, &super_admin);
+
+        e.storage()
+            .instance()
+            .remove(&DataKey::FunctionRole(contract.clone(), fn_name.clone()));
+        e.events().publish(
+            (Symbol::new(&e, "function_role_cleared"),),
+            (contract, fn_name),
+        );
+    }
+
+    /// Get the minimum role mapped to an entrypoint, if any.
+    pub fn get_function_role(e: Env, contract: Address, fn_name: Symbol) -> Option<AdminRole> {
+        e.storage()
+            .instance()
+            .get(&DataKey::FunctionRole(contract, fn_name))
+    }
+
+    /// Check whether `caller` may call `fn_name` on `contract`.
+    ///
+    /// # Returns
+    /// `true` if the entrypoint is mapped and the caller is an active admin whose role is at
+    /// least the mapped role; `false` otherwise (including unmapped entrypoints)
+    pub fn can_call(e: Env, caller: Address, contract: Address, fn_name: Symbol) -> bool {
+        match Self::get_function_role(e.clone(), contract, fn_name) {
+            Some(min_role) => Self::has_role_at_least(e, caller, min_role),
+            None => false,
+        }
+    }
+
+    /// Cap how many times admins of `role` may perform `action` per `window_secs`.
+    ///
+    /// Usage is tracked per admin. SuperAdmins are never rate limited.
+    ///
+    /// # Panics
+    /// * If caller is not an active super admin
+    /// * If `role` is `SuperAdmin` or `window_secs` is 0
+    ///
+    /// # Events
+    /// Emits `role_quota_set` with (role, action, max_actions, window_secs)
+    pub fn set_role_quota(
+        e: Env,
+        super_admin: Address,
+        role: AdminRole,
+        action: AdminAction,
+        max_actions: u32,
+        window_secs: u64,
+    ) {
+        pausable::require_not_paused(&e);
+        super_admin.require_auth();
+        Self::require_active_super_admin(&e, &super_admin);
+
+        quota::set_quota(
+            &e,
+            role,
+            action,
+            &RoleQuota {
+                max_actions,
+                window_secs,
+            },
+        );
+        e.events().publish(
+            (Symbol::new(&e, "role_quota_set"),),
+            (role, action, max_actions, window_secs),
+        );
+    }
+
+    /// Remove the quota for (`role`, `action`); the action becomes unlimited for that role.
+    ///
+    /// # Panics
+    /// * If caller is not an active super admin
+    ///
+    /// # Events
+    /// Emits `role_quota_cleared` with (role, action)
+    pub fn clear_role_quota(e: Env, super_admin: Address, role: AdminRole, action: AdminAction) {
+        pausable::require_not_paused(&e);
+        super_admin.require_auth();
+        Self::require_active_super_admin(&e, &super_admin);
+
+        quota::clear_quota(&e, role, action);
+        e.events()
+            .publish((Symbol::new(&e, "role_quota_cleared"),), (role, action));
+    }
+
+    /// Get the quota configured for (`role`, `action`), if any.
+    pub fn get_role_quota(e: Env, role: AdminRole, action: AdminAction) -> Option<RoleQuota> {
+        quota::get_quota(&e, role, action)
+    }
+
+    /// How many more times `admin` may perform `action` in the current window.
+    ///
+    /// # Returns
+    /// `None` if the action is unlimited for the admin (no quota for their role, or SuperAdmin)
+    ///
+    /// # Panics
+    /// * If `admin` is not an admin
+    pub fn get_remaining_quota(e: Env, admin: Address, action: AdminAction) -> Option<u32> {
+        let role = Self::get_role(e.clone(), admin.clone());
+        quota::remaining(&e, &admin, role, action)
+    }
+
     // Helper functions
 
     /// Get the role of an address (panics if not admin).
@@ -649,6 +807,13 @@ impl AdminContract {
         }
     }
 
+    /// Require that the caller is an active super admin.
+    fn require_active_super_admin(e: &Env, caller: &Address) {
+        if !Self::has_role_at_least(e.clone(), caller.clone(), AdminRole::SuperAdmin) {
+            panic!("insufficient privileges");
+        }
+    }
+
     /// Require that the caller has at least the specified role.
     fn require_role_at_least(
         e: &Env,
@@ -704,3 +869,9 @@ mod test_pausable;
 
 #[cfg(test)]
 mod test_basic;
+
+#[cfg(test)]
+mod test_function_registry;
+
+#[cfg(test)]
+mod test_quotas;
@@ -0,0 +1,120 @@
+//! Per-role action quotas.
+//!
+//! A SuperAdmin can cap how many sensitive admin-management actions each role may perform
+//! within a rolling window (e.g. Operators: 10 deactivations per 24h). Usage is tracked per
+//! admin and per action, and a window is reset lazily by the first action after it ends.
+//! SuperAdmins are never subject to quotas.
+
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::{AdminRole, DataKey};
+
+/// Admin-management actions that can be rate limited.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AdminAction {
+    Remove,
+    Deactivate,
+    Reactivate,
+    UpdateRole,
+}
+
+/// Maximum number of `action`s an admin of a role may perform per window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleQuota {
+    pub max_actions: u32,
+    pub window_secs: u64,
+}
+
+/// Usage of one action by one admin in the current window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuotaUsage {
+    pub window_start: u64,
+    pub used: u32,
+}
+
+pub fn get_quota(e: &Env, role: AdminRole, action: AdminAction) -> Option<RoleQuota> {
+    e.storage()
+        .instance()
+        .get(&DataKey::RoleQuota(role, action))
+}
+
+pub fn set_quota(e: &Env, role: AdminRole, action: AdminAction, quota: &RoleQuota) {
+    if role == AdminRole::SuperAdmin {
+        panic!("super admin cannot be rate limited");
+    }
+    if quota.window_secs == 0 {
+        panic!("quota window must be positive");
+    }
+    e.storage()
+        .instance()
+        .set(&DataKey::RoleQuota(role, action), quota);
+}
+
+pub fn clear_quota(e: &Env, role: AdminRole, action: AdminAction) {
+    e.storage()
+        .instance()
+        .remove(&DataKey::RoleQuota(role, action));
+}
+
+/// Usage for the window containing `now`; an expired window reads as a fresh one.
+fn current_usage(
+    e: &Env,
+    admin: &Address,
+    action: AdminAction,
+    quota: &RoleQuota,
+    now: u64,
+) -> QuotaUsage {
+    let usage: Option<QuotaUsage> = e
+        .storage()
+        .instance()
+        .get(&DataKey::QuotaUsage(admin.clone(), action));
+    match usage {
+        Some(u) if now < u.window_start.saturating_add(quota.window_secs) => u,
+        _ => QuotaUsage {
+            window_start: now,
+            used: 0,
+        },
+    }
+}
+
+/// Actions `admin` (holding `role`) may still perform in the current window, or `None` if the
+/// action is unlimited for them.
+pub fn remaining(e: &Env, admin: &Address, role: AdminRole, action: AdminAction) -> Option<u32> {
+    if role == AdminRole::SuperAdmin {
+        return None;
+    }
+    let quota = get_quota(e, role, action)?;
+    let usage = current_usage(e, admin, action, &quota, e.ledger().timestamp());
+    Some(quota.max_actions.saturating_sub(usage.used))
+}
+
+/// Record one `action` by `admin`.
+///
+/// # Panics
+/// * "quota exceeded" if the admin's role quota for `action` is used up in the current window;
+///   `quota_exceeded` is emitted first with (admin, action, retry_after)
+pub fn consume(e: &Env, admin: &Address, role: AdminRole, action: AdminAction) {
+    if role == AdminRole::SuperAdmin {
+        return;
+    }
+    let Some(quota) = get_quota(e, role, action) else {
+        return;
+    };
+    let now = e.ledger().timestamp();
+    let mut usage = current_usage(e, admin, action, &quota, now);
+    if usage.used >= quota.max_actions {
+        let retry_after = usage.window_start.saturating_add(quota.window_secs);
+        e.events().publish(
+            (Symbol::new(e, "quota_exceeded"),),
+            (admin.clone(), action, retry_after),
+        );
+        panic!("quota exceeded");
+    }
+    usage.used += 1;
+    e.storage()
+        .instance()
+        .set(&DataKey::QuotaUsage(admin.clone(), action), &usage);
+}
@@ -0,0 +1,139 @@
+use crate::*;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{vec, Address, Env, IntoVal, Symbol};
+
+fn setup(env: &Env) -> (AdminContractClient<'_>, Address, Address, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, AdminContract);
+    let client = AdminContractClient::new(env, &contract_id);
+    let super_admin = Address::generate(env);
+    let admin = Address::generate(env);
+    let operator = Address::generate(env);
+    client.initialize(&super_admin, &1, &100);
+    client.add_admin(&super_admin, &admin, &AdminRole::Admin);
+    client.add_admin(&admin, &operator, &AdminRole::Operator);
+    (client, contract_id, super_admin, admin, operator)
+}
+
+#[test]
+fn test_unmapped_function_denied() {
+    let env = Env::default();
+    let (client, _id, super_admin, _admin, _operator) = setup(&env);
+    let bond = Address::generate(&env);
+    let fn_name = Symbol::new(&env, "set_fee_config");
+
+    assert_eq!(client.get_function_role(&bond, &fn_name), None);
+    assert!(!client.can_call(&super_admin, &bond, &fn_name));
+}
+
+#[test]
+fn test_mapping_to_operator_allows_operator_and_above() {
+    let env = Env::default();
+    let (client, _id, super_admin, admin, operator) = setup(&env);
+    let bond = Address::generate(&env);
+    let fn_name = Symbol::new(&env, "set_fee_config");
+
+    client.set_function_role(&super_admin, &bond, &fn_name, &AdminRole::Operator);
+
+    assert_eq!(
+        client.get_function_role(&bond, &fn_name),
+        Some(AdminRole::Operator)
+    );
+    assert!(client.can_call(&operator, &bond, &fn_name));
+    assert!(client.can_call(&admin, &bond, &fn_name));
+    assert!(client.can_call(&super_admin, &bond, &fn_name));
+    assert!(!client.can_call(&Address::generate(&env), &bond, &fn_name));
+}
+
+#[test]
+fn test_raising_role_blocks_operator() {
+    let env = Env::default();
+    let (client, _id, super_admin, admin, operator) = setup(&env);
+    let bond = Address::generate(&env);
+    let fn_name = Symbol::new(&env, "set_fee_config");
+
+    client.set_function_role(&super_admin, &bond, &fn_name, &AdminRole::Operator);
+    client.set_function_role(&super_admin, &bond, &fn_name, &AdminRole::Admin);
+
+    assert!(!client.can_call(&operator, &bond, &fn_name));
+    assert!(client.can_call(&admin, &bond, &fn_name));
+}
+
+#[test]
+fn test_mapping_is_scoped_to_contract() {
+    let env = Env::default();
+    let (client, _id, super_admin, _admin, operator) = setup(&env);
+    let bond = Address::generate(&env);
+    let other = Address::generate(&env);
+    let fn_name = Symbol::new(&env, "set_fee_config");
+
+    client.set_function_role(&super_admin, &bond, &fn_name, &AdminRole::Operator);
+    assert!(!client.can_call(&operator, &other, &fn_name));
+}
+
+#[test]
+fn test_deactivated_admin_denied() {
+    let env = Env::default();
+    let (client, _id, super_admin, admin, operator) = setup(&env);
+    let bond = Address::generate(&env);
+    let fn_name = Symbol::new(&env, "set_fee_config");
+
+    client.set_function_role(&super_admin, &bond, &fn_name, &AdminRole::Operator);
+    client.deactivate_admin(&admin, &operator);
+    assert!(!client.can_call(&operator, &bond, &fn_name));
+}
+
+#[test]
+fn test_clear_function_role() {
+    let env = Env::default();
+    let (client, _id, super_admin, _admin, operator) = setup(&env);
+    let bond = Address::generate(&env);
+    let fn_name = Symbol::new(&env, "set_fee_config");
+
+    client.set_function_role(&super_admin, &bond, &fn_name, &AdminRole::Operator);
+    client.clear_function_role(&super_admin, &bond, &fn_name);
+
+    assert_eq!(client.get_function_role(&bond, &fn_name), None);
+    assert!(!client.can_call(&operator, &bond, &fn_name));
+}
+
+#[test]
+#[should_panic(expected = "insufficient privileges")]
+fn test_set_function_role_requires_super_admin() {
+    let env = Env::default();
+    let (client, _id, _super_admin, admin, _operator) = setup(&env);
+    let bond = Address::generate(&env);
+    client.set_function_role(
+        &admin,
+        &bond,
+        &Symbol::new(&env, "set_fee_config"),
+        &AdminRole::Operator,
+    );
+}
+
+#[test]
+fn test_registry_changes_emit_events() {
+    let env = Env::default();
+    let (client, contract_id, super_admin, _admin, _operator) = setup(&env);
+    let bond = Address::generate(&env);
+    let fn_name = Symbol::new(&env, "set_fee_config");
+
+    client.set_function_role(&super_admin, &bond, &fn_name, &AdminRole::Operator);
+    let set_topics = vec![&env, Symbol::new(&env, "function_role_set").into_val(&env)];
+    assert!(env
+        .events()
+        .all()
+        .iter()
+        .any(|(c, t, _)| c == contract_id && t == set_topics));
+
+    client.clear_function_role(&super_admin, &bond, &fn_name);
+    let cleared_topics = vec![
+        &env,
+        Symbol::new(&env, "function_role_cleared").into_val(&env),
+    ];
+    assert!(env
+        .events()
+        .all()
+        .iter()
+        .any(|(c, t, _)| c == contract_id && t == cleared_topics));
+}
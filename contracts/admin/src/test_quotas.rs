@@ -0,0 +1,157 @@
+use crate::quota::AdminAction;
+use crate::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+const DAY: u64 = 86_400;
+
+fn setup(env: &Env) -> (AdminContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, AdminContract);
+    let client = AdminContractClient::new(env, &contract_id);
+    let super_admin = Address::generate(env);
+    let admin = Address::generate(env);
+    client.initialize(&super_admin, &1, &100);
+    client.add_admin(&super_admin, &admin, &AdminRole::Admin);
+    (client, super_admin, admin)
+}
+
+fn add_operators(env: &Env, client: &AdminContractClient, admin: &Address, n: u32) -> Vec<Address> {
+    let mut ops = Vec::new(env);
+    for _ in 0..n {
+        let op = Address::generate(env);
+        client.add_admin(admin, &op, &AdminRole::Operator);
+        ops.push_back(op);
+    }
+    ops
+}
+
+#[test]
+fn test_quota_consumed_per_action() {
+    let env = Env::default();
+    let (client, super_admin, admin) = setup(&env);
+    let ops = add_operators(&env, &client, &admin, 3);
+
+    assert_eq!(
+        client.get_remaining_quota(&admin, &AdminAction::Deactivate),
+        None
+    );
+    client.set_role_quota(
+        &super_admin,
+        &AdminRole::Admin,
+        &AdminAction::Deactivate,
+        &2,
+        &DAY,
+    );
+    client.set_role_quota(
+        &super_admin,
+        &AdminRole::Admin,
+        &AdminAction::Reactivate,
+        &2,
+        &DAY,
+    );
+    assert_eq!(
+        client.get_remaining_quota(&admin, &AdminAction::Deactivate),
+        Some(2)
+    );
+
+    client.deactivate_admin(&admin, &ops.get(0).unwrap());
+    client.deactivate_admin(&admin, &ops.get(1).unwrap());
+    client.reactivate_admin(&admin, &ops.get(0).unwrap());
+    assert_eq!(
+        client.get_remaining_quota(&admin, &AdminAction::Deactivate),
+        Some(0)
+    );
+    assert_eq!(
+        client.get_remaining_quota(&admin, &AdminAction::Reactivate),
+        Some(1)
+    );
+    // Removal has no quota configured.
+    client.remove_admin(&admin, &ops.get(1).unwrap());
+
+    assert!(client
+        .try_deactivate_admin(&admin, &ops.get(2).unwrap())
+        .is_err());
+    assert!(client.get_admin_info(&ops.get(2).unwrap()).active);
+}
+
+#[test]
+fn test_quota_resets_after_window() {
+    let env = Env::default();
+    let (client, super_admin, admin) = setup(&env);
+    let ops = add_operators(&env, &client, &admin, 2);
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.set_role_quota(
+        &super_admin,
+        &AdminRole::Admin,
+        &AdminAction::Remove,
+        &1,
+        &DAY,
+    );
+
+    client.remove_admin(&admin, &ops.get(0).unwrap());
+    assert!(client
+        .try_remove_admin(&admin, &ops.get(1).unwrap())
+        .is_err());
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + DAY - 1);
+    assert_eq!(
+        client.get_remaining_quota(&admin, &AdminAction::Remove),
+        Some(0)
+    );
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + DAY);
+    assert_eq!(
+        client.get_remaining_quota(&admin, &AdminAction::Remove),
+        Some(1)
+    );
+    client.remove_admin(&admin, &ops.get(1).unwrap());
+    assert!(!client.is_admin(&ops.get(1).unwrap()));
+}
+
+#[test]
+fn test_super_admin_exempt() {
+    let env = Env::default();
+    let (client, super_admin, admin) = setup(&env);
+    let ops = add_operators(&env, &client, &admin, 2);
+    client.set_role_quota(
+        &super_admin,
+        &AdminRole::Admin,
+        &AdminAction::UpdateRole,
+        &1,
+        &DAY,
+    );
+
+    client.update_admin_role(&super_admin, &ops.get(0).unwrap(), &AdminRole::Admin);
+    client.update_admin_role(&super_admin, &ops.get(1).unwrap(), &AdminRole::Admin);
+    assert_eq!(
+        client.get_remaining_quota(&super_admin, &AdminAction::UpdateRole),
+        None
+    );
+    assert!(client
+        .try_set_role_quota(
+            &super_admin,
+            &AdminRole::SuperAdmin,
+            &AdminAction::Remove,
+            &1,
+            &DAY
+        )
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "quota exceeded")]
+fn test_exceeding_quota_panics() {
+    let env = Env::default();
+    let (client, super_admin, admin) = setup(&env);
+    let ops = add_operators(&env, &client, &admin, 2);
+    client.set_role_quota(
+        &super_admin,
+        &AdminRole::Admin,
+        &AdminAction::Deactivate,
+        &1,
+        &DAY,
+    );
+
+    client.deactivate_admin(&admin, &ops.get(0).unwrap());
+    client.deactivate_admin(&admin, &ops.get(1).unwrap());
+}
@@ -192,8 +192,7 @@ pub fn add_verifier_role(e: &Env, admin: &Address, verifier: &Address) {
     let verifier_key = build_verifier_key(e, verifier);
     e.storage().instance().set(&verifier_key, &true);
 
-    e.events()
-        .publish((Symbol::new(e, "verifier_added"),), (verifier.clone(),));
+    crate::events::publish(e, "verifier_added", (verifier.clone(),));
 }
 
 /// @notice Remove a verifier (admin only).
@@ -215,8 +214,7 @@ pub fn remove_verifier_role(e: &Env, admin: &Address, verifier: &Address) {
     let verifier_key = build_verifier_key(e, verifier);
     e.storage().instance().set(&verifier_key, &false);
 
-    e.events()
-        .publish((Symbol::new(e, "verifier_removed"),), (verifier.clone(),));
+    crate::events::publish(e, "verifier_removed", (verifier.clone(),));
 }
 
 /// @notice Check if an address is a verifier (read-only, no panic).
@@ -276,8 +274,9 @@ fn emit_access_denied(e: &Env, caller: &Address, role: &str, error: AccessError)
         AccessError::NotInitialized => 4u32,
     };
 
-    e.events().publish(
-        (Symbol::new(e, ACCESS_DENIED_EVENT),),
+    crate::events::publish(
+        e,
+        ACCESS_DENIED_EVENT,
         (caller.clone(), Symbol::new(e, role), error_code),
     );
 }
@@ -0,0 +1,215 @@
+//! Attestation Import
+//!
+//! Carries attestation history over from an older attestation contract so subjects keep their
+//! reputation after a migration. Each source attestation is read cross-contract, re-created
+//! locally under a new id with its original timestamp, attester, subject, payload and revocation
+//! state, and tagged with an `ImportOrigin` recording the source contract and original id.
+//!
+//! Entries are skipped (with an `attestation_import_skipped` event) when they were already
+//! imported, duplicate a local attestation, or were issued by an attester that is not registered
+//! locally, unless the caller sets `allow_unregistered`. A call processes at most
+//! `MAX_IMPORT_BATCH` ids; the rest are returned for the next call.
+//!
+//! Imported attestations do not count toward attester issuance statistics, which only track
+//! attestations issued on this contract.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env, Symbol, Vec};
+
+use crate::{subject_seq, Attestation, DataKey};
+
+/// Maximum number of source ids processed per `import_attestations` call.
+pub const MAX_IMPORT_BATCH: u32 = 25;
+
+/// Minimal read interface of the source attestation contract. Only the generated
+/// `AttestationSourceClient` is used.
+#[allow(dead_code)]
+#[contractclient(name = "AttestationSourceClient")]
+pub trait AttestationSource {
+    fn get_attestation(env: Env, attestation_id: u64) -> Attestation;
+}
+
+/// @notice Where an imported attestation came from.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImportOrigin {
+    pub imported_from: Address,
+    pub original_id: u64,
+}
+
+/// @notice Outcome of one import batch.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImportBatchResult {
+    /// Local ids assigned to the attestations imported by this call, in input order.
+    pub imported: Vec<u64>,
+    /// Number of ids skipped by this call.
+    pub skipped: u32,
+    /// Ids beyond `MAX_IMPORT_BATCH` that were not processed; pass them to the next call.
+    pub remaining: Vec<u64>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum ImportKey {
+    /// Local attestation id -> ImportOrigin
+    Origin(u64),
+    /// (source contract, original id) -> local attestation id
+    Imported(Address, u64),
+}
+
+/// @notice Origin of `attestation_id` if it was imported, else `None`.
+#[must_use]
+pub fn get_origin(e: &Env, attestation_id: u64) -> Option<ImportOrigin> {
+    e.storage()
+        .instance()
+        .get(&ImportKey::Origin(attestation_id))
+}
+
+/// @notice Local id assigned to `original_id` from `source`, if it has been imported.
+#[must_use]
+pub fn local_id(e: &Env, source: &Address, original_id: u64) -> Option<u64> {
+    e.storage()
+        .instance()
+        .get(&ImportKey::Imported(source.clone(), original_id))
+}
+
+fn skip(e: &Env, source: &Address, original_id: u64, reason: &str) {
+    crate::events::publish_for(
+        e,
+        "attestation_import_skipped",
+        source.clone(),
+        (original_id, Symbol::new(e, reason)),
+    );
+}
+
+/// @notice Imports up to `MAX_IMPORT_BATCH` of `ids` from `source` (admin only; caller must
+/// enforce).
+///
+/// # Panics
+/// - If `source` is this contract, or the source call fails for any id.
+pub fn import_batch(
+    e: &Env,
+    source: &Address,
+    ids: &Vec<u64>,
+    allow_unregistered: bool,
+) -> ImportBatchResult {
+    if *source == e.current_contract_address() {
+        panic!("cannot import from self");
+    }
+    let client = AttestationSourceClient::new(e, source);
+    let mut imported = Vec::new(e);
+    let mut skipped = 0_u32;
+    let mut remaining = Vec::new(e);
+
+    for (i, original_id) in ids.iter().enumerate() {
+        if i as u32 >= MAX_IMPORT_BATCH {
+            remaining.push_back(original_id);
+            continue;
+        }
+        if local_id(e, source, original_id).is_some() {
+            skip(e, source, original_id, "already_imported");
+            skipped += 1;
+            continue;
+        }
+        let att = client.get_attestation(&original_id);
+        let registered: bool = e
+            .storage()
+            .instance()
+            .get(&DataKey::Attester(att.verifier.clone()))
+            .unwrap_or(false);
+        if !registered && !allow_unregistered {
+            skip(e, source, original_id, "unregistered_attester");
+            skipped += 1;
+            continue;
+        }
+        if !att.revoked && att.data_hash.is_none() {
+            let dup_key = DataKey::DuplicateCheck(
+                att.verifier.clone(),
+                att.identity.clone(),
+                att.attestation_data.clone(),
+            );
+            if e.storage().instance().has(&dup_key) {
+                skip(e, source, original_id, "duplicate");
+                skipped += 1;
+                continue;
+            }
+            e.storage().instance().set(&dup_key, &true);
+        }
+
+        let id = store_imported(e, source, original_id, att);
+        imported.push_back(id);
+    }
+
+    ImportBatchResult {
+        imported,
+        skipped,
+        remaining,
+    }
+}
+
+fn store_imported(e: &Env, source: &Address, original_id: u64, att: Attestation) -> u64 {
+    let id: u64 = e
+        .storage()
+        .instance()
+        .get(&DataKey::AttestationCounter)
+        .unwrap_or(0);
+    let next_id = id.checked_add(1).expect("attestation counter overflow");
+    e.storage()
+        .instance()
+        .set(&DataKey::AttestationCounter, &next_id);
+
+    let subject_seq = subject_seq::assign(e, &att.identity, id);
+    let local = Attestation {
+        id,
+        verifier: att.verifier.clone(),
+        identity: att.identity.clone(),
+        timestamp: att.timestamp,
+        weight: att.weight,
+        attestation_data: att.attestation_data,
+        revoked: att.revoked,
+        revoked_at: att.revoked_at,
+        subject_seq,
+        use_count: 0,
+        data_hash: att.data_hash,
+        expires_at: 0,
+        flags: 0,
+        consented: false,
+        revocation_reason: None,
+    };
+    crate::attestation_store::put(e, &local);
+
+    let subject_key = DataKey::SubjectAttestations(att.identity.clone());
+    let mut list: Vec<u64> = e
+        .storage()
+        .instance()
+        .get(&subject_key)
+        .unwrap_or(Vec::new(e));
+    list.push_back(id);
+    e.storage().instance().set(&subject_key, &list);
+
+    if !local.revoked {
+        let count_key = DataKey::SubjectAttestationCount(local.identity.clone());
+        let count: u32 = e.storage().instance().get(&count_key).unwrap_or(0);
+        let next = count.checked_add(1).expect("attestation count overflow");
+        e.storage().instance().set(&count_key, &next);
+    }
+
+    e.storage().instance().set(
+        &ImportKey::Origin(id),
+        &ImportOrigin {
+            imported_from: source.clone(),
+            original_id,
+        },
+    );
+    e.storage()
+        .instance()
+        .set(&ImportKey::Imported(source.clone(), original_id), &id);
+
+    crate::events::publish_for(
+        e,
+        "attestation_imported",
+        att.identity,
+        (id, source.clone(), original_id),
+    );
+    id
+}
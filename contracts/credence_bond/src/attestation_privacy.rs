@@ -0,0 +1,72 @@
+//! Hash-only Attestations
+//!
+//! Deployments that must not store attestation payloads on-chain enable the
+//! `attestation_privacy` feature flag. Plain `add_attestation` is then rejected and attesters use
+//! `add_attestation_hash`, which stores only a 32-byte commitment in `data_hash` (with an empty
+//! `attestation_data`). Anyone holding the off-chain payload can later prove it matches with
+//! `verify_attestation_data`, which compares its sha256 against the stored hash.
+//!
+//! Hash attestations are accepted whether or not the flag is on, so a subject can hold both
+//! kinds after a deployment switches modes.
+
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Symbol};
+
+use crate::features;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum PrivacyKey {
+    /// Dedup marker for (attester, subject, data hash).
+    HashSeen(Address, Address, BytesN<32>),
+}
+
+/// @notice Returns true if plain-text attestation payloads are disallowed.
+#[must_use]
+pub fn is_enabled(e: &Env) -> bool {
+    features::is_enabled(e, &Symbol::new(e, features::ATTESTATION_PRIVACY))
+}
+
+/// @notice Rejects plain-text attestations while privacy mode is on.
+///
+/// # Panics
+/// - "attestation privacy mode: use add_attestation_hash" if privacy mode is enabled.
+pub fn require_plain_allowed(e: &Env) {
+    if is_enabled(e) {
+        panic!("attestation privacy mode: use add_attestation_hash");
+    }
+}
+
+/// @notice Marks (attester, subject, hash) as attested.
+///
+/// # Panics
+/// - "duplicate attestation" if the same hash was already attested for the subject.
+pub fn mark_hash_seen(e: &Env, attester: &Address, subject: &Address, data_hash: &BytesN<32>) {
+    let key = PrivacyKey::HashSeen(attester.clone(), subject.clone(), data_hash.clone());
+    if e.storage().instance().has(&key) {
+        panic!("duplicate attestation");
+    }
+    e.storage().instance().set(&key, &true);
+}
+
+/// @notice Moves the (attester, subject, hash) dedup marker from subject `from` to `to`, if set.
+pub fn move_hash_seen(
+    e: &Env,
+    attester: &Address,
+    from: &Address,
+    to: &Address,
+    data_hash: &BytesN<32>,
+) {
+    let old = PrivacyKey::HashSeen(attester.clone(), from.clone(), data_hash.clone());
+    if e.storage().instance().has(&old) {
+        e.storage().instance().remove(&old);
+        let new = PrivacyKey::HashSeen(attester.clone(), to.clone(), data_hash.clone());
+        e.storage().instance().set(&new, &true);
+    }
+}
+
+/// @notice True if `preimage` hashes (sha256) to `data_hash`.
+#[must_use]
+pub fn matches(e: &Env, data_hash: &BytesN<32>, preimage: &Bytes) -> bool {
+    let digest: BytesN<32> = e.crypto().sha256(preimage).into();
+    digest == *data_hash
+}
@@ -0,0 +1,108 @@
+//! Versioned Attestation Storage
+//!
+//! Attestations live under `DataKey::Attestation(id)`. Contracts deployed before the V2 fields
+//! (`expires_at`, `flags`, `consented`, `revocation_reason`) were added stored a bare
+//! `AttestationV1` there, which no longer decodes as `Attestation`. New records are written as
+//! `AttestationRecord::V2`, a version-tagged value under the same key.
+//!
+//! `get` accepts both forms and upgrades a V1 record to V2 defaults in memory, so every reader
+//! sees the current layout. The stored V1 value is replaced the next time the record is written
+//! (revocation, usage, transfer), or proactively by `migrate_batch`, which follows the dry-run
+//! convention in `docs/migrations.md`.
+
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Bytes, BytesN, Env, Symbol, TryFromVal, Val, Vec};
+
+use crate::types::{Attestation, AttestationRecord, AttestationV1};
+use crate::DataKey;
+
+/// Most ids accepted by one batch migration call.
+pub const MAX_MIGRATION_BATCH: u32 = 100;
+
+fn decode(e: &Env, raw: &Val) -> (Attestation, bool) {
+    if let Ok(AttestationRecord::V2(att)) = AttestationRecord::try_from_val(e, raw) {
+        return (att, false);
+    }
+    let legacy =
+        AttestationV1::try_from_val(e, raw).unwrap_or_else(|_| panic!("corrupt attestation"));
+    (legacy.upgrade(), true)
+}
+
+fn load(e: &Env, id: u64) -> Option<(Attestation, bool)> {
+    let raw: Val = e.storage().instance().get(&DataKey::Attestation(id))?;
+    Some(decode(e, &raw))
+}
+
+/// @notice Attestation `id` in the current layout, upgrading a V1 record in memory.
+#[must_use]
+pub fn get(e: &Env, id: u64) -> Option<Attestation> {
+    load(e, id).map(|(att, _)| att)
+}
+
+/// @notice Writes `attestation` under its id as a V2 record.
+pub fn put(e: &Env, attestation: &Attestation) {
+    e.storage().instance().set(
+        &DataKey::Attestation(attestation.id),
+        &AttestationRecord::V2(attestation.clone()),
+    );
+}
+
+/// @notice Storage layout version of attestation `id`: 1 for a bare V1 record, 2 otherwise.
+///
+/// # Panics
+/// - "attestation not found"
+#[must_use]
+pub fn record_version(e: &Env, id: u64) -> u32 {
+    match load(e, id) {
+        Some((_, true)) => 1,
+        Some((_, false)) => 2,
+        None => panic!("attestation not found"),
+    }
+}
+
+/// @notice Rewrites the V1 records among `ids` as V2 (admin check enforced by the caller).
+/// Missing and already migrated ids are skipped, so repeating a batch writes nothing. With
+/// `dry_run` nothing is written. Emits `migration_preview` or `migration_applied` with
+/// `(migration, items_read, items_written, items_removed, digest)`. Returns the number of
+/// records rewritten, or that would be.
+///
+/// # Panics
+/// - "too many ids" above `MAX_MIGRATION_BATCH`
+pub fn migrate_batch(e: &Env, ids: &Vec<u64>, dry_run: bool) -> u32 {
+    if ids.len() > MAX_MIGRATION_BATCH {
+        panic!("too many ids");
+    }
+    let mut read = 0_u32;
+    let mut writes: Vec<Attestation> = Vec::new(e);
+    let mut encoded = Bytes::new(e);
+    for id in ids.iter() {
+        let Some((att, legacy)) = load(e, id) else {
+            continue;
+        };
+        read += 1;
+        if legacy && !writes.iter().any(|w| w.id == id) {
+            encoded.append(&DataKey::Attestation(id).to_xdr(e));
+            encoded.append(&AttestationRecord::V2(att.clone()).to_xdr(e));
+            writes.push_back(att);
+        }
+    }
+
+    let digest: BytesN<32> = e.crypto().sha256(&encoded).into();
+    let written = writes.len();
+    let summary = (
+        Symbol::new(e, "attestation_v2"),
+        read,
+        written,
+        0_u32,
+        digest,
+    );
+    if dry_run {
+        crate::events::publish(e, "migration_preview", summary);
+        return written;
+    }
+    for att in writes.iter() {
+        put(e, &att);
+    }
+    crate::events::publish(e, "migration_applied", summary);
+    written
+}
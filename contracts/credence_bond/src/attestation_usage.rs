@@ -0,0 +1,103 @@
+//! Attestation Usage Tracking
+//!
+//! Downstream contracts that rely on an attestation can report it, so the attestation record
+//! carries a lightweight "relied upon N times" signal. Only consumer contracts the admin has
+//! allow-listed may report, and a single call can record at most `MAX_USES_PER_CALL` uses, which
+//! bounds how fast any one consumer can inflate a count.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+/// Maximum uses a consumer may record in one `record_attestation_use` call.
+pub const MAX_USES_PER_CALL: u32 = 100;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum UsageKey {
+    /// Consumer contract allowed to record uses.
+    Consumer(Address),
+    /// Total uses recorded by a consumer across all attestations.
+    ConsumerUses(Address),
+}
+
+/// @notice Returns true if `consumer` may record attestation uses.
+#[must_use]
+pub fn is_consumer(e: &Env, consumer: &Address) -> bool {
+    e.storage()
+        .instance()
+        .get(&UsageKey::Consumer(consumer.clone()))
+        .unwrap_or(false)
+}
+
+/// @notice Adds or removes `consumer` from the allow-list (admin only; caller must enforce).
+pub fn set_consumer(e: &Env, consumer: &Address, allowed: bool) {
+    let key = UsageKey::Consumer(consumer.clone());
+    if allowed {
+        e.storage().instance().set(&key, &true);
+    } else {
+        e.storage().instance().remove(&key);
+    }
+    crate::events::publish_for(e, "attestation_consumer_set", consumer.clone(), allowed);
+}
+
+/// @notice Total uses recorded by `consumer`.
+#[must_use]
+pub fn consumer_uses(e: &Env, consumer: &Address) -> u64 {
+    e.storage()
+        .instance()
+        .get(&UsageKey::ConsumerUses(consumer.clone()))
+        .unwrap_or(0)
+}
+
+/// @notice Records `count` uses of `attestation_id` by `consumer`. Caller must have
+/// authenticated `consumer`.
+/// @return The attestation's new use count.
+///
+/// # Panics
+/// - "consumer not allowed" if `consumer` is not allow-listed.
+/// - "invalid use count" if `count` is 0 or above `MAX_USES_PER_CALL`.
+/// - "attestation not found" / "attestation revoked".
+pub fn record_use(e: &Env, consumer: &Address, attestation_id: u64, count: u32) -> u32 {
+    if !is_consumer(e, consumer) {
+        panic!("consumer not allowed");
+    }
+    if count == 0 || count > MAX_USES_PER_CALL {
+        panic!("invalid use count");
+    }
+    let mut attestation = crate::attestation_store::get(e, attestation_id)
+        .unwrap_or_else(|| panic!("attestation not found"));
+    if attestation.revoked {
+        panic!("attestation revoked");
+    }
+
+    attestation.use_count = attestation
+        .use_count
+        .checked_add(count)
+        .expect("use count overflow");
+    crate::attestation_store::put(e, &attestation);
+
+    let total = consumer_uses(e, consumer)
+        .checked_add(u64::from(count))
+        .expect("consumer use count overflow");
+    e.storage()
+        .instance()
+        .set(&UsageKey::ConsumerUses(consumer.clone()), &total);
+
+    crate::events::publish_for(
+        e,
+        "attestation_used",
+        consumer.clone(),
+        (attestation_id, count, attestation.use_count),
+    );
+    attestation.use_count
+}
+
+/// @notice Use count of `attestation_id`.
+///
+/// # Panics
+/// - "attestation not found" if the attestation does not exist.
+#[must_use]
+pub fn get_usage(e: &Env, attestation_id: u64) -> u32 {
+    crate::attestation_store::get(e, attestation_id)
+        .unwrap_or_else(|| panic!("attestation not found"))
+        .use_count
+}
@@ -0,0 +1,82 @@
+//! Attester Bond Accountability
+//!
+//! Deployments that make attesters bond (separately from verifier stake) can require the
+//! attester's own bond to stay in force for a minimum remaining duration. When configured,
+//! `add_attestation` refuses attestations from attesters whose bond is inactive or about to
+//! mature, since their accountability would lapse shortly after issuing the claim.
+//!
+//! The check is skipped entirely while the threshold is unset.
+
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::{DataKey, IdentityBond};
+
+const KEY_MIN_REMAINING: &str = "min_att_bond_rem";
+
+const EVENT_CONFIG_UPDATED: &str = "attester_bond_config_updated";
+
+fn min_remaining_key(e: &Env) -> Symbol {
+    Symbol::new(e, KEY_MIN_REMAINING)
+}
+
+/// @notice Returns the minimum remaining bond duration (seconds) required from attesters.
+/// @dev `None` means the requirement is disabled.
+#[must_use]
+pub fn get_min_remaining(e: &Env) -> Option<u64> {
+    e.storage().instance().get(&min_remaining_key(e))
+}
+
+/// @notice Sets the minimum remaining bond duration (admin only; caller must enforce).
+/// @param min_remaining Seconds the attester's bond must still run at attestation time.
+pub fn set_min_remaining(e: &Env, min_remaining: u64) {
+    e.storage()
+        .instance()
+        .set(&min_remaining_key(e), &min_remaining);
+    crate::events::publish(e, EVENT_CONFIG_UPDATED, (Some(min_remaining),));
+}
+
+/// @notice Disables the attester bond requirement (admin only; caller must enforce).
+pub fn clear_min_remaining(e: &Env) {
+    e.storage().instance().remove(&min_remaining_key(e));
+    crate::events::publish(e, EVENT_CONFIG_UPDATED, (None::<u64>,));
+}
+
+/// @notice Returns the seconds left until the bond matures (0 once matured).
+#[must_use]
+pub fn remaining_duration(now: u64, bond: &IdentityBond) -> u64 {
+    bond.bond_start
+        .saturating_add(bond.bond_duration)
+        .saturating_sub(now)
+}
+
+fn active_bond(e: &Env, attester: &Address) -> Option<IdentityBond> {
+    let bond: Option<IdentityBond> = e.storage().instance().get(&DataKey::Bond(attester.clone()));
+    bond.filter(|b| b.active)
+}
+
+/// @notice Returns the seconds the attester's own bond still runs, or `None` without an
+/// active bond.
+/// @dev Lets callers see ahead of time whether `add_attestation` would be refused: a refusal
+/// rolls back, so it leaves no event or record behind.
+#[must_use]
+pub fn attester_remaining(e: &Env, attester: &Address) -> Option<u64> {
+    active_bond(e, attester).map(|b| remaining_duration(e.ledger().timestamp(), &b))
+}
+
+/// @notice Enforces the attester bond requirement, if configured.
+///
+/// # Panics
+/// - "attester bond inactive" if the attester has no active bond.
+/// - "attester bond remaining duration too short" if the bond matures within the threshold.
+pub fn require_attester_bond(e: &Env, attester: &Address) {
+    let Some(threshold) = get_min_remaining(e) else {
+        return;
+    };
+
+    let Some(remaining) = attester_remaining(e, attester) else {
+        panic!("attester bond inactive");
+    };
+    if remaining < threshold {
+        panic!("attester bond remaining duration too short");
+    }
+}
@@ -11,8 +11,8 @@
 //! - Comprehensive event emission
 //! - Per-identity bond support
 
-use crate::{tiered_bond, BondTier, DataKey, IdentityBond};
-use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+use crate::{bond_gate, tiered_bond, BondKind, BondStatus, BondTier, DataKey, IdentityBond};
+use soroban_sdk::{contracttype, Address, Env, Vec};
 
 /// Parameters for creating a single bond in a batch
 #[contracttype]
@@ -73,6 +73,12 @@ pub fn validate_batch_bonds(e: &Env, params_list: &Vec<BatchBondParams>) {
         if params.is_rolling && params.notice_period_duration == 0 {
             panic!("rolling bond requires notice period");
         }
+        if params.is_rolling {
+            crate::rolling_bond::validate_duration(params.duration);
+        }
+
+        // Enforce the attestation gate, if configured
+        bond_gate::require_gate(e, &params.identity);
     }
 }
 
@@ -92,6 +98,7 @@ pub fn validate_batch_bonds(e: &Env, params_list: &Vec<BatchBondParams>) {
 /// * If validation fails for any bond
 /// * If params_list is empty
 /// * If a bond for any identity already exists
+/// * If an identity appears more than once in the batch
 ///
 /// # Events
 /// Emits `batch_bonds_created` with the result
@@ -126,13 +133,15 @@ pub fn create_batch_bonds(e: &Env, params_list: Vec<BatchBondParams>) -> BatchBo
     // Step 2: Check for existing bonds (before creating any)
     for i in 0..params_list.len() {
         let params = params_list.get(i).unwrap();
-        let bond_key = DataKey::Bond; // Note: Current implementation uses single bond
-
-        // In a multi-identity system, you'd check per-identity:
-        // let bond_key = DataKey::IdentityBond(params.identity.clone());
+        let bond_key = DataKey::Bond(params.identity.clone());
         if e.storage().instance().has(&bond_key) {
             panic!("bond already exists");
         }
+        for j in 0..i {
+            if params_list.get(j).unwrap().identity == params.identity {
+                panic!("duplicate identity in batch");
+            }
+        }
     }
 
     // Step 3: Create all bonds (atomic - all or nothing)
@@ -148,12 +157,26 @@ pub fn create_batch_bonds(e: &Env, params_list: Vec<BatchBondParams>) -> BatchBo
             active: true,
             is_rolling: params.is_rolling,
             withdrawal_requested_at: 0,
-            notice_period_duration: params.notice_period_duration,
+            notice_period: params.notice_period_duration,
+            kind: if params.is_rolling {
+                BondKind::Rolling
+            } else {
+                BondKind::Fixed
+            },
+            status: BondStatus::Active,
         };
 
+        crate::exposure_caps::reserve(
+            e,
+            crate::multi_bond::total_after_deposit(e, &params.identity, params.amount),
+            params.amount,
+        );
+
         // Store the bond
-        let bond_key = DataKey::Bond;
+        let bond_key = DataKey::Bond(params.identity.clone());
         e.storage().instance().set(&bond_key, &bond);
+        crate::closeout::record_open(e, &params.identity, params.amount);
+        crate::maturity_ladder::sync(e, &bond);
 
         // Emit tier change event for this bond
         let tier = tiered_bond::get_tier_for_amount(params.amount);
@@ -168,8 +191,7 @@ pub fn create_batch_bonds(e: &Env, params_list: Vec<BatchBondParams>) -> BatchBo
     };
 
     // Emit batch completion event
-    e.events()
-        .publish((Symbol::new(e, "batch_bonds_created"),), result.clone());
+    crate::events::publish(e, "batch_bonds_created", result.clone());
 
     result
 }
@@ -0,0 +1,109 @@
+//! Bond Creation Gate
+//!
+//! Optional per-deployment requirement that an identity already holds a valid attestation
+//! of a given category (e.g. "kyc") with at least a minimum weight before it may bond.
+//! The category is matched against the attestation payload (`attestation_data`).
+//!
+//! The gate is only evaluated when a bond is created; existing bonds are never affected, and
+//! clearing the gate restores open bonding.
+
+use soroban_sdk::{contracttype, Address, Env, String, Symbol, Vec};
+
+use crate::DataKey;
+
+const KEY_BOND_GATE: &str = "bond_gate";
+
+/// Maximum length of a Soroban symbol; longer payloads can never match a category.
+const MAX_CATEGORY_LEN: usize = 32;
+
+/// Attestation requirement that must be met before `create_bond`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondGate {
+    /// Attestation category the identity must hold (compared to `attestation_data`).
+    pub category: Symbol,
+    /// Minimum weight of the qualifying attestation.
+    pub min_weight: u32,
+}
+
+fn gate_key(e: &Env) -> Symbol {
+    Symbol::new(e, KEY_BOND_GATE)
+}
+
+/// @notice Returns the configured bond gate, if any.
+#[must_use]
+pub fn get_gate(e: &Env) -> Option<BondGate> {
+    e.storage().instance().get(&gate_key(e))
+}
+
+/// @notice Sets the bond gate (admin only; caller must enforce).
+pub fn set_gate(e: &Env, category: Symbol, min_weight: u32) {
+    let gate = BondGate {
+        category,
+        min_weight,
+    };
+    e.storage().instance().set(&gate_key(e), &gate);
+    crate::events::publish(e, "bond_gate_set", (gate.category, gate.min_weight));
+}
+
+/// @notice Removes the bond gate (admin only; caller must enforce).
+pub fn clear_gate(e: &Env) {
+    e.storage().instance().remove(&gate_key(e));
+    crate::events::publish(e, "bond_gate_cleared", ());
+}
+
+/// @notice Returns true if `identity` holds an effective attestation matching the gate.
+#[must_use]
+pub fn is_satisfied(e: &Env, gate: &BondGate, identity: &Address) -> bool {
+    let ids: Vec<u64> = e
+        .storage()
+        .instance()
+        .get(&DataKey::SubjectAttestations(identity.clone()))
+        .unwrap_or(Vec::new(e));
+    for id in ids.iter() {
+        let Some(att) = crate::attestation_store::get(e, id) else {
+            continue;
+        };
+        if !crate::valid_attestations::is_effective(&att) || att.weight < gate.min_weight {
+            continue;
+        }
+        if data_matches_category(e, &att.attestation_data, &gate.category) {
+            return true;
+        }
+    }
+    false
+}
+
+/// @notice Enforces the bond gate for a new bond, if configured.
+///
+/// # Panics
+/// Panics with "bond gate not satisfied" if no qualifying attestation exists.
+pub fn require_gate(e: &Env, identity: &Address) {
+    if let Some(gate) = get_gate(e) {
+        if !is_satisfied(e, &gate, identity) {
+            panic!("bond gate not satisfied");
+        }
+    }
+}
+
+/// Compares an attestation payload to a category symbol. Payloads that are not valid symbols
+/// (too long or containing other characters) never match.
+fn data_matches_category(e: &Env, data: &String, category: &Symbol) -> bool {
+    let len = data.len() as usize;
+    if len == 0 || len > MAX_CATEGORY_LEN {
+        return false;
+    }
+    let mut buf = [0u8; MAX_CATEGORY_LEN];
+    data.copy_into_slice(&mut buf[..len]);
+    let bytes = &buf[..len];
+    if !bytes
+        .iter()
+        .all(|b| b.is_ascii_alphanumeric() || *b == b'_')
+    {
+        return false;
+    }
+    match core::str::from_utf8(bytes) {
+        Ok(s) => Symbol::new(e, s) == *category,
+        Err(_) => false,
+    }
+}
@@ -0,0 +1,65 @@
+//! Bond Ownership Proofs
+//!
+//! Lets off-chain services check "address X holds a bond of at least Y" from a struct a relayer
+//! hands them instead of simulating a call. `get_bond_proof` returns the bond, the ledger
+//! position it was read at and a sha256 digest over the XDR encoding of
+//! `(contract, bond, context)`, the same encoding state snapshots use. A verifier recomputes the
+//! digest to detect tampering and uses the context to judge staleness, cross-checking a later
+//! light query when it needs the live state.
+//!
+//! The digest is an integrity check, not a signature: anyone can build a proof for any values.
+//! Only a proof re-read from this contract (or checked against its live state) shows the bond
+//! exists.
+
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+
+use crate::context::{self, Context};
+use crate::IdentityBond;
+
+/// @notice Snapshot of a bond with the ledger position it was read at.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondProof {
+    /// Contract holding the bond.
+    pub contract: Address,
+    pub bond: IdentityBond,
+    pub context: Context,
+    /// sha256 of the XDR encoding of `(contract, bond, context)`.
+    pub digest: BytesN<32>,
+}
+
+/// @notice Canonical digest of a proof's fields.
+#[must_use]
+pub fn digest(e: &Env, contract: &Address, bond: &IdentityBond, context: &Context) -> BytesN<32> {
+    let encoded = (contract.clone(), bond.clone(), context.clone()).to_xdr(e);
+    e.crypto().sha256(&encoded).into()
+}
+
+/// @notice Proof for `bond` at the current ledger.
+#[must_use]
+pub fn build(e: &Env, bond: IdentityBond) -> BondProof {
+    let contract = e.current_contract_address();
+    let context = context::current(e);
+    let digest = digest(e, &contract, &bond, &context);
+    BondProof {
+        contract,
+        bond,
+        context,
+        digest,
+    }
+}
+
+/// @notice True if `proof` was issued by this contract, its digest matches its fields, and it
+/// is at most `max_age` seconds old. Proofs dated in the future are rejected.
+#[must_use]
+pub fn is_fresh(e: &Env, proof: &BondProof, max_age: u64) -> bool {
+    if proof.contract != e.current_contract_address() {
+        return false;
+    }
+    if digest(e, &proof.contract, &proof.bond, &proof.context) != proof.digest {
+        return false;
+    }
+    let now = e.ledger().timestamp();
+    proof.context.timestamp <= now && now - proof.context.timestamp <= max_age
+}
@@ -0,0 +1,182 @@
+//! Bond Rewards
+//!
+//! Pays bond holders a yearly rate on their net bonded amount (`bonded_amount -
+//! slashed_amount`) out of the protocol fee pool, so long-lived and rolling bonds earn something
+//! for staying locked. Rewards accrue pro rata from `bond_start`, or from the holder's last
+//! settlement if that is later, and are paid only when claimed.
+//!
+//! Every change to the bond's `bonded_amount` or `slashed_amount` first settles: the rewards
+//! accrued on the old principal move into a stored pending balance and accrual restarts at the
+//! current ledger time. Rewards are therefore time-weighted over the principal actually bonded;
+//! a top-up just before a claim earns nothing for the time before it.
+//!
+//! A claim never pays more than the fee pool holds. Whatever the pool cannot cover is forfeited:
+//! the claim point still moves to the current ledger time. A rate of 0 (the default) disables
+//! accrual; claims then pay nothing but still move the claim point.
+
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::{token_integration, DataKey, IdentityBond};
+
+/// Highest configurable reward rate (100% per year).
+pub const MAX_REWARD_RATE_BPS: u32 = 10_000;
+
+/// Seconds in the 365-day year the reward rate is quoted against.
+pub const SECONDS_PER_YEAR: u64 = 31_536_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum RewardKey {
+    RateBps,
+    /// Ledger timestamp of the identity's last reward claim.
+    LastClaim(Address),
+    /// Ledger timestamp accrual was last settled at (claim or principal change).
+    SettledAt(Address),
+    /// Rewards settled but not yet claimed.
+    Unclaimed(Address),
+}
+
+/// @notice Yearly reward rate in basis points (0 = disabled).
+#[must_use]
+pub fn get_rate(e: &Env) -> u32 {
+    e.storage().instance().get(&RewardKey::RateBps).unwrap_or(0)
+}
+
+/// @notice Sets the yearly reward rate (admin check enforced by the caller). Emits
+/// `reward_rate_set`.
+///
+/// # Panics
+/// - "reward rate too high" above `MAX_REWARD_RATE_BPS`
+pub fn set_rate(e: &Env, rate_bps: u32) {
+    if rate_bps > MAX_REWARD_RATE_BPS {
+        panic!("reward rate too high");
+    }
+    e.storage().instance().set(&RewardKey::RateBps, &rate_bps);
+    crate::events::publish(e, "reward_rate_set", rate_bps);
+}
+
+/// @notice Timestamp of `identity`'s last reward claim (0 if never claimed).
+#[must_use]
+pub fn get_last_claim(e: &Env, identity: &Address) -> u64 {
+    e.storage()
+        .instance()
+        .get(&RewardKey::LastClaim(identity.clone()))
+        .unwrap_or(0)
+}
+
+fn unclaimed(e: &Env, identity: &Address) -> i128 {
+    e.storage()
+        .instance()
+        .get(&RewardKey::Unclaimed(identity.clone()))
+        .unwrap_or(0)
+}
+
+/// Rewards accrued on `bond`'s current principal since its start or the last settlement.
+fn accrued_since_settlement(e: &Env, bond: &IdentityBond) -> i128 {
+    let rate = get_rate(e);
+    if rate == 0 {
+        return 0;
+    }
+    let settled_at: u64 = e
+        .storage()
+        .instance()
+        .get(&RewardKey::SettledAt(bond.identity.clone()))
+        .unwrap_or(0);
+    let from = bond
+        .bond_start
+        .max(get_last_claim(e, &bond.identity))
+        .max(settled_at);
+    let elapsed = e.ledger().timestamp().saturating_sub(from);
+    let principal = bond
+        .bonded_amount
+        .saturating_sub(bond.slashed_amount)
+        .max(0);
+    principal
+        .saturating_mul(i128::from(rate))
+        .saturating_mul(i128::from(elapsed))
+        / (10_000 * i128::from(SECONDS_PER_YEAR))
+}
+
+/// @notice Rewards `bond` has accrued and not yet claimed, before capping at the fee pool.
+/// Saturates instead of overflowing.
+#[must_use]
+pub fn accrued(e: &Env, bond: &IdentityBond) -> i128 {
+    unclaimed(e, &bond.identity).saturating_add(accrued_since_settlement(e, bond))
+}
+
+/// @notice Moves the rewards `identity`'s stored bond has accrued into its pending balance and
+/// restarts accrual now. Must run before any change to the bond's `bonded_amount` or
+/// `slashed_amount`, while storage still holds the old bond.
+pub fn settle(e: &Env, identity: &Address) {
+    if let Some(bond) = e
+        .storage()
+        .instance()
+        .get::<_, IdentityBond>(&DataKey::Bond(identity.clone()))
+    {
+        let amount = accrued(e, &bond);
+        e.storage()
+            .instance()
+            .set(&RewardKey::Unclaimed(identity.clone()), &amount);
+    }
+    e.storage().instance().set(
+        &RewardKey::SettledAt(identity.clone()),
+        &e.ledger().timestamp(),
+    );
+}
+
+/// @notice Settles `from`'s bond and hands its unclaimed rewards to `to`, whose accrual starts
+/// now. Called before a bond is re-keyed to a new holder.
+pub fn move_holder(e: &Env, from: &Address, to: &Address) {
+    settle(e, from);
+    let amount = unclaimed(e, from).saturating_add(unclaimed(e, to));
+    e.storage()
+        .instance()
+        .remove(&RewardKey::Unclaimed(from.clone()));
+    e.storage()
+        .instance()
+        .set(&RewardKey::Unclaimed(to.clone()), &amount);
+    e.storage()
+        .instance()
+        .set(&RewardKey::SettledAt(to.clone()), &e.ledger().timestamp());
+}
+
+/// @notice Pays `identity` its accrued rewards, capped at the fee pool, and moves its claim
+/// point to now (holder auth and reentrancy guard are enforced by the caller). Emits
+/// `rewards_claimed` (topic: identity) with `(amount, remaining_pool)`. Returns the amount paid.
+///
+/// # Panics
+/// - "no bond"
+/// - "bond not active"
+pub fn claim(e: &Env, identity: &Address) -> i128 {
+    let bond: IdentityBond = e
+        .storage()
+        .instance()
+        .get(&DataKey::Bond(identity.clone()))
+        .unwrap_or_else(|| panic!("no bond"));
+    if !bond.active {
+        panic!("bond not active");
+    }
+
+    let pool = crate::fees::pool_balance(e);
+    let amount = accrued(e, &bond).min(pool);
+    let remaining = pool - amount;
+    let now = e.ledger().timestamp();
+    e.storage()
+        .instance()
+        .set(&RewardKey::LastClaim(identity.clone()), &now);
+    e.storage()
+        .instance()
+        .set(&RewardKey::SettledAt(identity.clone()), &now);
+    e.storage()
+        .instance()
+        .remove(&RewardKey::Unclaimed(identity.clone()));
+    if amount > 0 {
+        e.storage()
+            .instance()
+            .set(&Symbol::new(e, "fees"), &remaining);
+        token_integration::transfer_from_contract(e, identity, amount);
+        crate::journal::append(e, identity, "rewards_claimed", amount, 0);
+    }
+    crate::events::publish_for(e, "rewards_claimed", identity.clone(), (amount, remaining));
+    amount
+}
@@ -0,0 +1,134 @@
+//! Bond Tranches
+//!
+//! `split_bond` carves part of an identity's bond (bond 0) into an additional bond with its own
+//! duration and rolling setting, so a holder can commit part of the stake for longer without
+//! withdrawing and re-bonding. The tranche is stored through `multi_bond` and starts now.
+//!
+//! The slashed amount moves pro rata: the tranche takes `slashed * amount / bonded`, rounded
+//! down, and the source keeps the rounding remainder. Both pieces must hold at least
+//! `validation::MIN_BOND_AMOUNT`.
+//!
+//! A split can never unlock stake earlier than the source would have: a fixed tranche must end
+//! no earlier than the source's lock-up (or its notice period, for rolling and perpetual
+//! sources), and a rolling tranche's notice period must reach that point as well. The source's
+//! close-out counters are not touched; the carved amount is neither a deposit nor a withdrawal.
+
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    collateral, governance_approval, math, maturity_ladder, multi_bond, perpetual_bond,
+    tiered_bond, validation, BondKind, BondStatus, DataKey, IdentityBond,
+};
+
+/// Earliest time funds could leave `bond` if its holder started exiting now.
+fn earliest_exit(now: u64, bond: &IdentityBond) -> u64 {
+    if bond.is_rolling || perpetual_bond::is_perpetual(bond) {
+        now.saturating_add(bond.notice_period)
+    } else {
+        bond.bond_start.saturating_add(bond.bond_duration)
+    }
+}
+
+/// @notice Moves `amount` of `identity`'s bond into a new tranche and returns the tranche's
+/// bond id. Emits `bond_split` (topic: identity) with
+/// `(bond_id, amount, moved_slashed, new_duration, is_rolling)`.
+///
+/// # Panics
+/// - "no bond", "bond not active", "withdrawal requested"
+/// - "split amount below minimum", "remaining bond below minimum"
+/// - "tranche would unlock before source bond"
+/// - "open slash request", "cooldown withdrawal pending", "insufficient balance for split"
+pub fn split(
+    e: &Env,
+    identity: &Address,
+    amount: i128,
+    new_duration: u64,
+    is_rolling: bool,
+    notice_period: u64,
+) -> u64 {
+    let key = DataKey::Bond(identity.clone());
+    let mut source: IdentityBond = e
+        .storage()
+        .instance()
+        .get(&key)
+        .unwrap_or_else(|| panic!("no bond"));
+    match source.status {
+        BondStatus::Active | BondStatus::Matured => {}
+        BondStatus::NoticeServing => panic!("withdrawal requested"),
+        _ => panic!("bond not active"),
+    }
+    if amount < validation::MIN_BOND_AMOUNT {
+        panic!("split amount below minimum");
+    }
+    let remaining = source
+        .bonded_amount
+        .checked_sub(amount)
+        .filter(|r| *r >= validation::MIN_BOND_AMOUNT)
+        .unwrap_or_else(|| panic!("remaining bond below minimum"));
+    validation::validate_bond_duration(new_duration);
+
+    let now = e.ledger().timestamp();
+    let (tranche_exit, tranche_notice) = if is_rolling {
+        (now.saturating_add(notice_period), notice_period)
+    } else {
+        (now.saturating_add(new_duration), 0)
+    };
+    if tranche_exit < earliest_exit(now, &source) {
+        panic!("tranche would unlock before source bond");
+    }
+    if governance_approval::has_open_proposal(e, identity) {
+        panic!("open slash request");
+    }
+    if e.storage()
+        .instance()
+        .has(&DataKey::CooldownReq(identity.clone()))
+    {
+        panic!("cooldown withdrawal pending");
+    }
+
+    let moved_slashed = math::div_i128(
+        math::mul_i128(source.slashed_amount, amount, "split proration overflow"),
+        source.bonded_amount,
+        "split proration div-by-zero",
+    );
+    if amount - moved_slashed > collateral::free_balance(e, &source) {
+        panic!("insufficient balance for split");
+    }
+
+    let old_tier = tiered_bond::get_tier_for_amount(source.bonded_amount);
+    crate::bond_rewards::settle(e, identity);
+    source.bonded_amount = remaining;
+    source.slashed_amount -= moved_slashed;
+    e.storage().instance().set(&key, &source);
+    maturity_ladder::sync(e, &source);
+
+    let tranche = IdentityBond {
+        identity: identity.clone(),
+        bonded_amount: amount,
+        bond_start: now,
+        bond_duration: new_duration,
+        slashed_amount: moved_slashed,
+        active: true,
+        is_rolling,
+        withdrawal_requested_at: 0,
+        notice_period: tranche_notice,
+        kind: if is_rolling {
+            BondKind::Rolling
+        } else {
+            BondKind::Fixed
+        },
+        status: BondStatus::Active,
+    };
+    let bond_id = multi_bond::insert(e, identity, &tranche);
+
+    let new_tier = tiered_bond::get_tier_for_amount(source.bonded_amount);
+    tiered_bond::emit_tier_change_if_needed(e, identity, old_tier, new_tier);
+    crate::events::publish_for(
+        e,
+        "bond_split",
+        identity.clone(),
+        (bond_id, amount, moved_slashed, new_duration, is_rolling),
+    );
+    crate::journal::append(e, identity, "bond_split", amount, bond_id);
+    bond_id
+}
@@ -0,0 +1,92 @@
+//! Bond Lifecycle Status
+//!
+//! Every bond stores a `BondStatus` next to the older `active` / `is_rolling` /
+//! `withdrawal_requested_at` fields. Mutating paths change it only through `transition`, which
+//! rejects edges missing from the table in `next` with "invalid bond transition", keeps `active`
+//! in sync (`false` exactly when `Closed`) and emits `bond_status_changed` (topic: identity)
+//! with `(old, new)` whenever the status changes.
+//!
+//! - `Active` → `NoticeServing` (`request_withdrawal`) → `Active` again on cancellation or
+//!   renewal.
+//! - `Active` / `NoticeServing` → `Matured` once a withdrawal after lock-up or notice goes
+//!   through, or a rolling bond lapses. `extend_duration` and renewals bring it back to `Active`.
+//! - `Quarantined` and `Frozen` are entered from any open status and only leave back to
+//!   `Active`. `freeze_bond` / `unfreeze_bond` drive `Frozen`; no entrypoint quarantines a bond
+//!   yet.
+//! - `Closed` is terminal. Reactivating writes a new bond that starts `Active`.
+//!
+//! `withdraw_early`, `emergency_withdraw` and the `slash_bond` entrypoint close a bond they
+//! leave with nothing available (`bonded_amount - slashed_amount == 0`); a frozen or quarantined
+//! bond keeps that status until released. A bond fully slashed through any other path keeps its
+//! status (and `active = true`) until it is withdrawn from or closed, as before.
+
+use soroban_sdk::Env;
+
+use crate::{BondStatus, IdentityBond};
+
+/// Lifecycle events that move a bond between statuses.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BondTransition {
+    /// Holder requested withdrawal of a rolling or perpetual bond.
+    ServeNotice,
+    /// Pending withdrawal request withdrawn.
+    CancelNotice,
+    /// Withdrawn from after lock-up or notice, or a rolling bond lapsed.
+    Mature,
+    /// New lock-up period: rolling renewal or duration extension.
+    Renew,
+    #[allow(dead_code)]
+    Quarantine,
+    #[allow(dead_code)]
+    Release,
+    /// Admin froze the bond during an investigation.
+    Freeze,
+    /// Admin lifted the freeze.
+    Unfreeze,
+    /// Nothing left to withdraw, or the bond was closed.
+    Close,
+}
+
+/// @notice True for the statuses a bond can mature, renew, freeze or close from.
+#[must_use]
+pub fn is_open(status: &BondStatus) -> bool {
+    matches!(
+        status,
+        BondStatus::Active | BondStatus::NoticeServing | BondStatus::Matured
+    )
+}
+
+/// @notice Status reached from `from` by `transition`, or `None` if the edge is illegal.
+#[must_use]
+pub fn next(from: &BondStatus, transition: BondTransition) -> Option<BondStatus> {
+    use BondStatus as S;
+    use BondTransition as T;
+
+    let open = is_open(from);
+    let to = match (from, transition) {
+        (S::Active, T::ServeNotice) => S::NoticeServing,
+        (S::NoticeServing | S::Matured, T::CancelNotice) => S::Active,
+        (_, T::Mature) if open => S::Matured,
+        (_, T::Renew) if open => S::Active,
+        (_, T::Quarantine) if open => S::Quarantined,
+        (_, T::Freeze) if open => S::Frozen,
+        (S::Quarantined, T::Release) | (S::Frozen, T::Unfreeze) => S::Active,
+        (_, T::Close) if open => S::Closed,
+        _ => return None,
+    };
+    Some(to)
+}
+
+/// @notice Applies `transition` to `bond` (callers persist it).
+///
+/// # Panics
+/// - "invalid bond transition" if the edge is not in the table.
+pub fn transition(e: &Env, bond: &mut IdentityBond, transition: BondTransition) {
+    let old = bond.status.clone();
+    let new = next(&old, transition).unwrap_or_else(|| panic!("invalid bond transition"));
+    bond.active = new != BondStatus::Closed;
+    if new != old {
+        bond.status = new.clone();
+        crate::events::publish_for(e, "bond_status_changed", bond.identity.clone(), (old, new));
+    }
+}
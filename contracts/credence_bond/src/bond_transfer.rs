@@ -0,0 +1,140 @@
+//! Bond Ownership Transfer
+//!
+//! Moves a bond to a new identity address (key rotation) without withdrawing it, so the holder
+//! keeps the bonded and slashed amounts, `bond_start` and tier. The attestation index can move
+//! with the bond: each attestation's subject is rewritten together with the per-subject count,
+//! sequence numbers and duplicate markers, so the new address reads exactly like the old one.
+//!
+//! Transfers are refused while anything else is keyed to the old address: an open slash request,
+//! a pending cooldown withdrawal or collateral held against its attestations. A frozen bond
+//! (see `bond_freeze`) cannot be transferred, and the global pause blocks all transfers.
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    attestation_privacy, collateral, governance_approval, maturity_ladder, subject_seq, DataKey,
+    IdentityBond,
+};
+
+/// @notice Moves `from`'s bond to `to` (auth from both is enforced by the caller). With
+/// `move_attestations`, every attestation issued for `from` is re-keyed to `to` as well.
+/// Emits `bond_transferred` (topic: `from`) with `(to, bonded_amount, moved_attestations)`.
+///
+/// # Panics
+/// - "cannot transfer to self", "no bond", "bond not active", "bond frozen"
+/// - "new identity already bonded"
+/// - "new identity has attestations" (only with `move_attestations`)
+/// - "open slash request", "cooldown withdrawal pending"
+/// - "bond backs collateralized attestations"
+pub fn transfer(e: &Env, from: &Address, to: &Address, move_attestations: bool) -> IdentityBond {
+    if from == to {
+        panic!("cannot transfer to self");
+    }
+    let from_key = DataKey::Bond(from.clone());
+    let mut bond: IdentityBond = e
+        .storage()
+        .instance()
+        .get(&from_key)
+        .unwrap_or_else(|| panic!("no bond"));
+    if !bond.active {
+        panic!("bond not active");
+    }
+    crate::bond_freeze::require_not_frozen(&bond);
+    let to_key = DataKey::Bond(to.clone());
+    if e.storage().instance().has(&to_key) {
+        panic!("new identity already bonded");
+    }
+    if governance_approval::has_open_proposal(e, from) {
+        panic!("open slash request");
+    }
+    if e.storage()
+        .instance()
+        .has(&DataKey::CooldownReq(from.clone()))
+    {
+        panic!("cooldown withdrawal pending");
+    }
+    if collateral::get_exposure(e, from) > 0 {
+        panic!("bond backs collateralized attestations");
+    }
+
+    // Drop the old holder's ladder position before the bond is re-keyed.
+    let mut retired = bond.clone();
+    retired.active = false;
+    maturity_ladder::sync(e, &retired);
+
+    crate::bond_rewards::move_holder(e, from, to);
+    bond.identity = to.clone();
+    e.storage().instance().remove(&from_key);
+    e.storage().instance().set(&to_key, &bond);
+    maturity_ladder::sync(e, &bond);
+
+    let moved = if move_attestations {
+        move_attestation_index(e, from, to)
+    } else {
+        0
+    };
+    crate::events::publish_for(
+        e,
+        "bond_transferred",
+        from.clone(),
+        (to.clone(), bond.bonded_amount, moved),
+    );
+    crate::journal::append(e, from, "bond_transferred", bond.bonded_amount, 0);
+    crate::journal::append(e, to, "bond_transferred", bond.bonded_amount, 0);
+    bond
+}
+
+/// Re-keys every attestation issued for `from` to `to` and returns how many moved.
+fn move_attestation_index(e: &Env, from: &Address, to: &Address) -> u32 {
+    let from_list = DataKey::SubjectAttestations(from.clone());
+    let to_list = DataKey::SubjectAttestations(to.clone());
+    if e.storage().instance().has(&to_list) || subject_seq::issued(e, to) > 0 {
+        panic!("new identity has attestations");
+    }
+    let ids: Vec<u64> = e
+        .storage()
+        .instance()
+        .get(&from_list)
+        .unwrap_or_else(|| Vec::new(e));
+
+    for id in ids.iter() {
+        let Some(mut att) = crate::attestation_store::get(e, id) else {
+            continue;
+        };
+        match &att.data_hash {
+            Some(hash) => attestation_privacy::move_hash_seen(e, &att.verifier, from, to, hash),
+            None => {
+                let old = DataKey::DuplicateCheck(
+                    att.verifier.clone(),
+                    from.clone(),
+                    att.attestation_data.clone(),
+                );
+                if e.storage().instance().has(&old) {
+                    e.storage().instance().remove(&old);
+                    let new = DataKey::DuplicateCheck(
+                        att.verifier.clone(),
+                        to.clone(),
+                        att.attestation_data.clone(),
+                    );
+                    e.storage().instance().set(&new, &true);
+                }
+            }
+        }
+        att.identity = to.clone();
+        crate::attestation_store::put(e, &att);
+    }
+    subject_seq::reassign(e, from, to);
+
+    if !ids.is_empty() {
+        e.storage().instance().remove(&from_list);
+        e.storage().instance().set(&to_list, &ids);
+    }
+    let from_count = DataKey::SubjectAttestationCount(from.clone());
+    if let Some(count) = e.storage().instance().get::<_, u32>(&from_count) {
+        e.storage().instance().remove(&from_count);
+        e.storage()
+            .instance()
+            .set(&DataKey::SubjectAttestationCount(to.clone()), &count);
+    }
+    ids.len()
+}
@@ -0,0 +1,213 @@
+//! Bond Close-out Statements
+//!
+//! When a bond winds down (fully withdrawn, withdrawn after maturity, fully slashed, or closed
+//! by its owner with `close_bond`) an immutable `BondCloseout` statement is written under `DataKey::Closeout(identity, epoch)`.
+//! The epoch starts at 0 and increments each time the identity closes a bond, so re-bonding
+//! produces a new statement instead of overwriting the previous one.
+//!
+//! Lifetime totals are tracked in running counters that are opened on bond creation, updated
+//! on top-ups and withdrawals, and cleared once the statement is written.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::{DataKey, IdentityBond};
+
+/// Why a bond reached its terminal state.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CloseReason {
+    /// Fully withdrawn before the lock-up ended (early exit or full withdrawal).
+    Withdrawn,
+    /// Fully withdrawn at or after the end of the lock-up.
+    Matured,
+    /// Slashing consumed the entire remaining balance.
+    Slashed,
+    /// Closed by the owner with `close_bond` before any other terminal transition.
+    Voluntary,
+}
+
+/// Final statement for a closed bond.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondCloseout {
+    pub identity: Address,
+    /// Initial deposit plus all top-ups.
+    pub total_bonded_lifetime: i128,
+    pub total_slashed: i128,
+    /// Gross amount withdrawn from the bond (including any early-exit penalties).
+    pub total_withdrawn: i128,
+    /// Early-exit penalties paid out of withdrawals.
+    pub total_penalties_paid: i128,
+    pub opened_at: u64,
+    pub closed_at: u64,
+    pub close_reason: CloseReason,
+}
+
+/// Running counters for the currently open bond of an identity.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct BondTotals {
+    opened_at: u64,
+    total_bonded_lifetime: i128,
+    total_withdrawn: i128,
+    total_penalties_paid: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum CloseoutKey {
+    /// Running totals of the open bond.
+    Totals(Address),
+    /// Number of close-outs written (next epoch).
+    Epoch(Address),
+}
+
+fn get_totals(e: &Env, identity: &Address) -> Option<BondTotals> {
+    e.storage()
+        .instance()
+        .get(&CloseoutKey::Totals(identity.clone()))
+}
+
+fn put_totals(e: &Env, identity: &Address, totals: &BondTotals) {
+    e.storage()
+        .instance()
+        .set(&CloseoutKey::Totals(identity.clone()), totals);
+}
+
+/// @notice Returns true if `identity` has an open bond record or any close-out statement.
+#[must_use]
+pub fn has_history(e: &Env, identity: &Address) -> bool {
+    get_totals(e, identity).is_some() || get_epoch(e, identity) > 0
+}
+
+/// @notice Returns the number of close-out statements written for `identity`.
+#[must_use]
+pub fn get_epoch(e: &Env, identity: &Address) -> u32 {
+    e.storage()
+        .instance()
+        .get(&CloseoutKey::Epoch(identity.clone()))
+        .unwrap_or(0)
+}
+
+/// @notice When the open bond of `identity` was created. Top-ups and rolling renewals do not
+/// change it. `None` if no bond is open or it predates close-out tracking.
+#[must_use]
+pub fn get_opened_at(e: &Env, identity: &Address) -> Option<u64> {
+    get_totals(e, identity).map(|t| t.opened_at)
+}
+
+/// @notice Starts running totals for a newly created bond.
+pub fn record_open(e: &Env, identity: &Address, amount: i128) {
+    let totals = BondTotals {
+        opened_at: e.ledger().timestamp(),
+        total_bonded_lifetime: amount,
+        total_withdrawn: 0,
+        total_penalties_paid: 0,
+    };
+    put_totals(e, identity, &totals);
+}
+
+/// @notice Adds a top-up to the lifetime bonded total.
+pub fn record_deposit(e: &Env, identity: &Address, amount: i128) {
+    if let Some(mut totals) = get_totals(e, identity) {
+        totals.total_bonded_lifetime = totals
+            .total_bonded_lifetime
+            .checked_add(amount)
+            .expect("lifetime bonded overflow");
+        put_totals(e, identity, &totals);
+    }
+}
+
+/// @notice Adds a withdrawal (gross amount and any penalty) to the running totals.
+pub fn record_withdrawal(e: &Env, identity: &Address, amount: i128, penalty: i128) {
+    if let Some(mut totals) = get_totals(e, identity) {
+        totals.total_withdrawn = totals
+            .total_withdrawn
+            .checked_add(amount)
+            .expect("lifetime withdrawn overflow");
+        totals.total_penalties_paid = totals
+            .total_penalties_paid
+            .checked_add(penalty)
+            .expect("lifetime penalties overflow");
+        put_totals(e, identity, &totals);
+    }
+}
+
+/// @notice True once `bond` is inactive or nothing remains after withdrawals and slashing.
+#[must_use]
+pub fn is_terminal(bond: &IdentityBond) -> bool {
+    !bond.active || bond.bonded_amount <= bond.slashed_amount
+}
+
+/// @notice Writes the close-out statement if `bond` has reached a terminal state.
+/// @dev See `is_terminal`. `by_slash` marks the transition as caused by slashing. No-op if the
+/// bond was already closed.
+pub fn close_if_terminal(e: &Env, bond: &IdentityBond, by_slash: bool) {
+    if !is_terminal(bond) {
+        return;
+    }
+    let now = e.ledger().timestamp();
+    let close_reason = if by_slash {
+        CloseReason::Slashed
+    } else if now >= bond.bond_start.saturating_add(bond.bond_duration) {
+        CloseReason::Matured
+    } else {
+        CloseReason::Withdrawn
+    };
+    write_closeout(e, bond, close_reason);
+}
+
+/// @notice Writes a `Voluntary` close-out statement for `bond` if none was written yet.
+/// Returns true if a statement was written.
+pub fn close_voluntarily(e: &Env, bond: &IdentityBond) -> bool {
+    write_closeout(e, bond, CloseReason::Voluntary)
+}
+
+fn write_closeout(e: &Env, bond: &IdentityBond, close_reason: CloseReason) -> bool {
+    let Some(totals) = get_totals(e, &bond.identity) else {
+        return false;
+    };
+    let now = e.ledger().timestamp();
+    let closeout = BondCloseout {
+        identity: bond.identity.clone(),
+        total_bonded_lifetime: totals.total_bonded_lifetime,
+        total_slashed: bond.slashed_amount,
+        total_withdrawn: totals.total_withdrawn,
+        total_penalties_paid: totals.total_penalties_paid,
+        opened_at: totals.opened_at,
+        closed_at: now,
+        close_reason,
+    };
+
+    let epoch = get_epoch(e, &bond.identity);
+    e.storage()
+        .instance()
+        .set(&DataKey::Closeout(bond.identity.clone(), epoch), &closeout);
+    let next_epoch = epoch.checked_add(1).expect("closeout epoch overflow");
+    e.storage()
+        .instance()
+        .set(&CloseoutKey::Epoch(bond.identity.clone()), &next_epoch);
+    e.storage()
+        .instance()
+        .remove(&CloseoutKey::Totals(bond.identity.clone()));
+
+    crate::events::publish_for(e, "bond_closed", bond.identity.clone(), closeout);
+    crate::journal::append(e, &bond.identity, "bond_closed", 0, epoch.into());
+    true
+}
+
+/// @notice Returns all close-out statements for `identity`, oldest first.
+#[must_use]
+pub fn get_closeouts(e: &Env, identity: &Address) -> Vec<BondCloseout> {
+    let mut out = Vec::new(e);
+    for epoch in 0..get_epoch(e, identity) {
+        if let Some(c) = e
+            .storage()
+            .instance()
+            .get(&DataKey::Closeout(identity.clone(), epoch))
+        {
+            out.push_back(c);
+        }
+    }
+    out
+}
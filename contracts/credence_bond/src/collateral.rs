@@ -0,0 +1,157 @@
+//! Collateralized Attestations
+//!
+//! An attester can back a claim with part of their own bond ("I vouch up to N tokens"). The
+//! backing amount is recorded against the attestation and held on the attester's bond: held
+//! funds are excluded from the withdrawable balance until the attestation is revoked. A
+//! governance slash request that references the attestation draws from that held amount first.
+//!
+//! Attestations carry no expiry in this contract, so revocation is the only release path besides
+//! a slash consuming the hold.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::IdentityBond;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum CollateralKey {
+    /// Amount still held for an attestation.
+    Backing(u64),
+    /// Total held across all of an attester's attestations.
+    Exposure(Address),
+    /// Attestation a slash proposal was raised against.
+    ProposalAttestation(u64),
+}
+
+/// @notice Amount currently held to back `attestation_id` (0 if uncollateralized).
+#[must_use]
+pub fn get_backing(e: &Env, attestation_id: u64) -> i128 {
+    e.storage()
+        .instance()
+        .get(&CollateralKey::Backing(attestation_id))
+        .unwrap_or(0)
+}
+
+/// @notice Total backing held on `attester`'s bond.
+#[must_use]
+pub fn get_exposure(e: &Env, attester: &Address) -> i128 {
+    e.storage()
+        .instance()
+        .get(&CollateralKey::Exposure(attester.clone()))
+        .unwrap_or(0)
+}
+
+fn set_exposure(e: &Env, attester: &Address, amount: i128) {
+    let key = CollateralKey::Exposure(attester.clone());
+    if amount == 0 {
+        e.storage().instance().remove(&key);
+    } else {
+        e.storage().instance().set(&key, &amount);
+    }
+}
+
+/// @notice Bond balance not slashed and not held as attestation backing.
+#[must_use]
+pub fn free_balance(e: &Env, bond: &IdentityBond) -> i128 {
+    bond.bonded_amount
+        .checked_sub(bond.slashed_amount)
+        .expect("slashed amount exceeds bonded amount")
+        .saturating_sub(get_exposure(e, &bond.identity))
+        .max(0)
+}
+
+/// @notice Places a hold of `amount` on `bond` to back `attestation_id`.
+///
+/// # Panics
+/// - "backing amount must be positive" if `amount <= 0`.
+/// - "insufficient bond for backing" if the bond's free balance cannot cover `amount`.
+pub fn place_hold(e: &Env, bond: &IdentityBond, attestation_id: u64, amount: i128) {
+    if amount <= 0 {
+        panic!("backing amount must be positive");
+    }
+    if amount > free_balance(e, bond) {
+        panic!("insufficient bond for backing");
+    }
+    e.storage()
+        .instance()
+        .set(&CollateralKey::Backing(attestation_id), &amount);
+    let exposure = get_exposure(e, &bond.identity)
+        .checked_add(amount)
+        .expect("backing exposure overflow");
+    set_exposure(e, &bond.identity, exposure);
+    crate::events::publish_for(
+        e,
+        "backing_held",
+        bond.identity.clone(),
+        (attestation_id, amount, exposure),
+    );
+}
+
+/// @notice Releases whatever is still held for `attestation_id` (no-op if nothing is held).
+pub fn release_hold(e: &Env, attester: &Address, attestation_id: u64) {
+    let held = get_backing(e, attestation_id);
+    if held == 0 {
+        return;
+    }
+    e.storage()
+        .instance()
+        .remove(&CollateralKey::Backing(attestation_id));
+    let exposure = get_exposure(e, attester).saturating_sub(held).max(0);
+    set_exposure(e, attester, exposure);
+    crate::events::publish_for(
+        e,
+        "backing_released",
+        attester.clone(),
+        (attestation_id, held, exposure),
+    );
+}
+
+/// @notice Links slash proposal `proposal_id` to the attestation it targets.
+pub fn link_proposal(e: &Env, proposal_id: u64, attestation_id: u64) {
+    e.storage().instance().set(
+        &CollateralKey::ProposalAttestation(proposal_id),
+        &attestation_id,
+    );
+}
+
+/// @notice Attestation referenced by slash proposal `proposal_id`, if any.
+#[must_use]
+pub fn get_proposal_attestation(e: &Env, proposal_id: u64) -> Option<u64> {
+    e.storage()
+        .instance()
+        .get(&CollateralKey::ProposalAttestation(proposal_id))
+}
+
+/// @notice Draws an executed slash of `amount` from the backing of the attestation linked to
+/// `proposal_id`. The consumed part of the hold is dropped (those funds are now slashed); any
+/// remainder of the slash falls on the attester's free balance as usual.
+/// @return Amount taken from the held backing.
+pub fn consume_for_slash(e: &Env, attester: &Address, proposal_id: u64, amount: i128) -> i128 {
+    let Some(attestation_id) = get_proposal_attestation(e, proposal_id) else {
+        return 0;
+    };
+    let held = get_backing(e, attestation_id);
+    let consumed = amount.min(held).max(0);
+    if consumed == 0 {
+        return 0;
+    }
+    let remaining = held - consumed;
+    if remaining == 0 {
+        e.storage()
+            .instance()
+            .remove(&CollateralKey::Backing(attestation_id));
+    } else {
+        e.storage()
+            .instance()
+            .set(&CollateralKey::Backing(attestation_id), &remaining);
+    }
+    let exposure = get_exposure(e, attester).saturating_sub(consumed).max(0);
+    set_exposure(e, attester, exposure);
+    crate::events::publish_for(
+        e,
+        "backing_slashed",
+        attester.clone(),
+        (attestation_id, consumed, remaining),
+    );
+    consumed
+}
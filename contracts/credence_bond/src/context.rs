@@ -0,0 +1,24 @@
+//! Ledger Context
+//!
+//! Describe views and bond lifecycle events carry the ledger sequence and timestamp they were
+//! produced at, so off-chain caches can order results from different calls and judge how
+//! stale a cached view is.
+
+use soroban_sdk::{contracttype, Env};
+
+/// @notice Ledger position a view or event was produced at.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Context {
+    pub ledger_seq: u32,
+    pub timestamp: u64,
+}
+
+/// @notice Context of the current ledger.
+#[must_use]
+pub fn current(e: &Env) -> Context {
+    Context {
+        ledger_seq: e.ledger().sequence(),
+        timestamp: e.ledger().timestamp(),
+    }
+}
@@ -55,30 +55,20 @@ pub fn can_withdraw(now: u64, request_time: u64, cooldown_period: u64) -> bool {
 
 /// Emit an event when a cooldown withdrawal is requested.
 pub fn emit_cooldown_requested(e: &Env, requester: &Address, amount: i128) {
-    e.events().publish(
-        (Symbol::new(e, "cooldown_requested"),),
-        (requester.clone(), amount),
-    );
+    crate::events::publish(e, "cooldown_requested", (requester.clone(), amount));
 }
 
 /// Emit an event when a cooldown withdrawal is executed.
 pub fn emit_cooldown_executed(e: &Env, requester: &Address, amount: i128) {
-    e.events().publish(
-        (Symbol::new(e, "cooldown_executed"),),
-        (requester.clone(), amount),
-    );
+    crate::events::publish(e, "cooldown_executed", (requester.clone(), amount));
 }
 
 /// Emit an event when a cooldown withdrawal is cancelled.
 pub fn emit_cooldown_cancelled(e: &Env, requester: &Address) {
-    e.events()
-        .publish((Symbol::new(e, "cooldown_cancelled"),), requester.clone());
+    crate::events::publish(e, "cooldown_cancelled", requester.clone());
 }
 
 /// Emit an event when the cooldown period is updated by the admin.
 pub fn emit_cooldown_period_updated(e: &Env, old_period: u64, new_period: u64) {
-    e.events().publish(
-        (Symbol::new(e, "cooldown_period_updated"),),
-        (old_period, new_period),
-    );
+    crate::events::publish(e, "cooldown_period_updated", (old_period, new_period));
 }
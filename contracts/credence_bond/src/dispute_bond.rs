@@ -0,0 +1,196 @@
+//! Stake-backed Slash Disputes
+//!
+//! Governance members may dispute an open slash request (governance slash proposal). When a
+//! dispute bond is configured the disputer escrows that amount of the bond token; the escrow is
+//! tracked per (request, disputer) and settled once the request is resolved:
+//! - request executed (slash upheld): the escrow is forfeited to the fee pool;
+//! - request rejected: each escrow is refunded, and one reward of `reward_bps` of the would-be
+//!   slash amount is paid out of the fee pool, split equally between the disputers. The reward
+//!   is capped at the pool balance; rounding remainders stay in the pool;
+//! - request closed as expired: each escrow is refunded without a reward.
+//!
+//! A member can dispute a given request at most once.
+
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+use crate::governance_approval::{self, ProposalStatus};
+use crate::{math, token_integration};
+
+const KEY_DISPUTE_BOND: &str = "disp_bond";
+const KEY_DISPUTE_REWARD_BPS: &str = "disp_rwd_bps";
+
+/// Max reward in basis points (100% of the would-be slash amount).
+const MAX_REWARD_BPS: u32 = 10_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum DisputeKey {
+    /// Escrowed amount for (request_id, disputer).
+    Escrow(u64, Address),
+    /// Disputers of a request, in filing order.
+    Disputers(u64),
+}
+
+/// @notice Returns (dispute bond amount, reward bps). Both default to zero (free disputes).
+#[must_use]
+pub fn get_config(e: &Env) -> (i128, u32) {
+    let amount: i128 = e
+        .storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_DISPUTE_BOND))
+        .unwrap_or(0);
+    let reward_bps: u32 = e
+        .storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_DISPUTE_REWARD_BPS))
+        .unwrap_or(0);
+    (amount, reward_bps)
+}
+
+/// @notice Sets the dispute bond amount and refund reward (admin only; caller must enforce).
+pub fn set_config(e: &Env, amount: i128, reward_bps: u32) {
+    if amount < 0 {
+        panic!("dispute bond must be non-negative");
+    }
+    if reward_bps > MAX_REWARD_BPS {
+        panic!("reward_bps must be <= 10000");
+    }
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_DISPUTE_BOND), &amount);
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_DISPUTE_REWARD_BPS), &reward_bps);
+}
+
+/// @notice Returns the escrow held for `disputer` on `request_id`, if they disputed it.
+#[must_use]
+pub fn get_escrow(e: &Env, request_id: u64, disputer: &Address) -> Option<i128> {
+    e.storage()
+        .instance()
+        .get(&DisputeKey::Escrow(request_id, disputer.clone()))
+}
+
+/// @notice Returns the disputers of `request_id` whose escrow is still unsettled.
+#[must_use]
+pub fn get_disputers(e: &Env, request_id: u64) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&DisputeKey::Disputers(request_id))
+        .unwrap_or(Vec::new(e))
+}
+
+/// @notice Files a dispute and escrows the configured dispute bond. Returns the escrowed amount.
+/// @dev Effects are written before the token transfer (CEI); caller must hold the reentrancy lock.
+///
+/// # Panics
+/// "not a governor", "proposal not found", "proposal not open for dispute", "already disputed".
+pub fn file_dispute(e: &Env, disputer: &Address, request_id: u64) -> i128 {
+    if !governance_approval::is_active_member(e, disputer) {
+        panic!("not a governor");
+    }
+    let proposal = governance_approval::get_proposal(e, request_id)
+        .unwrap_or_else(|| panic!("proposal not found"));
+    if proposal.status != ProposalStatus::Open {
+        panic!("proposal not open for dispute");
+    }
+    let escrow_key = DisputeKey::Escrow(request_id, disputer.clone());
+    if e.storage().instance().has(&escrow_key) {
+        panic!("already disputed");
+    }
+
+    let (amount, _) = get_config(e);
+    e.storage().instance().set(&escrow_key, &amount);
+    let mut disputers = get_disputers(e, request_id);
+    disputers.push_back(disputer.clone());
+    e.storage()
+        .instance()
+        .set(&DisputeKey::Disputers(request_id), &disputers);
+    crate::slash_pipeline::record_disputed(e, request_id);
+
+    token_integration::transfer_into_contract(e, disputer, amount);
+    crate::events::publish_for(
+        e,
+        "dispute_escrowed",
+        request_id,
+        (disputer.clone(), amount),
+    );
+    amount
+}
+
+/// @notice Forfeits every escrow on an executed request to the fee pool.
+pub fn forfeit_all(e: &Env, request_id: u64) {
+    let disputers = get_disputers(e, request_id);
+    if !disputers.is_empty() {
+        crate::slash_pipeline::record_resolved(e, request_id);
+    }
+    let mut total: i128 = 0;
+    for disputer in disputers.iter() {
+        let amount = take_escrow(e, request_id, &disputer);
+        total = total.checked_add(amount).expect("dispute escrow overflow");
+        crate::events::publish_for(
+            e,
+            "dispute_escrow_forfeited",
+            request_id,
+            (disputer, amount),
+        );
+    }
+    e.storage()
+        .instance()
+        .remove(&DisputeKey::Disputers(request_id));
+    crate::fees::add_to_pool(e, total);
+}
+
+/// @notice Refunds every escrow on a rejected request and splits the configured reward, funded
+/// by the fee pool, between the disputers.
+/// @dev State (escrows and the fee pool) is updated before any transfer (CEI); caller must hold
+/// the lock.
+pub fn refund_all(e: &Env, request_id: u64, slash_amount: i128) {
+    let disputers = get_disputers(e, request_id);
+    if disputers.is_empty() {
+        return;
+    }
+    crate::slash_pipeline::record_resolved(e, request_id);
+
+    let (_, reward_bps) = get_config(e);
+    let reward_total = if reward_bps == 0 {
+        0
+    } else {
+        math::bps(
+            slash_amount,
+            reward_bps,
+            "dispute reward overflow",
+            "dispute reward div-by-zero",
+        )
+    };
+    let count = i128::from(disputers.len());
+    let share = reward_total.min(crate::fees::pool_balance(e)) / count;
+    crate::fees::take_from_pool(e, share * count);
+
+    let mut payouts: Vec<(Address, i128)> = Vec::new(e);
+    for disputer in disputers.iter() {
+        let amount = take_escrow(e, request_id, &disputer);
+        let payout = amount.checked_add(share).expect("dispute refund overflow");
+        payouts.push_back((disputer, payout));
+    }
+    e.storage()
+        .instance()
+        .remove(&DisputeKey::Disputers(request_id));
+
+    for (disputer, payout) in payouts.iter() {
+        token_integration::transfer_from_contract(e, &disputer, payout);
+        crate::events::publish_for(
+            e,
+            "dispute_escrow_refunded",
+            request_id,
+            (disputer, payout, share),
+        );
+    }
+}
+
+fn take_escrow(e: &Env, request_id: u64, disputer: &Address) -> i128 {
+    let key = DisputeKey::Escrow(request_id, disputer.clone());
+    let amount: i128 = e.storage().instance().get(&key).unwrap_or(0);
+    e.storage().instance().remove(&key);
+    amount
+}
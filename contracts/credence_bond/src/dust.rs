@@ -0,0 +1,92 @@
+//! Dust Auto-close
+//!
+//! Repeated partial withdrawals can leave a few stroops that cost more to withdraw than they
+//! are worth. With a dust threshold configured, a withdrawal that leaves the bond's net balance
+//! (`bonded_amount - slashed_amount`) below the threshold pays out the remainder in the same
+//! operation and deactivates the bond, so the close-out statement is written.
+//!
+//! Bonds that still back collateralized attestations are never swept, since the held amount is
+//! not withdrawable. The threshold is disabled (0) by default.
+
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::{collateral, payout_queue, token_integration, IdentityBond};
+
+const KEY_DUST_THRESHOLD: &str = "dust_threshold";
+
+/// @notice Returns the dust threshold (0 when disabled).
+#[must_use]
+pub fn get_threshold(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_DUST_THRESHOLD))
+        .unwrap_or(0)
+}
+
+/// @notice Returns true once a dust threshold has been stored (even if 0).
+#[must_use]
+pub fn is_configured(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .has(&Symbol::new(e, KEY_DUST_THRESHOLD))
+}
+
+/// @notice Sets the dust threshold (admin only; caller must enforce). 0 disables auto-close.
+///
+/// # Panics
+/// - "dust threshold must be non-negative" if `threshold < 0`.
+pub fn set_threshold(e: &Env, threshold: i128) {
+    if threshold < 0 {
+        panic!("dust threshold must be non-negative");
+    }
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_DUST_THRESHOLD), &threshold);
+    crate::events::publish(e, "dust_threshold_set", threshold);
+}
+
+/// @notice Net balance left on `bond` that auto-close would pay out.
+#[must_use]
+pub fn remainder(bond: &IdentityBond) -> i128 {
+    bond.bonded_amount
+        .checked_sub(bond.slashed_amount)
+        .unwrap_or(0)
+        .max(0)
+}
+
+/// @notice Returns true if `bond` should be auto-closed after a withdrawal.
+#[must_use]
+pub fn is_dust(e: &Env, bond: &IdentityBond) -> bool {
+    let threshold = get_threshold(e);
+    threshold > 0
+        && bond.active
+        && remainder(bond) < threshold
+        && collateral::get_exposure(e, &bond.identity) == 0
+}
+
+/// @notice Deactivates a dust bond and takes its remainder off the books. Callers persist the
+/// bond, record the returned amount as withdrawn and then hand it to `pay`, so no tokens move
+/// before the state is written.
+/// @return Dust amount swept (0 if the bond is not dust).
+pub fn sweep(e: &Env, bond: &mut IdentityBond) -> i128 {
+    if !is_dust(e, bond) {
+        return 0;
+    }
+    let dust = remainder(bond);
+    bond.bonded_amount = bond
+        .bonded_amount
+        .checked_sub(dust)
+        .expect("dust sweep underflow");
+    crate::bond_status::transition(e, bond, crate::bond_status::BondTransition::Close);
+    crate::events::publish_for(e, "bond_auto_closed", bond.identity.clone(), dust);
+    crate::journal::append(e, &bond.identity, "bond_auto_closed", dust, 0);
+    dust
+}
+
+/// @notice Pays a swept dust amount to `recipient`. Without a configured token the sweep is
+/// accounting only and nothing is paid.
+pub fn pay(e: &Env, recipient: &Address, dust: i128) {
+    if dust > 0 && token_integration::has_token(e) {
+        payout_queue::pay_or_queue(e, recipient, dust);
+    }
+}
@@ -67,19 +67,27 @@ pub fn calculate_penalty(
     )
 }
 
-/// Emit early exit penalty event.
+/// Emit early exit penalty event. `free_amount` is the part of `withdraw_amount` covered by the
+/// penalty-free allowance; the penalty applies only to the remaining `penalized_amount`.
 pub fn emit_penalty_event(
     e: &Env,
     identity: &Address,
     withdraw_amount: i128,
+    free_amount: i128,
     penalty_amount: i128,
     treasury: &Address,
 ) {
-    e.events().publish(
-        (Symbol::new(e, "early_exit_penalty"),),
+    let penalized_amount = withdraw_amount
+        .checked_sub(free_amount)
+        .expect("free amount exceeds withdrawal");
+    crate::events::publish(
+        e,
+        "early_exit_penalty",
         (
             identity.clone(),
             withdraw_amount,
+            free_amount,
+            penalized_amount,
             penalty_amount,
             treasury.clone(),
         ),
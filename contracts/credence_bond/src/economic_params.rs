@@ -0,0 +1,127 @@
+//! Economic Parameters Bundle
+//!
+//! Groups the parameters that decide who may attest and how much a bond counts for: verifier
+//! minimum stake, attestation weight config (multiplier, cap and baseline) and tier thresholds.
+//! `set_economic_params` validates every field before writing any of them, so an upgrade never
+//! leaves attestations issued under a half-updated regime.
+//!
+//! The individual setters (`set_verifier_stake_requirement`, `set_weight_config` and the four
+//! `set_<tier>_threshold` calls) are wrappers: they copy the current bundle, change their own
+//! field and go through the same validation and write. They keep their own events; only the
+//! bundle call emits `economic_params_set`.
+
+use soroban_sdk::{contracttype, Env};
+
+use crate::parameters::{self, ParameterKey};
+use crate::types::attestation::MAX_ATTESTATION_WEIGHT;
+use crate::{verifier, weighted_attestation};
+
+/// @notice Every economic parameter, written together by `set_economic_params`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EconomicParams {
+    /// Minimum stake to register or stay active as a verifier.
+    pub verifier_min_stake: i128,
+    /// Attestation weight per unit of attester stake, in basis points.
+    pub weight_multiplier_bps: u32,
+    /// Cap on a computed attestation weight.
+    pub max_weight: u32,
+    /// Weight of an attester without stake, and the floor for staked attesters.
+    pub baseline_weight: u32,
+    pub bronze_threshold: i128,
+    pub silver_threshold: i128,
+    pub gold_threshold: i128,
+    pub platinum_threshold: i128,
+}
+
+/// @notice Current values of every economic parameter (due scheduled threshold changes are
+/// applied first).
+#[must_use]
+pub fn get(e: &Env) -> EconomicParams {
+    let (weight_multiplier_bps, max_weight) = weighted_attestation::get_weight_config(e);
+    EconomicParams {
+        verifier_min_stake: verifier::get_min_stake(e),
+        weight_multiplier_bps,
+        max_weight,
+        baseline_weight: weighted_attestation::get_baseline_weight(e),
+        bronze_threshold: parameters::get_bronze_threshold(e),
+        silver_threshold: parameters::get_silver_threshold(e),
+        gold_threshold: parameters::get_gold_threshold(e),
+        platinum_threshold: parameters::get_platinum_threshold(e),
+    }
+}
+
+/// Checks every field against its bounds.
+///
+/// # Panics
+/// - "min stake cannot be negative"
+/// - "max_weight out of bounds", "baseline_weight out of bounds"
+/// - "<tier>_threshold out of bounds"
+fn validate(params: &EconomicParams) {
+    if params.verifier_min_stake < 0 {
+        panic!("min stake cannot be negative");
+    }
+    if params.max_weight > MAX_ATTESTATION_WEIGHT {
+        panic!("max_weight out of bounds");
+    }
+    if !(1..=MAX_ATTESTATION_WEIGHT).contains(&params.baseline_weight) {
+        panic!("baseline_weight out of bounds");
+    }
+    let thresholds = [
+        (
+            params.bronze_threshold,
+            parameters::MIN_BRONZE_THRESHOLD..=parameters::MAX_BRONZE_THRESHOLD,
+            "bronze_threshold out of bounds",
+        ),
+        (
+            params.silver_threshold,
+            parameters::MIN_SILVER_THRESHOLD..=parameters::MAX_SILVER_THRESHOLD,
+            "silver_threshold out of bounds",
+        ),
+        (
+            params.gold_threshold,
+            parameters::MIN_GOLD_THRESHOLD..=parameters::MAX_GOLD_THRESHOLD,
+            "gold_threshold out of bounds",
+        ),
+        (
+            params.platinum_threshold,
+            parameters::MIN_PLATINUM_THRESHOLD..=parameters::MAX_PLATINUM_THRESHOLD,
+            "platinum_threshold out of bounds",
+        ),
+    ];
+    for (value, bounds, message) in thresholds {
+        if !bounds.contains(&value) {
+            panic!("{}", message);
+        }
+    }
+}
+
+/// @notice Validates `params` and writes all of them, returning the previous values. Nothing is
+/// written if any field is out of bounds. Emits no event (see `set`).
+pub fn write(e: &Env, params: &EconomicParams) -> EconomicParams {
+    validate(params);
+    let old = get(e);
+    verifier::store_min_stake(e, params.verifier_min_stake);
+    weighted_attestation::store_weight_config(
+        e,
+        params.weight_multiplier_bps,
+        params.max_weight,
+        params.baseline_weight,
+    );
+    parameters::store_value(e, &ParameterKey::BronzeThreshold, params.bronze_threshold);
+    parameters::store_value(e, &ParameterKey::SilverThreshold, params.silver_threshold);
+    parameters::store_value(e, &ParameterKey::GoldThreshold, params.gold_threshold);
+    parameters::store_value(
+        e,
+        &ParameterKey::PlatinumThreshold,
+        params.platinum_threshold,
+    );
+    old
+}
+
+/// @notice Replaces every economic parameter at once (admin check enforced by the caller).
+/// Emits `economic_params_set` with `(old, new)`.
+pub fn set(e: &Env, params: &EconomicParams) {
+    let old = write(e, params);
+    crate::events::publish(e, "economic_params_set", (old, params.clone()));
+}
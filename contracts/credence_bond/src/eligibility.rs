@@ -0,0 +1,108 @@
+//! Eligibility Checks
+//!
+//! One read-only call that answers "may this address use our feature" for partner contracts,
+//! instead of each integrator stitching bond, attestation and slash data together. A policy
+//! lists the criteria to apply; unset criteria are skipped, so an empty policy always passes.
+//!
+//! - Tier: the effective tier (`get_effective_tier`) of the subject's bond.
+//! - Attestation: an effective attestation of the category with at least the weight, matched
+//!   the same way as the bond gate.
+//! - Slashes: slashes recorded in the subject's slash history within the lookback window.
+//! - Restrictions: the bond is neither `Quarantined` nor `Frozen`.
+//!
+//! Nothing is written; due tier holds are evaluated, not applied.
+
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+use crate::bond_gate::{self, BondGate};
+use crate::{slash_history, tiered_bond, BondStatus, BondTier, DataKey, IdentityBond};
+
+/// @notice Criteria a subject must meet. `None` / `false` fields are not checked.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EligibilityPolicy {
+    /// Lowest acceptable effective tier (`Bronze` accepts any subject).
+    pub min_tier: BondTier,
+    /// Attestation category the subject must hold.
+    pub attestation_category: Option<Symbol>,
+    /// Minimum weight of that attestation (ignored without a category).
+    pub min_attestation_weight: u32,
+    /// Most slashes allowed within `slash_lookback_secs`.
+    pub max_slashes: Option<u32>,
+    /// Window for `max_slashes`, ending now. 0 counts every recorded slash.
+    pub slash_lookback_secs: u64,
+    /// Reject bonds that are quarantined or frozen.
+    pub require_unrestricted: bool,
+}
+
+/// @notice A criterion a subject failed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EligibilityCriterion {
+    Tier,
+    Attestation,
+    SlashCount,
+    Restricted,
+}
+
+/// @notice Outcome of `check_eligibility`: `eligible` is true exactly when `failed` is empty.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EligibilityResult {
+    pub eligible: bool,
+    pub failed: Vec<EligibilityCriterion>,
+}
+
+/// @notice Evaluates `policy` for `subject` without writing state.
+#[must_use]
+pub fn check(e: &Env, subject: &Address, policy: &EligibilityPolicy) -> EligibilityResult {
+    let bond: Option<IdentityBond> = e.storage().instance().get(&DataKey::Bond(subject.clone()));
+    let mut failed = Vec::new(e);
+
+    if policy.min_tier != BondTier::Bronze {
+        let raw = bond.as_ref().map_or(BondTier::Bronze, |b| {
+            tiered_bond::get_tier_for_amount(b.bonded_amount)
+        });
+        let effective = tiered_bond::get_effective_tier(e, subject, raw);
+        if tiered_bond::rank(&effective) < tiered_bond::rank(&policy.min_tier) {
+            failed.push_back(EligibilityCriterion::Tier);
+        }
+    }
+
+    if let Some(category) = &policy.attestation_category {
+        let requirement = BondGate {
+            category: category.clone(),
+            min_weight: policy.min_attestation_weight,
+        };
+        if !bond_gate::is_satisfied(e, &requirement, subject) {
+            failed.push_back(EligibilityCriterion::Attestation);
+        }
+    }
+
+    if let Some(max_slashes) = policy.max_slashes {
+        let since = if policy.slash_lookback_secs == 0 {
+            0
+        } else {
+            e.ledger()
+                .timestamp()
+                .saturating_sub(policy.slash_lookback_secs)
+        };
+        if slash_history::count_since(e, subject, since) > max_slashes {
+            failed.push_back(EligibilityCriterion::SlashCount);
+        }
+    }
+
+    if policy.require_unrestricted {
+        let restricted = bond
+            .as_ref()
+            .is_some_and(|b| matches!(b.status, BondStatus::Quarantined | BondStatus::Frozen));
+        if restricted {
+            failed.push_back(EligibilityCriterion::Restricted);
+        }
+    }
+
+    EligibilityResult {
+        eligible: failed.is_empty(),
+        failed,
+    }
+}
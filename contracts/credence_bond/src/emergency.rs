@@ -79,6 +79,15 @@ pub fn get_config(e: &Env) -> EmergencyConfig {
         .unwrap_or_else(|| panic!("emergency config not set"))
 }
 
+/// @notice Whether emergency mode is enabled; false when no config has been set.
+#[must_use]
+pub fn is_enabled(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get::<_, EmergencyConfig>(&Symbol::new(e, KEY_EMERGENCY_CONFIG))
+        .is_some_and(|cfg| cfg.enabled)
+}
+
 /// @notice Update emergency enabled state.
 /// @param enabled New emergency mode status.
 pub fn set_enabled(e: &Env, enabled: bool) {
@@ -114,6 +123,7 @@ pub fn calculate_fee(amount: i128, fee_bps: u32) -> i128 {
 /// @param approved_governance Governance approver address.
 /// @param reason Symbolic reason code for audit trail.
 /// @return Created record id.
+#[allow(clippy::too_many_arguments)]
 pub fn store_record(
     e: &Env,
     identity: Address,
@@ -180,8 +190,9 @@ pub fn get_record(e: &Env, id: u64) -> EmergencyWithdrawalRecord {
 /// @param admin Admin approver.
 /// @param governance Governance approver.
 pub fn emit_emergency_mode_event(e: &Env, enabled: bool, admin: &Address, governance: &Address) {
-    e.events().publish(
-        (Symbol::new(e, "emergency_mode"),),
+    crate::events::publish(
+        e,
+        "emergency_mode",
         (
             enabled,
             admin.clone(),
@@ -207,8 +218,9 @@ pub fn emit_emergency_withdrawal_event(
     net_amount: i128,
     reason: &Symbol,
 ) {
-    e.events().publish(
-        (Symbol::new(e, "emergency_withdrawal"),),
+    crate::events::publish(
+        e,
+        "emergency_withdrawal",
         (
             record_id,
             identity.clone(),
@@ -1,15 +1,104 @@
-use soroban_sdk::{Address, Env, Symbol};
+//! Contract Events
+//!
+//! Every event is published through `publish` / `publish_for`, which insert the event schema
+//! version as the topic right after the event name: `(name, version)` or
+//! `(name, version, subject)`. Indexers can check `get_event_schema_version()` at startup and
+//! read the version from each event.
+//!
+//! The bond lifecycle events below (`bond_created`, `bond_increased`, `bond_withdrawn`,
+//! `bond_slashed`, `slash_executed`) end their data with the ledger `Context` they were emitted
+//! at.
+//!
+//! Deployments can silence a few low-value, high-volume events (`SILENCEABLE_EVENTS`) with
+//! `set_event_enabled`. Both helpers skip silenced events. Everything else, including every
+//! slash, withdrawal and admin event, is always emitted.
+
+use soroban_sdk::{contracttype, Address, Env, IntoVal, Symbol, Val};
+
+/// Version of the event payload schema. Bump whenever any event's topics or data change shape.
+pub const EVENT_SCHEMA_VERSION: u32 = 5;
+
+/// Events an admin may silence. Never add slash, withdrawal or admin events here.
+pub const SILENCEABLE_EVENTS: [&str; 3] =
+    ["attestation_used", "tier_changed", "tier_upgrade_pending"];
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum EventPolicyKey {
+    Silenced(Symbol),
+}
+
+fn silenceable(e: &Env, name: &Symbol) -> bool {
+    SILENCEABLE_EVENTS
+        .iter()
+        .any(|allowed| Symbol::new(e, allowed) == *name)
+}
+
+/// @notice True unless `name` is silenceable and currently silenced.
+#[must_use]
+pub fn is_enabled(e: &Env, name: &str) -> bool {
+    if !SILENCEABLE_EVENTS.contains(&name) {
+        return true;
+    }
+    !e.storage()
+        .instance()
+        .has(&EventPolicyKey::Silenced(Symbol::new(e, name)))
+}
+
+/// @notice Silences or re-enables `name` (admin only; caller must enforce).
+///
+/// # Panics
+/// - "event not silenceable" if `name` is not in `SILENCEABLE_EVENTS`.
+pub fn set_enabled(e: &Env, name: &Symbol, enabled: bool) {
+    if !silenceable(e, name) {
+        panic!("event not silenceable");
+    }
+    let key = EventPolicyKey::Silenced(name.clone());
+    if enabled {
+        e.storage().instance().remove(&key);
+    } else {
+        e.storage().instance().set(&key, &true);
+    }
+    publish(e, "event_policy_set", (name.clone(), enabled));
+}
+
+/// Publishes `name` with topics `(name, EVENT_SCHEMA_VERSION)`.
+pub fn publish<D>(e: &Env, name: &str, data: D)
+where
+    D: IntoVal<Env, Val>,
+{
+    if !is_enabled(e, name) {
+        return;
+    }
+    e.events()
+        .publish((Symbol::new(e, name), EVENT_SCHEMA_VERSION), data);
+}
+
+/// Publishes `name` with topics `(name, EVENT_SCHEMA_VERSION, subject)`.
+pub fn publish_for<T, D>(e: &Env, name: &str, subject: T, data: D)
+where
+    T: IntoVal<Env, Val>,
+    D: IntoVal<Env, Val>,
+{
+    if !is_enabled(e, name) {
+        return;
+    }
+    e.events()
+        .publish((Symbol::new(e, name), EVENT_SCHEMA_VERSION, subject), data);
+}
 
 /// Emitted when a new bond is created.
 ///
 /// # Topics
 /// * `Symbol` - "bond_created"
+/// * `u32` - Event schema version
 /// * `Address` - The identity owning the bond
 ///
 /// # Data
 /// * `i128` - The initial bonded amount
 /// * `u64` - The duration of the bond in seconds
 /// * `bool` - Whether the bond is rolling
+/// * `Context` - Ledger sequence and timestamp
 pub fn emit_bond_created(
     e: &Env,
     identity: &Address,
@@ -17,52 +106,151 @@ pub fn emit_bond_created(
     duration: u64,
     is_rolling: bool,
 ) {
-    let topics = (Symbol::new(e, "bond_created"), identity.clone());
-    let data = (amount, duration, is_rolling);
-    e.events().publish(topics, data);
+    publish_for(
+        e,
+        "bond_created",
+        identity.clone(),
+        (amount, duration, is_rolling, crate::context::current(e)),
+    );
+    crate::journal::append(e, identity, "bond_created", amount, 0);
 }
 
 /// Emitted when an existing bond is increased (topped up).
 ///
 /// # Topics
 /// * `Symbol` - "bond_increased"
+/// * `u32` - Event schema version
 /// * `Address` - The identity owning the bond
 ///
 /// # Data
 /// * `i128` - The additional amount added
 /// * `i128` - The new total bonded amount
+/// * `Context` - Ledger sequence and timestamp
 pub fn emit_bond_increased(e: &Env, identity: &Address, added_amount: i128, new_total: i128) {
-    let topics = (Symbol::new(e, "bond_increased"), identity.clone());
-    let data = (added_amount, new_total);
-    e.events().publish(topics, data);
+    publish_for(
+        e,
+        "bond_increased",
+        identity.clone(),
+        (added_amount, new_total, crate::context::current(e)),
+    );
+    crate::journal::append(e, identity, "bond_increased", added_amount, 0);
+}
+
+/// Emitted once by `extend_and_top_up`, in place of `bond_increased`.
+///
+/// # Topics
+/// * `Symbol` - "bond_extended"
+/// * `u32` - Event schema version
+/// * `Address` - The identity owning the bond
+///
+/// # Data
+/// * `i128` - The additional amount added
+/// * `i128` - The new total bonded amount
+/// * `u64` - The new bond duration in seconds
+/// * `u64` - The new lock-up end timestamp
+/// * `Context` - Ledger sequence and timestamp
+pub fn emit_bond_extended(
+    e: &Env,
+    identity: &Address,
+    added_amount: i128,
+    new_total: i128,
+    new_duration: u64,
+    new_end: u64,
+) {
+    publish_for(
+        e,
+        "bond_extended",
+        identity.clone(),
+        (
+            added_amount,
+            new_total,
+            new_duration,
+            new_end,
+            crate::context::current(e),
+        ),
+    );
+    crate::journal::append(e, identity, "bond_extended", added_amount, 0);
 }
 
 /// Emitted when funds are successfully withdrawn from a bond.
 ///
 /// # Topics
 /// * `Symbol` - "bond_withdrawn"
+/// * `u32` - Event schema version
 /// * `Address` - The identity owning the bond
 ///
 /// # Data
 /// * `i128` - The amount withdrawn
 /// * `i128` - The remaining bonded amount
+/// * `Context` - Ledger sequence and timestamp
 pub fn emit_bond_withdrawn(e: &Env, identity: &Address, amount_withdrawn: i128, remaining: i128) {
-    let topics = (Symbol::new(e, "bond_withdrawn"), identity.clone());
-    let data = (amount_withdrawn, remaining);
-    e.events().publish(topics, data);
+    publish_for(
+        e,
+        "bond_withdrawn",
+        identity.clone(),
+        (amount_withdrawn, remaining, crate::context::current(e)),
+    );
+    crate::journal::append(e, identity, "bond_withdrawn", amount_withdrawn, 0);
 }
 
-/// Emitted when a bond is slashed by an admin.
+/// Emitted when a bond is slashed by an admin, governance execution or an accepted settlement.
 ///
 /// # Topics
 /// * `Symbol` - "bond_slashed"
+/// * `u32` - Event schema version
 /// * `Address` - The identity owning the bond
 ///
 /// # Data
 /// * `i128` - The amount slashed in this event
+/// * `i128` - The total slashed amount before this slash
 /// * `i128` - The new total slashed amount for this bond
-pub fn emit_bond_slashed(e: &Env, identity: &Address, slash_amount: i128, total_slashed: i128) {
-    let topics = (Symbol::new(e, "bond_slashed"), identity.clone());
-    let data = (slash_amount, total_slashed);
-    e.events().publish(topics, data);
+/// * `Symbol` - Reason (`slash`, `governance` or `settlement`)
+/// * `Context` - Ledger sequence and timestamp
+pub fn emit_bond_slashed(
+    e: &Env,
+    identity: &Address,
+    slash_amount: i128,
+    previous_slashed: i128,
+    total_slashed: i128,
+    reason: &Symbol,
+) {
+    publish_for(
+        e,
+        "bond_slashed",
+        identity.clone(),
+        (
+            slash_amount,
+            previous_slashed,
+            total_slashed,
+            reason.clone(),
+            crate::context::current(e),
+        ),
+    );
+    crate::journal::append(e, identity, "bond_slashed", slash_amount, 0);
+}
+
+/// Emitted when governance executes an approved slash request, after `bond_slashed`.
+///
+/// # Topics
+/// * `Symbol` - "slash_executed"
+/// * `u32` - Event schema version
+/// * `Address` - The identity owning the bond
+///
+/// # Data
+/// * `i128` - The requested slash amount
+/// * `i128` - The bonded amount after the slash
+/// * `i128` - The new total slashed amount (capped at the bonded amount)
+/// * `Context` - Ledger sequence and timestamp
+pub fn emit_slash_executed(e: &Env, bond: &crate::IdentityBond, amount: i128) {
+    publish_for(
+        e,
+        "slash_executed",
+        bond.identity.clone(),
+        (
+            amount,
+            bond.bonded_amount,
+            bond.slashed_amount,
+            crate::context::current(e),
+        ),
+    );
 }
@@ -18,7 +18,7 @@
 //! - Hash uniqueness enforced to prevent duplicate evidence
 //! - All operations emit events for auditability
 
-use soroban_sdk::{contracttype, Address, Env, String, Symbol, Vec};
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
 
 /// Type of evidence hash being stored.
 #[contracttype]
@@ -117,7 +117,7 @@ pub fn submit_evidence(
     description: &Option<String>,
 ) -> u64 {
     // Validation
-    if hash.len() == 0 {
+    if hash.is_empty() {
         panic!("hash cannot be empty");
     }
 
@@ -283,8 +283,10 @@ fn emit_evidence_submitted(
     submitter: &Address,
     hash: &String,
 ) {
-    e.events().publish(
-        (Symbol::new(e, "evidence_submitted"), evidence_id),
+    crate::events::publish_for(
+        e,
+        "evidence_submitted",
+        evidence_id,
         (proposal_id, submitter.clone(), hash.clone()),
     );
 }
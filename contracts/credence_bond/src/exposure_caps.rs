@@ -0,0 +1,106 @@
+//! Exposure Caps
+//!
+//! Circuit-breaker limits on value bonded while the protocol is young: no identity may have more
+//! than `per_identity_cap` bonded across all its bonds (see `multi_bond::total_after_deposit`)
+//! and the contract may hold at most `global_cap` in total. A cap of 0 means unlimited. The
+//! global check runs against a maintained `total_bonded` aggregate of what bonds hold net of
+//! slashes: deposits (`create_bond`, `top_up`, `increase_bond`, batch creation) add to it;
+//! withdrawals and slashes release it, since slashed funds leave the bond through slash proceeds.
+
+use soroban_sdk::{contracttype, Env};
+
+/// Configured caps; 0 disables a cap.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExposureCaps {
+    pub per_identity_cap: i128,
+    pub global_cap: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum ExposureKey {
+    Caps,
+    TotalBonded,
+}
+
+/// @notice Current caps (unlimited by default).
+#[must_use]
+pub fn get_caps(e: &Env) -> ExposureCaps {
+    e.storage()
+        .instance()
+        .get(&ExposureKey::Caps)
+        .unwrap_or(ExposureCaps {
+            per_identity_cap: 0,
+            global_cap: 0,
+        })
+}
+
+/// @notice Sets both caps (admin only; caller must enforce). Lowering a cap below current
+/// exposure only blocks further deposits.
+///
+/// # Panics
+/// - "exposure caps must be non-negative" if either cap is negative.
+pub fn set_caps(e: &Env, per_identity_cap: i128, global_cap: i128) {
+    if per_identity_cap < 0 || global_cap < 0 {
+        panic!("exposure caps must be non-negative");
+    }
+    e.storage().instance().set(
+        &ExposureKey::Caps,
+        &ExposureCaps {
+            per_identity_cap,
+            global_cap,
+        },
+    );
+    crate::events::publish(e, "exposure_caps_set", (per_identity_cap, global_cap));
+}
+
+/// @notice Total value currently bonded across identities.
+#[must_use]
+pub fn total_bonded(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&ExposureKey::TotalBonded)
+        .unwrap_or(0)
+}
+
+/// @notice Checks a deposit of `amount` that leaves the identity with `identity_bonded` bonded,
+/// then adds it to the global aggregate.
+///
+/// # Panics
+/// - "exposure cap exceeded" if either cap would be exceeded.
+pub fn reserve(e: &Env, identity_bonded: i128, amount: i128) {
+    let caps = get_caps(e);
+    if caps.per_identity_cap > 0 && identity_bonded > caps.per_identity_cap {
+        panic!("exposure cap exceeded");
+    }
+    let total = total_bonded(e)
+        .checked_add(amount)
+        .expect("total bonded overflow");
+    if caps.global_cap > 0 && total > caps.global_cap {
+        panic!("exposure cap exceeded");
+    }
+    e.storage()
+        .instance()
+        .set(&ExposureKey::TotalBonded, &total);
+}
+
+/// @notice Frees `amount` of global capacity after a withdrawal or slash. Saturates at 0 for
+/// bonds created before the aggregate was tracked.
+pub fn release(e: &Env, amount: i128) {
+    let total = total_bonded(e).saturating_sub(amount).max(0);
+    e.storage()
+        .instance()
+        .set(&ExposureKey::TotalBonded, &total);
+}
+
+/// @notice Adds `amount` back without a cap check, for an unslash returning a slashed portion to
+/// its bond.
+pub fn restore(e: &Env, amount: i128) {
+    let total = total_bonded(e)
+        .checked_add(amount)
+        .expect("total bonded overflow");
+    e.storage()
+        .instance()
+        .set(&ExposureKey::TotalBonded, &total);
+}
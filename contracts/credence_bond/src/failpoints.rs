@@ -0,0 +1,99 @@
+//! Failure Injection
+//!
+//! Named failpoints on the contract's external-call helpers, so tests can simulate a token
+//! transfer, callback or admin-contract lookup failing without deploying a bespoke mock for each
+//! case. A failpoint armed with `nth` fires on the `nth` invocation of its helper after arming
+//! and then disarms itself. Token and lookup helpers panic when their failpoint fires; subject
+//! hook delivery reports the call as failed, as a reverting hook would.
+//!
+//! Only compiled with `cfg(test)` or the `testing-hooks` feature. In every other build
+//! `hit` and `should_fail` are empty and the arming entrypoints do not exist.
+
+/// Token transfers into and out of the contract (`token_integration`).
+pub const TOKEN_TRANSFER: &str = "token_transfer";
+
+/// Reentrancy-test callbacks and subject hook deliveries.
+pub const CALLBACK_INVOKE: &str = "callback_invoke";
+
+/// `can_call` lookups against the external role registry (admin contract).
+pub const ADMIN_CONTRACT_LOOKUP: &str = "admin_contract_lookup";
+
+#[cfg(any(test, feature = "testing-hooks"))]
+mod armed {
+    use soroban_sdk::{contracttype, Env, Symbol};
+
+    /// Failpoints that may be armed.
+    const KNOWN_FAILPOINTS: [&str; 3] = [
+        super::TOKEN_TRANSFER,
+        super::CALLBACK_INVOKE,
+        super::ADMIN_CONTRACT_LOOKUP,
+    ];
+
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    enum FailpointKey {
+        /// (invocation that fires, invocations seen so far) for an armed failpoint.
+        Armed(Symbol),
+    }
+
+    fn require_known(e: &Env, name: &Symbol) {
+        if !KNOWN_FAILPOINTS.iter().any(|f| Symbol::new(e, f) == *name) {
+            panic!("unknown failpoint");
+        }
+    }
+
+    /// @notice Arms `name` to fire on its `nth` invocation (1 = the next one).
+    ///
+    /// # Panics
+    /// - "unknown failpoint", "nth must be positive"
+    pub fn arm(e: &Env, name: &Symbol, nth: u32) {
+        require_known(e, name);
+        if nth == 0 {
+            panic!("nth must be positive");
+        }
+        e.storage()
+            .instance()
+            .set(&FailpointKey::Armed(name.clone()), &(nth, 0_u32));
+    }
+
+    /// @notice Disarms `name`; a no-op if it is not armed.
+    pub fn disarm(e: &Env, name: &Symbol) {
+        require_known(e, name);
+        e.storage()
+            .instance()
+            .remove(&FailpointKey::Armed(name.clone()));
+    }
+
+    /// @notice Counts one invocation of `name` and returns true if it should fail now.
+    pub fn should_fail(e: &Env, name: &str) -> bool {
+        let key = FailpointKey::Armed(Symbol::new(e, name));
+        let Some((nth, seen)) = e.storage().instance().get::<_, (u32, u32)>(&key) else {
+            return false;
+        };
+        let seen = seen.saturating_add(1);
+        if seen >= nth {
+            e.storage().instance().remove(&key);
+            return true;
+        }
+        e.storage().instance().set(&key, &(nth, seen));
+        false
+    }
+}
+
+#[cfg(any(test, feature = "testing-hooks"))]
+pub use armed::{arm, disarm, should_fail};
+
+/// @notice Counts one invocation of `name` and returns true if it should fail now.
+#[cfg(not(any(test, feature = "testing-hooks")))]
+#[inline(always)]
+pub fn should_fail(_e: &soroban_sdk::Env, _name: &str) -> bool {
+    false
+}
+
+/// @notice Panics with "failpoint triggered: <name>" if `name` fires on this invocation.
+#[inline(always)]
+pub fn hit(e: &soroban_sdk::Env, name: &str) {
+    if should_fail(e, name) {
+        panic!("failpoint triggered: {}", name);
+    }
+}
@@ -0,0 +1,73 @@
+//! Feature Flags
+//!
+//! Per-deployment switches for opt-in entrypoints, so a feature can be rolled out on one
+//! network without shipping a different wasm. Only flags on the allow-list below can be set;
+//! every flag defaults to off and gated entrypoints panic with "feature disabled" until the
+//! admin enables it.
+
+use soroban_sdk::{contracttype, Env, Symbol, Vec};
+
+/// Collateralized attestations (`add_collateralized_attestation`, `propose_attestation_slash`).
+pub const COLLATERALIZED_ATTESTATIONS: &str = "collateralized_attestations";
+
+/// Hash-only attestations: plain `add_attestation` is rejected in favour of
+/// `add_attestation_hash`.
+pub const ATTESTATION_PRIVACY: &str = "attestation_privacy";
+
+/// Flags that may be set with `set_feature`.
+const KNOWN_FEATURES: [&str; 2] = [COLLATERALIZED_ATTESTATIONS, ATTESTATION_PRIVACY];
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum FeatureKey {
+    Enabled(Symbol),
+}
+
+fn require_known(e: &Env, flag: &Symbol) {
+    if !KNOWN_FEATURES.iter().any(|f| Symbol::new(e, f) == *flag) {
+        panic!("unknown feature");
+    }
+}
+
+/// @notice Returns true if `flag` is enabled (unknown flags are never enabled).
+#[must_use]
+pub fn is_enabled(e: &Env, flag: &Symbol) -> bool {
+    e.storage()
+        .instance()
+        .get(&FeatureKey::Enabled(flag.clone()))
+        .unwrap_or(false)
+}
+
+/// @notice Enables or disables a known feature (admin only; caller must enforce).
+///
+/// # Panics
+/// - "unknown feature" if `flag` is not on the allow-list.
+pub fn set_enabled(e: &Env, flag: &Symbol, enabled: bool) {
+    require_known(e, flag);
+    let key = FeatureKey::Enabled(flag.clone());
+    if enabled {
+        e.storage().instance().set(&key, &true);
+    } else {
+        e.storage().instance().remove(&key);
+    }
+    crate::events::publish_for(e, "feature_set", flag.clone(), enabled);
+}
+
+/// @notice Returns every known flag with its current state.
+#[must_use]
+pub fn list(e: &Env) -> Vec<(Symbol, bool)> {
+    let mut out = Vec::new(e);
+    for name in KNOWN_FEATURES {
+        let flag = Symbol::new(e, name);
+        let enabled = is_enabled(e, &flag);
+        out.push_back((flag, enabled));
+    }
+    out
+}
+
+/// @notice Panics with "feature disabled" unless `name` is enabled.
+pub fn require_enabled(e: &Env, name: &str) {
+    if !is_enabled(e, &Symbol::new(e, name)) {
+        panic!("feature disabled");
+    }
+}
@@ -75,6 +75,39 @@ pub fn record_fee(e: &Env, identity: &Address, amount: i128, fee: i128, treasury
     emit_fee_event(e, identity, amount, fee, treasury);
 }
 
+/// Add tokens already held by the contract (e.g. forfeited escrow) to the fee pool.
+pub fn add_to_pool(e: &Env, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    let key = Symbol::new(e, "fees");
+    let current: i128 = e.storage().instance().get(&key).unwrap_or(0);
+    let new_total = current.checked_add(amount).expect("fee pool overflow");
+    e.storage().instance().set(&key, &new_total);
+}
+
+/// Current fee pool balance.
+#[must_use]
+pub fn pool_balance(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, "fees"))
+        .unwrap_or(0)
+}
+
+/// Take up to `amount` out of the fee pool for a payout the caller makes. Returns the amount
+/// taken, which is capped at the pool balance.
+pub fn take_from_pool(e: &Env, amount: i128) -> i128 {
+    let pool = pool_balance(e);
+    let taken = amount.clamp(0, pool.max(0));
+    if taken > 0 {
+        e.storage()
+            .instance()
+            .set(&Symbol::new(e, "fees"), &(pool - taken));
+    }
+    taken
+}
+
 /// Emit fee collection event.
 pub fn emit_fee_event(
     e: &Env,
@@ -83,8 +116,9 @@ pub fn emit_fee_event(
     fee_amount: i128,
     treasury: &Address,
 ) {
-    e.events().publish(
-        (Symbol::new(e, "bond_creation_fee"),),
+    crate::events::publish(
+        e,
+        "bond_creation_fee",
         (identity.clone(), bond_amount, fee_amount, treasury.clone()),
     );
 }
@@ -0,0 +1,137 @@
+//! Penalty-free Withdrawal Allowance
+//!
+//! A liquidity valve for locked bonds: each identity may withdraw up to `free_withdrawal_bps` of
+//! its bond per `free_withdrawal_period` through `withdraw_early` without paying the early exit
+//! penalty. Only the excess over the remaining allowance is penalized.
+//!
+//! Periods are tracked per identity and reset lazily: the first early withdrawal after the
+//! previous period has elapsed opens a new period at the current ledger time. The allowance for
+//! a period is fixed when it opens, from the bonded amount at that moment, so withdrawing inside
+//! a period does not shrink what is left of it.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::math;
+
+/// Period used when the allowance is configured without an explicit length (30 days).
+pub const DEFAULT_PERIOD_SECS: u64 = 30 * 86_400;
+
+/// Allowance configuration; `free_withdrawal_bps == 0` disables the allowance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FreeWithdrawalConfig {
+    pub free_withdrawal_bps: u32,
+    pub free_withdrawal_period: u64,
+}
+
+/// An identity's usage in its current period.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FreeWithdrawalUsage {
+    pub period_start: u64,
+    /// Penalty-free amount available for the whole period.
+    pub allowance: i128,
+    pub used: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum FreeWithdrawalKey {
+    Config,
+    Usage(Address),
+}
+
+/// @notice Current allowance configuration (disabled by default).
+#[must_use]
+pub fn get_config(e: &Env) -> FreeWithdrawalConfig {
+    e.storage()
+        .instance()
+        .get(&FreeWithdrawalKey::Config)
+        .unwrap_or(FreeWithdrawalConfig {
+            free_withdrawal_bps: 0,
+            free_withdrawal_period: DEFAULT_PERIOD_SECS,
+        })
+}
+
+/// @notice Sets the allowance (admin only; caller must enforce). A period of 0 selects
+/// `DEFAULT_PERIOD_SECS`.
+///
+/// # Panics
+/// - "free_withdrawal_bps must be <= 10000" if `bps` exceeds 100%.
+pub fn set_config(e: &Env, bps: u32, period: u64) {
+    if bps > 10_000 {
+        panic!("free_withdrawal_bps must be <= 10000");
+    }
+    let config = FreeWithdrawalConfig {
+        free_withdrawal_bps: bps,
+        free_withdrawal_period: if period == 0 {
+            DEFAULT_PERIOD_SECS
+        } else {
+            period
+        },
+    };
+    e.storage()
+        .instance()
+        .set(&FreeWithdrawalKey::Config, &config);
+    crate::events::publish(
+        e,
+        "free_withdrawal_config_set",
+        (config.free_withdrawal_bps, config.free_withdrawal_period),
+    );
+}
+
+fn period_end(usage: &FreeWithdrawalUsage, config: &FreeWithdrawalConfig) -> u64 {
+    usage
+        .period_start
+        .saturating_add(config.free_withdrawal_period)
+}
+
+/// @notice Usage of `identity` in the period that applies now, opening a fresh period (with the
+/// allowance derived from `bonded_amount`) if the stored one has elapsed.
+#[must_use]
+pub fn current_usage(e: &Env, identity: &Address, bonded_amount: i128) -> FreeWithdrawalUsage {
+    let config = get_config(e);
+    let now = e.ledger().timestamp();
+    let stored: Option<FreeWithdrawalUsage> = e
+        .storage()
+        .instance()
+        .get(&FreeWithdrawalKey::Usage(identity.clone()));
+    match stored {
+        Some(usage) if now < period_end(&usage, &config) => usage,
+        _ => FreeWithdrawalUsage {
+            period_start: now,
+            allowance: math::bps(
+                bonded_amount,
+                config.free_withdrawal_bps,
+                "free withdrawal allowance overflow",
+                "free withdrawal allowance div-by-zero",
+            ),
+            used: 0,
+        },
+    }
+}
+
+/// @notice Penalty-free amount `identity` can still withdraw in the current period.
+#[must_use]
+pub fn remaining(e: &Env, identity: &Address, bonded_amount: i128) -> i128 {
+    let usage = current_usage(e, identity, bonded_amount);
+    usage.allowance.saturating_sub(usage.used).max(0)
+}
+
+/// @notice Consumes as much of `amount` as the remaining allowance covers.
+/// @return The penalty-free portion of `amount`.
+pub fn consume(e: &Env, identity: &Address, bonded_amount: i128, amount: i128) -> i128 {
+    if get_config(e).free_withdrawal_bps == 0 {
+        return 0;
+    }
+    let mut usage = current_usage(e, identity, bonded_amount);
+    let free = amount.min(usage.allowance.saturating_sub(usage.used).max(0));
+    usage.used = usage
+        .used
+        .checked_add(free)
+        .expect("free withdrawal usage overflow");
+    e.storage()
+        .instance()
+        .set(&FreeWithdrawalKey::Usage(identity.clone()), &usage);
+    free
+}
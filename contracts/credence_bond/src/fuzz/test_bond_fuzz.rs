@@ -5,7 +5,7 @@
 //! violations across:
 //! - `create_bond`
 //! - withdrawals (`withdraw_bond`, `withdraw_early`)
-//! - slashing (`slash`, `slash_bond`)
+//! - slashing (`slash`, `slash_bond`, governance `propose_slash` / `execute_slash_with_governance`)
 //!
 //! ## Configuration
 //! Environment variables:
@@ -27,7 +27,7 @@ use crate::test_helpers;
 use soroban_sdk::testutils::Address as _;
 use soroban_sdk::testutils::Ledger;
 use soroban_sdk::token::{StellarAssetClient, TokenClient};
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Env, Vec};
 use std::collections::BTreeMap;
 use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::string::String;
@@ -141,9 +141,18 @@ fn assert_bond_invariants(bond: &crate::IdentityBond) {
         "invariant violated: slashed_amount > bonded_amount"
     );
     assert!(
-        bond.bond_start.checked_add(bond.bond_duration).is_some(),
+        bond.kind == crate::BondKind::Perpetual
+            || bond.bond_start.checked_add(bond.bond_duration).is_some(),
         "invariant violated: bond_end timestamp overflow"
     );
+    assert!(
+        crate::rolling_bond::period_end(bond.bond_start, bond.bond_duration) >= bond.bond_start,
+        "invariant violated: period end before period start"
+    );
+    assert!(
+        !bond.is_rolling || bond.bond_duration > 0,
+        "invariant violated: zero-duration rolling bond"
+    );
 }
 
 fn sample_timestamp(rng: &mut SplitMix64) -> u64 {
@@ -267,7 +276,7 @@ fn fuzz_bond_operations() {
         client.set_early_exit_config(&admin, &treasury, &500_u32);
 
         // Keep allowance large across iterations.
-        let expiration = e.ledger().sequence().saturating_add(10_000) as u32;
+        let expiration = e.ledger().sequence().saturating_add(10_000);
         token_client.approve(&identity, &bond_contract_id, &i128::MAX, &expiration);
 
         let mut rng = SplitMix64::new(seed);
@@ -282,35 +291,38 @@ fn fuzz_bond_operations() {
         let fixed = [
             // Use durations that pass `validation::validate_bond_duration`.
             (
-                1_000_i128,
+                crate::validation::MIN_BOND_AMOUNT,
                 crate::validation::MIN_BOND_DURATION,
                 false,
                 0_u64,
             ),
             (
-                50_000_i128,
+                50_000_000_i128,
                 crate::validation::MIN_BOND_DURATION.saturating_mul(30),
                 false,
                 0_u64,
             ),
             (
-                1_000_i128,
+                crate::validation::MIN_BOND_AMOUNT,
                 crate::validation::MIN_BOND_DURATION,
                 true,
                 60_u64,
             ),
         ];
 
+        // An identity holds one bond, so each fixed scenario bonds a fresh funded holder.
         for (amount, duration, is_rolling, notice) in fixed {
             e.ledger().with_mut(|li| li.timestamp = 1_000);
-            let before_identity = token_client.balance(&identity);
+            let holder = Address::generate(&e);
+            test_helpers::fund(&e, &token_id, &bond_contract_id, &holder, amount);
+            let before_identity = token_client.balance(&holder);
             let before_contract = token_client.balance(&bond_contract_id);
 
-            let bond = client.create_bond(&identity, &amount, &duration, &is_rolling, &notice);
+            let bond = client.create_bond(&holder, &amount, &duration, &is_rolling, &notice);
             create_ok = create_ok.saturating_add(1);
             assert_bond_invariants(&bond);
 
-            let after_identity = token_client.balance(&identity);
+            let after_identity = token_client.balance(&holder);
             let after_contract = token_client.balance(&bond_contract_id);
             assert_eq!(
                 before_identity.checked_sub(amount).unwrap(),
@@ -328,7 +340,7 @@ fn fuzz_bond_operations() {
             // Top up identity balance occasionally so long runs don't starve on token balance.
             if iter % 512 == 0 {
                 asset_client.mint(&identity, &10_000_000_000_000_i128);
-                let expiration = e.ledger().sequence().saturating_add(10_000) as u32;
+                let expiration = e.ledger().sequence().saturating_add(10_000);
                 token_client.approve(&identity, &bond_contract_id, &i128::MAX, &expiration);
             }
 
@@ -380,20 +392,20 @@ fn fuzz_bond_operations() {
 
             // Run a small sequence of operations after successful creation.
             for _ in 0..actions {
-                let op = rng.gen_range_u64(3);
+                let op = rng.gen_range_u64(4);
                 match op {
                     // Slashing
                     0 => {
-                        let before = client.get_identity_state();
+                        let before = client.get_identity_state(&identity);
                         let slash_amount = sample_amount_for_slash(&mut rng, before.bonded_amount);
                         let res = catch_unwind(AssertUnwindSafe(|| {
                             // Mix between the two slashing entrypoints.
                             if rng.next_bool() {
-                                client.slash(&admin, &slash_amount)
+                                client.slash(&admin, &identity, &slash_amount)
                             } else {
                                 // `slash_bond` returns i128 (new_slashed).
-                                let _ = client.slash_bond(&admin, &slash_amount);
-                                client.get_identity_state()
+                                let _ = client.slash_bond(&admin, &identity, &slash_amount);
+                                client.get_identity_state(&identity)
                             }
                         }));
                         match res {
@@ -414,7 +426,7 @@ fn fuzz_bond_operations() {
                     }
                     // Withdrawals
                     1 => {
-                        let state = client.get_identity_state();
+                        let state = client.get_identity_state(&identity);
                         assert_bond_invariants(&state);
                         let available = state
                             .bonded_amount
@@ -438,11 +450,13 @@ fn fuzz_bond_operations() {
                             // Request withdrawal then advance beyond notice period.
                             e.ledger()
                                 .with_mut(|li| li.timestamp = state.bond_start.saturating_add(1));
-                            let _ = catch_unwind(AssertUnwindSafe(|| client.request_withdrawal()));
+                            let _ = catch_unwind(AssertUnwindSafe(|| {
+                                client.request_withdrawal(&identity)
+                            }));
                             let now = e
                                 .ledger()
                                 .timestamp()
-                                .saturating_add(state.notice_period_duration)
+                                .saturating_add(state.notice_period)
                                 .saturating_add(1);
                             e.ledger().with_mut(|li| li.timestamp = now);
                         } else {
@@ -456,9 +470,9 @@ fn fuzz_bond_operations() {
 
                         let res = catch_unwind(AssertUnwindSafe(|| {
                             if use_early {
-                                client.withdraw_early(&withdraw_amount)
+                                client.withdraw_early(&identity, &withdraw_amount)
                             } else {
-                                client.withdraw_bond(&withdraw_amount)
+                                client.withdraw_bond(&identity, &withdraw_amount)
                             }
                         }));
 
@@ -504,9 +518,30 @@ fn fuzz_bond_operations() {
                             }
                         }
                     }
+                    // Rolling renewal at a sampled (possibly near-max) timestamp
+                    2 => {
+                        let before = client.get_identity_state(&identity);
+                        let ts = sample_timestamp(&mut rng).max(before.bond_start);
+                        e.ledger().with_mut(|li| li.timestamp = ts);
+                        let res =
+                            catch_unwind(AssertUnwindSafe(|| client.renew_if_rolling(&identity)));
+                        match res {
+                            Ok(after) => {
+                                ops_ok = ops_ok.saturating_add(1);
+                                assert_bond_invariants(&after);
+                                assert!(
+                                    after.bond_start >= before.bond_start,
+                                    "iter={iter} renewal moved bond_start backwards"
+                                );
+                            }
+                            Err(err) => {
+                                *panic_counts.entry(panic_msg(&*err)).or_default() += 1;
+                            }
+                        }
+                    }
                     // No-op / state check
                     _ => {
-                        let state = client.get_identity_state();
+                        let state = client.get_identity_state(&identity);
                         assert_bond_invariants(&state);
                     }
                 }
@@ -538,3 +573,132 @@ fn fuzz_bond_operations() {
         std::panic::resume_unwind(err);
     }
 }
+
+/// Slash amounts at and just below `i128::MAX`, so every addition to `slashed_amount` would wrap
+/// if it were unchecked.
+fn sample_amount_near_max(rng: &mut SplitMix64, bonded_amount: i128) -> i128 {
+    match rng.gen_range_u64(5) {
+        0 => i128::MAX,
+        1 => i128::MAX - 1,
+        2 => i128::MAX - bonded_amount + 1,
+        3 => i128::MAX - rng.gen_i128_nonneg(bonded_amount.saturating_add(1)),
+        _ => 1 + rng.gen_i128_nonneg(bonded_amount),
+    }
+}
+
+/// Pushes near-`i128::MAX` slash amounts through the governance path and `slash_bond`.
+/// Governance execution caps the slash and must land on `min(before + amount, bonded)`;
+/// `slash_bond` rejects an over-slash and must leave the bond unchanged. Neither may wrap.
+#[test]
+fn fuzz_governance_slash_max_amounts() {
+    let seed = fuzz_seed();
+    let iters = fuzz_iters().min(200);
+    let bonded = 10_000_000_i128;
+    let mut rng = SplitMix64::new(seed);
+
+    for iter in 0..iters {
+        let e = Env::default();
+        e.ledger().with_mut(|li| li.timestamp = 1_000);
+        let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+        client.create_bond(
+            &identity,
+            &bonded,
+            &crate::validation::MIN_BOND_DURATION,
+            &false,
+            &0_u64,
+        );
+        let governor = Address::generate(&e);
+        client.initialize_governance(&admin, &Vec::from_array(&e, [governor.clone()]), &5_100, &1);
+
+        // Start partly slashed so that adding a near-max amount would overflow unchecked.
+        let partial = 1 + rng.gen_i128_nonneg(bonded - 1);
+        client.slash(&admin, &identity, &partial);
+
+        for _ in 0..3 {
+            let before = client.get_identity_state(&identity);
+            let amount = sample_amount_near_max(&mut rng, bonded);
+            let after = if rng.next_bool() {
+                let id = client.propose_slash(&admin, &identity, &amount);
+                client.governance_vote(&governor, &id, &true);
+                let after = client.execute_slash_with_governance(&admin, &id);
+                assert_eq!(
+                    after.slashed_amount,
+                    before.slashed_amount.saturating_add(amount).min(bonded),
+                    "iter={iter} governance slash not capped (amount={amount})"
+                );
+                after
+            } else {
+                let res = client.try_slash_bond(&admin, &identity, &amount);
+                let after = client.get_identity_state(&identity);
+                if res.is_err() {
+                    assert_eq!(after, before, "iter={iter} rejected slash changed the bond");
+                }
+                after
+            };
+            assert_bond_invariants(&after);
+            assert!(
+                after.slashed_amount >= before.slashed_amount,
+                "iter={iter} slashed_amount wrapped (before={}, after={}, amount={amount})",
+                before.slashed_amount,
+                after.slashed_amount
+            );
+            let total = catch_unwind(AssertUnwindSafe(|| {
+                e.as_contract(&client.address, || {
+                    crate::slash_history::get_total_slashed_from_history(&e, &identity)
+                })
+            }));
+            match total {
+                Ok(total) => assert!(
+                    total >= after.slashed_amount,
+                    "iter={iter} slash history total wrapped ({total})"
+                ),
+                Err(err) => {
+                    assert!(panic_msg(&*err).contains("slash history total overflow"));
+                    // The panic left the contract frame open, so this env takes no more calls.
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn fuzz_governance_slash_max_amount_is_capped() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(
+        &identity,
+        &10_000_000,
+        &crate::validation::MIN_BOND_DURATION,
+        &false,
+        &0_u64,
+    );
+    let governor = Address::generate(&e);
+    client.initialize_governance(&admin, &Vec::from_array(&e, [governor.clone()]), &5_100, &1);
+    client.slash(&admin, &identity, &1);
+
+    let id = client.propose_slash(&admin, &identity, &i128::MAX);
+    client.governance_vote(&governor, &id, &true);
+    let bond = client.execute_slash_with_governance(&admin, &id);
+    assert_eq!(bond.slashed_amount, bond.bonded_amount);
+}
+
+#[test]
+fn fuzz_negative_slash_amounts_rejected() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(
+        &identity,
+        &10_000_000,
+        &crate::validation::MIN_BOND_DURATION,
+        &false,
+        &0_u64,
+    );
+    for amount in [-1, i128::MIN] {
+        assert!(client
+            .try_propose_slash(&admin, &identity, &amount)
+            .is_err());
+        assert!(client.try_slash_bond(&admin, &identity, &amount).is_err());
+    }
+    assert_eq!(client.get_identity_state(&identity).slashed_amount, 0);
+}
@@ -3,8 +3,39 @@
 //! Multi-signature verification for slash requests: proposals are created, governors vote
 //! (with optional delegation), and slashing is executed only when quorum and approval
 //! requirements are met. Emits governance events for audit.
+//!
+//! Governors under investigation can be suspended instead of removed: they stay in the stored
+//! list, but cannot propose, vote or dispute, their votes are discounted from tallies and they
+//! are excluded from the quorum denominator until reinstated.
+//!
+//! In bond-weighted mode each governor's `bonded_amount - slashed_amount` is snapshotted when a
+//! proposal is created and approval is decided by snapshot weight instead of head count, so
+//! topping up around a vote has no effect. Quorum is still counted in governors.
+//!
+//! An optional minimum governor bond gives members skin in the game: each governor must hold a
+//! net bond (`bonded_amount - slashed_amount`) of at least `min_governor_bond` when added and
+//! whenever they vote. A member whose bond dropped below it (withdrawal or slashing) has votes
+//! rejected with "governor under-bonded" until they top up. 0 disables the requirement.
+//!
+//! Proposal creation can be rate limited per proposer (maximum open proposals and a cooldown
+//! between proposals); closing a proposal frees its slot.
+//!
+//! Governor votes are stored as `u128` bitmaps over the member roster (bit `i` = roster index
+//! `i`), so governance is capped at 128 members. Each `initialize_governance` call stores a new
+//! roster version and proposals remember the version they were created under, so their bits
+//! always decode against the right list. Only votes from delegates outside the roster keep a
+//! per-address record. Address lists are produced at the view layer. A proposal accepts at most
+//! as many vote records (roster bits plus outside-delegate records) as its roster has members;
+//! further votes panic with "approval list full".
+//!
+//! Approved requests against one identity execute in id order, so which request gets capped at
+//! the remaining bond never depends on caller timing. Open request ids are indexed per identity;
+//! executing a request panics with "earlier request pending execution" while an older open
+//! request against the same identity is approved, unless that older request is disputed or has
+//! outlived the execution window (0, the default, means requests never expire). Expired requests
+//! can no longer be executed; anyone can close them as `Expired`.
 
-use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+use soroban_sdk::{contracttype, Address, Env, Vec};
 
 /// Status of a slash proposal.
 #[contracttype]
@@ -16,13 +47,19 @@ pub enum ProposalStatus {
     Executed,
     /// Rejected (quorum not met or majority against).
     Rejected,
+    /// Closed by the identity's accepted settlement offer (offered amount slashed).
+    Settled,
+    /// Closed without a slash after outliving the execution window.
+    Expired,
 }
 
-/// A slash proposal: amount to slash, proposer, and execution state.
+/// A slash proposal: target bond, amount to slash, proposer, and execution state.
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct SlashProposal {
     pub id: u64,
+    /// Identity whose bond is slashed on execution.
+    pub identity: Address,
     pub amount: i128,
     pub proposed_by: Address,
     pub proposed_at: u64,
@@ -57,6 +94,77 @@ fn key_min_governors() -> crate::DataKey {
     crate::DataKey::GovernanceMinGovernors
 }
 
+/// Maximum governors addressable by a ballot bitmap.
+pub const MAX_GOVERNORS: u32 = 128;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum MemberKey {
+    /// Suspension marker for a governor; absent means active.
+    Suspended(Address),
+    /// Current roster version (0 before governance is initialized).
+    Version,
+    /// Ordered governor list of a roster version; a governor's position is its bit index.
+    Roster(u32),
+    /// Governor votes on a proposal.
+    Ballot(u64),
+    /// Number of per-address votes recorded on a proposal by delegates outside its roster.
+    OutsideVotes(u64),
+    /// Minimum net bond a governor must hold to be added or to vote (absent means 0, disabled).
+    MinBond,
+}
+
+/// Governor votes on a proposal as bitmaps over the roster it was created under.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Ballot {
+    roster_version: u32,
+    /// Bit set when the governor at that index has voted.
+    voted: u128,
+    /// Bit set when the governor at that index voted to approve.
+    approvals: u128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum WeightKey {
+    /// Bond-weighted mode flag for new proposals.
+    Enabled,
+    /// Total snapshot weight of a proposal; present only for bond-weighted proposals.
+    Total(u64),
+    /// A governor's snapshot weight for a proposal (absent means zero).
+    Power(u64, Address),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum QueueKey {
+    /// Ids of open proposals against an identity, in creation order.
+    Open(Address),
+    /// Seconds after creation during which a proposal may be executed (absent means 0, no expiry).
+    ExecutionWindow,
+}
+
+/// Per-proposer limits on slash proposal creation; 0 disables a limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalRateLimit {
+    /// Maximum proposals a proposer may have open at once.
+    pub max_open: u32,
+    /// Minimum seconds between two proposals from the same proposer.
+    pub cooldown_secs: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum RateKey {
+    Config,
+    /// Open proposals created by a proposer.
+    Open(Address),
+    /// Timestamp of a proposer's latest proposal.
+    LastProposedAt(Address),
+}
+
 fn is_governor(governors: &Vec<Address>, addr: &Address) -> bool {
     for g in governors.iter() {
         if g == addr.clone() {
@@ -66,6 +174,428 @@ fn is_governor(governors: &Vec<Address>, addr: &Address) -> bool {
     false
 }
 
+fn roster_version(e: &Env) -> u32 {
+    e.storage().instance().get(&MemberKey::Version).unwrap_or(0)
+}
+
+fn roster(e: &Env, version: u32) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&MemberKey::Roster(version))
+        .unwrap_or_else(|| get_governors(e))
+}
+
+fn roster_index(roster: &Vec<Address>, addr: &Address) -> Option<u32> {
+    roster.first_index_of(addr)
+}
+
+fn bit(index: u32) -> u128 {
+    1_u128 << index
+}
+
+fn load_ballot(e: &Env, proposal_id: u64) -> Ballot {
+    e.storage()
+        .instance()
+        .get(&MemberKey::Ballot(proposal_id))
+        .unwrap_or(Ballot {
+            roster_version: roster_version(e),
+            voted: 0,
+            approvals: 0,
+        })
+}
+
+/// Vote recorded for `voter`: from the ballot bits for roster members, otherwise from the
+/// per-address record kept for outside delegates.
+fn recorded_vote(
+    e: &Env,
+    proposal_id: u64,
+    ballot: &Ballot,
+    roster: &Vec<Address>,
+    voter: &Address,
+) -> Option<bool> {
+    match roster_index(roster, voter) {
+        Some(i) if ballot.voted & bit(i) != 0 => Some(ballot.approvals & bit(i) != 0),
+        Some(_) => None,
+        None => e
+            .storage()
+            .instance()
+            .get(&key_vote(proposal_id, voter.clone())),
+    }
+}
+
+/// Store a vote without eligibility checks (callers validate first).
+pub(crate) fn record_vote(e: &Env, proposal_id: u64, voter: &Address, approve: bool) {
+    let mut ballot = load_ballot(e, proposal_id);
+    let roster = roster(e, ballot.roster_version);
+    let Some(i) = roster_index(&roster, voter) else {
+        e.storage()
+            .instance()
+            .set(&key_vote(proposal_id, voter.clone()), &approve);
+        let outside = outside_votes(e, proposal_id)
+            .checked_add(1)
+            .expect("vote count overflow");
+        e.storage()
+            .instance()
+            .set(&MemberKey::OutsideVotes(proposal_id), &outside);
+        return;
+    };
+    ballot.voted |= bit(i);
+    if approve {
+        ballot.approvals |= bit(i);
+    }
+    e.storage()
+        .instance()
+        .set(&MemberKey::Ballot(proposal_id), &ballot);
+}
+
+fn outside_votes(e: &Env, proposal_id: u64) -> u32 {
+    e.storage()
+        .instance()
+        .get(&MemberKey::OutsideVotes(proposal_id))
+        .unwrap_or(0)
+}
+
+/// Vote records stored for a proposal: roster members who voted plus outside delegates.
+fn recorded_votes(e: &Env, proposal_id: u64, ballot: &Ballot) -> u32 {
+    ballot
+        .voted
+        .count_ones()
+        .saturating_add(outside_votes(e, proposal_id))
+}
+
+/// Counted votes as (roster, voted bitmap, approvals bitmap): one bit per active governor,
+/// taken from their delegate's vote when they delegated.
+fn effective_votes(e: &Env, proposal_id: u64) -> (Vec<Address>, u128, u128) {
+    let ballot = load_ballot(e, proposal_id);
+    let roster = roster(e, ballot.roster_version);
+    let mut voted = 0_u128;
+    let mut approvals = 0_u128;
+    for (i, g) in roster.iter().enumerate() {
+        if is_suspended(e, &g) {
+            continue;
+        }
+        let voter = effective_voter(e, &g);
+        if let Some(v) = recorded_vote(e, proposal_id, &ballot, &roster, &voter) {
+            voted |= bit(i as u32);
+            if v {
+                approvals |= bit(i as u32);
+            }
+        }
+    }
+    (roster, voted, approvals)
+}
+
+/// Returns true if `member` is currently suspended.
+#[must_use]
+pub fn is_suspended(e: &Env, member: &Address) -> bool {
+    e.storage()
+        .instance()
+        .get(&MemberKey::Suspended(member.clone()))
+        .unwrap_or(false)
+}
+
+/// Returns true if `addr` is a governor and not suspended.
+#[must_use]
+pub fn is_active_member(e: &Env, addr: &Address) -> bool {
+    is_governor(&get_governors(e), addr) && !is_suspended(e, addr)
+}
+
+/// Suspend a governor. Admin only (enforced by caller).
+pub fn suspend_member(e: &Env, member: &Address) {
+    if !is_governor(&get_governors(e), member) {
+        panic!("not a governor");
+    }
+    if is_suspended(e, member) {
+        panic!("member already suspended");
+    }
+    e.storage()
+        .instance()
+        .set(&MemberKey::Suspended(member.clone()), &true);
+    emit_governance_event(e, "governance_member_suspended", 0, member, 0_i128);
+}
+
+/// Reinstate a suspended governor. Admin only (enforced by caller).
+pub fn reinstate_member(e: &Env, member: &Address) {
+    if !is_suspended(e, member) {
+        panic!("member not suspended");
+    }
+    e.storage()
+        .instance()
+        .remove(&MemberKey::Suspended(member.clone()));
+    emit_governance_event(e, "governance_member_reinstated", 0, member, 0_i128);
+}
+
+/// Returns true if new proposals are bond-weighted.
+#[must_use]
+pub fn is_bond_weighted(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&WeightKey::Enabled)
+        .unwrap_or(false)
+}
+
+/// Returns true once the weight mode has been stored.
+#[must_use]
+pub fn has_weight_mode(e: &Env) -> bool {
+    e.storage().instance().has(&WeightKey::Enabled)
+}
+
+/// Enable or disable bond-weighted voting for proposals created from now on. Admin only
+/// (enforced by caller); existing proposals keep the mode they were created with.
+pub fn set_bond_weighted(e: &Env, enabled: bool) {
+    e.storage().instance().set(&WeightKey::Enabled, &enabled);
+    crate::events::publish(e, "governance_weight_mode", enabled);
+}
+
+/// `member`'s active bond net of slashing (0 without an active bond).
+#[must_use]
+pub fn net_bond(e: &Env, member: &Address) -> i128 {
+    let bond: Option<crate::IdentityBond> = e
+        .storage()
+        .instance()
+        .get(&crate::DataKey::Bond(member.clone()));
+    match &bond {
+        Some(b) if b.active => b
+            .bonded_amount
+            .checked_sub(b.slashed_amount)
+            .unwrap_or(0)
+            .max(0),
+        _ => 0,
+    }
+}
+
+/// Minimum net bond required of governors (0 when disabled).
+#[must_use]
+pub fn get_min_governor_bond(e: &Env) -> i128 {
+    e.storage().instance().get(&MemberKey::MinBond).unwrap_or(0)
+}
+
+/// Set the minimum governor bond; 0 disables it. Admin only (enforced by caller). Current
+/// members are checked lazily when they next vote.
+pub fn set_min_governor_bond(e: &Env, min_bond: i128) {
+    if min_bond < 0 {
+        panic!("min governor bond must be non-negative");
+    }
+    e.storage().instance().set(&MemberKey::MinBond, &min_bond);
+    crate::events::publish(e, "governance_min_bond_set", min_bond);
+}
+
+/// True if `member`'s net bond meets the minimum governor bond (always true when disabled).
+#[must_use]
+pub fn meets_min_bond(e: &Env, member: &Address) -> bool {
+    let min_bond = get_min_governor_bond(e);
+    min_bond == 0 || net_bond(e, member) >= min_bond
+}
+
+/// # Panics
+/// - "governor under-bonded" if `member`'s net bond is below the configured minimum.
+fn require_bonded(e: &Env, member: &Address) {
+    if !meets_min_bond(e, member) {
+        panic!("governor under-bonded");
+    }
+}
+
+/// Snapshot every governor's net bond for `proposal_id`. Returns the total weight.
+fn snapshot_voting_power(e: &Env, proposal_id: u64) -> i128 {
+    let mut total: i128 = 0;
+    for g in get_governors(e).iter() {
+        let power = net_bond(e, &g);
+        if power > 0 {
+            e.storage()
+                .instance()
+                .set(&WeightKey::Power(proposal_id, g), &power);
+            total = total.checked_add(power).expect("voting power overflow");
+        }
+    }
+    e.storage()
+        .instance()
+        .set(&WeightKey::Total(proposal_id), &total);
+    total
+}
+
+fn is_weighted_proposal(e: &Env, proposal_id: u64) -> bool {
+    e.storage().instance().has(&WeightKey::Total(proposal_id))
+}
+
+/// Voting power of `governor` on `proposal_id`: the snapshot weight for bond-weighted
+/// proposals, otherwise 1 for an active governor and 0 for anyone else.
+#[must_use]
+pub fn get_voting_power(e: &Env, proposal_id: u64, governor: &Address) -> i128 {
+    if is_weighted_proposal(e, proposal_id) {
+        return e
+            .storage()
+            .instance()
+            .get(&WeightKey::Power(proposal_id, governor.clone()))
+            .unwrap_or(0);
+    }
+    if is_active_member(e, governor) {
+        1
+    } else {
+        0
+    }
+}
+
+/// Get the proposal rate limit (both limits 0 when unset).
+#[must_use]
+pub fn get_rate_limit(e: &Env) -> ProposalRateLimit {
+    e.storage()
+        .instance()
+        .get(&RateKey::Config)
+        .unwrap_or(ProposalRateLimit {
+            max_open: 0,
+            cooldown_secs: 0,
+        })
+}
+
+/// Returns true once a proposal rate limit has been stored.
+#[must_use]
+pub fn has_rate_limit(e: &Env) -> bool {
+    e.storage().instance().has(&RateKey::Config)
+}
+
+/// Set the proposal rate limit. Admin only (enforced by caller).
+pub fn set_rate_limit(e: &Env, max_open: u32, cooldown_secs: u64) {
+    let limit = ProposalRateLimit {
+        max_open,
+        cooldown_secs,
+    };
+    e.storage().instance().set(&RateKey::Config, &limit);
+    crate::events::publish(e, "proposal_rate_limit_set", (max_open, cooldown_secs));
+}
+
+/// Number of open proposals created by `proposer`.
+#[must_use]
+pub fn get_open_count(e: &Env, proposer: &Address) -> u32 {
+    e.storage()
+        .instance()
+        .get(&RateKey::Open(proposer.clone()))
+        .unwrap_or(0)
+}
+
+fn set_open_count(e: &Env, proposer: &Address, count: u32) {
+    let key = RateKey::Open(proposer.clone());
+    if count == 0 {
+        e.storage().instance().remove(&key);
+    } else {
+        e.storage().instance().set(&key, &count);
+    }
+}
+
+/// Timestamp at which `proposer`'s proposal cooldown ends, or 0 when none is running.
+#[must_use]
+pub fn retry_after(e: &Env, proposer: &Address) -> u64 {
+    let cooldown_secs = get_rate_limit(e).cooldown_secs;
+    if cooldown_secs == 0 {
+        return 0;
+    }
+    let last: Option<u64> = e
+        .storage()
+        .instance()
+        .get(&RateKey::LastProposedAt(proposer.clone()));
+    match last {
+        Some(last) if e.ledger().timestamp() < last.saturating_add(cooldown_secs) => {
+            last.saturating_add(cooldown_secs)
+        }
+        _ => 0,
+    }
+}
+
+/// Enforce the rate limit for a new proposal from `proposer`.
+///
+/// # Panics
+/// - "proposal rate limited: too many open proposals" when `max_open` is reached.
+/// - "proposal rate limited: retry after <timestamp>" while the cooldown is running.
+fn enforce_rate_limit(e: &Env, proposer: &Address) {
+    let limit = get_rate_limit(e);
+    if limit.max_open > 0 && get_open_count(e, proposer) >= limit.max_open {
+        panic!("proposal rate limited: too many open proposals");
+    }
+    let retry_after = retry_after(e, proposer);
+    if retry_after > 0 {
+        panic!("proposal rate limited: retry after {}", retry_after);
+    }
+}
+
+/// Ids of open proposals against `identity`, in creation order.
+#[must_use]
+pub fn open_request_ids(e: &Env, identity: &Address) -> Vec<u64> {
+    e.storage()
+        .instance()
+        .get(&QueueKey::Open(identity.clone()))
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+fn set_open_request_ids(e: &Env, identity: &Address, ids: &Vec<u64>) {
+    let key = QueueKey::Open(identity.clone());
+    if ids.is_empty() {
+        e.storage().instance().remove(&key);
+    } else {
+        e.storage().instance().set(&key, ids);
+    }
+}
+
+/// Add an open proposal to its identity's index (also used when seeding proposals).
+pub(crate) fn index_open_request(e: &Env, identity: &Address, proposal_id: u64) {
+    let mut ids = open_request_ids(e, identity);
+    ids.push_back(proposal_id);
+    set_open_request_ids(e, identity, &ids);
+}
+
+fn unindex_open_request(e: &Env, identity: &Address, proposal_id: u64) {
+    let mut ids = open_request_ids(e, identity);
+    if let Some(i) = ids.first_index_of(proposal_id) {
+        ids.remove(i);
+        set_open_request_ids(e, identity, &ids);
+    }
+}
+
+/// Execution window in seconds (0 means proposals never expire).
+#[must_use]
+pub fn get_execution_window(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&QueueKey::ExecutionWindow)
+        .unwrap_or(0)
+}
+
+/// Set the execution window. Admin only (enforced by caller).
+pub fn set_execution_window(e: &Env, window_secs: u64) {
+    e.storage()
+        .instance()
+        .set(&QueueKey::ExecutionWindow, &window_secs);
+    crate::events::publish(e, "execution_window_set", window_secs);
+}
+
+/// True once `proposal` has outlived the execution window.
+#[must_use]
+pub fn is_expired(e: &Env, proposal: &SlashProposal) -> bool {
+    let window = get_execution_window(e);
+    window > 0 && e.ledger().timestamp() >= proposal.proposed_at.saturating_add(window)
+}
+
+/// Enforce per-identity FIFO execution: no older approved request against the same identity
+/// may still be waiting, unless it is disputed or expired.
+///
+/// # Panics
+/// - "earlier request pending execution".
+fn require_no_earlier_pending(e: &Env, proposal: &SlashProposal) {
+    for id in open_request_ids(e, &proposal.identity).iter() {
+        if id >= proposal.id {
+            continue;
+        }
+        let Some(earlier) = get_proposal(e, id) else {
+            continue;
+        };
+        if earlier.status == ProposalStatus::Open
+            && !is_expired(e, &earlier)
+            && crate::dispute_bond::get_disputers(e, id).is_empty()
+            && is_approved(e, id)
+        {
+            panic!("earlier request pending execution");
+        }
+    }
+}
+
 /// Initialize governance: set governors and quorum. Admin only (enforced by caller).
 pub fn initialize_governance(
     e: &Env,
@@ -76,36 +606,87 @@ pub fn initialize_governance(
     if quorum_bps > 10_000 {
         panic!("quorum_bps must be <= 10000");
     }
+    if governors.len() > MAX_GOVERNORS {
+        panic!("too many governors");
+    }
+    for g in governors.iter() {
+        require_bonded(e, &g);
+    }
+    let version = roster_version(e)
+        .checked_add(1)
+        .expect("roster version overflow");
+    e.storage()
+        .instance()
+        .set(&MemberKey::Roster(version), &governors);
+    e.storage().instance().set(&MemberKey::Version, &version);
     e.storage().instance().set(&key_governors(), &governors);
     e.storage().instance().set(&key_quorum_bps(), &quorum_bps);
     e.storage()
         .instance()
         .set(&key_min_governors(), &min_governors);
-    e.storage().instance().set(&key_next_id(), &0_u64);
+    // Roster changes re-run this; restarting the ids would overwrite existing proposals.
+    if !e.storage().instance().has(&key_next_id()) {
+        e.storage().instance().set(&key_next_id(), &0_u64);
+    }
 }
 
-/// Create a new slash proposal. Caller must be admin or governor. Returns proposal id.
-pub fn propose_slash(e: &Env, proposer: &Address, amount: i128) -> u64 {
+/// Create a new slash proposal against `identity`'s bond. Caller must be admin or governor.
+/// Returns proposal id.
+pub fn propose_slash(e: &Env, proposer: &Address, identity: &Address, amount: i128) -> u64 {
     if amount <= 0 {
         panic!("slash amount must be positive");
     }
+    enforce_rate_limit(e, proposer);
     let id: u64 = e.storage().instance().get(&key_next_id()).unwrap_or(0);
     let next_id = id.checked_add(1).expect("proposal id overflow");
     e.storage().instance().set(&key_next_id(), &next_id);
 
     let proposal = SlashProposal {
         id,
+        identity: identity.clone(),
         amount,
         proposed_by: proposer.clone(),
         proposed_at: e.ledger().timestamp(),
         status: ProposalStatus::Open,
     };
     e.storage().instance().set(&key_proposal(id), &proposal);
-    emit_governance_event(e, "slash_proposed", id, proposer, amount);
+    index_open_request(e, identity, id);
+    crate::slash_pipeline::record_submitted(e, id);
+    e.storage().instance().set(
+        &MemberKey::Ballot(id),
+        &Ballot {
+            roster_version: roster_version(e),
+            voted: 0,
+            approvals: 0,
+        },
+    );
+    let open = get_open_count(e, proposer)
+        .checked_add(1)
+        .expect("open proposal count overflow");
+    set_open_count(e, proposer, open);
+    e.storage().instance().set(
+        &RateKey::LastProposedAt(proposer.clone()),
+        &proposal.proposed_at,
+    );
+    let snapshot_weight = if is_bond_weighted(e) {
+        snapshot_voting_power(e, id)
+    } else {
+        0
+    };
+    crate::events::publish(
+        e,
+        "slash_proposed",
+        (id, proposer.clone(), amount, snapshot_weight),
+    );
+    crate::journal::append(e, identity, "slash_proposed", amount, id);
     id
 }
 
 /// Record a vote (approve = true, reject = false). Caller must be a governor or delegate.
+///
+/// # Panics
+/// - "approval list full" once the proposal holds as many vote records as its roster has
+///   members.
 pub fn vote(e: &Env, voter: &Address, proposal_id: u64, approve: bool) {
     let proposal: SlashProposal = e
         .storage()
@@ -115,13 +696,19 @@ pub fn vote(e: &Env, voter: &Address, proposal_id: u64, approve: bool) {
     if proposal.status != ProposalStatus::Open {
         panic!("proposal not open for voting");
     }
-    let governors: Vec<Address> = e
-        .storage()
-        .instance()
-        .get(&key_governors())
-        .unwrap_or_else(|| panic!("governance not initialized"));
+    if !e.storage().instance().has(&key_governors()) {
+        panic!("governance not initialized");
+    }
+    if is_suspended(e, voter) {
+        panic!("governance member suspended");
+    }
+    let ballot = load_ballot(e, proposal_id);
+    let governors = roster(e, ballot.roster_version);
     let is_gov = is_governor(&governors, voter);
     let is_delegate_of_some = governors.iter().any(|g| {
+        if is_suspended(e, &g) {
+            return false;
+        }
         let d: Option<Address> = e.storage().instance().get(&key_delegate(g.clone()));
         d.as_ref() == Some(voter)
     });
@@ -129,11 +716,22 @@ pub fn vote(e: &Env, voter: &Address, proposal_id: u64, approve: bool) {
     if !can_vote {
         panic!("not a governor or delegate");
     }
-    let vote_key = key_vote(proposal_id, voter.clone());
-    if e.storage().instance().has(&vote_key) {
+    // The bond requirement applies to each governor the vote is counted for.
+    for g in governors.iter() {
+        if g == *voter || (!is_suspended(e, &g) && effective_voter(e, &g) == *voter) {
+            require_bonded(e, &g);
+        }
+    }
+    if recorded_vote(e, proposal_id, &ballot, &governors, voter).is_some() {
         panic!("already voted");
     }
-    e.storage().instance().set(&vote_key, &approve);
+    if recorded_votes(e, proposal_id, &ballot) >= governors.len() {
+        panic!("approval list full");
+    }
+    record_vote(e, proposal_id, voter, approve);
+    if is_approved(e, proposal_id) {
+        crate::slash_pipeline::record_approved(e, proposal_id);
+    }
     emit_governance_event(
         e,
         "governance_vote",
@@ -168,38 +766,59 @@ fn effective_voter(e: &Env, governor: &Address) -> Address {
 
 /// Count votes for a proposal: (approve_count, reject_count, total_voted).
 fn count_votes(e: &Env, proposal_id: u64) -> (u32, u32, u32) {
-    let governors: Vec<Address> = e
-        .storage()
-        .instance()
-        .get(&key_governors())
-        .unwrap_or(Vec::new(e));
-    let mut approve = 0u32;
-    let mut reject = 0u32;
-    let mut voted = 0u32;
-    for g in governors.iter() {
-        let effective = effective_voter(e, &g);
-        let vote_key = key_vote(proposal_id, effective);
-        if e.storage().instance().has(&vote_key) {
-            voted += 1;
-            let v: bool = e.storage().instance().get(&vote_key).unwrap();
-            if v {
-                approve += 1;
-            } else {
-                reject += 1;
-            }
+    let (_, voted, approvals) = effective_votes(e, proposal_id);
+    let approve = approvals.count_ones();
+    let total = voted.count_ones();
+    (approve, total - approve, total)
+}
+
+/// Sum snapshot weights of cast votes: (approve_weight, total_voted_weight).
+fn weigh_votes(e: &Env, proposal_id: u64) -> (i128, i128) {
+    let (roster, voted_bits, approvals) = effective_votes(e, proposal_id);
+    let mut approve: i128 = 0;
+    let mut voted: i128 = 0;
+    for (i, g) in roster.iter().enumerate() {
+        if voted_bits & bit(i as u32) == 0 {
+            continue;
         }
+        let power = get_voting_power(e, proposal_id, &g);
+        voted = voted.checked_add(power).expect("voting power overflow");
+        if approvals & bit(i as u32) != 0 {
+            approve = approve.checked_add(power).expect("voting power overflow");
+        }
+    }
+    (approve, voted)
+}
+
+/// Majority of cast votes approve: by snapshot weight for bond-weighted proposals, otherwise
+/// by head count.
+fn majority_approves(e: &Env, proposal_id: u64, approve: u32, voted: u32) -> bool {
+    if is_weighted_proposal(e, proposal_id) {
+        let (approve_weight, voted_weight) = weigh_votes(e, proposal_id);
+        return voted_weight > 0 && approve_weight > voted_weight / 2;
     }
-    (approve, reject, voted)
+    approve > voted / 2
 }
 
 /// Check if quorum is met and majority approve.
 pub fn is_approved(e: &Env, proposal_id: u64) -> bool {
-    let governors: Vec<Address> = e
-        .storage()
-        .instance()
-        .get(&key_governors())
-        .unwrap_or(Vec::new(e));
-    let total = governors.len() as u32;
+    let (approve, _reject, voted) = count_votes(e, proposal_id);
+    quorum_met(e, proposal_id, voted)
+        && voted > 0
+        && majority_approves(e, proposal_id, approve, voted)
+}
+
+/// Check if quorum is met without a majority approving (slash request contested).
+pub fn is_rejected(e: &Env, proposal_id: u64) -> bool {
+    let (approve, _reject, voted) = count_votes(e, proposal_id);
+    quorum_met(e, proposal_id, voted)
+        && voted > 0
+        && !majority_approves(e, proposal_id, approve, voted)
+}
+
+fn quorum_met(e: &Env, proposal_id: u64, voted: u32) -> bool {
+    let governors = roster(e, load_ballot(e, proposal_id).roster_version);
+    let total = governors.iter().filter(|g| !is_suspended(e, g)).count() as u32;
     if total == 0 {
         return false;
     }
@@ -213,13 +832,14 @@ pub fn is_approved(e: &Env, proposal_id: u64) -> bool {
         .instance()
         .get(&key_min_governors())
         .unwrap_or(1);
-    let (approve, _reject, voted) = count_votes(e, proposal_id);
-    let quorum_ok = voted >= (total * quorum_bps / 10_000).max(min_governors);
-    let majority_approve = voted > 0 && approve > voted / 2;
-    quorum_ok && majority_approve
+    voted >= (total * quorum_bps / 10_000).max(min_governors)
 }
 
 /// Execute slash for an approved proposal. Returns true if executed.
+///
+/// # Panics
+/// - "proposal expired" when an approved proposal outlived the execution window.
+/// - "earlier request pending execution" (see `require_no_earlier_pending`).
 pub fn execute_slash_if_approved(e: &Env, proposal_id: u64) -> bool {
     let mut proposal: SlashProposal = e
         .storage()
@@ -229,11 +849,15 @@ pub fn execute_slash_if_approved(e: &Env, proposal_id: u64) -> bool {
     if proposal.status != ProposalStatus::Open {
         panic!("proposal already closed");
     }
+    let open = get_open_count(e, &proposal.proposed_by);
+    set_open_count(e, &proposal.proposed_by, open.saturating_sub(1));
+    unindex_open_request(e, &proposal.identity, proposal_id);
     if !is_approved(e, proposal_id) {
         proposal.status = ProposalStatus::Rejected;
         e.storage()
             .instance()
             .set(&key_proposal(proposal_id), &proposal);
+        crate::slash_pipeline::record_rejected(e, proposal_id);
         emit_governance_event(
             e,
             "slash_proposal_rejected",
@@ -243,10 +867,17 @@ pub fn execute_slash_if_approved(e: &Env, proposal_id: u64) -> bool {
         );
         return false;
     }
+    if is_expired(e, &proposal) {
+        panic!("proposal expired");
+    }
+    require_no_earlier_pending(e, &proposal);
     proposal.status = ProposalStatus::Executed;
     e.storage()
         .instance()
         .set(&key_proposal(proposal_id), &proposal);
+    // Approval may have been reached without a vote (e.g. a suspension shrinking the quorum).
+    crate::slash_pipeline::record_approved(e, proposal_id);
+    crate::slash_pipeline::record_executed(e, proposal_id);
     emit_governance_event(
         e,
         "slash_proposal_executed",
@@ -257,6 +888,82 @@ pub fn execute_slash_if_approved(e: &Env, proposal_id: u64) -> bool {
     true
 }
 
+/// Close an open proposal as settled and free the proposer's open slot. Returns the proposal.
+///
+/// # Panics
+/// - "proposal not found", "proposal already closed".
+pub fn mark_settled(e: &Env, proposal_id: u64) -> SlashProposal {
+    let mut proposal = take_open(e, proposal_id);
+    proposal.status = ProposalStatus::Settled;
+    e.storage()
+        .instance()
+        .set(&key_proposal(proposal_id), &proposal);
+    crate::slash_pipeline::record_executed(e, proposal_id);
+    proposal
+}
+
+/// Close an open proposal that outlived the execution window as expired and free the
+/// proposer's open slot. Returns the proposal.
+///
+/// # Panics
+/// - "proposal not found", "proposal already closed", "proposal not expired".
+pub fn mark_expired(e: &Env, proposal_id: u64) -> SlashProposal {
+    let mut proposal = take_open(e, proposal_id);
+    if !is_expired(e, &proposal) {
+        panic!("proposal not expired");
+    }
+    proposal.status = ProposalStatus::Expired;
+    e.storage()
+        .instance()
+        .set(&key_proposal(proposal_id), &proposal);
+    crate::slash_pipeline::record_rejected(e, proposal_id);
+    emit_governance_event(
+        e,
+        "slash_proposal_expired",
+        proposal_id,
+        &proposal.proposed_by,
+        proposal.amount,
+    );
+    proposal
+}
+
+/// Load an open proposal, free the proposer's open slot and drop it from its identity's index.
+fn take_open(e: &Env, proposal_id: u64) -> SlashProposal {
+    let proposal: SlashProposal = e
+        .storage()
+        .instance()
+        .get(&key_proposal(proposal_id))
+        .unwrap_or_else(|| panic!("proposal not found"));
+    if proposal.status != ProposalStatus::Open {
+        panic!("proposal already closed");
+    }
+    let open = get_open_count(e, &proposal.proposed_by);
+    set_open_count(e, &proposal.proposed_by, open.saturating_sub(1));
+    unindex_open_request(e, &proposal.identity, proposal_id);
+    proposal
+}
+
+/// Returns true if any slash proposal against `identity` is still open.
+#[must_use]
+pub fn has_open_proposal(e: &Env, identity: &Address) -> bool {
+    !open_request_ids(e, identity).is_empty()
+}
+
+/// All open slash proposals, in id order.
+#[must_use]
+pub fn open_proposals(e: &Env) -> Vec<SlashProposal> {
+    let next_id: u64 = e.storage().instance().get(&key_next_id()).unwrap_or(0);
+    let mut out = Vec::new(e);
+    for id in 0..next_id {
+        if let Some(p) = get_proposal(e, id) {
+            if p.status == ProposalStatus::Open {
+                out.push_back(p);
+            }
+        }
+    }
+    out
+}
+
 /// Get proposal by id.
 pub fn get_proposal(e: &Env, proposal_id: u64) -> Option<SlashProposal> {
     e.storage().instance().get(&key_proposal(proposal_id))
@@ -264,12 +971,21 @@ pub fn get_proposal(e: &Env, proposal_id: u64) -> Option<SlashProposal> {
 
 /// Get vote for (proposal_id, voter). Returns None if not voted.
 pub fn get_vote(e: &Env, proposal_id: u64, voter: &Address) -> Option<bool> {
-    let key = key_vote(proposal_id, voter.clone());
-    if e.storage().instance().has(&key) {
-        e.storage().instance().get(&key)
-    } else {
-        None
+    let ballot = load_ballot(e, proposal_id);
+    let roster = roster(e, ballot.roster_version);
+    recorded_vote(e, proposal_id, &ballot, &roster, voter)
+}
+
+/// Governors whose own vote on `proposal_id` approves, in roster order.
+pub fn get_approvers(e: &Env, proposal_id: u64) -> Vec<Address> {
+    let ballot = load_ballot(e, proposal_id);
+    let mut out = Vec::new(e);
+    for (i, g) in roster(e, ballot.roster_version).iter().enumerate() {
+        if ballot.approvals & bit(i as u32) != 0 {
+            out.push_back(g);
+        }
     }
+    out
 }
 
 /// Get governors list.
@@ -301,8 +1017,5 @@ pub fn get_quorum_config(e: &Env) -> (u32, u32) {
 }
 
 fn emit_governance_event(e: &Env, topic: &str, proposal_id: u64, addr: &Address, amount: i128) {
-    e.events().publish(
-        (Symbol::new(e, topic),),
-        (proposal_id, addr.clone(), amount),
-    );
+    crate::events::publish(e, topic, (proposal_id, addr.clone(), amount));
 }
@@ -0,0 +1,173 @@
+//! Governor Fee Stream
+//!
+//! Compensates governance members for reviewing slash requests with a small stream from the
+//! protocol fee pool. Each complete period of `period_secs` releases `bps_per_period` of the
+//! pool, split equally between the members eligible in that period: on the roster, not
+//! suspended and meeting the minimum governor bond. Shares are credited to per-member claimable
+//! balances and leave the fee pool, so `collect_fees` can no longer sweep them; members withdraw
+//! them with `claim_governor_fees`.
+//!
+//! Accrual happens only when `distribute` runs (permissionless). Eligibility is read at that
+//! moment, so suspensions and reinstatements settle the elapsed periods first; bond changes do
+//! not, and keepers should distribute at least once per period. At most
+//! `MAX_PERIODS_PER_CALL` periods are settled per call; the rest carry over. Rounding
+//! remainders stay in the pool.
+
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+use crate::{governance_approval, math, token_integration};
+
+/// Largest share of the fee pool released per period (10%).
+pub const MAX_BPS_PER_PERIOD: u32 = 1_000;
+
+/// Periods settled by one `distribute` call.
+pub const MAX_PERIODS_PER_CALL: u64 = 52;
+
+/// @notice Stream configuration. `bps_per_period` of 0 disables accrual.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernorFeeConfig {
+    pub bps_per_period: u32,
+    pub period_secs: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum GovFeeKey {
+    Config,
+    /// Start of the first period not yet settled.
+    Checkpoint,
+    /// Fees credited to a member and not yet claimed.
+    Claimable(Address),
+}
+
+/// @notice Current stream configuration, if one was set.
+#[must_use]
+pub fn get_config(e: &Env) -> Option<GovernorFeeConfig> {
+    e.storage().instance().get(&GovFeeKey::Config)
+}
+
+/// @notice Sets the stream configuration (admin only; caller must enforce). Periods elapsed
+/// under the old configuration are settled first; the next period starts now.
+///
+/// # Panics
+/// - "period must be positive"
+/// - "governor fee bps too high" above `MAX_BPS_PER_PERIOD`.
+pub fn set_config(e: &Env, bps_per_period: u32, period_secs: u64) {
+    if period_secs == 0 {
+        panic!("period must be positive");
+    }
+    if bps_per_period > MAX_BPS_PER_PERIOD {
+        panic!("governor fee bps too high");
+    }
+    distribute(e);
+    let config = GovernorFeeConfig {
+        bps_per_period,
+        period_secs,
+    };
+    e.storage().instance().set(&GovFeeKey::Config, &config);
+    e.storage()
+        .instance()
+        .set(&GovFeeKey::Checkpoint, &e.ledger().timestamp());
+    crate::events::publish(e, "governor_fee_config_set", config);
+}
+
+/// @notice Fees credited to `member` and not yet claimed.
+#[must_use]
+pub fn get_claimable(e: &Env, member: &Address) -> i128 {
+    e.storage()
+        .instance()
+        .get(&GovFeeKey::Claimable(member.clone()))
+        .unwrap_or(0)
+}
+
+/// @notice Settles every complete period since the last checkpoint (up to
+/// `MAX_PERIODS_PER_CALL`), crediting eligible members. Emits `governor_fees_distributed` with
+/// `(periods, amount)` when any period was settled. Returns the amount credited.
+pub fn distribute(e: &Env) -> i128 {
+    let Some(config) = get_config(e) else {
+        return 0;
+    };
+    let checkpoint: u64 = e
+        .storage()
+        .instance()
+        .get(&GovFeeKey::Checkpoint)
+        .unwrap_or(0);
+    let elapsed = e.ledger().timestamp().saturating_sub(checkpoint) / config.period_secs;
+    let periods = elapsed.min(MAX_PERIODS_PER_CALL);
+    if periods == 0 {
+        return 0;
+    }
+    e.storage().instance().set(
+        &GovFeeKey::Checkpoint,
+        &checkpoint.saturating_add(periods.saturating_mul(config.period_secs)),
+    );
+
+    let mut members = Vec::new(e);
+    for member in governance_approval::get_governors(e).iter() {
+        if !governance_approval::is_suspended(e, &member)
+            && governance_approval::meets_min_bond(e, &member)
+        {
+            members.push_back(member);
+        }
+    }
+    if members.is_empty() || config.bps_per_period == 0 {
+        crate::events::publish(e, "governor_fees_distributed", (periods, 0_i128));
+        return 0;
+    }
+
+    let pool_key = Symbol::new(e, "fees");
+    let mut pool: i128 = e.storage().instance().get(&pool_key).unwrap_or(0);
+    let count = i128::from(members.len());
+    let mut share = 0_i128;
+    for _ in 0..periods {
+        let released = math::bps(
+            pool,
+            config.bps_per_period,
+            "governor fee overflow",
+            "governor fee div-by-zero",
+        );
+        let per_member = released / count;
+        share = share
+            .checked_add(per_member)
+            .expect("governor fee overflow");
+        pool = pool
+            .checked_sub(per_member * count)
+            .expect("governor fee underflow");
+    }
+    if share == 0 {
+        crate::events::publish(e, "governor_fees_distributed", (periods, 0_i128));
+        return 0;
+    }
+
+    e.storage().instance().set(&pool_key, &pool);
+    for member in members.iter() {
+        let credited = get_claimable(e, &member)
+            .checked_add(share)
+            .expect("governor fee overflow");
+        e.storage()
+            .instance()
+            .set(&GovFeeKey::Claimable(member), &credited);
+    }
+    let total = share * count;
+    crate::events::publish(e, "governor_fees_distributed", (periods, total));
+    total
+}
+
+/// @notice Pays out `member`'s claimable balance (member auth and reentrancy guard are enforced
+/// by the caller). The balance is zeroed before the transfer. Emits `governor_fees_claimed`.
+///
+/// # Panics
+/// - "nothing to claim"
+pub fn claim(e: &Env, member: &Address) -> i128 {
+    let amount = get_claimable(e, member);
+    if amount == 0 {
+        panic!("nothing to claim");
+    }
+    e.storage()
+        .instance()
+        .remove(&GovFeeKey::Claimable(member.clone()));
+    token_integration::transfer_from_contract(e, member, amount);
+    crate::events::publish_for(e, "governor_fees_claimed", member.clone(), amount);
+    amount
+}
@@ -0,0 +1,96 @@
+//! Identity Labels
+//!
+//! Admin-managed operational annotations on identities ("pilot_partner", "under_review"), so
+//! ops context lives next to the bond instead of in an external spreadsheet. Each identity holds
+//! at most one label, which must be on an admin-configured allow-list of at most
+//! `MAX_ALLOWED_LABELS` symbols. Labels are pure metadata: nothing in the protocol reads them
+//! besides the describe views.
+
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+/// Largest number of labels the allow-list may hold.
+pub const MAX_ALLOWED_LABELS: u32 = 32;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum LabelKey {
+    /// Identity -> label
+    Label(Address),
+    /// Labels the admin may assign
+    AllowList,
+}
+
+/// @notice Label currently set on `identity`, if any.
+#[must_use]
+pub fn get_label(e: &Env, identity: &Address) -> Option<Symbol> {
+    e.storage()
+        .instance()
+        .get(&LabelKey::Label(identity.clone()))
+}
+
+/// @notice Labels that may be assigned (empty until configured).
+#[must_use]
+pub fn get_allowed(e: &Env) -> Vec<Symbol> {
+    e.storage()
+        .instance()
+        .get(&LabelKey::AllowList)
+        .unwrap_or(Vec::new(e))
+}
+
+/// @notice Replaces the allow-list (admin only; caller must enforce). Labels already set are
+/// kept even if they are no longer allowed.
+///
+/// # Panics
+/// - "too many labels" if `labels` holds more than `MAX_ALLOWED_LABELS` entries.
+/// - "label must not be empty".
+pub fn set_allowed(e: &Env, labels: Vec<Symbol>) {
+    if labels.len() > MAX_ALLOWED_LABELS {
+        panic!("too many labels");
+    }
+    for label in labels.iter() {
+        crate::reason_code::require_label(e, &label);
+    }
+    e.storage().instance().set(&LabelKey::AllowList, &labels);
+    crate::events::publish(e, "label_allow_list_set", labels);
+}
+
+/// @notice Sets `identity`'s label, replacing any previous one (admin only; caller must
+/// enforce).
+///
+/// # Panics
+/// - "label must not be empty".
+/// - "label not allowed" if `label` is not on the allow-list.
+pub fn set_label(e: &Env, identity: &Address, label: Symbol) {
+    crate::reason_code::require_label(e, &label);
+    if !get_allowed(e).contains(&label) {
+        panic!("label not allowed");
+    }
+    e.storage()
+        .instance()
+        .set(&LabelKey::Label(identity.clone()), &label);
+    crate::events::publish_for(e, "identity_label_set", identity.clone(), label);
+}
+
+/// @notice Removes `identity`'s label (admin only; caller must enforce). Returns whether a
+/// label was removed.
+pub fn clear_label(e: &Env, identity: &Address) -> bool {
+    let key = LabelKey::Label(identity.clone());
+    let Some(label) = e.storage().instance().get::<_, Symbol>(&key) else {
+        return false;
+    };
+    e.storage().instance().remove(&key);
+    crate::events::publish_for(e, "identity_label_cleared", identity.clone(), label);
+    true
+}
+
+/// @notice Removes the labels of all `identities` (admin only; caller must enforce). Returns
+/// how many labels were removed.
+pub fn clear_labels(e: &Env, identities: &Vec<Address>) -> u32 {
+    let mut cleared: u32 = 0;
+    for identity in identities.iter() {
+        if clear_label(e, &identity) {
+            cleared = cleared.checked_add(1).expect("label count overflow");
+        }
+    }
+    cleared
+}
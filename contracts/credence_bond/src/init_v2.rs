@@ -0,0 +1,135 @@
+//! Post-upgrade Initialization
+//!
+//! `initialize` only writes the original configuration. After a wasm upgrade adds subsystems
+//! with their own config keys, `initialize_v2` lets the admin populate those keys once: every
+//! requested section must still be absent, so nothing already configured can be overwritten
+//! through this path. The config schema version is recorded so clients can tell which sections
+//! a deployment knows about.
+
+use soroban_sdk::{contracttype, Env, Symbol, Vec};
+
+use crate::governance_approval;
+use crate::{dust, subject_policy};
+
+/// Schema version written by `initialize` alone.
+pub const CONFIG_SCHEMA_V1: u32 = 1;
+/// Schema version recorded by `initialize_v2`.
+pub const CONFIG_SCHEMA_V2: u32 = 2;
+
+const KEY_SCHEMA_VERSION: &str = "cfg_schema";
+
+const SECTION_DUST: &str = "dust_threshold";
+const SECTION_RATE_LIMIT: &str = "proposal_rate_limit";
+const SECTION_SUBJECT_POLICY: &str = "subject_policy";
+const SECTION_GOVERNANCE_WEIGHT: &str = "governance_weight";
+
+/// Sections introduced after v1; `None` leaves a section untouched.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitV2Config {
+    pub dust_threshold: Option<i128>,
+    /// Proposal rate limit section: either field requests it, and an unset one is written as 0
+    /// (disabled).
+    pub proposal_max_open: Option<u32>,
+    pub proposal_cooldown_secs: Option<u64>,
+    pub require_subject_known: Option<bool>,
+    pub governance_bond_weighted: Option<bool>,
+}
+
+/// @notice Config schema version: 0 before `initialize`, then 1 until `initialize_v2` runs.
+#[must_use]
+pub fn schema_version(e: &Env) -> u32 {
+    if let Some(v) = e
+        .storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_SCHEMA_VERSION))
+    {
+        return v;
+    }
+    if e.storage().instance().has(&crate::DataKey::Admin) {
+        CONFIG_SCHEMA_V1
+    } else {
+        0
+    }
+}
+
+/// @notice Names of the post-v1 sections that currently hold a value.
+#[must_use]
+pub fn configured_sections(e: &Env) -> Vec<Symbol> {
+    let mut out = Vec::new(e);
+    let present = [
+        (SECTION_DUST, dust::is_configured(e)),
+        (SECTION_RATE_LIMIT, governance_approval::has_rate_limit(e)),
+        (SECTION_SUBJECT_POLICY, subject_policy::is_configured(e)),
+        (
+            SECTION_GOVERNANCE_WEIGHT,
+            governance_approval::has_weight_mode(e),
+        ),
+    ];
+    for (name, configured) in present {
+        if configured {
+            out.push_back(Symbol::new(e, name));
+        }
+    }
+    out
+}
+
+fn require_absent(name: &str, configured: bool) {
+    if configured {
+        panic!("config section already initialized: {}", name);
+    }
+}
+
+/// @notice Writes each requested section, refusing to touch any that already exists. Admin only
+/// (enforced by caller). All sections are checked before anything is written.
+/// @return Names of the sections populated.
+///
+/// # Panics
+/// - "config section already initialized: <section>" if a requested section has a value.
+pub fn initialize(e: &Env, config: InitV2Config) -> Vec<Symbol> {
+    if config.dust_threshold.is_some() {
+        require_absent(SECTION_DUST, dust::is_configured(e));
+    }
+    let rate_limit_requested =
+        config.proposal_max_open.is_some() || config.proposal_cooldown_secs.is_some();
+    if rate_limit_requested {
+        require_absent(SECTION_RATE_LIMIT, governance_approval::has_rate_limit(e));
+    }
+    if config.require_subject_known.is_some() {
+        require_absent(SECTION_SUBJECT_POLICY, subject_policy::is_configured(e));
+    }
+    if config.governance_bond_weighted.is_some() {
+        require_absent(
+            SECTION_GOVERNANCE_WEIGHT,
+            governance_approval::has_weight_mode(e),
+        );
+    }
+
+    let mut populated = Vec::new(e);
+    if let Some(threshold) = config.dust_threshold {
+        dust::set_threshold(e, threshold);
+        populated.push_back(Symbol::new(e, SECTION_DUST));
+    }
+    if rate_limit_requested {
+        governance_approval::set_rate_limit(
+            e,
+            config.proposal_max_open.unwrap_or(0),
+            config.proposal_cooldown_secs.unwrap_or(0),
+        );
+        populated.push_back(Symbol::new(e, SECTION_RATE_LIMIT));
+    }
+    if let Some(required) = config.require_subject_known {
+        subject_policy::set_require_known_subject(e, required);
+        populated.push_back(Symbol::new(e, SECTION_SUBJECT_POLICY));
+    }
+    if let Some(enabled) = config.governance_bond_weighted {
+        governance_approval::set_bond_weighted(e, enabled);
+        populated.push_back(Symbol::new(e, SECTION_GOVERNANCE_WEIGHT));
+    }
+
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_SCHEMA_VERSION), &CONFIG_SCHEMA_V2);
+    crate::events::publish(e, "initialized_v2", (CONFIG_SCHEMA_V2, populated.clone()));
+    populated
+}
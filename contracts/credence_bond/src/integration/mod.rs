@@ -2,3 +2,4 @@
 
 mod test_bond_lifecycle;
 mod test_governance;
+mod test_seed_state;
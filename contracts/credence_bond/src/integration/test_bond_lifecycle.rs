@@ -25,10 +25,10 @@ fn setup(
 fn test_lifecycle_create_then_withdraw() {
     let e = Env::default();
     let (client, _admin, identity) = setup(&e);
-    let amount = 1000_i128;
+    let amount = 1_000_000_000_i128;
     let duration = 86400_u64;
     client.create_bond(&identity, &amount, &duration, &false, &0_u64);
-    let state = client.get_identity_state();
+    let state = client.get_identity_state(&identity);
     assert_eq!(state.bonded_amount, amount);
     assert_eq!(state.slashed_amount, 0);
     assert!(state.active);
@@ -36,7 +36,7 @@ fn test_lifecycle_create_then_withdraw() {
     // Advance past lock-up so withdraw path is valid.
     e.ledger().with_mut(|li| li.timestamp = duration + 1);
 
-    let withdrawn = client.withdraw(&amount);
+    let withdrawn = client.withdraw(&identity, &amount);
     assert_eq!(withdrawn.bonded_amount, 0);
     assert_eq!(withdrawn.slashed_amount, 0);
 }
@@ -47,14 +47,14 @@ fn test_lifecycle_create_topup_withdraw() {
     let e = Env::default();
     let (client, _admin, identity) = setup(&e);
     let duration = 86400_u64;
-    client.create_bond(&identity, &500_i128, &duration, &false, &0_u64);
-    let after_topup = client.top_up(&300_i128);
-    assert_eq!(after_topup.bonded_amount, 800);
+    client.create_bond(&identity, &500_000_000_i128, &duration, &false, &0_u64);
+    let after_topup = client.top_up(&identity, &identity, &300_000_000_i128);
+    assert_eq!(after_topup.bonded_amount, 800_000_000);
 
     // Advance past lock-up before withdrawing.
     e.ledger().with_mut(|li| li.timestamp = duration + 1);
-    client.withdraw(&800_i128);
-    let state = client.get_identity_state();
+    client.withdraw(&identity, &800_000_000_i128);
+    let state = client.get_identity_state(&identity);
     assert_eq!(state.bonded_amount, 0);
 }
 
@@ -64,17 +64,17 @@ fn test_lifecycle_slash_then_withdraw_remaining() {
     let e = Env::default();
     let (client, admin, identity) = setup(&e);
     let duration = 86400_u64;
-    client.create_bond(&identity, &1000_i128, &duration, &false, &0_u64);
-    let after_slash = client.slash(&admin, &400_i128);
-    assert_eq!(after_slash.slashed_amount, 400);
-    assert_eq!(after_slash.bonded_amount, 1000);
+    client.create_bond(&identity, &1_000_000_000_i128, &duration, &false, &0_u64);
+    let after_slash = client.slash(&admin, &identity, &400_000_000_i128);
+    assert_eq!(after_slash.slashed_amount, 400_000_000);
+    assert_eq!(after_slash.bonded_amount, 1_000_000_000);
 
-    let remaining = 1000_i128 - 400_i128;
+    let remaining = 1_000_000_000_i128 - 400_000_000_i128;
     // Advance past lock-up before withdrawing remaining amount.
     e.ledger().with_mut(|li| li.timestamp = duration + 1);
-    let after_withdraw = client.withdraw(&remaining);
-    assert_eq!(after_withdraw.bonded_amount, 400);
-    assert_eq!(after_withdraw.slashed_amount, 400);
+    let after_withdraw = client.withdraw(&identity, &remaining);
+    assert_eq!(after_withdraw.bonded_amount, 400_000_000);
+    assert_eq!(after_withdraw.slashed_amount, 400_000_000);
 }
 
 /// Multiple operations: create, top-up, slash, withdraw.
@@ -83,18 +83,18 @@ fn test_lifecycle_create_topup_slash_withdraw() {
     let e = Env::default();
     let (client, admin, identity) = setup(&e);
     let duration = 86400_u64;
-    client.create_bond(&identity, &1000_i128, &duration, &false, &0_u64);
-    client.top_up(&500_i128);
-    client.slash(&admin, &300_i128);
-    let state = client.get_identity_state();
-    assert_eq!(state.bonded_amount, 1500);
-    assert_eq!(state.slashed_amount, 300);
-    let available = 1500 - 300;
+    client.create_bond(&identity, &1_000_000_000_i128, &duration, &false, &0_u64);
+    client.top_up(&identity, &identity, &500_000_000_i128);
+    client.slash(&admin, &identity, &300_000_000_i128);
+    let state = client.get_identity_state(&identity);
+    assert_eq!(state.bonded_amount, 1_500_000_000);
+    assert_eq!(state.slashed_amount, 300_000_000);
+    let available = 1_500_000_000 - 300_000_000;
     // Advance past lock-up before withdrawing.
     e.ledger().with_mut(|li| li.timestamp = duration + 1);
-    client.withdraw(&available);
-    let final_state = client.get_identity_state();
-    assert_eq!(final_state.bonded_amount, 300);
+    client.withdraw(&identity, &available);
+    let final_state = client.get_identity_state(&identity);
+    assert_eq!(final_state.bonded_amount, 300_000_000);
 }
 
 /// State consistency: get_identity_state matches after each step.
@@ -103,23 +103,23 @@ fn test_lifecycle_state_consistency() {
     let e = Env::default();
     let (client, admin, identity) = setup(&e);
     let duration = 86400_u64;
-    client.create_bond(&identity, &2000_i128, &duration, &false, &0_u64);
-    let s1 = client.get_identity_state();
-    let s2 = client.get_identity_state();
+    client.create_bond(&identity, &2_000_000_000_i128, &duration, &false, &0_u64);
+    let s1 = client.get_identity_state(&identity);
+    let s2 = client.get_identity_state(&identity);
     assert_eq!(s1.bonded_amount, s2.bonded_amount);
     assert_eq!(s1.slashed_amount, s2.slashed_amount);
 
-    client.slash(&admin, &500_i128);
-    let s3 = client.get_identity_state();
-    assert_eq!(s3.slashed_amount, 500);
-    assert_eq!(s3.bonded_amount, 2000);
+    client.slash(&admin, &identity, &500_000_000_i128);
+    let s3 = client.get_identity_state(&identity);
+    assert_eq!(s3.slashed_amount, 500_000_000);
+    assert_eq!(s3.bonded_amount, 2_000_000_000);
 
     // Advance past lock-up before withdrawing.
     e.ledger().with_mut(|li| li.timestamp = duration + 1);
-    client.withdraw(&1500_i128);
-    let s4 = client.get_identity_state();
-    assert_eq!(s4.bonded_amount, 500);
-    assert_eq!(s4.slashed_amount, 500);
+    client.withdraw(&identity, &1_500_000_000_i128);
+    let s4 = client.get_identity_state(&identity);
+    assert_eq!(s4.bonded_amount, 500_000_000);
+    assert_eq!(s4.slashed_amount, 500_000_000);
 }
 
 /// Extend duration then verify bond fields unchanged where expected.
@@ -127,10 +127,10 @@ fn test_lifecycle_state_consistency() {
 fn test_lifecycle_extend_duration() {
     let e = Env::default();
     let (client, _admin, identity) = setup(&e);
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
-    let before = client.get_identity_state();
-    client.extend_duration(&86400_u64);
-    let after = client.get_identity_state();
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    let before = client.get_identity_state(&identity);
+    client.extend_duration(&identity, &86400_u64);
+    let after = client.get_identity_state(&identity);
     assert_eq!(after.bond_duration, before.bond_duration + 86400);
     assert_eq!(after.bonded_amount, before.bonded_amount);
 }
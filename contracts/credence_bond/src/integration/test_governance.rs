@@ -1,16 +1,26 @@
 //! Integration tests for governance slash flow (#48).
 //! Covers slash request submission, multi-sig approval, slash execution,
 //! contested/rejected flow (dispute-style resolution), delegation, and state consistency.
+//!
+//! The bond + governance baseline is built once per module and restored into each test's
+//! contract from a storage snapshot.
 
 #![cfg(test)]
 
+extern crate std;
+
 use crate::governance_approval::ProposalStatus;
 use crate::test_helpers;
-use crate::CredenceBondClient;
+use crate::{CredenceBond, CredenceBondClient, DataKey};
+use soroban_sdk::testutils::storage::Instance as _;
 use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{Address, Env, Vec};
+use soroban_sdk::{Address, Bytes, Env, TryFromVal, Vec};
+use std::sync::OnceLock;
 
-fn setup(
+type Snapshot = std::vec::Vec<(std::vec::Vec<u8>, std::vec::Vec<u8>)>;
+
+/// Builds the baseline call by call: a 1_000_000_000 bond and three governors at 66% quorum.
+fn setup_manually(
     e: &Env,
 ) -> (
     CredenceBondClient<'_>,
@@ -25,7 +35,7 @@ fn setup(
     let g2 = Address::generate(e);
     let g3 = Address::generate(e);
 
-    client.create_bond(&identity, &1_000_i128, &86_400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86_400_u64, &false, &0_u64);
 
     let governors = Vec::from_array(e, [g1.clone(), g2.clone(), g3.clone()]);
     client.initialize_governance(&admin, &governors, &6_600_u32, &2_u32);
@@ -33,13 +43,106 @@ fn setup(
     (client, admin, identity, g1, g2, g3)
 }
 
+fn baseline() -> &'static Snapshot {
+    static BASELINE: OnceLock<Snapshot> = OnceLock::new();
+    BASELINE.get_or_init(|| {
+        let e = Env::default();
+        let (client, ..) = setup_manually(&e);
+        client
+            .export_all_state()
+            .iter()
+            .map(|(k, v)| (k.iter().collect(), v.iter().collect()))
+            .collect()
+    })
+}
+
+/// Restores the baseline into a fresh contract. Token balances are not part of the snapshot;
+/// the governance flows below do not move tokens.
+fn setup(
+    e: &Env,
+) -> (
+    CredenceBondClient<'_>,
+    Address,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let mut entries = Vec::new(e);
+    for (k, v) in baseline() {
+        entries.push_back((Bytes::from_slice(e, k), Bytes::from_slice(e, v)));
+    }
+    let client = CredenceBondClient::new(e, &contract_id);
+    // Instance storage only becomes visible to `all()` once the importing frame commits.
+    client.import_state(&entries);
+    let (admin, identity) = e.as_contract(&contract_id, || {
+        let admin: Address = e.storage().instance().get(&DataKey::Admin).unwrap();
+        // The baseline holds a single bond; its key names the identity.
+        let identity = e
+            .storage()
+            .instance()
+            .all()
+            .keys()
+            .iter()
+            .find_map(|k| match DataKey::try_from_val(e, &k) {
+                Ok(DataKey::Bond(identity)) => Some(identity),
+                _ => None,
+            })
+            .unwrap();
+        (admin, identity)
+    });
+
+    let governors = client.get_governors();
+    (
+        client,
+        admin,
+        identity,
+        governors.get(0).unwrap(),
+        governors.get(1).unwrap(),
+        governors.get(2).unwrap(),
+    )
+}
+
+/// Scenario: a restored baseline behaves exactly like one built call by call.
+#[test]
+fn test_snapshot_matches_manual_setup() {
+    let restored_env = Env::default();
+    let (restored, admin, identity, g1, g2, _g3) = setup(&restored_env);
+    let reexported: Snapshot = restored
+        .export_all_state()
+        .iter()
+        .map(|(k, v)| (k.iter().collect(), v.iter().collect()))
+        .collect();
+    assert_eq!(&reexported, baseline());
+
+    let manual_env = Env::default();
+    let (manual, m_admin, m_identity, m_g1, m_g2, _m_g3) = setup_manually(&manual_env);
+
+    let id = restored.propose_slash(&admin, &identity, &300_000_000_i128);
+    let m_id = manual.propose_slash(&m_admin, &m_identity, &300_000_000_i128);
+    assert_eq!(id, m_id);
+    restored.governance_vote(&g1, &id, &true);
+    restored.governance_vote(&g2, &id, &true);
+    manual.governance_vote(&m_g1, &m_id, &true);
+    manual.governance_vote(&m_g2, &m_id, &true);
+
+    let bond = restored.execute_slash_with_governance(&admin, &id);
+    let m_bond = manual.execute_slash_with_governance(&m_admin, &m_id);
+    assert_eq!(bond.bonded_amount, m_bond.bonded_amount);
+    assert_eq!(bond.slashed_amount, m_bond.slashed_amount);
+    assert_eq!(bond.active, m_bond.active);
+    assert_eq!(restored.get_governors().len(), manual.get_governors().len());
+}
+
 /// Scenario: slash request submission is persisted with exact proposer, amount and status.
 #[test]
 fn test_governance_slash_request_submission() {
     let e = Env::default();
-    let (client, admin, _identity, ..) = setup(&e);
+    let (client, admin, identity, ..) = setup(&e);
 
-    let proposal_id = client.propose_slash(&admin, &250_i128);
+    let proposal_id = client.propose_slash(&admin, &identity, &250_000_000_i128);
     assert_eq!(proposal_id, 0);
 
     let proposal = client
@@ -47,7 +150,7 @@ fn test_governance_slash_request_submission() {
         .expect("proposal should exist");
 
     assert_eq!(proposal.id, 0);
-    assert_eq!(proposal.amount, 250);
+    assert_eq!(proposal.amount, 250_000_000);
     assert_eq!(proposal.proposed_by, admin);
     assert!(matches!(proposal.status, ProposalStatus::Open));
 }
@@ -58,12 +161,12 @@ fn test_governance_multisig_approval_and_execution() {
     let e = Env::default();
     let (client, admin, identity, g1, g2, _g3) = setup(&e);
 
-    let before = client.get_identity_state();
+    let before = client.get_identity_state(&identity);
     assert_eq!(before.identity, identity);
-    assert_eq!(before.bonded_amount, 1_000);
+    assert_eq!(before.bonded_amount, 1_000_000_000);
     assert_eq!(before.slashed_amount, 0);
 
-    let proposal_id = client.propose_slash(&admin, &300_i128);
+    let proposal_id = client.propose_slash(&admin, &identity, &300_000_000_i128);
 
     client.governance_vote(&g1, &proposal_id, &true);
     client.governance_vote(&g2, &proposal_id, &true);
@@ -73,8 +176,8 @@ fn test_governance_multisig_approval_and_execution() {
 
     let after_slash = client.execute_slash_with_governance(&admin, &proposal_id);
 
-    assert_eq!(after_slash.bonded_amount, 1_000);
-    assert_eq!(after_slash.slashed_amount, 300);
+    assert_eq!(after_slash.bonded_amount, 1_000_000_000);
+    assert_eq!(after_slash.slashed_amount, 300_000_000);
     assert!(after_slash.active);
 
     let proposal = client
@@ -88,9 +191,9 @@ fn test_governance_multisig_approval_and_execution() {
 #[should_panic(expected = "proposal not approved")]
 fn test_governance_contested_flow_rejects_execution() {
     let e = Env::default();
-    let (client, admin, _identity, g1, g2, _g3) = setup(&e);
+    let (client, admin, identity, g1, g2, _g3) = setup(&e);
 
-    let disputed_id = client.propose_slash(&admin, &400_i128);
+    let disputed_id = client.propose_slash(&admin, &identity, &400_000_000_i128);
     client.governance_vote(&g1, &disputed_id, &true);
     client.governance_vote(&g2, &disputed_id, &false);
     client.execute_slash_with_governance(&admin, &disputed_id);
@@ -100,19 +203,19 @@ fn test_governance_contested_flow_rejects_execution() {
 #[test]
 fn test_governance_reproposal_after_contested_request() {
     let e = Env::default();
-    let (client, admin, _identity, g1, g2, _g3) = setup(&e);
+    let (client, admin, identity, g1, g2, _g3) = setup(&e);
 
-    let disputed_id = client.propose_slash(&admin, &400_i128);
+    let disputed_id = client.propose_slash(&admin, &identity, &400_000_000_i128);
     client.governance_vote(&g1, &disputed_id, &true);
     client.governance_vote(&g2, &disputed_id, &false);
 
     // Do not execute the disputed proposal; open a replacement proposal with updated amount.
-    let accepted_id = client.propose_slash(&admin, &150_i128);
+    let accepted_id = client.propose_slash(&admin, &identity, &150_000_000_i128);
     client.governance_vote(&g1, &accepted_id, &true);
     client.governance_vote(&g2, &accepted_id, &true);
 
     let bond = client.execute_slash_with_governance(&admin, &accepted_id);
-    assert_eq!(bond.slashed_amount, 150);
+    assert_eq!(bond.slashed_amount, 150_000_000);
 
     let accepted = client
         .get_slash_proposal(&accepted_id)
@@ -124,20 +227,20 @@ fn test_governance_reproposal_after_contested_request() {
 #[test]
 fn test_governance_multi_actor_delegation_flow() {
     let e = Env::default();
-    let (client, admin, _identity, g1, g2, _g3) = setup(&e);
+    let (client, admin, identity, g1, g2, _g3) = setup(&e);
     let delegate = Address::generate(&e);
 
     client.governance_delegate(&g1, &delegate);
     assert_eq!(client.get_governance_delegate(&g1), Some(delegate.clone()));
 
-    let proposal_id = client.propose_slash(&admin, &125_i128);
+    let proposal_id = client.propose_slash(&admin, &identity, &125_000_000_i128);
     client.governance_vote(&delegate, &proposal_id, &true);
     client.governance_vote(&g2, &proposal_id, &true);
 
     let bond = client.execute_slash_with_governance(&admin, &proposal_id);
-    assert_eq!(bond.slashed_amount, 125);
+    assert_eq!(bond.slashed_amount, 125_000_000);
 
-    let final_state = client.get_identity_state();
-    assert_eq!(final_state.bonded_amount, 1_000);
-    assert_eq!(final_state.slashed_amount, 125);
+    let final_state = client.get_identity_state(&identity);
+    assert_eq!(final_state.bonded_amount, 1_000_000_000);
+    assert_eq!(final_state.slashed_amount, 125_000_000);
 }
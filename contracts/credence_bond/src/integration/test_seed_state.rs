@@ -0,0 +1,461 @@
+//! Integration tests for `test_seed_state`.
+//! A reference contract reaches a scenario organically (bond 60% through its duration, one
+//! executed slash, two attestations, one open proposal with a vote) while a second contract is
+//! seeded to the same scenario; every public view must match, before and after pause and
+//! governance flows are replayed on both. The upgrade scenarios rewrite the seeded contract's
+//! storage into the layouts an earlier contract version left behind and check that the
+//! migrations bring every view back to the reference without changing anything else.
+
+#![cfg(test)]
+
+use crate::governance_approval::{ProposalStatus, SlashProposal};
+use crate::legacy_bond::{LegacyIdentityBond, LegacyKey};
+use crate::seed::{SeedAttestation, SeedGovernance, SeedSpec, SeedVote};
+use crate::test_helpers;
+use crate::types::{Attestation, AttestationV1};
+use crate::verifier::VerifierInfo;
+use crate::{
+    BondKind, BondStatus, BondTier, CredenceBond, CredenceBondClient, DataKey, IdentityBond,
+};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, String, Vec};
+
+const START: u64 = 1_000;
+const DURATION: u64 = 1_000_000;
+const AMOUNT: i128 = 100_000_000;
+const SLASHED: i128 = 10_000_000;
+const PROPOSED: i128 = 5_000_000;
+
+struct Actors {
+    admin: Address,
+    identity: Address,
+    attester_a: Address,
+    attester_b: Address,
+    governors: Vec<Address>,
+}
+
+fn set_time(e: &Env, t: u64) {
+    e.ledger().with_mut(|li| li.timestamp = t);
+}
+
+/// Organic path: every state change goes through the public entrypoints.
+fn build_reference<'a>(e: &'a Env) -> (CredenceBondClient<'a>, Actors, Address) {
+    set_time(e, START);
+    let (client, admin, identity, token, _contract_id) = test_helpers::setup_with_token(e);
+    let actors = Actors {
+        admin: admin.clone(),
+        identity: identity.clone(),
+        attester_a: Address::generate(e),
+        attester_b: Address::generate(e),
+        governors: Vec::from_array(
+            e,
+            [
+                Address::generate(e),
+                Address::generate(e),
+                Address::generate(e),
+            ],
+        ),
+    };
+    client.register_attester(&actors.attester_a);
+    client.register_attester(&actors.attester_b);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0);
+
+    set_time(e, START + 100);
+    client.add_attestation(&actors.attester_a, &identity, &String::from_str(e, "kyc"));
+    set_time(e, START + 200);
+    client.add_attestation(&actors.attester_b, &identity, &String::from_str(e, "aml"));
+
+    client.slash_bond(&admin, &identity, &SLASHED);
+
+    client.initialize_governance(&admin, &actors.governors, &6_600_u32, &2_u32);
+    set_time(e, START + 300);
+    let proposal_id = client.propose_slash(&admin, &identity, &PROPOSED);
+    client.governance_vote(&actors.governors.get(0).unwrap(), &proposal_id, &true);
+
+    set_time(e, START + DURATION * 6 / 10);
+    (client, actors, token)
+}
+
+/// Seeded path: only attesters are registered organically; everything else comes from the spec.
+fn build_seeded<'a>(e: &'a Env, actors: &Actors, token: &Address) -> CredenceBondClient<'a> {
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    client.initialize(&actors.admin);
+    client.set_token(&actors.admin, token);
+
+    set_time(e, START);
+    client.register_attester(&actors.attester_a);
+    client.register_attester(&actors.attester_b);
+
+    let spec = SeedSpec {
+        bonds: Vec::from_array(
+            e,
+            [IdentityBond {
+                identity: actors.identity.clone(),
+                bonded_amount: AMOUNT,
+                bond_start: START,
+                bond_duration: DURATION,
+                slashed_amount: SLASHED,
+                active: true,
+                is_rolling: false,
+                withdrawal_requested_at: 0,
+                notice_period: 0,
+                kind: BondKind::Fixed,
+                status: BondStatus::Active,
+            }],
+        ),
+        attestations: Vec::from_array(
+            e,
+            [
+                SeedAttestation {
+                    verifier: actors.attester_a.clone(),
+                    identity: actors.identity.clone(),
+                    timestamp: START + 100,
+                    weight: 1,
+                    attestation_data: String::from_str(e, "kyc"),
+                    revoked: false,
+                },
+                SeedAttestation {
+                    verifier: actors.attester_b.clone(),
+                    identity: actors.identity.clone(),
+                    timestamp: START + 200,
+                    weight: 1,
+                    attestation_data: String::from_str(e, "aml"),
+                    revoked: false,
+                },
+            ],
+        ),
+        governance: Vec::from_array(
+            e,
+            [SeedGovernance {
+                governors: actors.governors.clone(),
+                quorum_bps: 6_600,
+                min_governors: 2,
+                proposals: Vec::from_array(
+                    e,
+                    [SlashProposal {
+                        id: 0,
+                        identity: actors.identity.clone(),
+                        amount: PROPOSED,
+                        proposed_by: actors.admin.clone(),
+                        proposed_at: START + 300,
+                        status: ProposalStatus::Open,
+                    }],
+                ),
+                votes: Vec::from_array(
+                    e,
+                    [SeedVote {
+                        proposal_id: 0,
+                        voter: actors.governors.get(0).unwrap(),
+                        approve: true,
+                    }],
+                ),
+            }],
+        ),
+    };
+    let ids = client.test_seed_state(&actors.admin, &spec);
+    assert_eq!(ids, Vec::from_array(e, [0_u64, 1_u64]));
+
+    set_time(e, START + DURATION * 6 / 10);
+    client
+}
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, CredenceBondClient<'_>, Actors) {
+    let (reference, actors, token) = build_reference(e);
+    let seeded = build_seeded(e, &actors, &token);
+    (reference, seeded, actors)
+}
+
+/// Every public view of the scenario. `bond` and `tier` are `None` while the bond cannot be
+/// read (before a bond migration).
+#[derive(Clone, Debug, PartialEq)]
+struct Views {
+    bond: Option<IdentityBond>,
+    tier: Option<BondTier>,
+    paused: bool,
+    attestation_ids: Vec<u64>,
+    attestation_count: u32,
+    attestations: Vec<Attestation>,
+    verifiers: [Option<VerifierInfo>; 2],
+    governors: Vec<Address>,
+    quorum: (u32, u32),
+    proposal: (u64, Address, i128, Address, u64, ProposalStatus),
+    votes: Vec<Option<bool>>,
+}
+
+fn views(e: &Env, client: &CredenceBondClient<'_>, actors: &Actors) -> Views {
+    let attestation_ids = client.get_subject_attestations(&actors.identity);
+    let mut attestations = Vec::new(e);
+    for id in attestation_ids.iter() {
+        attestations.push_back(client.get_attestation(&id));
+    }
+    let p = client.get_slash_proposal(&0).expect("proposal");
+    let mut votes = Vec::new(e);
+    for g in actors.governors.iter() {
+        votes.push_back(client.get_governance_vote(&0, &g));
+    }
+    Views {
+        bond: client
+            .try_get_identity_state(&actors.identity)
+            .ok()
+            .and_then(Result::ok),
+        tier: client
+            .try_get_tier(&actors.identity)
+            .ok()
+            .and_then(Result::ok),
+        paused: client.is_paused(),
+        attestation_count: client.get_subject_attestation_count(&actors.identity),
+        attestation_ids,
+        attestations,
+        verifiers: [
+            client.get_verifier_info(&actors.attester_a),
+            client.get_verifier_info(&actors.attester_b),
+        ],
+        governors: client.get_governors(),
+        quorum: client.get_quorum_config(),
+        proposal: (
+            p.id,
+            p.identity,
+            p.amount,
+            p.proposed_by,
+            p.proposed_at,
+            p.status,
+        ),
+        votes,
+    }
+}
+
+fn assert_views_match(
+    reference: &CredenceBondClient<'_>,
+    seeded: &CredenceBondClient<'_>,
+    actors: &Actors,
+) {
+    let e = &reference.env;
+    assert_eq!(views(e, reference, actors), views(e, seeded, actors));
+}
+
+/// Rewrites the seeded attestations in the V1 layout, as a pre-V2 contract stored them.
+fn downgrade_attestations(e: &Env, client: &CredenceBondClient<'_>, actors: &Actors) {
+    for id in client.get_subject_attestations(&actors.identity).iter() {
+        let att = client.get_attestation(&id);
+        let legacy = AttestationV1 {
+            id: att.id,
+            verifier: att.verifier,
+            identity: att.identity,
+            timestamp: att.timestamp,
+            weight: att.weight,
+            attestation_data: att.attestation_data,
+            revoked: att.revoked,
+            revoked_at: att.revoked_at,
+            subject_seq: att.subject_seq,
+            use_count: att.use_count,
+            data_hash: att.data_hash,
+        };
+        e.as_contract(&client.address, || {
+            e.storage()
+                .instance()
+                .set(&DataKey::Attestation(id), &legacy);
+        });
+    }
+}
+
+/// The seeded bond in the layout without `kind` and `status`.
+fn legacy_bond(client: &CredenceBondClient<'_>, actors: &Actors) -> LegacyIdentityBond {
+    let bond = client.get_identity_state(&actors.identity);
+    LegacyIdentityBond {
+        identity: bond.identity,
+        bonded_amount: bond.bonded_amount,
+        bond_start: bond.bond_start,
+        bond_duration: bond.bond_duration,
+        slashed_amount: bond.slashed_amount,
+        active: bond.active,
+        is_rolling: bond.is_rolling,
+        withdrawal_requested_at: bond.withdrawal_requested_at,
+        notice_period: bond.notice_period,
+    }
+}
+
+/// Views expected while the bond cannot be read: everything else is untouched.
+fn without_bond(views: &Views) -> Views {
+    Views {
+        bond: None,
+        tier: None,
+        ..views.clone()
+    }
+}
+
+/// Replays the rest of the open proposal on both contracts and compares the outcome.
+fn assert_execution_matches(
+    reference: &CredenceBondClient<'_>,
+    seeded: &CredenceBondClient<'_>,
+    actors: &Actors,
+) {
+    let mut results = [None, None];
+    for (i, client) in [reference, seeded].into_iter().enumerate() {
+        client.governance_vote(&actors.governors.get(1).unwrap(), &0, &true);
+        results[i] = Some(client.execute_slash_with_governance(&actors.admin, &0));
+    }
+    assert_eq!(results[0], results[1]);
+    assert_views_match(reference, seeded, actors);
+}
+
+#[test]
+fn test_seeded_state_matches_organic_state() {
+    let e = Env::default();
+    let (reference, seeded, actors) = setup(&e);
+    assert_views_match(&reference, &seeded, &actors);
+}
+
+#[test]
+fn test_seeded_counters_continue_like_organic() {
+    let e = Env::default();
+    let (reference, seeded, actors) = setup(&e);
+    let subject = Address::generate(&e);
+
+    let r = reference.add_attestation(&actors.attester_a, &subject, &String::from_str(&e, "kyc"));
+    let s = seeded.add_attestation(&actors.attester_a, &subject, &String::from_str(&e, "kyc"));
+    assert_eq!(r, s);
+    assert_eq!(s.id, 2);
+
+    let r = reference.propose_slash(&actors.admin, &actors.identity, &PROPOSED);
+    let s = seeded.propose_slash(&actors.admin, &actors.identity, &PROPOSED);
+    assert_eq!((r, s), (1, 1));
+
+    // Seeded dedup keys reject the same duplicate the organic ones do.
+    let data = String::from_str(&e, "kyc");
+    assert!(reference
+        .try_add_attestation(&actors.attester_a, &actors.identity, &data)
+        .is_err());
+    assert!(seeded
+        .try_add_attestation(&actors.attester_a, &actors.identity, &data)
+        .is_err());
+    assert_views_match(&reference, &seeded, &actors);
+}
+
+#[test]
+fn test_seeded_state_through_pause_cycle() {
+    let e = Env::default();
+    let (reference, seeded, actors) = setup(&e);
+
+    for client in [&reference, &seeded] {
+        client.pause(&actors.admin);
+        assert!(client
+            .try_governance_vote(&actors.governors.get(1).unwrap(), &0, &true)
+            .is_err());
+    }
+    assert_views_match(&reference, &seeded, &actors);
+
+    for client in [&reference, &seeded] {
+        client.unpause(&actors.admin);
+    }
+    assert_views_match(&reference, &seeded, &actors);
+}
+
+#[test]
+fn test_seeded_open_proposal_executes_like_organic() {
+    let e = Env::default();
+    let (reference, seeded, actors) = setup(&e);
+
+    assert_execution_matches(&reference, &seeded, &actors);
+    assert_eq!(
+        seeded.get_identity_state(&actors.identity).slashed_amount,
+        SLASHED + PROPOSED
+    );
+}
+
+#[test]
+fn test_seeded_state_through_attestation_upgrade() {
+    let e = Env::default();
+    let (reference, seeded, actors) = setup(&e);
+    let expected = views(&e, &reference, &actors);
+    downgrade_attestations(&e, &seeded, &actors);
+    let ids = seeded.get_subject_attestations(&actors.identity);
+
+    // V1 records read in the current layout, so nothing differs before the migration.
+    assert_eq!(views(&e, &seeded, &actors), expected);
+    assert_eq!(
+        seeded.migrate_attestation_records(&actors.admin, &ids, &true),
+        2
+    );
+    assert_eq!(views(&e, &seeded, &actors), expected);
+    assert_eq!(seeded.get_attestation_record_version(&0), 1);
+
+    assert_eq!(
+        seeded.migrate_attestation_records(&actors.admin, &ids, &false),
+        2
+    );
+    assert_eq!(views(&e, &seeded, &actors), expected);
+    for id in ids.iter() {
+        assert_eq!(seeded.get_attestation_record_version(&id), 2);
+    }
+    assert_execution_matches(&reference, &seeded, &actors);
+}
+
+#[test]
+fn test_seeded_state_through_legacy_bond_upgrade() {
+    let e = Env::default();
+    let (reference, seeded, actors) = setup(&e);
+    let expected = views(&e, &reference, &actors);
+    let legacy = legacy_bond(&seeded, &actors);
+    e.as_contract(&seeded.address, || {
+        e.storage()
+            .instance()
+            .remove(&DataKey::Bond(actors.identity.clone()));
+        e.storage().instance().set(&LegacyKey::Bond, &legacy);
+    });
+
+    // The singleton bond is invisible until migrated; every other view is unaffected.
+    assert!(seeded.has_legacy_bond());
+    assert_eq!(views(&e, &seeded, &actors), without_bond(&expected));
+    seeded.migrate_legacy_bond(&actors.admin, &true);
+    assert!(seeded.has_legacy_bond());
+    assert_eq!(views(&e, &seeded, &actors), without_bond(&expected));
+
+    seeded.migrate_legacy_bond(&actors.admin, &false);
+    assert!(!seeded.has_legacy_bond());
+    assert_eq!(views(&e, &seeded, &actors), expected);
+    assert_execution_matches(&reference, &seeded, &actors);
+}
+
+#[test]
+fn test_seeded_state_through_bond_record_upgrade() {
+    let e = Env::default();
+    let (reference, seeded, actors) = setup(&e);
+    let expected = views(&e, &reference, &actors);
+    let legacy = legacy_bond(&seeded, &actors);
+    e.as_contract(&seeded.address, || {
+        e.storage()
+            .instance()
+            .set(&DataKey::Bond(actors.identity.clone()), &legacy);
+    });
+    let identities = Vec::from_array(&e, [actors.identity.clone()]);
+
+    assert_eq!(seeded.get_bond_record_version(&actors.identity), 1);
+    assert_eq!(views(&e, &seeded, &actors), without_bond(&expected));
+    assert_eq!(
+        seeded.migrate_bond_records(&actors.admin, &identities, &true),
+        1
+    );
+    assert_eq!(views(&e, &seeded, &actors), without_bond(&expected));
+
+    assert_eq!(
+        seeded.migrate_bond_records(&actors.admin, &identities, &false),
+        1
+    );
+    assert_eq!(seeded.get_bond_record_version(&actors.identity), 2);
+    assert_eq!(views(&e, &seeded, &actors), expected);
+    assert_execution_matches(&reference, &seeded, &actors);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_seed_state_requires_admin() {
+    let e = Env::default();
+    let (_reference, seeded, actors) = setup(&e);
+    let spec = SeedSpec {
+        bonds: Vec::new(&e),
+        attestations: Vec::new(&e),
+        governance: Vec::new(&e),
+    };
+    seeded.test_seed_state(&actors.identity, &spec);
+}
@@ -0,0 +1,87 @@
+//! Per-identity Event Journal
+//!
+//! Support tooling needs an identity's recent history without replaying the whole event
+//! stream. Every bond, attestation (as subject) and slash event that affects an identity also
+//! appends a compact `JournalEntry` to that identity's journal: the event name as `kind`, the
+//! amount it moved (or the attestation weight) and the id it refers to (proposal, attestation,
+//! bond entry or close-out epoch; 0 when none applies).
+//!
+//! The journal is a ring buffer of at most `MAX_JOURNAL_ENTRIES` entries in persistent storage.
+//! Appending past the cap drops the oldest entry, and every write extends the entry's TTL.
+
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+/// Entries kept per identity; older ones are pruned on append.
+pub const MAX_JOURNAL_ENTRIES: u32 = 50;
+
+/// Largest `limit` accepted by `page`.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// TTL (in ledgers) below which a write extends the journal, and the TTL it is extended to.
+const TTL_THRESHOLD: u32 = 17_280;
+const TTL_EXTEND_TO: u32 = 518_400;
+
+/// @notice One journal line.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JournalEntry {
+    /// Ledger timestamp of the event.
+    pub at: u64,
+    /// Event name, e.g. `bond_created` or `attestation_added`.
+    pub kind: Symbol,
+    /// Amount moved by the event, or the attestation weight.
+    pub amount: i128,
+    /// Id the event refers to, 0 if none.
+    pub ref_id: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum JournalKey {
+    Entries(Address),
+}
+
+fn load(e: &Env, key: &JournalKey) -> Vec<JournalEntry> {
+    e.storage()
+        .persistent()
+        .get(key)
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+/// @notice Appends an entry to `identity`'s journal, pruning the oldest one at the cap.
+pub fn append(e: &Env, identity: &Address, kind: &str, amount: i128, ref_id: u64) {
+    let key = JournalKey::Entries(identity.clone());
+    let mut entries = load(e, &key);
+    entries.push_back(JournalEntry {
+        at: e.ledger().timestamp(),
+        kind: Symbol::new(e, kind),
+        amount,
+        ref_id,
+    });
+    while entries.len() > MAX_JOURNAL_ENTRIES {
+        entries.pop_front();
+    }
+    e.storage().persistent().set(&key, &entries);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// @notice Up to `limit` journal entries of `identity` starting at position `start`, oldest
+/// first. Positions are relative to the retained entries, so pruning shifts them.
+///
+/// # Panics
+/// - "page size too large" if `limit > MAX_PAGE_SIZE`
+#[must_use]
+pub fn page(e: &Env, identity: &Address, start: u32, limit: u32) -> Vec<JournalEntry> {
+    if limit > MAX_PAGE_SIZE {
+        panic!("page size too large");
+    }
+    let entries = load(e, &JournalKey::Entries(identity.clone()));
+    let mut out = Vec::new(e);
+    let end = start.saturating_add(limit).min(entries.len());
+    for i in start..end {
+        out.push_back(entries.get_unchecked(i));
+    }
+    out
+}
@@ -0,0 +1,207 @@
+//! Legacy Bond Migration
+//!
+//! Bonds used to live under a single `DataKey::Bond` entry, so creating a bond for a second
+//! identity overwrote the first. They are now keyed by holder (`DataKey::Bond(identity)`).
+//! A deployment upgraded from the singleton layout keeps its one bond under the old key, where
+//! no entrypoint reads it, until the admin calls `migrate_legacy_bond` to move it to the
+//! holder's key. The old unit variant is mirrored by `LegacyKey::Bond`, which encodes to the
+//! same storage key.
+//!
+//! The singleton predates the `kind` and `status` fields, so the stored value is decoded as the
+//! frozen `LegacyIdentityBond` layout and upgraded before it is written back.
+//!
+//! Per-holder bonds written before `kind` and `status` existed sit under `DataKey::Bond` in the
+//! same frozen layout, which no longer decodes as `IdentityBond`. `migrate_records` rewrites
+//! them in the current layout; `record_version` tells the two apart. Both migrations follow the
+//! dry-run convention in `docs/migrations.md`.
+
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Map, Symbol, TryFromVal, Val, Vec};
+
+use crate::{BondKind, BondStatus, DataKey, IdentityBond};
+
+/// Most identities accepted by one `migrate_records` call.
+pub const MAX_MIGRATION_BATCH: u32 = 100;
+
+/// Pre-migration storage layout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum LegacyKey {
+    /// The singleton bond entry.
+    Bond,
+}
+
+/// Bond layout before `kind` and `status` were added. Frozen: only used to decode bonds stored
+/// by earlier contract versions.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyIdentityBond {
+    pub identity: Address,
+    pub bonded_amount: i128,
+    pub bond_start: u64,
+    pub bond_duration: u64,
+    pub slashed_amount: i128,
+    pub active: bool,
+    pub is_rolling: bool,
+    pub withdrawal_requested_at: u64,
+    pub notice_period: u64,
+}
+
+impl LegacyIdentityBond {
+    /// Converts to the current layout. Perpetual bonds did not exist yet, so the kind is
+    /// `Fixed`, or `Rolling` for a rolling bond; the status is `Active`, or `Closed` for an
+    /// inactive bond.
+    #[must_use]
+    pub fn upgrade(self) -> IdentityBond {
+        let kind = if self.is_rolling {
+            BondKind::Rolling
+        } else {
+            BondKind::Fixed
+        };
+        let status = if self.active {
+            BondStatus::Active
+        } else {
+            BondStatus::Closed
+        };
+        IdentityBond {
+            identity: self.identity,
+            bonded_amount: self.bonded_amount,
+            bond_start: self.bond_start,
+            bond_duration: self.bond_duration,
+            slashed_amount: self.slashed_amount,
+            active: self.active,
+            is_rolling: self.is_rolling,
+            withdrawal_requested_at: self.withdrawal_requested_at,
+            notice_period: self.notice_period,
+            kind,
+            status,
+        }
+    }
+}
+
+/// @notice True if a bond is still stored under the singleton key.
+#[must_use]
+pub fn has_legacy_bond(e: &Env) -> bool {
+    e.storage().instance().has(&LegacyKey::Bond)
+}
+
+/// @notice Moves the singleton bond to its holder's key in the current layout. Returns the
+/// migrated bond, or `None` if there was nothing to migrate. With `dry_run` nothing is written.
+/// Emits `migration_preview` or `migration_applied` with
+/// `(migration, items_read, items_written, items_removed, digest)`. Admin only (enforced by
+/// caller).
+///
+/// # Panics
+/// - "identity already has a bond" if the holder opened a bond under the new layout.
+pub fn migrate(e: &Env, dry_run: bool) -> Option<IdentityBond> {
+    let legacy: Option<LegacyIdentityBond> = e.storage().instance().get(&LegacyKey::Bond);
+    let bond = legacy.map(LegacyIdentityBond::upgrade);
+    let mut encoded = Bytes::new(e);
+    if let Some(bond) = &bond {
+        let key = DataKey::Bond(bond.identity.clone());
+        if e.storage().instance().has(&key) {
+            panic!("identity already has a bond");
+        }
+        encoded.append(&key.to_xdr(e));
+        encoded.append(&bond.clone().to_xdr(e));
+    }
+
+    let digest: BytesN<32> = e.crypto().sha256(&encoded).into();
+    let count = u32::from(bond.is_some());
+    let summary = (Symbol::new(e, "legacy_bond"), count, count, count, digest);
+    if dry_run {
+        crate::events::publish(e, "migration_preview", summary);
+        return bond;
+    }
+    if let Some(bond) = &bond {
+        e.storage()
+            .instance()
+            .set(&DataKey::Bond(bond.identity.clone()), bond);
+        e.storage().instance().remove(&LegacyKey::Bond);
+        crate::events::publish_for(
+            e,
+            "bond_migrated",
+            bond.identity.clone(),
+            bond.bonded_amount,
+        );
+    }
+    crate::events::publish(e, "migration_applied", summary);
+    bond
+}
+
+/// Bond of `identity` and whether it is still stored in the legacy layout.
+fn load(e: &Env, identity: &Address) -> Option<(IdentityBond, bool)> {
+    let raw: Val = e
+        .storage()
+        .instance()
+        .get(&DataKey::Bond(identity.clone()))?;
+    // Decoding a struct from a map with a different field count traps in the host instead of
+    // returning an error, so tell the layouts apart by the `kind` field first.
+    let fields =
+        Map::<Symbol, Val>::try_from_val(e, &raw).unwrap_or_else(|_| panic!("corrupt bond"));
+    if fields.contains_key(Symbol::new(e, "kind")) {
+        let bond = IdentityBond::try_from_val(e, &raw).unwrap_or_else(|_| panic!("corrupt bond"));
+        return Some((bond, false));
+    }
+    let legacy =
+        LegacyIdentityBond::try_from_val(e, &raw).unwrap_or_else(|_| panic!("corrupt bond"));
+    Some((legacy.upgrade(), true))
+}
+
+/// @notice Storage layout version of `identity`'s bond: 1 for the layout without `kind` and
+/// `status`, 2 for the current one.
+///
+/// # Panics
+/// - "no bond"
+#[must_use]
+pub fn record_version(e: &Env, identity: &Address) -> u32 {
+    match load(e, identity) {
+        Some((_, true)) => 1,
+        Some((_, false)) => 2,
+        None => panic!("no bond"),
+    }
+}
+
+/// @notice Rewrites the bonds of `identities` still stored in the legacy layout in the current
+/// one (admin check enforced by the caller). Identities without a bond and bonds already in the
+/// current layout are skipped, so repeating a batch writes nothing. With `dry_run` nothing is
+/// written. Emits `migration_preview` or `migration_applied` with
+/// `(migration, items_read, items_written, items_removed, digest)`. Returns the number of bonds
+/// rewritten, or that would be.
+///
+/// # Panics
+/// - "too many identities" above `MAX_MIGRATION_BATCH`
+pub fn migrate_records(e: &Env, identities: &Vec<Address>, dry_run: bool) -> u32 {
+    if identities.len() > MAX_MIGRATION_BATCH {
+        panic!("too many identities");
+    }
+    let mut read = 0_u32;
+    let mut writes: Vec<IdentityBond> = Vec::new(e);
+    let mut encoded = Bytes::new(e);
+    for identity in identities.iter() {
+        let Some((bond, legacy)) = load(e, &identity) else {
+            continue;
+        };
+        read += 1;
+        if legacy && !writes.iter().any(|w| w.identity == identity) {
+            encoded.append(&DataKey::Bond(identity).to_xdr(e));
+            encoded.append(&bond.clone().to_xdr(e));
+            writes.push_back(bond);
+        }
+    }
+
+    let digest: BytesN<32> = e.crypto().sha256(&encoded).into();
+    let written = writes.len();
+    let summary = (Symbol::new(e, "bond_v2"), read, written, 0_u32, digest);
+    if dry_run {
+        crate::events::publish(e, "migration_preview", summary);
+        return written;
+    }
+    for bond in writes.iter() {
+        e.storage()
+            .instance()
+            .set(&DataKey::Bond(bond.identity.clone()), &bond);
+    }
+    crate::events::publish(e, "migration_applied", summary);
+    written
+}
@@ -1,47 +1,102 @@
 #![no_std]
 
+// Test-support entrypoints (callback registration, state seeding, failpoints, snapshots) live
+// behind `testing-hooks`. Tests enable it through the self dev-dependency in Cargo.toml; a
+// release-profile build with it enabled would ship them in the contract ABI, so refuse it.
+#[cfg(all(feature = "testing-hooks", not(debug_assertions)))]
+compile_error!(concat!(
+    "the `testing-hooks` feature exposes test-only entrypoints ",
+    "and must not be enabled in release builds"
+));
+
 use soroban_sdk::{
-    contract, contractimpl, contracttype, Address, Env, IntoVal, String, Symbol, Val, Vec,
+    contract, contractimpl, contracttype, Address, Bytes, BytesN, Env, IntoVal, String, Symbol,
+    Val, Vec,
 };
 
-mod early_exit_penalty;
-mod rolling_bond;
-mod tiered_bond;
 pub mod access_control;
+mod attestation_import;
+mod attestation_privacy;
+mod attestation_store;
+mod attestation_usage;
+mod attester_bond;
 mod batch;
+mod bond_freeze;
+mod bond_gate;
+mod bond_proof;
+mod bond_rewards;
+mod bond_split;
+mod bond_status;
+mod bond_transfer;
+mod closeout;
+mod collateral;
+pub mod context;
+mod dispute_bond;
+mod dust;
 pub mod early_exit_penalty;
+pub mod economic_params;
+pub mod eligibility;
+mod emergency;
+mod events;
 #[allow(dead_code)]
 pub mod evidence;
-mod events;
-mod emergency;
+mod exposure_caps;
+mod failpoints;
+mod features;
 mod fees;
+mod free_withdrawal;
 pub mod governance_approval;
+mod governor_fees;
+mod identity_label;
+mod init_v2;
+mod journal;
+mod legacy_bond;
 #[allow(dead_code)]
 mod math;
+mod maturity_ladder;
+mod multi_bond;
+#[allow(dead_code)]
 mod nonce;
+mod parameters;
 pub mod pausable;
+mod payout_queue;
+mod perpetual_bond;
+mod reason_code;
+mod rescue;
+mod residual_floor;
+mod restrictions;
+mod role_registry;
 pub mod rolling_bond;
-mod parameters;
-mod rolling_bond;
+#[cfg(any(test, feature = "testing-hooks"))]
+pub mod seed;
+mod self_lock;
+#[allow(dead_code)]
+mod slash_accounting;
 #[allow(dead_code)]
 mod slash_history;
+mod slash_pipeline;
+mod slash_proceeds;
+mod slash_settlement;
+mod slash_simulation;
 #[allow(dead_code)]
 mod slashing;
-mod tiered_bond;
-mod validation;
+#[cfg(any(test, feature = "testing-hooks"))]
+mod snapshot;
+mod subject_hook;
+mod subject_policy;
+mod subject_seq;
 pub mod tiered_bond;
 mod token_integration;
+mod topup_sponsor;
+pub mod types;
+mod uncollectable_slash;
+mod valid_attestations;
 mod validation;
 pub mod verifier;
 mod weighted_attestation;
-pub mod types;
 
-use crate::access_control::{
-    add_verifier_role, is_verifier, remove_verifier_role, require_admin, require_verifier,
-};
-use soroban_sdk::{
-    contract, contractimpl, contracttype, Address, Env, IntoVal, String, Symbol, Val, Vec,
-};
+use crate::access_control::{is_verifier, require_identity_owner, require_verifier};
+use crate::bond_status::BondTransition;
 
 use soroban_sdk::token::TokenClient;
 
@@ -58,10 +113,34 @@ pub enum BondTier {
     Platinum,
 }
 
+/// How a bond matures: at a fixed end, by auto-renewing periods, or never (exit by notice only).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BondKind {
+    Fixed,
+    Rolling,
+    Perpetual,
+}
+
+/// Lifecycle status stored on every bond; see `bond_status` for the legal transitions.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BondStatus {
+    Active,
+    /// A rolling or perpetual bond whose holder requested withdrawal.
+    NoticeServing,
+    /// Lock-up or notice has elapsed and the bond has been withdrawn from.
+    Matured,
+    Quarantined,
+    Frozen,
+    /// Wound down (`active = false`).
+    Closed,
+}
+
 pub mod cooldown;
 
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct IdentityBond {
     pub identity: Address,
     pub bonded_amount: i128,
@@ -72,22 +151,13 @@ pub struct IdentityBond {
     pub is_rolling: bool,
     pub withdrawal_requested_at: u64,
     pub notice_period: u64,
+    pub kind: BondKind,
+    pub status: BondStatus,
 }
 
 // Re-export batch types
 pub use batch::{BatchBondParams, BatchBondResult};
 
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Attestation {
-    pub id: u64,
-    pub attester: Address,
-    pub subject: Address,
-    pub attestation_data: String,
-    pub timestamp: u64,
-    pub revoked: bool,
-// Re-export batch types
-pub use batch::{BatchBondParams, BatchBondResult};
 /// A pending cooldown withdrawal request. Created when a bond holder signals
 /// intent to withdraw; the withdrawal can only execute after the cooldown
 /// period elapses.
@@ -100,24 +170,21 @@ pub struct CooldownRequest {
 }
 
 #[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
     Admin,
-    Bond,
+    /// Bond held by an identity.
+    Bond(Address),
     Token,
     Attester(Address),
     Attestation(u64),
     AttestationCounter,
     SubjectAttestations(Address),
     DuplicateCheck(Address, Address, String),
-}
-
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub enum BondTier {
-    Bronze,
-    Silver,
-    Gold,
-    Platinum,
+    /// Close-out statement for (identity, epoch).
+    Closeout(Address, u32),
+    /// Additional bond (id >= 1) held by an identity; bond 0 stays under `Bond`.
+    BondEntry(Address, u64),
     /// Per-identity attestation count (updated on add/revoke).
     SubjectAttestationCount(Address),
     /// Per-identity nonce for replay prevention.
@@ -154,21 +221,89 @@ pub enum BondTier {
     BondToken,
 }
 
+/// Slash request view: the proposal plus its pipeline timestamps (`submitted_at` 0 for requests
+/// created before tracking existed), the target identity's label and the ledger it was read at.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SlashRequestDescription {
+    pub proposal: governance_approval::SlashProposal,
+    pub timeline: slash_pipeline::SlashTimeline,
+    pub label: Option<Symbol>,
+    pub context: context::Context,
+}
+
+/// Snapshot of the reentrancy lock for monitoring.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LockInfo {
+    pub locked: bool,
+    /// Ledger timestamp at which the lock was acquired (0 when not held).
+    pub held_since: u64,
+    /// Ledger sequence at which the lock was acquired (0 when not held).
+    pub held_since_ledger: u32,
+    /// Ledgers closed since the lock was acquired (0 when not held).
+    pub held_for_ledgers: u32,
+}
+
+/// A lock held across ledgers for longer than this is considered stuck and is cleared on
+/// the next acquisition attempt.
+const LOCK_STALE_AFTER_SECS: u64 = 7 * 86_400;
+
+/// Identity bond contract.
+///
+/// Test-only entrypoints (`set_callback`, `test_seed_state`, `arm_failpoint`, `export_state`,
+/// `import_state`, ...) exist only in unit tests or with the `testing-hooks` feature.
+/// The CI release job checks that the release wasm exports none of them.
 #[contract]
 pub struct CredenceBond;
 
 #[contractimpl]
 impl CredenceBond {
-    /// Initialize the contract (set admin).
-    fn acquire_lock(e: &Env) {
+    /// Acquire the reentrancy lock for `context` (the guarded entrypoint name).
+    ///
+    /// If the lock is already held, panics with "reentrancy detected". Nothing is recorded: the
+    /// panic rolls back every write and event of the call, so a blocked attempt is visible only
+    /// as the failed call itself. A lock held since an earlier ledger for longer than
+    /// `LOCK_STALE_AFTER_SECS` is treated as stuck: it is cleared with a
+    /// `reentrancy_lock_expired` event and re-acquired.
+    fn acquire_lock(e: &Env, context: &str) {
+        let now = e.ledger().timestamp();
         if Self::check_lock(e) {
-            panic!("reentrancy detected");
+            let held_since = Self::lock_held_since(e);
+            if now.saturating_sub(held_since) < LOCK_STALE_AFTER_SECS {
+                panic!("reentrancy detected");
+            }
+            events::publish(
+                e,
+                "reentrancy_lock_expired",
+                (Symbol::new(e, context), held_since, now),
+            );
         }
         e.storage().instance().set(&Self::lock_key(e), &true);
+        e.storage().instance().set(&Self::lock_since_key(e), &now);
+        e.storage()
+            .instance()
+            .set(&Self::lock_seq_key(e), &e.ledger().sequence());
     }
 
     fn release_lock(e: &Env) {
         e.storage().instance().set(&Self::lock_key(e), &false);
+        e.storage().instance().remove(&Self::lock_since_key(e));
+        e.storage().instance().remove(&Self::lock_seq_key(e));
+    }
+
+    fn lock_held_since_ledger(e: &Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&Self::lock_seq_key(e))
+            .unwrap_or(0)
+    }
+
+    fn lock_held_since(e: &Env) -> u64 {
+        e.storage()
+            .instance()
+            .get(&Self::lock_since_key(e))
+            .unwrap_or(0)
     }
 
     fn check_lock(e: &Env) -> bool {
@@ -182,16 +317,31 @@ impl CredenceBond {
         Symbol::new(e, "lock")
     }
 
+    fn lock_since_key(e: &Env) -> Symbol {
+        Symbol::new(e, "lock_since")
+    }
+
+    fn lock_seq_key(e: &Env) -> Symbol {
+        Symbol::new(e, "lock_seq")
+    }
+
     fn callback_key(e: &Env) -> Symbol {
         Symbol::new(e, "callback")
     }
 
-    #[allow(dead_code)]
-    fn with_reentrancy_guard<T, F: FnOnce() -> T>(e: &Env, f: F) -> T {
-        if Self::check_lock(e) {
-            panic!("reentrancy detected");
+    /// Invoke `fn_name(amount)` on the registered callback contract, if any.
+    fn invoke_callback(e: &Env, fn_name: &str, amount: i128) {
+        let callback: Option<Address> = e.storage().instance().get(&Self::callback_key(e));
+        if let Some(cb_addr) = callback {
+            failpoints::hit(e, failpoints::CALLBACK_INVOKE);
+            let args: Vec<Val> = Vec::from_array(e, [amount.into_val(e)]);
+            e.invoke_contract::<Val>(&cb_addr, &Symbol::new(e, fn_name), args);
         }
-        Self::acquire_lock(e);
+    }
+
+    #[allow(dead_code)]
+    fn with_reentrancy_guard<T, F: FnOnce() -> T>(e: &Env, context: &str, f: F) -> T {
+        Self::acquire_lock(e, context);
         let result = f();
         Self::release_lock(e);
         result
@@ -208,8 +358,34 @@ impl CredenceBond {
         }
     }
 
-    /// Initialize the contract (admin).
+    /// Require the bond admin, or (when a role registry is configured) a caller the registry
+    /// allows to call `fn_name` on this contract.
+    fn require_admin_or_role(e: &Env, caller: &Address, fn_name: &str) {
+        let stored_admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        if stored_admin == *caller {
+            return;
+        }
+        if let Some(registry) = role_registry::get_registry(e) {
+            if role_registry::can_call(e, &registry, caller, fn_name) {
+                return;
+            }
+        }
+        panic!("not admin");
+    }
+
+    /// Initialize the contract (admin). Retrying with the same admin is a no-op; use
+    /// `initialize_v2` to populate config sections added by later upgrades.
     pub fn initialize(e: Env, admin: Address) {
+        if let Some(existing) = e.storage().instance().get::<_, Address>(&DataKey::Admin) {
+            if existing == admin {
+                return;
+            }
+            panic!("already initialized");
+        }
         e.storage().instance().set(&DataKey::Admin, &admin);
         // Initialize pause state
         e.storage().instance().set(&DataKey::Paused, &false);
@@ -226,21 +402,81 @@ impl CredenceBond {
             .set(&Symbol::new(&e, "admin"), &admin);
     }
 
+    /// Populate config sections introduced after the original `initialize` (admin only). Only
+    /// sections that are still absent may be written; the call panics without writing anything
+    /// if a requested section already exists. Returns the sections populated.
+    pub fn initialize_v2(
+        e: Env,
+        admin: Address,
+        new_sections: init_v2::InitV2Config,
+    ) -> Vec<Symbol> {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        init_v2::initialize(&e, new_sections)
+    }
+
+    /// Config schema version: 0 uninitialized, 1 after `initialize`, 2 after `initialize_v2`.
+    pub fn get_config_schema_version(e: Env) -> u32 {
+        init_v2::schema_version(&e)
+    }
+
+    /// Post-v1 config sections that currently hold a value.
+    pub fn get_configured_sections(e: Env) -> Vec<Symbol> {
+        init_v2::configured_sections(&e)
+    }
+
     /// Set early exit penalty config (admin only). Penalty in basis points (e.g. 500 = 5%).
     pub fn set_early_exit_config(e: Env, admin: Address, treasury: Address, penalty_bps: u32) {
         pausable::require_not_paused(&e);
-        let stored_admin: Address = e
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("not initialized"));
         admin.require_auth();
-        if admin != stored_admin {
-            panic!("not admin");
         Self::require_admin_internal(&e, &admin);
         early_exit_penalty::set_config(&e, treasury, penalty_bps);
     }
 
+    /// Cap the value any one identity may have bonded and the total the contract may hold
+    /// (admin only). 0 disables a cap. Deposits that would exceed a cap panic.
+    pub fn set_exposure_caps(e: Env, admin: Address, per_identity_cap: i128, global_cap: i128) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_exposure_caps");
+        exposure_caps::set_caps(&e, per_identity_cap, global_cap);
+    }
+
+    pub fn get_exposure_caps(e: Env) -> exposure_caps::ExposureCaps {
+        exposure_caps::get_caps(&e)
+    }
+
+    /// Total value currently bonded, as checked against the global exposure cap.
+    pub fn get_total_bonded(e: Env) -> i128 {
+        exposure_caps::total_bonded(&e)
+    }
+
+    /// Allow `free_withdrawal_bps` of a bond to be withdrawn early without penalty once per
+    /// `free_withdrawal_period` seconds (0 = 30 days). Admin only; bps of 0 disables.
+    pub fn set_free_withdrawal_config(
+        e: Env,
+        admin: Address,
+        free_withdrawal_bps: u32,
+        free_withdrawal_period: u64,
+    ) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_free_withdrawal_config");
+        free_withdrawal::set_config(&e, free_withdrawal_bps, free_withdrawal_period);
+    }
+
+    pub fn get_free_withdrawal_config(e: Env) -> free_withdrawal::FreeWithdrawalConfig {
+        free_withdrawal::get_config(&e)
+    }
+
+    /// Penalty-free amount `identity` can still withdraw early in the current period.
+    pub fn get_free_withdrawal_remaining(e: Env, identity: Address) -> i128 {
+        let bond: IdentityBond = e
+            .storage()
+            .instance()
+            .get(&DataKey::Bond(identity))
+            .unwrap_or_else(|| panic!("no bond"));
+        free_withdrawal::remaining(&e, &bond.identity, bond.bonded_amount)
+    }
+
     /// @notice Configure emergency withdrawal controls.
     /// @dev Requires admin authorization and stores governance approver, treasury, fee, and enabled mode.
     /// @param admin Admin address authorized to configure emergency settings.
@@ -282,13 +518,15 @@ impl CredenceBond {
     /// @dev Requires elevated approval from both admin and governance, applies emergency fee, emits event, and writes immutable audit record.
     /// @param admin Admin approver for emergency override.
     /// @param governance Governance approver for emergency override.
+    /// @param identity Holder of the bond to withdraw from.
     /// @param amount Gross amount withdrawn from bond.
-    /// @param reason Symbolic reason code for audit trail.
+    /// @param reason Symbolic reason code for audit trail (non-empty, at most 9 characters).
     /// @return Updated bond after emergency withdrawal.
     pub fn emergency_withdraw(
         e: Env,
         admin: Address,
         governance: Address,
+        identity: Address,
         amount: i128,
         reason: Symbol,
     ) -> IdentityBond {
@@ -304,11 +542,12 @@ impl CredenceBond {
         if amount <= 0 {
             panic!("amount must be positive");
         }
+        reason_code::require_reason(&e, &reason);
 
         admin.require_auth();
         governance.require_auth();
 
-        let key = DataKey::Bond;
+        let key = DataKey::Bond(identity);
         let mut bond: IdentityBond = e
             .storage()
             .instance()
@@ -328,6 +567,7 @@ impl CredenceBond {
             .checked_sub(fee_amount)
             .expect("emergency fee exceeds amount");
 
+        bond_rewards::settle(&e, &bond.identity);
         let old_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
         bond.bonded_amount = bond
             .bonded_amount
@@ -336,6 +576,9 @@ impl CredenceBond {
         if bond.slashed_amount > bond.bonded_amount {
             panic!("slashed amount exceeds bonded amount");
         }
+        if dust::remainder(&bond) == 0 && bond_status::is_open(&bond.status) {
+            bond_status::transition(&e, &mut bond, BondTransition::Close);
+        }
         let new_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
         tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
 
@@ -350,6 +593,7 @@ impl CredenceBond {
             governance,
             reason.clone(),
         );
+        exposure_caps::release(&e, amount);
 
         emergency::emit_emergency_withdrawal_event(
             &e,
@@ -362,6 +606,7 @@ impl CredenceBond {
         );
 
         e.storage().instance().set(&key, &bond);
+        maturity_ladder::sync(&e, &bond);
         bond
     }
 
@@ -384,44 +629,49 @@ impl CredenceBond {
         emergency::get_record(&e, id)
     }
 
+    /// @notice Register `attester` through the legacy admin path (admin auth, no stake). Goes
+    /// through the verifier module so the attester flag, role and `VerifierInfo` stay in step.
     pub fn register_attester(e: Env, attester: Address) {
         pausable::require_not_paused(&e);
-        let _admin: Address = e
+        let admin: Address = e
             .storage()
             .instance()
             .get(&DataKey::Admin)
             .unwrap_or_else(|| panic!("not initialized"));
-        require_admin(&e, &_admin);
-        _admin.require_auth();
-        add_verifier_role(&e, &_admin, &attester);
-        Self::require_admin_internal(&e, &admin);
         admin.require_auth();
-        add_verifier_role(&e, &admin, &attester);
-        e.storage()
-            .instance()
-            .set(&DataKey::Attester(attester.clone()), &true);
-        // Ensure verifier info exists for reputation tracking (legacy admin path).
         verifier::register_legacy(&e, &attester);
-        e.events()
-            .publish((Symbol::new(&e, "attester_registered"),), attester);
+        events::publish(&e, "attester_registered", attester);
     }
 
+    /// @notice Remove `attester` (admin auth). Deactivates its `VerifierInfo` when present, so
+    /// the flag and the registry cannot disagree.
     pub fn unregister_attester(e: Env, attester: Address) {
         pausable::require_not_paused(&e);
-        let _admin: Address = e
+        let admin: Address = e
             .storage()
             .instance()
             .get(&DataKey::Admin)
             .unwrap_or_else(|| panic!("not initialized"));
-        require_admin(&e, &_admin);
-        _admin.require_auth();
-        remove_verifier_role(&e, &_admin, &attester);
-        Self::require_admin_internal(&e, &admin);
         admin.require_auth();
-        remove_verifier_role(&e, &admin, &attester);
         verifier::deactivate_if_exists(&e, &attester, Symbol::new(&e, "admin"));
-        e.events()
-            .publish((Symbol::new(&e, "attester_unregistered"),), attester);
+        events::publish(&e, "attester_unregistered", attester);
+    }
+
+    /// @notice Addresses among `addresses` whose attester flag or verifier role disagrees with
+    /// their `VerifierInfo` (read-only).
+    pub fn audit_attester_consistency(
+        e: Env,
+        addresses: Vec<Address>,
+    ) -> Vec<verifier::AttesterMismatch> {
+        verifier::audit_attester_consistency(&e, &addresses)
+    }
+
+    /// @notice Re-derive the attester flag and role of mismatched `addresses` from their
+    /// `VerifierInfo` (admin only). Returns the number repaired.
+    pub fn repair_attester_flags(e: Env, admin: Address, addresses: Vec<Address>) -> u32 {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        verifier::repair_attester_flags(&e, &addresses)
     }
 
     pub fn is_attester(e: Env, attester: Address) -> bool {
@@ -440,6 +690,195 @@ impl CredenceBond {
         verifier::get_min_stake(&e)
     }
 
+    /// @notice Require attesters' own bonds to run for at least `min_remaining` more seconds
+    /// when they add an attestation (admin only).
+    pub fn set_min_attester_bond_remaining(e: Env, admin: Address, min_remaining: u64) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_min_attester_bond_remaining");
+        attester_bond::set_min_remaining(&e, min_remaining);
+    }
+
+    /// @notice Disable the attester bond remaining-duration requirement (admin only).
+    pub fn clear_attester_bond_remaining(e: Env, admin: Address) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "clear_attester_bond_remaining");
+        attester_bond::clear_min_remaining(&e);
+    }
+
+    /// @notice Get the attester bond remaining-duration requirement (`None` when disabled).
+    pub fn get_min_attester_bond_remaining(e: Env) -> Option<u64> {
+        attester_bond::get_min_remaining(&e)
+    }
+
+    /// @notice Get how many seconds the attester's own bond still runs (`None` without an
+    /// active bond). Compare with `get_min_attester_bond_remaining` to predict a refusal.
+    pub fn get_attester_bond_remaining(e: Env, attester: Address) -> Option<u64> {
+        attester_bond::attester_remaining(&e, &attester)
+    }
+
+    /// @notice Require partial withdrawals by subjects with effective attestations to leave at
+    /// least `amount` available (admin only; 0 disables).
+    pub fn set_min_residual_bond(e: Env, admin: Address, amount: i128) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_min_residual_bond");
+        residual_floor::set_floor(&e, amount);
+    }
+
+    /// @notice Get the residual bond floor (0 when disabled).
+    pub fn get_min_residual_bond(e: Env) -> i128 {
+        residual_floor::get_floor(&e)
+    }
+
+    /// @notice Dock a re-bond opened within `secs` of an uncollectable slash by the uncollectable
+    /// amount (admin only; 0, the default, disables docking).
+    pub fn set_uncollectable_rebond_window(e: Env, admin: Address, secs: u64) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_uncollectable_rebond_window");
+        uncollectable_slash::set_rebond_window(&e, secs);
+    }
+
+    /// @notice Get the uncollectable re-bond docking window in seconds (0 when disabled).
+    pub fn get_uncollectable_rebond_window(e: Env) -> u64 {
+        uncollectable_slash::get_rebond_window(&e)
+    }
+
+    /// @notice Uncollectable slash amount `identity`'s next bond may be docked.
+    pub fn get_pending_uncollectable(e: Env, identity: Address) -> i128 {
+        uncollectable_slash::get_pending(&e, &identity)
+    }
+
+    /// @notice Freeze `identity`'s bond while an investigation runs (admin only): withdrawals,
+    /// withdrawal requests and transfers panic with "bond frozen"; top-ups and slashing still
+    /// work.
+    pub fn freeze_bond(e: Env, admin: Address, identity: Address, reason: Symbol) -> IdentityBond {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "freeze_bond");
+        bond_freeze::freeze(&e, &identity, reason)
+    }
+
+    /// @notice Lift a freeze placed by `freeze_bond` (admin only).
+    pub fn unfreeze_bond(e: Env, admin: Address, identity: Address) -> IdentityBond {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "unfreeze_bond");
+        bond_freeze::unfreeze(&e, &identity)
+    }
+
+    /// @notice Reason `identity`'s bond was frozen for, or `None` if it is not frozen.
+    pub fn get_freeze_reason(e: Env, identity: Address) -> Option<Symbol> {
+        bond_freeze::get_reason(&e, &identity)
+    }
+
+    /// @notice Lock `identity`'s bond against owner-initiated withdrawals, transfers and grants
+    /// until `until` (holder auth, at most 90 days ahead). The lock can be extended but not
+    /// shortened; only `emergency_release_self_lock` lifts it early.
+    pub fn self_lock(e: Env, identity: Address, until: u64) {
+        identity.require_auth();
+        self_lock::lock(&e, &identity, until);
+    }
+
+    /// @notice Lift `identity`'s self-lock early. Requires both admin and the configured
+    /// emergency governance approvals.
+    pub fn emergency_release_self_lock(
+        e: Env,
+        admin: Address,
+        governance: Address,
+        identity: Address,
+    ) {
+        Self::require_admin_internal(&e, &admin);
+        if governance != emergency::get_config(&e).governance {
+            panic!("not governance");
+        }
+        admin.require_auth();
+        governance.require_auth();
+        self_lock::release(&e, &identity);
+    }
+
+    /// @notice Time until which `identity`'s bond is self-locked (0 if it is not).
+    pub fn get_self_locked_until(e: Env, identity: Address) -> u64 {
+        self_lock::get_until(&e, &identity)
+    }
+
+    /// @notice Restrict attestations to subjects with a current or past bond (admin only).
+    pub fn set_require_subject_known(e: Env, admin: Address, required: bool) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_require_subject_known");
+        subject_policy::set_require_known_subject(&e, required);
+    }
+
+    /// @notice Whether attestations require a known subject (default false).
+    pub fn get_require_subject_known(e: Env) -> bool {
+        subject_policy::requires_known_subject(&e)
+    }
+
+    /// @notice Register `hook_contract` to receive `on_attestation(subject, id, added)` whenever
+    /// `subject` gains or loses an attestation (subject auth). Replaces any existing hook; a
+    /// failing hook never blocks the attestation and is reported with `hook_failed`.
+    pub fn register_subject_hook(e: Env, subject: Address, hook_contract: Address) {
+        subject.require_auth();
+        subject_hook::register(&e, &subject, &hook_contract);
+    }
+
+    /// @notice Remove `subject`'s notification hook (subject auth).
+    pub fn clear_subject_hook(e: Env, subject: Address) {
+        subject.require_auth();
+        subject_hook::clear(&e, &subject);
+    }
+
+    /// @notice Hook contract notified of `subject`'s attestation changes, if any.
+    pub fn get_subject_hook(e: Env, subject: Address) -> Option<Address> {
+        subject_hook::get_hook(&e, &subject)
+    }
+
+    /// @notice Re-deliver up to `limit` failed hook notifications, oldest first. Callable by
+    /// anyone. Returns the number delivered.
+    pub fn retry_failed_callbacks(e: Env, limit: u32) -> u32 {
+        pausable::require_not_paused(&e);
+        subject_hook::retry_failed(&e, limit)
+    }
+
+    /// @notice Failed hook notifications awaiting retry, oldest first.
+    pub fn get_failed_callbacks(e: Env) -> Vec<subject_hook::FailedDelivery> {
+        subject_hook::get_failed(&e)
+    }
+
+    /// @notice Replace the labels `set_identity_label` may assign (admin only, at most
+    /// `MAX_ALLOWED_LABELS`).
+    pub fn set_allowed_labels(e: Env, admin: Address, labels: Vec<Symbol>) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        identity_label::set_allowed(&e, labels);
+    }
+
+    pub fn get_allowed_labels(e: Env) -> Vec<Symbol> {
+        identity_label::get_allowed(&e)
+    }
+
+    /// @notice Annotate `identity` with an allow-listed label (admin only). Metadata only; no
+    /// protocol logic reads it.
+    pub fn set_identity_label(e: Env, admin: Address, identity: Address, label: Symbol) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        identity_label::set_label(&e, &identity, label);
+    }
+
+    /// @notice Remove `identity`'s label (admin only). No-op if none is set.
+    pub fn clear_identity_label(e: Env, admin: Address, identity: Address) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        identity_label::clear_label(&e, &identity);
+    }
+
+    /// @notice Remove the labels of all `identities` (admin only). Returns how many were set.
+    pub fn clear_labels(e: Env, admin: Address, identities: Vec<Address>) -> u32 {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        identity_label::clear_labels(&e, &identities)
+    }
+
+    pub fn get_identity_label(e: Env, identity: Address) -> Option<Symbol> {
+        identity_label::get_label(&e, &identity)
+    }
+
     /// @notice Register (or reactivate) as a verifier by staking the configured token.
     /// @dev Caller must approve the contract to transfer the stake amount via `transfer_from`.
     pub fn register_verifier(
@@ -448,7 +887,7 @@ impl CredenceBond {
         stake_deposit: i128,
     ) -> verifier::VerifierInfo {
         verifier_addr.require_auth();
-        Self::with_reentrancy_guard(&e, || {
+        Self::with_reentrancy_guard(&e, "register_verifier", || {
             verifier::register_with_stake(&e, &verifier_addr, stake_deposit)
         })
     }
@@ -477,7 +916,9 @@ impl CredenceBond {
         amount: i128,
     ) -> verifier::VerifierInfo {
         verifier_addr.require_auth();
-        Self::with_reentrancy_guard(&e, || verifier::withdraw_stake(&e, &verifier_addr, amount))
+        Self::with_reentrancy_guard(&e, "withdraw_verifier_stake", || {
+            verifier::withdraw_stake(&e, &verifier_addr, amount)
+        })
     }
 
     /// @notice Get verifier info (stake, reputation, status), if present.
@@ -485,6 +926,39 @@ impl CredenceBond {
         verifier::get_verifier_info(&e, &verifier_addr)
     }
 
+    /// @notice Aggregate verifier health: active count, active and total stake, min/max active
+    /// stake and attestations issued/revoked across all verifiers.
+    pub fn get_verifier_stats(e: Env) -> verifier::VerifierStats {
+        verifier::get_stats(&e)
+    }
+
+    /// @notice Publish the caller's display name hash and policy URI (verifier auth). Allowed
+    /// while active or inactive; the URI is capped at `MAX_METADATA_URI_LEN` bytes.
+    pub fn set_verifier_metadata(
+        e: Env,
+        verifier_addr: Address,
+        name_hash: BytesN<32>,
+        uri: String,
+    ) -> verifier::VerifierMetadata {
+        verifier_addr.require_auth();
+        verifier::set_metadata(&e, &verifier_addr, name_hash, uri)
+    }
+
+    /// @notice Remove a verifier's metadata, e.g. for abusive content (admin only).
+    pub fn clear_verifier_metadata(e: Env, admin: Address, verifier_addr: Address) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        verifier::clear_metadata(&e, &verifier_addr);
+    }
+
+    /// @notice Verifier info plus published metadata, if the verifier is registered.
+    pub fn describe_verifier(
+        e: Env,
+        verifier_addr: Address,
+    ) -> Option<verifier::VerifierDescription> {
+        verifier::describe(&e, &verifier_addr)
+    }
+
     /// @notice Set verifier reputation (admin only).
     pub fn set_verifier_reputation(
         e: Env,
@@ -519,16 +993,10 @@ impl CredenceBond {
         token_integration::get_usdc_network(&e)
     }
 
-    /// Create or top-up a bond for an identity (non-rolling helper).
-    pub fn create_bond(e: Env, identity: Address, amount: i128, duration: u64) -> IdentityBond {
-        Self::create_bond_with_rolling(e, identity, amount, duration, false, 0)
-    }
-
-    /// Create a bond with rolling parameters.
-    pub fn create_bond_with_rolling(
-    /// Create a bond for an identity.
-    /// Transfers USDC from the identity to the contract (token must be set and approved).
-    /// Bond creation fee (if configured) is deducted and recorded for the treasury.
+    /// Create a bond for an identity (identity auth).
+    /// Transfers `amount` of the configured token from the identity to the contract (token must
+    /// be set and approved). Bond creation fee (if configured) is deducted and recorded for the
+    /// treasury.
     pub fn create_bond(
         e: Env,
         identity: Address,
@@ -540,20 +1008,14 @@ impl CredenceBond {
         pausable::require_not_paused(&e);
         // Validate bond amount before creating the bond
         validation::validate_bond_amount(amount);
-        
+
         // Validate bond duration is within allowed range
         validation::validate_bond_duration(duration);
-        Self::create_bond_with_rolling(
-            e,
-            identity,
-            amount,
-            duration,
-            is_rolling,
-            notice_period_duration,
-        )
+        Self::create_bond_with_rolling(e, identity, amount, duration, is_rolling, notice_period)
     }
 
-    /// Create a bond with rolling parameters.
+    /// Create a bond with rolling parameters, without the amount/duration bounds applied by
+    /// `create_bond`. Same auth and token transfer as `create_bond`.
     pub fn create_bond_with_rolling(
         e: Env,
         identity: Address,
@@ -562,25 +1024,126 @@ impl CredenceBond {
         is_rolling: bool,
         notice_period_duration: u64,
     ) -> IdentityBond {
-        if amount < 0 {
-            panic!("amount must be non-negative");
-        }
-        identity.require_auth();
-        token_integration::transfer_into_contract(&e, &identity, amount);
+        let kind = if is_rolling {
+            BondKind::Rolling
+        } else {
+            BondKind::Fixed
+        };
+        Self::open_bond(e, identity, amount, duration, kind, notice_period_duration)
+    }
 
-        let token: Address = e
-            .storage()
-            .instance()
-            .get(&DataKey::Token)
-            .unwrap_or_else(|| panic!("token not set"));
-        let contract = e.current_contract_address();
-        TokenClient::new(&e, &token).transfer_from(&contract, &identity, &contract, &amount);
+    /// @notice Create a perpetual bond: no lock-up, never matures, and no early exit. Funds leave
+    /// only via `request_withdrawal` followed by `notice_period` seconds (at least 30 days).
+    pub fn create_perpetual_bond(
+        e: Env,
+        identity: Address,
+        amount: i128,
+        notice_period: u64,
+    ) -> IdentityBond {
+        pausable::require_not_paused(&e);
+        validation::validate_bond_amount(amount);
+        perpetual_bond::validate_notice_period(notice_period);
+        Self::open_bond(
+            e,
+            identity,
+            amount,
+            perpetual_bond::PERPETUAL_DURATION,
+            BondKind::Perpetual,
+            notice_period,
+        )
+    }
+
+    /// @notice Open an additional fixed bond for `identity` next to its existing ones and return
+    /// its `bond_id` (1, 2, ...). Same bounds, gate, fee and token transfer as `create_bond`.
+    /// Bond 0 is the bond managed by `create_bond` and the other single-bond entrypoints.
+    pub fn create_bond_v2(e: Env, identity: Address, amount: i128, duration: u64) -> u64 {
+        pausable::require_not_paused(&e);
+        multi_bond::open(&e, &identity, amount, duration)
+    }
+
+    /// @notice Bond `bond_id` of `identity` (0 for the original bond). Panics with "no bond".
+    pub fn get_bond(e: Env, identity: Address, bond_id: u64) -> IdentityBond {
+        multi_bond::require(&e, &identity, bond_id)
+    }
+
+    /// @notice Ids of every bond `identity` holds, 0 first when the original bond exists.
+    pub fn list_bond_ids(e: Env, identity: Address) -> Vec<u64> {
+        multi_bond::list_ids(&e, &identity)
+    }
+
+    /// @notice Carve `amount` out of `identity`'s bond into a new tranche with its own duration
+    /// and rolling setting (holder auth). The tranche takes a pro-rata share of the slashed
+    /// amount and may not unlock earlier than the source bond would. Returns its `bond_id`.
+    pub fn split_bond(
+        e: Env,
+        identity: Address,
+        amount: i128,
+        new_duration: u64,
+        is_rolling: bool,
+        notice_period: u64,
+    ) -> u64 {
+        pausable::require_not_paused(&e);
+        identity.require_auth();
+        self_lock::require_unlocked(&e, &identity);
+        bond_split::split(
+            &e,
+            &identity,
+            amount,
+            new_duration,
+            is_rolling,
+            notice_period,
+        )
+    }
+
+    /// @notice Start the notice period of rolling bond `bond_id` of `identity` (holder auth).
+    /// Bond 0 goes through `request_withdrawal`.
+    pub fn request_bond_withdrawal(e: Env, identity: Address, bond_id: u64) -> IdentityBond {
+        if bond_id == multi_bond::LEGACY_BOND_ID {
+            return Self::request_withdrawal(e, identity);
+        }
+        pausable::require_not_paused(&e);
+        identity.require_auth();
+        self_lock::require_unlocked(&e, &identity);
+        multi_bond::request_withdrawal(&e, &identity, bond_id)
+    }
+
+    fn open_bond(
+        e: Env,
+        identity: Address,
+        amount: i128,
+        duration: u64,
+        kind: BondKind,
+        notice_period: u64,
+    ) -> IdentityBond {
+        if amount < 0 {
+            panic!("amount must be non-negative");
+        }
+        let is_rolling = kind == BondKind::Rolling;
+        if is_rolling {
+            rolling_bond::validate_duration(duration);
+        }
+        identity.require_auth();
+        let key = DataKey::Bond(identity.clone());
+        // An active bond is never overwritten, so re-creating cannot wipe a partial slash.
+        let replaced: Option<IdentityBond> = e.storage().instance().get(&key);
+        if replaced
+            .as_ref()
+            .is_some_and(|old| !closeout::is_terminal(old))
+        {
+            panic!("bond already exists");
+        }
+        bond_gate::require_gate(&e, &identity);
+        // Checks: token configured and allowance/balance sufficient before any state is written.
+        token_integration::get_token(&e);
+        token_integration::require_owner_funds(&e, &identity, amount);
         let bond_start = e.ledger().timestamp();
 
-        // Verify end timestamp wouldn't overflow.
-        let _end_timestamp = bond_start
-            .checked_add(duration)
-            .expect("bond end timestamp would overflow");
+        // Verify end timestamp wouldn't overflow (perpetual bonds have no end).
+        if kind != BondKind::Perpetual {
+            let _end_timestamp = bond_start
+                .checked_add(duration)
+                .expect("bond end timestamp would overflow");
+        }
 
         let (fee, net_amount) = fees::calculate_fee(&e, amount);
         if fee > 0 {
@@ -590,51 +1153,360 @@ impl CredenceBond {
             }
         }
 
+        // Whatever the replaced bond still held stops counting towards the global total.
+        if let Some(old) = replaced.as_ref() {
+            exposure_caps::release(&e, (old.bonded_amount - old.slashed_amount).max(0));
+        }
+        // The cap covers all of the identity's bonds; the bond being replaced no longer counts.
+        let replaced_open = replaced
+            .as_ref()
+            .filter(|old| old.status != BondStatus::Closed)
+            .map_or(0, |old| old.bonded_amount);
+        let identity_total = multi_bond::total_after_deposit(&e, &identity, net_amount)
+            .checked_sub(replaced_open)
+            .expect("bond total underflow");
+        exposure_caps::reserve(&e, identity_total, net_amount);
+        // Uncollectable slashes recorded against the previous bond, if re-bonding in the window.
+        let docked = uncollectable_slash::take_dock(&e, &identity, net_amount);
+
         let bond = IdentityBond {
             identity: identity.clone(),
             bonded_amount: net_amount,
             bond_start,
             bond_duration: duration,
-            slashed_amount: 0,
+            slashed_amount: docked,
             active: true,
             is_rolling,
             withdrawal_requested_at: 0,
             notice_period,
+            kind,
+            status: BondStatus::Active,
         };
-        let key = DataKey::Bond;
+        bond_rewards::settle(&e, &identity);
         e.storage().instance().set(&key, &bond);
+        maturity_ladder::sync(&e, &bond);
+        closeout::record_open(&e, &identity, net_amount);
 
-        e.storage().instance().set(&DataKey::Bond, &bond);
+        // Interactions: pull the full amount (fee included) once state is persisted (CEI).
+        token_integration::transfer_into_contract(&e, &identity, amount);
+        if docked > 0 {
+            slash_accounting::record_applied(&e, docked);
+            exposure_caps::release(&e, docked);
+            slash_history::append_slash_history(
+                &e,
+                &identity,
+                docked,
+                docked,
+                Symbol::new(&e, "rebond_dock"),
+                docked,
+            );
+            slash_proceeds::route(&e, &identity, docked);
+        }
 
         let old_tier = BondTier::Bronze;
         let new_tier = tiered_bond::get_tier_for_amount(net_amount);
         tiered_bond::emit_tier_change_if_needed(&e, &identity, old_tier, new_tier);
 
+        if let Some(old) = replaced {
+            rolling_bond::reset_renewal_count(&e, &identity);
+            events::publish_for(&e, "bond_replaced", identity.clone(), old);
+        }
         events::emit_bond_created(&e, &identity, amount, duration, is_rolling);
         bond
     }
 
-    pub fn get_identity_state(e: Env) -> IdentityBond {
+    /// @notice Require identities to hold a valid `category` attestation with weight of at
+    /// least `min_weight` before `create_bond` (admin only). Existing bonds are unaffected.
+    pub fn set_bond_gate(e: Env, admin: Address, category: Symbol, min_weight: u32) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_bond_gate");
+        bond_gate::set_gate(&e, category, min_weight);
+    }
+
+    /// @notice Remove the bond creation gate (admin only).
+    pub fn clear_bond_gate(e: Env, admin: Address) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "clear_bond_gate");
+        bond_gate::clear_gate(&e);
+    }
+
+    /// @notice Return the bond creation gate, if configured.
+    pub fn get_bond_gate(e: Env) -> Option<bond_gate::BondGate> {
+        bond_gate::get_gate(&e)
+    }
+
+    /// Enable cross-contract auth: configuration entrypoints also accept callers that the admin
+    /// contract at `registry` allows via `can_call(caller, this_contract, fn_name)`. Admin only.
+    pub fn set_role_registry(e: Env, admin: Address, registry: Address) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        role_registry::set_registry(&e, &registry);
+    }
+
+    /// Disable cross-contract auth; only the bond admin passes admin checks again. Admin only.
+    pub fn clear_role_registry(e: Env, admin: Address) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        role_registry::clear_registry(&e);
+    }
+
+    pub fn get_role_registry(e: Env) -> Option<Address> {
+        role_registry::get_registry(&e)
+    }
+
+    /// Enable or disable queueing of withdrawals the contract balance cannot currently cover
+    /// (admin only). When disabled, such withdrawals fail as before.
+    pub fn set_payout_queue_enabled(e: Env, admin: Address, enabled: bool) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_payout_queue_enabled");
+        payout_queue::set_enabled(&e, enabled);
+    }
+
+    pub fn is_payout_queue_enabled(e: Env) -> bool {
+        payout_queue::is_enabled(&e)
+    }
+
+    /// Pay up to `limit` queued withdrawals in FIFO order. Callable by anyone; stops at the
+    /// first payout the contract balance cannot cover. Returns the number paid.
+    pub fn process_payout_queue(e: Env, limit: u32) -> u32 {
+        pausable::require_not_paused(&e);
+        Self::with_reentrancy_guard(&e, "process_payout_queue", || {
+            payout_queue::process(&e, limit)
+        })
+    }
+
+    /// Number of queued withdrawals awaiting payment.
+    pub fn get_payout_queue_len(e: Env) -> u64 {
+        payout_queue::len(&e)
+    }
+
+    /// Queued payout at `position` (0 = next to be paid), if any.
+    pub fn get_pending_payout(e: Env, position: u64) -> Option<payout_queue::PendingPayout> {
+        payout_queue::get(&e, position)
+    }
+
+    /// Close a bond with nothing left in it (owner only): writes a `Voluntary` close-out statement
+    /// if the bond was not already closed out, and removes the bond entry. Panics while any
+    /// balance remains or a slash request is open. A later `create_bond` starts a new epoch.
+    pub fn close_bond(e: Env, identity: Address) {
+        identity.require_auth();
+        self_lock::require_unlocked(&e, &identity);
+        let key = DataKey::Bond(identity.clone());
+        let mut bond: IdentityBond = e
+            .storage()
+            .instance()
+            .get(&key)
+            .unwrap_or_else(|| panic!("no bond"));
+        if bond.bonded_amount > bond.slashed_amount {
+            panic!("bond has residual balance");
+        }
+        if collateral::get_exposure(&e, &identity) > 0 {
+            panic!("bond backs collateralized attestations");
+        }
+        if governance_approval::has_open_proposal(&e, &identity) {
+            panic!("open slash request");
+        }
+
+        // A full withdrawal already closed the bond; only the entry is left to remove.
+        if bond.status != BondStatus::Closed {
+            bond_status::transition(&e, &mut bond, BondTransition::Close);
+        }
+        // Nothing is released: slashes already released the slashed part, withdrawals the rest.
+        maturity_ladder::sync(&e, &bond);
+        e.storage().instance().remove(&key);
+        if !closeout::close_voluntarily(&e, &bond) {
+            let epoch = closeout::get_epoch(&e, &identity);
+            events::publish_for(&e, "bond_entry_removed", identity, epoch);
+        }
+    }
+
+    /// Start a fresh bond in place of `identity`'s inactive or emptied one (owner auth), keeping
+    /// its kind and notice period. `amount` must meet `MIN_BOND_AMOUNT` and is pulled like a new
+    /// bond; `duration` is ignored for perpetual bonds. Emits `bond_reactivated` (topic:
+    /// identity) with the new bonded amount. Panics with `no bond` or `bond still active`.
+    pub fn reactivate_bond(e: Env, identity: Address, amount: i128, duration: u64) -> IdentityBond {
+        pausable::require_not_paused(&e);
+        let old: IdentityBond = e
+            .storage()
+            .instance()
+            .get(&DataKey::Bond(identity.clone()))
+            .unwrap_or_else(|| panic!("no bond"));
+        if !closeout::is_terminal(&old) {
+            panic!("bond still active");
+        }
+        validation::validate_bond_amount(amount);
+        let duration = if old.kind == BondKind::Perpetual {
+            perpetual_bond::PERPETUAL_DURATION
+        } else {
+            validation::validate_bond_duration(duration);
+            duration
+        };
+
+        let bond = Self::open_bond(
+            e.clone(),
+            identity,
+            amount,
+            duration,
+            old.kind,
+            old.notice_period,
+        );
+        events::publish_for(
+            &e,
+            "bond_reactivated",
+            bond.identity.clone(),
+            bond.bonded_amount,
+        );
+        bond
+    }
+
+    /// Move `current_identity`'s bond to `new_identity` (key rotation), keeping its amounts,
+    /// `bond_start` and kind. Both addresses must authorize. With `move_attestations` the
+    /// attestations issued for the old address move too. Refused while paused, while a slash
+    /// request or cooldown withdrawal is open, or if `new_identity` already has a bond.
+    pub fn transfer_bond(
+        e: Env,
+        current_identity: Address,
+        new_identity: Address,
+        move_attestations: bool,
+    ) -> IdentityBond {
+        pausable::require_not_paused(&e);
+        // Checked before auth: requiring the same address twice in one frame is a host error.
+        if current_identity == new_identity {
+            panic!("cannot transfer to self");
+        }
+        current_identity.require_auth();
+        new_identity.require_auth();
+        self_lock::require_unlocked(&e, &current_identity);
+        bond_transfer::transfer(&e, &current_identity, &new_identity, move_attestations)
+    }
+
+    /// Close-out statements for every bond `identity` has wound down, oldest epoch first.
+    pub fn get_closeouts(e: Env, identity: Address) -> Vec<closeout::BondCloseout> {
+        closeout::get_closeouts(&e, &identity)
+    }
+
+    /// Up to `limit` (max 50) entries of `identity`'s event journal starting at `start`, oldest
+    /// first. Only the newest 50 entries are kept.
+    pub fn get_journal(
+        e: Env,
+        identity: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<journal::JournalEntry> {
+        journal::page(&e, &identity, start, limit)
+    }
+
+    /// Bonded value (net of slashing) maturing in each of `count` weekly epochs starting at
+    /// `start_epoch`, as `(epoch, amount)`. Epoch = maturity timestamp / 604800.
+    pub fn get_maturity_ladder(e: Env, start_epoch: u64, count: u32) -> Vec<(u64, i128)> {
+        maturity_ladder::ladder(&e, start_epoch, count)
+    }
+
+    /// Bond held by `identity`; panics with "no bond" if it has none.
+    pub fn get_identity_state(e: Env, identity: Address) -> IdentityBond {
         e.storage()
             .instance()
-            .get::<_, IdentityBond>(&DataKey::Bond)
+            .get::<_, IdentityBond>(&DataKey::Bond(identity))
             .unwrap_or_else(|| panic!("no bond"))
     }
 
+    /// @notice `identity`'s bond with the ledger position it was read at and a digest over both,
+    /// for off-chain verification. Panics with "no bond" if it has none.
+    pub fn get_bond_proof(e: Env, identity: Address) -> bond_proof::BondProof {
+        let bond = Self::get_identity_state(e.clone(), identity);
+        bond_proof::build(&e, bond)
+    }
+
+    /// @notice True if `proof` came from this contract, is untampered and at most `max_age`
+    /// seconds old.
+    pub fn is_proof_fresh(e: Env, proof: bond_proof::BondProof, max_age: u64) -> bool {
+        bond_proof::is_fresh(&e, &proof, max_age)
+    }
+
+    /// Move a bond stored under the pre-upgrade singleton key to its holder's key, upgraded to
+    /// the current layout (admin only). Returns the migrated bond, or `None` if there is nothing
+    /// to migrate. With `dry_run` only `migration_preview` is emitted; otherwise the bond is
+    /// moved and `migration_applied` is emitted.
+    pub fn migrate_legacy_bond(e: Env, admin: Address, dry_run: bool) -> Option<IdentityBond> {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        legacy_bond::migrate(&e, dry_run)
+    }
+
+    /// True while a bond is still stored under the pre-upgrade singleton key.
+    pub fn has_legacy_bond(e: Env) -> bool {
+        legacy_bond::has_legacy_bond(&e)
+    }
+
+    /// Rewrite the bonds of `identities` (max 100) still stored in the layout without `kind`
+    /// and `status` (admin only). Bonds already in the current layout and identities without a
+    /// bond are skipped. With `dry_run` only `migration_preview` is emitted; otherwise the bonds
+    /// are written and `migration_applied` is emitted. Returns the number of bonds rewritten, or
+    /// that would be.
+    pub fn migrate_bond_records(
+        e: Env,
+        admin: Address,
+        identities: Vec<Address>,
+        dry_run: bool,
+    ) -> u32 {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        legacy_bond::migrate_records(&e, &identities, dry_run)
+    }
+
+    /// Storage layout version of `identity`'s bond: 1 until `migrate_bond_records` rewrites it,
+    /// 2 after. Readable even while the bond itself cannot be decoded by the other entrypoints.
+    pub fn get_bond_record_version(e: Env, identity: Address) -> u32 {
+        legacy_bond::record_version(&e, &identity)
+    }
+
     /// Add an attestation for a subject (only authorized attesters can call).
     /// Requires correct nonce for replay prevention; rejects duplicate (verifier, identity, data).
-    /// Weight is computed from attester stake.
+    /// Weight is computed from attester stake. Rejected while attestation privacy mode is on.
     pub fn add_attestation(
         e: Env,
         attester: Address,
         subject: Address,
         attestation_data: String,
+    ) -> Attestation {
+        attestation_privacy::require_plain_allowed(&e);
+        Self::store_attestation(e, attester, subject, attestation_data, None)
+    }
+
+    /// Add a hash-only attestation: only the sha256 `data_hash` of the off-chain payload is
+    /// stored and emitted. Rejects a duplicate (attester, subject, hash).
+    pub fn add_attestation_hash(
+        e: Env,
+        attester: Address,
+        subject: Address,
+        data_hash: BytesN<32>,
+    ) -> Attestation {
+        let empty = String::from_str(&e, "");
+        Self::store_attestation(e, attester, subject, empty, Some(data_hash))
+    }
+
+    /// True if `preimage` hashes (sha256) to the stored `data_hash` of a hash-only attestation.
+    /// Always false for plain attestations.
+    pub fn verify_attestation_data(e: Env, attestation_id: u64, preimage: Bytes) -> bool {
+        match Self::load_attestation(&e, attestation_id).data_hash {
+            Some(data_hash) => attestation_privacy::matches(&e, &data_hash, &preimage),
+            None => false,
+        }
+    }
+
+    fn store_attestation(
+        e: Env,
+        attester: Address,
+        subject: Address,
+        attestation_data: String,
+        data_hash: Option<BytesN<32>>,
     ) -> Attestation {
         attester.require_auth();
         require_verifier(&e, &attester);
+        attester_bond::require_attester_bond(&e, &attester);
+        subject_policy::require_subject(&e, &subject);
 
         // Verify attester is authorized
-        let is_authorized = e
         let is_authorized: bool = e
             .storage()
             .instance()
@@ -647,14 +1519,21 @@ impl CredenceBond {
 
         // 2. NEW: Duplicate Check Logic
         // We create a unique key based on the content of the attestation
-        let dup_key =
-            DataKey::DuplicateCheck(attester.clone(), subject.clone(), attestation_data.clone());
+        if let Some(hash) = &data_hash {
+            attestation_privacy::mark_hash_seen(&e, &attester, &subject, hash);
+        } else {
+            let dup_key = DataKey::DuplicateCheck(
+                attester.clone(),
+                subject.clone(),
+                attestation_data.clone(),
+            );
 
-        if e.storage().instance().has(&dup_key) {
-            panic!("duplicate attestation");
+            if e.storage().instance().has(&dup_key) {
+                panic!("duplicate attestation");
+            }
+            // --- THE FIX: Mark this as "seen" so the NEXT call fails ---
+            e.storage().instance().set(&dup_key, &true);
         }
-        // --- THE FIX: Mark this as "seen" so the NEXT call fails ---
-        e.storage().instance().set(&dup_key, &true);
         // Get and increment attestation counter
         let counter_key = DataKey::AttestationCounter;
         let id: u64 = e.storage().instance().get(&counter_key).unwrap_or(0);
@@ -662,20 +1541,29 @@ impl CredenceBond {
         let next_id = id.checked_add(1).expect("attestation counter overflow");
         e.storage().instance().set(&counter_key, &next_id);
 
+        let subject_seq = subject_seq::assign(&e, &subject, id);
+
         // Create attestation
         let attestation = Attestation {
             id,
-            attester: attester.clone(),
-            subject: subject.clone(),
-            attestation_data: attestation_data.clone(),
+            verifier: attester.clone(),
+            identity: subject.clone(),
             timestamp: e.ledger().timestamp(),
+            weight: weighted_attestation::compute_weight(&e, &attester),
+            attestation_data: attestation_data.clone(),
             revoked: false,
+            revoked_at: 0,
+            subject_seq,
+            use_count: 0,
+            data_hash: data_hash.clone(),
+            expires_at: 0,
+            flags: 0,
+            consented: false,
+            revocation_reason: None,
         };
 
         // Store attestation
-        e.storage()
-            .instance()
-            .set(&DataKey::Attestation(id), &attestation);
+        attestation_store::put(&e, &attestation);
 
         // Add to subject's attestation list
         let subject_key = DataKey::SubjectAttestations(subject.clone());
@@ -687,37 +1575,212 @@ impl CredenceBond {
         attestations.push_back(id);
         e.storage().instance().set(&subject_key, &attestations);
 
-        // Emit event
-        e.events().publish(
-            (Symbol::new(&e, "attestation_added"), subject),
-            (id, attester, attestation_data),
+        let count_key = DataKey::SubjectAttestationCount(subject.clone());
+        let count: u32 = e.storage().instance().get(&count_key).unwrap_or(0);
+        let next_count = count.checked_add(1).expect("attestation count overflow");
+        e.storage().instance().set(&count_key, &next_count);
+
+        // Emit event (hash-only attestations never emit a payload)
+        let kind = match data_hash {
+            Some(hash) => {
+                events::publish_for(
+                    &e,
+                    "attestation_hash_added",
+                    subject,
+                    (id, attester, hash, subject_seq),
+                );
+                "attestation_hash_added"
+            }
+            None => {
+                events::publish_for(
+                    &e,
+                    "attestation_added",
+                    subject,
+                    (id, attester, attestation_data, subject_seq),
+                );
+                "attestation_added"
+            }
+        };
+        journal::append(
+            &e,
+            &attestation.identity,
+            kind,
+            attestation.weight.into(),
+            id,
         );
 
         verifier::record_attestation_issued(&e, &attestation.verifier, attestation.weight);
+        subject_hook::notify(&e, &attestation.identity, id, true);
 
         attestation
     }
 
+    /// Add an attestation backed by `backing_amount` of the attester's own bond. The amount is
+    /// held (not withdrawable) until the attestation is revoked, and a slash request raised
+    /// against the attestation via `propose_attestation_slash` draws from it first.
+    pub fn add_collateralized_attestation(
+        e: Env,
+        attester: Address,
+        subject: Address,
+        attestation_data: String,
+        backing_amount: i128,
+    ) -> Attestation {
+        features::require_enabled(&e, features::COLLATERALIZED_ATTESTATIONS);
+        if backing_amount <= 0 {
+            panic!("backing amount must be positive");
+        }
+        let bond: IdentityBond = e
+            .storage()
+            .instance()
+            .get(&DataKey::Bond(attester.clone()))
+            .filter(|b: &IdentityBond| b.active)
+            .unwrap_or_else(|| panic!("attester bond inactive"));
+
+        let attestation = Self::add_attestation(e.clone(), attester, subject, attestation_data);
+        collateral::place_hold(&e, &bond, attestation.id, backing_amount);
+        attestation
+    }
+
+    /// Enable or disable an opt-in feature for this deployment (admin only). Only flags on the
+    /// contract's allow-list are accepted.
+    pub fn set_feature(e: Env, admin: Address, flag: Symbol, enabled: bool) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_feature");
+        features::set_enabled(&e, &flag, enabled);
+    }
+
+    pub fn is_feature_enabled(e: Env, flag: Symbol) -> bool {
+        features::is_enabled(&e, &flag)
+    }
+
+    /// Silence or re-enable a low-value event (admin only). Only events on the silenceable
+    /// allow-list are accepted; slash, withdrawal and admin events are always emitted.
+    pub fn set_event_enabled(e: Env, admin: Address, name: Symbol, enabled: bool) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_event_enabled");
+        events::set_enabled(&e, &name, enabled);
+    }
+
+    /// All known feature flags and whether each is enabled.
+    pub fn get_features(e: Env) -> Vec<(Symbol, bool)> {
+        features::list(&e)
+    }
+
+    /// Allow or disallow `consumer` to report attestation uses (admin only).
+    pub fn set_attestation_consumer(e: Env, admin: Address, consumer: Address, allowed: bool) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_attestation_consumer");
+        attestation_usage::set_consumer(&e, &consumer, allowed);
+    }
+
+    pub fn is_attestation_consumer(e: Env, consumer: Address) -> bool {
+        attestation_usage::is_consumer(&e, &consumer)
+    }
+
+    /// Record that `consumer_contract` relied on `attestation_id`, `count` times (default 1,
+    /// at most `MAX_USES_PER_CALL`). Only allow-listed consumers may call this.
+    /// Returns the attestation's new use count.
+    pub fn record_attestation_use(
+        e: Env,
+        consumer_contract: Address,
+        attestation_id: u64,
+        count: Option<u32>,
+    ) -> u32 {
+        pausable::require_not_paused(&e);
+        consumer_contract.require_auth();
+        attestation_usage::record_use(&e, &consumer_contract, attestation_id, count.unwrap_or(1))
+    }
+
+    /// Number of uses recorded against `attestation_id` (also `Attestation::use_count`).
+    pub fn get_attestation_usage(e: Env, attestation_id: u64) -> u32 {
+        attestation_usage::get_usage(&e, attestation_id)
+    }
+
+    /// Total uses `consumer` has recorded across all attestations.
+    pub fn get_consumer_usage(e: Env, consumer: Address) -> u64 {
+        attestation_usage::consumer_uses(&e, &consumer)
+    }
+
+    /// Amount of the attester's bond currently held to back `attestation_id`.
+    pub fn get_attestation_backing(e: Env, attestation_id: u64) -> i128 {
+        collateral::get_backing(&e, attestation_id)
+    }
+
+    /// Total backing held on `attester`'s bond across their collateralized attestations.
+    pub fn get_attester_backed_exposure(e: Env, attester: Address) -> i128 {
+        collateral::get_exposure(&e, &attester)
+    }
+
     /// Revoke an attestation (only the original attester can revoke).
     pub fn revoke_attestation(e: Env, attester: Address, attestation_id: u64) {
-    /// Revoke an attestation (only original attester). Requires correct nonce.
-    pub fn revoke_attestation(e: Env, attester: Address, attestation_id: u64, nonce: u64) {
         pausable::require_not_paused(&e);
         attester.require_auth();
 
-        // Get attestation
-        let key = DataKey::Attestation(attestation_id);
-        let mut attestation: Attestation = e
-            .storage()
-            .instance()
-            .get(&key)
-            .unwrap_or_else(|| panic!("attestation not found"));
-
+        let attestation = Self::load_attestation(&e, attestation_id);
         // Verify attester is the original attester
-        if attestation.attester != attester {
+        if attestation.verifier != attester {
             panic!("only original attester can revoke");
         }
+        Self::revoke_internal(
+            &e,
+            attestation_id,
+            attestation,
+            verifier::RevocationInitiator::Attester,
+            Symbol::new(&e, "self"),
+        );
+    }
+
+    /// Revoke an attestation as admin (e.g. for fraud). `reason` is recorded in the verifier's
+    /// revocation stats and selects the reputation penalty multiplier. It must be a non-empty
+    /// symbol of at most `MAX_REASON_LEN` characters.
+    pub fn revoke_attestation_by_admin(
+        e: Env,
+        admin: Address,
+        attestation_id: u64,
+        reason: Symbol,
+    ) {
+        pausable::require_not_paused(&e);
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        reason_code::require_reason(&e, &reason);
+        let attestation = Self::load_attestation(&e, attestation_id);
+        Self::revoke_internal(
+            &e,
+            attestation_id,
+            attestation,
+            verifier::RevocationInitiator::Admin,
+            reason,
+        );
+    }
 
+    /// Set the reputation penalty multiplier (bps of attestation weight) for revocations by
+    /// `initiator`, optionally only for `reason` (admin only). Unconfigured revocations use 1x.
+    pub fn set_revocation_multiplier(
+        e: Env,
+        admin: Address,
+        initiator: verifier::RevocationInitiator,
+        reason: Option<Symbol>,
+        multiplier_bps: u32,
+    ) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_revocation_multiplier");
+        if let Some(reason) = &reason {
+            reason_code::require_reason(&e, reason);
+        }
+        verifier::set_revocation_multiplier(&e, initiator, reason, multiplier_bps);
+    }
+
+    fn load_attestation(e: &Env, attestation_id: u64) -> Attestation {
+        attestation_store::get(e, attestation_id).unwrap_or_else(|| panic!("attestation not found"))
+    }
+
+    fn revoke_internal(
+        e: &Env,
+        attestation_id: u64,
+        mut attestation: Attestation,
+        initiator: verifier::RevocationInitiator,
+        reason: Symbol,
+    ) {
         // Check if already revoked
         if attestation.revoked {
             panic!("attestation already revoked");
@@ -725,25 +1788,55 @@ impl CredenceBond {
 
         // Mark as revoked
         attestation.revoked = true;
-        e.storage().instance().set(&key, &attestation);
+        attestation.revoked_at = e.ledger().timestamp();
+        attestation.revocation_reason = Some(reason.clone());
+        attestation_store::put(e, &attestation);
+
+        let count_key = DataKey::SubjectAttestationCount(attestation.identity.clone());
+        let count: u32 = e.storage().instance().get(&count_key).unwrap_or(0);
+        e.storage()
+            .instance()
+            .set(&count_key, &count.saturating_sub(1));
 
         // Emit event
-        e.events().publish(
+        events::publish_for(
+            e,
+            "attestation_revoked",
+            attestation.identity.clone(),
             (
-                Symbol::new(&e, "attestation_revoked"),
-                attestation.subject.clone(),
+                attestation_id,
+                attestation.verifier.clone(),
+                attestation.revoked_at,
             ),
-            (attestation_id, attester),
+        );
+        journal::append(
+            e,
+            &attestation.identity,
+            "attestation_revoked",
+            attestation.weight.into(),
+            attestation_id,
         );
 
-        verifier::record_attestation_revoked(&e, &attestation.verifier, attestation.weight);
+        verifier::record_attestation_revoked(
+            e,
+            &attestation.verifier,
+            attestation.weight,
+            initiator,
+            reason,
+        );
+        collateral::release_hold(e, &attestation.verifier, attestation_id);
+        subject_hook::notify(e, &attestation.identity, attestation_id, false);
     }
 
     pub fn get_attestation(e: Env, attestation_id: u64) -> Attestation {
-        e.storage()
-            .instance()
-            .get(&DataKey::Attestation(attestation_id))
-            .unwrap_or_else(|| panic!("attestation not found"))
+        Self::load_attestation(&e, attestation_id)
+    }
+
+    /// Resolve a subject's `seq`-th attestation (1-based, never reused) to the attestation.
+    pub fn get_attestation_by_subject_seq(e: Env, subject: Address, seq: u32) -> Attestation {
+        let id = subject_seq::resolve(&e, &subject, seq)
+            .unwrap_or_else(|| panic!("attestation not found"));
+        Self::get_attestation(e, id)
     }
 
     pub fn get_subject_attestations(e: Env, subject: Address) -> Vec<u64> {
@@ -753,6 +1846,76 @@ impl CredenceBond {
             .unwrap_or(Vec::new(&e))
     }
 
+    /// Up to `limit` (max 100) attestations for `subject` that currently count, skipping the
+    /// first `start` of them. Revoked attestations are excluded.
+    pub fn get_valid_attestations(
+        e: Env,
+        subject: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<Attestation> {
+        valid_attestations::valid_page(&e, &subject, start, limit)
+    }
+
+    /// Whether attestation `attestation_id` was in force at `timestamp`, for disputes over past
+    /// reliance: issued at or before `timestamp` and not revoked until after it.
+    pub fn was_valid_at(e: Env, attestation_id: u64, timestamp: u64) -> bool {
+        valid_attestations::was_valid_at(&e, attestation_id, timestamp)
+    }
+
+    /// Rewrite the attestations among `ids` (max 100) still stored in the V1 layout as V2 records
+    /// (admin only). Already migrated and unknown ids are skipped. With `dry_run` only
+    /// `migration_preview` is emitted; otherwise the records are written and `migration_applied`
+    /// is emitted. Returns the number of records rewritten, or that would be.
+    pub fn migrate_attestation_records(
+        e: Env,
+        admin: Address,
+        ids: Vec<u64>,
+        dry_run: bool,
+    ) -> u32 {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        attestation_store::migrate_batch(&e, &ids, dry_run)
+    }
+
+    /// Storage layout version of attestation `attestation_id`: 1 until it is rewritten, 2 after.
+    /// `get_attestation` returns the V2 layout either way.
+    pub fn get_attestation_record_version(e: Env, attestation_id: u64) -> u32 {
+        attestation_store::record_version(&e, attestation_id)
+    }
+
+    /// Import attestations `ids` from an older attestation contract at `source_contract` (admin
+    /// only). Processes at most 25 ids; unprocessed ids are returned in `remaining`. Entries
+    /// whose attester is not registered here are skipped unless `allow_unregistered` is set.
+    pub fn import_attestations(
+        e: Env,
+        admin: Address,
+        source_contract: Address,
+        ids: Vec<u64>,
+        allow_unregistered: bool,
+    ) -> attestation_import::ImportBatchResult {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "import_attestations");
+        attestation_import::import_batch(&e, &source_contract, &ids, allow_unregistered)
+    }
+
+    /// Source contract and original id of an imported attestation; `None` for local ones.
+    pub fn get_attestation_origin(
+        e: Env,
+        attestation_id: u64,
+    ) -> Option<attestation_import::ImportOrigin> {
+        attestation_import::get_origin(&e, attestation_id)
+    }
+
+    /// @notice Counts, active weight, newest issue time and attester count over every attestation
+    /// issued for `subject`, for indexers. Zeroed for subjects with none; no auth.
+    pub fn describe_attestation_stats(
+        e: Env,
+        subject: Address,
+    ) -> valid_attestations::AttestationStats {
+        valid_attestations::stats(&e, &subject)
+    }
+
     /// Withdraw from bond. Checks that the bond has sufficient balance after accounting for slashed amount.
     /// Returns the updated bond with reduced bonded_amount.
     pub fn get_subject_attestation_count(e: Env, subject: Address) -> u32 {
@@ -762,17 +1925,24 @@ impl CredenceBond {
             .unwrap_or(0)
     }
 
+    /// Version of the event schema; also the second topic of every event this contract emits.
+    pub fn get_event_schema_version(_e: Env) -> u32 {
+        events::EVENT_SCHEMA_VERSION
+    }
+
     pub fn get_nonce(e: Env, identity: Address) -> u64 {
         nonce::get_nonce(&e, &identity)
     }
 
     pub fn set_attester_stake(e: Env, admin: Address, attester: Address, amount: i128) {
-        Self::require_admin_internal(&e, &admin);
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_attester_stake");
         weighted_attestation::set_attester_stake(&e, &attester, amount);
     }
 
     pub fn set_weight_config(e: Env, admin: Address, multiplier_bps: u32, max_weight: u32) {
-        Self::require_admin_internal(&e, &admin);
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_weight_config");
         weighted_attestation::set_weight_config(&e, multiplier_bps, max_weight);
     }
 
@@ -780,60 +1950,101 @@ impl CredenceBond {
         weighted_attestation::get_weight_config(&e)
     }
 
+    /// @notice Replace verifier min stake, weight config and tier thresholds in one validated
+    /// write (admin only). Nothing changes if any field is out of bounds.
+    pub fn set_economic_params(e: Env, admin: Address, params: economic_params::EconomicParams) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_economic_params");
+        economic_params::set(&e, &params);
+    }
+
+    pub fn get_economic_params(e: Env) -> economic_params::EconomicParams {
+        economic_params::get(&e)
+    }
+
+    /// Set the dust threshold (admin only). A withdrawal that leaves less than this net
+    /// balance pays out the remainder and closes the bond; 0 disables auto-close.
+    pub fn set_dust_threshold(e: Env, admin: Address, threshold: i128) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_dust_threshold");
+        dust::set_threshold(&e, threshold);
+    }
+
+    /// Current dust threshold (0 when disabled).
+    pub fn get_dust_threshold(e: Env) -> i128 {
+        dust::get_threshold(&e)
+    }
+
     /// Withdraw from bond (no penalty). Alias for `withdraw_bond`. Use when lock-up has ended
-    /// or after the notice period for rolling bonds.
-    pub fn withdraw(e: Env, amount: i128) -> IdentityBond {
-        Self::withdraw_bond(e, amount)
+    /// or after the notice period for rolling bonds. Requires `identity` auth.
+    pub fn withdraw(e: Env, identity: Address, amount: i128) -> IdentityBond {
+        Self::withdraw_bond(e, identity, amount)
+    }
+
+    /// @notice Withdraw `amount` from bond `bond_id` of `identity` (identity auth). Bond 0 goes
+    /// through `withdraw_bond`; additional bonds unlock at the end of their lock-up.
+    pub fn withdraw_bond_v2(e: Env, identity: Address, bond_id: u64, amount: i128) -> IdentityBond {
+        if bond_id == multi_bond::LEGACY_BOND_ID {
+            return Self::withdraw_bond(e, identity, amount);
+        }
+        pausable::require_not_paused(&e);
+        identity.require_auth();
+        self_lock::require_unlocked(&e, &identity);
+        Self::with_reentrancy_guard(&e, "withdraw_bond_v2", || {
+            multi_bond::withdraw(&e, &identity, bond_id, amount)
+        })
     }
 
     /// Withdraw USDC from bond after lock-up has elapsed and (for rolling bonds) the cooldown
     /// window has passed. Verifies:
     /// 1. Lock-up period has elapsed for non-rolling bonds.
-    /// 2. For rolling bonds, withdrawal was requested and the notice period has elapsed.
+    /// 2. For rolling and perpetual bonds, withdrawal was requested and the notice period has
+    ///    elapsed.
     /// 3. `amount` does not exceed the available balance (`bonded_amount - slashed_amount`).
-    /// Transfers USDC to the identity owner and updates tiers.
-    pub fn withdraw_bond(e: Env, amount: i128) -> IdentityBond {
-        let key = DataKey::Bond;
+    /// Transfers USDC to the identity owner and updates tiers. A partial withdrawal keeps the
+    /// bond active; withdrawing the whole available balance sets `active = false`. Runs under
+    /// the reentrancy guard and persists the bond before transferring. Requires `identity` auth.
+    pub fn withdraw_bond(e: Env, identity: Address, amount: i128) -> IdentityBond {
+        identity.require_auth();
+        self_lock::require_unlocked(&e, &identity);
+        Self::with_reentrancy_guard(&e, "withdraw_bond", || {
+            Self::withdraw_bond_guarded(&e, &identity, amount)
+        })
+    }
+
+    fn withdraw_bond_guarded(e: &Env, identity: &Address, amount: i128) -> IdentityBond {
+        let key = DataKey::Bond(identity.clone());
         let mut bond = e
             .storage()
             .instance()
             .get::<_, IdentityBond>(&key)
             .unwrap_or_else(|| panic!("no bond"));
+        require_identity_owner(e, identity, &bond.identity);
+        bond_freeze::require_not_frozen(&bond);
 
         if amount < 0 {
             panic!("amount must be non-negative");
         }
-        bond.identity.require_auth();
 
         let now = e.ledger().timestamp();
         let end = bond.bond_start.saturating_add(bond.bond_duration);
 
-        if bond.is_rolling {
-            if bond.withdrawal_requested_at == 0 {
-                panic!("cooldown window not elapsed; request_withdrawal first");
-            }
-            if !rolling_bond::can_withdraw_after_notice(
-                now,
-                bond.withdrawal_requested_at,
-                bond.notice_period_duration,
-            ) {
-                panic!("cooldown window not elapsed; request_withdrawal first");
-            }
+        if perpetual_bond::is_perpetual(&bond) {
+            perpetual_bond::require_notice_served(now, &bond);
+        } else if bond.is_rolling {
+            rolling_bond::require_notice_elapsed(now, &bond);
         } else if now < end {
             panic!("lock-up period not elapsed; use withdraw_early");
         }
 
-        let available = bond
-            .bonded_amount
-            .checked_sub(bond.slashed_amount)
-            .expect("slashed amount exceeds bonded amount");
+        let available = collateral::free_balance(e, &bond);
 
         if amount > available {
             panic!("insufficient balance for withdrawal");
         }
+        residual_floor::require_floor(e, &bond, amount);
 
-        token_integration::transfer_from_contract(&e, &bond.identity, amount);
-
+        bond_rewards::settle(e, identity);
         let old_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
         bond.bonded_amount = bond
             .bonded_amount
@@ -844,62 +2055,88 @@ impl CredenceBond {
             bond.slashed_amount = bond.bonded_amount;
         }
         let new_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
-        tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
-
-        let old_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount + amount);
-        let new_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
-        tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
-
+        tiered_bond::emit_tier_change_if_needed(e, &bond.identity, old_tier, new_tier);
+
+        let dust = dust::sweep(e, &mut bond);
+        // Withdrawing everything available winds the bond down; a partial one keeps it active.
+        if bond.status != BondStatus::Closed {
+            let drained = amount > 0 && dust::remainder(&bond) == 0;
+            let step = if drained {
+                BondTransition::Close
+            } else {
+                BondTransition::Mature
+            };
+            bond_status::transition(e, &mut bond, step);
+        }
+        rolling_bond::clear_request_if_drained(&mut bond);
         e.storage().instance().set(&key, &bond);
-
-        events::emit_bond_withdrawn(&e, &bond.identity, amount, bond.bonded_amount);
+        maturity_ladder::sync(e, &bond);
+        closeout::record_withdrawal(e, &bond.identity, amount + dust, 0);
+        exposure_caps::release(e, amount + dust);
+        closeout::close_if_terminal(e, &bond, false);
+
+        // State is persisted before any tokens leave the contract.
+        payout_queue::pay_or_queue(e, &bond.identity, amount);
+        dust::pay(e, &bond.identity, dust);
+        events::emit_bond_withdrawn(e, &bond.identity, amount, bond.bonded_amount);
         bond
     }
 
     /// Early withdrawal path (only valid before lock-up end). Applies an early exit penalty and
-    /// transfers the penalty to the configured treasury.
-    pub fn withdraw_early(e: Env, amount: i128) -> IdentityBond {
-        let key = DataKey::Bond;
+    /// transfers the penalty to the configured treasury. Not available for perpetual bonds.
+    /// Runs under the reentrancy guard and persists the bond before transferring. Requires
+    /// `identity` auth.
+    pub fn withdraw_early(e: Env, identity: Address, amount: i128) -> IdentityBond {
+        identity.require_auth();
+        self_lock::require_unlocked(&e, &identity);
+        Self::with_reentrancy_guard(&e, "withdraw_early", || {
+            Self::withdraw_early_guarded(&e, &identity, amount)
+        })
+    }
+
+    fn withdraw_early_guarded(e: &Env, identity: &Address, amount: i128) -> IdentityBond {
+        let key = DataKey::Bond(identity.clone());
         let mut bond = e
             .storage()
             .instance()
             .get::<_, IdentityBond>(&key)
             .unwrap_or_else(|| panic!("no bond"));
+        require_identity_owner(e, identity, &bond.identity);
+        bond_freeze::require_not_frozen(&bond);
 
         if amount < 0 {
             panic!("amount must be non-negative");
         }
-        bond.identity.require_auth();
+        if perpetual_bond::is_perpetual(&bond) {
+            panic!("perpetual bond: use request_withdrawal");
+        }
 
         let now = e.ledger().timestamp();
         let end = bond.bond_start.saturating_add(bond.bond_duration);
         if now >= end {
             panic!("use withdraw for post lock-up");
         }
+        rolling_bond::require_notice_elapsed(now, &bond);
 
-        let available = bond
-            .bonded_amount
-            .checked_sub(bond.slashed_amount)
-            .expect("slashed amount exceeds bonded amount");
+        let available = collateral::free_balance(e, &bond);
         if amount > available {
             panic!("insufficient balance for withdrawal");
         }
+        residual_floor::require_floor(e, &bond, amount);
 
-        let (treasury, penalty_bps) = early_exit_penalty::get_config(&e);
+        let (treasury, penalty_bps) = early_exit_penalty::get_config(e);
         let remaining = end.saturating_sub(now);
+        let free = free_withdrawal::consume(e, &bond.identity, bond.bonded_amount, amount);
         let penalty = early_exit_penalty::calculate_penalty(
-            amount,
+            amount - free,
             remaining,
             bond.bond_duration,
             penalty_bps,
         );
-        early_exit_penalty::emit_penalty_event(&e, &bond.identity, amount, penalty, &treasury);
+        early_exit_penalty::emit_penalty_event(e, &bond.identity, amount, free, penalty, &treasury);
 
         let net_amount = amount.checked_sub(penalty).expect("penalty exceeds amount");
-        token_integration::transfer_from_contract(&e, &bond.identity, net_amount);
-        if penalty > 0 {
-            token_integration::transfer_from_contract(&e, &treasury, penalty);
-        }
+        bond_rewards::settle(e, identity);
         let old_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
         bond.bonded_amount = bond
             .bonded_amount
@@ -911,23 +2148,54 @@ impl CredenceBond {
         }
 
         let new_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
-        tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
+        tiered_bond::emit_tier_change_if_needed(e, &bond.identity, old_tier, new_tier);
 
+        // Never sweep dust that would have been penalized on an early exit.
+        let dust_penalty = early_exit_penalty::calculate_penalty(
+            dust::remainder(&bond),
+            remaining,
+            bond.bond_duration,
+            penalty_bps,
+        );
+        let dust = if dust_penalty == 0 {
+            dust::sweep(e, &mut bond)
+        } else {
+            0
+        };
+        // Exiting with everything available closes the bond; a partial exit keeps its status.
+        if amount > 0 && dust::remainder(&bond) == 0 && bond_status::is_open(&bond.status) {
+            bond_status::transition(e, &mut bond, BondTransition::Close);
+        }
+        rolling_bond::clear_request_if_drained(&mut bond);
         e.storage().instance().set(&key, &bond);
-        events::emit_bond_withdrawn(&e, &bond.identity, amount, bond.bonded_amount);
+        maturity_ladder::sync(e, &bond);
+        closeout::record_withdrawal(e, &bond.identity, amount + dust, penalty);
+        exposure_caps::release(e, amount + dust);
+        closeout::close_if_terminal(e, &bond, false);
+
+        // State is persisted before any tokens leave the contract.
+        payout_queue::pay_or_queue(e, &bond.identity, net_amount);
+        if penalty > 0 {
+            token_integration::transfer_from_contract(e, &treasury, penalty);
+        }
+        dust::pay(e, &bond.identity, dust);
+        events::emit_bond_withdrawn(e, &bond.identity, amount, bond.bonded_amount);
         bond
     }
 
-    pub fn request_withdrawal(e: Env) -> IdentityBond {
+    /// Start the notice period of a rolling or perpetual bond.
+    pub fn request_withdrawal(e: Env, identity: Address) -> IdentityBond {
         pausable::require_not_paused(&e);
-        let key = DataKey::Bond;
+        let key = DataKey::Bond(identity);
         let mut bond: IdentityBond = e
             .storage()
             .instance()
             .get(&key)
             .unwrap_or_else(|| panic!("no bond"));
         bond.identity.require_auth();
-        if !bond.is_rolling {
+        bond_freeze::require_not_frozen(&bond);
+        self_lock::require_unlocked(&e, &bond.identity);
+        if !bond.is_rolling && !perpetual_bond::is_perpetual(&bond) {
             panic!("not a rolling bond");
         }
         if bond.withdrawal_requested_at != 0 {
@@ -935,16 +2203,66 @@ impl CredenceBond {
         }
 
         bond.withdrawal_requested_at = e.ledger().timestamp();
+        bond_status::transition(&e, &mut bond, BondTransition::ServeNotice);
         e.storage().instance().set(&key, &bond);
-        e.events().publish(
-            (Symbol::new(&e, "withdrawal_requested"),),
+        events::publish(
+            &e,
+            "withdrawal_requested",
             (bond.identity.clone(), bond.withdrawal_requested_at),
         );
         bond
     }
 
-    pub fn renew_if_rolling(e: Env) -> IdentityBond {
-        let key = DataKey::Bond;
+    /// Withdraw a pending `request_withdrawal`, so a rolling bond keeps renewing (and a
+    /// perpetual bond stays open) as if no notice had been given.
+    pub fn cancel_withdrawal_request(e: Env, identity: Address) -> IdentityBond {
+        pausable::require_not_paused(&e);
+        let key = DataKey::Bond(identity);
+        let mut bond: IdentityBond = e
+            .storage()
+            .instance()
+            .get(&key)
+            .unwrap_or_else(|| panic!("no bond"));
+        bond.identity.require_auth();
+        if bond.withdrawal_requested_at == 0 {
+            panic!("no withdrawal requested");
+        }
+
+        bond.withdrawal_requested_at = 0;
+        bond_status::transition(&e, &mut bond, BondTransition::CancelNotice);
+        e.storage().instance().set(&key, &bond);
+        events::publish(
+            &e,
+            "withdrawal_cancelled",
+            (bond.identity.clone(), e.ledger().timestamp()),
+        );
+        bond
+    }
+
+    /// Queue `duration` for the next period of `identity`'s rolling bond (owner only). It takes
+    /// effect at the next renewal and replaces any earlier queued duration.
+    pub fn set_next_period_duration(e: Env, identity: Address, duration: u64) {
+        pausable::require_not_paused(&e);
+        let bond = Self::get_identity_state(e.clone(), identity);
+        bond.identity.require_auth();
+        bond_freeze::require_not_frozen(&bond);
+        if !bond.is_rolling {
+            panic!("not a rolling bond");
+        }
+        validation::validate_bond_duration(duration);
+        rolling_bond::set_next_duration(&e, &bond.identity, duration);
+    }
+
+    /// Duration queued for the next period of `identity`'s bond, if any.
+    pub fn get_next_period_duration(e: Env, identity: Address) -> Option<u64> {
+        rolling_bond::get_next_duration(&e, &identity)
+    }
+
+    /// Start a new period if a rolling bond's period has ended. Once `max_renewals` renewals
+    /// were used the bond lapses into a fixed-term bond instead (`rolling_lapsed`). Fixed and
+    /// perpetual bonds are returned unchanged.
+    pub fn renew_if_rolling(e: Env, identity: Address) -> IdentityBond {
+        let key = DataKey::Bond(identity);
         let mut bond: IdentityBond = e
             .storage()
             .instance()
@@ -959,52 +2277,198 @@ impl CredenceBond {
             return bond;
         }
 
-        rolling_bond::apply_renewal(&mut bond, now);
+        if rolling_bond::renewals_exhausted(&e, &bond) {
+            rolling_bond::apply_lapse(&mut bond);
+            bond_status::transition(&e, &mut bond, BondTransition::Mature);
+            e.storage().instance().set(&key, &bond);
+            maturity_ladder::sync(&e, &bond);
+            events::publish_for(
+                &e,
+                "rolling_lapsed",
+                bond.identity.clone(),
+                rolling_bond::get_renewal_count(&e, &bond.identity),
+            );
+            return bond;
+        }
+        if !rolling_bond::apply_renewal(&e, &mut bond, now) {
+            events::publish(
+                &e,
+                "bond_renewal_refused",
+                (
+                    bond.identity.clone(),
+                    now,
+                    rolling_bond::renewal_duration(&e, &bond),
+                ),
+            );
+            return bond;
+        }
+        bond_status::transition(&e, &mut bond, BondTransition::Renew);
         e.storage().instance().set(&key, &bond);
-        e.events().publish(
-            (Symbol::new(&e, "bond_renewed"),),
+        maturity_ladder::sync(&e, &bond);
+        events::publish(
+            &e,
+            "bond_renewed",
             (bond.identity.clone(), bond.bond_start, bond.bond_duration),
         );
         bond
     }
 
-    pub fn get_tier(e: Env) -> BondTier {
-        let bond = Self::get_identity_state(e);
-        tiered_bond::get_tier_for_amount(bond.bonded_amount)
+    /// Renewals allowed per rolling bond before it lapses (0, the default, means unlimited;
+    /// admin only).
+    pub fn set_max_renewals(e: Env, admin: Address, max_renewals: u32) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_max_renewals");
+        rolling_bond::set_max_renewals(&e, max_renewals);
+    }
+
+    pub fn get_max_renewals(e: Env) -> u32 {
+        rolling_bond::get_max_renewals(&e)
+    }
+
+    /// Renewals used by `identity`'s current bond.
+    pub fn get_renewal_count(e: Env, identity: Address) -> u32 {
+        rolling_bond::get_renewal_count(&e, &identity)
+    }
+
+    /// When `identity`'s current lock-up ends. Accounts for rolling renewals, including one that
+    /// is due but not yet applied. Saturates at u64::MAX (perpetual bonds).
+    pub fn get_bond_end(e: Env, identity: Address) -> u64 {
+        let bond = Self::get_identity_state(e.clone(), identity);
+        rolling_bond::effective_end(&e, e.ledger().timestamp(), &bond)
+    }
+
+    /// Seconds until `identity`'s current lock-up ends (see `get_bond_end`), 0 once it has ended.
+    pub fn get_time_remaining(e: Env, identity: Address) -> u64 {
+        let now = e.ledger().timestamp();
+        let bond = Self::get_identity_state(e.clone(), identity);
+        rolling_bond::effective_end(&e, now, &bond).saturating_sub(now)
+    }
+
+    /// `identity`'s current period as (start, end, renewals so far). A renewal that is due is
+    /// reported as if `renew_if_rolling` ran now.
+    pub fn get_current_period(e: Env, identity: Address) -> (u64, u64, u32) {
+        let bond = Self::get_identity_state(e.clone(), identity);
+        rolling_bond::current_period(&e, e.ledger().timestamp(), &bond)
+    }
+
+    /// The next `count` (max 52) projected period ends of `identity`'s bond, starting with the
+    /// current one. Stops early where the bond lapses at its renewal cap.
+    pub fn get_renewal_schedule(e: Env, identity: Address, count: u32) -> Vec<u64> {
+        let bond = Self::get_identity_state(e.clone(), identity);
+        rolling_bond::renewal_schedule(&e, e.ledger().timestamp(), &bond, count)
+    }
+
+    pub fn get_tier(e: Env, identity: Address) -> BondTier {
+        let bond = Self::get_identity_state(e, identity);
+        tiered_bond::get_tier_for_amount(bond.bonded_amount)
+    }
+
+    /// @notice Tier of bond `bond_id` of `identity` on its own.
+    pub fn get_bond_tier(e: Env, identity: Address, bond_id: u64) -> BondTier {
+        let bond = multi_bond::require(&e, &identity, bond_id);
+        tiered_bond::get_tier_for_amount(bond.bonded_amount)
+    }
+
+    /// @notice Tier for the combined `bonded_amount` of every open bond `identity` holds.
+    pub fn get_aggregate_tier(e: Env, identity: Address) -> BondTier {
+        multi_bond::aggregate_tier(&e, &identity)
+    }
+
+    /// Tier integrations should gate on: upgrades take effect only after being held for the
+    /// tier hold duration, downgrades immediately. Bronze for identities without the bond.
+    pub fn get_effective_tier(e: Env, identity: Address) -> BondTier {
+        let bond_key = DataKey::Bond(identity.clone());
+        let raw = match e.storage().instance().get::<_, IdentityBond>(&bond_key) {
+            Some(bond) => tiered_bond::get_tier_for_amount(bond.bonded_amount),
+            None => BondTier::Bronze,
+        };
+        tiered_bond::get_effective_tier(&e, &identity, raw)
+    }
+
+    /// @notice Read-only check of `subject` against `policy` (effective tier, attestation
+    /// category and weight, recent slashes, quarantine/freeze). Returns pass/fail plus every
+    /// criterion that failed; unset criteria pass.
+    pub fn check_eligibility(
+        e: Env,
+        subject: Address,
+        policy: eligibility::EligibilityPolicy,
+    ) -> eligibility::EligibilityResult {
+        eligibility::check(&e, &subject, &policy)
+    }
+
+    /// Set how long a higher tier must be held before it becomes effective (admin only;
+    /// 0 disables the hold).
+    pub fn set_tier_hold_duration(e: Env, admin: Address, secs: u64) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_tier_hold_duration");
+        tiered_bond::set_hold_duration(&e, secs);
+    }
+
+    pub fn get_tier_hold_duration(e: Env) -> u64 {
+        tiered_bond::get_hold_duration(&e)
+    }
+
+    /// Slash a portion of `identity`'s bond (admin only). Increases slashed_amount up to the
+    /// bonded_amount and returns the updated bond. Other identities' bonds are untouched.
+    pub fn slash(e: Env, admin: Address, identity: Address, amount: i128) -> IdentityBond {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        if amount < 0 {
+            panic!("slash amount must be non-negative");
+        }
+        Self::with_reentrancy_guard(&e, "slash", || {
+            slashing::slash_bond(&e, &admin, &identity, amount, &Symbol::new(&e, "slash"))
+        })
+    }
+
+    /// @notice Slash `amount` from bond `bond_id` of `identity` (admin only). Bond 0 goes through
+    /// `slash`; other bonds are capped at their own bonded amount.
+    pub fn slash_bond_v2(
+        e: Env,
+        admin: Address,
+        identity: Address,
+        bond_id: u64,
+        amount: i128,
+    ) -> IdentityBond {
+        if bond_id == multi_bond::LEGACY_BOND_ID {
+            return Self::slash(e, admin, identity, amount);
+        }
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        Self::with_reentrancy_guard(&e, "slash_bond_v2", || {
+            multi_bond::slash(&e, &identity, bond_id, amount)
+        })
     }
 
-    /// Slash a portion of the bond. Increases slashed_amount up to the bonded_amount.
-    /// Returns the updated bond with increased slashed_amount.
-    pub fn slash(e: Env, amount: i128) -> IdentityBond {
-        let key = DataKey::Bond;
-        let mut bond = e
-            .storage()
-            .instance()
-            .get::<_, IdentityBond>(&key)
-            .unwrap_or_else(|| panic!("no bond"));
+    /// @notice Send slash proceeds to `beneficiary` (admin only). Without a beneficiary they
+    /// accrue to the fee pool.
+    pub fn set_slash_beneficiary(e: Env, admin: Address, beneficiary: Address) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_slash_beneficiary");
+        slash_proceeds::set_beneficiary(&e, &beneficiary);
+    }
 
-        // Calculate new slashed amount, checking for overflow
-        let new_slashed = bond
-            .slashed_amount
-            .checked_add(amount)
-            .expect("slashing caused overflow");
+    /// @notice Stop routing slash proceeds to a beneficiary (admin only).
+    pub fn clear_slash_beneficiary(e: Env, admin: Address) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "clear_slash_beneficiary");
+        slash_proceeds::clear_beneficiary(&e);
+    }
 
-        // Cap slashed amount at bonded amount
-        bond.slashed_amount = if new_slashed > bond.bonded_amount {
-            bond.bonded_amount
-        } else {
-            new_slashed
-        };
+    pub fn get_slash_beneficiary(e: Env) -> Option<Address> {
+        slash_proceeds::get_beneficiary(&e)
+    }
 
-        e.storage().instance().set(&key, &bond);
-        bond
-    pub fn slash(e: Env, admin: Address, amount: i128) -> IdentityBond {
+    /// @notice Block slashing of a bond until `secs` after it was created (admin only; 0
+    /// disables). Top-ups do not restart the grace period.
+    pub fn set_slash_grace_period(e: Env, admin: Address, secs: u64) {
         admin.require_auth();
-        Self::require_admin_internal(&e, &admin);
-        if amount < 0 {
-            panic!("slash amount must be non-negative");
-        }
-        slashing::slash_bond(&e, &admin, amount)
+        Self::require_admin_or_role(&e, &admin, "set_slash_grace_period");
+        slashing::set_grace_period(&e, secs);
+    }
+
+    pub fn get_slash_grace_period(e: Env) -> u64 {
+        slashing::get_grace_period(&e)
     }
 
     pub fn initialize_governance(
@@ -1016,10 +2480,12 @@ impl CredenceBond {
     ) {
         pausable::require_not_paused(&e);
         Self::require_admin_internal(&e, &admin);
+        governor_fees::distribute(&e);
         governance_approval::initialize_governance(&e, governors, quorum_bps, min_governors);
     }
 
-    pub fn propose_slash(e: Env, proposer: Address, amount: i128) -> u64 {
+    /// Propose slashing `amount` from `identity`'s bond. Caller must be admin or governor.
+    pub fn propose_slash(e: Env, proposer: Address, identity: Address, amount: i128) -> u64 {
         pausable::require_not_paused(&e);
         proposer.require_auth();
         let admin: Address = e
@@ -1027,12 +2493,31 @@ impl CredenceBond {
             .instance()
             .get(&DataKey::Admin)
             .unwrap_or_else(|| panic!("not initialized"));
-        let governors = governance_approval::get_governors(&e);
-        let is_governor = governors.iter().any(|g| g == proposer);
+        let is_governor = governance_approval::is_active_member(&e, &proposer);
         if proposer != admin && !is_governor {
             panic!("not admin or governor");
         }
-        governance_approval::propose_slash(&e, &proposer, amount)
+        governance_approval::propose_slash(&e, &proposer, &identity, amount)
+    }
+
+    /// Propose a slash for a specific collateralized attestation. On execution the slash draws
+    /// from the attestation's held backing before the attester's free balance.
+    pub fn propose_attestation_slash(
+        e: Env,
+        proposer: Address,
+        attestation_id: u64,
+        amount: i128,
+    ) -> u64 {
+        features::require_enabled(&e, features::COLLATERALIZED_ATTESTATIONS);
+        let attestation = Self::load_attestation(&e, attestation_id);
+        let proposal_id = Self::propose_slash(e.clone(), proposer, attestation.verifier, amount);
+        collateral::link_proposal(&e, proposal_id, attestation_id);
+        proposal_id
+    }
+
+    /// Attestation a slash proposal was raised against, if any.
+    pub fn get_proposal_attestation(e: Env, proposal_id: u64) -> Option<u64> {
+        collateral::get_proposal_attestation(&e, proposal_id)
     }
 
     pub fn governance_vote(e: Env, voter: Address, proposal_id: u64, approve: bool) {
@@ -1041,6 +2526,134 @@ impl CredenceBond {
         governance_approval::vote(&e, &voter, proposal_id, approve);
     }
 
+    /// Suspend a governor under investigation (admin only). The member stays in the governor
+    /// list but cannot propose, vote or dispute; their votes on open proposals stop counting
+    /// and they no longer count toward quorum until reinstated.
+    pub fn suspend_governance_member(e: Env, admin: Address, member: Address) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        governor_fees::distribute(&e);
+        governance_approval::suspend_member(&e, &member);
+    }
+
+    /// Reinstate a suspended governor (admin only). Votes cast before suspension count again.
+    pub fn reinstate_governance_member(e: Env, admin: Address, member: Address) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        governor_fees::distribute(&e);
+        governance_approval::reinstate_member(&e, &member);
+    }
+
+    /// Configure the governor fee stream: each complete period of `period_secs` releases
+    /// `bps_per_period` (max 1000) of the fee pool to eligible governors (admin only).
+    pub fn set_governor_fee_config(e: Env, admin: Address, bps_per_period: u32, period_secs: u64) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        governor_fees::set_config(&e, bps_per_period, period_secs);
+    }
+
+    pub fn get_governor_fee_config(e: Env) -> Option<governor_fees::GovernorFeeConfig> {
+        governor_fees::get_config(&e)
+    }
+
+    /// Credit governors their share of the fee pool for every complete period since the last
+    /// distribution. Permissionless; returns the amount credited.
+    pub fn distribute_governor_fees(e: Env) -> i128 {
+        pausable::require_not_paused(&e);
+        governor_fees::distribute(&e)
+    }
+
+    /// Fees credited to `member` and not yet claimed.
+    pub fn get_governor_fee_balance(e: Env, member: Address) -> i128 {
+        governor_fees::get_claimable(&e, &member)
+    }
+
+    /// Transfer `member`'s credited governor fees to them (member auth).
+    pub fn claim_governor_fees(e: Env, member: Address) -> i128 {
+        member.require_auth();
+        pausable::require_not_paused(&e);
+        Self::with_reentrancy_guard(&e, "claim_governor_fees", || {
+            governor_fees::claim(&e, &member)
+        })
+    }
+
+    pub fn is_governance_member_suspended(e: Env, member: Address) -> bool {
+        governance_approval::is_suspended(&e, &member)
+    }
+
+    /// Limit slash proposal creation per proposer: at most `max_open` open proposals and at
+    /// least `cooldown_secs` between proposals (0 disables either limit; admin only).
+    pub fn set_proposal_rate_limit(e: Env, admin: Address, max_open: u32, cooldown_secs: u64) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_proposal_rate_limit");
+        governance_approval::set_rate_limit(&e, max_open, cooldown_secs);
+    }
+
+    pub fn get_proposal_rate_limit(e: Env) -> governance_approval::ProposalRateLimit {
+        governance_approval::get_rate_limit(&e)
+    }
+
+    /// Seconds after creation during which an approved slash request may be executed (0
+    /// disables expiry; admin only). Expired requests no longer hold back newer ones.
+    pub fn set_slash_execution_window(e: Env, admin: Address, window_secs: u64) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_slash_execution_window");
+        governance_approval::set_execution_window(&e, window_secs);
+    }
+
+    pub fn get_slash_execution_window(e: Env) -> u64 {
+        governance_approval::get_execution_window(&e)
+    }
+
+    /// Ids of open slash requests against `identity`, in creation order.
+    pub fn get_open_slash_requests(e: Env, identity: Address) -> Vec<u64> {
+        governance_approval::open_request_ids(&e, &identity)
+    }
+
+    pub fn get_open_proposal_count(e: Env, proposer: Address) -> u32 {
+        governance_approval::get_open_count(&e, &proposer)
+    }
+
+    /// Timestamp from which `proposer`'s cooldown allows a new proposal, or 0 when no cooldown
+    /// is running. `get_open_proposal_count` shows whether `max_open` still blocks them.
+    pub fn get_proposal_retry_after(e: Env, proposer: Address) -> u64 {
+        governance_approval::retry_after(&e, &proposer)
+    }
+
+    /// Enable or disable bond-weighted voting for new slash proposals (admin only).
+    pub fn set_governance_bond_weighted(e: Env, admin: Address, enabled: bool) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        governance_approval::set_bond_weighted(&e, enabled);
+    }
+
+    pub fn is_governance_bond_weighted(e: Env) -> bool {
+        governance_approval::is_bond_weighted(&e)
+    }
+
+    /// Require every governor to hold a net bond of at least `min_bond` (admin only; 0
+    /// disables). Checked when governors are set and lazily whenever a member votes.
+    pub fn set_min_governor_bond(e: Env, admin: Address, min_bond: i128) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        governance_approval::set_min_governor_bond(&e, min_bond);
+    }
+
+    pub fn get_min_governor_bond(e: Env) -> i128 {
+        governance_approval::get_min_governor_bond(&e)
+    }
+
+    /// True if `member`'s net bond meets the minimum governor bond, i.e. their vote would not be
+    /// refused with "governor under-bonded". Always true while the minimum is 0.
+    pub fn is_governor_bonded(e: Env, member: Address) -> bool {
+        governance_approval::meets_min_bond(&e, &member)
+    }
+
+    /// Voting power of `governor` on `proposal_id` (snapshot weight in bond-weighted mode).
+    pub fn get_voting_power(e: Env, proposal_id: u64, governor: Address) -> i128 {
+        governance_approval::get_voting_power(&e, proposal_id, &governor)
+    }
+
     pub fn governance_delegate(e: Env, governor: Address, to: Address) {
         pausable::require_not_paused(&e);
         governance_approval::delegate(&e, &governor, &to);
@@ -1062,12 +2675,154 @@ impl CredenceBond {
         if !executed {
             panic!("proposal not approved");
         }
-        slashing::slash_bond(&e, &proposer, proposal.amount)
+        dispute_bond::forfeit_all(&e, proposal_id);
+        if uncollectable_slash::targets_closed_bond(&e, &proposal.identity) {
+            return uncollectable_slash::record(
+                &e,
+                &proposal.identity,
+                proposal.amount,
+                proposal_id,
+            );
+        }
+        collateral::consume_for_slash(&e, &proposal.identity, proposal_id, proposal.amount);
+        // The approved proposal is the authority here; a governor proposer is not the admin.
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        let bond = Self::with_reentrancy_guard(&e, "execute_slash", || {
+            slashing::slash_bond(
+                &e,
+                &admin,
+                &proposal.identity,
+                proposal.amount,
+                &Symbol::new(&e, "governance"),
+            )
+        });
+        events::emit_slash_executed(&e, &bond, proposal.amount);
+        journal::append(
+            &e,
+            &bond.identity,
+            "slash_executed",
+            proposal.amount,
+            proposal_id,
+        );
+        bond
+    }
+
+    /// Configure the dispute bond escrowed by `dispute_slash_request` and the reward (bps of
+    /// the would-be slash amount) paid on top of the refund when the slash is rejected.
+    pub fn set_dispute_bond_config(e: Env, admin: Address, amount: i128, reward_bps: u32) {
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_dispute_bond_config");
+        dispute_bond::set_config(&e, amount, reward_bps);
+    }
+
+    /// Returns (dispute bond amount, reward bps).
+    pub fn get_dispute_bond_config(e: Env) -> (i128, u32) {
+        dispute_bond::get_config(&e)
+    }
+
+    /// Dispute an open slash request. Governors only; escrows the configured dispute bond.
+    /// Returns the escrowed amount.
+    pub fn dispute_slash_request(e: Env, disputer: Address, proposal_id: u64) -> i128 {
+        pausable::require_not_paused(&e);
+        disputer.require_auth();
+        Self::with_reentrancy_guard(&e, "dispute_slash_request", || {
+            dispute_bond::file_dispute(&e, &disputer, proposal_id)
+        })
+    }
+
+    /// Close a contested slash request (quorum reached without an approving majority) and
+    /// refund dispute escrows plus reward. Callable by anyone.
+    pub fn reject_slash_request(e: Env, proposal_id: u64) {
+        pausable::require_not_paused(&e);
+        if !governance_approval::is_rejected(&e, proposal_id) {
+            panic!("proposal not rejected");
+        }
+        let proposal = governance_approval::get_proposal(&e, proposal_id)
+            .unwrap_or_else(|| panic!("proposal not found"));
+        governance_approval::execute_slash_if_approved(&e, proposal_id);
+        Self::with_reentrancy_guard(&e, "reject_slash_request", || {
+            dispute_bond::refund_all(&e, proposal_id, proposal.amount)
+        });
+    }
+
+    /// Close open slash request `proposal_id` once it has outlived the execution window (anyone
+    /// may call). Nothing is slashed; the request becomes `Expired`, the proposer's open slot is
+    /// freed and dispute escrows are refunded without a reward. Panics with `proposal not
+    /// expired` before then.
+    pub fn close_expired_slash_request(e: Env, proposal_id: u64) {
+        pausable::require_not_paused(&e);
+        governance_approval::mark_expired(&e, proposal_id);
+        Self::with_reentrancy_guard(&e, "close_expired_slash_request", || {
+            dispute_bond::refund_all(&e, proposal_id, 0)
+        });
+    }
+
+    /// Offer to settle open slash request `request_id` by accepting a slash of
+    /// `offered_amount` (at most the requested amount). Bond holder only; replaces any earlier
+    /// offer and has no effect until accepted.
+    pub fn acknowledge_and_settle(
+        e: Env,
+        identity: Address,
+        request_id: u64,
+        offered_amount: i128,
+    ) -> slash_settlement::SettlementOffer {
+        pausable::require_not_paused(&e);
+        identity.require_auth();
+        slash_settlement::offer(&e, &identity, request_id, offered_amount)
+    }
+
+    /// Accept the pending settlement offer (a governor or the requester): slashes the offered
+    /// amount and closes the request as `Settled`. Dispute escrows are forfeited.
+    pub fn accept_settlement(e: Env, member: Address, request_id: u64) -> IdentityBond {
+        pausable::require_not_paused(&e);
+        member.require_auth();
+        let offer = slash_settlement::accept(&e, &member, request_id);
+        dispute_bond::forfeit_all(&e, request_id);
+        collateral::consume_for_slash(&e, &offer.identity, request_id, offer.amount);
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        Self::with_reentrancy_guard(&e, "accept_settlement", || {
+            slashing::slash_bond(
+                &e,
+                &admin,
+                &offer.identity,
+                offer.amount,
+                &Symbol::new(&e, "settlement"),
+            )
+        })
+    }
+
+    /// Discard the pending settlement offer (a governor or the requester). The request stays
+    /// open for the normal governance flow.
+    pub fn reject_settlement(e: Env, member: Address, request_id: u64) {
+        pausable::require_not_paused(&e);
+        member.require_auth();
+        slash_settlement::reject(&e, &member, request_id);
+    }
+
+    pub fn get_settlement_offer(
+        e: Env,
+        request_id: u64,
+    ) -> Option<slash_settlement::SettlementOffer> {
+        slash_settlement::get_offer(&e, request_id)
+    }
+
+    /// Escrow held for `disputer` on an unsettled slash request, if any.
+    pub fn get_dispute_escrow(e: Env, proposal_id: u64, disputer: Address) -> Option<i128> {
+        dispute_bond::get_escrow(&e, proposal_id, &disputer)
     }
 
     pub fn set_fee_config(e: Env, admin: Address, treasury: Address, fee_bps: u32) {
         pausable::require_not_paused(&e);
-        Self::require_admin_internal(&e, &admin);
+        admin.require_auth();
+        Self::require_admin_or_role(&e, &admin, "set_fee_config");
         fees::set_config(&e, treasury, fee_bps);
     }
 
@@ -1077,17 +2832,53 @@ impl CredenceBond {
         fees::get_config(&e)
     }
 
-    pub fn deposit_fees(e: Env, amount: i128) {
-        let key = Symbol::new(&e, "fees");
-        let current: i128 = e.storage().instance().get(&key).unwrap_or(0);
-        let next = current.checked_add(amount).expect("fee pool overflow");
-        e.storage().instance().set(&key, &next);
+    /// @notice Pull `amount` bond tokens from `from` into the contract and add them to the fee
+    /// pool paid out by `collect_fees`. Requires `from` auth and a token allowance.
+    pub fn deposit_fees(e: Env, from: Address, amount: i128) {
+        from.require_auth();
+        if amount <= 0 {
+            panic!("amount must be positive");
+        }
+        fees::add_to_pool(&e, amount);
+        token_integration::transfer_into_contract(&e, &from, amount);
+        events::publish_for(&e, "fees_deposited", from, amount);
     }
 
-    pub fn set_callback(e: Env, callback: Address) {
-        e.storage()
+    /// @notice Set the yearly reward rate (basis points, max 10000) paid to bonds out of the
+    /// fee pool. 0 disables rewards. Admin only.
+    pub fn set_reward_rate(e: Env, admin: Address, rate_bps: u32) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        bond_rewards::set_rate(&e, rate_bps);
+    }
+
+    pub fn get_reward_rate(e: Env) -> u32 {
+        bond_rewards::get_rate(&e)
+    }
+
+    /// @notice Rewards `identity`'s bond has accrued and would receive if the fee pool covers
+    /// them. Panics with "no bond".
+    pub fn get_accrued_rewards(e: Env, identity: Address) -> i128 {
+        let bond: IdentityBond = e
+            .storage()
             .instance()
-            .set(&Self::callback_key(&e), &callback);
+            .get(&DataKey::Bond(identity))
+            .unwrap_or_else(|| panic!("no bond"));
+        bond_rewards::accrued(&e, &bond)
+    }
+
+    /// @notice Timestamp of `identity`'s last reward claim (0 if never claimed).
+    pub fn get_last_reward_claim(e: Env, identity: Address) -> u64 {
+        bond_rewards::get_last_claim(&e, &identity)
+    }
+
+    /// @notice Pay `identity` the rewards accrued since `bond_start` or its last claim, capped
+    /// at the fee pool balance (holder auth). Returns the amount paid.
+    pub fn claim_rewards(e: Env, identity: Address) -> i128 {
+        identity.require_auth();
+        self_lock::require_unlocked(&e, &identity);
+        pausable::require_not_paused(&e);
+        Self::with_reentrancy_guard(&e, "claim_rewards", || bond_rewards::claim(&e, &identity))
     }
 
     /// Configure the USDC token contract used by `increase_bond`.
@@ -1102,13 +2893,6 @@ impl CredenceBond {
         e.storage().instance().get(&DataKey::BondToken)
     }
 
-    pub fn is_locked(e: Env) -> bool {
-        e.storage()
-            .instance()
-            .get(&Self::lock_key(&e))
-            .unwrap_or(false)
-    }
-
     pub fn get_slash_proposal(
         e: Env,
         proposal_id: u64,
@@ -1116,10 +2900,47 @@ impl CredenceBond {
         governance_approval::get_proposal(&e, proposal_id)
     }
 
+    /// Projected effect of executing every open slash request against `identity` in id order,
+    /// with execution's capping. Read-only; flags requests that would be capped to zero.
+    pub fn simulate_pending_slashes(
+        e: Env,
+        identity: Address,
+    ) -> slash_simulation::SlashSimulation {
+        slash_simulation::simulate(&e, &identity)
+    }
+
+    /// A slash request together with its transition timestamps.
+    pub fn describe_slash_request(e: Env, proposal_id: u64) -> Option<SlashRequestDescription> {
+        let proposal = governance_approval::get_proposal(&e, proposal_id)?;
+        Some(SlashRequestDescription {
+            label: identity_label::get_label(&e, &proposal.identity),
+            proposal,
+            timeline: slash_pipeline::get_timeline(&e, proposal_id)
+                .unwrap_or_else(slash_pipeline::untracked_timeline),
+            context: context::current(&e),
+        })
+    }
+
+    /// Per-transition counts and total seconds across all slash requests.
+    pub fn get_slash_pipeline_stats(e: Env) -> slash_pipeline::SlashPipelineStats {
+        slash_pipeline::get_stats(&e)
+    }
+
+    /// `(applied, distributed, pending)` slash totals: amounts applied to bonds after capping,
+    /// slashed funds transferred out, and the slashed funds still held by the contract.
+    pub fn get_slash_accounting(e: Env) -> (i128, i128, i128) {
+        slash_accounting::get_accounting(&e)
+    }
+
     pub fn get_governance_vote(e: Env, proposal_id: u64, voter: Address) -> Option<bool> {
         governance_approval::get_vote(&e, proposal_id, &voter)
     }
 
+    /// Governors whose own vote on `proposal_id` approves, in governor-list order.
+    pub fn get_governance_approvers(e: Env, proposal_id: u64) -> Vec<Address> {
+        governance_approval::get_approvers(&e, proposal_id)
+    }
+
     // State update BEFORE external interaction
 
     pub fn get_governors(e: Env) -> Vec<Address> {
@@ -1134,7 +2955,35 @@ impl CredenceBond {
         governance_approval::get_quorum_config(&e)
     }
 
-    pub fn top_up(e: Env, amount: i128) -> IdentityBond {
+    /// Let `sponsor` top up `identity`'s bond with its own tokens (holder auth). The sponsor
+    /// gains no other rights over the bond.
+    pub fn authorize_topup_sponsor(e: Env, identity: Address, sponsor: Address) {
+        identity.require_auth();
+        self_lock::require_unlocked(&e, &identity);
+        topup_sponsor::authorize(&e, &identity, &sponsor);
+    }
+
+    /// Withdraw a grant made with `authorize_topup_sponsor` (holder auth).
+    pub fn revoke_topup_sponsor(e: Env, identity: Address, sponsor: Address) {
+        identity.require_auth();
+        self_lock::require_unlocked(&e, &identity);
+        topup_sponsor::revoke(&e, &identity, &sponsor);
+    }
+
+    /// True if `sponsor` may top up `identity`'s bond.
+    pub fn is_topup_sponsor(e: Env, identity: Address, sponsor: Address) -> bool {
+        topup_sponsor::is_sponsor(&e, &identity, &sponsor)
+    }
+
+    /// Add `amount` to `identity`'s bond, paid by `from` (the holder, or a sponsor granted with
+    /// `authorize_topup_sponsor`; `from` auth). Pulls `amount` of the configured token from
+    /// `from` with `transfer_from`, so `from` must have approved the contract. Emits
+    /// `bond_increased` and, when the tier moves, `tier_changed`.
+    pub fn top_up(e: Env, from: Address, identity: Address, amount: i128) -> IdentityBond {
+        from.require_auth();
+        if amount <= 0 {
+            panic!("amount must be positive");
+        }
         // Validate the top-up amount meets minimum requirements
         if amount < validation::MIN_BOND_AMOUNT {
             panic!(
@@ -1143,49 +2992,43 @@ impl CredenceBond {
                 validation::MIN_BOND_AMOUNT
             );
         }
-        
-        let key = DataKey::Bond;
+
+        let key = DataKey::Bond(identity);
         let mut bond: IdentityBond = e
             .storage()
             .instance()
             .get(&key)
             .unwrap_or_else(|| panic!("no bond"));
-
-        if amount < 0 {
-            panic!("amount must be non-negative");
+        if !bond.active {
+            panic!("bond not active");
         }
-        bond.identity.require_auth();
+        topup_sponsor::require_payer(&e, &bond.identity, &from, amount);
 
-        // Calculate the new bonded amount after top-up
-        let new_bonded_amount = bond
-        // Perform top-up with overflow protection
-        let old_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
-        bond.bonded_amount = bond
-        // Overflow check before token transfer (CEI pattern)
         let new_bonded = bond
             .bonded_amount
             .checked_add(amount)
             .expect("top-up caused overflow");
-            
         // Validate the new total bonded amount is within limits
-        validation::validate_bond_amount(new_bonded_amount);
+        validation::validate_bond_amount(new_bonded);
 
-        // Perform top-up with overflow protection
+        bond_rewards::settle(&e, &bond.identity);
         let old_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
-        bond.bonded_amount = new_bonded_amount;
+        bond.bonded_amount = new_bonded;
+        exposure_caps::reserve(
+            &e,
+            multi_bond::total_after_deposit(&e, &bond.identity, amount),
+            amount,
+        );
+        e.storage().instance().set(&key, &bond);
+        maturity_ladder::sync(&e, &bond);
+        closeout::record_deposit(&e, &bond.identity, amount);
 
-        token_integration::transfer_into_contract(&e, &bond.identity, amount);
+        // Interactions: pull the tokens from the payer once state is persisted (CEI).
+        token_integration::transfer_into_contract(&e, &from, amount);
 
-        let old_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
-        bond.bonded_amount = new_bonded;
         let new_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
         tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
         events::emit_bond_increased(&e, &bond.identity, amount, bond.bonded_amount);
-
-        let new_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
-        tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
-
-        e.storage().instance().set(&key, &bond);
         bond
     }
 
@@ -1206,16 +3049,15 @@ impl CredenceBond {
         if amount <= 0 {
             panic!("amount must be positive");
         }
-        Self::with_reentrancy_guard(&e, || {
-            let key = DataKey::Bond;
+        Self::with_reentrancy_guard(&e, "increase_bond", || {
+            let key = DataKey::Bond(caller.clone());
             let mut bond = e
                 .storage()
                 .instance()
                 .get::<_, IdentityBond>(&key)
                 .unwrap_or_else(|| panic!("no bond"));
-
-            if bond.identity != caller {
-                panic!("not bond owner");
+            if !bond.active {
+                panic!("bond not active");
             }
 
             let token_addr: Address = e
@@ -1229,28 +3071,37 @@ impl CredenceBond {
                 .checked_add(amount)
                 .expect("bond increase caused overflow");
 
+            exposure_caps::reserve(
+                &e,
+                multi_bond::total_after_deposit(&e, &caller, amount),
+                amount,
+            );
+
             let token_client = TokenClient::new(&e, &token_addr);
             let contract_address = e.current_contract_address();
+            token_integration::require_funds(&e, &token_addr, &caller, &contract_address, amount);
             token_client.transfer_from(&contract_address, &caller, &contract_address, &amount);
 
             let old_tier = tiered_bond::get_tier_for_amount(old_amount);
             let new_tier = tiered_bond::get_tier_for_amount(new_amount);
 
+            bond_rewards::settle(&e, &caller);
             bond.bonded_amount = new_amount;
             e.storage().instance().set(&key, &bond);
+            maturity_ladder::sync(&e, &bond);
+            closeout::record_deposit(&e, &bond.identity, amount);
 
             tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
-            e.events().publish(
-                (Symbol::new(&e, "bond_increased"), bond.identity.clone()),
-                (amount, old_amount, new_amount),
-            );
+            events::emit_bond_increased(&e, &bond.identity, amount, new_amount);
 
             bond
         })
     }
 
-    pub fn extend_duration(e: Env, additional_duration: u64) -> IdentityBond {
-        let key = DataKey::Bond;
+    /// Lengthen `identity`'s bond by `additional_duration` seconds (holder auth). The resulting
+    /// duration may not exceed `get_max_bond_duration` ("duration exceeds maximum").
+    pub fn extend_duration(e: Env, identity: Address, additional_duration: u64) -> IdentityBond {
+        let key = DataKey::Bond(identity);
         let mut bond: IdentityBond = e
             .storage()
             .instance()
@@ -1258,18 +3109,115 @@ impl CredenceBond {
             .unwrap_or_else(|| panic!("no bond"));
 
         bond.identity.require_auth();
+        if perpetual_bond::is_perpetual(&bond) {
+            panic!("perpetual bond has no duration");
+        }
+
+        bond.bond_duration = bond
+            .bond_duration
+            .checked_add(additional_duration)
+            .expect("duration extension caused overflow");
+        if bond.bond_duration > parameters::get_max_bond_duration(&e) {
+            panic!("duration exceeds maximum");
+        }
+
+        let end_timestamp = bond
+            .bond_start
+            .checked_add(bond.bond_duration)
+            .expect("bond end timestamp would overflow");
+        // Extending a matured bond past now starts a new lock-up.
+        if bond.status == BondStatus::Matured && end_timestamp > e.ledger().timestamp() {
+            bond_status::transition(&e, &mut bond, BondTransition::Renew);
+        }
+
+        e.storage().instance().set(&key, &bond);
+        maturity_ladder::sync(&e, &bond);
+        bond
+    }
+
+    /// @notice Add `additional_amount` and lengthen the term by `additional_duration` in one call
+    /// (holder auth). Both parts are validated before anything is written: the top-up bounds of
+    /// `top_up`, the duration maximum of `extend_duration` and the exposure caps. One token pull,
+    /// one tier check on the final amount and a single `bond_extended` event with the new bonded
+    /// amount, duration and lock-up end.
+    pub fn extend_and_top_up(
+        e: Env,
+        identity: Address,
+        additional_amount: i128,
+        additional_duration: u64,
+    ) -> IdentityBond {
+        pausable::require_not_paused(&e);
+        identity.require_auth();
+        let key = DataKey::Bond(identity.clone());
+        let mut bond: IdentityBond = e
+            .storage()
+            .instance()
+            .get(&key)
+            .unwrap_or_else(|| panic!("no bond"));
+        require_identity_owner(&e, &identity, &bond.identity);
+        if !bond.active {
+            panic!("bond not active");
+        }
+        if perpetual_bond::is_perpetual(&bond) {
+            panic!("perpetual bond has no duration");
+        }
+        if additional_duration == 0 {
+            panic!("additional duration must be positive");
+        }
+        if additional_amount < validation::MIN_BOND_AMOUNT {
+            panic!(
+                "top-up amount below minimum required: {} (minimum: {})",
+                additional_amount,
+                validation::MIN_BOND_AMOUNT
+            );
+        }
 
-        bond.bond_duration = bond
+        let new_bonded = bond
+            .bonded_amount
+            .checked_add(additional_amount)
+            .expect("top-up caused overflow");
+        validation::validate_bond_amount(new_bonded);
+        let new_duration = bond
             .bond_duration
             .checked_add(additional_duration)
             .expect("duration extension caused overflow");
-
-        let _end_timestamp = bond
+        if new_duration > parameters::get_max_bond_duration(&e) {
+            panic!("duration exceeds maximum");
+        }
+        let end_timestamp = bond
             .bond_start
-            .checked_add(bond.bond_duration)
+            .checked_add(new_duration)
             .expect("bond end timestamp would overflow");
+        exposure_caps::reserve(
+            &e,
+            multi_bond::total_after_deposit(&e, &identity, additional_amount),
+            additional_amount,
+        );
 
+        bond_rewards::settle(&e, &identity);
+        let old_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
+        bond.bonded_amount = new_bonded;
+        bond.bond_duration = new_duration;
+        if bond.status == BondStatus::Matured && end_timestamp > e.ledger().timestamp() {
+            bond_status::transition(&e, &mut bond, BondTransition::Renew);
+        }
         e.storage().instance().set(&key, &bond);
+        maturity_ladder::sync(&e, &bond);
+        closeout::record_deposit(&e, &bond.identity, additional_amount);
+
+        // Interactions: pull the tokens once state is persisted (CEI).
+        token_integration::transfer_into_contract(&e, &identity, additional_amount);
+
+        let new_tier = tiered_bond::get_tier_for_amount(new_bonded);
+        tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
+        events::emit_bond_extended(
+            &e,
+            &bond.identity,
+            additional_amount,
+            new_bonded,
+            new_duration,
+            end_timestamp,
+        );
         bond
     }
 
@@ -1420,6 +3368,46 @@ impl CredenceBond {
         Self::check_lock(&e)
     }
 
+    /// Return the reentrancy lock state, the ledger timestamp and sequence at which it was
+    /// acquired and how many ledgers it has been held for (all 0 when not held), so monitors
+    /// can detect a stuck lock.
+    pub fn get_lock_info(e: Env) -> LockInfo {
+        if !Self::check_lock(&e) {
+            return LockInfo {
+                locked: false,
+                held_since: 0,
+                held_since_ledger: 0,
+                held_for_ledgers: 0,
+            };
+        }
+        let held_since_ledger = Self::lock_held_since_ledger(&e);
+        LockInfo {
+            locked: true,
+            held_since: Self::lock_held_since(&e),
+            held_since_ledger,
+            held_for_ledgers: e.ledger().sequence().saturating_sub(held_since_ledger),
+        }
+    }
+
+    /// Clear a reentrancy lock left set by a failed invocation (admin only), without waiting
+    /// for it to go stale. Emits `reentrancy_lock_force_released` with
+    /// `(admin, held_since, held_since_ledger)`. Panics with "lock not held".
+    pub fn force_release_lock(e: Env, admin: Address) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        if !Self::check_lock(&e) {
+            panic!("lock not held");
+        }
+        let held_since = Self::lock_held_since(&e);
+        let held_since_ledger = Self::lock_held_since_ledger(&e);
+        Self::release_lock(&e);
+        events::publish(
+            &e,
+            "reentrancy_lock_force_released",
+            (admin, held_since, held_since_ledger),
+        );
+    }
+
     /// Get protocol fee rate in basis points.
     pub fn get_protocol_fee_bps(e: Env) -> u32 {
         parameters::get_protocol_fee_bps(&e)
@@ -1500,71 +3488,137 @@ impl CredenceBond {
         parameters::set_platinum_threshold(&e, &admin, value)
     }
 
+    /// Get the longest duration `extend_duration` may extend a bond to, in seconds.
+    pub fn get_max_bond_duration(e: Env) -> u64 {
+        parameters::get_max_bond_duration(&e)
+    }
+
+    /// Set the max bond duration. Governance-only.
+    pub fn set_max_bond_duration(e: Env, admin: Address, value: u64) {
+        admin.require_auth();
+        parameters::set_max_bond_duration(&e, &admin, value)
+    }
+
+    /// Announce a change of `key` to `value` at `effective_at` (admin only). The value is
+    /// bounds-checked now and applied by the first read at or after `effective_at`. Returns the
+    /// change id.
+    pub fn schedule_parameter_change(
+        e: Env,
+        admin: Address,
+        key: parameters::ParameterKey,
+        value: i128,
+        effective_at: u64,
+    ) -> u64 {
+        admin.require_auth();
+        parameters::schedule_change(&e, &admin, key, value, effective_at)
+    }
+
+    /// Cancel a scheduled parameter change that has not taken effect yet (admin only).
+    pub fn cancel_parameter_change(e: Env, admin: Address, id: u64) {
+        admin.require_auth();
+        parameters::cancel_change(&e, &admin, id)
+    }
+
+    /// Apply every scheduled parameter change that is due. Callable by anyone; returns how many
+    /// were applied.
+    pub fn apply_scheduled_changes(e: Env) -> u32 {
+        parameters::apply_scheduled_changes(&e)
+    }
+
+    /// Scheduled parameter changes not yet applied, ordered by effective time.
+    pub fn get_scheduled_changes(e: Env) -> Vec<parameters::ScheduledChange> {
+        parameters::get_pending(&e)
+    }
+
+    /// Current value of every protocol parameter plus the scheduled changes still pending.
+    pub fn describe_config(e: Env) -> parameters::ConfigDescription {
+        parameters::describe_config(&e)
+    }
+
     // ==================== Reentrancy Test Functions ====================
 
     /// Withdraw the full bonded amount back to the identity (callback-based, for reentrancy tests).
     /// Uses a reentrancy guard to prevent re-entrance during external calls.
     pub fn withdraw_bond_full(e: Env, identity: Address) -> i128 {
         identity.require_auth();
-        Self::acquire_lock(&e);
+        self_lock::require_unlocked(&e, &identity);
+        Self::acquire_lock(&e, "withdraw_bond_full");
 
-        let bond_key = DataKey::Bond;
+        let bond_key = DataKey::Bond(identity.clone());
         let bond: IdentityBond = e
             .storage()
             .instance()
             .get(&bond_key)
             .unwrap_or_else(|| panic!("no bond"));
 
-        if bond.identity != identity {
-            Self::release_lock(&e);
-            panic!("not bond owner");
-        }
         if !bond.active {
             Self::release_lock(&e);
             panic!("bond not active");
         }
+        if bond.status == BondStatus::Frozen {
+            Self::release_lock(&e);
+            panic!("bond frozen");
+        }
+        if collateral::get_exposure(&e, &identity) > 0 {
+            Self::release_lock(&e);
+            panic!("bond backs collateralized attestations");
+        }
+        if !perpetual_bond::notice_served(e.ledger().timestamp(), &bond) {
+            Self::release_lock(&e);
+            panic!("perpetual bond: notice not served");
+        }
+        if bond.is_rolling
+            && !rolling_bond::can_withdraw_after_notice(
+                e.ledger().timestamp(),
+                bond.withdrawal_requested_at,
+                bond.notice_period,
+            )
+        {
+            Self::release_lock(&e);
+            panic!("notice period not elapsed");
+        }
 
         let withdraw_amount = bond.bonded_amount - bond.slashed_amount;
 
+        bond_rewards::settle(&e, &identity);
         // State update BEFORE external interaction (checks-effects-interactions)
-        let updated = IdentityBond {
+        let mut updated = IdentityBond {
             identity: identity.clone(),
             bonded_amount: 0,
             bond_start: bond.bond_start,
             bond_duration: bond.bond_duration,
             slashed_amount: bond.slashed_amount,
             active: false,
-            // Add these missing fields:
-            is_rolling: false,
-            withdrawal_requested_at: 0,
-            notice_period: bond.notice_period,
             is_rolling: bond.is_rolling,
             withdrawal_requested_at: bond.withdrawal_requested_at,
-            notice_period_duration: bond.notice_period_duration,
+            notice_period: bond.notice_period,
+            kind: bond.kind.clone(),
+            status: bond.status.clone(),
         };
+        bond_status::transition(&e, &mut updated, BondTransition::Close);
         e.storage().instance().set(&bond_key, &updated);
+        maturity_ladder::sync(&e, &updated);
+        closeout::record_withdrawal(&e, &identity, withdraw_amount, 0);
+        exposure_caps::release(&e, withdraw_amount);
+        closeout::close_if_terminal(&e, &updated, false);
+        events::emit_bond_withdrawn(&e, &identity, withdraw_amount, 0);
 
         // External call: invoke callback if a callback contract is registered.
         // In production this would be a token transfer; here we use a hook for testing.
-        let cb_key = Symbol::new(&e, "callback");
-        if let Some(cb_addr) = e.storage().instance().get::<_, Address>(&cb_key) {
-            let fn_name = Symbol::new(&e, "on_withdraw");
-            let args: Vec<Val> = Vec::from_array(&e, [withdraw_amount.into_val(&e)]);
-            e.invoke_contract::<Val>(&cb_addr, &fn_name, args);
-        }
+        Self::invoke_callback(&e, "on_withdraw", withdraw_amount);
 
         Self::release_lock(&e);
         withdraw_amount
     }
 
-    /// Slash a portion of a bond. Only callable by admin.
+    /// Slash a portion of `identity`'s bond. Only callable by admin.
     /// Uses a reentrancy guard to prevent re-entrance during external calls.
-    pub fn slash_bond(e: Env, admin: Address, slash_amount: i128) -> i128 {
+    pub fn slash_bond(e: Env, admin: Address, identity: Address, slash_amount: i128) -> i128 {
         admin.require_auth();
         if slash_amount < 0 {
             panic!("slash amount must be non-negative");
         }
-        Self::acquire_lock(&e);
+        Self::acquire_lock(&e, "slash_bond");
 
         let stored_admin: Address = e
             .storage()
@@ -1576,7 +3630,7 @@ impl CredenceBond {
             panic!("not admin");
         }
 
-        let bond_key = DataKey::Bond;
+        let bond_key = DataKey::Bond(identity);
         let bond: IdentityBond = e
             .storage()
             .instance()
@@ -1587,6 +3641,7 @@ impl CredenceBond {
             Self::release_lock(&e);
             panic!("bond not active");
         }
+        slashing::require_past_grace(&e, &bond);
 
         let new_slashed = bond
             .slashed_amount
@@ -1597,32 +3652,44 @@ impl CredenceBond {
             panic!("slash exceeds bond");
         }
 
+        bond_rewards::settle(&e, &bond.identity);
         // State update BEFORE external interaction
-        let updated = IdentityBond {
+        let mut updated = IdentityBond {
             identity: bond.identity.clone(),
             bonded_amount: bond.bonded_amount,
             bond_start: bond.bond_start,
             bond_duration: bond.bond_duration,
             slashed_amount: new_slashed,
             active: bond.active,
-            // Add these missing fields:
-            is_rolling: false,
-            withdrawal_requested_at: 0,
-            notice_period: bond.notice_period,
             is_rolling: bond.is_rolling,
             withdrawal_requested_at: bond.withdrawal_requested_at,
-            notice_period_duration: bond.notice_period_duration,
+            notice_period: bond.notice_period,
+            kind: bond.kind.clone(),
+            status: bond.status.clone(),
         };
+        if new_slashed == updated.bonded_amount && bond_status::is_open(&updated.status) {
+            bond_status::transition(&e, &mut updated, BondTransition::Close);
+        }
         e.storage().instance().set(&bond_key, &updated);
-
-        // External call: invoke callback if registered
-        let cb_key = Symbol::new(&e, "callback");
-        if let Some(cb_addr) = e.storage().instance().get::<_, Address>(&cb_key) {
-            let fn_name = Symbol::new(&e, "on_slash");
-            let args: Vec<Val> = Vec::from_array(&e, [slash_amount.into_val(&e)]);
-            e.invoke_contract::<Val>(&cb_addr, &fn_name, args);
+        maturity_ladder::sync(&e, &updated);
+        closeout::close_if_terminal(&e, &updated, true);
+        slash_accounting::record_applied(&e, slash_amount);
+        exposure_caps::release(&e, slash_amount);
+        if slash_amount > 0 {
+            slash_history::append_slash_history(
+                &e,
+                &updated.identity,
+                slash_amount,
+                slash_amount,
+                Symbol::new(&e, "slash"),
+                new_slashed,
+            );
         }
 
+        // External calls: route the proceeds, then invoke callback if registered
+        slash_proceeds::route(&e, &updated.identity, slash_amount);
+        Self::invoke_callback(&e, "on_slash", slash_amount);
+
         Self::release_lock(&e);
         new_slashed
     }
@@ -1631,7 +3698,7 @@ impl CredenceBond {
     /// Uses a reentrancy guard to prevent re-entrance during external calls.
     pub fn collect_fees(e: Env, admin: Address) -> i128 {
         admin.require_auth();
-        Self::acquire_lock(&e);
+        Self::acquire_lock(&e, "collect_fees");
 
         let stored_admin: Address = e
             .storage()
@@ -1650,12 +3717,7 @@ impl CredenceBond {
         e.storage().instance().set(&fee_key, &0_i128);
 
         // External call: invoke callback if registered
-        let cb_key = Symbol::new(&e, "callback");
-        if let Some(cb_addr) = e.storage().instance().get::<_, Address>(&cb_key) {
-            let fn_name = Symbol::new(&e, "on_collect");
-            let args: Vec<Val> = Vec::from_array(&e, [fees.into_val(&e)]);
-            e.invoke_contract::<Val>(&cb_addr, &fn_name, args);
-        }
+        Self::invoke_callback(&e, "on_collect", fees);
 
         Self::release_lock(&e);
         fees
@@ -1689,6 +3751,11 @@ impl CredenceBond {
         cooldown::get_cooldown_period(&e)
     }
 
+    /// @notice Combined view of the pause, emergency mode and withdrawal cooldown in force.
+    pub fn get_effective_restrictions(e: Env) -> restrictions::EffectiveRestrictions {
+        restrictions::current(&e)
+    }
+
     /// Request a cooldown withdrawal. Records the caller's intent plus the
     /// requested amount and the current ledger timestamp. Panics if a request
     /// already exists for the same address, or if the amount exceeds the
@@ -1701,27 +3768,22 @@ impl CredenceBond {
         amount: i128,
     ) -> CooldownRequest {
         requester.require_auth();
+        self_lock::require_unlocked(&e, &requester);
 
         if amount <= 0 {
             panic!("amount must be positive");
         }
 
-        // Verify a bond exists and the requester matches the bond identity
+        // Verify the requester holds a bond
         let bond = e
             .storage()
             .instance()
-            .get::<_, IdentityBond>(&DataKey::Bond)
+            .get::<_, IdentityBond>(&DataKey::Bond(requester.clone()))
             .unwrap_or_else(|| panic!("no bond"));
-
-        if bond.identity != requester {
-            panic!("requester is not the bond holder");
-        }
+        perpetual_bond::require_notice_served(e.ledger().timestamp(), &bond);
 
         // Check available balance
-        let available = bond
-            .bonded_amount
-            .checked_sub(bond.slashed_amount)
-            .expect("slashed amount exceeds bonded amount");
+        let available = collateral::free_balance(&e, &bond);
 
         if amount > available {
             panic!("amount exceeds available balance");
@@ -1750,6 +3812,7 @@ impl CredenceBond {
     /// @param requester The address that originally requested the withdrawal
     pub fn execute_cooldown_withdrawal(e: Env, requester: Address) -> IdentityBond {
         requester.require_auth();
+        self_lock::require_unlocked(&e, &requester);
 
         let req_key = DataKey::CooldownReq(requester.clone());
         let request: CooldownRequest = e
@@ -1766,22 +3829,21 @@ impl CredenceBond {
         }
 
         // Perform the actual withdrawal on the bond
-        let bond_key = DataKey::Bond;
+        let bond_key = DataKey::Bond(requester.clone());
         let mut bond = e
             .storage()
             .instance()
             .get::<_, IdentityBond>(&bond_key)
             .unwrap_or_else(|| panic!("no bond"));
+        bond_freeze::require_not_frozen(&bond);
 
-        let available = bond
-            .bonded_amount
-            .checked_sub(bond.slashed_amount)
-            .expect("slashed amount exceeds bonded amount");
+        let available = collateral::free_balance(&e, &bond);
 
         if request.amount > available {
             panic!("insufficient balance for withdrawal");
         }
 
+        bond_rewards::settle(&e, &requester);
         bond.bonded_amount = bond
             .bonded_amount
             .checked_sub(request.amount)
@@ -1791,10 +3853,17 @@ impl CredenceBond {
             panic!("slashed amount exceeds bonded amount after withdrawal");
         }
 
+        let dust = dust::sweep(&e, &mut bond);
         e.storage().instance().set(&bond_key, &bond);
+        maturity_ladder::sync(&e, &bond);
+        closeout::record_withdrawal(&e, &bond.identity, request.amount + dust, 0);
+        exposure_caps::release(&e, request.amount + dust);
+        closeout::close_if_terminal(&e, &bond, false);
         e.storage().instance().remove(&req_key);
+        dust::pay(&e, &bond.identity, dust);
 
         cooldown::emit_cooldown_executed(&e, &requester, request.amount);
+        events::emit_bond_withdrawn(&e, &requester, request.amount, bond.bonded_amount);
         bond
     }
 
@@ -1831,23 +3900,69 @@ mod test;
 
 #[cfg(test)]
 mod test_reentrancy;
-mod test_attestation;
+
+#[cfg(test)]
+mod test_lock_watchdog;
 
 #[cfg(test)]
 mod test_batch;
 
+#[cfg(test)]
+mod test_bond_gate;
+
+#[cfg(test)]
+mod test_closeout;
+#[cfg(test)]
+mod test_dust;
+
+#[cfg(test)]
+mod test_maturity_ladder;
+
+#[cfg(test)]
+mod test_collateral;
+
+#[cfg(test)]
+mod test_payout_queue;
+
+#[cfg(test)]
+mod test_role_registry;
+
+#[cfg(test)]
+mod test_dispute_bond;
+
 #[cfg(test)]
 mod test_attestation_types;
 
+#[cfg(test)]
+mod test_subject_seq;
+
+#[cfg(test)]
+mod test_attester_bond;
+
+#[cfg(test)]
+mod test_subject_policy;
+
 #[cfg(test)]
 mod test_validation;
-mod test_attestation_types;
 
 #[cfg(test)]
 mod test_attestation;
 
 #[cfg(test)]
 mod test_governance_approval;
+#[cfg(test)]
+mod test_governance_bitmap;
+
+#[cfg(test)]
+mod test_governance_suspension;
+
+#[cfg(test)]
+mod test_governance_weighted;
+
+#[cfg(test)]
+mod test_proposal_rate_limit;
+#[cfg(test)]
+mod test_slash_fifo;
 
 #[cfg(test)]
 mod test_parameters;
@@ -1855,6 +3970,11 @@ mod test_parameters;
 #[cfg(test)]
 mod test_fees;
 
+#[cfg(test)]
+mod test_features;
+#[cfg(test)]
+mod test_init_v2;
+
 #[cfg(test)]
 mod integration;
 
@@ -1894,6 +4014,105 @@ impl CredenceBond {
     pub fn execute_pause_proposal(e: Env, proposal_id: u64) {
         pausable::execute_pause_proposal(&e, proposal_id)
     }
+
+    /// @notice Send `amount` of a token other than the bond token, held by the contract by
+    /// mistake, to `to`. Admin only; above the rescue limit `signers` must also include at
+    /// least the pause threshold of pause signers, each authorizing the call.
+    pub fn rescue_tokens(
+        e: Env,
+        admin: Address,
+        token: Address,
+        to: Address,
+        amount: i128,
+        signers: Vec<Address>,
+    ) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        Self::with_reentrancy_guard(&e, "rescue_tokens", || {
+            rescue::rescue(&e, &token, &to, amount, &signers)
+        });
+    }
+
+    /// @notice Set the largest amount `rescue_tokens` moves without pause-signer co-signers.
+    pub fn set_rescue_limit(e: Env, admin: Address, limit: i128) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        rescue::set_limit(&e, limit);
+    }
+
+    pub fn get_rescue_limit(e: Env) -> i128 {
+        rescue::get_limit(&e)
+    }
+}
+
+// Test-support entrypoints (never compiled into release wasm)
+#[cfg(any(test, feature = "testing-hooks"))]
+#[contractimpl]
+impl CredenceBond {
+    /// Register the contract whose `on_*` functions are called around token payouts, so
+    /// reentrancy tests can call back into the bond. Admin only; kept out of production until
+    /// the observer registry replaces it. Emits `callback_set` with `(old, new)`. Panics with
+    /// "invalid callback" if `callback` is this contract.
+    pub fn set_callback(e: Env, admin: Address, callback: Address) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        if callback == e.current_contract_address() {
+            panic!("invalid callback");
+        }
+        let key = Self::callback_key(&e);
+        let old: Option<Address> = e.storage().instance().get(&key);
+        e.storage().instance().set(&key, &callback);
+        events::publish(&e, "callback_set", (old, Some(callback)));
+    }
+
+    /// Remove the registered callback contract. Admin only. Emits `callback_set` with
+    /// `(old, None)`.
+    pub fn clear_callback(e: Env, admin: Address) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        let key = Self::callback_key(&e);
+        let old: Option<Address> = e.storage().instance().get(&key);
+        e.storage().instance().remove(&key);
+        events::publish(&e, "callback_set", (old, None::<Address>));
+    }
+
+    /// Arm failpoint `name` to fire on its `nth` invocation from now. Admin only.
+    pub fn arm_failpoint(e: Env, admin: Address, name: Symbol, nth: u32) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        failpoints::arm(&e, &name, nth);
+    }
+
+    /// Disarm failpoint `name`. Admin only.
+    pub fn disarm_failpoint(e: Env, admin: Address, name: Symbol) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        failpoints::disarm(&e, &name);
+    }
+
+    /// Seed bonds, attestations, counters and governance state from a declarative spec.
+    /// Returns the ids assigned to the seeded attestations. Admin only.
+    pub fn test_seed_state(e: Env, admin: Address, spec: seed::SeedSpec) -> Vec<u64> {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        seed::seed_state(&e, spec)
+    }
+
+    /// Export the instance entries stored under `keys` as XDR `(key, value)` pairs.
+    pub fn export_state(e: Env, keys: Vec<DataKey>) -> Vec<(Bytes, Bytes)> {
+        snapshot::export(&e, keys)
+    }
+
+    /// Export every instance entry, including subsystem keys outside `DataKey`.
+    pub fn export_all_state(e: Env) -> Vec<(Bytes, Bytes)> {
+        snapshot::export_all(&e)
+    }
+
+    /// Restore entries produced by `export_state` / `export_all_state`. Unauthenticated so a
+    /// baseline can be loaded into a freshly registered contract.
+    pub fn import_state(e: Env, entries: Vec<(Bytes, Bytes)>) {
+        snapshot::import(&e, entries)
+    }
 }
 
 #[cfg(test)]
@@ -1908,6 +4127,7 @@ mod test_duration_validation;
 #[cfg(test)]
 mod test_access_control;
 
+#[cfg(test)]
 mod test_cooldown;
 #[cfg(test)]
 mod test_events;
@@ -1915,9 +4135,149 @@ mod test_events;
 #[cfg(test)]
 mod test_early_exit_penalty;
 
+#[cfg(test)]
+mod test_attestation_import;
+#[cfg(test)]
+mod test_attestation_privacy;
+#[cfg(test)]
+mod test_attestation_stats;
+#[cfg(test)]
+mod test_attestation_usage;
+#[cfg(test)]
+mod test_attester_consistency;
+#[cfg(test)]
+mod test_bond_isolation;
+#[cfg(test)]
+mod test_bond_proof;
+#[cfg(test)]
+mod test_bond_replacement;
+#[cfg(test)]
+mod test_bond_split;
+#[cfg(test)]
+mod test_bond_status;
+#[cfg(test)]
+mod test_bond_timing;
+#[cfg(test)]
+mod test_bond_transfer;
+#[cfg(test)]
+mod test_close_bond;
+#[cfg(test)]
+mod test_context;
+#[cfg(test)]
+mod test_eligibility;
+#[cfg(test)]
+mod test_event_policy;
+#[cfg(test)]
+mod test_exposure_caps;
+#[cfg(test)]
+mod test_extend_and_top_up;
+#[cfg(test)]
+mod test_extend_duration;
+#[cfg(test)]
+mod test_failpoints;
+#[cfg(test)]
+mod test_fee_deposit;
+#[cfg(test)]
+mod test_fixtures;
+#[cfg(test)]
+mod test_free_withdrawal;
+#[cfg(test)]
+mod test_governance_min_bond;
+#[cfg(test)]
+mod test_governor_fees;
+#[cfg(test)]
+mod test_identity_label;
+#[cfg(test)]
+mod test_lifecycle_events;
+#[cfg(test)]
+mod test_multi_bond;
+#[cfg(test)]
+mod test_parameter_schedule;
+#[cfg(test)]
+mod test_perpetual_bond;
+#[cfg(test)]
+mod test_reactivate_bond;
+#[cfg(test)]
+mod test_reason_code;
+#[cfg(test)]
+mod test_require_funds;
+#[cfg(test)]
+mod test_rescue;
+#[cfg(test)]
+mod test_resource_budgets;
+#[cfg(test)]
+mod test_restrictions;
+#[cfg(test)]
+mod test_revocation_stats;
+#[cfg(test)]
+mod test_slash_accounting;
+#[cfg(test)]
+mod test_slash_pipeline;
+#[cfg(test)]
+mod test_slash_settlement;
+#[cfg(test)]
+mod test_slash_simulation;
+#[cfg(test)]
+mod test_subject_hook;
+#[cfg(test)]
+mod test_tier_hold;
+#[cfg(test)]
+mod test_valid_attestations;
 #[cfg(test)]
 mod test_verifier;
+#[cfg(test)]
+mod test_verifier_metadata;
+#[cfg(test)]
+mod test_withdraw_auth;
+
+#[cfg(test)]
+mod test_slash_proceeds;
+
+#[cfg(test)]
+mod test_economic_params;
+
+#[cfg(test)]
+mod test_slash_grace;
+
+#[cfg(test)]
+mod test_residual_floor;
+
+#[cfg(test)]
+mod test_journal;
+
+#[cfg(test)]
+mod test_bond_freeze;
+
+#[cfg(test)]
+mod test_uncollectable_slash;
+
+#[cfg(test)]
+mod test_topup_sponsor;
+
+#[cfg(test)]
+mod test_verifier_stats;
+
+#[cfg(test)]
+mod test_bond_rewards;
+
+#[cfg(test)]
+mod test_self_lock;
+
+#[cfg(test)]
+mod test_callback_admin;
+
+#[cfg(test)]
+mod test_attestation_migration;
+#[cfg(test)]
+mod test_bond_record_migration;
+
+#[cfg(test)]
+mod test_renewal_schedule;
+
+#[cfg(test)]
 mod test_evidence;
+
+#[cfg(test)]
 mod test_emergency;
 
 #[cfg(test)]
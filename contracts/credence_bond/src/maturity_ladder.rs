@@ -0,0 +1,114 @@
+//! Maturity Ladder
+//!
+//! Per-epoch aggregates of bonded value by maturity (`bond_start + bond_duration`), so treasury
+//! planning can read how much unlocks in each upcoming week without scanning bonds off-chain.
+//! Each bond's contribution (`bonded_amount - slashed_amount` while active) is tracked alongside
+//! the bucket it sits in; `sync` moves it whenever the bond changes, so extensions and rolling
+//! renewals shift value to the new bucket and closed bonds drop out.
+//!
+//! Buckets never go negative: a subtraction that would underflow clamps to zero and emits
+//! `maturity_ladder_invariant`.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::{BondKind, IdentityBond};
+
+/// Length of one ladder epoch in seconds (one week).
+pub const EPOCH_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// Maximum number of buckets returned by one `ladder` query.
+pub const MAX_LADDER_BUCKETS: u32 = 104;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum LadderKey {
+    /// Total value maturing in the epoch.
+    Bucket(u64),
+    /// (epoch, amount) currently counted for an identity's bond.
+    Position(Address),
+}
+
+/// @notice Epoch in which `bond` matures (current period end for rolling bonds).
+#[must_use]
+pub fn maturity_epoch(bond: &IdentityBond) -> u64 {
+    bond.bond_start.saturating_add(bond.bond_duration) / EPOCH_SECS
+}
+
+fn contribution(bond: &IdentityBond) -> i128 {
+    // Perpetual bonds never mature, so they never appear in a bucket.
+    if !bond.active || bond.kind == BondKind::Perpetual {
+        return 0;
+    }
+    bond.bonded_amount
+        .checked_sub(bond.slashed_amount)
+        .unwrap_or(0)
+        .max(0)
+}
+
+/// @notice Total value maturing in `epoch`.
+#[must_use]
+pub fn get_bucket(e: &Env, epoch: u64) -> i128 {
+    e.storage()
+        .instance()
+        .get(&LadderKey::Bucket(epoch))
+        .unwrap_or(0)
+}
+
+fn set_bucket(e: &Env, epoch: u64, amount: i128) {
+    let key = LadderKey::Bucket(epoch);
+    if amount == 0 {
+        e.storage().instance().remove(&key);
+    } else {
+        e.storage().instance().set(&key, &amount);
+    }
+}
+
+fn add_to_bucket(e: &Env, epoch: u64, delta: i128) {
+    let current = get_bucket(e, epoch);
+    let updated = current
+        .checked_add(delta)
+        .expect("maturity ladder overflow");
+    if updated < 0 {
+        crate::events::publish(e, "maturity_ladder_invariant", (epoch, current, delta));
+        set_bucket(e, epoch, 0);
+    } else {
+        set_bucket(e, epoch, updated);
+    }
+}
+
+/// @notice Re-buckets `bond` after any change to its amount, term or status.
+pub fn sync(e: &Env, bond: &IdentityBond) {
+    let key = LadderKey::Position(bond.identity.clone());
+    let previous: Option<(u64, i128)> = e.storage().instance().get(&key);
+    if let Some((epoch, amount)) = previous {
+        add_to_bucket(e, epoch, -amount);
+    }
+
+    let amount = contribution(bond);
+    if amount == 0 {
+        e.storage().instance().remove(&key);
+        return;
+    }
+    let epoch = maturity_epoch(bond);
+    add_to_bucket(e, epoch, amount);
+    e.storage().instance().set(&key, &(epoch, amount));
+}
+
+/// @notice Returns `(epoch, amount)` for `count` consecutive epochs starting at `start_epoch`.
+///
+/// # Panics
+/// - "ladder range too large" if `count > MAX_LADDER_BUCKETS`.
+#[must_use]
+pub fn ladder(e: &Env, start_epoch: u64, count: u32) -> Vec<(u64, i128)> {
+    if count > MAX_LADDER_BUCKETS {
+        panic!("ladder range too large");
+    }
+    let mut out = Vec::new(e);
+    for i in 0..u64::from(count) {
+        let Some(epoch) = start_epoch.checked_add(i) else {
+            break;
+        };
+        out.push_back((epoch, get_bucket(e, epoch)));
+    }
+    out
+}
@@ -0,0 +1,321 @@
+//! Multiple Bonds per Identity
+//!
+//! An identity can hold several fixed bonds side by side, for example a short-term working bond
+//! next to a long-term reputation bond. Bonds are addressed by `(identity, bond_id)`:
+//!
+//! - Bond 0 is the identity's original bond under `DataKey::Bond(identity)`. Every
+//!   single-bond entrypoint keeps operating on it unchanged.
+//! - `create_bond_v2` opens bonds 1, 2, ... under `DataKey::BondEntry(identity, bond_id)`. Ids
+//!   come from a per-identity counter and are never reused.
+//!
+//! Features keyed by identity alone (maturity ladder, close-out statements, collateral, rolling
+//! renewals, cooldown requests, effective tier) only track bond 0. `create_bond_v2` opens fixed
+//! bonds, which unlock at `bond_start + bond_duration`; `split_bond` can also carve out rolling
+//! tranches, which unlock once `request_bond_withdrawal` was called and the notice has elapsed.
+//! Rolling tranches do not renew. Withdrawing everything closes an additional bond.
+//!
+//! `aggregate_tier` is the tier for the sum of `bonded_amount` over the identity's open bonds.
+
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+use crate::bond_status::{self, BondTransition};
+use crate::{
+    bond_freeze, bond_gate, bond_rewards, events, exposure_caps, fees, payout_queue, rolling_bond,
+    slash_accounting, slash_history, slash_proceeds, slashing, tiered_bond, token_integration,
+    validation, BondKind, BondStatus, BondTier, DataKey, IdentityBond,
+};
+
+/// Id of the identity's original bond.
+pub const LEGACY_BOND_ID: u64 = 0;
+
+/// Additional bonds an identity may open.
+pub const MAX_EXTRA_BONDS: u32 = 16;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum MultiBondKey {
+    /// Last id handed out to an identity.
+    LastId(Address),
+    /// Ids of the identity's additional bonds, in creation order.
+    Ids(Address),
+}
+
+fn key(identity: &Address, bond_id: u64) -> DataKey {
+    if bond_id == LEGACY_BOND_ID {
+        DataKey::Bond(identity.clone())
+    } else {
+        DataKey::BondEntry(identity.clone(), bond_id)
+    }
+}
+
+fn extra_ids(e: &Env, identity: &Address) -> Vec<u64> {
+    e.storage()
+        .instance()
+        .get(&MultiBondKey::Ids(identity.clone()))
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+/// @notice Bond `bond_id` of `identity`, if it exists.
+#[must_use]
+pub fn load(e: &Env, identity: &Address, bond_id: u64) -> Option<IdentityBond> {
+    e.storage().instance().get(&key(identity, bond_id))
+}
+
+/// @notice Bond `bond_id` of `identity`.
+///
+/// # Panics
+/// - "no bond"
+#[must_use]
+pub fn require(e: &Env, identity: &Address, bond_id: u64) -> IdentityBond {
+    load(e, identity, bond_id).unwrap_or_else(|| panic!("no bond"))
+}
+
+/// @notice Ids of every bond `identity` holds: 0 first if the original bond exists, then the
+/// additional bonds in creation order.
+#[must_use]
+pub fn list_ids(e: &Env, identity: &Address) -> Vec<u64> {
+    let mut ids = Vec::new(e);
+    if load(e, identity, LEGACY_BOND_ID).is_some() {
+        ids.push_back(LEGACY_BOND_ID);
+    }
+    ids.append(&extra_ids(e, identity));
+    ids
+}
+
+/// @notice Sum of `bonded_amount` over `identity`'s open bonds.
+#[must_use]
+pub fn total_bonded(e: &Env, identity: &Address) -> i128 {
+    let mut total = 0_i128;
+    for bond_id in list_ids(e, identity).iter() {
+        let bond = require(e, identity, bond_id);
+        if bond.status != BondStatus::Closed {
+            total = total
+                .checked_add(bond.bonded_amount)
+                .expect("bond total overflow");
+        }
+    }
+    total
+}
+
+/// @notice Sum of `bonded_amount` over `identity`'s open bonds once `amount` more is deposited,
+/// the figure the per-identity exposure cap is checked against.
+#[must_use]
+pub fn total_after_deposit(e: &Env, identity: &Address, amount: i128) -> i128 {
+    total_bonded(e, identity)
+        .checked_add(amount)
+        .expect("bond total overflow")
+}
+
+/// @notice Tier for the combined bonded amount of `identity`'s open bonds.
+#[must_use]
+pub fn aggregate_tier(e: &Env, identity: &Address) -> BondTier {
+    tiered_bond::get_tier_for_amount(total_bonded(e, identity))
+}
+
+/// @notice Stores `bond` as `identity`'s next additional bond and returns its id.
+///
+/// # Panics
+/// - "too many bonds" once `MAX_EXTRA_BONDS` additional bonds were opened.
+pub fn insert(e: &Env, identity: &Address, bond: &IdentityBond) -> u64 {
+    let mut ids = extra_ids(e, identity);
+    if ids.len() >= MAX_EXTRA_BONDS {
+        panic!("too many bonds");
+    }
+    let last: u64 = e
+        .storage()
+        .instance()
+        .get(&MultiBondKey::LastId(identity.clone()))
+        .unwrap_or(LEGACY_BOND_ID);
+    let bond_id = last.checked_add(1).expect("bond id overflow");
+    e.storage().instance().set(&key(identity, bond_id), bond);
+    e.storage()
+        .instance()
+        .set(&MultiBondKey::LastId(identity.clone()), &bond_id);
+    ids.push_back(bond_id);
+    e.storage()
+        .instance()
+        .set(&MultiBondKey::Ids(identity.clone()), &ids);
+    bond_id
+}
+
+/// @notice Opens an additional fixed bond for `identity` (identity auth enforced here) and
+/// returns its id. Applies the same amount and duration bounds, gate, fee and exposure caps as
+/// `create_bond`. Emits `bond_entry_created` (topic: identity) with
+/// `(bond_id, bonded_amount, duration)`.
+///
+/// # Panics
+/// - "too many bonds" once `MAX_EXTRA_BONDS` additional bonds were opened.
+pub fn open(e: &Env, identity: &Address, amount: i128, duration: u64) -> u64 {
+    validation::validate_bond_amount(amount);
+    validation::validate_bond_duration(duration);
+    identity.require_auth();
+    if extra_ids(e, identity).len() >= MAX_EXTRA_BONDS {
+        panic!("too many bonds");
+    }
+    bond_gate::require_gate(e, identity);
+    token_integration::get_token(e);
+    token_integration::require_owner_funds(e, identity, amount);
+    let bond_start = e.ledger().timestamp();
+    bond_start
+        .checked_add(duration)
+        .expect("bond end timestamp would overflow");
+
+    let (fee, net_amount) = fees::calculate_fee(e, amount);
+    if fee > 0 {
+        let (treasury_opt, _) = fees::get_config(e);
+        if let Some(treasury) = treasury_opt {
+            fees::record_fee(e, identity, amount, fee, &treasury);
+        }
+    }
+    exposure_caps::reserve(e, total_after_deposit(e, identity, net_amount), net_amount);
+
+    let bond = IdentityBond {
+        identity: identity.clone(),
+        bonded_amount: net_amount,
+        bond_start,
+        bond_duration: duration,
+        slashed_amount: 0,
+        active: true,
+        is_rolling: false,
+        withdrawal_requested_at: 0,
+        notice_period: 0,
+        kind: BondKind::Fixed,
+        status: BondStatus::Active,
+    };
+    let bond_id = insert(e, identity, &bond);
+
+    token_integration::transfer_into_contract(e, identity, amount);
+    events::publish_for(
+        e,
+        "bond_entry_created",
+        identity.clone(),
+        (bond_id, net_amount, duration),
+    );
+    crate::journal::append(e, identity, "bond_entry_created", net_amount, bond_id);
+    bond_id
+}
+
+/// @notice Starts the notice period of rolling additional bond `bond_id` (identity auth
+/// enforced by the caller). Emits `bond_entry_withdrawal_requested` (topic: identity) with
+/// `(bond_id, requested_at)`.
+///
+/// # Panics
+/// - "not a rolling bond", "withdrawal already requested", "invalid bond transition"
+/// - "bond frozen" while the identity is frozen
+pub fn request_withdrawal(e: &Env, identity: &Address, bond_id: u64) -> IdentityBond {
+    let mut bond = require(e, identity, bond_id);
+    bond_freeze::require_identity_not_frozen(e, identity);
+    if !bond.is_rolling {
+        panic!("not a rolling bond");
+    }
+    if bond.withdrawal_requested_at != 0 {
+        panic!("withdrawal already requested");
+    }
+    bond_status::transition(e, &mut bond, BondTransition::ServeNotice);
+    bond.withdrawal_requested_at = e.ledger().timestamp();
+    e.storage().instance().set(&key(identity, bond_id), &bond);
+    events::publish_for(
+        e,
+        "bond_entry_withdrawal_requested",
+        identity.clone(),
+        (bond_id, bond.withdrawal_requested_at),
+    );
+    crate::journal::append(e, identity, "bond_entry_withdrawal_requested", 0, bond_id);
+    bond
+}
+
+/// @notice Withdraws `amount` from additional bond `bond_id` once it has unlocked (identity
+/// auth enforced by the caller): the lock-up has ended for fixed bonds, the requested notice
+/// has elapsed for rolling ones. Withdrawing everything left closes the bond. Emits
+/// `bond_entry_withdrawn` (topic: identity) with `(bond_id, amount, remaining)`.
+///
+/// # Panics
+/// - "lock-up period not elapsed", "notice period not elapsed"
+/// - "insufficient balance for withdrawal", "bond not active"
+/// - "bond frozen" while the identity is frozen
+pub fn withdraw(e: &Env, identity: &Address, bond_id: u64, amount: i128) -> IdentityBond {
+    if amount < 0 {
+        panic!("amount must be non-negative");
+    }
+    let mut bond = require(e, identity, bond_id);
+    if bond.status == BondStatus::Closed {
+        panic!("bond not active");
+    }
+    bond_freeze::require_identity_not_frozen(e, identity);
+    let now = e.ledger().timestamp();
+    if bond.is_rolling {
+        rolling_bond::require_notice_elapsed(now, &bond);
+    } else if now < bond.bond_start.saturating_add(bond.bond_duration) {
+        panic!("lock-up period not elapsed");
+    }
+    let available = bond.bonded_amount - bond.slashed_amount;
+    if amount > available {
+        panic!("insufficient balance for withdrawal");
+    }
+
+    if bond_id == LEGACY_BOND_ID {
+        bond_rewards::settle(e, identity);
+    }
+    bond.bonded_amount = bond
+        .bonded_amount
+        .checked_sub(amount)
+        .expect("withdrawal caused underflow");
+    let step = if bond.bonded_amount == bond.slashed_amount {
+        BondTransition::Close
+    } else {
+        BondTransition::Mature
+    };
+    bond_status::transition(e, &mut bond, step);
+    e.storage().instance().set(&key(identity, bond_id), &bond);
+    exposure_caps::release(e, amount);
+
+    payout_queue::pay_or_queue(e, identity, amount);
+    let remaining = bond.bonded_amount - bond.slashed_amount;
+    events::publish_for(
+        e,
+        "bond_entry_withdrawn",
+        identity.clone(),
+        (bond_id, amount, remaining),
+    );
+    crate::journal::append(e, identity, "bond_entry_withdrawn", amount, bond_id);
+    bond
+}
+
+/// @notice Slashes `amount` from additional bond `bond_id`, capped at its bonded amount (admin
+/// check enforced by the caller). Emits `bond_entry_slashed` (topic: identity) with
+/// `(bond_id, amount, slashed_amount)`.
+pub fn slash(e: &Env, identity: &Address, bond_id: u64, amount: i128) -> IdentityBond {
+    if amount < 0 {
+        panic!("slash amount must be non-negative");
+    }
+    let mut bond = require(e, identity, bond_id);
+    if bond_id == LEGACY_BOND_ID {
+        bond_rewards::settle(e, identity);
+    }
+    let previous = bond.slashed_amount;
+    bond.slashed_amount =
+        slashing::capped_slashed_amount(bond.bonded_amount, bond.slashed_amount, amount);
+    let applied = bond.slashed_amount - previous;
+    slash_accounting::record_applied(e, applied);
+    exposure_caps::release(e, applied);
+    e.storage().instance().set(&key(identity, bond_id), &bond);
+    if amount > 0 {
+        slash_history::append_slash_history(
+            e,
+            identity,
+            amount,
+            applied,
+            Symbol::new(e, "slash"),
+            bond.slashed_amount,
+        );
+    }
+    events::publish_for(
+        e,
+        "bond_entry_slashed",
+        identity.clone(),
+        (bond_id, amount, bond.slashed_amount),
+    );
+    crate::journal::append(e, identity, "bond_entry_slashed", amount, bond_id);
+    slash_proceeds::route(e, identity, applied);
+    bond
+}
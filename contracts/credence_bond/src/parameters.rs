@@ -7,6 +7,7 @@
 //! 1. **Fee Rates** - Protocol fees expressed as basis points (1 bps = 0.01%)
 //! 2. **Cooldown Periods** - Time delays between operations (in seconds)
 //! 3. **Tier Thresholds** - Value boundaries defining user/operation tiers (in token units)
+//! 4. **Bond Limits** - Longest duration a bond may be extended to (in seconds)
 //!
 //! ## Governance Control
 //! All parameter updates are restricted to the governance address (contract admin).
@@ -23,8 +24,19 @@
 //! - new value
 //! - caller address
 //! - timestamp
+//!
+//! ## Scheduled Changes
+//! A change can instead be announced ahead of time with `schedule_change`, which validates the
+//! value now and stores it in a pending list ordered by `effective_at`. The first read of the
+//! parameter at or after `effective_at` (or `apply_scheduled_changes`) applies it, emitting
+//! `change_applied` and `parameter_changed`. Until then reads return the old value and the
+//! admin may `cancel_change`.
+//!
+//! ## Economic Parameters
+//! The tier thresholds are also part of the `economic_params` bundle. Their setters write
+//! through it, so a bundle update and a single-threshold update are validated the same way.
 
-use soroban_sdk::{contracttype, Address, Env, String, Symbol};
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
 
 // ============================================================================
 // Parameter Bounds Constants
@@ -86,12 +98,22 @@ pub const MAX_PLATINUM_THRESHOLD: i128 = 1_000_000_000_000_000;
 /// Default platinum tier threshold (100000 tokens)
 pub const DEFAULT_PLATINUM_THRESHOLD: i128 = 100_000_000_000;
 
+/// Minimum allowed max bond duration in seconds (1 day, the shortest bond)
+pub const MIN_MAX_BOND_DURATION: u64 = crate::validation::MIN_BOND_DURATION;
+/// Maximum allowed max bond duration in seconds (10 years)
+pub const MAX_MAX_BOND_DURATION: u64 = 315_360_000;
+/// Default max bond duration in seconds (365 days, the longest bond `create_bond` accepts)
+pub const DEFAULT_MAX_BOND_DURATION: u64 = crate::validation::MAX_BOND_DURATION;
+
+/// Largest number of scheduled changes that may be pending at once.
+pub const MAX_PENDING_CHANGES: u32 = 20;
+
 // ============================================================================
 // Storage Keys
 // ============================================================================
 
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ParameterKey {
     ProtocolFeeBps,
     AttestationFeeBps,
@@ -101,6 +123,44 @@ pub enum ParameterKey {
     SilverThreshold,
     GoldThreshold,
     PlatinumThreshold,
+    MaxBondDuration,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum ScheduleKey {
+    /// Pending changes, ordered by (effective_at, id)
+    Pending,
+    /// Last assigned change id
+    NextId,
+}
+
+/// A parameter change announced ahead of its effective time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledChange {
+    pub id: u64,
+    pub key: ParameterKey,
+    /// New value, normalized to i128 like `parameter_changed`.
+    pub value: i128,
+    pub effective_at: u64,
+    pub scheduled_by: Address,
+}
+
+/// Current value of one parameter (normalized to i128).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParameterValue {
+    pub key: ParameterKey,
+    pub value: i128,
+}
+
+/// Returned by `describe_config`: every parameter's current value plus pending changes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigDescription {
+    pub current: Vec<ParameterValue>,
+    pub pending: Vec<ScheduledChange>,
 }
 
 // ============================================================================
@@ -118,6 +178,7 @@ pub enum ParameterKey {
 /// ```
 #[must_use]
 pub fn get_protocol_fee_bps(e: &Env) -> u32 {
+    apply_due(e, Some(&ParameterKey::ProtocolFeeBps));
     e.storage()
         .instance()
         .get(&ParameterKey::ProtocolFeeBps)
@@ -130,6 +191,7 @@ pub fn get_protocol_fee_bps(e: &Env) -> u32 {
 /// Attestation fee rate (u32) in basis points. Returns default if not set.
 #[must_use]
 pub fn get_attestation_fee_bps(e: &Env) -> u32 {
+    apply_due(e, Some(&ParameterKey::AttestationFeeBps));
     e.storage()
         .instance()
         .get(&ParameterKey::AttestationFeeBps)
@@ -142,6 +204,7 @@ pub fn get_attestation_fee_bps(e: &Env) -> u32 {
 /// Cooldown period (u64) in seconds. Returns default if not set.
 #[must_use]
 pub fn get_withdrawal_cooldown_secs(e: &Env) -> u64 {
+    apply_due(e, Some(&ParameterKey::WithdrawalCooldownSecs));
     e.storage()
         .instance()
         .get(&ParameterKey::WithdrawalCooldownSecs)
@@ -154,6 +217,7 @@ pub fn get_withdrawal_cooldown_secs(e: &Env) -> u64 {
 /// Cooldown period (u64) in seconds. Returns default if not set.
 #[must_use]
 pub fn get_slash_cooldown_secs(e: &Env) -> u64 {
+    apply_due(e, Some(&ParameterKey::SlashCooldownSecs));
     e.storage()
         .instance()
         .get(&ParameterKey::SlashCooldownSecs)
@@ -166,6 +230,7 @@ pub fn get_slash_cooldown_secs(e: &Env) -> u64 {
 /// Threshold amount (i128). Returns default if not set.
 #[must_use]
 pub fn get_bronze_threshold(e: &Env) -> i128 {
+    apply_due(e, Some(&ParameterKey::BronzeThreshold));
     e.storage()
         .instance()
         .get(&ParameterKey::BronzeThreshold)
@@ -178,6 +243,7 @@ pub fn get_bronze_threshold(e: &Env) -> i128 {
 /// Threshold amount (i128). Returns default if not set.
 #[must_use]
 pub fn get_silver_threshold(e: &Env) -> i128 {
+    apply_due(e, Some(&ParameterKey::SilverThreshold));
     e.storage()
         .instance()
         .get(&ParameterKey::SilverThreshold)
@@ -190,6 +256,7 @@ pub fn get_silver_threshold(e: &Env) -> i128 {
 /// Threshold amount (i128). Returns default if not set.
 #[must_use]
 pub fn get_gold_threshold(e: &Env) -> i128 {
+    apply_due(e, Some(&ParameterKey::GoldThreshold));
     e.storage()
         .instance()
         .get(&ParameterKey::GoldThreshold)
@@ -202,12 +269,26 @@ pub fn get_gold_threshold(e: &Env) -> i128 {
 /// Threshold amount (i128). Returns default if not set.
 #[must_use]
 pub fn get_platinum_threshold(e: &Env) -> i128 {
+    apply_due(e, Some(&ParameterKey::PlatinumThreshold));
     e.storage()
         .instance()
         .get(&ParameterKey::PlatinumThreshold)
         .unwrap_or(DEFAULT_PLATINUM_THRESHOLD)
 }
 
+/// Get the longest duration `extend_duration` may extend a bond to, in seconds.
+///
+/// # Returns
+/// Max duration (u64) in seconds. Returns default if not set.
+#[must_use]
+pub fn get_max_bond_duration(e: &Env) -> u64 {
+    apply_due(e, Some(&ParameterKey::MaxBondDuration));
+    e.storage()
+        .instance()
+        .get(&ParameterKey::MaxBondDuration)
+        .unwrap_or(DEFAULT_MAX_BOND_DURATION)
+}
+
 // ============================================================================
 // Parameter Setters (Governance-Only)
 // ============================================================================
@@ -379,14 +460,11 @@ pub fn set_slash_cooldown_secs(e: &Env, admin: &Address, value: u64) {
 pub fn set_bronze_threshold(e: &Env, admin: &Address, value: i128) {
     validate_admin(e, admin);
 
-    if !(MIN_BRONZE_THRESHOLD..=MAX_BRONZE_THRESHOLD).contains(&value) {
-        panic!("bronze_threshold out of bounds");
-    }
-
-    let old_value = get_bronze_threshold(e);
-    e.storage()
-        .instance()
-        .set(&ParameterKey::BronzeThreshold, &value);
+    let params = crate::economic_params::EconomicParams {
+        bronze_threshold: value,
+        ..crate::economic_params::get(e)
+    };
+    let old_value = crate::economic_params::write(e, &params).bronze_threshold;
 
     emit_parameter_changed(e, "bronze_threshold", old_value, value, admin);
 }
@@ -410,14 +488,11 @@ pub fn set_bronze_threshold(e: &Env, admin: &Address, value: i128) {
 pub fn set_silver_threshold(e: &Env, admin: &Address, value: i128) {
     validate_admin(e, admin);
 
-    if !(MIN_SILVER_THRESHOLD..=MAX_SILVER_THRESHOLD).contains(&value) {
-        panic!("silver_threshold out of bounds");
-    }
-
-    let old_value = get_silver_threshold(e);
-    e.storage()
-        .instance()
-        .set(&ParameterKey::SilverThreshold, &value);
+    let params = crate::economic_params::EconomicParams {
+        silver_threshold: value,
+        ..crate::economic_params::get(e)
+    };
+    let old_value = crate::economic_params::write(e, &params).silver_threshold;
 
     emit_parameter_changed(e, "silver_threshold", old_value, value, admin);
 }
@@ -441,14 +516,11 @@ pub fn set_silver_threshold(e: &Env, admin: &Address, value: i128) {
 pub fn set_gold_threshold(e: &Env, admin: &Address, value: i128) {
     validate_admin(e, admin);
 
-    if !(MIN_GOLD_THRESHOLD..=MAX_GOLD_THRESHOLD).contains(&value) {
-        panic!("gold_threshold out of bounds");
-    }
-
-    let old_value = get_gold_threshold(e);
-    e.storage()
-        .instance()
-        .set(&ParameterKey::GoldThreshold, &value);
+    let params = crate::economic_params::EconomicParams {
+        gold_threshold: value,
+        ..crate::economic_params::get(e)
+    };
+    let old_value = crate::economic_params::write(e, &params).gold_threshold;
 
     emit_parameter_changed(e, "gold_threshold", old_value, value, admin);
 }
@@ -472,16 +544,304 @@ pub fn set_gold_threshold(e: &Env, admin: &Address, value: i128) {
 pub fn set_platinum_threshold(e: &Env, admin: &Address, value: i128) {
     validate_admin(e, admin);
 
-    if !(MIN_PLATINUM_THRESHOLD..=MAX_PLATINUM_THRESHOLD).contains(&value) {
-        panic!("platinum_threshold out of bounds");
+    let params = crate::economic_params::EconomicParams {
+        platinum_threshold: value,
+        ..crate::economic_params::get(e)
+    };
+    let old_value = crate::economic_params::write(e, &params).platinum_threshold;
+
+    emit_parameter_changed(e, "platinum_threshold", old_value, value, admin);
+}
+
+/// Set the max bond duration. Governance-only.
+///
+/// # Arguments
+/// * `e` - Soroban environment
+/// * `admin` - Governance address (must be contract admin)
+/// * `value` - New max duration in seconds
+///
+/// # Bounds
+/// Must be between MIN_MAX_BOND_DURATION and MAX_MAX_BOND_DURATION (1 day-10 years)
+///
+/// # Panics
+/// - "not admin" if caller is not the contract admin
+/// - "max_bond_duration out of bounds" if value < min or value > max
+///
+/// # Events
+/// Emits `parameter_changed` event with old and new values
+pub fn set_max_bond_duration(e: &Env, admin: &Address, value: u64) {
+    validate_admin(e, admin);
+
+    if !(MIN_MAX_BOND_DURATION..=MAX_MAX_BOND_DURATION).contains(&value) {
+        panic!("max_bond_duration out of bounds");
     }
 
-    let old_value = get_platinum_threshold(e);
+    let old_value = get_max_bond_duration(e);
     e.storage()
         .instance()
-        .set(&ParameterKey::PlatinumThreshold, &value);
+        .set(&ParameterKey::MaxBondDuration, &value);
 
-    emit_parameter_changed(e, "platinum_threshold", old_value, value, admin);
+    emit_parameter_changed(
+        e,
+        "max_bond_duration",
+        old_value as i128,
+        value as i128,
+        admin,
+    );
+}
+
+// ============================================================================
+// Scheduled Changes
+// ============================================================================
+
+/// Announce that `key` will change to `value` at `effective_at`. Governance-only.
+///
+/// # Panics
+/// - "not admin" if caller is not the contract admin
+/// - "effective_at must be in the future"
+/// - "<parameter> out of bounds" if `value` is outside the parameter's bounds
+/// - "too many pending changes" if `MAX_PENDING_CHANGES` are already pending
+///
+/// # Events
+/// Emits `change_scheduled` with (id, parameter, value, effective_at)
+pub fn schedule_change(
+    e: &Env,
+    admin: &Address,
+    key: ParameterKey,
+    value: i128,
+    effective_at: u64,
+) -> u64 {
+    validate_admin(e, admin);
+    if effective_at <= e.ledger().timestamp() {
+        panic!("effective_at must be in the future");
+    }
+    check_bounds(&key, value);
+
+    let mut pending = get_pending(e);
+    if pending.len() >= MAX_PENDING_CHANGES {
+        panic!("too many pending changes");
+    }
+    let id = e
+        .storage()
+        .instance()
+        .get::<_, u64>(&ScheduleKey::NextId)
+        .unwrap_or(0)
+        .checked_add(1)
+        .expect("change id overflow");
+    e.storage().instance().set(&ScheduleKey::NextId, &id);
+
+    let position = pending
+        .iter()
+        .position(|c| c.effective_at > effective_at)
+        .map_or(pending.len(), |i| i as u32);
+    pending.insert(
+        position,
+        ScheduledChange {
+            id,
+            key: key.clone(),
+            value,
+            effective_at,
+            scheduled_by: admin.clone(),
+        },
+    );
+    e.storage().instance().set(&ScheduleKey::Pending, &pending);
+
+    crate::events::publish(
+        e,
+        "change_scheduled",
+        (id, String::from_str(e, name(&key)), value, effective_at),
+    );
+    id
+}
+
+/// Drop a pending change before it takes effect. Governance-only.
+///
+/// # Panics
+/// - "not admin" if caller is not the contract admin
+/// - "scheduled change not found" if `id` is not pending (never scheduled, applied or
+///   cancelled)
+///
+/// # Events
+/// Emits `change_cancelled` with the change
+pub fn cancel_change(e: &Env, admin: &Address, id: u64) {
+    validate_admin(e, admin);
+    apply_due(e, None);
+    let mut pending = get_pending(e);
+    let index = pending
+        .iter()
+        .position(|c| c.id == id)
+        .unwrap_or_else(|| panic!("scheduled change not found"));
+    let change = pending.get_unchecked(index as u32);
+    pending.remove(index as u32);
+    e.storage().instance().set(&ScheduleKey::Pending, &pending);
+    crate::events::publish(e, "change_cancelled", change);
+}
+
+/// Apply every pending change whose effective time has passed. Returns how many were applied.
+pub fn apply_scheduled_changes(e: &Env) -> u32 {
+    apply_due(e, None)
+}
+
+/// Pending changes, ordered by effective time. Includes changes that are due but not yet
+/// applied.
+#[must_use]
+pub fn get_pending(e: &Env) -> Vec<ScheduledChange> {
+    e.storage()
+        .instance()
+        .get(&ScheduleKey::Pending)
+        .unwrap_or(Vec::new(e))
+}
+
+/// Applies due changes, then returns every parameter's current value and the changes still
+/// pending.
+pub fn describe_config(e: &Env) -> ConfigDescription {
+    apply_due(e, None);
+    let mut current = Vec::new(e);
+    for key in [
+        ParameterKey::ProtocolFeeBps,
+        ParameterKey::AttestationFeeBps,
+        ParameterKey::WithdrawalCooldownSecs,
+        ParameterKey::SlashCooldownSecs,
+        ParameterKey::BronzeThreshold,
+        ParameterKey::SilverThreshold,
+        ParameterKey::GoldThreshold,
+        ParameterKey::PlatinumThreshold,
+        ParameterKey::MaxBondDuration,
+    ] {
+        let value = stored_value(e, &key);
+        current.push_back(ParameterValue { key, value });
+    }
+    ConfigDescription {
+        current,
+        pending: get_pending(e),
+    }
+}
+
+/// Applies due changes to `only` (or to every parameter when `None`), oldest first.
+fn apply_due(e: &Env, only: Option<&ParameterKey>) -> u32 {
+    let pending = get_pending(e);
+    let now = e.ledger().timestamp();
+    let mut remaining = Vec::new(e);
+    let mut applied = 0u32;
+    for change in pending.iter() {
+        let due = change.effective_at <= now && only.is_none_or(|k| *k == change.key);
+        if !due {
+            remaining.push_back(change);
+            continue;
+        }
+        let old_value = stored_value(e, &change.key);
+        store_value(e, &change.key, change.value);
+        let parameter = name(&change.key);
+        crate::events::publish(
+            e,
+            "change_applied",
+            (
+                change.id,
+                String::from_str(e, parameter),
+                old_value,
+                change.value,
+            ),
+        );
+        emit_parameter_changed(e, parameter, old_value, change.value, &change.scheduled_by);
+        applied += 1;
+    }
+    if applied > 0 {
+        e.storage()
+            .instance()
+            .set(&ScheduleKey::Pending, &remaining);
+    }
+    applied
+}
+
+/// Name used in events and bounds errors.
+fn name(key: &ParameterKey) -> &'static str {
+    match key {
+        ParameterKey::ProtocolFeeBps => "protocol_fee_bps",
+        ParameterKey::AttestationFeeBps => "attestation_fee_bps",
+        ParameterKey::WithdrawalCooldownSecs => "withdrawal_cooldown_secs",
+        ParameterKey::SlashCooldownSecs => "slash_cooldown_secs",
+        ParameterKey::BronzeThreshold => "bronze_threshold",
+        ParameterKey::SilverThreshold => "silver_threshold",
+        ParameterKey::GoldThreshold => "gold_threshold",
+        ParameterKey::PlatinumThreshold => "platinum_threshold",
+        ParameterKey::MaxBondDuration => "max_bond_duration",
+    }
+}
+
+/// Same bounds the immediate setters enforce.
+fn check_bounds(key: &ParameterKey, value: i128) {
+    let (min, max) = match key {
+        ParameterKey::ProtocolFeeBps => (
+            i128::from(MIN_PROTOCOL_FEE_BPS),
+            i128::from(MAX_PROTOCOL_FEE_BPS),
+        ),
+        ParameterKey::AttestationFeeBps => (
+            i128::from(MIN_ATTESTATION_FEE_BPS),
+            i128::from(MAX_ATTESTATION_FEE_BPS),
+        ),
+        ParameterKey::WithdrawalCooldownSecs => (
+            i128::from(MIN_WITHDRAWAL_COOLDOWN_SECS),
+            i128::from(MAX_WITHDRAWAL_COOLDOWN_SECS),
+        ),
+        ParameterKey::SlashCooldownSecs => (
+            i128::from(MIN_SLASH_COOLDOWN_SECS),
+            i128::from(MAX_SLASH_COOLDOWN_SECS),
+        ),
+        ParameterKey::BronzeThreshold => (MIN_BRONZE_THRESHOLD, MAX_BRONZE_THRESHOLD),
+        ParameterKey::SilverThreshold => (MIN_SILVER_THRESHOLD, MAX_SILVER_THRESHOLD),
+        ParameterKey::GoldThreshold => (MIN_GOLD_THRESHOLD, MAX_GOLD_THRESHOLD),
+        ParameterKey::PlatinumThreshold => (MIN_PLATINUM_THRESHOLD, MAX_PLATINUM_THRESHOLD),
+        ParameterKey::MaxBondDuration => (
+            i128::from(MIN_MAX_BOND_DURATION),
+            i128::from(MAX_MAX_BOND_DURATION),
+        ),
+    };
+    if !(min..=max).contains(&value) {
+        panic!("{} out of bounds", name(key));
+    }
+}
+
+/// Current value of `key` without applying scheduled changes.
+fn stored_value(e: &Env, key: &ParameterKey) -> i128 {
+    let storage = e.storage().instance();
+    match key {
+        ParameterKey::ProtocolFeeBps => {
+            i128::from(storage.get(key).unwrap_or(DEFAULT_PROTOCOL_FEE_BPS))
+        }
+        ParameterKey::AttestationFeeBps => {
+            i128::from(storage.get(key).unwrap_or(DEFAULT_ATTESTATION_FEE_BPS))
+        }
+        ParameterKey::WithdrawalCooldownSecs => {
+            i128::from(storage.get(key).unwrap_or(DEFAULT_WITHDRAWAL_COOLDOWN_SECS))
+        }
+        ParameterKey::SlashCooldownSecs => {
+            i128::from(storage.get(key).unwrap_or(DEFAULT_SLASH_COOLDOWN_SECS))
+        }
+        ParameterKey::BronzeThreshold => storage.get(key).unwrap_or(DEFAULT_BRONZE_THRESHOLD),
+        ParameterKey::SilverThreshold => storage.get(key).unwrap_or(DEFAULT_SILVER_THRESHOLD),
+        ParameterKey::GoldThreshold => storage.get(key).unwrap_or(DEFAULT_GOLD_THRESHOLD),
+        ParameterKey::PlatinumThreshold => storage.get(key).unwrap_or(DEFAULT_PLATINUM_THRESHOLD),
+        ParameterKey::MaxBondDuration => {
+            i128::from(storage.get(key).unwrap_or(DEFAULT_MAX_BOND_DURATION))
+        }
+    }
+}
+
+/// Writes `value` (already bounds-checked) with the parameter's storage type.
+pub(crate) fn store_value(e: &Env, key: &ParameterKey, value: i128) {
+    let storage = e.storage().instance();
+    match key {
+        ParameterKey::ProtocolFeeBps | ParameterKey::AttestationFeeBps => {
+            storage.set(key, &(value as u32))
+        }
+        ParameterKey::WithdrawalCooldownSecs
+        | ParameterKey::SlashCooldownSecs
+        | ParameterKey::MaxBondDuration => storage.set(key, &(value as u64)),
+        ParameterKey::BronzeThreshold
+        | ParameterKey::SilverThreshold
+        | ParameterKey::GoldThreshold
+        | ParameterKey::PlatinumThreshold => storage.set(key, &value),
+    }
 }
 
 // ============================================================================
@@ -524,8 +884,9 @@ fn emit_parameter_changed(
     updated_by: &Address,
 ) {
     let timestamp = e.ledger().timestamp();
-    e.events().publish(
-        (Symbol::new(e, "parameter_changed"),),
+    crate::events::publish(
+        e,
+        "parameter_changed",
         (
             String::from_str(e, parameter),
             old_value,
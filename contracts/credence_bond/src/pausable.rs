@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{Address, Env};
 
 use crate::DataKey;
 
@@ -80,10 +80,7 @@ pub fn set_pause_signer(e: &Env, admin: &Address, signer: &Address, enabled: boo
         }
     }
 
-    e.events().publish(
-        (Symbol::new(e, "pause_signer_set"), signer.clone()),
-        enabled,
-    );
+    crate::events::publish_for(e, "pause_signer_set", signer.clone(), enabled);
 }
 
 pub fn set_pause_threshold(e: &Env, admin: &Address, threshold: u32) {
@@ -99,8 +96,22 @@ pub fn set_pause_threshold(e: &Env, admin: &Address, threshold: u32) {
     e.storage()
         .instance()
         .set(&DataKey::PauseThreshold, &threshold);
-    e.events()
-        .publish((Symbol::new(e, "pause_threshold_set"),), threshold);
+    crate::events::publish(e, "pause_threshold_set", threshold);
+}
+
+/// Approvals a pause proposal needs (0 means the admin acts alone).
+pub fn get_pause_threshold(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::PauseThreshold)
+        .unwrap_or(0)
+}
+
+pub fn is_pause_signer(e: &Env, signer: &Address) -> bool {
+    e.storage()
+        .instance()
+        .get(&DataKey::PauseSigner(signer.clone()))
+        .unwrap_or(false)
 }
 
 fn require_pause_signer(e: &Env, signer: &Address) {
@@ -188,8 +199,7 @@ fn propose_action(e: &Env, caller: &Address, action: PauseAction) -> Option<u64>
 
     record_approval(e, id, caller);
 
-    e.events()
-        .publish((Symbol::new(e, "pause_proposed"), id), action as u32);
+    crate::events::publish_for(e, "pause_proposed", id, action as u32);
 
     Some(id)
 }
@@ -205,10 +215,7 @@ pub fn approve_pause_proposal(e: &Env, signer: &Address, proposal_id: u64) {
 
     record_approval(e, proposal_id, signer);
 
-    e.events().publish(
-        (Symbol::new(e, "pause_approved"), proposal_id),
-        signer.clone(),
-    );
+    crate::events::publish_for(e, "pause_approved", proposal_id, signer.clone());
 }
 
 pub fn execute_pause_proposal(e: &Env, proposal_id: u64) {
@@ -246,11 +253,10 @@ pub fn execute_pause_proposal(e: &Env, proposal_id: u64) {
 
 fn do_pause(e: &Env, proposal_id: Option<u64>) {
     e.storage().instance().set(&DataKey::Paused, &true);
-    e.events().publish((Symbol::new(e, "paused"),), proposal_id);
+    crate::events::publish(e, "paused", proposal_id);
 }
 
 fn do_unpause(e: &Env, proposal_id: Option<u64>) {
     e.storage().instance().set(&DataKey::Paused, &false);
-    e.events()
-        .publish((Symbol::new(e, "unpaused"),), proposal_id);
+    crate::events::publish(e, "unpaused", proposal_id);
 }
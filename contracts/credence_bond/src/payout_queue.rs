@@ -0,0 +1,144 @@
+//! Withdrawal Payout Queue
+//!
+//! Opt-in fallback for when the contract's token balance is temporarily short of what the bond
+//! records say is owed (e.g. slashed funds forwarded to treasury ahead of accounting, or a
+//! callback consuming balance). With queue mode on, a withdrawal that cannot be paid is recorded
+//! as a `PendingPayout` instead of failing; bond state is still updated immediately, so the
+//! liability is tracked. Anyone can later call `process_payout_queue` to pay the queue in FIFO
+//! order once the balance is replenished.
+//!
+//! While the queue is non-empty, new payouts are queued behind it to preserve ordering.
+
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::token_integration;
+
+const KEY_QUEUE_ENABLED: &str = "payout_q_on";
+
+/// A withdrawal owed to `identity` that could not be paid when requested.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingPayout {
+    pub identity: Address,
+    pub amount: i128,
+    pub queued_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum PayoutKey {
+    /// Index of the oldest unpaid entry.
+    Head,
+    /// Index the next queued entry will use.
+    Tail,
+    Entry(u64),
+}
+
+/// @notice Returns true if queue mode is enabled.
+#[must_use]
+pub fn is_enabled(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_QUEUE_ENABLED))
+        .unwrap_or(false)
+}
+
+/// @notice Enables or disables queue mode (admin only; caller must enforce).
+pub fn set_enabled(e: &Env, enabled: bool) {
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_QUEUE_ENABLED), &enabled);
+}
+
+fn head(e: &Env) -> u64 {
+    e.storage().instance().get(&PayoutKey::Head).unwrap_or(0)
+}
+
+fn tail(e: &Env) -> u64 {
+    e.storage().instance().get(&PayoutKey::Tail).unwrap_or(0)
+}
+
+/// @notice Number of payouts waiting in the queue.
+#[must_use]
+pub fn len(e: &Env) -> u64 {
+    tail(e).saturating_sub(head(e))
+}
+
+/// @notice Returns the pending payout at `position` (0 = next to be paid), if any.
+#[must_use]
+pub fn get(e: &Env, position: u64) -> Option<PendingPayout> {
+    let index = head(e).checked_add(position)?;
+    if index >= tail(e) {
+        return None;
+    }
+    e.storage().instance().get(&PayoutKey::Entry(index))
+}
+
+/// @notice Pays `amount` to `recipient`, or queues it when queue mode is on and the contract
+/// balance cannot cover it (or earlier payouts are still waiting).
+/// @return true if paid immediately, false if queued.
+pub fn pay_or_queue(e: &Env, recipient: &Address, amount: i128) -> bool {
+    if amount <= 0 {
+        return true;
+    }
+    if !is_enabled(e) {
+        token_integration::transfer_from_contract(e, recipient, amount);
+        return true;
+    }
+    if len(e) == 0 && token_integration::contract_balance(e) >= amount {
+        token_integration::transfer_from_contract(e, recipient, amount);
+        return true;
+    }
+
+    let index = tail(e);
+    let payout = PendingPayout {
+        identity: recipient.clone(),
+        amount,
+        queued_at: e.ledger().timestamp(),
+    };
+    e.storage()
+        .instance()
+        .set(&PayoutKey::Entry(index), &payout);
+    e.storage().instance().set(
+        &PayoutKey::Tail,
+        &index.checked_add(1).expect("payout queue overflow"),
+    );
+    crate::events::publish_for(e, "payout_queued", recipient.clone(), (index, amount));
+    false
+}
+
+/// @notice Pays up to `limit` queued payouts in FIFO order, stopping at the first one the
+/// contract balance cannot cover. Returns the number paid.
+pub fn process(e: &Env, limit: u32) -> u32 {
+    let mut paid = 0u32;
+    let mut index = head(e);
+    let end = tail(e);
+    let mut balance = token_integration::contract_balance(e);
+
+    while paid < limit && index < end {
+        let key = PayoutKey::Entry(index);
+        let payout: PendingPayout = e
+            .storage()
+            .instance()
+            .get(&key)
+            .unwrap_or_else(|| panic!("payout entry missing"));
+        if payout.amount > balance {
+            break;
+        }
+        // Dequeue before the transfer (checks-effects-interactions).
+        e.storage().instance().remove(&key);
+        index += 1;
+        e.storage().instance().set(&PayoutKey::Head, &index);
+        balance -= payout.amount;
+
+        token_integration::transfer_from_contract(e, &payout.identity, payout.amount);
+        crate::events::publish_for(
+            e,
+            "payout_processed",
+            payout.identity,
+            (index - 1, payout.amount, payout.queued_at),
+        );
+        paid += 1;
+    }
+    paid
+}
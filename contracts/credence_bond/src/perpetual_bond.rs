@@ -0,0 +1,53 @@
+//! Perpetual Bond Type
+//!
+//! Perpetual bonds have no lock-up and never mature: `bond_duration` is stored as `u64::MAX`, so
+//! every `bond_start + bond_duration` check saturates to "not yet". The only way out is
+//! `request_withdrawal` followed by the notice period, which must be at least
+//! `MIN_NOTICE_PERIOD`. There is no early exit and no renewal; tiers and slashing behave exactly
+//! as for fixed and rolling bonds.
+
+use crate::{BondKind, IdentityBond};
+
+/// Stored `bond_duration` for perpetual bonds (never matures).
+pub const PERPETUAL_DURATION: u64 = u64::MAX;
+
+/// Minimum notice period for perpetual bonds in seconds (30 days).
+pub const MIN_NOTICE_PERIOD: u64 = 30 * 24 * 60 * 60;
+
+#[must_use]
+pub fn is_perpetual(bond: &IdentityBond) -> bool {
+    bond.kind == BondKind::Perpetual
+}
+
+/// @notice Validates the notice period of a new perpetual bond.
+///
+/// # Panics
+/// - "perpetual notice period below minimum" if `notice_period < MIN_NOTICE_PERIOD`.
+pub fn validate_notice_period(notice_period: u64) {
+    if notice_period < MIN_NOTICE_PERIOD {
+        panic!("perpetual notice period below minimum");
+    }
+}
+
+/// @notice True unless `bond` is perpetual and its notice has not been served yet.
+#[must_use]
+pub fn notice_served(now: u64, bond: &IdentityBond) -> bool {
+    !is_perpetual(bond)
+        || crate::rolling_bond::can_withdraw_after_notice(
+            now,
+            bond.withdrawal_requested_at,
+            bond.notice_period,
+        )
+}
+
+/// @notice Rejects withdrawals from a perpetual bond until notice has been served.
+/// No-op for other bond kinds.
+///
+/// # Panics
+/// - "perpetual bond: notice not served" if withdrawal was not requested or the notice period
+///   has not elapsed.
+pub fn require_notice_served(now: u64, bond: &IdentityBond) {
+    if !notice_served(now, bond) {
+        panic!("perpetual bond: notice not served");
+    }
+}
@@ -0,0 +1,46 @@
+//! Reason Codes
+//!
+//! Reason symbols key revocation stats, penalty multipliers and the emergency audit trail, and
+//! analytics group on them, so entrypoints reject reasons that could never match: the empty
+//! symbol, and symbols longer than `MAX_REASON_LEN` characters. The latter cannot be built with
+//! `symbol_short!`, which is how clients and configured multipliers spell reason codes.
+//!
+//! Only new input is checked. Records written before these checks (e.g. an emergency record with
+//! an empty reason) still load unchanged.
+
+use soroban_sdk::{Env, Symbol};
+
+/// Longest reason code accepted (the Soroban short-symbol limit).
+#[allow(dead_code)]
+pub const MAX_REASON_LEN: u32 = 9;
+
+/// @notice Returns true if `symbol` is the empty symbol.
+#[must_use]
+pub fn is_empty(e: &Env, symbol: &Symbol) -> bool {
+    *symbol == Symbol::new(e, "")
+}
+
+/// @notice Validates a caller-supplied reason code.
+///
+/// # Panics
+/// - "reason must not be empty"
+/// - "reason too long" if it exceeds `MAX_REASON_LEN` characters.
+pub fn require_reason(e: &Env, reason: &Symbol) {
+    if is_empty(e, reason) {
+        panic!("reason must not be empty");
+    }
+    // Short symbols are packed into the value itself; longer ones are host objects.
+    if reason.to_val().is_object() {
+        panic!("reason too long");
+    }
+}
+
+/// @notice Validates a caller-supplied label. Labels are free-form and may be long.
+///
+/// # Panics
+/// - "label must not be empty"
+pub fn require_label(e: &Env, label: &Symbol) {
+    if is_empty(e, label) {
+        panic!("label must not be empty");
+    }
+}
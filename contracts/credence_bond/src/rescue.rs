@@ -0,0 +1,91 @@
+//! Token Rescue
+//!
+//! Recovers tokens sent to the contract address by mistake. Every transfer path only touches the
+//! configured bond token, so any other asset would otherwise be stranded. The bond token
+//! (`set_token`/`set_usdc_token`) and the `increase_bond` token (`set_bond_token`) back bonds
+//! and can never be rescued, not even by the admin.
+//!
+//! Rescues up to the configured limit need only the admin. Larger ones must also be co-signed by
+//! at least the pause threshold of distinct pause signers. The limit defaults to 0, so every
+//! rescue needs the co-signers once a pause threshold is configured.
+
+use soroban_sdk::{contracttype, token::TokenClient, Address, Env, Vec};
+
+use crate::{pausable, DataKey};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum RescueKey {
+    /// Largest amount the admin may rescue without pause-signer co-signatures.
+    Limit,
+}
+
+/// @notice Largest rescue the admin may perform alone (default 0).
+#[must_use]
+pub fn get_limit(e: &Env) -> i128 {
+    e.storage().instance().get(&RescueKey::Limit).unwrap_or(0)
+}
+
+/// @notice Sets the single-signer rescue limit (admin only; caller must enforce).
+///
+/// # Panics
+/// - "rescue limit must be non-negative".
+pub fn set_limit(e: &Env, limit: i128) {
+    if limit < 0 {
+        panic!("rescue limit must be non-negative");
+    }
+    e.storage().instance().set(&RescueKey::Limit, &limit);
+    crate::events::publish(e, "rescue_limit_set", limit);
+}
+
+/// @notice Returns true if `token` backs bonds and therefore cannot be rescued.
+#[must_use]
+pub fn is_protected(e: &Env, token: &Address) -> bool {
+    let bond_token: Option<Address> = e.storage().instance().get(&DataKey::Token);
+    let increase_token: Option<Address> = e.storage().instance().get(&DataKey::BondToken);
+    bond_token.as_ref() == Some(token) || increase_token.as_ref() == Some(token)
+}
+
+/// @notice Transfers `amount` of a foreign `token` held by the contract to `to` (admin auth is
+/// enforced by the caller). `signers` must hold at least the pause threshold of distinct pause
+/// signers when `amount` exceeds the rescue limit; each one must authorize the call.
+///
+/// # Panics
+/// - "amount must be positive"
+/// - "cannot rescue bond token"
+/// - "duplicate signer", "not pause signer"
+/// - "insufficient rescue approvals"
+pub fn rescue(e: &Env, token: &Address, to: &Address, amount: i128, signers: &Vec<Address>) {
+    if amount <= 0 {
+        panic!("amount must be positive");
+    }
+    if is_protected(e, token) {
+        panic!("cannot rescue bond token");
+    }
+    if amount > get_limit(e) {
+        require_cosigners(e, signers);
+    }
+
+    TokenClient::new(e, token).transfer(&e.current_contract_address(), to, &amount);
+    crate::events::publish_for(
+        e,
+        "tokens_rescued",
+        token.clone(),
+        (to.clone(), amount, signers.len()),
+    );
+}
+
+fn require_cosigners(e: &Env, signers: &Vec<Address>) {
+    for (i, signer) in signers.iter().enumerate() {
+        if signers.first_index_of(&signer) != Some(i as u32) {
+            panic!("duplicate signer");
+        }
+        signer.require_auth();
+        if !pausable::is_pause_signer(e, &signer) {
+            panic!("not pause signer");
+        }
+    }
+    if signers.len() < pausable::get_pause_threshold(e) {
+        panic!("insufficient rescue approvals");
+    }
+}
@@ -0,0 +1,56 @@
+//! Minimum Residual Bond
+//!
+//! Keeps attested identities from withdrawing down to dust while others still rely on their
+//! attestations. With a floor configured, a withdrawal from a subject holding at least one
+//! effective (non-revoked) attestation must leave either nothing (a full exit) or at least the
+//! floor of available balance (`bonded_amount - slashed_amount`). Subjects whose attestations
+//! are all revoked are not restricted.
+//!
+//! The check is skipped entirely while the floor is 0 (the default).
+
+use soroban_sdk::{contracttype, Env};
+
+use crate::{valid_attestations, IdentityBond};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum ResidualKey {
+    Floor,
+}
+
+/// @notice Smallest available balance a partial withdrawal may leave (0 = disabled).
+#[must_use]
+pub fn get_floor(e: &Env) -> i128 {
+    e.storage().instance().get(&ResidualKey::Floor).unwrap_or(0)
+}
+
+/// @notice Sets the residual bond floor (admin only; caller must enforce).
+///
+/// # Panics
+/// - "min residual bond must be non-negative"
+pub fn set_floor(e: &Env, amount: i128) {
+    if amount < 0 {
+        panic!("min residual bond must be non-negative");
+    }
+    e.storage().instance().set(&ResidualKey::Floor, &amount);
+    crate::events::publish(e, "min_residual_bond_set", amount);
+}
+
+/// @notice Enforces the floor for withdrawing `amount` from `bond`, if configured.
+///
+/// # Panics
+/// - "withdrawal below residual bond floor"
+pub fn require_floor(e: &Env, bond: &IdentityBond, amount: i128) {
+    let floor = get_floor(e);
+    if floor == 0 {
+        return;
+    }
+    let remaining = bond.bonded_amount - bond.slashed_amount - amount;
+    if remaining <= 0 || remaining >= floor {
+        return;
+    }
+    if !valid_attestations::has_effective(e, &bond.identity) {
+        return;
+    }
+    panic!("withdrawal below residual bond floor");
+}
@@ -0,0 +1,35 @@
+//! Effective Restrictions
+//!
+//! One read-only view of every operational restriction currently in force on the contract, so
+//! operators do not have to query each mechanism separately.
+//!
+//! This tree has a single global pause (no per-scope pauses or pauser/timestamp record), no
+//! configuration freeze, no link to the admin contract's lockdown state and no withdrawal rate
+//! limit beyond the cooldown window. The view covers what exists; further restrictions belong
+//! here when they are added.
+
+use soroban_sdk::{contracttype, Env};
+
+use crate::{cooldown, emergency, pausable};
+
+/// @notice Combined view of the restrictions currently in force.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EffectiveRestrictions {
+    /// State-changing entrypoints are blocked by the global pause.
+    pub paused: bool,
+    /// Emergency mode is enabled (false when emergency config was never set).
+    pub emergency_mode: bool,
+    /// Delay between a withdrawal request and its execution; 0 when disabled.
+    pub withdrawal_cooldown_secs: u64,
+}
+
+/// @notice Reads the current restrictions. Performs no writes and no cross-contract calls.
+#[must_use]
+pub fn current(e: &Env) -> EffectiveRestrictions {
+    EffectiveRestrictions {
+        paused: pausable::is_paused(e),
+        emergency_mode: emergency::is_enabled(e),
+        withdrawal_cooldown_secs: cooldown::get_cooldown_period(e),
+    }
+}
@@ -0,0 +1,47 @@
+//! Cross-contract Role Registry
+//!
+//! Optional delegation of entrypoint authorization to the admin contract. When a registry is
+//! configured, admin-gated configuration entrypoints also accept any caller for which the
+//! registry's `can_call(caller, this_contract, fn_name)` returns true, so the mapping of
+//! entrypoints to roles lives in one place. The stored bond admin is always allowed.
+
+use soroban_sdk::{vec, Address, Env, IntoVal, Symbol};
+
+const KEY_ROLE_REGISTRY: &str = "role_registry";
+
+/// @notice Returns the configured admin-contract registry, if cross-contract auth is enabled.
+#[must_use]
+pub fn get_registry(e: &Env) -> Option<Address> {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_ROLE_REGISTRY))
+}
+
+/// @notice Enables cross-contract auth against `registry` (admin only; caller must enforce).
+pub fn set_registry(e: &Env, registry: &Address) {
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_ROLE_REGISTRY), registry);
+    crate::events::publish(e, "role_registry_set", registry.clone());
+}
+
+/// @notice Disables cross-contract auth (admin only; caller must enforce).
+pub fn clear_registry(e: &Env) {
+    e.storage()
+        .instance()
+        .remove(&Symbol::new(e, KEY_ROLE_REGISTRY));
+    crate::events::publish(e, "role_registry_cleared", ());
+}
+
+/// @notice Asks the registry whether `caller` may call `fn_name` on this contract.
+#[must_use]
+pub fn can_call(e: &Env, registry: &Address, caller: &Address, fn_name: &str) -> bool {
+    crate::failpoints::hit(e, crate::failpoints::ADMIN_CONTRACT_LOOKUP);
+    let args = vec![
+        e,
+        caller.into_val(e),
+        e.current_contract_address().into_val(e),
+        Symbol::new(e, fn_name).into_val(e),
+    ];
+    e.invoke_contract::<bool>(registry, &Symbol::new(e, "can_call"), args)
+}
@@ -2,14 +2,190 @@
 //!
 //! Auto-renews at period end unless withdrawal was requested with notice.
 //! Tracks withdrawal request and notice period for scoring.
+//!
+//! All period arithmetic saturates, so near-max timestamps and durations never wrap and a period
+//! never ends before it starts. Rolling bonds must have a non-zero duration, and a renewal whose
+//! new period end would not fit in a u64 is refused.
+//!
+//! Renewals are counted per identity. With an admin-set `max_renewals` cap, a bond whose count
+//! has reached the cap lapses at its next period end instead of renewing: it becomes a fixed-term
+//! bond whose lock-up has ended. 0, the default, allows unlimited renewals.
+//!
+//! The holder can queue a different duration for the next period. It takes effect at the next
+//! renewal and then stays, like the duration set at creation. The timing views
+//! (`effective_end`, `current_period`, `renewal_schedule`) project exactly what
+//! `renew_if_rolling` would do, queued duration and renewal cap included.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
 
 use crate::IdentityBond;
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum RollingKey {
+    /// Identity -> renewals of its current bond
+    RenewalCount(Address),
+    /// Renewals allowed per bond (absent means 0, unlimited)
+    MaxRenewals,
+    /// Identity -> duration its bond takes at the next renewal
+    NextDuration(Address),
+}
+
+/// Most period ends one `renewal_schedule` query returns.
+pub const MAX_SCHEDULE_LEN: u32 = 52;
+
+/// Renewals of `identity`'s current bond.
+#[must_use]
+pub fn get_renewal_count(e: &Env, identity: &Address) -> u32 {
+    e.storage()
+        .instance()
+        .get(&RollingKey::RenewalCount(identity.clone()))
+        .unwrap_or(0)
+}
+
+/// Forgets the renewal count and any queued duration, for a bond that replaces an earlier one.
+pub fn reset_renewal_count(e: &Env, identity: &Address) {
+    e.storage()
+        .instance()
+        .remove(&RollingKey::RenewalCount(identity.clone()));
+    e.storage()
+        .instance()
+        .remove(&RollingKey::NextDuration(identity.clone()));
+}
+
+/// Duration queued for `identity`'s next period, if any.
+#[must_use]
+pub fn get_next_duration(e: &Env, identity: &Address) -> Option<u64> {
+    e.storage()
+        .instance()
+        .get(&RollingKey::NextDuration(identity.clone()))
+}
+
+/// Queues `duration` for `identity`'s next period (owner auth and duration bounds enforced by
+/// the caller), replacing any earlier one. Emits `next_duration_set` (topic: identity) with
+/// `duration`.
+pub fn set_next_duration(e: &Env, identity: &Address, duration: u64) {
+    validate_duration(duration);
+    e.storage()
+        .instance()
+        .set(&RollingKey::NextDuration(identity.clone()), &duration);
+    crate::events::publish_for(e, "next_duration_set", identity.clone(), duration);
+}
+
+/// Duration `bond` takes at its next renewal: the queued one, else its current one.
+#[must_use]
+pub fn renewal_duration(e: &Env, bond: &IdentityBond) -> u64 {
+    get_next_duration(e, &bond.identity).unwrap_or(bond.bond_duration)
+}
+
+/// Renewals allowed per bond (0 means unlimited).
+#[must_use]
+pub fn get_max_renewals(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&RollingKey::MaxRenewals)
+        .unwrap_or(0)
+}
+
+/// Sets the renewal cap (admin only; caller must enforce). Applies to existing bonds at their
+/// next period end.
+pub fn set_max_renewals(e: &Env, max_renewals: u32) {
+    e.storage()
+        .instance()
+        .set(&RollingKey::MaxRenewals, &max_renewals);
+    crate::events::publish(e, "max_renewals_set", max_renewals);
+}
+
+/// Returns true if `bond` used up its renewals and must lapse instead of renewing.
+#[must_use]
+pub fn renewals_exhausted(e: &Env, bond: &IdentityBond) -> bool {
+    let max = get_max_renewals(e);
+    max > 0 && get_renewal_count(e, &bond.identity) >= max
+}
+
+/// Turns a rolling bond whose renewals are exhausted into a fixed-term bond ending at its current
+/// period end, dropping any pending withdrawal request.
+pub fn apply_lapse(bond: &mut IdentityBond) {
+    bond.is_rolling = false;
+    bond.withdrawal_requested_at = 0;
+}
+
+/// Returns the period end (`bond_start + bond_duration`), saturating at u64::MAX.
+#[must_use]
+pub fn period_end(bond_start: u64, bond_duration: u64) -> u64 {
+    bond_start.saturating_add(bond_duration)
+}
+
 /// Returns true if the bond has passed its period end (bond_start + bond_duration).
 #[must_use]
 pub fn is_period_ended(now: u64, bond_start: u64, bond_duration: u64) -> bool {
-    let end = bond_start.saturating_add(bond_duration);
-    now >= end
+    now >= period_end(bond_start, bond_duration)
+}
+
+/// Returns true if `renew_if_rolling` at `now` would start a new period for `bond`.
+#[must_use]
+pub fn renewal_due(e: &Env, now: u64, bond: &IdentityBond) -> bool {
+    bond.is_rolling
+        && is_period_ended(now, bond.bond_start, bond.bond_duration)
+        && !renewals_exhausted(e, bond)
+        && now.checked_add(renewal_duration(e, bond)).is_some()
+}
+
+/// Returns `bond`'s current period as of `now`: (start, end, renewals so far). A renewal that is
+/// due is reported as if `renew_if_rolling` ran at `now`; a bond whose renewals are exhausted
+/// keeps its stored period, at whose end it lapses.
+#[must_use]
+pub fn current_period(e: &Env, now: u64, bond: &IdentityBond) -> (u64, u64, u32) {
+    let renewals = get_renewal_count(e, &bond.identity);
+    if renewal_due(e, now, bond) {
+        let end = period_end(now, renewal_duration(e, bond));
+        return (now, end, renewals.saturating_add(1));
+    }
+    (
+        bond.bond_start,
+        period_end(bond.bond_start, bond.bond_duration),
+        renewals,
+    )
+}
+
+/// Returns when `bond`'s current lock-up ends as of `now` (see `current_period`).
+#[must_use]
+pub fn effective_end(e: &Env, now: u64, bond: &IdentityBond) -> u64 {
+    current_period(e, now, bond).1
+}
+
+/// Returns the next `count` period ends of `bond` as of `now`, starting with the current one
+/// and assuming each renewal happens exactly at its period end. The list stops early at the
+/// period the bond lapses at (renewal cap), at a renewal that would be refused, and after the
+/// only period of a fixed-term or perpetual bond.
+///
+/// # Panics
+/// - "schedule too long" if `count > MAX_SCHEDULE_LEN`.
+#[must_use]
+pub fn renewal_schedule(e: &Env, now: u64, bond: &IdentityBond, count: u32) -> Vec<u64> {
+    if count > MAX_SCHEDULE_LEN {
+        panic!("schedule too long");
+    }
+    let mut ends = Vec::new(e);
+    if count == 0 {
+        return ends;
+    }
+    let (_, mut end, mut renewals) = current_period(e, now, bond);
+    ends.push_back(end);
+    if !bond.is_rolling {
+        return ends;
+    }
+    let max = get_max_renewals(e);
+    let duration = renewal_duration(e, bond);
+    while ends.len() < count && (max == 0 || renewals < max) {
+        match end.checked_add(duration) {
+            Some(next) => end = next,
+            None => break,
+        }
+        renewals = renewals.saturating_add(1);
+        ends.push_back(end);
+    }
+    ends
 }
 
 /// Returns true if a withdrawal was requested and the notice period has elapsed.
@@ -26,9 +202,60 @@ pub fn can_withdraw_after_notice(
     now >= notice_end
 }
 
-/// Advance bond to a new period (set bond_start to now, keep duration and rolling flag).
-/// Call when period has ended and bond is rolling.
-pub fn apply_renewal(bond: &mut IdentityBond, new_start: u64) {
+/// Rejects any withdrawal from a rolling bond until a withdrawal was requested and its notice
+/// period has elapsed. Non-rolling bonds pass.
+///
+/// # Panics
+/// - "notice period not elapsed" if `withdrawal_requested_at + notice_period_duration > now` or
+///   no withdrawal was requested.
+pub fn require_notice_elapsed(now: u64, bond: &IdentityBond) {
+    if bond.is_rolling
+        && !can_withdraw_after_notice(now, bond.withdrawal_requested_at, bond.notice_period)
+    {
+        panic!("notice period not elapsed");
+    }
+}
+
+/// Clears the withdrawal request once nothing is left to withdraw, so a later top-up starts a
+/// fresh notice.
+pub fn clear_request_if_drained(bond: &mut IdentityBond) {
+    if bond.bonded_amount <= bond.slashed_amount {
+        bond.withdrawal_requested_at = 0;
+    }
+}
+
+/// Rejects rolling bonds whose periods would have zero length (and renew on every call).
+///
+/// # Panics
+/// - "rolling bond duration must be positive" if `bond_duration == 0`.
+pub fn validate_duration(bond_duration: u64) {
+    if bond_duration == 0 {
+        panic!("rolling bond duration must be positive");
+    }
+}
+
+/// Advance bond to a new period (set bond_start to now, apply any queued duration, keep the
+/// rolling flag) and count the renewal. Call when period has ended and bond is rolling.
+///
+/// Returns false, leaving the bond, count and queued duration unchanged, if the new period end
+/// would overflow u64.
+#[must_use]
+pub fn apply_renewal(e: &Env, bond: &mut IdentityBond, new_start: u64) -> bool {
+    let duration = renewal_duration(e, bond);
+    if new_start.checked_add(duration).is_none() {
+        return false;
+    }
+    // Accrual restarts at the new `bond_start`; bank what the ended period earned.
+    crate::bond_rewards::settle(e, &bond.identity);
+    e.storage()
+        .instance()
+        .remove(&RollingKey::NextDuration(bond.identity.clone()));
     bond.bond_start = new_start;
+    bond.bond_duration = duration;
     bond.withdrawal_requested_at = 0; // reset withdrawal request on renewal
+    let count = get_renewal_count(e, &bond.identity).saturating_add(1);
+    e.storage()
+        .instance()
+        .set(&RollingKey::RenewalCount(bond.identity.clone()), &count);
+    true
 }
@@ -60,7 +60,7 @@ fn test_i128_overflow_on_top_up() {
     client.create_bond(&identity, &(i128::MAX - 1000), &86400_u64, &false, &0_u64);
 
     // FIX: Passes value instead of reference
-    client.top_up(&2000);
+    client.top_up(&identity, &identity, &2000);
 }
 
 #[test]
@@ -80,7 +80,7 @@ fn test_i128_overflow_on_max_top_up() {
     client.create_bond(&identity, &i128::MAX, &86400_u64, &false, &0_u64);
 
     // Attempt to top up by 1, which should overflow
-    client.top_up(&1);
+    client.top_up(&identity, &identity, &1);
 }
 
 #[test]
@@ -101,15 +101,15 @@ fn test_i128_overflow_on_massive_slashing() {
     client.create_bond(&identity, &(i128::MAX / 2), &86400_u64, &false, &0_u64);
 
     // Slash near-maximum amount first
-    client.slash(&admin, &(i128::MAX / 2));
-    client.slash(&admin, &(i128::MAX / 2));
+    client.slash(&admin, &identity, &(i128::MAX / 2));
+    client.slash(&admin, &identity, &(i128::MAX / 2));
 
-    client.slash(&admin, &(i128::MAX / 2));
+    client.slash(&admin, &identity, &(i128::MAX / 2));
 
     // Current slashed_amount is now i128::MAX / 2
     // Attempt to slash more than i128::MAX / 2, which will cause overflow in checked_add
-    client.slash(&admin, &(i128::MAX / 2 + 2));
-    client.slash(&admin, &(i128::MAX / 2 + 2));
+    client.slash(&admin, &identity, &(i128::MAX / 2 + 2));
+    client.slash(&admin, &identity, &(i128::MAX / 2 + 2));
 }
 
 #[test]
@@ -131,7 +131,7 @@ fn test_i128_large_bond_operations() {
     assert_eq!(bond.bonded_amount, large_amount);
 
     // Top up with another large amount (should succeed as sum < i128::MAX)
-    let bond = client.top_up(&(large_amount / 2));
+    let bond = client.top_up(&identity, &identity, &(large_amount / 2));
     assert_eq!(bond.bonded_amount, large_amount + (large_amount / 2));
 }
 
@@ -145,10 +145,10 @@ fn test_negative_bond_amount_handling() {
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
-    client.slash(&(i128::MAX / 2));
+    client.slash(&admin, &identity, &(i128::MAX / 2));
 
     // Attempt to slash more, causing overflow in the slashed_amount tracker
-    client.slash(&(i128::MAX / 2 + 2));
+    client.slash(&admin, &identity, &(i128::MAX / 2 + 2));
     // Test with negative amount (technically allowed by i128, but may be business logic violation)
     // This documents current behavior
     let bond = client.create_bond(&identity, &(-1000), &86400_u64, &false, &0_u64);
@@ -194,7 +194,7 @@ fn test_u64_overflow_on_duration_extension() {
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Attempt to extend by u64::MAX, which should overflow
-    client.extend_duration(&u64::MAX);
+    client.extend_duration(&identity, &u64::MAX);
 }
 
 #[test]
@@ -242,7 +242,7 @@ fn test_u64_large_duration_extension() {
     assert_eq!(bond.bond_duration, duration);
 
     // Extend with another duration (should succeed as sum doesn't overflow)
-    let bond = client.extend_duration(&86400_u64);
+    let bond = client.extend_duration(&identity, &86400_u64);
     assert_eq!(bond.bond_duration, duration + 86400);
 }
 
@@ -291,7 +291,7 @@ fn test_withdrawal_exceeds_available_balance() {
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Attempt to withdraw more than available
-    client.withdraw(&1001);
+    client.withdraw(&identity, &1001);
 }
 
 #[test]
@@ -310,11 +310,11 @@ fn test_withdrawal_after_slashing() {
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Slash 400
-    client.slash(&admin, &400);
-    client.slash(&admin, &400);
+    client.slash(&admin, &identity, &400);
+    client.slash(&admin, &identity, &400);
 
     // Available balance is now 600, attempt to withdraw 601
-    client.withdraw(&601);
+    client.withdraw(&identity, &601);
 }
 
 #[test]
@@ -332,7 +332,7 @@ fn test_withdrawal_exact_available_balance() {
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Withdraw exact available amount
-    let bond = client.withdraw(&1000);
+    let bond = client.withdraw(&identity, &1000);
     assert_eq!(bond.bonded_amount, 0);
 }
 
@@ -351,7 +351,7 @@ fn test_withdrawal_zero_amount() {
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Withdraw zero amount (should succeed)
-    let bond = client.withdraw(&0);
+    let bond = client.withdraw(&identity, &0);
     assert_eq!(bond.bonded_amount, 1000);
 }
 
@@ -371,10 +371,10 @@ fn test_multiple_withdrawals_causing_underflow() {
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Multiple withdrawals
-    client.withdraw(&400);
-    client.withdraw(&400);
+    client.withdraw(&identity, &400);
+    client.withdraw(&identity, &400);
     // Available balance is now 200, this should fail
-    client.withdraw(&300);
+    client.withdraw(&identity, &300);
 }
 
 #[test]
@@ -392,7 +392,7 @@ fn test_withdrawal_with_max_i128_bond() {
     client.create_bond(&identity, &i128::MAX, &86400_u64, &false, &0_u64);
 
     // Withdraw large amount
-    let bond = client.withdraw(&(i128::MAX / 2));
+    let bond = client.withdraw(&identity, &(i128::MAX / 2));
     assert_eq!(bond.bonded_amount, i128::MAX - (i128::MAX / 2));
 }
 
@@ -412,11 +412,11 @@ fn test_withdrawal_when_fully_slashed() {
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Slash entire amount
-    client.slash(&admin, &1000);
-    client.slash(&admin, &1000);
+    client.slash(&admin, &identity, &1000);
+    client.slash(&admin, &identity, &1000);
 
     // Attempt to withdraw when fully slashed (available = 0)
-    client.withdraw(&1);
+    client.withdraw(&identity, &1);
 }
 
 // ============================================================================
@@ -438,7 +438,7 @@ fn test_slashing_normal_amount() {
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Slash normal amount
-    let bond = client.slash(&admin, &300);
+    let bond = client.slash(&admin, &identity, &300);
     assert_eq!(bond.slashed_amount, 300);
     assert_eq!(bond.bonded_amount, 1000);
 }
@@ -459,8 +459,8 @@ fn test_slashing_exceeds_bonded_amount() {
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Slash more than bonded amount (should cap at bonded amount)
-    let bond = client.slash(&admin, &2000);
-    let bond = client.slash(&admin, &2000);
+    let bond = client.slash(&admin, &identity, &2000);
+    let bond = client.slash(&admin, &identity, &2000);
     assert_eq!(bond.slashed_amount, 1000); // Capped at bonded_amount
     assert_eq!(bond.bonded_amount, 1000);
 }
@@ -480,13 +480,13 @@ fn test_multiple_slashing_operations() {
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Multiple slashing operations
-    let bond = client.slash(&admin, &200);
+    let bond = client.slash(&admin, &identity, &200);
     assert_eq!(bond.slashed_amount, 200);
 
-    let bond = client.slash(&admin, &300);
+    let bond = client.slash(&admin, &identity, &300);
     assert_eq!(bond.slashed_amount, 500);
 
-    let bond = client.slash(&admin, &100);
+    let bond = client.slash(&admin, &identity, &100);
     assert_eq!(bond.slashed_amount, 600);
 }
 
@@ -505,8 +505,8 @@ fn test_slashing_zero_amount() {
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Slash zero amount
-    let bond = client.slash(&admin, &0);
-    let bond = client.slash(&admin, &0);
+    let bond = client.slash(&admin, &identity, &0);
+    let bond = client.slash(&admin, &identity, &0);
     assert_eq!(bond.slashed_amount, 0);
 }
 
@@ -525,10 +525,10 @@ fn test_slashing_after_withdrawal() {
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Withdraw first
-    client.withdraw(&300);
+    client.withdraw(&identity, &300);
 
     // Then slash (should still reference original bonded amount)
-    let bond = client.slash(&admin, &400);
+    let bond = client.slash(&admin, &identity, &400);
     assert_eq!(bond.slashed_amount, 400);
     assert_eq!(bond.bonded_amount, 700); // After withdrawal
 }
@@ -548,7 +548,7 @@ fn test_slashing_with_max_values() {
     client.create_bond(&identity, &i128::MAX, &86400_u64, &false, &0_u64);
 
     // Slash large amount
-    let bond = client.slash(&admin, &(i128::MAX / 2));
+    let bond = client.slash(&admin, &identity, &(i128::MAX / 2));
     assert_eq!(bond.slashed_amount, i128::MAX / 2);
 }
 
@@ -572,15 +572,15 @@ fn test_complex_arithmetic_scenario() {
     client.create_bond(&identity, &10000, &86400_u64, &false, &0_u64);
 
     // Top up
-    let bond = client.top_up(&5000);
+    let bond = client.top_up(&identity, &identity, &5000);
     assert_eq!(bond.bonded_amount, 15000);
 
     // Slash some
-    let bond = client.slash(&admin, &3000);
+    let bond = client.slash(&admin, &identity, &3000);
     assert_eq!(bond.slashed_amount, 3000);
 
     // Withdraw available (15000 - 3000 = 12000 available)
-    let bond = client.withdraw(&8000);
+    let bond = client.withdraw(&identity, &8000);
     assert_eq!(bond.bonded_amount, 7000);
 
     // Verify final state
@@ -604,12 +604,12 @@ fn test_withdrawal_leaves_insufficient_for_slashed() {
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Slash 500
-    client.slash(&admin, &500);
-    client.slash(&admin, &500);
+    client.slash(&admin, &identity, &500);
+    client.slash(&admin, &identity, &500);
 
     // Try to withdraw 600 (but only 500 is available after slashing)
     // This should panic with "insufficient balance for withdrawal"
-    client.withdraw(&600);
+    client.withdraw(&identity, &600);
 }
 
 #[test]
@@ -629,11 +629,11 @@ fn test_boundary_arithmetic_with_zero_values() {
     assert_eq!(bond.bonded_amount, 0);
 
     // Try operations on zero bond
-    let bond = client.slash(&admin, &0);
-    let bond = client.slash(&admin, &0);
+    let bond = client.slash(&admin, &identity, &0);
+    let bond = client.slash(&admin, &identity, &0);
     assert_eq!(bond.slashed_amount, 0);
 
     // If your slash function caps at bonded_amount:
-    let bond = client.slash(&2000);
+    let bond = client.slash(&admin, &identity, &2000);
     assert_eq!(bond.slashed_amount, 1000);
 }
@@ -0,0 +1,175 @@
+//! Test State Seeding
+//!
+//! Declarative fixture loader for integration tests: writes a bond, attestations, counters and
+//! governance state directly to storage so complex scenarios ("bond 60% through its duration,
+//! one executed slash, two attestations, one open proposal") do not have to be replayed call by
+//! call. Compiled only for unit tests or with the `testing-hooks` feature, never into release wasm.
+//!
+//! Seeding writes the same storage the organic entrypoints write (including secondary indexes
+//! such as the per-subject attestation list and count, dedup keys, verifier reputation and id
+//! counters), so every public view reads a seeded state exactly like an organic one. Token
+//! balances are not touched; fund the contract separately if a scenario moves tokens.
+
+use soroban_sdk::{contracttype, Address, Env, String, Symbol, Vec};
+
+use crate::governance_approval::{ProposalStatus, SlashProposal};
+use crate::types::Attestation;
+use crate::{subject_seq, verifier, DataKey, IdentityBond};
+
+/// Attestation to seed. Ids are assigned from the attestation counter in order.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeedAttestation {
+    pub verifier: Address,
+    pub identity: Address,
+    pub timestamp: u64,
+    pub weight: u32,
+    pub attestation_data: String,
+    pub revoked: bool,
+}
+
+/// Governance vote to seed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeedVote {
+    pub proposal_id: u64,
+    pub voter: Address,
+    pub approve: bool,
+}
+
+/// Governance configuration, proposals and votes to seed.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SeedGovernance {
+    pub governors: Vec<Address>,
+    pub quorum_bps: u32,
+    pub min_governors: u32,
+    /// Stored as given; the next proposal id becomes `max(id) + 1`.
+    pub proposals: Vec<SlashProposal>,
+    pub votes: Vec<SeedVote>,
+}
+
+/// Declarative description of the state to seed.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SeedSpec {
+    /// Bonds to store, keyed by their `identity`.
+    pub bonds: Vec<IdentityBond>,
+    pub attestations: Vec<SeedAttestation>,
+    /// Empty to leave governance uninitialized; normally at most one entry.
+    pub governance: Vec<SeedGovernance>,
+}
+
+/// @notice Writes `spec` to storage. Returns the ids assigned to the seeded attestations.
+pub fn seed_state(e: &Env, spec: SeedSpec) -> Vec<u64> {
+    for bond in spec.bonds.iter() {
+        e.storage()
+            .instance()
+            .set(&DataKey::Bond(bond.identity.clone()), &bond);
+    }
+
+    let mut ids = Vec::new(e);
+    for att in spec.attestations.iter() {
+        ids.push_back(seed_attestation(e, att));
+    }
+
+    for gov in spec.governance.iter() {
+        seed_governance(e, gov);
+    }
+    ids
+}
+
+fn seed_attestation(e: &Env, seed: SeedAttestation) -> u64 {
+    Attestation::validate_weight(seed.weight);
+
+    let id: u64 = e
+        .storage()
+        .instance()
+        .get(&DataKey::AttestationCounter)
+        .unwrap_or(0);
+    let next_id = id.checked_add(1).expect("attestation counter overflow");
+    e.storage()
+        .instance()
+        .set(&DataKey::AttestationCounter, &next_id);
+
+    let subject_seq = subject_seq::assign(e, &seed.identity, id);
+    let attestation = Attestation {
+        id,
+        verifier: seed.verifier.clone(),
+        identity: seed.identity.clone(),
+        timestamp: seed.timestamp,
+        weight: seed.weight,
+        attestation_data: seed.attestation_data.clone(),
+        revoked: seed.revoked,
+        // Seeded revocations have no separate time; treat them as revoked on issue.
+        revoked_at: if seed.revoked { seed.timestamp } else { 0 },
+        subject_seq,
+        use_count: 0,
+        data_hash: None,
+        expires_at: 0,
+        flags: 0,
+        consented: false,
+        revocation_reason: None,
+    };
+    crate::attestation_store::put(e, &attestation);
+
+    let subject_key = DataKey::SubjectAttestations(seed.identity.clone());
+    let mut list: Vec<u64> = e
+        .storage()
+        .instance()
+        .get(&subject_key)
+        .unwrap_or(Vec::new(e));
+    list.push_back(id);
+    e.storage().instance().set(&subject_key, &list);
+
+    verifier::record_attestation_issued(e, &seed.verifier, seed.weight);
+    if seed.revoked {
+        verifier::record_attestation_revoked(
+            e,
+            &seed.verifier,
+            seed.weight,
+            verifier::RevocationInitiator::Attester,
+            Symbol::new(e, "self"),
+        );
+    } else {
+        let dup_key =
+            DataKey::DuplicateCheck(seed.verifier, seed.identity.clone(), seed.attestation_data);
+        e.storage().instance().set(&dup_key, &true);
+
+        let count_key = DataKey::SubjectAttestationCount(seed.identity);
+        let count: u32 = e.storage().instance().get(&count_key).unwrap_or(0);
+        let next = count.checked_add(1).expect("attestation count overflow");
+        e.storage().instance().set(&count_key, &next);
+    }
+    id
+}
+
+fn seed_governance(e: &Env, gov: SeedGovernance) {
+    crate::governance_approval::initialize_governance(
+        e,
+        gov.governors,
+        gov.quorum_bps,
+        gov.min_governors,
+    );
+
+    let mut next_id: u64 = 0;
+    for proposal in gov.proposals.iter() {
+        let after = proposal.id.checked_add(1).expect("proposal id overflow");
+        if after > next_id {
+            next_id = after;
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::GovernanceProposal(proposal.id), &proposal);
+        if proposal.status == ProposalStatus::Open {
+            crate::governance_approval::index_open_request(e, &proposal.identity, proposal.id);
+        }
+    }
+    e.storage()
+        .instance()
+        .set(&DataKey::GovernanceNextProposalId, &next_id);
+
+    for v in gov.votes.iter() {
+        crate::governance_approval::record_vote(e, v.proposal_id, &v.voter, v.approve);
+    }
+}
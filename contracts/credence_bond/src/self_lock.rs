@@ -0,0 +1,96 @@
+//! Bond Self-Lock
+//!
+//! Lets a holder lock their own bond against owner-initiated changes until a chosen time, as
+//! protection against a stolen key while they cannot watch the bond (e.g. while traveling).
+//! While locked, withdrawals and withdrawal requests, transfers, splits, closing, top-up sponsor
+//! grants and reward claims signed by the holder panic with "self-locked". Top-ups, slashing and
+//! admin or governance actions are unaffected.
+//!
+//! A lock can be extended but never shortened or lifted by the holder; that is the point. The
+//! only early release is `release`, behind the admin + governance co-signed emergency path. A
+//! lock expires lazily: the first owner-initiated call at or after `self_locked_until` clears it
+//! and emits `self_lock_expired`.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::DataKey;
+
+/// Longest self-lock, measured from the time it is set (90 days).
+pub const MAX_SELF_LOCK_SECS: u64 = 90 * 86_400;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum SelfLockKey {
+    /// `self_locked_until` of the identity's bond.
+    Until(Address),
+}
+
+/// @notice Time until which `identity`'s bond is self-locked (0 if it is not). A lock that has
+/// passed but not yet been cleared by an owner call still reads as its old value.
+#[must_use]
+pub fn get_until(e: &Env, identity: &Address) -> u64 {
+    e.storage()
+        .instance()
+        .get(&SelfLockKey::Until(identity.clone()))
+        .unwrap_or(0)
+}
+
+/// @notice Locks `identity`'s bond until `until` (owner auth enforced by the caller). Emits
+/// `self_lock_set` (topic: identity) with `until`.
+///
+/// # Panics
+/// - "no bond"
+/// - "self-lock must end in the future"
+/// - "self-lock too long" beyond `MAX_SELF_LOCK_SECS` from now
+/// - "cannot shorten self-lock" if an active lock ends later
+pub fn lock(e: &Env, identity: &Address, until: u64) {
+    if !e.storage().instance().has(&DataKey::Bond(identity.clone())) {
+        panic!("no bond");
+    }
+    let now = e.ledger().timestamp();
+    if until <= now {
+        panic!("self-lock must end in the future");
+    }
+    if until - now > MAX_SELF_LOCK_SECS {
+        panic!("self-lock too long");
+    }
+    if until < get_until(e, identity) {
+        panic!("cannot shorten self-lock");
+    }
+    e.storage()
+        .instance()
+        .set(&SelfLockKey::Until(identity.clone()), &until);
+    crate::events::publish_for(e, "self_lock_set", identity.clone(), until);
+}
+
+/// @notice Panics with "self-locked" while `identity`'s bond is self-locked. A lock that has
+/// passed is cleared here and `self_lock_expired` (topic: identity) is emitted with its end.
+pub fn require_unlocked(e: &Env, identity: &Address) {
+    let until = get_until(e, identity);
+    if until == 0 {
+        return;
+    }
+    if e.ledger().timestamp() < until {
+        panic!("self-locked");
+    }
+    e.storage()
+        .instance()
+        .remove(&SelfLockKey::Until(identity.clone()));
+    crate::events::publish_for(e, "self_lock_expired", identity.clone(), until);
+}
+
+/// @notice Lifts `identity`'s self-lock early (admin and governance co-signatures enforced by
+/// the caller). Emits `self_lock_released` (topic: identity) with the lock's end.
+///
+/// # Panics
+/// - "not self-locked"
+pub fn release(e: &Env, identity: &Address) {
+    let until = get_until(e, identity);
+    if until == 0 {
+        panic!("not self-locked");
+    }
+    e.storage()
+        .instance()
+        .remove(&SelfLockKey::Until(identity.clone()));
+    crate::events::publish_for(e, "self_lock_released", identity.clone(), until);
+}
@@ -0,0 +1,89 @@
+//! Slash Accounting
+//!
+//! Global counters that let auditors check slashing against fund movements:
+//!
+//! - `applied`: slash amounts actually applied to bonds, after capping at the bonded amount.
+//! - `distributed`: slashed funds that have left the contract (treasury, burn or reporter
+//!   transfers).
+//! - `pending`: the difference, i.e. slashed funds still held by the contract.
+//!
+//! Every slash path (admin `slash`, governance execution and accepted settlements) goes through
+//! `slashing::slash_bond`, which records the applied delta here. `slash_proceeds` then records
+//! the amount transferred to the slash beneficiary as distributed. Without a beneficiary the
+//! proceeds accrue to the fee pool inside the contract and stay pending.
+//!
+//! With the `invariants` feature (and in unit tests) every update asserts that distribution
+//! never exceeds application.
+
+use soroban_sdk::{contracttype, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum AccountingKey {
+    /// Total slash applied to bonds (net of unslashing)
+    Applied,
+    /// Total slashed funds transferred out of the contract
+    Distributed,
+}
+
+fn get(e: &Env, key: &AccountingKey) -> i128 {
+    e.storage().instance().get(key).unwrap_or(0)
+}
+
+/// @notice Total slash applied to bonds.
+#[must_use]
+pub fn get_applied(e: &Env) -> i128 {
+    get(e, &AccountingKey::Applied)
+}
+
+/// @notice Total slashed funds transferred out of the contract.
+#[must_use]
+pub fn get_distributed(e: &Env) -> i128 {
+    get(e, &AccountingKey::Distributed)
+}
+
+/// @notice `(applied, distributed, pending)`, where `pending = applied - distributed`.
+#[must_use]
+pub fn get_accounting(e: &Env) -> (i128, i128, i128) {
+    let applied = get_applied(e);
+    let distributed = get_distributed(e);
+    (applied, distributed, applied - distributed)
+}
+
+/// @notice Adds `delta` (negative when unslashing) to the applied total.
+///
+/// # Panics
+/// - "slash accounting overflow"
+pub fn record_applied(e: &Env, delta: i128) {
+    let applied = get_applied(e)
+        .checked_add(delta)
+        .expect("slash accounting overflow");
+    e.storage()
+        .instance()
+        .set(&AccountingKey::Applied, &applied);
+    check(e);
+}
+
+/// @notice Adds `amount` of slashed funds transferred out to the distributed total.
+///
+/// # Panics
+/// - "slash accounting overflow"
+pub fn record_distributed(e: &Env, amount: i128) {
+    let distributed = get_distributed(e)
+        .checked_add(amount)
+        .expect("slash accounting overflow");
+    e.storage()
+        .instance()
+        .set(&AccountingKey::Distributed, &distributed);
+    check(e);
+}
+
+#[cfg(any(test, feature = "invariants"))]
+fn check(e: &Env) {
+    if get_distributed(e) > get_applied(e) {
+        panic!("slash distribution exceeds applied");
+    }
+}
+
+#[cfg(not(any(test, feature = "invariants")))]
+fn check(_e: &Env) {}
@@ -5,6 +5,10 @@ use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
 pub struct SlashRecord {
     pub identity: Address,
     pub slash_amount: i128,
+    /// Part of `slash_amount` actually taken from the bond.
+    pub applied: i128,
+    /// Part of `slash_amount` that could not be taken (capped, or the bond was already closed).
+    pub uncollectable: i128,
     pub reason: Symbol,
     pub timestamp: u64,
     pub total_slashed_after: i128,
@@ -22,6 +26,7 @@ pub fn append_slash_history(
     e: &Env,
     identity: &Address,
     slash_amount: i128,
+    applied: i128,
     reason: Symbol,
     total_slashed_after: i128,
 ) {
@@ -32,6 +37,8 @@ pub fn append_slash_history(
     let record = SlashRecord {
         identity: identity.clone(),
         slash_amount,
+        applied,
+        uncollectable: slash_amount - applied,
         reason,
         timestamp: e.ledger().timestamp(),
         total_slashed_after,
@@ -74,12 +81,31 @@ pub fn get_slash_record(e: &Env, identity: &Address, index: u32) -> SlashRecord
         .unwrap_or_else(|| panic!("slash record not found"))
 }
 
+/// Number of slashes recorded for `identity` at or after `since`. Records are appended in time
+/// order, so only the ones inside the window are read.
+#[must_use]
+pub fn count_since(e: &Env, identity: &Address, since: u64) -> u32 {
+    let mut index = get_slash_count(e, identity);
+    let mut count = 0;
+    while index > 0 {
+        index -= 1;
+        let key = SlashStorageKey::SlashRecord(identity.clone(), index);
+        match e.storage().persistent().get::<_, SlashRecord>(&key) {
+            Some(record) if record.timestamp >= since => count += 1,
+            _ => break,
+        }
+    }
+    count
+}
+
 #[must_use]
 pub fn get_total_slashed_from_history(e: &Env, identity: &Address) -> i128 {
     let history = get_slash_history(e, identity);
     let mut total: i128 = 0;
     for record in history.iter() {
-        total += record.slash_amount;
+        total = total
+            .checked_add(record.slash_amount)
+            .expect("slash history total overflow");
     }
     total
 }
@@ -0,0 +1,180 @@
+//! Slash Pipeline Latency
+//!
+//! Records when each slash request passes through each stage so operators can see how long
+//! requests sit in a state without scraping events. Every transition timestamp is written at
+//! most once; the first occurrence wins. Global aggregates keep a count and the total seconds
+//! spent reaching each stage, measured from the stage before it:
+//!
+//! - approved: submitted -> quorum reached with an approving majority
+//! - disputed: submitted -> first dispute filed
+//! - resolved: disputed -> disputed request closed (executed or rejected)
+//! - executed: approved -> slash applied
+//! - rejected: submitted -> request closed without a slash
+//!
+//! Requests created before tracking existed (or written by test seeding) have no timeline and
+//! are skipped.
+
+use soroban_sdk::{contracttype, Env};
+
+/// Per-request transition timestamps; `None` until the transition happens.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlashTimeline {
+    pub submitted_at: u64,
+    pub approved_at: Option<u64>,
+    pub disputed_at: Option<u64>,
+    pub resolved_at: Option<u64>,
+    pub executed_at: Option<u64>,
+    pub rejected_at: Option<u64>,
+}
+
+/// Number of requests that made a transition and the total seconds they took.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransitionStats {
+    pub count: u64,
+    pub total_secs: u64,
+}
+
+/// Aggregates per transition across all slash requests.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlashPipelineStats {
+    pub approved: TransitionStats,
+    pub disputed: TransitionStats,
+    pub resolved: TransitionStats,
+    pub executed: TransitionStats,
+    pub rejected: TransitionStats,
+}
+
+#[derive(Clone, Copy)]
+enum Transition {
+    Approved,
+    Disputed,
+    Resolved,
+    Executed,
+    Rejected,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum PipelineKey {
+    Timeline(u64),
+    Stats,
+}
+
+fn empty_stats() -> TransitionStats {
+    TransitionStats {
+        count: 0,
+        total_secs: 0,
+    }
+}
+
+/// @notice Transition timestamps for `request_id`, if tracked.
+#[must_use]
+pub fn get_timeline(e: &Env, request_id: u64) -> Option<SlashTimeline> {
+    e.storage()
+        .instance()
+        .get(&PipelineKey::Timeline(request_id))
+}
+
+/// @notice Timeline reported for a request created before tracking existed: `submitted_at` 0
+/// and no transitions.
+#[must_use]
+pub fn untracked_timeline() -> SlashTimeline {
+    SlashTimeline {
+        submitted_at: 0,
+        approved_at: None,
+        disputed_at: None,
+        resolved_at: None,
+        executed_at: None,
+        rejected_at: None,
+    }
+}
+
+/// @notice Aggregated transition counts and durations.
+#[must_use]
+pub fn get_stats(e: &Env) -> SlashPipelineStats {
+    e.storage()
+        .instance()
+        .get(&PipelineKey::Stats)
+        .unwrap_or(SlashPipelineStats {
+            approved: empty_stats(),
+            disputed: empty_stats(),
+            resolved: empty_stats(),
+            executed: empty_stats(),
+            rejected: empty_stats(),
+        })
+}
+
+/// @notice Starts tracking a newly submitted request.
+pub fn record_submitted(e: &Env, request_id: u64) {
+    let timeline = SlashTimeline {
+        submitted_at: e.ledger().timestamp(),
+        approved_at: None,
+        disputed_at: None,
+        resolved_at: None,
+        executed_at: None,
+        rejected_at: None,
+    };
+    e.storage()
+        .instance()
+        .set(&PipelineKey::Timeline(request_id), &timeline);
+}
+
+pub fn record_approved(e: &Env, request_id: u64) {
+    record(e, request_id, Transition::Approved);
+}
+
+pub fn record_disputed(e: &Env, request_id: u64) {
+    record(e, request_id, Transition::Disputed);
+}
+
+pub fn record_resolved(e: &Env, request_id: u64) {
+    record(e, request_id, Transition::Resolved);
+}
+
+pub fn record_executed(e: &Env, request_id: u64) {
+    record(e, request_id, Transition::Executed);
+}
+
+pub fn record_rejected(e: &Env, request_id: u64) {
+    record(e, request_id, Transition::Rejected);
+}
+
+fn record(e: &Env, request_id: u64, transition: Transition) {
+    let Some(mut timeline) = get_timeline(e, request_id) else {
+        return;
+    };
+    let now = e.ledger().timestamp();
+    let (slot, since) = match transition {
+        Transition::Approved => (&mut timeline.approved_at, Some(timeline.submitted_at)),
+        Transition::Disputed => (&mut timeline.disputed_at, Some(timeline.submitted_at)),
+        Transition::Resolved => (&mut timeline.resolved_at, timeline.disputed_at),
+        Transition::Executed => (&mut timeline.executed_at, timeline.approved_at),
+        Transition::Rejected => (&mut timeline.rejected_at, Some(timeline.submitted_at)),
+    };
+    if slot.is_some() {
+        return;
+    }
+    *slot = Some(now);
+    let elapsed = now.saturating_sub(since.unwrap_or(timeline.submitted_at));
+    e.storage()
+        .instance()
+        .set(&PipelineKey::Timeline(request_id), &timeline);
+
+    let mut stats = get_stats(e);
+    let entry = match transition {
+        Transition::Approved => &mut stats.approved,
+        Transition::Disputed => &mut stats.disputed,
+        Transition::Resolved => &mut stats.resolved,
+        Transition::Executed => &mut stats.executed,
+        Transition::Rejected => &mut stats.rejected,
+    };
+    entry.count = entry.count.checked_add(1).expect("pipeline count overflow");
+    entry.total_secs = entry
+        .total_secs
+        .checked_add(elapsed)
+        .expect("pipeline duration overflow");
+    e.storage().instance().set(&PipelineKey::Stats, &stats);
+}
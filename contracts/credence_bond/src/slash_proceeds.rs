@@ -0,0 +1,68 @@
+//! Slash Proceeds Routing
+//!
+//! Decides where slashed tokens go once a slash has been applied to a bond. When the admin has
+//! configured a beneficiary (typically a treasury), the applied amount is transferred to it and
+//! counted as distributed in `slash_accounting`. Otherwise it accrues to the protocol fee pool
+//! (the `"fees"` instance key drained by `collect_fees` and `distribute_governor_fees`) and
+//! stays in the contract.
+//!
+//! Only the applied amount is routed: a slash capped at the bonded amount moves what was
+//! actually taken, never the requested amount. Callers route after every state write so the
+//! transfer is the last step of the slash.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{fees, slash_accounting, token_integration};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum ProceedsKey {
+    Beneficiary,
+}
+
+/// @notice Recipient of slash proceeds, if configured.
+#[must_use]
+pub fn get_beneficiary(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&ProceedsKey::Beneficiary)
+}
+
+/// @notice Sends future slash proceeds to `beneficiary` (admin check enforced by the caller).
+///
+/// # Panics
+/// - "beneficiary cannot be contract" if `beneficiary` is this contract
+pub fn set_beneficiary(e: &Env, beneficiary: &Address) {
+    if *beneficiary == e.current_contract_address() {
+        panic!("beneficiary cannot be contract");
+    }
+    e.storage()
+        .instance()
+        .set(&ProceedsKey::Beneficiary, beneficiary);
+}
+
+/// @notice Removes the beneficiary; later proceeds accrue to the fee pool.
+pub fn clear_beneficiary(e: &Env) {
+    e.storage().instance().remove(&ProceedsKey::Beneficiary);
+}
+
+/// @notice Routes `amount` slashed from `identity` and emits `slash_proceeds_transferred`
+/// (topic: identity) with `(beneficiary, amount)`, where `beneficiary` is `None` when the
+/// amount went to the fee pool. Does nothing for a non-positive amount.
+pub fn route(e: &Env, identity: &Address, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    let beneficiary = get_beneficiary(e);
+    match &beneficiary {
+        Some(to) => {
+            slash_accounting::record_distributed(e, amount);
+            token_integration::transfer_from_contract(e, to, amount);
+        }
+        None => fees::add_to_pool(e, amount),
+    }
+    crate::events::publish_for(
+        e,
+        "slash_proceeds_transferred",
+        identity.clone(),
+        (beneficiary, amount),
+    );
+}
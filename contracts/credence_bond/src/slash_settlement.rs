@@ -0,0 +1,141 @@
+//! Voluntary Slash Settlement
+//!
+//! A bonded identity that acknowledges a fault can offer to settle an open slash request by
+//! accepting a penalty of at most the requested amount. The offer is recorded against the request
+//! (a new offer replaces the previous one) and has no effect until a governor or the requester
+//! accepts it: the offered amount is then slashed and the request closes as `Settled`. Rejecting
+//! an offer only discards it; the request stays open and continues through normal governance.
+//!
+//! Dispute escrows on a settled request are forfeited, as for an executed slash: the identity
+//! itself has acknowledged the fault.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::governance_approval::{self, ProposalStatus, SlashProposal};
+use crate::DataKey;
+
+/// A pending settlement offer on a slash request.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementOffer {
+    pub identity: Address,
+    pub amount: i128,
+    pub offered_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum SettlementKey {
+    Offer(u64),
+}
+
+fn open_request(e: &Env, request_id: u64) -> SlashProposal {
+    let proposal = governance_approval::get_proposal(e, request_id)
+        .unwrap_or_else(|| panic!("proposal not found"));
+    if proposal.status != ProposalStatus::Open {
+        panic!("proposal not open for settlement");
+    }
+    proposal
+}
+
+/// @notice Pending settlement offer on `request_id`, if any.
+#[must_use]
+pub fn get_offer(e: &Env, request_id: u64) -> Option<SettlementOffer> {
+    e.storage()
+        .instance()
+        .get(&SettlementKey::Offer(request_id))
+}
+
+/// @notice Records `identity`'s offer to settle `request_id` for `amount`.
+///
+/// # Panics
+/// - "proposal not found", "proposal not open for settlement".
+/// - "not bond owner" if the request does not target `identity`'s bond.
+/// - "no bond" if `identity` holds no bond.
+/// - "offered amount must be positive", "offer exceeds requested amount".
+pub fn offer(e: &Env, identity: &Address, request_id: u64, amount: i128) -> SettlementOffer {
+    let proposal = open_request(e, request_id);
+    if proposal.identity != *identity {
+        panic!("not bond owner");
+    }
+    if !e.storage().instance().has(&DataKey::Bond(identity.clone())) {
+        panic!("no bond");
+    }
+    if amount <= 0 {
+        panic!("offered amount must be positive");
+    }
+    if amount > proposal.amount {
+        panic!("offer exceeds requested amount");
+    }
+
+    let offer = SettlementOffer {
+        identity: identity.clone(),
+        amount,
+        offered_at: e.ledger().timestamp(),
+    };
+    e.storage()
+        .instance()
+        .set(&SettlementKey::Offer(request_id), &offer);
+    crate::events::publish_for(
+        e,
+        "settlement_offered",
+        request_id,
+        (identity.clone(), amount),
+    );
+    offer
+}
+
+/// Panics with "not a governor or requester" unless `member` may decide on the offer.
+fn require_decider(e: &Env, member: &Address, proposal: &SlashProposal) {
+    if *member != proposal.proposed_by && !governance_approval::is_active_member(e, member) {
+        panic!("not a governor or requester");
+    }
+}
+
+fn take_offer(e: &Env, request_id: u64) -> SettlementOffer {
+    let key = SettlementKey::Offer(request_id);
+    let offer: SettlementOffer = e
+        .storage()
+        .instance()
+        .get(&key)
+        .unwrap_or_else(|| panic!("no settlement offer"));
+    e.storage().instance().remove(&key);
+    offer
+}
+
+/// @notice Accepts the pending offer and closes the request as `Settled`. The caller applies
+/// the slash. Returns the accepted offer.
+///
+/// # Panics
+/// - "proposal not found", "proposal not open for settlement".
+/// - "not a governor or requester", "no settlement offer".
+pub fn accept(e: &Env, member: &Address, request_id: u64) -> SettlementOffer {
+    let proposal = open_request(e, request_id);
+    require_decider(e, member, &proposal);
+    let offer = take_offer(e, request_id);
+    governance_approval::mark_settled(e, request_id);
+    crate::events::publish_for(
+        e,
+        "settlement_accepted",
+        request_id,
+        (member.clone(), offer.identity.clone(), offer.amount),
+    );
+    offer
+}
+
+/// @notice Discards the pending offer; the request stays open.
+///
+/// # Panics
+/// - "proposal not found", "proposal not open for settlement".
+/// - "not a governor or requester", "no settlement offer".
+pub fn reject(e: &Env, member: &Address, request_id: u64) {
+    let proposal = open_request(e, request_id);
+    require_decider(e, member, &proposal);
+    let offer = take_offer(e, request_id);
+    crate::events::publish_for(
+        e,
+        "settlement_rejected",
+        request_id,
+        (member.clone(), offer.identity, offer.amount),
+    );
+}
@@ -0,0 +1,73 @@
+//! Pending Slash Simulation
+//!
+//! Read-only projection of what happens to an identity's bond if every open slash request
+//! against it is executed. Requests are applied in id order against the current bond using the
+//! same capping as `slashing::slash_bond`, so governance can see the combined effect (and which
+//! requests would be capped away entirely) before approving more.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::{governance_approval, slashing, DataKey, IdentityBond};
+
+/// @notice Projected outcome of one open slash request.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SimulatedSlash {
+    pub proposal_id: u64,
+    pub requested: i128,
+    /// Amount that would actually be slashed after capping at the remaining bond.
+    pub applied: i128,
+    /// True if the request would have no effect because earlier requests exhaust the bond.
+    pub capped_to_zero: bool,
+}
+
+/// @notice Projection of executing all open slash requests in id order.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SlashSimulation {
+    pub slashes: Vec<SimulatedSlash>,
+    pub total_applied: i128,
+    /// The bond as it would be after all requests execute.
+    pub projected_bond: IdentityBond,
+}
+
+/// @notice Simulates the open slash requests against `identity`'s bond. Writes nothing.
+///
+/// # Panics
+/// - "no bond" if `identity` has no bond.
+#[must_use]
+pub fn simulate(e: &Env, identity: &Address) -> SlashSimulation {
+    let mut bond: IdentityBond = e
+        .storage()
+        .instance()
+        .get(&DataKey::Bond(identity.clone()))
+        .unwrap_or_else(|| panic!("no bond"));
+
+    let mut slashes = Vec::new(e);
+    let mut total_applied: i128 = 0;
+    for proposal in governance_approval::open_proposals(e).iter() {
+        if proposal.identity != *identity {
+            continue;
+        }
+        let slashed = slashing::capped_slashed_amount(
+            bond.bonded_amount,
+            bond.slashed_amount,
+            proposal.amount,
+        );
+        let applied = (slashed - bond.slashed_amount).max(0);
+        bond.slashed_amount = slashed;
+        total_applied += applied;
+        slashes.push_back(SimulatedSlash {
+            proposal_id: proposal.id,
+            requested: proposal.amount,
+            applied,
+            capped_to_zero: applied == 0 && proposal.amount > 0,
+        });
+    }
+
+    SlashSimulation {
+        slashes,
+        total_applied,
+        projected_bond: bond,
+    }
+}
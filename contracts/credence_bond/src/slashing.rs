@@ -14,8 +14,17 @@
 //! - **Cumulative**: Multiple slashes accumulate (tracked in slashed_amount)
 //! - **Over-slash Protection**: Ensures slashed_amount never exceeds bonded_amount
 //! - **Withdrawals**: Affected by slashing (withdrawable = bonded - slashed)
+//! - **Grace Period**: A bond cannot be slashed until `slash_grace_period` seconds after it was
+//!   created. Top-ups and rolling renewals do not restart it; 0 (the default) disables it.
 
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum SlashingKey {
+    /// Seconds after bond creation during which slashing is blocked
+    GracePeriod,
+}
 
 /// Storage key for tracking accumulated slashed funds (for treasury transfer purposes).
 /// Not currently used for fund transfers in this implementation, but reserved for future use.
@@ -26,24 +35,48 @@ const KEY_SLASHED_FUNDS_POOL: &str = "slashed_funds_pool";
 ///
 /// # Arguments
 /// * `e` - Soroban environment
-/// * `_bond_identity` - Address of the bonded identity
+/// * `bond_identity` - Address of the bonded identity
 ///
 /// # Returns
 /// The accumulated slashed amount (i128). Returns 0 if no bond exists.
 #[allow(dead_code)]
 #[must_use]
-pub fn get_slashed_amount(e: &Env, _bond_identity: &Address) -> i128 {
-    let storage_key = crate::DataKey::Bond;
+pub fn get_slashed_amount(e: &Env, bond_identity: &Address) -> i128 {
+    let storage_key = crate::DataKey::Bond(bond_identity.clone());
+    e.storage()
+        .instance()
+        .get::<_, crate::IdentityBond>(&storage_key)
+        .map_or(0, |bond| bond.slashed_amount)
+}
+
+/// @notice Seconds after bond creation during which the bond cannot be slashed (0 = none).
+#[must_use]
+pub fn get_grace_period(e: &Env) -> u64 {
     e.storage()
         .instance()
-        .get::<_, i128>(&storage_key)
-        .map(|_| {
-            // In a full implementation, retrieve from bond state
-            0 // Simplified: return 0
-        })
+        .get(&SlashingKey::GracePeriod)
         .unwrap_or(0)
 }
 
+/// @notice Sets the slash grace period. Admin only (enforced by caller).
+pub fn set_grace_period(e: &Env, secs: u64) {
+    e.storage().instance().set(&SlashingKey::GracePeriod, &secs);
+    crate::events::publish(e, "slash_grace_period_set", secs);
+}
+
+/// @notice Panics with "bond in grace period" while `now < created + grace_period`, where
+/// `created` is when the bond was opened (its `bond_start` if that was not recorded).
+pub fn require_past_grace(e: &Env, bond: &crate::IdentityBond) {
+    let grace = get_grace_period(e);
+    if grace == 0 {
+        return;
+    }
+    let created = crate::closeout::get_opened_at(e, &bond.identity).unwrap_or(bond.bond_start);
+    if e.ledger().timestamp() < created.saturating_add(grace) {
+        panic!("bond in grace period");
+    }
+}
+
 /// NatSpec-style: Validates that the caller is the authorized admin.
 ///
 /// # Arguments
@@ -71,12 +104,16 @@ pub fn validate_admin(e: &Env, caller: &Address) {
 /// 3. Caps at bonded amount (prevents over-slash)
 /// 4. Updates bond state
 /// 5. Emits slashing event
-/// 6. Returns updated bond state
+/// 6. Routes the applied amount (see `slash_proceeds`)
+/// 7. Returns updated bond state
 ///
 /// # Arguments
 /// * `e` - Soroban environment
 /// * `admin` - Address claiming admin authority
+/// * `identity` - Holder of the bond to slash
 /// * `amount` - Amount to slash (i128)
+/// * `reason` - Why the slash happened (`slash`, `governance`, `settlement`); recorded in the
+///   `bond_slashed` event and the slash history
 ///
 /// # Returns
 /// Updated `IdentityBond` with modified `slashed_amount`
@@ -84,14 +121,20 @@ pub fn validate_admin(e: &Env, caller: &Address) {
 /// # Panics
 /// - "not admin" if caller is not the contract admin
 /// - "not initialized" if contract not initialized
-/// - "no bond" if no bond exists for this contract instance
-/// - If arithmetic overflows (checked_add protection)
+/// - "no bond" if `identity` holds no bond
+/// - "bond in grace period" if the bond is still within the slash grace period
 ///
 /// # Security Notes
 /// - Over-slash is prevented by capping at bonded_amount
 /// - Slashing is monotonic (always increases or stays same, never decreases)
 /// - Cannot slash bonds that don't exist (panic on "no bond")
-pub fn slash_bond(e: &Env, admin: &Address, amount: i128) -> crate::IdentityBond {
+pub fn slash_bond(
+    e: &Env,
+    admin: &Address,
+    identity: &Address,
+    amount: i128,
+    reason: &Symbol,
+) -> crate::IdentityBond {
     if amount < 0 {
         panic!("slash amount must be non-negative");
     }
@@ -99,36 +142,56 @@ pub fn slash_bond(e: &Env, admin: &Address, amount: i128) -> crate::IdentityBond
     validate_admin(e, admin);
 
     // 2. Retrieve current bond state
-    let key = crate::DataKey::Bond;
+    let key = crate::DataKey::Bond(identity.clone());
     let mut bond = e
         .storage()
         .instance()
         .get::<_, crate::IdentityBond>(&key)
         .unwrap_or_else(|| panic!("no bond"));
+    require_past_grace(e, &bond);
 
-    // 3. Calculate new slashed amount with overflow protection
-    let new_slashed = bond
-        .slashed_amount
-        .checked_add(amount)
-        .expect("slashing caused overflow");
-
-    // 4. Cap slashing at bonded amount (over-slash prevention)
-    bond.slashed_amount = if new_slashed > bond.bonded_amount {
-        bond.bonded_amount
-    } else {
-        new_slashed
-    };
+    // 3-4. New slashed amount, saturated and capped at bonded amount
+    crate::bond_rewards::settle(e, identity);
+    let previous = bond.slashed_amount;
+    bond.slashed_amount = capped_slashed_amount(bond.bonded_amount, bond.slashed_amount, amount);
+    let applied = bond.slashed_amount - previous;
+    crate::slash_accounting::record_applied(e, applied);
+    crate::exposure_caps::release(e, applied);
 
     // 5. Persist updated bond state
     e.storage().instance().set(&key, &bond);
+    crate::closeout::close_if_terminal(e, &bond, true);
+    crate::maturity_ladder::sync(e, &bond);
 
-    // 6. Emit slashing event for off-chain tracking
-    emit_slashing_event(e, &bond.identity, amount, bond.slashed_amount);
+    // 6. Emit slashing event for off-chain tracking and keep the per-identity history
+    emit_slashing_event(e, &bond, amount, previous, reason);
+    if amount > 0 {
+        crate::slash_history::append_slash_history(
+            e,
+            &bond.identity,
+            amount,
+            applied,
+            reason.clone(),
+            bond.slashed_amount,
+        );
+    }
+
+    // 7. Route the applied amount to the beneficiary or fee pool, after all state writes
+    crate::slash_proceeds::route(e, &bond.identity, applied);
 
-    // 7. Return updated bond state
+    // 8. Return updated bond state
     bond
 }
 
+/// Slashed amount after slashing `amount` more, capped at `bonded_amount` (over-slash
+/// prevention). Shared by execution and `simulate_pending_slashes` so both cap identically.
+/// The sum saturates before capping, so an amount near `i128::MAX` clamps to `bonded_amount`
+/// instead of overflowing.
+#[must_use]
+pub fn capped_slashed_amount(bonded_amount: i128, slashed_amount: i128, amount: i128) -> i128 {
+    slashed_amount.saturating_add(amount).min(bonded_amount)
+}
+
 /// NatSpec-style: Reverts slashing (reduces slashed amount). Admin only.
 ///
 /// Used for correcting mistaken slashes or appeals.
@@ -137,6 +200,7 @@ pub fn slash_bond(e: &Env, admin: &Address, amount: i128) -> crate::IdentityBond
 /// # Arguments
 /// * `e` - Soroban environment
 /// * `admin` - Address claiming admin authority  
+/// * `identity` - Holder of the bond to unslash
 /// * `amount` - Amount to unslash (i128)
 ///
 /// # Returns
@@ -146,23 +210,31 @@ pub fn slash_bond(e: &Env, admin: &Address, amount: i128) -> crate::IdentityBond
 /// - "not admin" if not authorized
 /// - If amount would reduce slashed_amount below 0
 #[allow(dead_code)]
-pub fn unslash_bond(e: &Env, admin: &Address, amount: i128) -> crate::IdentityBond {
+pub fn unslash_bond(
+    e: &Env,
+    admin: &Address,
+    identity: &Address,
+    amount: i128,
+) -> crate::IdentityBond {
     if amount < 0 {
         panic!("unslash amount must be non-negative");
     }
     validate_admin(e, admin);
 
-    let key = crate::DataKey::Bond;
+    let key = crate::DataKey::Bond(identity.clone());
     let mut bond = e
         .storage()
         .instance()
         .get::<_, crate::IdentityBond>(&key)
         .unwrap_or_else(|| panic!("no bond"));
 
+    crate::bond_rewards::settle(e, identity);
     bond.slashed_amount = bond
         .slashed_amount
         .checked_sub(amount)
         .expect("unslashing would reduce below 0");
+    crate::slash_accounting::record_applied(e, -amount);
+    crate::exposure_caps::restore(e, amount);
 
     e.storage().instance().set(&key, &bond);
     emit_unslashing_event(e, &bond.identity, amount, bond.slashed_amount);
@@ -224,13 +296,24 @@ pub fn is_partial_slash(slash_amount: i128, bonded_amount: i128) -> bool {
 ///
 /// # Arguments
 /// * `e` - Soroban environment for event publishing
-/// * `identity` - Address of the slashed bonded identity
+/// * `bond` - The slashed bond, after the slash
 /// * `slash_amount` - The amount just slashed
-/// * `total_slashed` - The cumulative slashed amount after this slash
-pub fn emit_slashing_event(e: &Env, identity: &Address, slash_amount: i128, total_slashed: i128) {
-    e.events().publish(
-        (Symbol::new(e, "bond_slashed"),),
-        (identity.clone(), slash_amount, total_slashed),
+/// * `previous_slashed` - The cumulative slashed amount before this slash
+/// * `reason` - Why the slash happened
+pub fn emit_slashing_event(
+    e: &Env,
+    bond: &crate::IdentityBond,
+    slash_amount: i128,
+    previous_slashed: i128,
+    reason: &Symbol,
+) {
+    crate::events::emit_bond_slashed(
+        e,
+        &bond.identity,
+        slash_amount,
+        previous_slashed,
+        bond.slashed_amount,
+        reason,
     );
 }
 
@@ -248,8 +331,9 @@ pub fn emit_unslashing_event(
     unslash_amount: i128,
     total_slashed: i128,
 ) {
-    e.events().publish(
-        (Symbol::new(e, "bond_unslashed"),),
+    crate::events::publish(
+        e,
+        "bond_unslashed",
         (identity.clone(), unslash_amount, total_slashed),
     );
 }
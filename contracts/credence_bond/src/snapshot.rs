@@ -0,0 +1,50 @@
+//! Test State Snapshots
+//!
+//! Lets integration tests build a configured baseline (bond, governance, verifiers) once, export
+//! it as XDR-encoded `(key, value)` pairs, and restore it into a fresh contract per test case
+//! instead of replaying the setup calls. Compiled only for unit tests or with the `testing-hooks`
+//! feature, never into release wasm.
+//!
+//! Snapshots cover instance storage, where the contract keeps its state; persistent entries
+//! (slash history) and other contracts' state, such as token balances, are not captured.
+//! Entries are plain bytes, so they can be copied out of one `Env` and into another.
+
+use soroban_sdk::testutils::storage::Instance as _;
+use soroban_sdk::xdr::{FromXdr, ToXdr};
+use soroban_sdk::{Bytes, Env, Val, Vec};
+
+use crate::DataKey;
+
+/// @notice Encodes the instance entries stored under `keys`; absent keys are skipped.
+#[must_use]
+pub fn export(e: &Env, keys: Vec<DataKey>) -> Vec<(Bytes, Bytes)> {
+    let mut out = Vec::new(e);
+    for key in keys.iter() {
+        if let Some(value) = e.storage().instance().get::<DataKey, Val>(&key) {
+            out.push_back((key.to_xdr(e), value.to_xdr(e)));
+        }
+    }
+    out
+}
+
+/// @notice Encodes every instance entry, including module-local keys that `export` cannot name.
+#[must_use]
+pub fn export_all(e: &Env) -> Vec<(Bytes, Bytes)> {
+    let mut out = Vec::new(e);
+    for (key, value) in e.storage().instance().all().iter() {
+        out.push_back((key.to_xdr(e), value.to_xdr(e)));
+    }
+    out
+}
+
+/// @notice Writes exported entries back to instance storage, overwriting existing values.
+///
+/// # Panics
+/// - "invalid snapshot entry" if a key or value does not decode.
+pub fn import(e: &Env, entries: Vec<(Bytes, Bytes)>) {
+    for (key, value) in entries.iter() {
+        let key = Val::from_xdr(e, &key).unwrap_or_else(|_| panic!("invalid snapshot entry"));
+        let value = Val::from_xdr(e, &value).unwrap_or_else(|_| panic!("invalid snapshot entry"));
+        e.storage().instance().set(&key, &value);
+    }
+}
@@ -0,0 +1,207 @@
+//! Subject Notification Hooks
+//!
+//! Subjects that are themselves contracts (DAOs, custodial wallets) can register one hook
+//! contract to be told when they gain or lose an attestation. After an attestation is stored or
+//! revoked, the hook's `on_attestation(subject, attestation_id, added)` is invoked with
+//! `try_invoke_contract`. A hook that panics, returns an error or does not exist never blocks
+//! the attestation: the failure is reported with a `hook_failed` event and the operation
+//! completes.
+//!
+//! Failed deliveries are kept in a queue of at most `MAX_FAILED_DELIVERIES` entries so
+//! transient hook failures eventually arrive. Anyone may call `retry_failed` to re-deliver them
+//! in queue order; an entry that fails again goes to the back of the queue and is dropped with
+//! `hook_delivery_abandoned` after `MAX_DELIVERY_ATTEMPTS` attempts (the original one
+//! included). Entries whose subject has since cleared or replaced its hook are dropped too.
+//! Failures beyond the cap are only reported. Attestation operations never wait on the queue.
+
+use soroban_sdk::{contracttype, vec, Address, Env, IntoVal, Symbol, Val, Vec};
+
+/// Largest number of failed deliveries kept for retry.
+pub const MAX_FAILED_DELIVERIES: u32 = 50;
+
+/// Delivery attempts (the original one included) before an entry is abandoned.
+pub const MAX_DELIVERY_ATTEMPTS: u32 = 5;
+
+/// @notice A hook notification that failed and awaits retry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FailedDelivery {
+    pub hook: Address,
+    pub subject: Address,
+    pub attestation_id: u64,
+    pub added: bool,
+    /// Delivery attempts made so far.
+    pub attempts: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum HookKey {
+    /// Subject -> hook contract
+    Hook(Address),
+    /// Failed deliveries awaiting retry, oldest first
+    Failed,
+}
+
+/// @notice Hook contract registered by `subject`, if any.
+#[must_use]
+pub fn get_hook(e: &Env, subject: &Address) -> Option<Address> {
+    e.storage().instance().get(&HookKey::Hook(subject.clone()))
+}
+
+/// @notice Sets `subject`'s hook, replacing any previous one (subject auth; caller must
+/// enforce).
+///
+/// # Panics
+/// - "hook cannot be this contract" if `hook` is the bond contract itself.
+pub fn register(e: &Env, subject: &Address, hook: &Address) {
+    if *hook == e.current_contract_address() {
+        panic!("hook cannot be this contract");
+    }
+    e.storage()
+        .instance()
+        .set(&HookKey::Hook(subject.clone()), hook);
+    crate::events::publish_for(e, "subject_hook_registered", subject.clone(), hook.clone());
+}
+
+/// @notice Removes `subject`'s hook (subject auth; caller must enforce). No-op if none is set.
+pub fn clear(e: &Env, subject: &Address) {
+    let key = HookKey::Hook(subject.clone());
+    if !e.storage().instance().has(&key) {
+        return;
+    }
+    e.storage().instance().remove(&key);
+    crate::events::publish_for(e, "subject_hook_cleared", subject.clone(), ());
+}
+
+/// @notice Delivers `on_attestation(subject, attestation_id, added)` to `subject`'s hook, if
+/// one is registered, and queues it for retry on failure. Call only after the attestation
+/// state change has been written.
+pub fn notify(e: &Env, subject: &Address, attestation_id: u64, added: bool) {
+    let Some(hook) = get_hook(e, subject) else {
+        return;
+    };
+    if !deliver(e, &hook, subject, attestation_id, added) {
+        crate::events::publish_for(
+            e,
+            "hook_failed",
+            subject.clone(),
+            (hook.clone(), attestation_id, added),
+        );
+        enqueue(
+            e,
+            FailedDelivery {
+                hook,
+                subject: subject.clone(),
+                attestation_id,
+                added,
+                attempts: 1,
+            },
+        );
+    }
+}
+
+/// @notice Failed deliveries awaiting retry, oldest first.
+#[must_use]
+pub fn get_failed(e: &Env) -> Vec<FailedDelivery> {
+    e.storage()
+        .instance()
+        .get(&HookKey::Failed)
+        .unwrap_or(Vec::new(e))
+}
+
+fn set_failed(e: &Env, queue: &Vec<FailedDelivery>) {
+    if queue.is_empty() {
+        e.storage().instance().remove(&HookKey::Failed);
+    } else {
+        e.storage().instance().set(&HookKey::Failed, queue);
+    }
+}
+
+/// @notice Retries up to `limit` queued deliveries from the front of the queue. Returns how
+/// many were delivered.
+pub fn retry_failed(e: &Env, limit: u32) -> u32 {
+    // Detach the batch before invoking hooks so a re-entering hook sees a consistent queue.
+    let mut queue = get_failed(e);
+    let take = limit.min(queue.len());
+    let batch = queue.slice(0..take);
+    queue = queue.slice(take..);
+    set_failed(e, &queue);
+
+    let mut delivered = 0_u32;
+    for mut entry in batch.iter() {
+        if get_hook(e, &entry.subject).as_ref() != Some(&entry.hook) {
+            abandon(e, &entry, "hook_changed");
+            continue;
+        }
+        entry.attempts = entry.attempts.saturating_add(1);
+        if deliver(
+            e,
+            &entry.hook,
+            &entry.subject,
+            entry.attestation_id,
+            entry.added,
+        ) {
+            delivered += 1;
+            crate::events::publish_for(
+                e,
+                "hook_delivered",
+                entry.subject.clone(),
+                (
+                    entry.hook,
+                    entry.attestation_id,
+                    entry.added,
+                    entry.attempts,
+                ),
+            );
+        } else if entry.attempts >= MAX_DELIVERY_ATTEMPTS {
+            abandon(e, &entry, "max_attempts");
+        } else {
+            enqueue(e, entry);
+        }
+    }
+    delivered
+}
+
+fn deliver(e: &Env, hook: &Address, subject: &Address, attestation_id: u64, added: bool) -> bool {
+    if crate::failpoints::should_fail(e, crate::failpoints::CALLBACK_INVOKE) {
+        return false;
+    }
+    let args = vec![
+        e,
+        subject.into_val(e),
+        attestation_id.into_val(e),
+        added.into_val(e),
+    ];
+    let result = e.try_invoke_contract::<Val, soroban_sdk::Error>(
+        hook,
+        &Symbol::new(e, "on_attestation"),
+        args,
+    );
+    matches!(result, Ok(Ok(_)))
+}
+
+fn enqueue(e: &Env, entry: FailedDelivery) {
+    let mut queue = get_failed(e);
+    if queue.len() >= MAX_FAILED_DELIVERIES {
+        abandon(e, &entry, "queue_full");
+        return;
+    }
+    queue.push_back(entry);
+    set_failed(e, &queue);
+}
+
+fn abandon(e: &Env, entry: &FailedDelivery, reason: &str) {
+    crate::events::publish_for(
+        e,
+        "hook_delivery_abandoned",
+        entry.subject.clone(),
+        (
+            entry.hook.clone(),
+            entry.attestation_id,
+            entry.added,
+            entry.attempts,
+            Symbol::new(e, reason),
+        ),
+    );
+}
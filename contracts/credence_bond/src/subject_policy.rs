@@ -0,0 +1,56 @@
+//! Attestation Subject Policy
+//!
+//! Deployments choose whether attestations are open to any address or restricted to subjects
+//! the contract knows about. With `require_subject_known` set, `add_attestation` only accepts
+//! subjects that currently hold the bond or have bonded before (an open close-out record or a
+//! written close-out statement). The flag defaults to false, preserving open attestations.
+
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::{closeout, DataKey};
+
+const KEY_REQUIRE_SUBJECT_KNOWN: &str = "req_subj_known";
+
+/// @notice Returns true if attestation subjects must have a current or past bond.
+#[must_use]
+pub fn requires_known_subject(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_REQUIRE_SUBJECT_KNOWN))
+        .unwrap_or(false)
+}
+
+/// @notice Returns true once the subject policy has been stored.
+#[must_use]
+pub fn is_configured(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .has(&Symbol::new(e, KEY_REQUIRE_SUBJECT_KNOWN))
+}
+
+/// @notice Sets the subject policy (admin only; caller must enforce).
+pub fn set_require_known_subject(e: &Env, required: bool) {
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_REQUIRE_SUBJECT_KNOWN), &required);
+    crate::events::publish(e, "subject_policy_updated", required);
+}
+
+/// @notice Returns true if `subject` holds a bond or has bonded before.
+#[must_use]
+pub fn is_known_subject(e: &Env, subject: &Address) -> bool {
+    if e.storage().instance().has(&DataKey::Bond(subject.clone())) {
+        return true;
+    }
+    closeout::has_history(e, subject)
+}
+
+/// @notice Enforces the subject policy, if enabled.
+///
+/// # Panics
+/// - "unknown subject" if the policy is on and `subject` has never bonded.
+pub fn require_subject(e: &Env, subject: &Address) {
+    if requires_known_subject(e) && !is_known_subject(e, subject) {
+        panic!("unknown subject");
+    }
+}
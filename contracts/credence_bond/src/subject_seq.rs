@@ -0,0 +1,72 @@
+//! Per-subject Attestation Sequence Numbers
+//!
+//! Each subject gets a stable 1-based ordinal for the attestations issued about them ("my 4th
+//! attestation"), independent of the global attestation id. Sequence numbers are allocated
+//! once and never reused, so revocations leave the numbering gap-free. A small per-subject
+//! mapping resolves `(subject, seq)` back to the attestation id.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum SubjectSeqKey {
+    /// Number of sequence numbers issued to the subject.
+    Issued(Address),
+    /// Attestation id for (subject, seq).
+    Entry(Address, u32),
+}
+
+/// @notice Number of attestations ever issued for `subject` (highest sequence number).
+#[must_use]
+pub fn issued(e: &Env, subject: &Address) -> u32 {
+    e.storage()
+        .instance()
+        .get(&SubjectSeqKey::Issued(subject.clone()))
+        .unwrap_or(0)
+}
+
+/// @notice Allocates the next sequence number for `subject` and maps it to `attestation_id`.
+pub fn assign(e: &Env, subject: &Address, attestation_id: u64) -> u32 {
+    let seq = issued(e, subject)
+        .checked_add(1)
+        .expect("subject sequence overflow");
+    e.storage()
+        .instance()
+        .set(&SubjectSeqKey::Issued(subject.clone()), &seq);
+    e.storage()
+        .instance()
+        .set(&SubjectSeqKey::Entry(subject.clone(), seq), &attestation_id);
+    seq
+}
+
+/// @notice Attestation id issued to `subject` with sequence number `seq`, if any.
+#[must_use]
+pub fn resolve(e: &Env, subject: &Address, seq: u32) -> Option<u64> {
+    e.storage()
+        .instance()
+        .get(&SubjectSeqKey::Entry(subject.clone(), seq))
+}
+
+/// @notice Moves every sequence number issued to `from` over to `to`, keeping the numbering.
+/// `to` must not have been issued any (caller enforces).
+pub fn reassign(e: &Env, from: &Address, to: &Address) {
+    let count = issued(e, from);
+    if count == 0 {
+        return;
+    }
+    for seq in 1..=count {
+        let old = SubjectSeqKey::Entry(from.clone(), seq);
+        if let Some(id) = e.storage().instance().get::<_, u64>(&old) {
+            e.storage().instance().remove(&old);
+            e.storage()
+                .instance()
+                .set(&SubjectSeqKey::Entry(to.clone(), seq), &id);
+        }
+    }
+    e.storage()
+        .instance()
+        .remove(&SubjectSeqKey::Issued(from.clone()));
+    e.storage()
+        .instance()
+        .set(&SubjectSeqKey::Issued(to.clone()), &count);
+}
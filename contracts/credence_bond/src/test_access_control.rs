@@ -8,6 +8,7 @@ use crate::access_control::{
     add_verifier_role, get_admin, is_admin, is_verifier, remove_verifier_role, require_admin,
     require_admin_or_verifier, require_identity_owner, require_verifier,
 };
+use crate::events::EVENT_SCHEMA_VERSION;
 use soroban_sdk::testutils::{Address as _, Events};
 use soroban_sdk::{
     contract, contractimpl, symbol_short, vec, Address, Env, IntoVal, Symbol, TryFromVal,
@@ -69,7 +70,11 @@ fn setup(e: &Env) -> (AccessControlHarnessClient<'_>, Address) {
 }
 
 fn count_access_denied_events(e: &Env, contract_id: &Address, role: &str, code: u32) -> u32 {
-    let expected_topics = vec![e, Symbol::new(e, "access_denied").into_val(e)];
+    let expected_topics = vec![
+        e,
+        Symbol::new(e, "access_denied").into_val(e),
+        EVENT_SCHEMA_VERSION.into_val(e),
+    ];
 
     e.events()
         .all()
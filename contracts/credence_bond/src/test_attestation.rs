@@ -113,14 +113,11 @@ fn test_add_attestation_basic() {
     let subject = Address::generate(&e);
     let data = String::from_str(&e, "verified identity");
 
-    // FIX: Removed get_nonce and removed the nonce argument from add_attestation
     let att = client.add_attestation(&attester, &subject, &data);
 
     assert_eq!(att.id, 0);
-    // FIX: Use 'attester' instead of 'verifier'
-    assert_eq!(att.attester, attester);
-    // FIX: Use 'subject' instead of 'identity'
-    assert_eq!(att.subject, subject);
+    assert_eq!(att.verifier, attester);
+    assert_eq!(att.identity, subject);
     assert_eq!(att.attestation_data, data);
     assert!(!att.revoked);
 }
@@ -141,7 +138,6 @@ fn test_add_multiple_attestations() {
 
     let subject = Address::generate(&e);
 
-    // FIX: Remove all get_nonce calls and the extra arguments
     let att1 = client.add_attestation(&attester, &subject, &String::from_str(&e, "att1"));
     let att2 = client.add_attestation(&attester, &subject, &String::from_str(&e, "att2"));
     let att3 = client.add_attestation(&attester, &subject, &String::from_str(&e, "att3"));
@@ -172,12 +168,11 @@ fn test_add_attestation_different_attesters() {
     let attestation1 = client.add_attestation(&att1, &subject, &data);
     let attestation2 = client.add_attestation(&att2, &subject, &data);
 
-    assert_eq!(attestation1.attester, att1);
-    assert_eq!(attestation2.attester, att2);
+    assert_eq!(attestation1.verifier, att1);
+    assert_eq!(attestation2.verifier, att2);
     assert_ne!(attestation1.id, attestation2.id);
 }
 
-#[test]
 #[test]
 fn test_add_attestation_different_subjects() {
     let e = Env::default();
@@ -199,9 +194,8 @@ fn test_add_attestation_different_subjects() {
     let att1 = client.add_attestation(&attester, &sub1, &data);
     let att2 = client.add_attestation(&attester, &sub2, &data);
 
-    // FIX: Change 'identity' to 'subject'
-    assert_eq!(att1.subject, sub1);
-    assert_eq!(att2.subject, sub2);
+    assert_eq!(att1.identity, sub1);
+    assert_eq!(att2.identity, sub2);
 }
 
 #[test]
@@ -594,8 +588,7 @@ fn test_self_attestation() {
 
     let att = client.add_attestation(&address, &address, &String::from_str(&e, "self"));
 
-    // FIX: Change 'verifier' to 'attester' and 'identity' to 'subject'
-    assert_eq!(att.attester, att.subject);
+    assert_eq!(att.verifier, att.identity);
 }
 
 #[test]
@@ -0,0 +1,134 @@
+//! Tests for importing attestations from another attestation contract.
+
+use crate::attestation_import::{ImportOrigin, MAX_IMPORT_BATCH};
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, String, Vec};
+
+fn deploy<'a>(e: &Env, admin: &Address) -> CredenceBondClient<'a> {
+    let client = CredenceBondClient::new(e, &e.register(CredenceBond, ()));
+    client.initialize(admin);
+    client
+}
+
+#[test]
+fn test_import_preserves_records_and_marks_origin() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let source = deploy(&e, &admin);
+    let target = deploy(&e, &admin);
+    let (known, unknown) = (Address::generate(&e), Address::generate(&e));
+    let subject = Address::generate(&e);
+    source.register_attester(&known);
+    source.register_attester(&unknown);
+    target.register_attester(&known);
+
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let first = source.add_attestation(&known, &subject, &String::from_str(&e, "kyc"));
+    e.ledger().with_mut(|li| li.timestamp = 2_000);
+    let skipped = source.add_attestation(&unknown, &subject, &String::from_str(&e, "x"));
+    let second = source.add_attestation(&known, &subject, &String::from_str(&e, "audit"));
+    source.revoke_attestation(&known, &second.id);
+
+    // A local attestation first, so imported ids differ from the source ids.
+    e.ledger().with_mut(|li| li.timestamp = 5_000);
+    target.add_attestation(&known, &subject, &String::from_str(&e, "local"));
+
+    let ids = Vec::from_array(&e, [first.id, skipped.id, second.id]);
+    let result = target.import_attestations(&admin, &source.address, &ids, &false);
+    assert_eq!(result.imported.len(), 2);
+    assert_eq!(result.skipped, 1);
+    assert!(result.remaining.is_empty());
+
+    let local_first = target.get_attestation(&result.imported.get(0).unwrap());
+    assert_ne!(local_first.id, first.id);
+    assert_eq!(local_first.verifier, known);
+    assert_eq!(local_first.identity, subject);
+    assert_eq!(local_first.attestation_data, first.attestation_data);
+    assert_eq!(local_first.timestamp, 1_000);
+    assert!(!local_first.revoked);
+    assert_eq!(
+        target.get_attestation_origin(&local_first.id),
+        Some(ImportOrigin {
+            imported_from: source.address.clone(),
+            original_id: first.id,
+        })
+    );
+
+    let local_second = target.get_attestation(&result.imported.get(1).unwrap());
+    assert!(local_second.revoked);
+    assert_eq!(local_second.timestamp, 2_000);
+    assert_eq!(target.get_subject_attestations(&subject).len(), 3);
+    assert_eq!(target.get_attestation_origin(&0), None);
+
+    // Re-running the import skips everything already carried over.
+    let again = target.import_attestations(&admin, &source.address, &ids, &false);
+    assert!(again.imported.is_empty());
+    assert_eq!(again.skipped, 3);
+}
+
+#[test]
+fn test_override_imports_unregistered_attester() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let source = deploy(&e, &admin);
+    let target = deploy(&e, &admin);
+    let attester = Address::generate(&e);
+    source.register_attester(&attester);
+    let att = source.add_attestation(
+        &attester,
+        &Address::generate(&e),
+        &String::from_str(&e, "legacy"),
+    );
+
+    let ids = Vec::from_array(&e, [att.id]);
+    let result = target.import_attestations(&admin, &source.address, &ids, &true);
+    assert_eq!(result.imported.len(), 1);
+    assert_eq!(result.skipped, 0);
+}
+
+#[test]
+fn test_batch_bounded_with_continuation() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let source = deploy(&e, &admin);
+    let target = deploy(&e, &admin);
+    let attester = Address::generate(&e);
+    source.register_attester(&attester);
+    target.register_attester(&attester);
+    e.cost_estimate().budget().reset_unlimited();
+
+    let mut ids = Vec::new(&e);
+    for _ in 0..MAX_IMPORT_BATCH + 2 {
+        let subject = Address::generate(&e);
+        let att = source.add_attestation(&attester, &subject, &String::from_str(&e, "legacy"));
+        ids.push_back(att.id);
+    }
+
+    let first = target.import_attestations(&admin, &source.address, &ids, &false);
+    assert_eq!(first.imported.len(), MAX_IMPORT_BATCH);
+    assert_eq!(first.remaining.len(), 2);
+
+    let rest = target.import_attestations(&admin, &source.address, &first.remaining, &false);
+    assert_eq!(rest.imported.len(), 2);
+    assert!(rest.remaining.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_import_requires_admin() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let source = deploy(&e, &admin);
+    let target = deploy(&e, &admin);
+    target.import_attestations(
+        &Address::generate(&e),
+        &source.address,
+        &Vec::new(&e),
+        &false,
+    );
+}
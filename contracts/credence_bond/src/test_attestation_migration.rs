@@ -0,0 +1,148 @@
+//! Tests for the versioned attestation records: a V1-shaped record written straight to storage
+//! reads back in the V2 layout, is rewritten on its next write, and batch migration is
+//! idempotent.
+
+use crate::test_helpers;
+use crate::types::{Attestation, AttestationV1};
+use crate::{CredenceBond, CredenceBondClient, DataKey};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Attestation) {
+    e.mock_all_auths();
+    let client = CredenceBondClient::new(e, &e.register(CredenceBond, ()));
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    let att = client.add_attestation(
+        &attester,
+        &Address::generate(e),
+        &String::from_str(e, "kyc"),
+    );
+    (client, admin, att)
+}
+
+/// Overwrites attestation `att` with its V1 shape, as an older contract version stored it.
+fn store_as_v1(e: &Env, client: &CredenceBondClient, att: &Attestation) {
+    let legacy = AttestationV1 {
+        id: att.id,
+        verifier: att.verifier.clone(),
+        identity: att.identity.clone(),
+        timestamp: att.timestamp,
+        weight: att.weight,
+        attestation_data: att.attestation_data.clone(),
+        revoked: att.revoked,
+        revoked_at: att.revoked_at,
+        subject_seq: att.subject_seq,
+        use_count: att.use_count,
+        data_hash: att.data_hash.clone(),
+    };
+    e.as_contract(&client.address, || {
+        e.storage()
+            .instance()
+            .set(&DataKey::Attestation(att.id), &legacy);
+    });
+}
+
+#[test]
+fn test_new_attestations_are_stored_as_v2() {
+    let e = Env::default();
+    let (client, _admin, att) = setup(&e);
+    assert_eq!(client.get_attestation_record_version(&att.id), 2);
+    assert_eq!(att.expires_at, 0);
+    assert_eq!(att.flags, 0);
+    assert!(!att.consented);
+    assert_eq!(att.revocation_reason, None);
+}
+
+#[test]
+fn test_v1_record_reads_as_v2_with_defaults() {
+    let e = Env::default();
+    let (client, _admin, att) = setup(&e);
+    store_as_v1(&e, &client, &att);
+    assert_eq!(client.get_attestation_record_version(&att.id), 1);
+
+    assert_eq!(client.get_attestation(&att.id), att);
+    assert_eq!(
+        client.get_valid_attestations(&att.identity, &0, &10).len(),
+        1
+    );
+    // Reading does not rewrite the record.
+    assert_eq!(client.get_attestation_record_version(&att.id), 1);
+}
+
+#[test]
+fn test_v1_record_rewritten_on_next_write() {
+    let e = Env::default();
+    let (client, admin, att) = setup(&e);
+    store_as_v1(&e, &client, &att);
+
+    let reason = Symbol::new(&e, "fraud");
+    client.revoke_attestation_by_admin(&admin, &att.id, &reason);
+    assert_eq!(client.get_attestation_record_version(&att.id), 2);
+    let revoked = client.get_attestation(&att.id);
+    assert!(revoked.revoked);
+    assert_eq!(revoked.revocation_reason, Some(reason));
+    assert_eq!(revoked.attestation_data, att.attestation_data);
+}
+
+#[test]
+fn test_batch_migration_is_idempotent() {
+    let e = Env::default();
+    let (client, admin, att) = setup(&e);
+    let other = client.add_attestation(&att.verifier, &att.identity, &String::from_str(&e, "aml"));
+    store_as_v1(&e, &client, &att);
+    let ids = Vec::from_array(&e, [att.id, other.id, 999]);
+
+    // A dry run reports the rewrite without performing it.
+    assert_eq!(client.migrate_attestation_records(&admin, &ids, &true), 1);
+    assert_eq!(client.get_attestation_record_version(&att.id), 1);
+
+    assert_eq!(client.migrate_attestation_records(&admin, &ids, &false), 1);
+    assert_eq!(client.get_attestation_record_version(&att.id), 2);
+    assert_eq!(client.get_attestation(&att.id), att);
+    assert_eq!(client.get_attestation(&other.id), other);
+
+    assert_eq!(client.migrate_attestation_records(&admin, &ids, &false), 0);
+    assert_eq!(client.get_attestation(&att.id), att);
+}
+
+#[test]
+fn test_dry_run_matches_applied_run() {
+    let e = Env::default();
+    let (client, admin, att) = setup(&e);
+    store_as_v1(&e, &client, &att);
+    let ids = Vec::from_array(&e, [att.id, 999]);
+
+    client.migrate_attestation_records(&admin, &ids, &true);
+    let preview = test_helpers::migration_summary(&e, "migration_preview");
+    client.migrate_attestation_records(&admin, &ids, &false);
+    let applied = test_helpers::migration_summary(&e, "migration_applied");
+    assert_eq!(preview, applied);
+    assert_eq!((applied.1, applied.2, applied.3), (1, 1, 0));
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_batch_migration_admin_only() {
+    let e = Env::default();
+    let (client, _admin, att) = setup(&e);
+    client.migrate_attestation_records(
+        &Address::generate(&e),
+        &Vec::from_array(&e, [att.id]),
+        &false,
+    );
+}
+
+#[test]
+#[should_panic(expected = "too many ids")]
+fn test_batch_migration_size_capped() {
+    let e = Env::default();
+    let (client, admin, _att) = setup(&e);
+    let mut ids = Vec::new(&e);
+    for id in 0..101_u64 {
+        ids.push_back(id);
+    }
+    client.migrate_attestation_records(&admin, &ids, &false);
+}
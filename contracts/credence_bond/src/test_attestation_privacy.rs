@@ -0,0 +1,82 @@
+//! Tests for hash-only attestations and preimage verification.
+
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Bytes, BytesN, Env, String, Symbol};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let client = CredenceBondClient::new(e, &e.register(CredenceBond, ()));
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    (client, admin, attester)
+}
+
+fn enable_privacy(e: &Env, client: &CredenceBondClient, admin: &Address) {
+    client.set_feature(admin, &Symbol::new(e, "attestation_privacy"), &true);
+}
+
+fn commit(e: &Env, payload: &[u8]) -> (Bytes, BytesN<32>) {
+    let preimage = Bytes::from_slice(e, payload);
+    let hash = e.crypto().sha256(&preimage).into();
+    (preimage, hash)
+}
+
+#[test]
+fn test_hash_attestation_round_trip() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    enable_privacy(&e, &client, &admin);
+    let subject = Address::generate(&e);
+    let (_, hash) = commit(&e, b"passport:verified");
+
+    let att = client.add_attestation_hash(&attester, &subject, &hash);
+    let stored = client.get_attestation(&att.id);
+    assert_eq!(stored.data_hash, Some(hash));
+    assert_eq!(stored.attestation_data, String::from_str(&e, ""));
+}
+
+#[test]
+fn test_preimage_verification() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    let (preimage, hash) = commit(&e, b"passport:verified");
+    let att = client.add_attestation_hash(&attester, &Address::generate(&e), &hash);
+
+    assert!(client.verify_attestation_data(&att.id, &preimage));
+    assert!(!client.verify_attestation_data(&att.id, &Bytes::from_slice(&e, b"passport:forged")));
+}
+
+#[test]
+fn test_mixed_mode_subject() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    let subject = Address::generate(&e);
+    let plain = client.add_attestation(&attester, &subject, &String::from_str(&e, "kyc"));
+
+    enable_privacy(&e, &client, &admin);
+    assert!(client
+        .try_add_attestation(&attester, &subject, &String::from_str(&e, "aml"))
+        .is_err());
+    let (preimage, hash) = commit(&e, b"aml");
+    let hashed = client.add_attestation_hash(&attester, &subject, &hash);
+
+    let ids = client.get_subject_attestations(&subject);
+    assert_eq!(ids.len(), 2);
+    assert_eq!(client.get_attestation(&plain.id).data_hash, None);
+    assert!(!client.verify_attestation_data(&plain.id, &preimage));
+    assert!(client.verify_attestation_data(&hashed.id, &preimage));
+}
+
+#[test]
+#[should_panic(expected = "duplicate attestation")]
+fn test_duplicate_hash_rejected() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    let subject = Address::generate(&e);
+    let (_, hash) = commit(&e, b"payload");
+    client.add_attestation_hash(&attester, &subject, &hash);
+    client.add_attestation_hash(&attester, &subject, &hash);
+}
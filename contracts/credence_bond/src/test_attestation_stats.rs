@@ -0,0 +1,70 @@
+//! Schema test for `describe_attestation_stats`.
+
+use crate::valid_attestations::AttestationStats;
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, String, Symbol};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let client = CredenceBondClient::new(e, &e.register(CredenceBond, ()));
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_stats_after_add_and_revoke() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let subject = Address::generate(&e);
+    let first = Address::generate(&e);
+    let second = Address::generate(&e);
+    client.register_attester(&first);
+    client.register_attester(&second);
+
+    e.ledger().with_mut(|li| li.timestamp = 100);
+    let a = client.add_attestation(&first, &subject, &String::from_str(&e, "kyc"));
+    e.ledger().with_mut(|li| li.timestamp = 200);
+    let b = client.add_attestation(&first, &subject, &String::from_str(&e, "aml"));
+    e.ledger().with_mut(|li| li.timestamp = 300);
+    let c = client.add_attestation(&second, &subject, &String::from_str(&e, "kyc"));
+    client.add_attestation(
+        &second,
+        &Address::generate(&e),
+        &String::from_str(&e, "kyc"),
+    );
+    e.ledger().with_mut(|li| li.timestamp = 400);
+    client.revoke_attestation_by_admin(&admin, &b.id, &Symbol::new(&e, "fraud"));
+
+    assert_eq!(
+        client.describe_attestation_stats(&subject),
+        AttestationStats {
+            total: 3,
+            revoked: 1,
+            active: 2,
+            active_weight: u64::from(a.weight) + u64::from(c.weight),
+            newest_timestamp: 300,
+            attester_count: 2,
+        }
+    );
+}
+
+#[test]
+fn test_stats_zeroed_without_attestations() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let stats = client.describe_attestation_stats(&Address::generate(&e));
+    assert_eq!(
+        stats,
+        AttestationStats {
+            total: 0,
+            revoked: 0,
+            active: 0,
+            active_weight: 0,
+            newest_timestamp: 0,
+            attester_count: 0,
+        }
+    );
+    assert!(e.auths().is_empty());
+}
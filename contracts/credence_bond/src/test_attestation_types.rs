@@ -35,6 +35,14 @@ fn attestation_validate_accepts_valid() {
         weight: DEFAULT_ATTESTATION_WEIGHT,
         attestation_data: String::from_str(&e, "x"),
         revoked: false,
+        revoked_at: 0,
+        subject_seq: 1,
+        use_count: 0,
+        data_hash: None,
+        expires_at: 0,
+        flags: 0,
+        consented: false,
+        revocation_reason: None,
     };
     att.validate();
 }
@@ -51,6 +59,14 @@ fn attestation_validate_rejects_zero_weight() {
         weight: 0,
         attestation_data: String::from_str(&e, "x"),
         revoked: false,
+        revoked_at: 0,
+        subject_seq: 1,
+        use_count: 0,
+        data_hash: None,
+        expires_at: 0,
+        flags: 0,
+        consented: false,
+        revocation_reason: None,
     };
     att.validate();
 }
@@ -67,6 +83,14 @@ fn attestation_validate_rejects_over_max_weight() {
         weight: MAX_ATTESTATION_WEIGHT + 1,
         attestation_data: String::from_str(&e, "x"),
         revoked: false,
+        revoked_at: 0,
+        subject_seq: 1,
+        use_count: 0,
+        data_hash: None,
+        expires_at: 0,
+        flags: 0,
+        consented: false,
+        revocation_reason: None,
     };
     att.validate();
 }
@@ -85,6 +109,14 @@ fn attestation_is_active() {
         weight: DEFAULT_ATTESTATION_WEIGHT,
         attestation_data: data,
         revoked: false,
+        revoked_at: 0,
+        subject_seq: 1,
+        use_count: 0,
+        data_hash: None,
+        expires_at: 0,
+        flags: 0,
+        consented: false,
+        revocation_reason: None,
     };
     assert!(att.is_active());
     let mut revoked = att.clone();
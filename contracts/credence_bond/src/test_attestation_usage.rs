@@ -0,0 +1,82 @@
+//! Tests for consumer-reported attestation usage counters.
+
+use crate::attestation_usage::MAX_USES_PER_CALL;
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, u64) {
+    e.mock_all_auths();
+    let client = CredenceBondClient::new(e, &e.register(CredenceBond, ()));
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    let att = client.add_attestation(
+        &attester,
+        &Address::generate(e),
+        &String::from_str(e, "kyc"),
+    );
+    (client, admin, att.id)
+}
+
+#[test]
+fn test_allow_listed_consumer_increments_counts() {
+    let e = Env::default();
+    let (client, admin, id) = setup(&e);
+    let consumer = Address::generate(&e);
+    client.set_attestation_consumer(&admin, &consumer, &true);
+
+    assert_eq!(client.record_attestation_use(&consumer, &id, &None), 1);
+    assert_eq!(client.record_attestation_use(&consumer, &id, &Some(4)), 5);
+
+    assert_eq!(client.get_attestation_usage(&id), 5);
+    assert_eq!(client.get_attestation(&id).use_count, 5);
+    assert_eq!(client.get_consumer_usage(&consumer), 5);
+}
+
+#[test]
+fn test_consumer_counters_are_separate() {
+    let e = Env::default();
+    let (client, admin, id) = setup(&e);
+    let first = Address::generate(&e);
+    let second = Address::generate(&e);
+    client.set_attestation_consumer(&admin, &first, &true);
+    client.set_attestation_consumer(&admin, &second, &true);
+
+    client.record_attestation_use(&first, &id, &Some(2));
+    client.record_attestation_use(&second, &id, &Some(3));
+
+    assert_eq!(client.get_attestation_usage(&id), 5);
+    assert_eq!(client.get_consumer_usage(&first), 2);
+    assert_eq!(client.get_consumer_usage(&second), 3);
+}
+
+#[test]
+#[should_panic(expected = "consumer not allowed")]
+fn test_unlisted_consumer_rejected() {
+    let e = Env::default();
+    let (client, _admin, id) = setup(&e);
+    client.record_attestation_use(&Address::generate(&e), &id, &None);
+}
+
+#[test]
+#[should_panic(expected = "consumer not allowed")]
+fn test_removed_consumer_rejected() {
+    let e = Env::default();
+    let (client, admin, id) = setup(&e);
+    let consumer = Address::generate(&e);
+    client.set_attestation_consumer(&admin, &consumer, &true);
+    client.set_attestation_consumer(&admin, &consumer, &false);
+    client.record_attestation_use(&consumer, &id, &None);
+}
+
+#[test]
+#[should_panic(expected = "invalid use count")]
+fn test_per_call_count_capped() {
+    let e = Env::default();
+    let (client, admin, id) = setup(&e);
+    let consumer = Address::generate(&e);
+    client.set_attestation_consumer(&admin, &consumer, &true);
+    client.record_attestation_use(&consumer, &id, &Some(MAX_USES_PER_CALL + 1));
+}
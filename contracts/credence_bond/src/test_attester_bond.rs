@@ -0,0 +1,137 @@
+//! Tests for the attester bond remaining-duration requirement on `add_attestation`.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, String};
+
+const BOND_AMOUNT: i128 = 10_000_000;
+const BOND_DURATION: u64 = 864_000; // 10 days
+const MIN_REMAINING: u64 = 172_800; // 2 days
+
+/// Setup where the bonded identity is also a registered attester.
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    let (client, admin, attester, _token, _contract_id) = test_helpers::setup_with_token(e);
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.register_attester(&attester);
+    client.create_bond(&attester, &BOND_AMOUNT, &BOND_DURATION, &false, &0);
+    (client, admin, attester)
+}
+
+#[test]
+fn test_config_defaults_to_disabled() {
+    let e = Env::default();
+    let (client, admin, _attester) = setup(&e);
+
+    assert_eq!(client.get_min_attester_bond_remaining(), None);
+    client.set_min_attester_bond_remaining(&admin, &MIN_REMAINING);
+    assert_eq!(
+        client.get_min_attester_bond_remaining(),
+        Some(MIN_REMAINING)
+    );
+    client.clear_attester_bond_remaining(&admin);
+    assert_eq!(client.get_min_attester_bond_remaining(), None);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_set_config_rejects_non_admin() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    client.set_min_attester_bond_remaining(&attester, &MIN_REMAINING);
+}
+
+#[test]
+fn test_attestation_allowed_at_exact_threshold() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.set_min_attester_bond_remaining(&admin, &MIN_REMAINING);
+
+    // Bond ends at 1_000 + BOND_DURATION; exactly MIN_REMAINING seconds remain.
+    e.ledger()
+        .with_mut(|li| li.timestamp = 1_000 + BOND_DURATION - MIN_REMAINING);
+    let subject = Address::generate(&e);
+    let att = client.add_attestation(&attester, &subject, &String::from_str(&e, "kyc"));
+    assert_eq!(att.verifier, attester);
+}
+
+#[test]
+#[should_panic(expected = "attester bond remaining duration too short")]
+fn test_attestation_rejected_one_second_past_threshold() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.set_min_attester_bond_remaining(&admin, &MIN_REMAINING);
+
+    e.ledger()
+        .with_mut(|li| li.timestamp = 1_000 + BOND_DURATION - MIN_REMAINING + 1);
+    let subject = Address::generate(&e);
+    client.add_attestation(&attester, &subject, &String::from_str(&e, "kyc"));
+}
+
+#[test]
+#[should_panic(expected = "attester bond inactive")]
+fn test_attestation_rejected_with_inactive_bond() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+
+    client.withdraw_bond_full(&attester);
+    client.set_min_attester_bond_remaining(&admin, &MIN_REMAINING);
+
+    let subject = Address::generate(&e);
+    client.add_attestation(&attester, &subject, &String::from_str(&e, "kyc"));
+}
+
+#[test]
+#[should_panic(expected = "attester bond inactive")]
+fn test_attestation_rejected_without_own_bond() {
+    let e = Env::default();
+    let (client, admin, _bonded) = setup(&e);
+    client.set_min_attester_bond_remaining(&admin, &MIN_REMAINING);
+
+    let unbonded = Address::generate(&e);
+    client.register_attester(&unbonded);
+    let subject = Address::generate(&e);
+    client.add_attestation(&unbonded, &subject, &String::from_str(&e, "kyc"));
+}
+
+#[test]
+fn test_check_skipped_when_disabled() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.set_min_attester_bond_remaining(&admin, &MIN_REMAINING);
+    client.clear_attester_bond_remaining(&admin);
+
+    // Matured bond and an unbonded attester are both accepted.
+    e.ledger()
+        .with_mut(|li| li.timestamp = 1_000 + BOND_DURATION + 1);
+    let subject = Address::generate(&e);
+    client.add_attestation(&attester, &subject, &String::from_str(&e, "kyc"));
+
+    let unbonded = Address::generate(&e);
+    client.register_attester(&unbonded);
+    client.add_attestation(&unbonded, &subject, &String::from_str(&e, "kyc"));
+}
+
+#[test]
+fn test_remaining_view_explains_rejection() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.set_min_attester_bond_remaining(&admin, &MIN_REMAINING);
+
+    let now = 1_000 + BOND_DURATION - MIN_REMAINING + 1;
+    e.ledger().with_mut(|li| li.timestamp = now);
+    assert_eq!(
+        client.get_attester_bond_remaining(&attester),
+        Some(MIN_REMAINING - 1)
+    );
+
+    let subject = Address::generate(&e);
+    let data = String::from_str(&e, "kyc");
+    assert!(client
+        .try_add_attestation(&attester, &subject, &data)
+        .is_err());
+    assert_eq!(client.get_subject_attestation_count(&subject), 0);
+
+    let unbonded = Address::generate(&e);
+    assert_eq!(client.get_attester_bond_remaining(&unbonded), None);
+}
@@ -0,0 +1,103 @@
+//! Tests that the legacy attester flag and the verifier registry cannot diverge, and the
+//! audit / repair entrypoints for deployments where they already have.
+
+use crate::test_helpers;
+use crate::verifier::AttesterMismatch;
+use crate::{CredenceBondClient, DataKey};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Vec};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    let (client, admin, _identity, _token, contract_id) = test_helpers::setup_with_token(e);
+    (client, admin, contract_id)
+}
+
+#[test]
+fn test_legacy_register_then_staked_deactivate_is_consistent() {
+    let e = Env::default();
+    let (client, _admin, _contract_id) = setup(&e);
+    let attester = Address::generate(&e);
+
+    client.register_attester(&attester);
+    assert!(client.is_attester(&attester));
+    assert!(client.get_verifier_info(&attester).unwrap().active);
+
+    client.deactivate_verifier(&attester);
+    assert!(!client.is_attester(&attester));
+    let addresses = Vec::from_array(&e, [attester]);
+    assert!(client.audit_attester_consistency(&addresses).is_empty());
+}
+
+#[test]
+fn test_unregister_deactivates_staked_verifier() {
+    let e = Env::default();
+    let (client, _admin, _contract_id) = setup(&e);
+    let verifier = Address::generate(&e);
+
+    client.register_verifier(&verifier, &0_i128);
+    client.unregister_attester(&verifier);
+
+    assert!(!client.get_verifier_info(&verifier).unwrap().active);
+    let addresses = Vec::from_array(&e, [verifier]);
+    assert!(client.audit_attester_consistency(&addresses).is_empty());
+}
+
+#[test]
+fn test_audit_finds_seeded_mismatch() {
+    let e = Env::default();
+    let (client, _admin, contract_id) = setup(&e);
+    let consistent = Address::generate(&e);
+    let stale = Address::generate(&e);
+    client.register_attester(&consistent);
+    e.as_contract(&contract_id, || {
+        e.storage()
+            .instance()
+            .set(&DataKey::Attester(stale.clone()), &true);
+    });
+
+    let report =
+        client.audit_attester_consistency(&Vec::from_array(&e, [consistent, stale.clone()]));
+    assert_eq!(
+        report,
+        Vec::from_array(
+            &e,
+            [AttesterMismatch {
+                address: stale,
+                attester_flag: true,
+                role: false,
+                verifier_active: None,
+            }]
+        )
+    );
+}
+
+#[test]
+fn test_repair_fixes_mismatches() {
+    let e = Env::default();
+    let (client, admin, contract_id) = setup(&e);
+    let stale = Address::generate(&e);
+    let dropped = Address::generate(&e);
+    client.register_attester(&dropped);
+    e.as_contract(&contract_id, || {
+        let storage = e.storage().instance();
+        storage.set(&DataKey::Attester(stale.clone()), &true);
+        storage.remove(&DataKey::Attester(dropped.clone()));
+    });
+    let addresses = Vec::from_array(&e, [stale.clone(), dropped.clone()]);
+    assert_eq!(client.audit_attester_consistency(&addresses).len(), 2);
+
+    assert_eq!(client.repair_attester_flags(&admin, &addresses), 2);
+    assert!(client.audit_attester_consistency(&addresses).is_empty());
+    assert!(!client.is_attester(&stale));
+    assert!(client.is_attester(&dropped));
+    assert_eq!(client.repair_attester_flags(&admin, &addresses), 0);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_repair_requires_admin() {
+    let e = Env::default();
+    let (client, _admin, _contract_id) = setup(&e);
+    let other = Address::generate(&e);
+    client.repair_attester_flags(&other, &Vec::new(&e));
+}
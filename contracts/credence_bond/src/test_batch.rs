@@ -35,8 +35,8 @@ fn test_create_single_bond_in_batch() {
     assert_eq!(bond.identity, identity);
     assert_eq!(bond.bonded_amount, 1000);
     assert_eq!(bond.bond_duration, 86400);
-    assert_eq!(bond.active, true);
-    assert_eq!(bond.is_rolling, false);
+    assert!(bond.active);
+    assert!(!bond.is_rolling);
 }
 
 #[test]
@@ -330,13 +330,13 @@ fn test_duplicate_bond_in_batch_fails() {
     let (client, _admin, identity, _token, _contract_id) = setup_with_token(&env);
 
     // Create first bond
-    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.create_bond(&identity, &1_000_000, &86400, &false, &0);
 
     // Try to create another bond (will fail because bond already exists)
     let mut params_list = Vec::new(&env);
     params_list.push_back(BatchBondParams {
         identity: identity.clone(),
-        amount: 2000,
+        amount: 2_000_000,
         duration: 86400,
         is_rolling: false,
         notice_period_duration: 0,
@@ -369,8 +369,8 @@ fn test_batch_with_rolling_bonds() {
 
     assert_eq!(result.created_count, 1);
     let bond = result.bonds.get(0).unwrap();
-    assert_eq!(bond.is_rolling, true);
-    assert_eq!(bond.notice_period_duration, 7200);
+    assert!(bond.is_rolling);
+    assert_eq!(bond.notice_period, 7200);
     assert_eq!(bond.withdrawal_requested_at, 0);
 }
 
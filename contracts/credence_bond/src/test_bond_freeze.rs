@@ -0,0 +1,146 @@
+//! Tests for admin bond freezes: frozen bonds refuse withdrawals and transfers, on the identity's
+//! additional bonds too, but can still be slashed and topped up, and unfreezing restores the
+//! previous behaviour.
+
+use crate::test_helpers;
+use crate::BondStatus;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, Symbol};
+
+const AMOUNT: i128 = 10_000_000;
+const DURATION: u64 = 86_400;
+
+fn reason(e: &Env) -> Symbol {
+    Symbol::new(e, "investigation")
+}
+
+#[test]
+#[should_panic(expected = "bond frozen")]
+fn test_frozen_bond_cannot_withdraw() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    client.freeze_bond(&admin, &identity, &reason(&e));
+    e.ledger().with_mut(|li| li.timestamp = DURATION);
+    client.withdraw(&identity, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "bond frozen")]
+fn test_frozen_bond_cannot_withdraw_early() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    client.freeze_bond(&admin, &identity, &reason(&e));
+    client.withdraw_early(&identity, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "bond frozen")]
+fn test_frozen_bond_cannot_request_withdrawal() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &true, &100_u64);
+    client.freeze_bond(&admin, &identity, &reason(&e));
+    client.request_withdrawal(&identity);
+}
+
+#[test]
+#[should_panic(expected = "bond frozen")]
+fn test_frozen_bond_cannot_transfer() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    client.freeze_bond(&admin, &identity, &reason(&e));
+    client.transfer_bond(&identity, &Address::generate(&e), &false);
+}
+
+#[test]
+#[should_panic(expected = "bond frozen")]
+fn test_frozen_identity_cannot_withdraw_additional_bond() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    let bond_id = client.create_bond_v2(&identity, &AMOUNT, &DURATION);
+    client.freeze_bond(&admin, &identity, &reason(&e));
+    e.ledger().with_mut(|li| li.timestamp = DURATION);
+    client.withdraw_bond_v2(&identity, &bond_id, &AMOUNT);
+}
+
+#[test]
+fn test_unfreeze_releases_additional_bond() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    let bond_id = client.create_bond_v2(&identity, &AMOUNT, &DURATION);
+    client.freeze_bond(&admin, &identity, &reason(&e));
+    client.unfreeze_bond(&admin, &identity);
+    e.ledger().with_mut(|li| li.timestamp = DURATION);
+    let bond = client.withdraw_bond_v2(&identity, &bond_id, &AMOUNT);
+    assert_eq!(bond.status, BondStatus::Closed);
+}
+
+#[test]
+fn test_frozen_bond_can_be_slashed_and_topped_up() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    let bond = client.freeze_bond(&admin, &identity, &reason(&e));
+    assert_eq!(bond.status, BondStatus::Frozen);
+    assert_eq!(client.get_freeze_reason(&identity), Some(reason(&e)));
+
+    let bond = client.slash(&admin, &identity, &1_000);
+    assert_eq!(bond.slashed_amount, 1_000);
+    assert_eq!(bond.status, BondStatus::Frozen);
+    let bond = client.top_up(&identity, &identity, &AMOUNT);
+    assert_eq!(bond.bonded_amount, 2 * AMOUNT);
+}
+
+#[test]
+fn test_unfreeze_restores_withdrawal() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    client.freeze_bond(&admin, &identity, &reason(&e));
+    let bond = client.unfreeze_bond(&admin, &identity);
+    assert_eq!(bond.status, BondStatus::Active);
+    assert_eq!(client.get_freeze_reason(&identity), None);
+
+    e.ledger().with_mut(|li| li.timestamp = DURATION);
+    let bond = client.withdraw(&identity, &1_000);
+    assert_eq!(bond.bonded_amount, AMOUNT - 1_000);
+}
+
+#[test]
+fn test_unfreeze_keeps_pending_notice() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    // A request timestamp of 0 reads as "no request", so move off the genesis ledger time.
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &true, &100_u64);
+    client.request_withdrawal(&identity);
+    client.freeze_bond(&admin, &identity, &reason(&e));
+
+    let bond = client.unfreeze_bond(&admin, &identity);
+    assert_eq!(bond.status, BondStatus::NoticeServing);
+    e.ledger().with_mut(|li| li.timestamp = 1_100);
+    client.withdraw_bond(&identity, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "bond not frozen")]
+fn test_unfreeze_requires_frozen_bond() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    client.unfreeze_bond(&admin, &identity);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_freeze_requires_admin() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    client.freeze_bond(&Address::generate(&e), &identity, &reason(&e));
+}
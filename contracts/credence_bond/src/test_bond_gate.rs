@@ -0,0 +1,135 @@
+//! Tests for the attestation gate on bond creation (e.g. KYC before bonding).
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String, Symbol};
+
+const BOND_AMOUNT: i128 = 10_000_000;
+const BOND_DURATION: u64 = 86_400;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address) {
+    let (client, admin, identity, _token, _contract_id) = test_helpers::setup_with_token(e);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    (client, admin, identity, attester)
+}
+
+fn gate_on_kyc(e: &Env, client: &CredenceBondClient<'_>, admin: &Address) {
+    client.set_bond_gate(admin, &Symbol::new(e, "kyc"), &1);
+}
+
+#[test]
+fn test_gate_config_round_trip() {
+    let e = Env::default();
+    let (client, admin, _identity, _attester) = setup(&e);
+
+    assert!(client.get_bond_gate().is_none());
+    client.set_bond_gate(&admin, &Symbol::new(&e, "kyc"), &5);
+    let gate = client.get_bond_gate().unwrap();
+    assert_eq!(gate.category, Symbol::new(&e, "kyc"));
+    assert_eq!(gate.min_weight, 5);
+
+    client.clear_bond_gate(&admin);
+    assert!(client.get_bond_gate().is_none());
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_set_gate_rejects_non_admin() {
+    let e = Env::default();
+    let (client, _admin, identity, _attester) = setup(&e);
+    client.set_bond_gate(&identity, &Symbol::new(&e, "kyc"), &1);
+}
+
+#[test]
+#[should_panic(expected = "bond gate not satisfied")]
+fn test_create_bond_blocked_without_attestation() {
+    let e = Env::default();
+    let (client, admin, identity, _attester) = setup(&e);
+    gate_on_kyc(&e, &client, &admin);
+
+    client.create_bond(&identity, &BOND_AMOUNT, &BOND_DURATION, &false, &0);
+}
+
+#[test]
+#[should_panic(expected = "bond gate not satisfied")]
+fn test_create_bond_blocked_by_other_category() {
+    let e = Env::default();
+    let (client, admin, identity, attester) = setup(&e);
+    gate_on_kyc(&e, &client, &admin);
+
+    client.add_attestation(&attester, &identity, &String::from_str(&e, "aml"));
+    client.create_bond(&identity, &BOND_AMOUNT, &BOND_DURATION, &false, &0);
+}
+
+#[test]
+fn test_create_bond_allowed_with_attestation() {
+    let e = Env::default();
+    let (client, admin, identity, attester) = setup(&e);
+    gate_on_kyc(&e, &client, &admin);
+
+    client.add_attestation(&attester, &identity, &String::from_str(&e, "kyc"));
+    let bond = client.create_bond(&identity, &BOND_AMOUNT, &BOND_DURATION, &false, &0);
+    assert!(bond.active);
+    assert_eq!(bond.identity, identity);
+}
+
+#[test]
+#[should_panic(expected = "bond gate not satisfied")]
+fn test_create_bond_blocked_below_min_weight() {
+    let e = Env::default();
+    let (client, admin, identity, attester) = setup(&e);
+    client.set_bond_gate(&admin, &Symbol::new(&e, "kyc"), &5);
+
+    // Unstaked attesters issue the default weight of 1.
+    client.add_attestation(&attester, &identity, &String::from_str(&e, "kyc"));
+    client.create_bond(&identity, &BOND_AMOUNT, &BOND_DURATION, &false, &0);
+}
+
+#[test]
+fn test_create_bond_allowed_at_min_weight() {
+    let e = Env::default();
+    let (client, admin, identity, attester) = setup(&e);
+    client.set_bond_gate(&admin, &Symbol::new(&e, "kyc"), &5);
+
+    // weight = stake * 100 bps / 10_000 = 5
+    client.set_attester_stake(&admin, &attester, &500);
+    let att = client.add_attestation(&attester, &identity, &String::from_str(&e, "kyc"));
+    assert_eq!(att.weight, 5);
+    client.create_bond(&identity, &BOND_AMOUNT, &BOND_DURATION, &false, &0);
+}
+
+#[test]
+#[should_panic(expected = "bond gate not satisfied")]
+fn test_create_bond_blocked_after_revocation() {
+    let e = Env::default();
+    let (client, admin, identity, attester) = setup(&e);
+    gate_on_kyc(&e, &client, &admin);
+
+    let att = client.add_attestation(&attester, &identity, &String::from_str(&e, "kyc"));
+    client.revoke_attestation(&attester, &att.id);
+    client.create_bond(&identity, &BOND_AMOUNT, &BOND_DURATION, &false, &0);
+}
+
+#[test]
+fn test_create_bond_unaffected_when_gate_cleared() {
+    let e = Env::default();
+    let (client, admin, identity, _attester) = setup(&e);
+    gate_on_kyc(&e, &client, &admin);
+    client.clear_bond_gate(&admin);
+
+    let bond = client.create_bond(&identity, &BOND_AMOUNT, &BOND_DURATION, &false, &0);
+    assert!(bond.active);
+}
+
+#[test]
+fn test_existing_bond_unaffected_by_new_gate() {
+    let e = Env::default();
+    let (client, admin, identity, _attester) = setup(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &BOND_DURATION, &false, &0);
+
+    gate_on_kyc(&e, &client, &admin);
+    let bond = client.top_up(&identity, &identity, &BOND_AMOUNT);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT * 2);
+}
@@ -0,0 +1,154 @@
+//! Tests for per-identity bond storage and migration of the legacy singleton bond.
+
+use crate::legacy_bond::{LegacyIdentityBond, LegacyKey};
+use crate::test_helpers;
+use crate::{BondKind, BondStatus};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, Vec};
+
+const AMOUNT: i128 = 10_000_000;
+const DURATION: u64 = 86_400;
+
+fn fund(e: &Env, token: &Address, bond_contract: &Address, who: &Address, amount: i128) {
+    StellarAssetClient::new(e, token).mint(who, &amount);
+    let expiration = e.ledger().sequence().saturating_add(10_000);
+    TokenClient::new(e, token).approve(who, bond_contract, &amount, &expiration);
+}
+
+#[test]
+fn test_bonds_are_independent() {
+    let e = Env::default();
+    let (client, admin, alice, token, contract_id) = test_helpers::setup_with_token(&e);
+    let bob = Address::generate(&e);
+    fund(&e, &token, &contract_id, &bob, 2 * AMOUNT);
+
+    client.create_bond(&alice, &AMOUNT, &DURATION, &false, &0_u64);
+    client.create_bond(&bob, &(2 * AMOUNT), &DURATION, &false, &0_u64);
+
+    let slashed = client.slash(&admin, &alice, &1_000);
+    assert_eq!(slashed.identity, alice);
+    assert_eq!(slashed.slashed_amount, 1_000);
+
+    let bob_bond = client.get_identity_state(&bob);
+    assert_eq!(bob_bond.bonded_amount, 2 * AMOUNT);
+    assert_eq!(bob_bond.slashed_amount, 0);
+
+    e.ledger().with_mut(|li| li.timestamp = DURATION);
+    client.withdraw(&bob, &AMOUNT);
+    assert_eq!(client.get_identity_state(&bob).bonded_amount, AMOUNT);
+    assert_eq!(client.get_identity_state(&alice).bonded_amount, AMOUNT);
+}
+
+#[test]
+fn test_governance_slash_targets_one_identity() {
+    let e = Env::default();
+    let (client, admin, alice, token, contract_id) = test_helpers::setup_with_token(&e);
+    let bob = Address::generate(&e);
+    fund(&e, &token, &contract_id, &bob, AMOUNT);
+    client.create_bond(&alice, &AMOUNT, &DURATION, &false, &0_u64);
+    client.create_bond(&bob, &AMOUNT, &DURATION, &false, &0_u64);
+
+    let governor = Address::generate(&e);
+    client.initialize_governance(&admin, &Vec::from_array(&e, [governor.clone()]), &5_100, &1);
+    let id = client.propose_slash(&admin, &bob, &2_500);
+    client.governance_vote(&governor, &id, &true);
+
+    let bond = client.execute_slash_with_governance(&admin, &id);
+    assert_eq!(bond.identity, bob);
+    assert_eq!(bond.slashed_amount, 2_500);
+    assert_eq!(client.get_identity_state(&alice).slashed_amount, 0);
+}
+
+/// Seeds the singleton key with a bond in the pre-`kind`/`status` layout.
+fn seed_legacy_bond(e: &Env, contract_id: &Address, legacy: &LegacyIdentityBond) {
+    e.as_contract(contract_id, || {
+        e.storage().instance().set(&LegacyKey::Bond, legacy);
+    });
+}
+
+fn legacy_bond(identity: &Address) -> LegacyIdentityBond {
+    LegacyIdentityBond {
+        identity: identity.clone(),
+        bonded_amount: AMOUNT,
+        bond_start: 0,
+        bond_duration: DURATION,
+        slashed_amount: 100,
+        active: true,
+        is_rolling: false,
+        withdrawal_requested_at: 0,
+        notice_period: 0,
+    }
+}
+
+#[test]
+fn test_migrate_legacy_bond() {
+    let e = Env::default();
+    let (client, admin, identity, _token, contract_id) = test_helpers::setup_with_token(&e);
+    seed_legacy_bond(&e, &contract_id, &legacy_bond(&identity));
+
+    assert!(client.has_legacy_bond());
+    assert!(client.try_get_identity_state(&identity).is_err());
+
+    let migrated = client.migrate_legacy_bond(&admin, &false).unwrap();
+    assert_eq!(migrated.identity, identity);
+    assert!(!client.has_legacy_bond());
+    let bond = client.get_identity_state(&identity);
+    assert_eq!(bond, migrated);
+    assert_eq!(bond.bonded_amount, AMOUNT);
+    assert_eq!(bond.slashed_amount, 100);
+    assert_eq!(bond.kind, BondKind::Fixed);
+    assert_eq!(bond.status, BondStatus::Active);
+
+    assert!(client.migrate_legacy_bond(&admin, &false).is_none());
+}
+
+#[test]
+fn test_migrate_legacy_bond_maps_rolling_and_inactive() {
+    let e = Env::default();
+    let (client, admin, identity, _token, contract_id) = test_helpers::setup_with_token(&e);
+    let mut legacy = legacy_bond(&identity);
+    legacy.is_rolling = true;
+    legacy.active = false;
+    seed_legacy_bond(&e, &contract_id, &legacy);
+
+    let bond = client.migrate_legacy_bond(&admin, &false).unwrap();
+    assert_eq!(bond.kind, BondKind::Rolling);
+    assert_eq!(bond.status, BondStatus::Closed);
+}
+
+#[test]
+fn test_migrate_legacy_bond_dry_run_matches_applied_run() {
+    let e = Env::default();
+    let (client, admin, identity, _token, contract_id) = test_helpers::setup_with_token(&e);
+    let legacy = legacy_bond(&identity);
+    seed_legacy_bond(&e, &contract_id, &legacy);
+
+    let preview_bond = client.migrate_legacy_bond(&admin, &true).unwrap();
+    let preview = test_helpers::migration_summary(&e, "migration_preview");
+    assert_eq!(preview.1, 1);
+    assert_eq!(preview.2, 1);
+    assert_eq!(preview.3, 1);
+
+    // Neither key was touched.
+    let stored: Option<LegacyIdentityBond> = e.as_contract(&contract_id, || {
+        e.storage().instance().get(&LegacyKey::Bond)
+    });
+    assert_eq!(stored, Some(legacy));
+    assert!(client.try_get_identity_state(&identity).is_err());
+
+    let applied_bond = client.migrate_legacy_bond(&admin, &false).unwrap();
+    let applied = test_helpers::migration_summary(&e, "migration_applied");
+    assert_eq!(preview, applied);
+    assert_eq!(preview_bond, applied_bond);
+}
+
+#[test]
+#[should_panic(expected = "identity already has a bond")]
+fn test_migrate_legacy_bond_conflict() {
+    let e = Env::default();
+    let (client, admin, identity, _token, contract_id) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    seed_legacy_bond(&e, &contract_id, &legacy_bond(&identity));
+    client.migrate_legacy_bond(&admin, &true);
+}
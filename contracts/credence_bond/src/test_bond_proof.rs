@@ -0,0 +1,79 @@
+//! Tests for bond ownership proofs: digest recomputation, tamper detection and freshness.
+
+use crate::bond_proof::{self, BondProof};
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+const AMOUNT: i128 = 10_000_000;
+const ISSUED_AT: u64 = 5_000;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, BondProof) {
+    e.ledger().with_mut(|li| {
+        li.timestamp = ISSUED_AT;
+        li.sequence_number = 42;
+    });
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &AMOUNT, &86_400_u64, &false, &0_u64);
+    let proof = client.get_bond_proof(&identity);
+    (client, identity, proof)
+}
+
+#[test]
+fn test_proof_digest_recomputes() {
+    let e = Env::default();
+    let (client, identity, proof) = setup(&e);
+
+    assert_eq!(proof.contract, client.address);
+    assert_eq!(proof.bond, client.get_identity_state(&identity));
+    assert_eq!(proof.context.ledger_seq, 42);
+    assert_eq!(proof.context.timestamp, ISSUED_AT);
+    let digest = bond_proof::digest(&e, &proof.contract, &proof.bond, &proof.context);
+    assert_eq!(digest, proof.digest);
+    assert!(client.is_proof_fresh(&proof, &0));
+}
+
+#[test]
+fn test_tampered_proof_rejected() {
+    let e = Env::default();
+    let (client, _identity, proof) = setup(&e);
+
+    let mut inflated = proof.clone();
+    inflated.bond.bonded_amount = AMOUNT * 100;
+    assert!(!client.is_proof_fresh(&inflated, &u64::MAX));
+
+    let mut other_holder = proof.clone();
+    other_holder.bond.identity = Address::generate(&e);
+    assert!(!client.is_proof_fresh(&other_holder, &u64::MAX));
+
+    let mut postdated = proof.clone();
+    postdated.context.timestamp = ISSUED_AT + 1_000;
+    assert!(!client.is_proof_fresh(&postdated, &u64::MAX));
+}
+
+#[test]
+fn test_freshness_window() {
+    let e = Env::default();
+    let (client, _identity, proof) = setup(&e);
+
+    e.ledger().with_mut(|li| li.timestamp = ISSUED_AT + 600);
+    assert!(client.is_proof_fresh(&proof, &600));
+    assert!(!client.is_proof_fresh(&proof, &599));
+}
+
+#[test]
+fn test_proof_from_other_contract_rejected() {
+    let e = Env::default();
+    let (_client, _identity, proof) = setup(&e);
+    let (other, ..) = test_helpers::setup_with_token(&e);
+    assert!(!other.is_proof_fresh(&proof, &u64::MAX));
+}
+
+#[test]
+#[should_panic(expected = "no bond")]
+fn test_proof_requires_bond() {
+    let e = Env::default();
+    let (client, ..) = setup(&e);
+    client.get_bond_proof(&Address::generate(&e));
+}
@@ -0,0 +1,115 @@
+//! Tests for rewriting per-holder bonds stored before the `kind` and `status` fields existed:
+//! such a record does not decode until migrated, a dry run previews the rewrite without
+//! performing it, and the batch migration is idempotent.
+
+use crate::legacy_bond::LegacyIdentityBond;
+use crate::test_helpers;
+use crate::{BondKind, BondStatus, CredenceBondClient, DataKey, IdentityBond};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Vec};
+
+const AMOUNT: i128 = 10_000_000;
+const DURATION: u64 = 86_400;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, IdentityBond) {
+    let (client, admin, identity, _token, _contract_id) = test_helpers::setup_with_token(e);
+    let bond = client.create_bond(&identity, &AMOUNT, &DURATION, &true, &DURATION);
+    (client, admin, bond)
+}
+
+/// Overwrites `bond` with the layout an older contract version stored.
+fn store_as_v1(e: &Env, client: &CredenceBondClient, bond: &IdentityBond) {
+    let legacy = LegacyIdentityBond {
+        identity: bond.identity.clone(),
+        bonded_amount: bond.bonded_amount,
+        bond_start: bond.bond_start,
+        bond_duration: bond.bond_duration,
+        slashed_amount: bond.slashed_amount,
+        active: bond.active,
+        is_rolling: bond.is_rolling,
+        withdrawal_requested_at: bond.withdrawal_requested_at,
+        notice_period: bond.notice_period,
+    };
+    e.as_contract(&client.address, || {
+        e.storage()
+            .instance()
+            .set(&DataKey::Bond(bond.identity.clone()), &legacy);
+    });
+}
+
+#[test]
+fn test_new_bonds_are_stored_as_v2() {
+    let e = Env::default();
+    let (client, _admin, bond) = setup(&e);
+    assert_eq!(client.get_bond_record_version(&bond.identity), 2);
+}
+
+#[test]
+fn test_v1_record_unreadable_until_migrated() {
+    let e = Env::default();
+    let (client, admin, bond) = setup(&e);
+    store_as_v1(&e, &client, &bond);
+    assert_eq!(client.get_bond_record_version(&bond.identity), 1);
+    assert!(client.try_get_identity_state(&bond.identity).is_err());
+
+    let ids = Vec::from_array(&e, [bond.identity.clone()]);
+    assert_eq!(client.migrate_bond_records(&admin, &ids, &false), 1);
+    assert_eq!(client.get_bond_record_version(&bond.identity), 2);
+    let migrated = client.get_identity_state(&bond.identity);
+    assert_eq!(migrated, bond);
+    assert_eq!(migrated.kind, BondKind::Rolling);
+    assert_eq!(migrated.status, BondStatus::Active);
+}
+
+#[test]
+fn test_dry_run_matches_applied_run() {
+    let e = Env::default();
+    let (client, admin, bond) = setup(&e);
+    store_as_v1(&e, &client, &bond);
+    let ids = Vec::from_array(&e, [bond.identity.clone(), Address::generate(&e)]);
+
+    assert_eq!(client.migrate_bond_records(&admin, &ids, &true), 1);
+    let preview = test_helpers::migration_summary(&e, "migration_preview");
+    assert_eq!(client.get_bond_record_version(&bond.identity), 1);
+
+    assert_eq!(client.migrate_bond_records(&admin, &ids, &false), 1);
+    let applied = test_helpers::migration_summary(&e, "migration_applied");
+    assert_eq!(preview, applied);
+    assert_eq!((applied.1, applied.2, applied.3), (1, 1, 0));
+}
+
+#[test]
+fn test_batch_migration_is_idempotent() {
+    let e = Env::default();
+    let (client, admin, bond) = setup(&e);
+    store_as_v1(&e, &client, &bond);
+    let ids = Vec::from_array(&e, [bond.identity.clone(), bond.identity.clone()]);
+
+    assert_eq!(client.migrate_bond_records(&admin, &ids, &false), 1);
+    assert_eq!(client.migrate_bond_records(&admin, &ids, &false), 0);
+    assert_eq!(client.get_identity_state(&bond.identity), bond);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_batch_migration_admin_only() {
+    let e = Env::default();
+    let (client, _admin, bond) = setup(&e);
+    client.migrate_bond_records(
+        &Address::generate(&e),
+        &Vec::from_array(&e, [bond.identity.clone()]),
+        &false,
+    );
+}
+
+#[test]
+#[should_panic(expected = "too many identities")]
+fn test_batch_migration_size_capped() {
+    let e = Env::default();
+    let (client, admin, _bond) = setup(&e);
+    let mut ids = Vec::new(&e);
+    for _ in 0..101 {
+        ids.push_back(Address::generate(&e));
+    }
+    client.migrate_bond_records(&admin, &ids, &false);
+}
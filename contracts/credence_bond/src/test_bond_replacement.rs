@@ -0,0 +1,73 @@
+//! Tests that `create_bond` never overwrites an active bond and reports replaced ones.
+
+use crate::test_helpers;
+use crate::IdentityBond;
+use soroban_sdk::testutils::{Events, Ledger};
+use soroban_sdk::{Address, Env, FromVal, Symbol};
+
+const AMOUNT: i128 = 10_000_000;
+const DURATION: u64 = 86_400;
+
+/// Old bond carried by the `bond_replaced` event of the last invocation, if one was emitted.
+fn replaced_bond(e: &Env, contract_id: &Address) -> Option<IdentityBond> {
+    e.events()
+        .all()
+        .iter()
+        .find(|(id, topics, _)| {
+            id == contract_id
+                && Symbol::from_val(e, &topics.get(0).unwrap()) == Symbol::new(e, "bond_replaced")
+        })
+        .map(|(_, _, data)| IdentityBond::from_val(e, &data))
+}
+
+#[test]
+fn test_recreate_after_partial_slash_rejected() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    client.slash(&admin, &identity, &(AMOUNT / 2));
+
+    let result = client.try_create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    assert!(result.is_err());
+    assert_eq!(
+        client.get_identity_state(&identity).slashed_amount,
+        AMOUNT / 2
+    );
+}
+
+#[test]
+fn test_recreate_after_withdrawal_replaces_bond() {
+    let e = Env::default();
+    let (client, _admin, identity, _token, contract_id) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    e.ledger().with_mut(|li| li.timestamp = DURATION);
+    client.withdraw_bond(&identity, &AMOUNT);
+
+    let bond = client.create_bond(&identity, &(2 * AMOUNT), &DURATION, &false, &0_u64);
+    assert_eq!(bond.bonded_amount, 2 * AMOUNT);
+    assert_eq!(bond.slashed_amount, 0);
+    let old = replaced_bond(&e, &contract_id).unwrap();
+    assert_eq!(old.bonded_amount, 0);
+    assert_eq!(old.bond_start, 0);
+}
+
+#[test]
+fn test_recreate_after_full_slash_replaces_bond() {
+    let e = Env::default();
+    let (client, admin, identity, _token, contract_id) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    client.slash(&admin, &identity, &AMOUNT);
+
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    let old = replaced_bond(&e, &contract_id).unwrap();
+    assert_eq!(old.slashed_amount, AMOUNT);
+    assert_eq!(client.get_identity_state(&identity).slashed_amount, 0);
+}
+
+#[test]
+fn test_first_bond_emits_no_replacement() {
+    let e = Env::default();
+    let (client, _admin, identity, _token, contract_id) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    assert!(replaced_bond(&e, &contract_id).is_none());
+}
@@ -0,0 +1,148 @@
+//! Tests for bond rewards paid from the fee pool: pro-rata accrual, the pool cap, repeated
+//! claims, a zero rate and settlement on principal changes.
+
+use crate::bond_rewards::SECONDS_PER_YEAR;
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, Env};
+
+const AMOUNT: i128 = 100_000_000;
+const RATE_BPS: u32 = 1_000;
+const START: u64 = 1_000;
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    admin: Address,
+    identity: Address,
+    token: TokenClient<'a>,
+}
+
+/// A bond of `AMOUNT` at a 10% yearly rate, with `pool` tokens in the fee pool.
+fn setup(e: &Env, pool: i128) -> Setup<'_> {
+    e.ledger().with_mut(|li| li.timestamp = START);
+    let (client, admin, identity, token_id, _contract_id) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &AMOUNT, &SECONDS_PER_YEAR, &false, &0_u64);
+    if pool > 0 {
+        client.deposit_fees(&identity, &pool);
+    }
+    client.set_reward_rate(&admin, &RATE_BPS);
+    Setup {
+        client,
+        admin,
+        identity,
+        token: TokenClient::new(e, &token_id),
+    }
+}
+
+fn advance(e: &Env, secs: u64) {
+    e.ledger().with_mut(|li| li.timestamp += secs);
+}
+
+#[test]
+fn test_claim_pays_pro_rata_rewards() {
+    let e = Env::default();
+    let s = setup(&e, AMOUNT);
+    advance(&e, SECONDS_PER_YEAR / 2);
+    assert_eq!(s.client.get_accrued_rewards(&s.identity), 5_000_000);
+
+    let before = s.token.balance(&s.identity);
+    assert_eq!(s.client.claim_rewards(&s.identity), 5_000_000);
+    assert_eq!(s.token.balance(&s.identity), before + 5_000_000);
+    assert_eq!(
+        s.client.get_last_reward_claim(&s.identity),
+        START + SECONDS_PER_YEAR / 2
+    );
+
+    // The next claim accrues from the last one, not from `bond_start`.
+    advance(&e, SECONDS_PER_YEAR / 4);
+    assert_eq!(s.client.claim_rewards(&s.identity), 2_500_000);
+    assert_eq!(s.client.collect_fees(&s.admin), AMOUNT - 7_500_000);
+}
+
+#[test]
+fn test_claim_capped_at_depleted_pool() {
+    let e = Env::default();
+    let pool = 3_000_000;
+    let s = setup(&e, pool);
+    advance(&e, SECONDS_PER_YEAR);
+    assert_eq!(s.client.get_accrued_rewards(&s.identity), 10_000_000);
+
+    let before = s.token.balance(&s.identity);
+    assert_eq!(s.client.claim_rewards(&s.identity), pool);
+    assert_eq!(s.token.balance(&s.identity), before + pool);
+    assert_eq!(s.client.collect_fees(&s.admin), 0);
+
+    // The shortfall is forfeited and an empty pool pays nothing.
+    advance(&e, SECONDS_PER_YEAR / 2);
+    assert_eq!(s.client.claim_rewards(&s.identity), 0);
+    assert_eq!(s.token.balance(&s.identity), before + pool);
+}
+
+#[test]
+fn test_second_claim_in_same_ledger_pays_nothing() {
+    let e = Env::default();
+    let s = setup(&e, AMOUNT);
+    advance(&e, SECONDS_PER_YEAR);
+    assert_eq!(s.client.claim_rewards(&s.identity), 10_000_000);
+
+    let before = s.token.balance(&s.identity);
+    assert_eq!(s.client.claim_rewards(&s.identity), 0);
+    assert_eq!(s.token.balance(&s.identity), before);
+    assert_eq!(s.client.collect_fees(&s.admin), AMOUNT - 10_000_000);
+}
+
+#[test]
+fn test_zero_rate_accrues_nothing() {
+    let e = Env::default();
+    let s = setup(&e, AMOUNT);
+    s.client.set_reward_rate(&s.admin, &0);
+    advance(&e, SECONDS_PER_YEAR);
+    assert_eq!(s.client.get_accrued_rewards(&s.identity), 0);
+
+    let before = s.token.balance(&s.identity);
+    assert_eq!(s.client.claim_rewards(&s.identity), 0);
+    assert_eq!(s.token.balance(&s.identity), before);
+    assert_eq!(
+        s.client.get_last_reward_claim(&s.identity),
+        START + SECONDS_PER_YEAR
+    );
+    assert_eq!(s.client.collect_fees(&s.admin), AMOUNT);
+}
+
+#[test]
+fn test_top_up_before_claim_earns_nothing_for_the_past() {
+    let e = Env::default();
+    let s = setup(&e, 10 * AMOUNT);
+    advance(&e, SECONDS_PER_YEAR / 2);
+    s.client.top_up(&s.identity, &s.identity, &(9 * AMOUNT));
+
+    // Only the half year on the original principal has been earned so far.
+    assert_eq!(s.client.get_accrued_rewards(&s.identity), 5_000_000);
+    assert_eq!(s.client.claim_rewards(&s.identity), 5_000_000);
+
+    // From the top-up on, the larger principal accrues.
+    advance(&e, SECONDS_PER_YEAR / 2);
+    assert_eq!(s.client.claim_rewards(&s.identity), 50_000_000);
+}
+
+#[test]
+fn test_slash_keeps_rewards_earned_before_it() {
+    let e = Env::default();
+    let s = setup(&e, AMOUNT);
+    advance(&e, SECONDS_PER_YEAR / 2);
+    s.client.slash(&s.admin, &s.identity, &(AMOUNT / 2));
+    advance(&e, SECONDS_PER_YEAR / 2);
+
+    // 5% on the full bond, then 5% on the half left after the slash.
+    assert_eq!(s.client.claim_rewards(&s.identity), 7_500_000);
+}
+
+#[test]
+#[should_panic(expected = "reward rate too high")]
+fn test_rate_above_max_rejected() {
+    let e = Env::default();
+    let s = setup(&e, 0);
+    s.client.set_reward_rate(&s.admin, &10_001);
+}
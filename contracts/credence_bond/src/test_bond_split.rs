@@ -0,0 +1,148 @@
+//! Tests for splitting a bond into tranches: amounts and slash proration, the unlock guard and
+//! rolling tranche withdrawals.
+
+use crate::test_helpers;
+use crate::validation::MIN_BOND_AMOUNT;
+use crate::BondKind;
+use soroban_sdk::testutils::{Events, Ledger};
+use soroban_sdk::{vec, Env, FromVal, Symbol};
+
+const DAY: u64 = 86_400;
+const AMOUNT: i128 = 10_000_000_000;
+
+#[test]
+fn test_split_rolling_bond_into_long_commitment() {
+    let e = Env::default();
+    let (client, _admin, identity, _token, contract_id) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &(30 * DAY), &true, &(30 * DAY));
+
+    let id = client.split_bond(&identity, &7_000_000_000, &(365 * DAY), &false, &0);
+    // Read before any further call replaces the recorded events.
+    let splits = e
+        .events()
+        .all()
+        .iter()
+        .filter(|(cid, topics, _)| {
+            *cid == contract_id
+                && Symbol::from_val(&e, &topics.get(0).unwrap()) == Symbol::new(&e, "bond_split")
+        })
+        .count();
+    assert_eq!(splits, 1);
+    assert_eq!(client.list_bond_ids(&identity), vec![&e, 0_u64, id]);
+
+    let source = client.get_identity_state(&identity);
+    assert_eq!(source.bonded_amount, 3_000_000_000);
+    assert!(source.is_rolling);
+    let tranche = client.get_bond(&identity, &id);
+    assert_eq!(tranche.bonded_amount, 7_000_000_000);
+    assert_eq!(tranche.bond_duration, 365 * DAY);
+    assert_eq!(tranche.kind, BondKind::Fixed);
+    assert_eq!(
+        client.get_aggregate_tier(&identity),
+        crate::tiered_bond::get_tier_for_amount(AMOUNT)
+    );
+}
+
+#[test]
+fn test_slashed_amount_prorated_and_rounded_down() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &10_000_000, &DAY, &false, &0_u64);
+    client.slash(&admin, &identity, &3_333_333);
+
+    // 3_333_333 * 3_000_000 / 10_000_000 = 999_999.9, so the tranche takes 999_999.
+    let id = client.split_bond(&identity, &3_000_000, &DAY, &false, &0);
+    let tranche = client.get_bond(&identity, &id);
+    let source = client.get_identity_state(&identity);
+    assert_eq!(tranche.slashed_amount, 999_999);
+    assert_eq!(source.slashed_amount, 2_333_334);
+    assert_eq!(tranche.slashed_amount + source.slashed_amount, 3_333_333);
+    assert_eq!(tranche.bonded_amount + source.bonded_amount, 10_000_000);
+}
+
+#[test]
+fn test_unslashed_split_moves_no_slash() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DAY, &false, &0_u64);
+    let id = client.split_bond(&identity, &MIN_BOND_AMOUNT, &DAY, &false, &0);
+    assert_eq!(client.get_bond(&identity, &id).slashed_amount, 0);
+    assert_eq!(client.get_identity_state(&identity).slashed_amount, 0);
+}
+
+#[test]
+#[should_panic(expected = "tranche would unlock before source bond")]
+fn test_shorter_fixed_tranche_rejected() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &(365 * DAY), &false, &0_u64);
+    client.split_bond(&identity, &3_000_000_000, &(30 * DAY), &false, &0);
+}
+
+#[test]
+#[should_panic(expected = "tranche would unlock before source bond")]
+fn test_rolling_tranche_with_short_notice_rejected() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &(365 * DAY), &false, &0_u64);
+    client.split_bond(&identity, &3_000_000_000, &(30 * DAY), &true, &(30 * DAY));
+}
+
+#[test]
+fn test_matured_bond_splits_into_short_tranche() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &(30 * DAY), &false, &0_u64);
+    e.ledger().with_mut(|li| li.timestamp = 30 * DAY);
+    let id = client.split_bond(&identity, &3_000_000_000, &DAY, &false, &0);
+    assert_eq!(client.get_bond(&identity, &id).bond_start, 30 * DAY);
+}
+
+#[test]
+fn test_both_pieces_must_meet_minimum() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &(2 * MIN_BOND_AMOUNT), &DAY, &false, &0_u64);
+
+    assert!(client
+        .try_split_bond(&identity, &(MIN_BOND_AMOUNT - 1), &DAY, &false, &0)
+        .is_err());
+    assert!(client
+        .try_split_bond(&identity, &(MIN_BOND_AMOUNT + 1), &DAY, &false, &0)
+        .is_err());
+    client.split_bond(&identity, &MIN_BOND_AMOUNT, &DAY, &false, &0);
+    assert_eq!(
+        client.get_identity_state(&identity).bonded_amount,
+        MIN_BOND_AMOUNT
+    );
+}
+
+#[test]
+fn test_rolling_tranche_withdraws_after_notice() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    // A request timestamp of 0 reads as "no request", so move off the genesis ledger time.
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.create_bond(&identity, &AMOUNT, &(30 * DAY), &false, &0_u64);
+    let id = client.split_bond(&identity, &3_000_000_000, &(30 * DAY), &true, &(30 * DAY));
+
+    assert!(client.try_withdraw_bond_v2(&identity, &id, &1).is_err());
+    client.request_bond_withdrawal(&identity, &id);
+    e.ledger()
+        .with_mut(|li| li.timestamp = 1_000 + 30 * DAY - 1);
+    assert!(client.try_withdraw_bond_v2(&identity, &id, &1).is_err());
+
+    e.ledger().with_mut(|li| li.timestamp = 1_000 + 30 * DAY);
+    let bond = client.withdraw_bond_v2(&identity, &id, &3_000_000_000);
+    assert!(!bond.active);
+}
+
+#[test]
+#[should_panic(expected = "withdrawal requested")]
+fn test_split_refused_while_serving_notice() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &(30 * DAY), &true, &DAY);
+    client.request_withdrawal(&identity);
+    client.split_bond(&identity, &3_000_000_000, &(365 * DAY), &false, &0);
+}
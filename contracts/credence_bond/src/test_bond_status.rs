@@ -0,0 +1,252 @@
+//! Tests for the bond lifecycle state machine: the full transition table and the statuses the
+//! entrypoints drive bonds through.
+
+extern crate std;
+
+use crate::bond_status::{self, BondTransition};
+use crate::test_helpers;
+use crate::{BondKind, BondStatus, IdentityBond};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Env, FromVal, Symbol};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+const AMOUNT: i128 = 10_000_000;
+const DURATION: u64 = 86_400;
+
+const STATUSES: [BondStatus; 6] = [
+    BondStatus::Active,
+    BondStatus::NoticeServing,
+    BondStatus::Matured,
+    BondStatus::Quarantined,
+    BondStatus::Frozen,
+    BondStatus::Closed,
+];
+
+const TRANSITIONS: [BondTransition; 9] = [
+    BondTransition::ServeNotice,
+    BondTransition::CancelNotice,
+    BondTransition::Mature,
+    BondTransition::Renew,
+    BondTransition::Quarantine,
+    BondTransition::Release,
+    BondTransition::Freeze,
+    BondTransition::Unfreeze,
+    BondTransition::Close,
+];
+
+/// Every legal edge; anything else must be rejected.
+fn legal(from: &BondStatus, transition: BondTransition) -> Option<BondStatus> {
+    use BondStatus as S;
+    use BondTransition as T;
+    match (from, transition) {
+        (S::Active, T::ServeNotice) => Some(S::NoticeServing),
+        (S::NoticeServing, T::CancelNotice) | (S::Matured, T::CancelNotice) => Some(S::Active),
+        (S::Active | S::NoticeServing | S::Matured, T::Mature) => Some(S::Matured),
+        (S::Active | S::NoticeServing | S::Matured, T::Renew) => Some(S::Active),
+        (S::Active | S::NoticeServing | S::Matured, T::Quarantine) => Some(S::Quarantined),
+        (S::Active | S::NoticeServing | S::Matured, T::Freeze) => Some(S::Frozen),
+        (S::Quarantined, T::Release) | (S::Frozen, T::Unfreeze) => Some(S::Active),
+        (S::Active | S::NoticeServing | S::Matured, T::Close) => Some(S::Closed),
+        _ => None,
+    }
+}
+
+fn bond_with(e: &Env, status: BondStatus) -> IdentityBond {
+    IdentityBond {
+        identity: Address::generate(e),
+        bonded_amount: AMOUNT,
+        bond_start: 0,
+        bond_duration: DURATION,
+        slashed_amount: 0,
+        active: status != BondStatus::Closed,
+        is_rolling: false,
+        withdrawal_requested_at: 0,
+        notice_period: 0,
+        kind: BondKind::Fixed,
+        status,
+    }
+}
+
+fn status_changes(e: &Env, contract_id: &Address) -> usize {
+    e.events()
+        .all()
+        .iter()
+        .filter(|(id, topics, _)| {
+            id == contract_id
+                && Symbol::from_val(e, &topics.get(0).unwrap())
+                    == Symbol::new(e, "bond_status_changed")
+        })
+        .count()
+}
+
+#[test]
+fn test_transition_table() {
+    let e = Env::default();
+    let (.., contract_id) = test_helpers::setup_with_token(&e);
+    for from in STATUSES.iter() {
+        for transition in TRANSITIONS {
+            let expected = legal(from, transition);
+            assert_eq!(bond_status::next(from, transition), expected);
+
+            let mut bond = bond_with(&e, from.clone());
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                e.as_contract(&contract_id, || {
+                    bond_status::transition(&e, &mut bond, transition);
+                });
+            }));
+            match expected {
+                Some(to) => {
+                    assert!(result.is_ok(), "{:?} -> {:?}", from, transition);
+                    assert_eq!(bond.active, to != BondStatus::Closed);
+                    assert_eq!(bond.status, to);
+                }
+                None => assert!(result.is_err(), "{:?} -> {:?}", from, transition),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_status_change_emits_event_once() {
+    let e = Env::default();
+    let (.., contract_id) = test_helpers::setup_with_token(&e);
+    let mut bond = bond_with(&e, BondStatus::Active);
+    e.as_contract(&contract_id, || {
+        bond_status::transition(&e, &mut bond, BondTransition::Renew);
+    });
+    assert_eq!(status_changes(&e, &contract_id), 0);
+    e.as_contract(&contract_id, || {
+        bond_status::transition(&e, &mut bond, BondTransition::Mature);
+    });
+    assert_eq!(status_changes(&e, &contract_id), 1);
+}
+
+#[test]
+fn test_rolling_bond_lifecycle() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    // A request timestamp of 0 reads as "no request", so move off the genesis ledger time.
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let bond = client.create_bond(&identity, &AMOUNT, &DURATION, &true, &100_u64);
+    assert_eq!(bond.status, BondStatus::Active);
+
+    let bond = client.request_withdrawal(&identity);
+    assert_eq!(bond.status, BondStatus::NoticeServing);
+    let bond = client.cancel_withdrawal_request(&identity);
+    assert_eq!(bond.status, BondStatus::Active);
+
+    client.request_withdrawal(&identity);
+    e.ledger().with_mut(|li| li.timestamp = 1_100);
+    let bond = client.withdraw_bond(&identity, &1_000);
+    assert_eq!(bond.status, BondStatus::Matured);
+    assert!(bond.active);
+
+    let bond = client.withdraw_bond(&identity, &(AMOUNT - 1_000));
+    assert_eq!(bond.status, BondStatus::Closed);
+    assert!(!bond.active);
+}
+
+#[test]
+fn test_extend_renews_matured_bond() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    e.ledger().with_mut(|li| li.timestamp = DURATION);
+    assert_eq!(
+        client.withdraw_bond(&identity, &1_000).status,
+        BondStatus::Matured
+    );
+
+    let bond = client.extend_duration(&identity, &DURATION);
+    assert_eq!(bond.status, BondStatus::Active);
+}
+
+#[test]
+#[should_panic(expected = "invalid bond transition")]
+fn test_closed_rolling_bond_cannot_renew() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    // A request timestamp of 0 reads as "no request", so move off the genesis ledger time.
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &true, &100_u64);
+    client.request_withdrawal(&identity);
+    e.ledger().with_mut(|li| li.timestamp = 1_100);
+    client.withdraw_bond(&identity, &AMOUNT);
+
+    e.ledger().with_mut(|li| li.timestamp = 2 * DURATION);
+    client.renew_if_rolling(&identity);
+}
+
+#[test]
+fn test_full_early_withdrawal_closes_bond() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.set_early_exit_config(&admin, &Address::generate(&e), &500_u32);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+
+    let bond = client.withdraw_early(&identity, &1_000);
+    assert_eq!(bond.status, BondStatus::Active);
+    assert!(bond.active);
+
+    let bond = client.withdraw_early(&identity, &(AMOUNT - 1_000));
+    assert_eq!(bond.status, BondStatus::Closed);
+    assert!(!bond.active);
+    assert_eq!(
+        client.get_identity_state(&identity).status,
+        BondStatus::Closed
+    );
+}
+
+#[test]
+fn test_full_emergency_withdrawal_closes_bond() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    let governance = Address::generate(&e);
+    client.set_emergency_config(&admin, &governance, &Address::generate(&e), &100, &true);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    let reason = Symbol::new(&e, "crisis");
+
+    let bond = client.emergency_withdraw(&admin, &governance, &identity, &1_000, &reason);
+    assert_eq!(bond.status, BondStatus::Active);
+
+    let bond =
+        client.emergency_withdraw(&admin, &governance, &identity, &(AMOUNT - 1_000), &reason);
+    assert_eq!(bond.status, BondStatus::Closed);
+    assert!(!bond.active);
+    assert_eq!(
+        client.get_identity_state(&identity).status,
+        BondStatus::Closed
+    );
+}
+
+#[test]
+fn test_full_emergency_withdrawal_keeps_frozen_bond_frozen() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    let governance = Address::generate(&e);
+    client.set_emergency_config(&admin, &governance, &Address::generate(&e), &100, &true);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    let reason = Symbol::new(&e, "crisis");
+    client.freeze_bond(&admin, &identity, &reason);
+
+    let bond = client.emergency_withdraw(&admin, &governance, &identity, &AMOUNT, &reason);
+    assert_eq!(bond.status, BondStatus::Frozen);
+    assert!(bond.active);
+}
+
+#[test]
+fn test_slash_bond_to_zero_closes_bond() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+
+    client.slash_bond(&admin, &identity, &(AMOUNT / 2));
+    let bond = client.get_identity_state(&identity);
+    assert_eq!(bond.status, BondStatus::Active);
+    assert!(bond.active);
+
+    client.slash_bond(&admin, &identity, &(AMOUNT / 2));
+    let bond = client.get_identity_state(&identity);
+    assert_eq!(bond.status, BondStatus::Closed);
+    assert!(!bond.active);
+}
@@ -0,0 +1,101 @@
+//! Tests for the `get_bond_end` and `get_time_remaining` views.
+
+use crate::test_helpers;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+const AMOUNT: i128 = 10_000_000;
+const START: u64 = 1_000;
+const DURATION: u64 = 86_400;
+
+fn set_time(e: &Env, t: u64) {
+    e.ledger().with_mut(|li| li.timestamp = t);
+}
+
+#[test]
+fn test_mid_term_bond() {
+    let e = Env::default();
+    set_time(&e, START);
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+
+    set_time(&e, START + 400);
+    assert_eq!(client.get_bond_end(&identity), START + DURATION);
+    assert_eq!(client.get_time_remaining(&identity), DURATION - 400);
+}
+
+#[test]
+fn test_expired_bond_saturates_to_zero() {
+    let e = Env::default();
+    set_time(&e, START);
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+
+    set_time(&e, START + DURATION);
+    assert_eq!(client.get_time_remaining(&identity), 0);
+    set_time(&e, START + 3 * DURATION);
+    assert_eq!(client.get_bond_end(&identity), START + DURATION);
+    assert_eq!(client.get_time_remaining(&identity), 0);
+}
+
+#[test]
+fn test_renewed_rolling_bond() {
+    let e = Env::default();
+    set_time(&e, START);
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &true, &10_u64);
+
+    let renewed_at = START + DURATION + 50;
+    set_time(&e, renewed_at);
+    client.renew_if_rolling(&identity);
+    set_time(&e, renewed_at + 100);
+    assert_eq!(client.get_bond_end(&identity), renewed_at + DURATION);
+    assert_eq!(client.get_time_remaining(&identity), DURATION - 100);
+}
+
+#[test]
+fn test_rolling_bond_due_for_renewal() {
+    let e = Env::default();
+    set_time(&e, START);
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &true, &10_u64);
+
+    let now = START + DURATION + 50;
+    set_time(&e, now);
+    assert_eq!(client.get_bond_end(&identity), now + DURATION);
+    assert_eq!(client.get_time_remaining(&identity), DURATION);
+}
+
+#[test]
+fn test_lapsing_rolling_bond_keeps_period_end() {
+    let e = Env::default();
+    set_time(&e, START);
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &true, &10_u64);
+    client.set_max_renewals(&admin, &1);
+    set_time(&e, START + DURATION);
+    client.renew_if_rolling(&identity);
+
+    set_time(&e, START + 3 * DURATION);
+    assert_eq!(client.get_bond_end(&identity), START + 2 * DURATION);
+    assert_eq!(client.get_time_remaining(&identity), 0);
+}
+
+#[test]
+fn test_views_need_no_auth() {
+    let e = Env::default();
+    set_time(&e, START);
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+
+    assert_eq!(client.get_time_remaining(&identity), DURATION);
+    assert!(e.auths().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "no bond")]
+fn test_missing_bond_panics() {
+    let e = Env::default();
+    let (client, ..) = test_helpers::setup_with_token(&e);
+    client.get_bond_end(&Address::generate(&e));
+}
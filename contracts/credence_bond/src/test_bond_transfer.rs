@@ -0,0 +1,109 @@
+//! Tests for `transfer_bond`: preserved state, attestation re-keying and refusal conditions.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, String, Vec};
+
+const AMOUNT: i128 = 10_000_000;
+const DURATION: u64 = 86_400;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address) {
+    let (client, admin, identity, token, _) = test_helpers::setup_with_token(e);
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    (client, admin, identity, token)
+}
+
+#[test]
+fn test_transfer_preserves_bond() {
+    let e = Env::default();
+    let (client, admin, identity, _) = setup(&e);
+    client.slash(&admin, &identity, &1_000);
+    let before = client.get_identity_state(&identity);
+    let new_identity = Address::generate(&e);
+
+    e.ledger().with_mut(|li| li.timestamp = 5_000);
+    let bond = client.transfer_bond(&identity, &new_identity, &false);
+    assert_eq!(bond.identity, new_identity);
+    assert_eq!(bond.bonded_amount, before.bonded_amount);
+    assert_eq!(bond.slashed_amount, 1_000);
+    assert_eq!(bond.bond_start, before.bond_start);
+    assert_eq!(bond.bond_duration, before.bond_duration);
+    assert_eq!(
+        client.get_identity_state(&new_identity).bonded_amount,
+        AMOUNT
+    );
+    assert!(client.try_get_identity_state(&identity).is_err());
+}
+
+#[test]
+fn test_transfer_moves_attestations_when_requested() {
+    let e = Env::default();
+    let (client, _admin, identity, _) = setup(&e);
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+    let kyc = String::from_str(&e, "kyc");
+    let att = client.add_attestation(&attester, &identity, &kyc);
+    let new_identity = Address::generate(&e);
+
+    client.transfer_bond(&identity, &new_identity, &true);
+    assert_eq!(
+        client.get_subject_attestations(&new_identity),
+        Vec::from_array(&e, [att.id])
+    );
+    assert!(client.get_subject_attestations(&identity).is_empty());
+    assert_eq!(client.get_attestation(&att.id).identity, new_identity);
+    // The duplicate marker moved with it.
+    assert!(client
+        .try_add_attestation(&attester, &new_identity, &kyc)
+        .is_err());
+    client.add_attestation(&attester, &identity, &kyc);
+}
+
+#[test]
+fn test_transfer_leaves_attestations_by_default() {
+    let e = Env::default();
+    let (client, _admin, identity, _) = setup(&e);
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+    let att = client.add_attestation(&attester, &identity, &String::from_str(&e, "kyc"));
+    let new_identity = Address::generate(&e);
+
+    client.transfer_bond(&identity, &new_identity, &false);
+    assert_eq!(
+        client.get_subject_attestations(&identity),
+        Vec::from_array(&e, [att.id])
+    );
+    assert!(client.get_subject_attestations(&new_identity).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "open slash request")]
+fn test_transfer_with_pending_slash_rejected() {
+    let e = Env::default();
+    let (client, admin, identity, _) = setup(&e);
+    let governor = Address::generate(&e);
+    client.initialize_governance(&admin, &Vec::from_array(&e, [governor]), &5_100, &1);
+    client.propose_slash(&admin, &identity, &1_000);
+    client.transfer_bond(&identity, &Address::generate(&e), &false);
+}
+
+#[test]
+#[should_panic(expected = "new identity already bonded")]
+fn test_transfer_onto_bonded_identity_rejected() {
+    let e = Env::default();
+    let (client, _admin, identity, token) = setup(&e);
+    let other = Address::generate(&e);
+    test_helpers::fund(&e, &token, &client.address, &other, AMOUNT);
+    client.create_bond(&other, &AMOUNT, &DURATION, &false, &0_u64);
+    client.transfer_bond(&identity, &other, &false);
+}
+
+#[test]
+#[should_panic(expected = "cannot transfer to self")]
+fn test_transfer_to_self_rejected() {
+    let e = Env::default();
+    let (client, _admin, identity, _) = setup(&e);
+    client.transfer_bond(&identity, &identity, &false);
+}
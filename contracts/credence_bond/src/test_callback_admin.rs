@@ -0,0 +1,127 @@
+//! Tests for callback registration: only the admin can set or clear the callback, and a
+//! callback registered by the admin that re-enters the contract still trips the reentrancy
+//! guard.
+
+use crate::test_helpers;
+use soroban_sdk::testutils::{Address as _, Events, MockAuth, MockAuthInvoke};
+use soroban_sdk::{Address, Env, IntoVal, Symbol, TryFromVal};
+
+mod reentrant {
+    use crate::CredenceBondClient;
+    use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+    /// Calls `collect_fees` again from inside the `on_collect` callback.
+    #[contract]
+    pub struct ReentrantCollector;
+
+    #[contractimpl]
+    impl ReentrantCollector {
+        pub fn setup(e: Env, target: Address, admin: Address) {
+            e.storage()
+                .instance()
+                .set(&Symbol::new(&e, "target"), &target);
+            e.storage()
+                .instance()
+                .set(&Symbol::new(&e, "admin"), &admin);
+        }
+
+        pub fn on_collect(e: Env, _amount: i128) {
+            let target: Address = e
+                .storage()
+                .instance()
+                .get(&Symbol::new(&e, "target"))
+                .unwrap();
+            let admin: Address = e
+                .storage()
+                .instance()
+                .get(&Symbol::new(&e, "admin"))
+                .unwrap();
+            CredenceBondClient::new(&e, &target).collect_fees(&admin);
+        }
+    }
+}
+
+use reentrant::{ReentrantCollector, ReentrantCollectorClient};
+
+#[test]
+fn test_admin_sets_and_clears_callback() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    let first = Address::generate(&e);
+    let second = Address::generate(&e);
+
+    client.set_callback(&admin, &first);
+    client.set_callback(&admin, &second);
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(
+        Symbol::try_from_val(&e, &topics.get_unchecked(0)).unwrap(),
+        Symbol::new(&e, "callback_set")
+    );
+    let (old, new) = <(Option<Address>, Option<Address>)>::try_from_val(&e, &data).unwrap();
+    assert_eq!(old, Some(first));
+    assert_eq!(new, Some(second.clone()));
+
+    client.clear_callback(&admin);
+    let (_, _, data) = e.events().all().last().unwrap();
+    let (old, new) = <(Option<Address>, Option<Address>)>::try_from_val(&e, &data).unwrap();
+    assert_eq!(old, Some(second));
+    assert_eq!(new, None);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_non_admin_cannot_set_callback() {
+    let e = Env::default();
+    let (client, ..) = test_helpers::setup_with_token(&e);
+    let attacker = Address::generate(&e);
+    client.set_callback(&attacker, &Address::generate(&e));
+}
+
+#[test]
+fn test_set_callback_requires_admin_auth() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    let attacker = Address::generate(&e);
+    let callback = Address::generate(&e);
+
+    // Only the attacker signs; the admin's auth is not mocked.
+    e.mock_auths(&[MockAuth {
+        address: &attacker,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "set_callback",
+            args: (admin.clone(), callback.clone()).into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_set_callback(&admin, &callback).is_err());
+    assert!(client.try_clear_callback(&admin).is_err());
+}
+
+#[test]
+#[should_panic(expected = "invalid callback")]
+fn test_callback_cannot_be_contract_itself() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    client.set_callback(&admin, &client.address);
+}
+
+#[test]
+fn test_reentrant_callback_still_blocked() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.deposit_fees(&identity, &500);
+
+    let attacker_id = e.register(ReentrantCollector, ());
+    ReentrantCollectorClient::new(&e, &attacker_id).setup(&client.address, &admin);
+    client.set_callback(&admin, &attacker_id);
+
+    // Let the nested call pass auth so only the reentrancy guard can stop it.
+    e.mock_all_auths_allowing_non_root_auth();
+    assert!(client.try_collect_fees(&admin).is_err());
+    assert!(!client.is_locked());
+
+    // Clearing the callback lets collection go through with the pool intact.
+    client.clear_callback(&admin);
+    assert_eq!(client.collect_fees(&admin), 500);
+}
@@ -0,0 +1,60 @@
+//! Tests for the explicit `close_bond` entrypoint.
+
+use crate::closeout::CloseReason;
+use crate::test_helpers;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::Env;
+
+const AMOUNT: i128 = 10_000_000;
+const DURATION: u64 = 86_400;
+
+#[test]
+#[should_panic(expected = "bond has residual balance")]
+fn test_close_blocked_with_residual_balance() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    e.ledger().with_mut(|li| li.timestamp = DURATION);
+    client.withdraw(&identity, &(AMOUNT - 1));
+    client.close_bond(&identity);
+}
+
+#[test]
+#[should_panic(expected = "open slash request")]
+fn test_close_blocked_with_open_slash_request() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    e.ledger().with_mut(|li| li.timestamp = DURATION);
+    client.withdraw(&identity, &AMOUNT);
+    client.propose_slash(&admin, &identity, &1_000);
+    client.close_bond(&identity);
+}
+
+#[test]
+fn test_close_then_rebond() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    // A zero-amount bond is never closed out by a withdrawal or slash.
+    client.create_bond_with_rolling(&identity, &0, &DURATION, &false, &0_u64);
+    client.close_bond(&identity);
+
+    assert!(client.try_get_identity_state(&identity).is_err());
+    let closeouts = client.get_closeouts(&identity);
+    assert_eq!(closeouts.len(), 1);
+    assert_eq!(
+        closeouts.get(0).unwrap().close_reason,
+        CloseReason::Voluntary
+    );
+
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    e.ledger().with_mut(|li| li.timestamp = DURATION);
+    client.withdraw(&identity, &AMOUNT);
+    client.close_bond(&identity);
+
+    // The withdrawal already wrote the second statement; closing only removes the entry.
+    let closeouts = client.get_closeouts(&identity);
+    assert_eq!(closeouts.len(), 2);
+    assert_eq!(closeouts.get(1).unwrap().close_reason, CloseReason::Matured);
+    assert!(client.try_get_identity_state(&identity).is_err());
+}
@@ -0,0 +1,142 @@
+//! Tests for bond close-out statements written when a bond reaches a terminal state.
+
+use crate::closeout::{BondCloseout, CloseReason};
+use crate::events::EVENT_SCHEMA_VERSION;
+use crate::test_helpers;
+use soroban_sdk::testutils::{Events, Ledger};
+use soroban_sdk::{Env, IntoVal, Symbol, TryFromVal};
+
+const AMOUNT: i128 = 10_000_000;
+const TOP_UP: i128 = 5_000_000;
+const DURATION: u64 = 86_400;
+const START: u64 = 1_000;
+
+fn set_time(e: &Env, t: u64) {
+    e.ledger().with_mut(|li| li.timestamp = t);
+}
+
+#[test]
+fn test_no_closeout_while_bond_open() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    set_time(&e, START);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0);
+
+    set_time(&e, START + DURATION);
+    client.withdraw(&identity, &(AMOUNT / 2));
+    assert_eq!(client.get_closeouts(&identity).len(), 0);
+}
+
+#[test]
+fn test_closeout_on_full_withdrawal_before_maturity() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    set_time(&e, START);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0);
+
+    set_time(&e, START + 100);
+    client.withdraw_bond_full(&identity);
+
+    let closeouts = client.get_closeouts(&identity);
+    assert_eq!(closeouts.len(), 1);
+    assert_eq!(
+        closeouts.get(0).unwrap(),
+        BondCloseout {
+            identity: identity.clone(),
+            total_bonded_lifetime: AMOUNT,
+            total_slashed: 0,
+            total_withdrawn: AMOUNT,
+            total_penalties_paid: 0,
+            opened_at: START,
+            closed_at: START + 100,
+            close_reason: CloseReason::Withdrawn,
+        }
+    );
+}
+
+#[test]
+fn test_closeout_on_matured_withdrawal_tracks_top_ups() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    set_time(&e, START);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0);
+    client.top_up(&identity, &identity, &TOP_UP);
+
+    set_time(&e, START + DURATION);
+    client.withdraw(&identity, &AMOUNT);
+    client.withdraw(&identity, &TOP_UP);
+
+    let closeout = client.get_closeouts(&identity).get(0).unwrap();
+    assert_eq!(closeout.close_reason, CloseReason::Matured);
+    assert_eq!(closeout.total_bonded_lifetime, AMOUNT + TOP_UP);
+    assert_eq!(closeout.total_withdrawn, AMOUNT + TOP_UP);
+    assert_eq!(closeout.opened_at, START);
+    assert_eq!(closeout.closed_at, START + DURATION);
+}
+
+#[test]
+fn test_closeout_on_full_slash() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    set_time(&e, START);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0);
+
+    set_time(&e, START + 10);
+    client.slash_bond(&admin, &identity, &(AMOUNT / 2));
+    assert_eq!(client.get_closeouts(&identity).len(), 0);
+    client.slash_bond(&admin, &identity, &(AMOUNT / 2));
+
+    let closeout = client.get_closeouts(&identity).get(0).unwrap();
+    assert_eq!(closeout.close_reason, CloseReason::Slashed);
+    assert_eq!(closeout.total_slashed, AMOUNT);
+    assert_eq!(closeout.total_withdrawn, 0);
+    assert_eq!(closeout.closed_at, START + 10);
+}
+
+#[test]
+fn test_closeout_emits_bond_closed_event() {
+    let e = Env::default();
+    let (client, _admin, identity, _token, contract_id) = test_helpers::setup_with_token(&e);
+    set_time(&e, START);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0);
+    client.withdraw_bond_full(&identity);
+
+    let topics = soroban_sdk::vec![
+        &e,
+        Symbol::new(&e, "bond_closed").into_val(&e),
+        EVENT_SCHEMA_VERSION.into_val(&e),
+        identity.into_val(&e)
+    ];
+    let data = e
+        .events()
+        .all()
+        .iter()
+        .find(|(c, t, _)| *c == contract_id && *t == topics)
+        .map(|(_, _, d)| d)
+        .expect("bond_closed event");
+    let emitted = BondCloseout::try_from_val(&e, &data).unwrap();
+    assert_eq!(emitted, client.get_closeouts(&identity).get(0).unwrap());
+}
+
+#[test]
+fn test_rebond_creates_second_epoch() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    set_time(&e, START);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0);
+    client.withdraw_bond_full(&identity);
+
+    set_time(&e, START + 1_000);
+    client.create_bond(&identity, &(AMOUNT * 2), &DURATION, &false, &0);
+    client.slash_bond(&admin, &identity, &(AMOUNT * 2));
+
+    let closeouts = client.get_closeouts(&identity);
+    assert_eq!(closeouts.len(), 2);
+    let first = closeouts.get(0).unwrap();
+    let second = closeouts.get(1).unwrap();
+    assert_eq!(first.close_reason, CloseReason::Withdrawn);
+    assert_eq!(first.total_bonded_lifetime, AMOUNT);
+    assert_eq!(second.close_reason, CloseReason::Slashed);
+    assert_eq!(second.total_bonded_lifetime, AMOUNT * 2);
+    assert_eq!(second.opened_at, START + 1_000);
+}
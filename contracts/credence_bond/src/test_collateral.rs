@@ -0,0 +1,171 @@
+//! Tests for collateralized attestations backed by holds on the attester's bond.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+const BOND_AMOUNT: i128 = 10_000_000_000;
+const BACKING: i128 = 4_000_000_000;
+const DURATION: u64 = 86_400;
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    admin: Address,
+    attester: Address,
+    subject: Address,
+}
+
+fn setup(e: &Env) -> Setup<'_> {
+    let (client, admin, attester, ..) = test_helpers::setup_with_token(e);
+    client.create_bond(&attester, &BOND_AMOUNT, &DURATION, &false, &0_u64);
+    client.register_attester(&attester);
+    client.set_feature(
+        &admin,
+        &Symbol::new(e, "collateralized_attestations"),
+        &true,
+    );
+    Setup {
+        client,
+        admin,
+        attester,
+        subject: Address::generate(e),
+    }
+}
+
+fn vouch(e: &Env, s: &Setup, data: &str, backing: i128) -> u64 {
+    s.client
+        .add_collateralized_attestation(
+            &s.attester,
+            &s.subject,
+            &String::from_str(e, data),
+            &backing,
+        )
+        .id
+}
+
+#[test]
+fn test_hold_placed_and_released_on_revoke() {
+    let e = Env::default();
+    let s = setup(&e);
+    let first = vouch(&e, &s, "vouch-a", BACKING);
+    let second = vouch(&e, &s, "vouch-b", 1_000_000_000);
+
+    assert_eq!(s.client.get_attestation_backing(&first), BACKING);
+    assert_eq!(
+        s.client.get_attester_backed_exposure(&s.attester),
+        BACKING + 1_000_000_000
+    );
+
+    s.client.revoke_attestation(&s.attester, &first);
+    assert_eq!(s.client.get_attestation_backing(&first), 0);
+    assert_eq!(s.client.get_attestation_backing(&second), 1_000_000_000);
+    assert_eq!(
+        s.client.get_attester_backed_exposure(&s.attester),
+        1_000_000_000
+    );
+}
+
+#[test]
+#[should_panic(expected = "insufficient bond for backing")]
+fn test_backing_limited_to_free_balance() {
+    let e = Env::default();
+    let s = setup(&e);
+    vouch(&e, &s, "vouch-a", BACKING);
+    vouch(&e, &s, "vouch-b", BOND_AMOUNT - BACKING + 1);
+}
+
+#[test]
+#[should_panic(expected = "attester bond inactive")]
+fn test_backing_requires_attester_bond() {
+    let e = Env::default();
+    let s = setup(&e);
+    let other = Address::generate(&e);
+    s.client.register_attester(&other);
+    s.client.add_collateralized_attestation(
+        &other,
+        &s.subject,
+        &String::from_str(&e, "vouch"),
+        &BACKING,
+    );
+}
+
+#[test]
+fn test_withdrawal_blocked_by_backing() {
+    let e = Env::default();
+    let s = setup(&e);
+    let id = vouch(&e, &s, "vouch-a", BACKING);
+    e.ledger().with_mut(|li| li.timestamp += DURATION);
+
+    let result = s
+        .client
+        .try_withdraw(&s.attester, &(BOND_AMOUNT - BACKING + 1));
+    assert!(result.is_err());
+    assert!(s.client.try_withdraw_bond_full(&s.attester).is_err());
+
+    s.client.withdraw(&s.attester, &(BOND_AMOUNT - BACKING));
+    s.client.revoke_attestation(&s.attester, &id);
+    let bond = s.client.withdraw(&s.attester, &BACKING);
+    assert_eq!(bond.bonded_amount, 0);
+}
+
+#[test]
+fn test_slash_consumes_backing_first() {
+    let e = Env::default();
+    let s = setup(&e);
+    let governors = [Address::generate(&e), Address::generate(&e)];
+    s.client.initialize_governance(
+        &s.admin,
+        &Vec::from_array(&e, governors.clone()),
+        &5_000_u32,
+        &1_u32,
+    );
+    let id = vouch(&e, &s, "vouch-a", BACKING);
+
+    let proposal_id = s
+        .client
+        .propose_attestation_slash(&s.admin, &id, &1_500_000_000);
+    assert_eq!(s.client.get_proposal_attestation(&proposal_id), Some(id));
+    for g in governors.iter() {
+        s.client.governance_vote(g, &proposal_id, &true);
+    }
+    let bond = s
+        .client
+        .execute_slash_with_governance(&s.admin, &proposal_id);
+
+    assert_eq!(bond.slashed_amount, 1_500_000_000);
+    assert_eq!(
+        s.client.get_attestation_backing(&id),
+        BACKING - 1_500_000_000
+    );
+    assert_eq!(
+        s.client.get_attester_backed_exposure(&s.attester),
+        BACKING - 1_500_000_000
+    );
+}
+
+#[test]
+fn test_slash_beyond_backing_falls_on_free_balance() {
+    let e = Env::default();
+    let s = setup(&e);
+    let governors = [Address::generate(&e)];
+    s.client.initialize_governance(
+        &s.admin,
+        &Vec::from_array(&e, governors.clone()),
+        &5_000_u32,
+        &1_u32,
+    );
+    let id = vouch(&e, &s, "vouch-a", BACKING);
+
+    let proposal_id = s
+        .client
+        .propose_attestation_slash(&s.admin, &id, &6_000_000_000);
+    s.client.governance_vote(&governors[0], &proposal_id, &true);
+    let bond = s
+        .client
+        .execute_slash_with_governance(&s.admin, &proposal_id);
+
+    assert_eq!(bond.slashed_amount, 6_000_000_000);
+    assert_eq!(s.client.get_attestation_backing(&id), 0);
+    assert_eq!(s.client.get_attester_backed_exposure(&s.attester), 0);
+}
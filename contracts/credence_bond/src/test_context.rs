@@ -0,0 +1,86 @@
+//! Tests that describe views and bond lifecycle events carry the ledger context of the call.
+
+use crate::context::Context;
+use crate::test_helpers;
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Env, FromVal, Symbol, Val};
+
+const BOND_AMOUNT: i128 = 10_000_000;
+
+fn set_ledger(e: &Env, sequence: u32, timestamp: u64) {
+    e.ledger().with_mut(|li| {
+        li.sequence_number = sequence;
+        li.timestamp = timestamp;
+    });
+}
+
+fn ledger_context(e: &Env) -> Context {
+    Context {
+        ledger_seq: e.ledger().sequence(),
+        timestamp: e.ledger().timestamp(),
+    }
+}
+
+/// Data of the last `name` event emitted by the bond contract in the last invocation.
+fn event_data(e: &Env, contract_id: &Address, name: &str) -> Val {
+    let (_, _, data) = e
+        .events()
+        .all()
+        .iter()
+        .rev()
+        .find(|(id, topics, _)| {
+            id == contract_id
+                && Symbol::from_val(e, &topics.get(0).unwrap()) == Symbol::new(e, name)
+        })
+        .unwrap();
+    data
+}
+
+#[test]
+fn test_describe_views_carry_context() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    set_ledger(&e, 42, 5_000);
+    client.create_bond(&identity, &BOND_AMOUNT, &86_400_u64, &false, &0_u64);
+    let verifier = Address::generate(&e);
+    client.register_attester(&verifier);
+    let id = client.propose_slash(&admin, &identity, &1_000);
+
+    set_ledger(&e, 57, 6_200);
+    let request = client.describe_slash_request(&id).unwrap();
+    assert_eq!(request.context, ledger_context(&e));
+    let description = client.describe_verifier(&verifier).unwrap();
+    assert_eq!(description.context, ledger_context(&e));
+}
+
+#[test]
+fn test_lifecycle_events_carry_context() {
+    let e = Env::default();
+    let (client, admin, identity, _token, contract_id) = test_helpers::setup_with_token(&e);
+
+    set_ledger(&e, 10, 1_000);
+    client.create_bond(&identity, &BOND_AMOUNT, &86_400_u64, &false, &0_u64);
+    let (.., context) =
+        <(i128, u64, bool, Context)>::from_val(&e, &event_data(&e, &contract_id, "bond_created"));
+    assert_eq!(context, ledger_context(&e));
+
+    set_ledger(&e, 11, 2_000);
+    client.top_up(&identity, &identity, &BOND_AMOUNT);
+    let (.., context) =
+        <(i128, i128, Context)>::from_val(&e, &event_data(&e, &contract_id, "bond_increased"));
+    assert_eq!(context, ledger_context(&e));
+
+    set_ledger(&e, 12, 3_000);
+    client.slash(&admin, &identity, &1_000);
+    let (.., context) = <(i128, i128, i128, Symbol, Context)>::from_val(
+        &e,
+        &event_data(&e, &contract_id, "bond_slashed"),
+    );
+    assert_eq!(context, ledger_context(&e));
+
+    set_ledger(&e, 13, 90_000);
+    client.withdraw(&identity, &BOND_AMOUNT);
+    let (.., context) =
+        <(i128, i128, Context)>::from_val(&e, &event_data(&e, &contract_id, "bond_withdrawn"));
+    assert_eq!(context, ledger_context(&e));
+}
@@ -83,12 +83,12 @@ fn test_request_cooldown_withdrawal() {
     e.mock_all_auths();
     e.ledger().with_mut(|li| li.timestamp = 5000);
     let (client, admin, identity) = setup_with_token(&e);
-    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.create_bond(&identity, &1_000_000_000, &86400, &false, &0);
     client.set_cooldown_period(&admin, &3600);
 
-    let req = client.request_cooldown_withdrawal(&identity, &500);
+    let req = client.request_cooldown_withdrawal(&identity, &500_000_000);
     assert_eq!(req.requester, identity);
-    assert_eq!(req.amount, 500);
+    assert_eq!(req.amount, 500_000_000);
     assert_eq!(req.requested_at, 5000);
 }
 
@@ -98,11 +98,11 @@ fn test_request_cooldown_withdrawal_full_amount() {
     e.mock_all_auths();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, admin, identity) = setup_with_token(&e);
-    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.create_bond(&identity, &1_000_000_000, &86400, &false, &0);
     client.set_cooldown_period(&admin, &100);
 
-    let req = client.request_cooldown_withdrawal(&identity, &1000);
-    assert_eq!(req.amount, 1000);
+    let req = client.request_cooldown_withdrawal(&identity, &1_000_000_000);
+    assert_eq!(req.amount, 1_000_000_000);
 }
 
 #[test]
@@ -111,7 +111,7 @@ fn test_request_cooldown_zero_amount() {
     let e = Env::default();
     e.mock_all_auths();
     let (client, admin, identity) = setup_with_token(&e);
-    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.create_bond(&identity, &1_000_000_000, &86400, &false, &0);
     client.set_cooldown_period(&admin, &100);
 
     client.request_cooldown_withdrawal(&identity, &0);
@@ -123,7 +123,7 @@ fn test_request_cooldown_negative_amount() {
     let e = Env::default();
     e.mock_all_auths();
     let (client, admin, identity) = setup_with_token(&e);
-    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.create_bond(&identity, &1_000_000_000, &86400, &false, &0);
     client.set_cooldown_period(&admin, &100);
 
     client.request_cooldown_withdrawal(&identity, &-10);
@@ -135,10 +135,10 @@ fn test_request_cooldown_exceeds_balance() {
     let e = Env::default();
     e.mock_all_auths();
     let (client, admin, identity) = setup_with_token(&e);
-    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.create_bond(&identity, &1_000_000_000, &86400, &false, &0);
     client.set_cooldown_period(&admin, &100);
 
-    client.request_cooldown_withdrawal(&identity, &1001);
+    client.request_cooldown_withdrawal(&identity, &1_001_000_000);
 }
 
 #[test]
@@ -147,12 +147,12 @@ fn test_request_cooldown_exceeds_available_after_slash() {
     let e = Env::default();
     e.mock_all_auths();
     let (client, admin, identity) = setup_with_token(&e);
-    client.create_bond(&identity, &1000, &86400, &false, &0);
-    client.slash(&admin, &300);
+    client.create_bond(&identity, &1_000_000_000, &86400, &false, &0);
+    client.slash(&admin, &identity, &300_000_000);
     client.set_cooldown_period(&admin, &100);
 
     // Available is 1000 - 300 = 700, requesting 701 should fail
-    client.request_cooldown_withdrawal(&identity, &701);
+    client.request_cooldown_withdrawal(&identity, &701_000_000);
 }
 
 #[test]
@@ -162,11 +162,11 @@ fn test_request_cooldown_duplicate() {
     e.mock_all_auths();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, admin, identity) = setup_with_token(&e);
-    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.create_bond(&identity, &1_000_000_000, &86400, &false, &0);
     client.set_cooldown_period(&admin, &100);
 
-    client.request_cooldown_withdrawal(&identity, &500);
-    client.request_cooldown_withdrawal(&identity, &200);
+    client.request_cooldown_withdrawal(&identity, &500_000_000);
+    client.request_cooldown_withdrawal(&identity, &200_000_000);
 }
 
 #[test]
@@ -178,20 +178,20 @@ fn test_request_cooldown_no_bond() {
 
     client.set_cooldown_period(&admin, &100);
     let identity = Address::generate(&e);
-    client.request_cooldown_withdrawal(&identity, &500);
+    client.request_cooldown_withdrawal(&identity, &500_000_000);
 }
 
 #[test]
-#[should_panic(expected = "requester is not the bond holder")]
+#[should_panic(expected = "no bond")]
 fn test_request_cooldown_wrong_identity() {
     let e = Env::default();
     e.mock_all_auths();
     let (client, admin, identity) = setup_with_token(&e);
-    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.create_bond(&identity, &1_000_000_000, &86400, &false, &0);
     client.set_cooldown_period(&admin, &100);
 
     let other = Address::generate(&e);
-    client.request_cooldown_withdrawal(&other, &500);
+    client.request_cooldown_withdrawal(&other, &500_000_000);
 }
 
 // ---------------------------------------------------------------
@@ -204,14 +204,14 @@ fn test_execute_cooldown_withdrawal_after_period() {
     e.mock_all_auths();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, admin, identity) = setup_with_token(&e);
-    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.create_bond(&identity, &1_000_000_000, &86400, &false, &0);
     client.set_cooldown_period(&admin, &100);
-    client.request_cooldown_withdrawal(&identity, &400);
+    client.request_cooldown_withdrawal(&identity, &400_000_000);
 
     // Advance time past the cooldown
     e.ledger().with_mut(|li| li.timestamp = 1101);
     let bond = client.execute_cooldown_withdrawal(&identity);
-    assert_eq!(bond.bonded_amount, 600);
+    assert_eq!(bond.bonded_amount, 600_000_000);
 }
 
 #[test]
@@ -220,14 +220,14 @@ fn test_execute_cooldown_withdrawal_exact_boundary() {
     e.mock_all_auths();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, admin, identity) = setup_with_token(&e);
-    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.create_bond(&identity, &1_000_000_000, &86400, &false, &0);
     client.set_cooldown_period(&admin, &100);
-    client.request_cooldown_withdrawal(&identity, &250);
+    client.request_cooldown_withdrawal(&identity, &250_000_000);
 
     // Exactly at the boundary (1000 + 100 = 1100)
     e.ledger().with_mut(|li| li.timestamp = 1100);
     let bond = client.execute_cooldown_withdrawal(&identity);
-    assert_eq!(bond.bonded_amount, 750);
+    assert_eq!(bond.bonded_amount, 750_000_000);
 }
 
 #[test]
@@ -236,17 +236,17 @@ fn test_execute_cooldown_removes_request() {
     e.mock_all_auths();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, admin, identity) = setup_with_token(&e);
-    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.create_bond(&identity, &1_000_000_000, &86400, &false, &0);
     client.set_cooldown_period(&admin, &100);
-    client.request_cooldown_withdrawal(&identity, &400);
+    client.request_cooldown_withdrawal(&identity, &400_000_000);
 
     e.ledger().with_mut(|li| li.timestamp = 1101);
     client.execute_cooldown_withdrawal(&identity);
 
     // Request should be cleared; a new one can be made
     e.ledger().with_mut(|li| li.timestamp = 2000);
-    let req = client.request_cooldown_withdrawal(&identity, &200);
-    assert_eq!(req.amount, 200);
+    let req = client.request_cooldown_withdrawal(&identity, &200_000_000);
+    assert_eq!(req.amount, 200_000_000);
     assert_eq!(req.requested_at, 2000);
 }
 
@@ -256,13 +256,13 @@ fn test_execute_cooldown_with_zero_period() {
     e.mock_all_auths();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin, identity) = setup_with_token(&e);
-    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.create_bond(&identity, &1_000_000_000, &86400, &false, &0);
     // Cooldown period defaults to 0 (instant)
-    client.request_cooldown_withdrawal(&identity, &300);
+    client.request_cooldown_withdrawal(&identity, &300_000_000);
 
     // Should succeed immediately since period is 0
     let bond = client.execute_cooldown_withdrawal(&identity);
-    assert_eq!(bond.bonded_amount, 700);
+    assert_eq!(bond.bonded_amount, 700_000_000);
 }
 
 #[test]
@@ -272,9 +272,9 @@ fn test_execute_cooldown_too_early() {
     e.mock_all_auths();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, admin, identity) = setup_with_token(&e);
-    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.create_bond(&identity, &1_000_000_000, &86400, &false, &0);
     client.set_cooldown_period(&admin, &100);
-    client.request_cooldown_withdrawal(&identity, &500);
+    client.request_cooldown_withdrawal(&identity, &500_000_000);
 
     // Try to execute 1 second too early
     e.ledger().with_mut(|li| li.timestamp = 1099);
@@ -287,7 +287,7 @@ fn test_execute_cooldown_no_request() {
     let e = Env::default();
     e.mock_all_auths();
     let (client, _admin, identity) = setup_with_token(&e);
-    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.create_bond(&identity, &1_000_000_000, &86400, &false, &0);
     client.execute_cooldown_withdrawal(&identity);
 }
 
@@ -298,12 +298,12 @@ fn test_execute_cooldown_balance_slashed_during_cooldown() {
     e.mock_all_auths();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, admin, identity) = setup_with_token(&e);
-    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.create_bond(&identity, &1_000_000_000, &86400, &false, &0);
     client.set_cooldown_period(&admin, &100);
-    client.request_cooldown_withdrawal(&identity, &800);
+    client.request_cooldown_withdrawal(&identity, &800_000_000);
 
     // Slash the bond while cooldown is pending
-    client.slash(&admin, &500);
+    client.slash(&admin, &identity, &500_000_000);
 
     // Now available = 1000 - 500 = 500, but request is for 800
     e.ledger().with_mut(|li| li.timestamp = 1101);
@@ -320,15 +320,15 @@ fn test_cancel_cooldown() {
     e.mock_all_auths();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, admin, identity) = setup_with_token(&e);
-    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.create_bond(&identity, &1_000_000_000, &86400, &false, &0);
     client.set_cooldown_period(&admin, &100);
-    client.request_cooldown_withdrawal(&identity, &500);
+    client.request_cooldown_withdrawal(&identity, &500_000_000);
 
     client.cancel_cooldown(&identity);
 
     // Request should be gone; new one can be made
-    let req = client.request_cooldown_withdrawal(&identity, &200);
-    assert_eq!(req.amount, 200);
+    let req = client.request_cooldown_withdrawal(&identity, &200_000_000);
+    assert_eq!(req.amount, 200_000_000);
 }
 
 #[test]
@@ -349,9 +349,9 @@ fn test_execute_after_cancel() {
     e.mock_all_auths();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, admin, identity) = setup_with_token(&e);
-    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.create_bond(&identity, &1_000_000_000, &86400, &false, &0);
     client.set_cooldown_period(&admin, &100);
-    client.request_cooldown_withdrawal(&identity, &500);
+    client.request_cooldown_withdrawal(&identity, &500_000_000);
     client.cancel_cooldown(&identity);
 
     e.ledger().with_mut(|li| li.timestamp = 1101);
@@ -368,13 +368,13 @@ fn test_get_cooldown_request() {
     e.mock_all_auths();
     e.ledger().with_mut(|li| li.timestamp = 2000);
     let (client, admin, identity) = setup_with_token(&e);
-    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.create_bond(&identity, &1_000_000_000, &86400, &false, &0);
     client.set_cooldown_period(&admin, &100);
-    client.request_cooldown_withdrawal(&identity, &750);
+    client.request_cooldown_withdrawal(&identity, &750_000_000);
 
     let req = client.get_cooldown_request(&identity);
     assert_eq!(req.requester, identity);
-    assert_eq!(req.amount, 750);
+    assert_eq!(req.amount, 750_000_000);
     assert_eq!(req.requested_at, 2000);
 }
 
@@ -461,29 +461,29 @@ fn test_full_cooldown_lifecycle() {
     e.mock_all_auths();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, admin, identity) = setup_with_token(&e);
-    client.create_bond(&identity, &5000, &86400, &false, &0);
+    client.create_bond(&identity, &5_000_000_000, &86400, &false, &0);
     client.set_cooldown_period(&admin, &3600);
 
     // Request withdrawal
-    let req = client.request_cooldown_withdrawal(&identity, &2000);
-    assert_eq!(req.amount, 2000);
+    let req = client.request_cooldown_withdrawal(&identity, &2_000_000_000);
+    assert_eq!(req.amount, 2_000_000_000);
     assert_eq!(req.requested_at, 1000);
 
     // Verify bond unchanged
-    let bond = client.get_identity_state();
-    assert_eq!(bond.bonded_amount, 5000);
+    let bond = client.get_identity_state(&identity);
+    assert_eq!(bond.bonded_amount, 5_000_000_000);
 
     // Advance past cooldown and execute
     e.ledger().with_mut(|li| li.timestamp = 4601);
     let bond = client.execute_cooldown_withdrawal(&identity);
-    assert_eq!(bond.bonded_amount, 3000);
+    assert_eq!(bond.bonded_amount, 3_000_000_000);
 
     // Request another withdrawal
     e.ledger().with_mut(|li| li.timestamp = 5000);
-    client.request_cooldown_withdrawal(&identity, &1000);
+    client.request_cooldown_withdrawal(&identity, &1_000_000_000);
     e.ledger().with_mut(|li| li.timestamp = 8601);
     let bond = client.execute_cooldown_withdrawal(&identity);
-    assert_eq!(bond.bonded_amount, 2000);
+    assert_eq!(bond.bonded_amount, 2_000_000_000);
 }
 
 #[test]
@@ -492,19 +492,19 @@ fn test_cancel_and_rerequest_lifecycle() {
     e.mock_all_auths();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, admin, identity) = setup_with_token(&e);
-    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.create_bond(&identity, &1_000_000_000, &86400, &false, &0);
     client.set_cooldown_period(&admin, &100);
 
-    client.request_cooldown_withdrawal(&identity, &800);
+    client.request_cooldown_withdrawal(&identity, &800_000_000);
     client.cancel_cooldown(&identity);
 
     // New request at a later time
     e.ledger().with_mut(|li| li.timestamp = 2000);
-    let req = client.request_cooldown_withdrawal(&identity, &500);
+    let req = client.request_cooldown_withdrawal(&identity, &500_000_000);
     assert_eq!(req.requested_at, 2000);
-    assert_eq!(req.amount, 500);
+    assert_eq!(req.amount, 500_000_000);
 
     e.ledger().with_mut(|li| li.timestamp = 2100);
     let bond = client.execute_cooldown_withdrawal(&identity);
-    assert_eq!(bond.bonded_amount, 500);
+    assert_eq!(bond.bonded_amount, 500_000_000);
 }
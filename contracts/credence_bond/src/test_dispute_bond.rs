@@ -0,0 +1,287 @@
+//! Tests for stake-backed disputes on governance slash requests.
+
+use crate::governance_approval::ProposalStatus;
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, Vec};
+
+const BOND_AMOUNT: i128 = 100_000_000;
+const SLASH_AMOUNT: i128 = 10_000_000;
+const DISPUTE_BOND: i128 = 1_000_000;
+const REWARD_BPS: u32 = 500; // 5% of the would-be slash
+const GOVERNOR_FUNDS: i128 = 50_000_000;
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    admin: Address,
+    identity: Address,
+    token: TokenClient<'a>,
+    contract_id: Address,
+    governors: [Address; 3],
+}
+
+fn setup(e: &Env) -> Setup<'_> {
+    let (client, admin, identity, token, contract_id) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &BOND_AMOUNT, &86_400_u64, &false, &0_u64);
+
+    let governors = [
+        Address::generate(e),
+        Address::generate(e),
+        Address::generate(e),
+    ];
+    let asset = StellarAssetClient::new(e, &token);
+    let token_client = TokenClient::new(e, &token);
+    let expiration = e.ledger().sequence().saturating_add(10_000);
+    for g in governors.iter() {
+        asset.mint(g, &GOVERNOR_FUNDS);
+        token_client.approve(g, &contract_id, &GOVERNOR_FUNDS, &expiration);
+    }
+    client.initialize_governance(
+        &admin,
+        &Vec::from_array(e, governors.clone()),
+        &6_600_u32,
+        &2_u32,
+    );
+    client.set_dispute_bond_config(&admin, &DISPUTE_BOND, &REWARD_BPS);
+
+    Setup {
+        client,
+        admin,
+        identity,
+        token: token_client,
+        contract_id,
+        governors,
+    }
+}
+
+#[test]
+fn test_dispute_escrows_bond() {
+    let e = Env::default();
+    let s = setup(&e);
+    let [g1, ..] = &s.governors;
+    let proposal_id = s.client.propose_slash(&s.admin, &s.identity, &SLASH_AMOUNT);
+    let contract_before = s.token.balance(&s.contract_id);
+
+    let escrowed = s.client.dispute_slash_request(g1, &proposal_id);
+
+    assert_eq!(escrowed, DISPUTE_BOND);
+    assert_eq!(s.token.balance(g1), GOVERNOR_FUNDS - DISPUTE_BOND);
+    assert_eq!(
+        s.token.balance(&s.contract_id),
+        contract_before + DISPUTE_BOND
+    );
+    assert_eq!(
+        s.client.get_dispute_escrow(&proposal_id, g1),
+        Some(DISPUTE_BOND)
+    );
+    assert!(!s.client.is_locked());
+}
+
+#[test]
+#[should_panic(expected = "already disputed")]
+fn test_double_dispute_by_same_member_rejected() {
+    let e = Env::default();
+    let s = setup(&e);
+    let [g1, ..] = &s.governors;
+    let proposal_id = s.client.propose_slash(&s.admin, &s.identity, &SLASH_AMOUNT);
+
+    s.client.dispute_slash_request(g1, &proposal_id);
+    s.client.dispute_slash_request(g1, &proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "not a governor")]
+fn test_dispute_requires_governor() {
+    let e = Env::default();
+    let s = setup(&e);
+    let proposal_id = s.client.propose_slash(&s.admin, &s.identity, &SLASH_AMOUNT);
+    s.client
+        .dispute_slash_request(&Address::generate(&e), &proposal_id);
+}
+
+#[test]
+fn test_escrow_forfeited_when_slash_upheld() {
+    let e = Env::default();
+    let s = setup(&e);
+    let [g1, g2, g3] = &s.governors;
+    let proposal_id = s.client.propose_slash(&s.admin, &s.identity, &SLASH_AMOUNT);
+    s.client.dispute_slash_request(g3, &proposal_id);
+
+    s.client.governance_vote(g1, &proposal_id, &true);
+    s.client.governance_vote(g2, &proposal_id, &true);
+    s.client
+        .execute_slash_with_governance(&s.admin, &proposal_id);
+
+    assert_eq!(s.client.get_dispute_escrow(&proposal_id, g3), None);
+    assert_eq!(s.token.balance(g3), GOVERNOR_FUNDS - DISPUTE_BOND);
+    // No slash beneficiary is set, so the slash proceeds accrue to the fee pool as well.
+    assert_eq!(s.client.collect_fees(&s.admin), DISPUTE_BOND + SLASH_AMOUNT);
+}
+
+/// Disputes by g2 and g3, then g2 and g3 outvote g1 and the request is rejected.
+fn dispute_and_reject(s: &Setup<'_>) -> u64 {
+    let [g1, g2, g3] = &s.governors;
+    let proposal_id = s.client.propose_slash(&s.admin, &s.identity, &SLASH_AMOUNT);
+    s.client.dispute_slash_request(g2, &proposal_id);
+    s.client.dispute_slash_request(g3, &proposal_id);
+
+    s.client.governance_vote(g1, &proposal_id, &true);
+    s.client.governance_vote(g2, &proposal_id, &false);
+    s.client.governance_vote(g3, &proposal_id, &false);
+    s.client.reject_slash_request(&proposal_id);
+    proposal_id
+}
+
+#[test]
+fn test_escrow_refunded_with_reward_when_slash_rejected() {
+    let e = Env::default();
+    let s = setup(&e);
+    let [_, g2, g3] = &s.governors;
+    let reward = SLASH_AMOUNT * i128::from(REWARD_BPS) / 10_000;
+    s.client.deposit_fees(&s.identity, &(reward + 1));
+    let contract_before = s.token.balance(&s.contract_id);
+
+    let proposal_id = dispute_and_reject(&s);
+
+    // One reward, split between the two disputers; the odd stroop stays in the pool.
+    assert_eq!(s.token.balance(g2), GOVERNOR_FUNDS + reward / 2);
+    assert_eq!(s.token.balance(g3), GOVERNOR_FUNDS + reward / 2);
+    assert_eq!(s.client.get_dispute_escrow(&proposal_id, g2), None);
+    assert_eq!(s.token.balance(&s.contract_id), contract_before - reward);
+    assert_eq!(s.client.collect_fees(&s.admin), 1);
+    assert_eq!(s.client.get_identity_state(&s.identity).slashed_amount, 0);
+}
+
+#[test]
+fn test_reward_capped_at_fee_pool() {
+    let e = Env::default();
+    let s = setup(&e);
+    let [_, g2, g3] = &s.governors;
+    let reward = SLASH_AMOUNT * i128::from(REWARD_BPS) / 10_000;
+    let pool = reward / 5;
+    s.client.deposit_fees(&s.identity, &pool);
+    let contract_before = s.token.balance(&s.contract_id);
+
+    dispute_and_reject(&s);
+
+    // The pool cannot cover the reward, so only the pool is split; bonded funds are untouched.
+    assert_eq!(s.token.balance(g2), GOVERNOR_FUNDS + pool / 2);
+    assert_eq!(s.token.balance(g3), GOVERNOR_FUNDS + pool / 2);
+    assert_eq!(s.client.collect_fees(&s.admin), 0);
+    assert_eq!(s.token.balance(&s.contract_id), contract_before - pool);
+}
+
+#[test]
+fn test_no_reward_without_fee_pool() {
+    let e = Env::default();
+    let s = setup(&e);
+    let [_, g2, g3] = &s.governors;
+    let contract_before = s.token.balance(&s.contract_id);
+
+    dispute_and_reject(&s);
+
+    assert_eq!(s.token.balance(g2), GOVERNOR_FUNDS);
+    assert_eq!(s.token.balance(g3), GOVERNOR_FUNDS);
+    assert_eq!(s.token.balance(&s.contract_id), contract_before);
+}
+
+#[test]
+#[should_panic(expected = "proposal not rejected")]
+fn test_reject_requires_contested_quorum() {
+    let e = Env::default();
+    let s = setup(&e);
+    let [g1, ..] = &s.governors;
+    let proposal_id = s.client.propose_slash(&s.admin, &s.identity, &SLASH_AMOUNT);
+    s.client.governance_vote(g1, &proposal_id, &false);
+    s.client.reject_slash_request(&proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "proposal not open for dispute")]
+fn test_dispute_after_resolution_rejected() {
+    let e = Env::default();
+    let s = setup(&e);
+    let [g1, g2, g3] = &s.governors;
+    let proposal_id = s.client.propose_slash(&s.admin, &s.identity, &SLASH_AMOUNT);
+    s.client.governance_vote(g1, &proposal_id, &true);
+    s.client.governance_vote(g2, &proposal_id, &true);
+    s.client
+        .execute_slash_with_governance(&s.admin, &proposal_id);
+
+    s.client.dispute_slash_request(g3, &proposal_id);
+}
+
+#[test]
+fn test_free_dispute_when_unconfigured() {
+    let e = Env::default();
+    let s = setup(&e);
+    let [g1, ..] = &s.governors;
+    s.client.set_dispute_bond_config(&s.admin, &0, &0);
+    let proposal_id = s.client.propose_slash(&s.admin, &s.identity, &SLASH_AMOUNT);
+
+    assert_eq!(s.client.dispute_slash_request(g1, &proposal_id), 0);
+    assert_eq!(s.token.balance(g1), GOVERNOR_FUNDS);
+}
+
+/// g3 disputes a request that g1 and g2 approve, and nobody executes it inside the window.
+fn dispute_approve_and_expire(e: &Env, s: &Setup<'_>) -> u64 {
+    let [g1, g2, g3] = &s.governors;
+    s.client.set_slash_execution_window(&s.admin, &1_000);
+    let proposal_id = s.client.propose_slash(&s.admin, &s.identity, &SLASH_AMOUNT);
+    s.client.dispute_slash_request(g3, &proposal_id);
+    s.client.governance_vote(g1, &proposal_id, &true);
+    s.client.governance_vote(g2, &proposal_id, &true);
+    e.ledger().with_mut(|li| li.timestamp += 1_000);
+    proposal_id
+}
+
+#[test]
+fn test_escrow_refunded_without_reward_when_request_expires() {
+    let e = Env::default();
+    let s = setup(&e);
+    let [_, _, g3] = &s.governors;
+    s.client.deposit_fees(&s.identity, &SLASH_AMOUNT);
+    let proposal_id = dispute_approve_and_expire(&e, &s);
+    assert!(s
+        .client
+        .try_execute_slash_with_governance(&s.admin, &proposal_id)
+        .is_err());
+    let contract_before = s.token.balance(&s.contract_id);
+
+    s.client.close_expired_slash_request(&proposal_id);
+
+    let proposal = s.client.get_slash_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Expired);
+    assert_eq!(s.client.get_dispute_escrow(&proposal_id, g3), None);
+    assert_eq!(s.token.balance(g3), GOVERNOR_FUNDS);
+    assert_eq!(
+        s.token.balance(&s.contract_id),
+        contract_before - DISPUTE_BOND
+    );
+    assert_eq!(s.client.get_open_proposal_count(&s.admin), 0);
+    assert!(s.client.get_open_slash_requests(&s.identity).is_empty());
+    assert_eq!(s.client.get_identity_state(&s.identity).slashed_amount, 0);
+    assert_eq!(s.client.collect_fees(&s.admin), SLASH_AMOUNT);
+}
+
+#[test]
+#[should_panic(expected = "proposal not expired")]
+fn test_close_expired_rejects_live_request() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.set_slash_execution_window(&s.admin, &1_000);
+    let proposal_id = s.client.propose_slash(&s.admin, &s.identity, &SLASH_AMOUNT);
+    s.client.close_expired_slash_request(&proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "proposal already closed")]
+fn test_close_expired_only_once() {
+    let e = Env::default();
+    let s = setup(&e);
+    let proposal_id = dispute_approve_and_expire(&e, &s);
+    s.client.close_expired_slash_request(&proposal_id);
+    s.client.close_expired_slash_request(&proposal_id);
+}
@@ -96,7 +96,13 @@ fn test_validate_duration_u64_max() {
 fn test_create_bond_min_duration() {
     let e = Env::default();
     let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
-    let bond = client.create_bond(&identity, &1000_i128, &MIN_BOND_DURATION, &false, &0_u64);
+    let bond = client.create_bond(
+        &identity,
+        &1_000_000_000_i128,
+        &MIN_BOND_DURATION,
+        &false,
+        &0_u64,
+    );
     assert!(bond.active);
     assert_eq!(bond.bond_duration, MIN_BOND_DURATION);
 }
@@ -106,7 +112,13 @@ fn test_create_bond_min_duration() {
 fn test_create_bond_max_duration() {
     let e = Env::default();
     let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
-    let bond = client.create_bond(&identity, &1000_i128, &MAX_BOND_DURATION, &false, &0_u64);
+    let bond = client.create_bond(
+        &identity,
+        &1_000_000_000_i128,
+        &MAX_BOND_DURATION,
+        &false,
+        &0_u64,
+    );
     assert!(bond.active);
     assert_eq!(bond.bond_duration, MAX_BOND_DURATION);
 }
@@ -117,7 +129,7 @@ fn test_create_bond_typical_duration() {
     let e = Env::default();
     let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
     let thirty_days = 30 * 86_400_u64;
-    let bond = client.create_bond(&identity, &1000_i128, &thirty_days, &false, &0_u64);
+    let bond = client.create_bond(&identity, &1_000_000_000_i128, &thirty_days, &false, &0_u64);
     assert!(bond.active);
     assert_eq!(bond.bond_duration, thirty_days);
 }
@@ -130,7 +142,7 @@ fn test_create_bond_zero_duration_rejected() {
     e.mock_all_auths();
     let client = setup(&e);
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000_i128, &0_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &0_u64, &false, &0_u64);
 }
 
 /// Bond creation with duration below minimum must be rejected.
@@ -143,7 +155,7 @@ fn test_create_bond_below_min_duration_rejected() {
     let identity = Address::generate(&e);
     client.create_bond(
         &identity,
-        &1000_i128,
+        &1_000_000_000_i128,
         &(MIN_BOND_DURATION - 1),
         &false,
         &0_u64,
@@ -160,7 +172,7 @@ fn test_create_bond_above_max_duration_rejected() {
     let identity = Address::generate(&e);
     client.create_bond(
         &identity,
-        &1000_i128,
+        &1_000_000_000_i128,
         &(MAX_BOND_DURATION + 1),
         &false,
         &0_u64,
@@ -172,7 +184,13 @@ fn test_create_bond_above_max_duration_rejected() {
 fn test_create_rolling_bond_valid_duration() {
     let e = Env::default();
     let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
-    let bond = client.create_bond(&identity, &1000_i128, &MIN_BOND_DURATION, &true, &3600_u64);
+    let bond = client.create_bond(
+        &identity,
+        &1_000_000_000_i128,
+        &MIN_BOND_DURATION,
+        &true,
+        &3600_u64,
+    );
     assert!(bond.active);
     assert!(bond.is_rolling);
     assert_eq!(bond.bond_duration, MIN_BOND_DURATION);
@@ -186,7 +204,7 @@ fn test_create_rolling_bond_invalid_duration_rejected() {
     e.mock_all_auths();
     let client = setup(&e);
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000_i128, &3600_u64, &true, &1800_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &3600_u64, &true, &1800_u64);
 }
 
 /// Constants have expected values.
@@ -0,0 +1,114 @@
+//! Tests for paying out dust and auto-closing bonds after partial withdrawals.
+
+use crate::closeout::CloseReason;
+use crate::test_helpers;
+use crate::{BatchBondParams, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token::TokenClient, Address, Env, Vec};
+
+const AMOUNT: i128 = 10_000_000_000;
+const DUST: i128 = 50_000_000;
+const DURATION: u64 = 86_400;
+
+fn set_time(e: &Env, t: u64) {
+    e.ledger().with_mut(|li| li.timestamp = t);
+}
+
+#[test]
+fn test_remainder_at_threshold_stays_open() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0);
+    client.set_dust_threshold(&admin, &DUST);
+
+    set_time(&e, DURATION);
+    let bond = client.withdraw(&identity, &(AMOUNT - DUST));
+    assert!(bond.active);
+    assert_eq!(bond.bonded_amount, DUST);
+    assert_eq!(client.get_closeouts(&identity).len(), 0);
+}
+
+#[test]
+fn test_remainder_below_threshold_auto_closes() {
+    let e = Env::default();
+    let (client, admin, identity, token, _) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0);
+    client.set_dust_threshold(&admin, &DUST);
+    let token_client = TokenClient::new(&e, &token);
+    let before = token_client.balance(&identity);
+
+    set_time(&e, DURATION);
+    let bond = client.withdraw(&identity, &(AMOUNT - DUST + 1));
+    assert!(!bond.active);
+    assert_eq!(bond.bonded_amount, 0);
+    assert_eq!(token_client.balance(&identity), before + AMOUNT);
+
+    let closeouts = client.get_closeouts(&identity);
+    assert_eq!(closeouts.len(), 1);
+    let closeout = closeouts.get(0).unwrap();
+    assert_eq!(closeout.total_withdrawn, AMOUNT);
+    assert_eq!(closeout.close_reason, CloseReason::Matured);
+    assert_eq!(closeout.closed_at, DURATION);
+}
+
+#[test]
+fn test_auto_close_without_token_is_accounting_only() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let client = CredenceBondClient::new(&e, &e.register(CredenceBond, ()));
+    let admin = Address::generate(&e);
+    client.initialize(&admin);
+    let identity = Address::generate(&e);
+    client.create_batch_bonds(&Vec::from_array(
+        &e,
+        [BatchBondParams {
+            identity: identity.clone(),
+            amount: AMOUNT,
+            duration: DURATION,
+            is_rolling: false,
+            notice_period_duration: 0,
+        }],
+    ));
+    client.set_dust_threshold(&admin, &DUST);
+
+    // A request timestamp of 0 reads as "no request", so move off the genesis ledger time.
+    set_time(&e, 1_000);
+    client.request_cooldown_withdrawal(&identity, &(AMOUNT - 1));
+    let bond = client.execute_cooldown_withdrawal(&identity);
+    assert!(!bond.active);
+    assert_eq!(bond.bonded_amount, 0);
+
+    let closeout = client.get_closeouts(&identity).get(0).unwrap();
+    assert_eq!(closeout.total_withdrawn, AMOUNT);
+    assert_eq!(closeout.close_reason, CloseReason::Withdrawn);
+}
+
+#[test]
+fn test_early_exit_keeps_penalized_dust() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0);
+    client.set_dust_threshold(&admin, &DUST);
+    client.set_early_exit_config(&admin, &Address::generate(&e), &1_000_u32);
+
+    // 10% of the 40_000_000 left at full remaining lock time would be penalized.
+    let bond = client.withdraw_early(&identity, &(AMOUNT - 40_000_000));
+    assert!(bond.active);
+    assert_eq!(bond.bonded_amount, 40_000_000);
+    assert_eq!(client.get_closeouts(&identity).len(), 0);
+}
+
+#[test]
+fn test_early_exit_without_penalty_auto_closes() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0);
+    client.set_dust_threshold(&admin, &DUST);
+    client.set_early_exit_config(&admin, &Address::generate(&e), &0_u32);
+
+    let bond = client.withdraw_early(&identity, &(AMOUNT - 1));
+    assert!(!bond.active);
+    let closeout = client.get_closeouts(&identity).get(0).unwrap();
+    assert_eq!(closeout.total_withdrawn, AMOUNT);
+    assert_eq!(closeout.close_reason, CloseReason::Withdrawn);
+}
@@ -0,0 +1,160 @@
+//! Tests for the economic parameters bundle: all-or-nothing validation, the single bundle event
+//! and the individual setters written through it.
+
+extern crate std;
+
+use crate::economic_params::EconomicParams;
+use crate::test_helpers;
+use crate::types::attestation::MAX_ATTESTATION_WEIGHT;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, Env, FromVal, String, Symbol};
+
+fn new_params() -> EconomicParams {
+    EconomicParams {
+        verifier_min_stake: 5_000,
+        weight_multiplier_bps: 250,
+        max_weight: 2_000,
+        baseline_weight: 3,
+        bronze_threshold: 200_000_000,
+        silver_threshold: 2_000_000_000,
+        gold_threshold: 20_000_000_000,
+        platinum_threshold: 200_000_000_000,
+    }
+}
+
+fn bundle_events(
+    e: &Env,
+    contract_id: &Address,
+) -> std::vec::Vec<(EconomicParams, EconomicParams)> {
+    e.events()
+        .all()
+        .iter()
+        .filter(|(id, topics, _)| {
+            id == contract_id
+                && Symbol::from_val(e, &topics.get(0).unwrap())
+                    == Symbol::new(e, "economic_params_set")
+        })
+        .map(|(_, _, data)| <(EconomicParams, EconomicParams)>::from_val(e, &data))
+        .collect()
+}
+
+#[test]
+fn test_valid_bundle_changes_everything() {
+    let e = Env::default();
+    let (client, admin, _identity, _token, contract_id) = test_helpers::setup_with_token(&e);
+    let old = client.get_economic_params();
+
+    client.set_economic_params(&admin, &new_params());
+    assert_eq!(bundle_events(&e, &contract_id), [(old, new_params())]);
+
+    assert_eq!(client.get_economic_params(), new_params());
+    assert_eq!(client.get_verifier_stake_requirement(), 5_000);
+    assert_eq!(client.get_weight_config(), (250, 2_000));
+    assert_eq!(client.get_bronze_threshold(), 200_000_000);
+    assert_eq!(client.get_silver_threshold(), 2_000_000_000);
+    assert_eq!(client.get_gold_threshold(), 20_000_000_000);
+    assert_eq!(client.get_platinum_threshold(), 200_000_000_000);
+}
+
+#[test]
+fn test_one_invalid_field_changes_nothing() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    let before = client.get_economic_params();
+
+    let invalid = [
+        EconomicParams {
+            verifier_min_stake: -1,
+            ..new_params()
+        },
+        EconomicParams {
+            max_weight: MAX_ATTESTATION_WEIGHT + 1,
+            ..new_params()
+        },
+        EconomicParams {
+            baseline_weight: 0,
+            ..new_params()
+        },
+        EconomicParams {
+            silver_threshold: crate::parameters::MIN_SILVER_THRESHOLD - 1,
+            ..new_params()
+        },
+        EconomicParams {
+            platinum_threshold: crate::parameters::MAX_PLATINUM_THRESHOLD + 1,
+            ..new_params()
+        },
+    ];
+    for params in invalid {
+        assert!(client.try_set_economic_params(&admin, &params).is_err());
+        assert_eq!(client.get_economic_params(), before);
+    }
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_non_admin_rejected() {
+    let e = Env::default();
+    let (client, ..) = test_helpers::setup_with_token(&e);
+    client.set_economic_params(&Address::generate(&e), &new_params());
+}
+
+#[test]
+fn test_wrapper_setters_carry_other_fields() {
+    let e = Env::default();
+    let (client, admin, _identity, _token, contract_id) = test_helpers::setup_with_token(&e);
+    client.set_economic_params(&admin, &new_params());
+
+    client.set_weight_config(&admin, &400, &(MAX_ATTESTATION_WEIGHT + 5));
+    assert!(bundle_events(&e, &contract_id).is_empty());
+    client.set_verifier_stake_requirement(&admin, &7_000);
+    client.set_gold_threshold(&admin, &30_000_000_000);
+
+    assert_eq!(
+        client.get_economic_params(),
+        EconomicParams {
+            verifier_min_stake: 7_000,
+            weight_multiplier_bps: 400,
+            // The wrapper still caps max_weight instead of rejecting it.
+            max_weight: MAX_ATTESTATION_WEIGHT,
+            gold_threshold: 30_000_000_000,
+            ..new_params()
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "bronze_threshold out of bounds")]
+fn test_wrapper_still_enforces_bounds() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    client.set_bronze_threshold(&admin, &(crate::parameters::MAX_BRONZE_THRESHOLD + 1));
+}
+
+#[test]
+#[should_panic(expected = "min stake cannot be negative")]
+fn test_stake_wrapper_rejects_negative() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    client.set_verifier_stake_requirement(&admin, &-1);
+}
+
+#[test]
+fn test_baseline_weight_applies_to_unstaked_attester() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    client.set_economic_params(
+        &admin,
+        &EconomicParams {
+            verifier_min_stake: 0,
+            ..new_params()
+        },
+    );
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+    let att = client.add_attestation(
+        &attester,
+        &Address::generate(&e),
+        &String::from_str(&e, "kyc"),
+    );
+    assert_eq!(att.weight, 3);
+}
@@ -0,0 +1,154 @@
+//! Tests for the read-only eligibility check: each criterion failing on its own, a subject that
+//! passes everything and the empty policy.
+
+use crate::eligibility::{EligibilityCriterion, EligibilityPolicy};
+use crate::test_helpers;
+use crate::{BondStatus, BondTier, CredenceBondClient, DataKey, IdentityBond};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, Env, String, Symbol};
+
+const SILVER_AMOUNT: i128 = crate::tiered_bond::TIER_BRONZE_MAX;
+const DURATION: u64 = 86_400;
+const WEEK: u64 = 7 * 86_400;
+
+fn empty_policy() -> EligibilityPolicy {
+    EligibilityPolicy {
+        min_tier: BondTier::Bronze,
+        attestation_category: None,
+        min_attestation_weight: 0,
+        max_slashes: None,
+        slash_lookback_secs: 0,
+        require_unrestricted: false,
+    }
+}
+
+fn strict_policy(e: &Env) -> EligibilityPolicy {
+    EligibilityPolicy {
+        min_tier: BondTier::Silver,
+        attestation_category: Some(Symbol::new(e, "kyc")),
+        min_attestation_weight: 5,
+        max_slashes: Some(1),
+        slash_lookback_secs: WEEK,
+        require_unrestricted: true,
+    }
+}
+
+/// A Silver bond with a weight-5 "kyc" attestation and no slashes.
+fn qualified(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.ledger().with_mut(|li| li.timestamp = 10 * WEEK);
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &SILVER_AMOUNT, &DURATION, &false, &0_u64);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    client.set_attester_stake(&admin, &attester, &500);
+    client.add_attestation(&attester, &identity, &String::from_str(e, "kyc"));
+    (client, admin, identity)
+}
+
+fn assert_fails_only(
+    client: &CredenceBondClient,
+    e: &Env,
+    subject: &Address,
+    criterion: EligibilityCriterion,
+) {
+    let result = client.check_eligibility(subject, &strict_policy(e));
+    assert!(!result.eligible);
+    assert_eq!(result.failed, vec![e, criterion]);
+}
+
+#[test]
+fn test_qualified_subject_passes() {
+    let e = Env::default();
+    let (client, _admin, identity) = qualified(&e);
+    let result = client.check_eligibility(&identity, &strict_policy(&e));
+    assert!(result.eligible);
+    assert!(result.failed.is_empty());
+}
+
+#[test]
+fn test_empty_policy_passes_trivially() {
+    let e = Env::default();
+    let (client, ..) = test_helpers::setup_with_token(&e);
+    let stranger = Address::generate(&e);
+    let result = client.check_eligibility(&stranger, &empty_policy());
+    assert!(result.eligible);
+    assert!(result.failed.is_empty());
+}
+
+#[test]
+fn test_tier_below_minimum_fails() {
+    let e = Env::default();
+    let (client, _admin, identity) = qualified(&e);
+    e.ledger().with_mut(|li| li.timestamp += DURATION);
+    client.withdraw_bond(&identity, &1);
+    assert_fails_only(&client, &e, &identity, EligibilityCriterion::Tier);
+}
+
+#[test]
+fn test_missing_attestation_weight_fails() {
+    let e = Env::default();
+    let (client, _admin, identity) = qualified(&e);
+    let mut policy = strict_policy(&e);
+    policy.min_attestation_weight = 6;
+    let result = client.check_eligibility(&identity, &policy);
+    assert_eq!(result.failed, vec![&e, EligibilityCriterion::Attestation]);
+
+    policy.min_attestation_weight = 5;
+    policy.attestation_category = Some(Symbol::new(&e, "aml"));
+    let result = client.check_eligibility(&identity, &policy);
+    assert_eq!(result.failed, vec![&e, EligibilityCriterion::Attestation]);
+}
+
+#[test]
+fn test_recent_slashes_fail_and_age_out() {
+    let e = Env::default();
+    let (client, admin, identity) = qualified(&e);
+    client.slash(&admin, &identity, &1);
+    client.slash(&admin, &identity, &1);
+    assert_fails_only(&client, &e, &identity, EligibilityCriterion::SlashCount);
+
+    // Once both slashes fall out of the lookback window the criterion passes again.
+    e.ledger().with_mut(|li| li.timestamp += WEEK + 1);
+    assert!(
+        client
+            .check_eligibility(&identity, &strict_policy(&e))
+            .eligible
+    );
+
+    let mut all_time = strict_policy(&e);
+    all_time.slash_lookback_secs = 0;
+    let result = client.check_eligibility(&identity, &all_time);
+    assert_eq!(result.failed, vec![&e, EligibilityCriterion::SlashCount]);
+}
+
+#[test]
+fn test_quarantined_or_frozen_bond_fails() {
+    let e = Env::default();
+    let (client, _admin, identity) = qualified(&e);
+    for status in [BondStatus::Quarantined, BondStatus::Frozen] {
+        e.as_contract(&client.address, || {
+            let key = DataKey::Bond(identity.clone());
+            let mut bond: IdentityBond = e.storage().instance().get(&key).unwrap();
+            bond.status = status;
+            e.storage().instance().set(&key, &bond);
+        });
+        assert_fails_only(&client, &e, &identity, EligibilityCriterion::Restricted);
+    }
+}
+
+#[test]
+fn test_reports_every_failed_criterion() {
+    let e = Env::default();
+    let (client, ..) = test_helpers::setup_with_token(&e);
+    let stranger = Address::generate(&e);
+    let result = client.check_eligibility(&stranger, &strict_policy(&e));
+    assert!(!result.eligible);
+    assert_eq!(
+        result.failed,
+        vec![
+            &e,
+            EligibilityCriterion::Tier,
+            EligibilityCriterion::Attestation
+        ]
+    );
+}
@@ -21,11 +21,12 @@ fn test_emergency_withdraw_success_records_audit_trail() {
     let (client, admin, governance, treasury, identity) = setup(&e);
 
     client.set_emergency_config(&admin, &governance, &treasury, &500, &true);
-    client.create_bond(&identity, &1000_i128, &86_400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86_400_u64, &false, &0_u64);
 
     let reason = Symbol::new(&e, "crisis");
-    let bond = client.emergency_withdraw(&admin, &governance, &200_i128, &reason);
-    assert_eq!(bond.bonded_amount, 800);
+    let bond =
+        client.emergency_withdraw(&admin, &governance, &identity, &200_000_000_i128, &reason);
+    assert_eq!(bond.bonded_amount, 800_000_000);
 
     let latest_id = client.get_latest_emergency_record_id();
     assert_eq!(latest_id, 1);
@@ -33,9 +34,9 @@ fn test_emergency_withdraw_success_records_audit_trail() {
     let record = client.get_emergency_record(&latest_id);
     assert_eq!(record.id, 1);
     assert_eq!(record.identity, identity);
-    assert_eq!(record.gross_amount, 200);
-    assert_eq!(record.fee_amount, 10); // 5% of 200
-    assert_eq!(record.net_amount, 190);
+    assert_eq!(record.gross_amount, 200_000_000);
+    assert_eq!(record.fee_amount, 10_000_000); // 5% of 200_000_000
+    assert_eq!(record.net_amount, 190_000_000);
     assert_eq!(record.treasury, treasury);
     assert_eq!(record.approved_admin, admin);
     assert_eq!(record.approved_governance, governance);
@@ -50,11 +51,23 @@ fn test_emergency_withdraw_multiple_records_increment_ids() {
     let (client, admin, governance, treasury, identity) = setup(&e);
 
     client.set_emergency_config(&admin, &governance, &treasury, &100, &true);
-    client.create_bond(&identity, &1000_i128, &86_400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86_400_u64, &false, &0_u64);
 
-    client.emergency_withdraw(&admin, &governance, &100_i128, &Symbol::new(&e, "ops1"));
+    client.emergency_withdraw(
+        &admin,
+        &governance,
+        &identity,
+        &100_000_000_i128,
+        &Symbol::new(&e, "ops1"),
+    );
     e.ledger().with_mut(|li| li.timestamp = 101);
-    client.emergency_withdraw(&admin, &governance, &100_i128, &Symbol::new(&e, "ops2"));
+    client.emergency_withdraw(
+        &admin,
+        &governance,
+        &identity,
+        &100_000_000_i128,
+        &Symbol::new(&e, "ops2"),
+    );
 
     let first = client.get_emergency_record(&1_u64);
     let second = client.get_emergency_record(&2_u64);
@@ -107,9 +120,15 @@ fn test_emergency_withdraw_rejected_when_disabled() {
     let (client, admin, governance, treasury, identity) = setup(&e);
 
     client.set_emergency_config(&admin, &governance, &treasury, &500, &false);
-    client.create_bond(&identity, &1000_i128, &86_400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86_400_u64, &false, &0_u64);
 
-    client.emergency_withdraw(&admin, &governance, &100_i128, &Symbol::new(&e, "crisis"));
+    client.emergency_withdraw(
+        &admin,
+        &governance,
+        &identity,
+        &100_000_000_i128,
+        &Symbol::new(&e, "crisis"),
+    );
 }
 
 #[test]
@@ -120,12 +139,13 @@ fn test_emergency_withdraw_requires_governance_approver() {
     let wrong_governance = Address::generate(&e);
 
     client.set_emergency_config(&admin, &governance, &treasury, &500, &true);
-    client.create_bond(&identity, &1000_i128, &86_400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86_400_u64, &false, &0_u64);
 
     client.emergency_withdraw(
         &admin,
         &wrong_governance,
-        &100_i128,
+        &identity,
+        &100_000_000_i128,
         &Symbol::new(&e, "crisis"),
     );
 }
@@ -137,10 +157,16 @@ fn test_emergency_withdraw_respects_slashed_available_balance() {
     let (client, admin, governance, treasury, identity) = setup(&e);
 
     client.set_emergency_config(&admin, &governance, &treasury, &500, &true);
-    client.create_bond(&identity, &1000_i128, &86_400_u64, &false, &0_u64);
-    client.slash(&admin, &900_i128);
+    client.create_bond(&identity, &1_000_000_000_i128, &86_400_u64, &false, &0_u64);
+    client.slash(&admin, &identity, &900_000_000_i128);
 
-    client.emergency_withdraw(&admin, &governance, &101_i128, &Symbol::new(&e, "crisis"));
+    client.emergency_withdraw(
+        &admin,
+        &governance,
+        &identity,
+        &101_000_000_i128,
+        &Symbol::new(&e, "crisis"),
+    );
 }
 
 #[test]
@@ -150,8 +176,14 @@ fn test_emergency_withdraw_rejects_non_positive_amount() {
     let (client, admin, governance, treasury, identity) = setup(&e);
 
     client.set_emergency_config(&admin, &governance, &treasury, &500, &true);
-    client.create_bond(&identity, &1000_i128, &86_400_u64, &false, &0_u64);
-    client.emergency_withdraw(&admin, &governance, &0_i128, &Symbol::new(&e, "crisis"));
+    client.create_bond(&identity, &1_000_000_000_i128, &86_400_u64, &false, &0_u64);
+    client.emergency_withdraw(
+        &admin,
+        &governance,
+        &identity,
+        &0_i128,
+        &Symbol::new(&e, "crisis"),
+    );
 }
 
 #[test]
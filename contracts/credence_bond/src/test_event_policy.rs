@@ -0,0 +1,45 @@
+//! Tests for the admin-managed event policy.
+
+use crate::test_helpers;
+use crate::tiered_bond::{TIER_BRONZE_MAX, TIER_SILVER_MAX};
+use soroban_sdk::testutils::Events;
+use soroban_sdk::{Address, Env, FromVal, Symbol};
+
+fn emitted(e: &Env, contract_id: &Address, name: &str) -> bool {
+    e.events().all().iter().any(|(id, topics, _)| {
+        id == *contract_id && Symbol::from_val(e, &topics.get(0).unwrap()) == Symbol::new(e, name)
+    })
+}
+
+#[test]
+fn test_silenced_event_suppressed_until_reenabled() {
+    let e = Env::default();
+    let (client, admin, identity, _token, contract_id) = test_helpers::setup_with_token(&e);
+    let tier_changed = Symbol::new(&e, "tier_changed");
+
+    client.set_event_enabled(&admin, &tier_changed, &false);
+    client.create_bond(&identity, &TIER_BRONZE_MAX, &86_400_u64, &false, &0_u64);
+    assert!(!emitted(&e, &contract_id, "tier_changed"));
+    // Mandatory events are unaffected.
+    assert!(emitted(&e, &contract_id, "bond_created"));
+
+    client.set_event_enabled(&admin, &tier_changed, &true);
+    client.top_up(&identity, &identity, &(TIER_SILVER_MAX - TIER_BRONZE_MAX));
+    assert!(emitted(&e, &contract_id, "tier_changed"));
+}
+
+#[test]
+#[should_panic(expected = "event not silenceable")]
+fn test_mandatory_event_cannot_be_silenced() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    client.set_event_enabled(&admin, &Symbol::new(&e, "bond_slashed"), &false);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_policy_is_admin_only() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.set_event_enabled(&identity, &Symbol::new(&e, "tier_changed"), &false);
+}
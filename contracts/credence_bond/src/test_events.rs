@@ -1,5 +1,7 @@
 #![cfg(test)]
 
+use crate::context::Context;
+use crate::events::EVENT_SCHEMA_VERSION;
 use crate::{CredenceBond, CredenceBondClient};
 use soroban_sdk::token::{StellarAssetClient, TokenClient};
 use soroban_sdk::{
@@ -12,7 +14,7 @@ fn test_lifecycle_event_emissions() {
     let e = Env::default();
     e.mock_all_auths();
 
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -24,19 +26,19 @@ fn test_lifecycle_event_emissions() {
     let token_admin = Address::generate(&e);
     let token_addr = e.register_stellar_asset_contract_v2(token_admin).address();
 
-    // 1. Mint 100,000 tokens to the identity so they have funds to bond
+    // 1. Mint 100,000,000 tokens to the identity so they have funds to bond
     let token_admin_client = StellarAssetClient::new(&e, &token_addr);
-    token_admin_client.mint(&identity, &100_000_i128);
+    token_admin_client.mint(&identity, &100_000_000_i128);
 
     // 2. APPROVE the contract to spend the identity's tokens (Fixes "not enough allowance")
     let token_client = TokenClient::new(&e, &token_addr);
-    token_client.approve(&identity, &contract_id, &100_000_i128, &99999_u32);
+    token_client.approve(&identity, &contract_id, &100_000_000_i128, &99999_u32);
 
     // 3. Tell the CredenceBond contract which token to use
     client.set_token(&admin, &token_addr);
 
     // --- 1. Test Create Bond Event ---
-    let initial_amount = 10_000_i128;
+    let initial_amount = 10_000_000_i128;
     let duration = 86400_u64;
     let is_rolling = false;
     let notice_period = 0_u64;
@@ -59,20 +61,26 @@ fn test_lifecycle_event_emissions() {
 
     // Decode Topics
     let topic_name = Symbol::from_val(&e, &create_event.1.get(0).unwrap());
-    let topic_ident = Address::from_val(&e, &create_event.1.get(1).unwrap());
+    let topic_version = u32::from_val(&e, &create_event.1.get(1).unwrap());
+    let topic_ident = Address::from_val(&e, &create_event.1.get(2).unwrap());
 
     assert_eq!(topic_name, Symbol::new(&e, "bond_created"));
+    assert_eq!(topic_version, EVENT_SCHEMA_VERSION);
     assert_eq!(topic_ident, identity.clone());
 
     // Decode Data
-    let create_data = <(i128, u64, bool)>::from_val(&e, &create_event.2);
-    assert_eq!(create_data, (initial_amount, duration, is_rolling));
+    let (amount, bond_duration, rolling, _) =
+        <(i128, u64, bool, Context)>::from_val(&e, &create_event.2);
+    assert_eq!(
+        (amount, bond_duration, rolling),
+        (initial_amount, duration, is_rolling)
+    );
 
     // --- 2. Test Top Up Event (Increase) ---
-    let top_up_amount = 5_000_i128;
-    let expected_total_after_top_up = 15_000_i128;
+    let top_up_amount = 5_000_000_i128;
+    let expected_total_after_top_up = 15_000_000_i128;
 
-    client.top_up(&top_up_amount);
+    client.top_up(&identity, &identity, &top_up_amount);
 
     let events = e.events().all();
     let top_up_event = events
@@ -83,26 +91,28 @@ fn test_lifecycle_event_emissions() {
 
     // Decode Topics
     let topic_name = Symbol::from_val(&e, &top_up_event.1.get(0).unwrap());
-    let topic_ident = Address::from_val(&e, &top_up_event.1.get(1).unwrap());
+    let topic_version = u32::from_val(&e, &top_up_event.1.get(1).unwrap());
+    let topic_ident = Address::from_val(&e, &top_up_event.1.get(2).unwrap());
 
     assert_eq!(topic_name, Symbol::new(&e, "bond_increased"));
+    assert_eq!(topic_version, EVENT_SCHEMA_VERSION);
     assert_eq!(topic_ident, identity.clone());
 
     // Decode Data
-    let top_up_data = <(i128, i128)>::from_val(&e, &top_up_event.2);
-    assert_eq!(top_up_data, (top_up_amount, expected_total_after_top_up));
+    let (added, total, _) = <(i128, i128, Context)>::from_val(&e, &top_up_event.2);
+    assert_eq!((added, total), (top_up_amount, expected_total_after_top_up));
 
     // --- 3. Test Withdraw Event ---
-    let withdraw_amount = 3_000_i128;
-    // Current bonded = 15,000. After withdrawing 3,000, expected remaining = 12,000.
-    let expected_remaining_bonded = 12_000_i128;
+    let withdraw_amount = 3_000_000_i128;
+    // Current bonded = 15,000,000. After withdrawing 3,000,000, expected remaining = 12,000,000.
+    let expected_remaining_bonded = 12_000_000_i128;
 
     // Fast-forward the ledger time so the 86400s lock-up period expires
     let mut ledger_info = e.ledger().get();
     ledger_info.timestamp += duration + 1;
     e.ledger().set(ledger_info);
 
-    client.withdraw(&withdraw_amount);
+    client.withdraw(&identity, &withdraw_amount);
 
     let events = e.events().all();
     let withdraw_event = events
@@ -113,12 +123,95 @@ fn test_lifecycle_event_emissions() {
 
     // Decode Topics
     let topic_name = Symbol::from_val(&e, &withdraw_event.1.get(0).unwrap());
-    let topic_ident = Address::from_val(&e, &withdraw_event.1.get(1).unwrap());
+    let topic_version = u32::from_val(&e, &withdraw_event.1.get(1).unwrap());
+    let topic_ident = Address::from_val(&e, &withdraw_event.1.get(2).unwrap());
 
     assert_eq!(topic_name, Symbol::new(&e, "bond_withdrawn"));
+    assert_eq!(topic_version, EVENT_SCHEMA_VERSION);
     assert_eq!(topic_ident, identity);
 
     // Decode Data
-    let withdraw_data = <(i128, i128)>::from_val(&e, &withdraw_event.2);
-    assert_eq!(withdraw_data, (withdraw_amount, expected_remaining_bonded));
+    let (withdrawn, remaining, context) = <(i128, i128, Context)>::from_val(&e, &withdraw_event.2);
+    assert_eq!(
+        (withdrawn, remaining),
+        (withdraw_amount, expected_remaining_bonded)
+    );
+    assert_eq!(context.timestamp, e.ledger().timestamp());
+}
+
+#[test]
+fn test_event_schema_version_exposed() {
+    let e = Env::default();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(&e, &contract_id);
+    assert_eq!(client.get_event_schema_version(), EVENT_SCHEMA_VERSION);
+}
+
+#[test]
+fn test_emitted_event_carries_schema_version() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    client.initialize(&admin);
+    client.register_attester(&Address::generate(&e));
+
+    let (_, topics, _) = e
+        .events()
+        .all()
+        .into_iter()
+        .rev()
+        .find(|ev| ev.0 == contract_id)
+        .unwrap();
+    assert_eq!(topics.len(), 2);
+    assert_eq!(
+        Symbol::from_val(&e, &topics.get(0).unwrap()),
+        Symbol::new(&e, "attester_registered")
+    );
+    assert_eq!(
+        u32::from_val(&e, &topics.get(1).unwrap()),
+        EVENT_SCHEMA_VERSION
+    );
+}
+
+/// Every contract module must publish through `events::publish` / `events::publish_for` so the
+/// schema version is never left out. Scans the crate sources for direct `events()` calls.
+#[test]
+fn test_all_events_route_through_versioned_emitter() {
+    extern crate std;
+    use std::path::Path;
+    use std::string::String;
+    use std::vec::Vec;
+
+    fn scan(dir: &Path, offenders: &mut Vec<String>) {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            if path.is_dir() {
+                if !matches!(name.as_str(), "fuzz" | "integration" | "security") {
+                    scan(&path, offenders);
+                }
+                continue;
+            }
+            let is_test = name.starts_with("test") || name.ends_with("_test.rs");
+            if !name.ends_with(".rs") || is_test || name == "events.rs" {
+                continue;
+            }
+            let source = std::fs::read_to_string(&path).unwrap();
+            if source.contains(".events()") {
+                offenders.push(name);
+            }
+        }
+    }
+
+    let mut offenders = Vec::new();
+    scan(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+        &mut offenders,
+    );
+    assert!(
+        offenders.is_empty(),
+        "modules publishing events directly: {offenders:?}"
+    );
 }
@@ -0,0 +1,135 @@
+//! Tests for per-identity and global exposure caps.
+
+use crate::test_helpers;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env};
+
+const UNIT: i128 = 1_000_000;
+const DURATION: u64 = 86_400;
+
+fn fund(e: &Env, token: &Address, bond_contract: &Address, who: &Address, amount: i128) {
+    StellarAssetClient::new(e, token).mint(who, &amount);
+    let expiration = e.ledger().sequence().saturating_add(10_000);
+    TokenClient::new(e, token).approve(who, bond_contract, &amount, &expiration);
+}
+
+#[test]
+fn test_caps_default_to_unlimited() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    let caps = client.get_exposure_caps();
+    assert_eq!(caps.per_identity_cap, 0);
+    assert_eq!(caps.global_cap, 0);
+
+    client.create_bond(&identity, &(1_000 * UNIT), &DURATION, &false, &0);
+    assert_eq!(client.get_total_bonded(), 1_000 * UNIT);
+}
+
+#[test]
+#[should_panic(expected = "exposure cap exceeded")]
+fn test_per_identity_cap_hit_on_top_up() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.set_exposure_caps(&admin, &(15 * UNIT), &0);
+    client.create_bond(&identity, &(10 * UNIT), &DURATION, &false, &0);
+
+    client.top_up(&identity, &identity, &(5 * UNIT));
+    client.top_up(&identity, &identity, &UNIT);
+}
+
+#[test]
+#[should_panic(expected = "exposure cap exceeded")]
+fn test_per_identity_cap_counts_additional_bonds() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.set_exposure_caps(&admin, &(15 * UNIT), &0);
+    client.create_bond_v2(&identity, &(10 * UNIT), &DURATION);
+
+    client.create_bond(&identity, &(10 * UNIT), &DURATION, &false, &0);
+}
+
+#[test]
+fn test_per_identity_cap_ignores_replaced_bond() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.set_exposure_caps(&admin, &(15 * UNIT), &0);
+    client.create_bond(&identity, &(10 * UNIT), &DURATION, &false, &0);
+    client.slash(&admin, &identity, &(10 * UNIT));
+
+    // The fully slashed bond is replaced, so only the new one counts against the cap.
+    let bond = client.create_bond(&identity, &(15 * UNIT), &DURATION, &false, &0);
+    assert_eq!(bond.bonded_amount, 15 * UNIT);
+}
+
+#[test]
+#[should_panic(expected = "exposure cap exceeded")]
+fn test_global_cap_hit_by_second_identity() {
+    let e = Env::default();
+    let (client, admin, identity, token, bond_contract) = test_helpers::setup_with_token(&e);
+    client.set_exposure_caps(&admin, &0, &(15 * UNIT));
+    client.create_bond(&identity, &(10 * UNIT), &DURATION, &false, &0);
+
+    let second = Address::generate(&e);
+    fund(&e, &token, &bond_contract, &second, 6 * UNIT);
+    client.create_bond(&second, &(6 * UNIT), &DURATION, &false, &0);
+}
+
+#[test]
+fn test_withdrawal_restores_capacity() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.set_exposure_caps(&admin, &0, &(15 * UNIT));
+    client.create_bond(&identity, &(10 * UNIT), &DURATION, &false, &0);
+    assert!(client
+        .try_top_up(&identity, &identity, &(6 * UNIT))
+        .is_err());
+
+    e.ledger().with_mut(|li| li.timestamp = DURATION);
+    client.withdraw(&identity, &(4 * UNIT));
+    assert_eq!(client.get_total_bonded(), 6 * UNIT);
+
+    let bond = client.top_up(&identity, &identity, &(6 * UNIT));
+    assert_eq!(bond.bonded_amount, 12 * UNIT);
+    assert_eq!(client.get_total_bonded(), 12 * UNIT);
+}
+
+#[test]
+fn test_slash_and_rebond_restore_capacity() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.set_exposure_caps(&admin, &0, &(15 * UNIT));
+    client.create_bond(&identity, &(10 * UNIT), &DURATION, &false, &0);
+
+    client.slash(&admin, &identity, &(4 * UNIT));
+    assert_eq!(client.get_total_bonded(), 6 * UNIT);
+    client.slash(&admin, &identity, &(6 * UNIT));
+    assert_eq!(client.get_total_bonded(), 0);
+
+    // The fully slashed bond is replaced and the whole global cap is available again.
+    client.create_bond(&identity, &(15 * UNIT), &DURATION, &false, &0);
+    assert_eq!(client.get_total_bonded(), 15 * UNIT);
+}
+
+#[test]
+fn test_full_withdrawal_after_slash_releases_only_the_rest() {
+    let e = Env::default();
+    let (client, admin, identity, token, bond_contract) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &(10 * UNIT), &DURATION, &false, &0);
+    let second = Address::generate(&e);
+    fund(&e, &token, &bond_contract, &second, 5 * UNIT);
+    client.create_bond(&second, &(5 * UNIT), &DURATION, &false, &0);
+
+    client.slash(&admin, &identity, &(4 * UNIT));
+    e.ledger().with_mut(|li| li.timestamp = DURATION);
+    client.withdraw_bond_full(&identity);
+    assert_eq!(client.get_total_bonded(), 5 * UNIT);
+}
+
+#[test]
+#[should_panic(expected = "exposure caps must be non-negative")]
+fn test_negative_cap_rejected() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    client.set_exposure_caps(&admin, &-1, &0);
+}
@@ -0,0 +1,142 @@
+//! Tests for `extend_and_top_up`: equivalence with separate top-up and extension calls,
+//! all-or-nothing validation and the single combined event.
+
+use crate::test_helpers;
+use crate::{BondStatus, CredenceBondClient, IdentityBond};
+use soroban_sdk::testutils::{Events, Ledger};
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, Env, FromVal, Symbol};
+
+const AMOUNT: i128 = 10_000_000;
+const DURATION: u64 = 86_400;
+const ADD_AMOUNT: i128 = 5_000_000;
+const ADD_DURATION: u64 = 3_600;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address, Address) {
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (client, admin, identity, token, contract_id) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    (client, admin, identity, token, contract_id)
+}
+
+fn count_events(e: &Env, contract_id: &Address, name: &str) -> usize {
+    e.events()
+        .all()
+        .iter()
+        .filter(|(id, topics, _)| {
+            id == contract_id
+                && Symbol::from_val(e, &topics.get(0).unwrap()) == Symbol::new(e, name)
+        })
+        .count()
+}
+
+fn assert_unchanged(
+    e: &Env,
+    client: &CredenceBondClient,
+    identity: &Address,
+    token: &Address,
+    before: &IdentityBond,
+    balance: i128,
+) {
+    assert_eq!(client.get_identity_state(identity), *before);
+    assert_eq!(TokenClient::new(e, token).balance(identity), balance);
+}
+
+#[test]
+fn test_matches_sequential_calls() {
+    let combined_env = Env::default();
+    let (combined, _, identity, token, _) = setup(&combined_env);
+    let bond = combined.extend_and_top_up(&identity, &ADD_AMOUNT, &ADD_DURATION);
+
+    let sequential_env = Env::default();
+    let (sequential, _, seq_identity, seq_token, _) = setup(&sequential_env);
+    sequential.top_up(&seq_identity, &seq_identity, &ADD_AMOUNT);
+    let expected = sequential.extend_duration(&seq_identity, &ADD_DURATION);
+
+    assert_eq!(bond.bonded_amount, expected.bonded_amount);
+    assert_eq!(bond.bond_duration, expected.bond_duration);
+    assert_eq!(bond.bond_start, expected.bond_start);
+    assert_eq!(bond.status, expected.status);
+    assert_eq!(
+        TokenClient::new(&combined_env, &token).balance(&identity),
+        TokenClient::new(&sequential_env, &seq_token).balance(&seq_identity)
+    );
+    assert_eq!(
+        combined.get_bond_end(&identity),
+        sequential.get_bond_end(&seq_identity)
+    );
+}
+
+#[test]
+fn test_emits_single_combined_event() {
+    let e = Env::default();
+    let (client, _admin, identity, _token, contract_id) = setup(&e);
+    client.extend_and_top_up(&identity, &ADD_AMOUNT, &ADD_DURATION);
+    assert_eq!(count_events(&e, &contract_id, "bond_extended"), 1);
+    assert_eq!(count_events(&e, &contract_id, "bond_increased"), 0);
+}
+
+#[test]
+fn test_tier_change_emitted_once_on_final_amount() {
+    let e = Env::default();
+    let (client, _admin, identity, _token, contract_id) = setup(&e);
+    let to_silver = crate::tiered_bond::TIER_BRONZE_MAX - AMOUNT;
+    client.extend_and_top_up(&identity, &to_silver, &ADD_DURATION);
+    assert_eq!(count_events(&e, &contract_id, "tier_changed"), 1);
+}
+
+#[test]
+fn test_bad_duration_leaves_bond_unchanged() {
+    let e = Env::default();
+    let (client, _admin, identity, token, _) = setup(&e);
+    let before = client.get_identity_state(&identity);
+    let balance = TokenClient::new(&e, &token).balance(&identity);
+
+    let too_long = client.get_max_bond_duration();
+    assert!(client
+        .try_extend_and_top_up(&identity, &ADD_AMOUNT, &too_long)
+        .is_err());
+    assert!(client
+        .try_extend_and_top_up(&identity, &ADD_AMOUNT, &0)
+        .is_err());
+    assert_unchanged(&e, &client, &identity, &token, &before, balance);
+}
+
+#[test]
+fn test_bad_amount_leaves_bond_unchanged() {
+    let e = Env::default();
+    let (client, _admin, identity, token, _) = setup(&e);
+    let before = client.get_identity_state(&identity);
+    let balance = TokenClient::new(&e, &token).balance(&identity);
+
+    assert!(client
+        .try_extend_and_top_up(&identity, &1, &ADD_DURATION)
+        .is_err());
+    assert_unchanged(&e, &client, &identity, &token, &before, balance);
+}
+
+#[test]
+fn test_exposure_cap_leaves_bond_unchanged() {
+    let e = Env::default();
+    let (client, admin, identity, token, _) = setup(&e);
+    client.set_exposure_caps(&admin, &AMOUNT, &0);
+    let before = client.get_identity_state(&identity);
+    let balance = TokenClient::new(&e, &token).balance(&identity);
+
+    assert!(client
+        .try_extend_and_top_up(&identity, &ADD_AMOUNT, &ADD_DURATION)
+        .is_err());
+    assert_unchanged(&e, &client, &identity, &token, &before, balance);
+}
+
+#[test]
+fn test_renews_matured_bond() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = setup(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1_000 + DURATION);
+    client.withdraw_bond(&identity, &ADD_AMOUNT);
+
+    let bond = client.extend_and_top_up(&identity, &ADD_AMOUNT, &ADD_DURATION);
+    assert_eq!(bond.status, BondStatus::Active);
+    assert_eq!(bond.bonded_amount, AMOUNT);
+}
@@ -0,0 +1,72 @@
+//! Tests for `extend_duration`: holder auth and the admin-configurable maximum duration.
+
+use crate::parameters::{DEFAULT_MAX_BOND_DURATION, MAX_MAX_BOND_DURATION};
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::{Address, Env, IntoVal};
+
+const BOND_AMOUNT: i128 = 10_000_000;
+const DURATION: u64 = 86_400;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address) {
+    let (client, admin, identity, _token, contract_id) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &BOND_AMOUNT, &DURATION, &false, &0_u64);
+    (client, admin, identity, contract_id)
+}
+
+#[test]
+fn test_extend_to_exactly_max_duration() {
+    let e = Env::default();
+    let (client, _admin, identity, _contract_id) = setup(&e);
+    assert_eq!(client.get_max_bond_duration(), DEFAULT_MAX_BOND_DURATION);
+
+    let bond = client.extend_duration(&identity, &(DEFAULT_MAX_BOND_DURATION - DURATION));
+    assert_eq!(bond.bond_duration, DEFAULT_MAX_BOND_DURATION);
+}
+
+#[test]
+#[should_panic(expected = "duration exceeds maximum")]
+fn test_extend_past_max_duration_panics() {
+    let e = Env::default();
+    let (client, _admin, identity, _contract_id) = setup(&e);
+    client.extend_duration(&identity, &(DEFAULT_MAX_BOND_DURATION - DURATION + 1));
+}
+
+#[test]
+fn test_admin_can_raise_max_duration() {
+    let e = Env::default();
+    let (client, admin, identity, _contract_id) = setup(&e);
+    client.set_max_bond_duration(&admin, &MAX_MAX_BOND_DURATION);
+    assert_eq!(client.get_max_bond_duration(), MAX_MAX_BOND_DURATION);
+
+    let bond = client.extend_duration(&identity, &(MAX_MAX_BOND_DURATION - DURATION));
+    assert_eq!(bond.bond_duration, MAX_MAX_BOND_DURATION);
+}
+
+#[test]
+#[should_panic(expected = "max_bond_duration out of bounds")]
+fn test_max_duration_bounded() {
+    let e = Env::default();
+    let (client, admin, _identity, _contract_id) = setup(&e);
+    client.set_max_bond_duration(&admin, &(MAX_MAX_BOND_DURATION + 1));
+}
+
+#[test]
+fn test_stranger_cannot_extend() {
+    let e = Env::default();
+    let (client, _admin, identity, contract_id) = setup(&e);
+    let stranger = Address::generate(&e);
+
+    e.mock_auths(&[MockAuth {
+        address: &stranger,
+        invoke: &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "extend_duration",
+            args: (identity.clone(), DURATION).into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_extend_duration(&identity, &DURATION).is_err());
+    assert_eq!(client.get_identity_state(&identity).bond_duration, DURATION);
+}
@@ -0,0 +1,132 @@
+//! Tests for failure injection on the token, callback and role-registry helpers.
+
+use crate::test_helpers;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, String, Symbol};
+
+mod sink {
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    /// Accepts every callback and subject hook delivery.
+    #[contract]
+    pub struct Sink;
+
+    #[contractimpl]
+    impl Sink {
+        pub fn on_collect(_e: Env, _amount: i128) {}
+
+        pub fn on_attestation(_e: Env, _subject: Address, _attestation_id: u64, _added: bool) {}
+    }
+}
+
+use sink::Sink;
+
+const AMOUNT: i128 = 30_000_000;
+const DURATION: u64 = 86_400;
+
+fn failpoint(e: &Env, name: &str) -> Symbol {
+    Symbol::new(e, name)
+}
+
+#[test]
+fn test_token_failure_mid_queue_leaves_queue_intact() {
+    let e = Env::default();
+    let (client, admin, identity, token, contract_id) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0);
+    e.ledger().with_mut(|li| li.timestamp = DURATION);
+    let token_client = TokenClient::new(&e, &token);
+    token_client.transfer(&contract_id, &Address::generate(&e), &AMOUNT);
+
+    // Three withdrawals queue behind the empty balance, then the contract is refunded.
+    client.set_payout_queue_enabled(&admin, &true);
+    for _ in 0..3 {
+        client.withdraw(&identity, &(AMOUNT / 3));
+    }
+    StellarAssetClient::new(&e, &token).mint(&contract_id, &AMOUNT);
+    let before = token_client.balance(&identity);
+
+    client.arm_failpoint(&admin, &failpoint(&e, "token_transfer"), &2);
+    assert!(client.try_process_payout_queue(&10).is_err());
+    assert_eq!(client.get_payout_queue_len(), 3);
+    assert_eq!(client.get_pending_payout(&0).unwrap().amount, AMOUNT / 3);
+    assert_eq!(token_client.balance(&identity), before);
+    assert_eq!(token_client.balance(&contract_id), AMOUNT);
+
+    client.disarm_failpoint(&admin, &failpoint(&e, "token_transfer"));
+    assert_eq!(client.process_payout_queue(&10), 3);
+    assert_eq!(token_client.balance(&identity), before + AMOUNT);
+}
+
+#[test]
+fn test_callback_failure_reverts_fee_collection() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.set_callback(&admin, &e.register(Sink, ()));
+    client.deposit_fees(&identity, &750);
+
+    client.arm_failpoint(&admin, &failpoint(&e, "callback_invoke"), &1);
+    assert!(client.try_collect_fees(&admin).is_err());
+    // The failpoint fired inside the reverted call, so it is still armed.
+    assert!(client.try_collect_fees(&admin).is_err());
+
+    client.disarm_failpoint(&admin, &failpoint(&e, "callback_invoke"));
+    assert_eq!(client.collect_fees(&admin), 750);
+}
+
+#[test]
+fn test_hook_failure_queues_delivery() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+    let subject = Address::generate(&e);
+    client.register_subject_hook(&subject, &e.register(Sink, ()));
+
+    client.arm_failpoint(&admin, &failpoint(&e, "callback_invoke"), &1);
+    let att = client.add_attestation(&attester, &subject, &String::from_str(&e, "kyc"));
+    assert_eq!(client.get_attestation(&att.id).identity, subject);
+    assert_eq!(client.get_failed_callbacks().len(), 1);
+
+    assert_eq!(client.retry_failed_callbacks(&10), 1);
+    assert!(client.get_failed_callbacks().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "failpoint triggered: admin_contract_lookup")]
+fn test_admin_contract_lookup_failure() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    client.set_role_registry(&admin, &Address::generate(&e));
+    client.arm_failpoint(&admin, &failpoint(&e, "admin_contract_lookup"), &1);
+    client.set_slash_execution_window(&Address::generate(&e), &1_000);
+}
+
+#[test]
+fn test_failpoint_fires_on_nth_invocation_only() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.arm_failpoint(&admin, &failpoint(&e, "token_transfer"), &2);
+    client.deposit_fees(&identity, &100);
+    assert!(client.try_deposit_fees(&identity, &100).is_err());
+    client.disarm_failpoint(&admin, &failpoint(&e, "token_transfer"));
+    client.deposit_fees(&identity, &100);
+    assert_eq!(client.collect_fees(&admin), 200);
+}
+
+#[test]
+#[should_panic(expected = "unknown failpoint")]
+fn test_unknown_failpoint_rejected() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    client.arm_failpoint(&admin, &failpoint(&e, "ledger_read"), &1);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_arming_requires_admin() {
+    let e = Env::default();
+    let (client, ..) = test_helpers::setup_with_token(&e);
+    let other = Address::generate(&e);
+    client.arm_failpoint(&other, &failpoint(&e, "token_transfer"), &1);
+}
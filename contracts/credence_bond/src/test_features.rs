@@ -0,0 +1,53 @@
+//! Tests for per-deployment feature flags.
+
+use crate::test_helpers;
+use soroban_sdk::{Env, String, Symbol};
+
+const FLAG: &str = "collateralized_attestations";
+
+#[test]
+fn test_flags_default_off_and_are_listed() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    let flag = Symbol::new(&e, FLAG);
+
+    assert!(!client.is_feature_enabled(&flag));
+    assert_eq!(client.get_features().get(0).unwrap(), (flag.clone(), false));
+
+    client.set_feature(&admin, &flag, &true);
+    assert!(client.is_feature_enabled(&flag));
+    assert_eq!(client.get_features().get(0).unwrap(), (flag, true));
+}
+
+#[test]
+fn test_gated_entrypoint_requires_flag() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &10_000_000_000, &86_400, &false, &0);
+    client.register_attester(&identity);
+    let data = String::from_str(&e, "vouch");
+
+    let result =
+        client.try_add_collateralized_attestation(&identity, &identity, &data, &1_000_000_000);
+    assert!(result.is_err());
+
+    client.set_feature(&admin, &Symbol::new(&e, FLAG), &true);
+    let att = client.add_collateralized_attestation(&identity, &identity, &data, &1_000_000_000);
+    assert_eq!(client.get_attestation_backing(&att.id), 1_000_000_000);
+}
+
+#[test]
+#[should_panic(expected = "unknown feature")]
+fn test_unknown_flag_rejected() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    client.set_feature(&admin, &Symbol::new(&e, "vesting_bonds"), &true);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_set_feature_requires_admin() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.set_feature(&identity, &Symbol::new(&e, FLAG), &true);
+}
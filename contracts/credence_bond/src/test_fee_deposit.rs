@@ -0,0 +1,72 @@
+//! Tests for `deposit_fees`: auth, amount validation, pool overflow and the token transfer.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address, Address) {
+    test_helpers::setup_with_token(e)
+}
+
+#[test]
+fn test_deposit_moves_tokens_and_funds_collection() {
+    let e = Env::default();
+    let (client, admin, identity, token, contract_id) = setup(&e);
+    let token_client = TokenClient::new(&e, &token);
+    let before = token_client.balance(&identity);
+
+    client.deposit_fees(&identity, &750_i128);
+    assert_eq!(before - token_client.balance(&identity), 750);
+    assert_eq!(token_client.balance(&contract_id), 750);
+    assert_eq!(client.collect_fees(&admin), 750);
+}
+
+#[test]
+#[should_panic(expected = "amount must be positive")]
+fn test_deposit_negative_amount_rejected() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = setup(&e);
+    client.deposit_fees(&identity, &-1_i128);
+}
+
+#[test]
+#[should_panic(expected = "amount must be positive")]
+fn test_deposit_zero_amount_rejected() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = setup(&e);
+    client.deposit_fees(&identity, &0_i128);
+}
+
+#[test]
+#[should_panic(expected = "fee pool overflow")]
+fn test_deposit_overflow_near_max() {
+    let e = Env::default();
+    let (client, _admin, identity, _token, contract_id) = setup(&e);
+    e.as_contract(&contract_id, || {
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "fees"), &(i128::MAX - 10));
+    });
+    client.deposit_fees(&identity, &11_i128);
+}
+
+#[test]
+fn test_deposit_requires_payer_auth() {
+    let e = Env::default();
+    let (client, _admin, identity, token, contract_id) = setup(&e);
+    let stranger = Address::generate(&e);
+    e.mock_auths(&[MockAuth {
+        address: &stranger,
+        invoke: &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "deposit_fees",
+            args: (identity.clone(), 500_i128).into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+
+    assert!(client.try_deposit_fees(&identity, &500_i128).is_err());
+    assert_eq!(TokenClient::new(&e, &token).balance(&contract_id), 0);
+}
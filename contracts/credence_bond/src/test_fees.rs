@@ -19,8 +19,8 @@ fn test_fee_zero_when_not_configured() {
     let (treasury, fee_bps) = client.get_fee_config();
     assert!(treasury.is_none());
     assert_eq!(fee_bps, 0);
-    let bond = client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
-    assert_eq!(bond.bonded_amount, 1000);
+    let bond = client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    assert_eq!(bond.bonded_amount, 1_000_000_000);
 }
 
 #[test]
@@ -40,8 +40,8 @@ fn test_fee_calculated_on_create_bond() {
     let (client, admin, identity) = setup(&e);
     let treasury = Address::generate(&e);
     client.set_fee_config(&admin, &treasury, &100_u32); // 1%
-    let bond = client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
-    assert_eq!(bond.bonded_amount, 990); // 1% fee = 10
+    let bond = client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    assert_eq!(bond.bonded_amount, 990_000_000); // 1% fee = 10_000_000
 }
 
 #[test]
@@ -50,8 +50,8 @@ fn test_fee_one_percent() {
     let (client, admin, identity) = setup(&e);
     let treasury = Address::generate(&e);
     client.set_fee_config(&admin, &treasury, &100_u32);
-    let bond = client.create_bond(&identity, &10_000_i128, &86400_u64, &false, &0_u64);
-    assert_eq!(bond.bonded_amount, 9_900);
+    let bond = client.create_bond(&identity, &10_000_000_000_i128, &86400_u64, &false, &0_u64);
+    assert_eq!(bond.bonded_amount, 9_900_000_000);
 }
 
 #[test]
@@ -60,8 +60,8 @@ fn test_fee_zero_bps() {
     let (client, admin, identity) = setup(&e);
     let treasury = Address::generate(&e);
     client.set_fee_config(&admin, &treasury, &0_u32);
-    let bond = client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
-    assert_eq!(bond.bonded_amount, 1000);
+    let bond = client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    assert_eq!(bond.bonded_amount, 1_000_000_000);
 }
 
 #[test]
@@ -70,7 +70,7 @@ fn test_fee_max_bps_capped() {
     let (client, admin, identity) = setup(&e);
     let treasury = Address::generate(&e);
     client.set_fee_config(&admin, &treasury, &10_000_u32);
-    let bond = client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    let bond = client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
     assert_eq!(bond.bonded_amount, 0);
 }
 
@@ -110,8 +110,9 @@ fn test_fee_accumulates_in_pool() {
     let (client, admin, identity) = setup(&e);
     let treasury = Address::generate(&e);
     client.set_fee_config(&admin, &treasury, &100_u32); // 1%
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64); // fee 10
-    client.create_bond(&identity, &2000_i128, &86400_u64, &false, &0_u64); // fee 20
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64); // fee 10_000_000
+    client.withdraw_bond_full(&identity);
+    client.create_bond(&identity, &2_000_000_000_i128, &86400_u64, &false, &0_u64); // fee 20_000_000
     let collected = client.collect_fees(&admin);
-    assert_eq!(collected, 10 + 20);
+    assert_eq!(collected, 10_000_000 + 20_000_000);
 }
@@ -0,0 +1,226 @@
+//! Canonical fixtures for downstream SDK tests.
+//!
+//! `cargo test -p credence_bond fixtures` runs a scripted bond lifecycle and renders every event
+//! the contract emits (decoded), the results of the main views and the final counters. The run
+//! is deterministic: the ledger time is fixed and addresses are rendered by role (`@identity`),
+//! never by strkey. The dump is compared line by line against `fixtures/bond_lifecycle.txt`, so
+//! any change to public behaviour shows up here. Rerun with `UPDATE_FIXTURES=1` to rewrite the
+//! file when the change is deliberate.
+//!
+//! `decode_val` / `decode_event` render any event or view value and can be reused by other tests.
+
+extern crate std;
+
+use std::format;
+use std::string::{String as StdString, ToString};
+use std::vec::Vec as StdVec;
+
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Bytes, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec};
+
+/// Role names for addresses, so dumps do not depend on generated strkeys.
+#[derive(Default)]
+pub struct Labels(StdVec<(Address, &'static str)>);
+
+impl Labels {
+    pub fn add(&mut self, address: &Address, name: &'static str) {
+        self.0.push((address.clone(), name));
+    }
+
+    #[must_use]
+    pub fn name(&self, address: &Address) -> &'static str {
+        self.0
+            .iter()
+            .find(|(a, _)| a == address)
+            .map_or("unknown", |(_, n)| *n)
+    }
+}
+
+/// Renders a value: integers carry their type suffix, addresses their role, structs are
+/// `{field: value}` in field order and enum variants `[Variant, ...]`.
+#[must_use]
+pub fn decode_val(e: &Env, labels: &Labels, v: &Val) -> StdString {
+    if v.is_void() {
+        return "()".to_string();
+    }
+    if let Ok(b) = bool::try_from_val(e, v) {
+        return format!("{b}");
+    }
+    if let Ok(n) = u32::try_from_val(e, v) {
+        return format!("{n}u32");
+    }
+    if let Ok(n) = i32::try_from_val(e, v) {
+        return format!("{n}i32");
+    }
+    if let Ok(n) = u64::try_from_val(e, v) {
+        return format!("{n}u64");
+    }
+    if let Ok(n) = i64::try_from_val(e, v) {
+        return format!("{n}i64");
+    }
+    if let Ok(n) = i128::try_from_val(e, v) {
+        return format!("{n}i128");
+    }
+    if let Ok(n) = u128::try_from_val(e, v) {
+        return format!("{n}u128");
+    }
+    if let Ok(s) = Symbol::try_from_val(e, v) {
+        return s.to_string();
+    }
+    if let Ok(a) = Address::try_from_val(e, v) {
+        return format!("@{}", labels.name(&a));
+    }
+    if let Ok(s) = String::try_from_val(e, v) {
+        return format!("{:?}", s.to_string());
+    }
+    if let Ok(b) = Bytes::try_from_val(e, v) {
+        let hex: StdString = b.iter().map(|x| format!("{x:02x}")).collect();
+        return format!("0x{hex}");
+    }
+    if let Ok(items) = Vec::<Val>::try_from_val(e, v) {
+        let parts: StdVec<StdString> = items.iter().map(|x| decode_val(e, labels, &x)).collect();
+        return format!("[{}]", parts.join(", "));
+    }
+    if let Ok(map) = Map::<Val, Val>::try_from_val(e, v) {
+        let parts: StdVec<StdString> = map
+            .iter()
+            .map(|(k, x)| {
+                format!(
+                    "{}: {}",
+                    decode_val(e, labels, &k),
+                    decode_val(e, labels, &x)
+                )
+            })
+            .collect();
+        return format!("{{{}}}", parts.join(", "));
+    }
+    format!("{v:?}")
+}
+
+/// Renders one event as `topics data`.
+#[must_use]
+pub fn decode_event(e: &Env, labels: &Labels, topics: &Vec<Val>, data: &Val) -> StdString {
+    format!(
+        "{} {}",
+        decode_val(e, labels, &topics.into_val(e)),
+        decode_val(e, labels, data)
+    )
+}
+
+/// Decoded events emitted by `contract` during the last invocation.
+#[must_use]
+pub fn contract_events(e: &Env, labels: &Labels, contract: &Address) -> StdVec<StdString> {
+    e.events()
+        .all()
+        .iter()
+        .filter(|(id, _, _)| id == contract)
+        .map(|(_, topics, data)| decode_event(e, labels, &topics, &data))
+        .collect()
+}
+
+/// Golden dump, relative to the crate root.
+const FIXTURE_PATH: &str = "fixtures/bond_lifecycle.txt";
+
+/// Compares `dump` with the golden file, or rewrites the file when `UPDATE_FIXTURES` is set.
+fn check_fixture(dump: &str) {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(FIXTURE_PATH);
+    if std::env::var_os("UPDATE_FIXTURES").is_some() {
+        std::fs::write(&path, dump).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap();
+    let mut diff = StdString::new();
+    let (mut want, mut got) = (expected.lines(), dump.lines());
+    for line in 1.. {
+        match (want.next(), got.next()) {
+            (None, None) => break,
+            (w, g) if w == g => {}
+            (w, g) => diff.push_str(&format!(
+                "line {line}\n  expected: {}\n  actual:   {}\n",
+                w.unwrap_or("<end>"),
+                g.unwrap_or("<end>")
+            )),
+        }
+    }
+    assert!(
+        diff.is_empty(),
+        "{FIXTURE_PATH} is out of date (rerun with UPDATE_FIXTURES=1 if intended):\n{diff}"
+    );
+}
+
+#[test]
+fn fixtures_bond_lifecycle() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let identity = Address::generate(&e);
+    client.initialize(&admin);
+    let token = e
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(&e, &token).mint(&identity, &2_000_000_000);
+    TokenClient::new(&e, &token).approve(&identity, &contract_id, &2_000_000_000, &10_000);
+    client.set_token(&admin, &token);
+
+    let mut labels = Labels::default();
+    labels.add(&contract_id, "bond");
+    labels.add(&admin, "admin");
+    labels.add(&identity, "identity");
+    labels.add(&token, "token");
+
+    let mut dump = StdString::new();
+    let step = |name: &str, dump: &mut StdString| {
+        dump.push_str(&format!("step {name}\n"));
+        for ev in contract_events(&e, &labels, &contract_id) {
+            dump.push_str(&format!("  event {ev}\n"));
+        }
+    };
+
+    client.create_bond(&identity, &1_000_000_000, &86_400, &false, &0);
+    step("create_bond", &mut dump);
+    client.top_up(&identity, &identity, &5_000_000);
+    step("top_up", &mut dump);
+    client.slash(&admin, &identity, &5_000_000);
+    step("slash", &mut dump);
+
+    let views: [(&str, Val); 5] = [
+        (
+            "get_identity_state",
+            client.get_identity_state(&identity).into_val(&e),
+        ),
+        ("get_tier", client.get_tier(&identity).into_val(&e)),
+        (
+            "get_effective_tier",
+            client.get_effective_tier(&identity).into_val(&e),
+        ),
+        (
+            "get_maturity_ladder",
+            client.get_maturity_ladder(&0, &1).into_val(&e),
+        ),
+        (
+            "get_effective_restrictions",
+            client.get_effective_restrictions().into_val(&e),
+        ),
+    ];
+    for (name, v) in views {
+        dump.push_str(&format!("view {name} {}\n", decode_val(&e, &labels, &v)));
+    }
+    let counters: [(&str, Val); 2] = [
+        ("get_total_bonded", client.get_total_bonded().into_val(&e)),
+        (
+            "get_event_schema_version",
+            client.get_event_schema_version().into_val(&e),
+        ),
+    ];
+    for (name, v) in counters {
+        dump.push_str(&format!("counter {name} {}\n", decode_val(&e, &labels, &v)));
+    }
+
+    check_fixture(&dump);
+}
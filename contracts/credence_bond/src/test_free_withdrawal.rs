@@ -0,0 +1,98 @@
+//! Tests for the per-period penalty-free early withdrawal allowance.
+
+use crate::test_helpers;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token::TokenClient, Address, Env};
+
+const AMOUNT: i128 = 10_000_000_000;
+const DURATION: u64 = 86_400;
+const PERIOD: u64 = 1_000;
+
+fn set_time(e: &Env, t: u64) {
+    e.ledger().with_mut(|li| li.timestamp = t);
+}
+
+/// 10% early exit penalty, 5% of the bond free per `PERIOD`. Returns (client, identity, token,
+/// treasury).
+fn setup(
+    e: &Env,
+) -> (
+    crate::CredenceBondClient<'_>,
+    Address,
+    TokenClient<'_>,
+    Address,
+) {
+    let (client, admin, identity, token, _) = test_helpers::setup_with_token(e);
+    let treasury = Address::generate(e);
+    client.set_early_exit_config(&admin, &treasury, &1_000_u32);
+    client.set_free_withdrawal_config(&admin, &500_u32, &PERIOD);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0);
+    (client, identity, TokenClient::new(e, &token), treasury)
+}
+
+#[test]
+fn test_withdrawal_inside_allowance_is_penalty_free() {
+    let e = Env::default();
+    let (client, identity, token, treasury) = setup(&e);
+    let before = token.balance(&identity);
+
+    client.withdraw_early(&identity, &300_000_000);
+
+    assert_eq!(token.balance(&identity), before + 300_000_000);
+    assert_eq!(token.balance(&treasury), 0);
+    assert_eq!(client.get_free_withdrawal_remaining(&identity), 200_000_000);
+}
+
+#[test]
+fn test_withdrawal_straddling_allowance_penalizes_excess_only() {
+    let e = Env::default();
+    let (client, identity, token, treasury) = setup(&e);
+    client.withdraw_early(&identity, &300_000_000);
+    let before = token.balance(&identity);
+
+    // 200_000_000 left free; the other 200_000_000 pays 10% at full remaining lock time.
+    client.withdraw_early(&identity, &400_000_000);
+
+    assert_eq!(token.balance(&treasury), 20_000_000);
+    assert_eq!(token.balance(&identity), before + 380_000_000);
+    assert_eq!(client.get_free_withdrawal_remaining(&identity), 0);
+}
+
+#[test]
+fn test_allowance_resets_after_period() {
+    let e = Env::default();
+    let (client, identity, token, treasury) = setup(&e);
+    client.withdraw_early(&identity, &500_000_000);
+    assert_eq!(client.get_free_withdrawal_remaining(&identity), 0);
+
+    set_time(&e, PERIOD - 1);
+    assert_eq!(client.get_free_withdrawal_remaining(&identity), 0);
+
+    // New period: 5% of the 9_500_000_000 still bonded.
+    set_time(&e, PERIOD);
+    assert_eq!(client.get_free_withdrawal_remaining(&identity), 475_000_000);
+    client.withdraw_early(&identity, &475_000_000);
+    assert_eq!(token.balance(&treasury), 0);
+}
+
+#[test]
+fn test_disabled_allowance_penalizes_everything() {
+    let e = Env::default();
+    let (client, admin, identity, token, _) = test_helpers::setup_with_token(&e);
+    let treasury = Address::generate(&e);
+    client.set_early_exit_config(&admin, &treasury, &1_000_u32);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0);
+
+    client.withdraw_early(&identity, &300_000_000);
+    assert_eq!(TokenClient::new(&e, &token).balance(&treasury), 30_000_000);
+    assert_eq!(client.get_free_withdrawal_remaining(&identity), 0);
+}
+
+#[test]
+#[should_panic(expected = "use withdraw for post lock-up")]
+fn test_allowance_does_not_extend_withdraw_early_past_lock_up() {
+    let e = Env::default();
+    let (client, identity, ..) = setup(&e);
+    set_time(&e, DURATION);
+    client.withdraw_early(&identity, &100_000_000);
+}
@@ -19,7 +19,7 @@ fn setup_with_bond_and_governance<'a>(
     min_governors: u32,
 ) -> (CredenceBondClient<'a>, Address, Address) {
     let (client, admin, identity) = setup(e);
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
     let mut gov_vec = Vec::new(e);
     for g in governors {
         gov_vec.push_back(g.clone());
@@ -57,14 +57,13 @@ fn test_initialize_governance_unauthorized() {
 fn test_propose_slash() {
     let e = Env::default();
     let g1 = Address::generate(&e);
-    let (client, admin, _identity) =
+    let (client, admin, identity) =
         setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 5100, 1);
-    let (client, admin, _identity) = setup_with_bond_and_governance(&e, &[g1.clone()], 5100, 1);
-    let id = client.propose_slash(&admin, &100_i128);
+    let id = client.propose_slash(&admin, &identity, &100_000_000_i128);
     assert_eq!(id, 0);
     let prop = client.get_slash_proposal(&id);
     let prop = prop.unwrap();
-    assert_eq!(prop.amount, 100);
+    assert_eq!(prop.amount, 100_000_000);
     assert_eq!(prop.proposed_by, admin);
     assert!(matches!(
         prop.status,
@@ -76,13 +75,12 @@ fn test_propose_slash() {
 fn test_vote_approve_and_execute() {
     let e = Env::default();
     let g1 = Address::generate(&e);
-    let (client, admin, _identity) =
+    let (client, admin, identity) =
         setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 5100, 1);
-    let (client, admin, _identity) = setup_with_bond_and_governance(&e, &[g1.clone()], 5100, 1);
-    let _id = client.propose_slash(&admin, &100_i128);
+    let _id = client.propose_slash(&admin, &identity, &100_000_000_i128);
     client.governance_vote(&g1, &0_u64, &true);
     let bond = client.execute_slash_with_governance(&admin, &0_u64);
-    assert_eq!(bond.slashed_amount, 100);
+    assert_eq!(bond.slashed_amount, 100_000_000);
 }
 
 #[test]
@@ -90,9 +88,9 @@ fn test_vote_approve_and_execute() {
 fn test_vote_reject_then_execute_fails() {
     let e = Env::default();
     let g1 = Address::generate(&e);
-    let (client, admin, _identity) =
+    let (client, admin, identity) =
         setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 5100, 1);
-    let _id = client.propose_slash(&admin, &100_i128);
+    let _id = client.propose_slash(&admin, &identity, &100_000_000_i128);
     client.governance_vote(&g1, &0_u64, &false);
     client.execute_slash_with_governance(&admin, &0_u64);
 }
@@ -103,13 +101,13 @@ fn test_quorum_two_of_three() {
     let g1 = Address::generate(&e);
     let g2 = Address::generate(&e);
     let g3 = Address::generate(&e);
-    let (client, admin, _) =
+    let (client, admin, identity) =
         setup_with_bond_and_governance(&e, &[g1.clone(), g2.clone(), g3.clone()], 6600, 2);
-    let _id = client.propose_slash(&admin, &50_i128);
+    let _id = client.propose_slash(&admin, &identity, &50_000_000_i128);
     client.governance_vote(&g1, &0_u64, &true);
     client.governance_vote(&g2, &0_u64, &true);
     let bond = client.execute_slash_with_governance(&admin, &0_u64);
-    assert_eq!(bond.slashed_amount, 50);
+    assert_eq!(bond.slashed_amount, 50_000_000);
 }
 
 #[test]
@@ -118,22 +116,23 @@ fn test_delegate_vote() {
     let g1 = Address::generate(&e);
     let g2 = Address::generate(&e);
     let delegate_to = Address::generate(&e);
-    let (client, admin, _) = setup_with_bond_and_governance(&e, &[g1.clone(), g2.clone()], 5100, 1);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, &[g1.clone(), g2.clone()], 5100, 1);
     client.governance_delegate(&g1, &delegate_to);
-    let _id = client.propose_slash(&admin, &75_i128);
+    let _id = client.propose_slash(&admin, &identity, &75_000_000_i128);
     client.governance_vote(&delegate_to, &0_u64, &true);
     client.governance_vote(&g2, &0_u64, &true);
     let bond = client.execute_slash_with_governance(&admin, &0_u64);
-    assert_eq!(bond.slashed_amount, 75);
+    assert_eq!(bond.slashed_amount, 75_000_000);
 }
 
 #[test]
 fn test_get_governance_vote() {
     let e = Env::default();
     let g1 = Address::generate(&e);
-    let (client, admin, _) =
+    let (client, admin, identity) =
         setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 5100, 1);
-    client.propose_slash(&admin, &10_i128);
+    client.propose_slash(&admin, &identity, &10_000_000_i128);
     assert!(client.get_governance_vote(&0_u64, &g1).is_none());
     client.governance_vote(&g1, &0_u64, &true);
     assert_eq!(client.get_governance_vote(&0_u64, &g1), Some(true));
@@ -144,9 +143,9 @@ fn test_get_governance_vote() {
 fn test_double_vote_rejected() {
     let e = Env::default();
     let g1 = Address::generate(&e);
-    let (client, admin, _) =
+    let (client, admin, identity) =
         setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 5100, 1);
-    client.propose_slash(&admin, &10_i128);
+    client.propose_slash(&admin, &identity, &10_000_000_i128);
     client.governance_vote(&g1, &0_u64, &true);
     client.governance_vote(&g1, &0_u64, &false);
 }
@@ -156,9 +155,9 @@ fn test_double_vote_rejected() {
 fn test_non_governor_cannot_vote() {
     let e = Env::default();
     let g1 = Address::generate(&e);
-    let (client, admin, _) =
+    let (client, admin, identity) =
         setup_with_bond_and_governance(&e, core::slice::from_ref(&g1), 5100, 1);
-    client.propose_slash(&admin, &10_i128);
+    client.propose_slash(&admin, &identity, &10_000_000_i128);
     let other = Address::generate(&e);
     client.governance_vote(&other, &0_u64, &true);
 }
@@ -169,8 +168,9 @@ fn test_only_proposer_executes() {
     let e = Env::default();
     let g1 = Address::generate(&e);
     let g2 = Address::generate(&e);
-    let (client, admin, _) = setup_with_bond_and_governance(&e, &[g1.clone(), g2.clone()], 5100, 1);
-    client.propose_slash(&admin, &50_i128);
+    let (client, admin, identity) =
+        setup_with_bond_and_governance(&e, &[g1.clone(), g2.clone()], 5100, 1);
+    client.propose_slash(&admin, &identity, &50_000_000_i128);
     client.governance_vote(&g1, &0_u64, &true);
     client.governance_vote(&g2, &0_u64, &true);
     client.execute_slash_with_governance(&g1, &0_u64);
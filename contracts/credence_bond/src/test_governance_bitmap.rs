@@ -0,0 +1,216 @@
+//! Tests for bitmap-encoded governance votes over versioned governor rosters.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Vec};
+
+fn governors(e: &Env, count: u32) -> Vec<Address> {
+    let mut out = Vec::new(e);
+    for _ in 0..count {
+        out.push_back(Address::generate(e));
+    }
+    out
+}
+
+fn setup<'a>(
+    e: &'a Env,
+    govs: &Vec<Address>,
+    quorum_bps: u32,
+    min: u32,
+) -> (CredenceBondClient<'a>, Address, Address) {
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86_400_u64, &false, &0_u64);
+    client.initialize_governance(&admin, govs, &quorum_bps, &min);
+    (client, admin, identity)
+}
+
+#[test]
+fn test_approvals_at_low_middle_and_high_indexes() {
+    let e = Env::default();
+    e.cost_estimate().budget().reset_unlimited();
+    let govs = governors(&e, 101);
+    let (client, admin, identity) = setup(&e, &govs, 0, 3);
+    let id = client.propose_slash(&admin, &identity, &100_000_000_i128);
+
+    let approvers = [
+        govs.get(0).unwrap(),
+        govs.get(63).unwrap(),
+        govs.get(100).unwrap(),
+    ];
+    for g in approvers.iter() {
+        client.governance_vote(g, &id, &true);
+    }
+
+    for g in approvers.iter() {
+        assert_eq!(client.get_governance_vote(&id, g), Some(true));
+    }
+    assert_eq!(
+        client.get_governance_vote(&id, &govs.get(64).unwrap()),
+        None
+    );
+    assert_eq!(
+        client.get_governance_approvers(&id),
+        Vec::from_array(&e, approvers)
+    );
+
+    let bond = client.execute_slash_with_governance(&admin, &id);
+    assert_eq!(bond.slashed_amount, 100_000_000);
+}
+
+#[test]
+fn test_threshold_counts_set_bits() {
+    let e = Env::default();
+    let govs = governors(&e, 5);
+    // 5 governors at 51% quorum: two votes needed.
+    let (client, admin, identity) = setup(&e, &govs, 5_100, 1);
+
+    let id = client.propose_slash(&admin, &identity, &100_000_000_i128);
+    client.governance_vote(&govs.get(0).unwrap(), &id, &true);
+    client.governance_vote(&govs.get(4).unwrap(), &id, &false);
+    // 1 of 2 approvals is not a majority.
+    client.reject_slash_request(&id);
+
+    let id = client.propose_slash(&admin, &identity, &100_000_000_i128);
+    client.governance_vote(&govs.get(1).unwrap(), &id, &true);
+    client.governance_vote(&govs.get(2).unwrap(), &id, &true);
+    client.governance_vote(&govs.get(3).unwrap(), &id, &false);
+    assert_eq!(
+        client.get_governance_approvers(&id),
+        Vec::from_array(&e, [govs.get(1).unwrap(), govs.get(2).unwrap()])
+    );
+    let bond = client.execute_slash_with_governance(&admin, &id);
+    assert_eq!(bond.slashed_amount, 100_000_000);
+}
+
+#[test]
+fn test_old_proposals_decode_against_their_roster() {
+    let e = Env::default();
+    let old = governors(&e, 3);
+    let (client, admin, identity) = setup(&e, &old, 5_100, 1);
+    let id = client.propose_slash(&admin, &identity, &100_000_000_i128);
+    client.governance_vote(&old.get(2).unwrap(), &id, &true);
+
+    // Reorder and extend the membership: index 2 now belongs to someone else.
+    let newcomer = Address::generate(&e);
+    let new = Vec::from_array(
+        &e,
+        [
+            old.get(2).unwrap(),
+            old.get(0).unwrap(),
+            newcomer.clone(),
+            old.get(1).unwrap(),
+        ],
+    );
+    client.initialize_governance(&admin, &new, &5_100_u32, &1_u32);
+
+    assert_eq!(
+        client.get_governance_vote(&id, &old.get(2).unwrap()),
+        Some(true)
+    );
+    assert_eq!(client.get_governance_vote(&id, &newcomer), None);
+    assert_eq!(
+        client.get_governance_approvers(&id),
+        Vec::from_array(&e, [old.get(2).unwrap()])
+    );
+
+    let before = client.describe_slash_request(&id).unwrap().proposal;
+    let next = client.propose_slash(&admin, &identity, &100_000_000_i128);
+    assert_ne!(id, next);
+    let after = client.describe_slash_request(&id).unwrap().proposal;
+    assert_eq!(
+        (after.id, after.amount, after.proposed_at, after.status),
+        (before.id, before.amount, before.proposed_at, before.status)
+    );
+    assert_eq!(
+        client.get_governance_vote(&id, &old.get(2).unwrap()),
+        Some(true)
+    );
+    client.governance_vote(&newcomer, &next, &true);
+    assert_eq!(client.get_governance_vote(&next, &newcomer), Some(true));
+    assert_eq!(
+        client.get_governance_vote(&next, &old.get(2).unwrap()),
+        None
+    );
+}
+
+#[test]
+#[should_panic(expected = "too many governors")]
+fn test_roster_limited_to_bitmap_width() {
+    let e = Env::default();
+    e.cost_estimate().budget().reset_unlimited();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    client.initialize_governance(&admin, &governors(&e, 129), &5_100_u32, &1_u32);
+}
+
+#[test]
+fn test_hundred_member_roster_accepts_every_vote() {
+    let e = Env::default();
+    e.cost_estimate().budget().reset_unlimited();
+    let govs = governors(&e, 100);
+    let (client, admin, identity) = setup(&e, &govs, 10_000, 100);
+    let id = client.propose_slash(&admin, &identity, &100_000_000_i128);
+    let before = client.describe_slash_request(&id).unwrap().proposal;
+
+    for g in govs.iter() {
+        client.governance_vote(&g, &id, &true);
+    }
+
+    assert_eq!(client.get_governance_approvers(&id), govs);
+    let after = client.describe_slash_request(&id).unwrap().proposal;
+    assert_eq!(
+        (after.id, after.identity, after.amount, after.status),
+        (before.id, before.identity, before.amount, before.status)
+    );
+    let bond = client.execute_slash_with_governance(&admin, &id);
+    assert_eq!(bond.slashed_amount, 100_000_000);
+}
+
+#[test]
+#[should_panic(expected = "approval list full")]
+fn test_vote_records_capped_at_member_count() {
+    let e = Env::default();
+    let govs = governors(&e, 2);
+    let (client, admin, identity) = setup(&e, &govs, 10_000, 2);
+    let id = client.propose_slash(&admin, &identity, &100_000_000_i128);
+
+    // g0 votes directly and through an outside delegate, filling both slots.
+    let g0 = govs.get(0).unwrap();
+    let outsider = Address::generate(&e);
+    client.governance_delegate(&g0, &outsider);
+    client.governance_vote(&g0, &id, &true);
+    client.governance_vote(&outsider, &id, &true);
+
+    client.governance_vote(&govs.get(1).unwrap(), &id, &true);
+}
+
+#[test]
+fn test_roster_shrink_keeps_existing_votes() {
+    let e = Env::default();
+    let old = governors(&e, 5);
+    let (client, admin, identity) = setup(&e, &old, 5_100, 1);
+    let id = client.propose_slash(&admin, &identity, &100_000_000_i128);
+    for i in 0..3 {
+        client.governance_vote(&old.get(i).unwrap(), &id, &true);
+    }
+
+    let shrunk = Vec::from_array(&e, [old.get(4).unwrap(), old.get(0).unwrap()]);
+    client.initialize_governance(&admin, &shrunk, &5_100_u32, &1_u32);
+
+    // The old request still decodes against its five-member roster.
+    let expected = Vec::from_array(
+        &e,
+        [
+            old.get(0).unwrap(),
+            old.get(1).unwrap(),
+            old.get(2).unwrap(),
+        ],
+    );
+    assert_eq!(client.get_governance_approvers(&id), expected);
+    client.governance_vote(&old.get(3).unwrap(), &id, &false);
+    assert_eq!(
+        client.get_governance_vote(&id, &old.get(3).unwrap()),
+        Some(false)
+    );
+    assert_eq!(client.get_governance_approvers(&id), expected);
+}
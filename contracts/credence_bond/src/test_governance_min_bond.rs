@@ -0,0 +1,94 @@
+//! Tests for the minimum bond required of governance members.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, Vec};
+
+const BOND_AMOUNT: i128 = 10_000_000;
+const MIN_BOND: i128 = 6_000_000;
+const DURATION: u64 = 86_400;
+const SLASH_AMOUNT: i128 = 1_000;
+
+/// Two bonded governors; `MIN_BOND` is configured before the roster is set.
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address) {
+    let (client, admin, g1, token, contract_id) = test_helpers::setup_with_token(e);
+    let g2 = Address::generate(e);
+    StellarAssetClient::new(e, &token).mint(&g2, &BOND_AMOUNT);
+    let expiration = e.ledger().sequence().saturating_add(10_000);
+    TokenClient::new(e, &token).approve(&g2, &contract_id, &BOND_AMOUNT, &expiration);
+
+    client.create_bond(&g1, &BOND_AMOUNT, &DURATION, &false, &0_u64);
+    client.create_bond(&g2, &BOND_AMOUNT, &DURATION, &false, &0_u64);
+    client.set_min_governor_bond(&admin, &MIN_BOND);
+    client.initialize_governance(
+        &admin,
+        &Vec::from_array(e, [g1.clone(), g2.clone()]),
+        &5_100_u32,
+        &1_u32,
+    );
+    (client, admin, g1, g2)
+}
+
+#[test]
+fn test_vote_blocked_after_withdrawal_until_top_up() {
+    let e = Env::default();
+    let (client, admin, g1, g2) = setup(&e);
+    assert_eq!(client.get_min_governor_bond(), MIN_BOND);
+
+    assert!(client.is_governor_bonded(&g1));
+
+    e.ledger().with_mut(|li| li.timestamp = DURATION);
+    client.withdraw(&g1, &5_000_000);
+    assert!(!client.is_governor_bonded(&g1));
+    let id = client.propose_slash(&admin, &g2, &SLASH_AMOUNT);
+    assert!(client.try_governance_vote(&g1, &id, &true).is_err());
+
+    client.top_up(&g1, &g1, &1_000_000);
+    assert!(client.is_governor_bonded(&g1));
+    client.governance_vote(&g1, &id, &true);
+    assert_eq!(client.get_governance_vote(&id, &g1), Some(true));
+}
+
+#[test]
+#[should_panic(expected = "governor under-bonded")]
+fn test_slashed_governor_vote_rejected() {
+    let e = Env::default();
+    let (client, admin, g1, g2) = setup(&e);
+    client.slash(&admin, &g1, &5_000_000);
+    let id = client.propose_slash(&admin, &g2, &SLASH_AMOUNT);
+    client.governance_vote(&g1, &id, &true);
+}
+
+#[test]
+#[should_panic(expected = "governor under-bonded")]
+fn test_unbonded_member_rejected_when_added() {
+    let e = Env::default();
+    let (client, admin, g1, _g2) = setup(&e);
+    let unbonded = Address::generate(&e);
+    client.initialize_governance(
+        &admin,
+        &Vec::from_array(&e, [g1, unbonded]),
+        &5_100_u32,
+        &1_u32,
+    );
+}
+
+#[test]
+fn test_zero_minimum_disables_requirement() {
+    let e = Env::default();
+    let (client, admin, g1, g2) = setup(&e);
+    client.set_min_governor_bond(&admin, &0);
+
+    let unbonded = Address::generate(&e);
+    client.initialize_governance(
+        &admin,
+        &Vec::from_array(&e, [g1, unbonded.clone()]),
+        &5_100_u32,
+        &1_u32,
+    );
+    assert!(client.is_governor_bonded(&unbonded));
+    let id = client.propose_slash(&admin, &g2, &SLASH_AMOUNT);
+    client.governance_vote(&unbonded, &id, &true);
+}
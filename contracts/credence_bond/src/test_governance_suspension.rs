@@ -0,0 +1,98 @@
+//! Tests for suspending and reinstating governance members.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Vec};
+
+const SLASH_AMOUNT: i128 = 100_000_000;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, [Address; 3]) {
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    let governors = [
+        Address::generate(e),
+        Address::generate(e),
+        Address::generate(e),
+    ];
+    client.initialize_governance(
+        &admin,
+        &Vec::from_array(e, governors.clone()),
+        &6_600_u32,
+        &2_u32,
+    );
+    (client, admin, identity, governors)
+}
+
+#[test]
+fn test_suspended_member_stays_in_list() {
+    let e = Env::default();
+    let (client, admin, _, [g1, ..]) = setup(&e);
+    client.suspend_governance_member(&admin, &g1);
+
+    assert!(client.is_governance_member_suspended(&g1));
+    assert_eq!(client.get_governors().len(), 3);
+
+    client.reinstate_governance_member(&admin, &g1);
+    assert!(!client.is_governance_member_suspended(&g1));
+}
+
+#[test]
+fn test_suspension_drops_approval_and_reinstatement_restores_it() {
+    let e = Env::default();
+    let (client, admin, identity, [g1, g2, _g3]) = setup(&e);
+    let proposal_id = client.propose_slash(&admin, &identity, &SLASH_AMOUNT);
+    client.governance_vote(&g1, &proposal_id, &true);
+    client.governance_vote(&g2, &proposal_id, &true);
+
+    client.suspend_governance_member(&admin, &g2);
+    assert!(client
+        .try_execute_slash_with_governance(&admin, &proposal_id)
+        .is_err());
+
+    client.reinstate_governance_member(&admin, &g2);
+    let bond = client.execute_slash_with_governance(&admin, &proposal_id);
+    assert_eq!(bond.slashed_amount, SLASH_AMOUNT);
+}
+
+#[test]
+fn test_quorum_excludes_suspended_members() {
+    let e = Env::default();
+    let (client, admin, identity, [g1, g2, g3]) = setup(&e);
+    client.suspend_governance_member(&admin, &g3);
+
+    let proposal_id = client.propose_slash(&admin, &identity, &SLASH_AMOUNT);
+    client.governance_vote(&g1, &proposal_id, &true);
+    client.governance_vote(&g2, &proposal_id, &false);
+    // 1 of 2 active members approves: no majority, even though g3 is still listed.
+    assert!(client
+        .try_execute_slash_with_governance(&admin, &proposal_id)
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "governance member suspended")]
+fn test_suspended_member_cannot_vote() {
+    let e = Env::default();
+    let (client, admin, identity, [g1, ..]) = setup(&e);
+    let proposal_id = client.propose_slash(&admin, &identity, &SLASH_AMOUNT);
+    client.suspend_governance_member(&admin, &g1);
+    client.governance_vote(&g1, &proposal_id, &true);
+}
+
+#[test]
+#[should_panic(expected = "not admin or governor")]
+fn test_suspended_member_cannot_propose() {
+    let e = Env::default();
+    let (client, admin, identity, [g1, ..]) = setup(&e);
+    client.suspend_governance_member(&admin, &g1);
+    client.propose_slash(&g1, &identity, &SLASH_AMOUNT);
+}
+
+#[test]
+#[should_panic(expected = "member not suspended")]
+fn test_reinstate_requires_suspension() {
+    let e = Env::default();
+    let (client, admin, _, [g1, ..]) = setup(&e);
+    client.reinstate_governance_member(&admin, &g1);
+}
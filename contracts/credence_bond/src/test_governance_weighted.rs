@@ -0,0 +1,107 @@
+//! Tests for bond-weighted governance voting with per-proposal snapshots.
+
+use crate::events::EVENT_SCHEMA_VERSION;
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec};
+
+const BOND_AMOUNT: i128 = 1_000_000_000;
+const SLASH_AMOUNT: i128 = 100_000_000;
+
+/// The bonded identity is the first of three governors; the other two hold no bond.
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, [Address; 3]) {
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &BOND_AMOUNT, &86_400_u64, &false, &0_u64);
+    let governors = [identity, Address::generate(e), Address::generate(e)];
+    client.initialize_governance(
+        &admin,
+        &Vec::from_array(e, governors.clone()),
+        &6_600_u32,
+        &2_u32,
+    );
+    client.set_governance_bond_weighted(&admin, &true);
+    (client, admin, governors)
+}
+
+#[test]
+fn test_snapshot_taken_at_proposal() {
+    let e = Env::default();
+    let (client, admin, [bonded, g2, _g3]) = setup(&e);
+    let proposal_id = client.propose_slash(&admin, &bonded, &SLASH_AMOUNT);
+
+    let topics: Vec<Val> = Vec::from_array(
+        &e,
+        [
+            Symbol::new(&e, "slash_proposed").into_val(&e),
+            EVENT_SCHEMA_VERSION.into_val(&e),
+        ],
+    );
+    let (_, _, data) = e
+        .events()
+        .all()
+        .iter()
+        .find(|(_, t, _)| *t == topics)
+        .unwrap();
+    let (id, _proposer, amount, total) =
+        <(u64, Address, i128, i128)>::try_from_val(&e, &data).unwrap();
+    assert_eq!(
+        (id, amount, total),
+        (proposal_id, SLASH_AMOUNT, BOND_AMOUNT)
+    );
+
+    assert_eq!(client.get_voting_power(&proposal_id, &bonded), BOND_AMOUNT);
+    assert_eq!(client.get_voting_power(&proposal_id, &g2), 0);
+}
+
+#[test]
+fn test_post_snapshot_top_up_does_not_change_tally() {
+    let e = Env::default();
+    let (client, admin, [bonded, g2, g3]) = setup(&e);
+    let proposal_id = client.propose_slash(&admin, &bonded, &SLASH_AMOUNT);
+
+    client.top_up(&bonded, &bonded, &500_000_000);
+    assert_eq!(client.get_voting_power(&proposal_id, &bonded), BOND_AMOUNT);
+
+    // The bonded governor outweighs two weightless approvals.
+    client.governance_vote(&bonded, &proposal_id, &false);
+    client.governance_vote(&g2, &proposal_id, &true);
+    client.governance_vote(&g3, &proposal_id, &true);
+    assert!(client
+        .try_execute_slash_with_governance(&admin, &proposal_id)
+        .is_err());
+}
+
+#[test]
+fn test_weight_decides_over_head_count() {
+    let e = Env::default();
+    let (client, admin, [bonded, g2, _g3]) = setup(&e);
+    let proposal_id = client.propose_slash(&admin, &bonded, &SLASH_AMOUNT);
+
+    client.governance_vote(&bonded, &proposal_id, &true);
+    client.governance_vote(&g2, &proposal_id, &false);
+    let bond = client.execute_slash_with_governance(&admin, &proposal_id);
+    assert_eq!(bond.slashed_amount, SLASH_AMOUNT);
+}
+
+#[test]
+fn test_zero_weight_vote_is_recorded() {
+    let e = Env::default();
+    let (client, admin, [bonded, g2, _g3]) = setup(&e);
+    let proposal_id = client.propose_slash(&admin, &bonded, &SLASH_AMOUNT);
+
+    client.governance_vote(&g2, &proposal_id, &true);
+    assert_eq!(client.get_governance_vote(&proposal_id, &g2), Some(true));
+    assert_eq!(client.get_voting_power(&proposal_id, &g2), 0);
+}
+
+#[test]
+fn test_unweighted_proposals_use_head_count() {
+    let e = Env::default();
+    let (client, admin, [bonded, g2, _g3]) = setup(&e);
+    client.set_governance_bond_weighted(&admin, &false);
+    let proposal_id = client.propose_slash(&admin, &bonded, &SLASH_AMOUNT);
+
+    assert_eq!(client.get_voting_power(&proposal_id, &bonded), 1);
+    assert_eq!(client.get_voting_power(&proposal_id, &g2), 1);
+}
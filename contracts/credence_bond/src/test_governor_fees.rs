@@ -0,0 +1,124 @@
+//! Tests for the governor fee stream: period accrual, suspension gaps and claims.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, Env, Vec};
+
+const POOL: i128 = 100_000;
+const PERIOD: u64 = 100;
+const START: u64 = 1_000;
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    admin: Address,
+    g1: Address,
+    g2: Address,
+    token: TokenClient<'a>,
+}
+
+/// Two governors, a funded fee pool and a 10%-per-period stream starting at `START`.
+fn setup(e: &Env) -> Setup<'_> {
+    let (client, admin, identity, token, _contract_id) = test_helpers::setup_with_token(e);
+    e.ledger().with_mut(|li| li.timestamp = START);
+    let g1 = Address::generate(e);
+    let g2 = Address::generate(e);
+    client.initialize_governance(
+        &admin,
+        &Vec::from_array(e, [g1.clone(), g2.clone()]),
+        &5_100,
+        &1,
+    );
+    client.deposit_fees(&identity, &POOL);
+    client.set_governor_fee_config(&admin, &1_000, &PERIOD);
+    Setup {
+        client,
+        admin,
+        g1,
+        g2,
+        token: TokenClient::new(e, &token),
+    }
+}
+
+fn advance(e: &Env, secs: u64) {
+    e.ledger().with_mut(|li| li.timestamp += secs);
+}
+
+#[test]
+fn test_accrual_over_two_periods() {
+    let e = Env::default();
+    let s = setup(&e);
+    advance(&e, 2 * PERIOD + PERIOD / 2);
+
+    // 10% of 100_000, then 10% of the remaining 90_000, split between two governors.
+    assert_eq!(s.client.distribute_governor_fees(), 19_000);
+    assert_eq!(s.client.get_governor_fee_balance(&s.g1), 9_500);
+    assert_eq!(s.client.get_governor_fee_balance(&s.g2), 9_500);
+    assert_eq!(s.client.distribute_governor_fees(), 0);
+
+    // The half period left over counts towards the next one.
+    advance(&e, PERIOD / 2);
+    assert_eq!(s.client.distribute_governor_fees(), 8_100);
+    assert_eq!(s.client.collect_fees(&s.admin), POOL - 19_000 - 8_100);
+}
+
+#[test]
+fn test_suspended_member_accrues_nothing() {
+    let e = Env::default();
+    let s = setup(&e);
+    advance(&e, PERIOD);
+    s.client.distribute_governor_fees();
+
+    s.client.suspend_governance_member(&s.admin, &s.g2);
+    advance(&e, PERIOD);
+    s.client.reinstate_governance_member(&s.admin, &s.g2);
+    advance(&e, PERIOD);
+    s.client.distribute_governor_fees();
+
+    // Period 1: 5_000 each. Period 2: 9_000 to g1 alone. Period 3: 4_050 each.
+    assert_eq!(s.client.get_governor_fee_balance(&s.g1), 18_050);
+    assert_eq!(s.client.get_governor_fee_balance(&s.g2), 9_050);
+}
+
+#[test]
+fn test_under_bonded_members_accrue_nothing() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.set_min_governor_bond(&s.admin, &1);
+    advance(&e, PERIOD);
+    assert_eq!(s.client.distribute_governor_fees(), 0);
+    assert_eq!(s.client.get_governor_fee_balance(&s.g1), 0);
+    assert_eq!(s.client.collect_fees(&s.admin), POOL);
+}
+
+#[test]
+fn test_claim_transfers_exact_balance() {
+    let e = Env::default();
+    let s = setup(&e);
+    advance(&e, PERIOD);
+    s.client.distribute_governor_fees();
+
+    let before = s.token.balance(&s.g1);
+    assert_eq!(s.client.claim_governor_fees(&s.g1), 5_000);
+    assert_eq!(s.token.balance(&s.g1), before + 5_000);
+    assert_eq!(s.client.get_governor_fee_balance(&s.g1), 0);
+    assert!(s.client.try_claim_governor_fees(&s.g1).is_err());
+    assert_eq!(s.client.get_governor_fee_balance(&s.g2), 5_000);
+}
+
+#[test]
+#[should_panic(expected = "nothing to claim")]
+fn test_claim_without_balance_rejected() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.claim_governor_fees(&s.g1);
+}
+
+#[test]
+#[should_panic(expected = "governor fee bps too high")]
+fn test_config_rejects_excessive_rate() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.set_governor_fee_config(&s.admin, &1_001, &PERIOD);
+}
@@ -1,10 +1,11 @@
 //! Shared test helpers for credence_bond tests.
 //! Provides token setup for tests that need create_bond, top_up, withdraw, etc.
 
+use crate::events::EVENT_SCHEMA_VERSION;
 use crate::{CredenceBond, CredenceBondClient};
-use soroban_sdk::testutils::Address as _;
+use soroban_sdk::testutils::{Address as _, Events};
 use soroban_sdk::token::{StellarAssetClient, TokenClient};
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, BytesN, Env, IntoVal, Symbol, TryFromVal};
 
 /// Default mint amount for tests (covers tier thresholds and most scenarios).
 const DEFAULT_MINT: i128 = 100_000_000_000_000_000;
@@ -54,3 +55,28 @@ pub fn setup_with_token_mint(
 
     (client, admin, identity, stellar_asset, contract_id)
 }
+
+/// Mint `amount` of `token` to `holder` and approve `contract_id` to spend it, so a second
+/// identity can bond alongside the one returned by `setup_with_token`.
+pub fn fund(e: &Env, token: &Address, contract_id: &Address, holder: &Address, amount: i128) {
+    StellarAssetClient::new(e, token).mint(holder, &amount);
+    let expiration = e.ledger().sequence().saturating_add(10000);
+    TokenClient::new(e, token).approve(holder, contract_id, &amount, &expiration);
+}
+
+/// Summary `(migration, items_read, items_written, items_removed, digest)` of the
+/// `migration_preview` or `migration_applied` event emitted by the last call.
+pub fn migration_summary(e: &Env, name: &str) -> (Symbol, u32, u32, u32, BytesN<32>) {
+    let topics = soroban_sdk::vec![
+        e,
+        Symbol::new(e, name).into_val(e),
+        EVENT_SCHEMA_VERSION.into_val(e)
+    ];
+    let (_, _, data) = e
+        .events()
+        .all()
+        .iter()
+        .find(|(_, t, _)| *t == topics)
+        .unwrap_or_else(|| panic!("no {name} event"));
+    <(Symbol, u32, u32, u32, BytesN<32>)>::try_from_val(e, &data).unwrap()
+}
@@ -0,0 +1,99 @@
+//! Tests for admin-managed identity labels.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    let (client, admin, identity, _token, _contract_id) = test_helpers::setup_with_token(e);
+    let labels = Vec::from_array(
+        e,
+        [
+            Symbol::new(e, "pilot_partner"),
+            Symbol::new(e, "under_review"),
+        ],
+    );
+    client.set_allowed_labels(&admin, &labels);
+    (client, admin, identity)
+}
+
+#[test]
+fn test_set_replace_and_clear_label() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    assert_eq!(client.get_identity_label(&identity), None);
+
+    client.set_identity_label(&admin, &identity, &Symbol::new(&e, "pilot_partner"));
+    assert_eq!(
+        client.get_identity_label(&identity),
+        Some(Symbol::new(&e, "pilot_partner"))
+    );
+    client.set_identity_label(&admin, &identity, &Symbol::new(&e, "under_review"));
+    assert_eq!(
+        client.get_identity_label(&identity),
+        Some(Symbol::new(&e, "under_review"))
+    );
+
+    client.clear_identity_label(&admin, &identity);
+    assert_eq!(client.get_identity_label(&identity), None);
+}
+
+#[test]
+#[should_panic(expected = "label not allowed")]
+fn test_label_must_be_allowed() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.set_identity_label(&admin, &identity, &Symbol::new(&e, "vip"));
+}
+
+#[test]
+#[should_panic(expected = "too many labels")]
+fn test_allow_list_is_bounded() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    let mut labels = Vec::new(&e);
+    for _ in 0..=crate::identity_label::MAX_ALLOWED_LABELS {
+        labels.push_back(Symbol::new(&e, "label"));
+    }
+    client.set_allowed_labels(&admin, &labels);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_set_label_requires_admin() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    let other = Address::generate(&e);
+    client.set_identity_label(&other, &identity, &Symbol::new(&e, "pilot_partner"));
+}
+
+#[test]
+fn test_label_appears_in_slash_request_description() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.create_bond(&identity, &10_000_000_i128, &86_400_u64, &false, &0_u64);
+    let id = client.propose_slash(&admin, &identity, &1_000);
+    assert_eq!(client.describe_slash_request(&id).unwrap().label, None);
+
+    client.set_identity_label(&admin, &identity, &Symbol::new(&e, "under_review"));
+    assert_eq!(
+        client.describe_slash_request(&id).unwrap().label,
+        Some(Symbol::new(&e, "under_review"))
+    );
+}
+
+#[test]
+fn test_bulk_clear() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let other = Address::generate(&e);
+    let unlabelled = Address::generate(&e);
+    client.set_identity_label(&admin, &identity, &Symbol::new(&e, "pilot_partner"));
+    client.set_identity_label(&admin, &other, &Symbol::new(&e, "under_review"));
+
+    let identities = Vec::from_array(&e, [identity.clone(), other.clone(), unlabelled]);
+    assert_eq!(client.clear_labels(&admin, &identities), 2);
+    assert_eq!(client.get_identity_label(&identity), None);
+    assert_eq!(client.get_identity_label(&other), None);
+}
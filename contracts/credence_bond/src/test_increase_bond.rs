@@ -1,6 +1,7 @@
 #![cfg(test)]
 
 use super::*;
+use crate::events::EVENT_SCHEMA_VERSION;
 use soroban_sdk::testutils::{Address as _, Events as _};
 use soroban_sdk::{Address, Env, IntoVal, Symbol, TryFromVal, Vec};
 
@@ -42,18 +43,21 @@ fn test_increase_bond_success_transfers_and_updates_storage() {
     let (client, contract_id, identity, token_client) = setup(&e);
 
     // Approve enough for both create_bond (1000) and increase_bond (500)
-    token_client.approve(&identity, &contract_id, &2000_i128, &1000_u32);
+    token_client.approve(&identity, &contract_id, &2_000_000_000_i128, &1000_u32);
 
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
 
     let before_user = token_client.balance(&identity);
     let before_contract = token_client.balance(&contract_id);
 
-    let updated = client.increase_bond(&identity, &500_i128);
+    let updated = client.increase_bond(&identity, &500_000_000_i128);
 
-    assert_eq!(updated.bonded_amount, 1500);
-    assert_eq!(token_client.balance(&identity), before_user - 500);
-    assert_eq!(token_client.balance(&contract_id), before_contract + 500);
+    assert_eq!(updated.bonded_amount, 1_500_000_000);
+    assert_eq!(token_client.balance(&identity), before_user - 500_000_000);
+    assert_eq!(
+        token_client.balance(&contract_id),
+        before_contract + 500_000_000
+    );
 }
 
 #[test]
@@ -68,13 +72,13 @@ fn test_increase_bond_fails_without_token_configuration() {
     let admin = Address::generate(&e);
     let identity = Address::generate(&e);
     client.initialize(&admin);
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
 
-    client.increase_bond(&identity, &10_i128);
+    client.increase_bond(&identity, &10_000_000_i128);
 }
 
 #[test]
-#[should_panic(expected = "not bond owner")]
+#[should_panic(expected = "no bond")]
 fn test_increase_bond_fails_for_non_owner() {
     let e = Env::default();
     let (client, contract_id, identity, token_client) = setup(&e);
@@ -82,12 +86,12 @@ fn test_increase_bond_fails_for_non_owner() {
     let stranger = Address::generate(&e);
 
     // Approve for create_bond (1000) and increase_bond (500)
-    token_client.approve(&identity, &contract_id, &2000_i128, &1000_u32);
-    token_client.approve(&stranger, &contract_id, &500_i128, &1000_u32);
+    token_client.approve(&identity, &contract_id, &2_000_000_000_i128, &1000_u32);
+    token_client.approve(&stranger, &contract_id, &500_000_000_i128, &1000_u32);
 
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
 
-    client.increase_bond(&stranger, &500_i128);
+    client.increase_bond(&stranger, &500_000_000_i128);
 }
 
 #[test]
@@ -97,12 +101,30 @@ fn test_increase_bond_rejects_zero_amount() {
     let (client, contract_id, identity, token_client) = setup(&e);
 
     // Approve for create_bond
-    token_client.approve(&identity, &contract_id, &2000_i128, &1000_u32);
+    token_client.approve(&identity, &contract_id, &2_000_000_000_i128, &1000_u32);
 
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
     client.increase_bond(&identity, &0_i128);
 }
 
+#[test]
+#[should_panic(expected = "bond not active")]
+fn test_increase_bond_inactive_bond() {
+    let e = Env::default();
+    let (client, contract_id, identity, token_client) = setup(&e);
+
+    token_client.approve(&identity, &contract_id, &2_000_000_000_i128, &1000_u32);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    e.as_contract(&contract_id, || {
+        let key = DataKey::Bond(identity.clone());
+        let mut bond: IdentityBond = e.storage().instance().get(&key).unwrap();
+        bond.active = false;
+        e.storage().instance().set(&key, &bond);
+    });
+
+    client.increase_bond(&identity, &500_000_000_i128);
+}
+
 #[test]
 #[should_panic(expected = "bond increase caused overflow")]
 fn test_increase_bond_overflow_protection() {
@@ -110,8 +132,8 @@ fn test_increase_bond_overflow_protection() {
     let (client, contract_id, identity, token_client) = setup(&e);
 
     // First create a bond with a normal amount
-    token_client.approve(&identity, &contract_id, &2000_i128, &1000_u32);
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    token_client.approve(&identity, &contract_id, &2_000_000_000_i128, &1000_u32);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
 
     // Now try to increase by i128::MAX - this should cause overflow
     token_client.approve(&identity, &contract_id, &i128::MAX, &1000_u32);
@@ -120,18 +142,18 @@ fn test_increase_bond_overflow_protection() {
 }
 
 #[test]
-#[should_panic(expected = "HostError")]
+#[should_panic(expected = "insufficient allowance: have 0 need 500")]
 fn test_increase_bond_fails_without_allowance() {
     let e = Env::default();
     let (client, contract_id, identity, token_client) = setup(&e);
 
     // Approve for create_bond only
-    token_client.approve(&identity, &contract_id, &1000_i128, &1000_u32);
+    token_client.approve(&identity, &contract_id, &1_000_000_000_i128, &1000_u32);
 
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
 
     // No approval for increase_bond - should fail
-    client.increase_bond(&identity, &500_i128);
+    client.increase_bond(&identity, &500_000_000_i128);
 }
 
 #[test]
@@ -140,11 +162,11 @@ fn test_increase_bond_emits_event() {
     let (client, contract_id, identity, token_client) = setup(&e);
 
     // Approve for create_bond (1000) and increase_bond (250)
-    token_client.approve(&identity, &contract_id, &2000_i128, &1000_u32);
+    token_client.approve(&identity, &contract_id, &2_000_000_000_i128, &1000_u32);
 
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
 
-    let _ = client.increase_bond(&identity, &250_i128);
+    let _ = client.increase_bond(&identity, &250_000_000_i128);
 
     let events = e.events().all();
     assert!(!events.is_empty());
@@ -153,16 +175,21 @@ fn test_increase_bond_emits_event() {
         &e,
         [
             Symbol::new(&e, "bond_increased").into_val(&e),
+            EVENT_SCHEMA_VERSION.into_val(&e),
             identity.clone().into_val(&e),
         ],
     );
-    let expected_data = (250_i128, 1000_i128, 1250_i128);
+    let expected_data = (
+        250_000_000_i128,
+        1_250_000_000_i128,
+        crate::context::current(&e),
+    );
 
     let found = events.iter().any(|evt| {
         if evt.1 != expected_topics {
             return false;
         }
-        <(i128, i128, i128)>::try_from_val(&e, &evt.2)
+        <(i128, i128, crate::context::Context)>::try_from_val(&e, &evt.2)
             .map(|data| data == expected_data)
             .unwrap_or(false)
     });
@@ -176,11 +203,11 @@ fn test_increase_bond_preserves_other_fields() {
     let (client, contract_id, identity, token_client) = setup(&e);
 
     // Approve for create_bond (1000) and increase_bond (150)
-    token_client.approve(&identity, &contract_id, &2000_i128, &1000_u32);
+    token_client.approve(&identity, &contract_id, &2_000_000_000_i128, &1000_u32);
 
-    let original = client.create_bond(&identity, &1000_i128, &86400_u64, &true, &7200_u64);
+    let original = client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &7200_u64);
 
-    let updated = client.increase_bond(&identity, &150_i128);
+    let updated = client.increase_bond(&identity, &150_000_000_i128);
 
     assert_eq!(updated.identity, original.identity);
     assert_eq!(updated.bond_start, original.bond_start);
@@ -192,9 +219,6 @@ fn test_increase_bond_preserves_other_fields() {
         updated.withdrawal_requested_at,
         original.withdrawal_requested_at
     );
-    assert_eq!(
-        updated.notice_period_duration,
-        original.notice_period_duration
-    );
-    assert_eq!(updated.bonded_amount, 1150_i128);
+    assert_eq!(updated.notice_period, original.notice_period);
+    assert_eq!(updated.bonded_amount, 1_150_000_000_i128);
 }
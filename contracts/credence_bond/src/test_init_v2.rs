@@ -0,0 +1,124 @@
+//! Tests for idempotent `initialize` and the post-upgrade `initialize_v2` path.
+
+use crate::init_v2::InitV2Config;
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let client = CredenceBondClient::new(e, &e.register(CredenceBond, ()));
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+fn empty() -> InitV2Config {
+    InitV2Config {
+        dust_threshold: None,
+        proposal_max_open: None,
+        proposal_cooldown_secs: None,
+        require_subject_known: None,
+        governance_bond_weighted: None,
+    }
+}
+
+fn sections(e: &Env, names: &[&str]) -> Vec<Symbol> {
+    let mut out = Vec::new(e);
+    for name in names {
+        out.push_back(Symbol::new(e, name));
+    }
+    out
+}
+
+#[test]
+fn test_initialize_retry_with_same_admin_is_noop() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.initialize(&admin);
+    assert_eq!(client.get_config_schema_version(), 1);
+}
+
+#[test]
+#[should_panic(expected = "already initialized")]
+fn test_initialize_with_other_admin_rejected() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    client.initialize(&Address::generate(&e));
+}
+
+#[test]
+fn test_fresh_deployment_full_init() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let populated = client.initialize_v2(
+        &admin,
+        &InitV2Config {
+            dust_threshold: Some(25),
+            proposal_max_open: Some(2),
+            proposal_cooldown_secs: Some(60),
+            require_subject_known: Some(true),
+            governance_bond_weighted: Some(false),
+        },
+    );
+
+    let all = sections(
+        &e,
+        &[
+            "dust_threshold",
+            "proposal_rate_limit",
+            "subject_policy",
+            "governance_weight",
+        ],
+    );
+    assert_eq!(populated, all);
+    assert_eq!(client.get_configured_sections(), all);
+    assert_eq!(client.get_config_schema_version(), 2);
+    assert_eq!(client.get_dust_threshold(), 25);
+    assert_eq!(client.get_proposal_rate_limit().max_open, 2);
+    assert!(client.get_require_subject_known());
+}
+
+#[test]
+fn test_post_upgrade_init_adds_only_missing_sections() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_dust_threshold(&admin, &10);
+    assert_eq!(client.get_config_schema_version(), 1);
+
+    let mut config = empty();
+    config.require_subject_known = Some(true);
+    let populated = client.initialize_v2(&admin, &config);
+
+    assert_eq!(populated, sections(&e, &["subject_policy"]));
+    assert_eq!(
+        client.get_configured_sections(),
+        sections(&e, &["dust_threshold", "subject_policy"])
+    );
+    assert_eq!(client.get_dust_threshold(), 10);
+    assert_eq!(client.get_config_schema_version(), 2);
+}
+
+#[test]
+fn test_overwriting_existing_section_fails_without_writes() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_dust_threshold(&admin, &10);
+
+    let mut config = empty();
+    config.dust_threshold = Some(99);
+    config.require_subject_known = Some(true);
+    assert!(client.try_initialize_v2(&admin, &config).is_err());
+
+    assert_eq!(client.get_dust_threshold(), 10);
+    assert!(!client.get_require_subject_known());
+    assert_eq!(client.get_config_schema_version(), 1);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_initialize_v2_admin_only() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    client.initialize_v2(&Address::generate(&e), &empty());
+}
@@ -0,0 +1,117 @@
+//! Tests for the per-identity event journal: a scripted lifecycle, pruning at the cap and
+//! paging.
+
+use crate::journal::{JournalEntry, MAX_JOURNAL_ENTRIES, MAX_PAGE_SIZE};
+use crate::test_helpers;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+const AMOUNT: i128 = 10_000_000;
+const UNIT: i128 = crate::validation::MIN_BOND_AMOUNT;
+const DURATION: u64 = 86_400;
+
+fn kinds(e: &Env, entries: &Vec<JournalEntry>) -> Vec<Symbol> {
+    let mut out = Vec::new(e);
+    for entry in entries.iter() {
+        out.push_back(entry.kind);
+    }
+    out
+}
+
+#[test]
+fn test_lifecycle_is_journaled_in_order() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    client.top_up(&identity, &identity, &UNIT);
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+    let attestation = client.add_attestation(&attester, &identity, &String::from_str(&e, "kyc"));
+    client.slash(&admin, &identity, &200);
+    e.ledger().with_mut(|li| li.timestamp += DURATION);
+    client.withdraw_bond(&identity, &100);
+    client.revoke_attestation_by_admin(&admin, &attestation.id, &Symbol::new(&e, "fraud"));
+
+    let journal = client.get_journal(&identity, &0, &MAX_PAGE_SIZE);
+    let expected = [
+        ("bond_created", AMOUNT, 0),
+        ("bond_increased", UNIT, 0),
+        (
+            "attestation_added",
+            i128::from(attestation.weight),
+            attestation.id,
+        ),
+        ("bond_slashed", 200, 0),
+        ("bond_withdrawn", 100, 0),
+        (
+            "attestation_revoked",
+            i128::from(attestation.weight),
+            attestation.id,
+        ),
+    ];
+    assert_eq!(journal.len() as usize, expected.len());
+    for (entry, (kind, amount, ref_id)) in journal.iter().zip(expected) {
+        assert_eq!(entry.kind, Symbol::new(&e, kind));
+        assert_eq!(entry.amount, amount);
+        assert_eq!(entry.ref_id, ref_id);
+    }
+    assert_eq!(journal.get_unchecked(0).at, 1_000);
+    assert_eq!(journal.get_unchecked(4).at, 1_000 + DURATION);
+
+    // The attester's own journal is untouched by attestations it issued.
+    assert!(client.get_journal(&attester, &0, &MAX_PAGE_SIZE).is_empty());
+}
+
+#[test]
+fn test_pruning_keeps_newest_entries() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    let top_ups = i128::from(MAX_JOURNAL_ENTRIES) + 10;
+    for i in 1..=top_ups {
+        client.top_up(&identity, &identity, &(i * UNIT));
+    }
+
+    let journal = client.get_journal(&identity, &0, &MAX_PAGE_SIZE);
+    assert_eq!(journal.len(), MAX_JOURNAL_ENTRIES);
+    // The create entry and the oldest top-ups were pruned.
+    assert_eq!(journal.get_unchecked(0).amount, 11 * UNIT);
+    assert_eq!(
+        journal.get_unchecked(MAX_JOURNAL_ENTRIES - 1).amount,
+        top_ups * UNIT
+    );
+    for entry in journal.iter() {
+        assert_eq!(entry.kind, Symbol::new(&e, "bond_increased"));
+    }
+}
+
+#[test]
+fn test_paging() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    client.top_up(&identity, &identity, &UNIT);
+    client.top_up(&identity, &identity, &(2 * UNIT));
+
+    let page = client.get_journal(&identity, &1, &1);
+    assert_eq!(
+        kinds(&e, &page),
+        Vec::from_array(&e, [Symbol::new(&e, "bond_increased")])
+    );
+    assert_eq!(page.get_unchecked(0).amount, UNIT);
+    assert_eq!(client.get_journal(&identity, &2, &10).len(), 1);
+    assert!(client.get_journal(&identity, &3, &10).is_empty());
+    assert!(client
+        .get_journal(&Address::generate(&e), &0, &10)
+        .is_empty());
+}
+
+#[test]
+#[should_panic(expected = "page size too large")]
+fn test_page_size_is_capped() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.get_journal(&identity, &0, &(MAX_PAGE_SIZE + 1));
+}
@@ -0,0 +1,144 @@
+//! Event-count tests for the bond lifecycle: exactly one event per operation, with decodable
+//! payloads.
+
+use crate::context::Context;
+use crate::events::EVENT_SCHEMA_VERSION;
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{vec, Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec};
+
+type ContractEvent = (Address, Vec<Val>, Val);
+
+const AMOUNT: i128 = 10_000_000;
+const DURATION: u64 = 86_400;
+
+fn topics(e: &Env, name: &str, identity: &Address) -> Vec<Val> {
+    vec![
+        e,
+        Symbol::new(e, name).into_val(e),
+        EVENT_SCHEMA_VERSION.into_val(e),
+        identity.into_val(e),
+    ]
+}
+
+fn count_event_topics(
+    events: &Vec<ContractEvent>,
+    contract_id: &Address,
+    topics: &Vec<Val>,
+) -> u32 {
+    events
+        .iter()
+        .filter(|(c, t, _)| c == contract_id && t == topics)
+        .count() as u32
+}
+
+/// Data of the single `name` event for `identity` in the last invocation.
+fn single_event(e: &Env, contract_id: &Address, name: &str, identity: &Address) -> Val {
+    let events = e.events().all();
+    let topics = topics(e, name, identity);
+    assert_eq!(count_event_topics(&events, contract_id, &topics), 1);
+    events
+        .iter()
+        .find(|(c, t, _)| c == contract_id && *t == topics)
+        .unwrap()
+        .2
+}
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address) {
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (client, admin, identity, _token, contract_id) = test_helpers::setup_with_token(e);
+    (client, admin, identity, contract_id)
+}
+
+#[test]
+fn test_create_bond_emits_one_event() {
+    let e = Env::default();
+    let (client, _admin, identity, contract_id) = setup(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+
+    let data = single_event(&e, &contract_id, "bond_created", &identity);
+    let (amount, duration, rolling, ctx) =
+        <(i128, u64, bool, Context)>::try_from_val(&e, &data).unwrap();
+    assert_eq!((amount, duration, rolling), (AMOUNT, DURATION, false));
+    assert_eq!(ctx.timestamp, 1_000);
+}
+
+#[test]
+fn test_top_up_emits_one_event() {
+    let e = Env::default();
+    let (client, _admin, identity, contract_id) = setup(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    client.top_up(&identity, &identity, &AMOUNT);
+
+    let data = single_event(&e, &contract_id, "bond_increased", &identity);
+    let (added, total, _ctx) = <(i128, i128, Context)>::try_from_val(&e, &data).unwrap();
+    assert_eq!((added, total), (AMOUNT, 2 * AMOUNT));
+}
+
+#[test]
+fn test_withdraw_emits_one_event() {
+    let e = Env::default();
+    let (client, _admin, identity, contract_id) = setup(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    e.ledger().with_mut(|li| li.timestamp = 1_000 + DURATION);
+    client.withdraw(&identity, &(AMOUNT / 4));
+
+    let data = single_event(&e, &contract_id, "bond_withdrawn", &identity);
+    let (amount, remaining, ctx) = <(i128, i128, Context)>::try_from_val(&e, &data).unwrap();
+    assert_eq!((amount, remaining), (AMOUNT / 4, AMOUNT - AMOUNT / 4));
+    assert_eq!(ctx.timestamp, 1_000 + DURATION);
+}
+
+#[test]
+fn test_full_withdrawal_emits_one_event() {
+    let e = Env::default();
+    let (client, _admin, identity, contract_id) = setup(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    client.withdraw_bond_full(&identity);
+
+    let data = single_event(&e, &contract_id, "bond_withdrawn", &identity);
+    let (amount, remaining, _ctx) = <(i128, i128, Context)>::try_from_val(&e, &data).unwrap();
+    assert_eq!((amount, remaining), (AMOUNT, 0));
+}
+
+#[test]
+fn test_cooldown_withdrawal_emits_one_event() {
+    let e = Env::default();
+    let (client, admin, identity, contract_id) = setup(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    client.set_cooldown_period(&admin, &100);
+    client.request_cooldown_withdrawal(&identity, &1_000_000);
+    e.ledger().with_mut(|li| li.timestamp = 1_100);
+    client.execute_cooldown_withdrawal(&identity);
+
+    let data = single_event(&e, &contract_id, "bond_withdrawn", &identity);
+    let (amount, remaining, _ctx) = <(i128, i128, Context)>::try_from_val(&e, &data).unwrap();
+    assert_eq!((amount, remaining), (1_000_000, AMOUNT - 1_000_000));
+}
+
+#[test]
+fn test_governance_slash_emits_one_event() {
+    let e = Env::default();
+    let (client, admin, identity, contract_id) = setup(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    let governor = Address::generate(&e);
+    client.initialize_governance(&admin, &vec![&e, governor.clone()], &5_100, &1);
+    let id = client.propose_slash(&admin, &identity, &(2 * AMOUNT));
+    client.governance_vote(&governor, &id, &true);
+    client.execute_slash_with_governance(&admin, &id);
+
+    let data = single_event(&e, &contract_id, "slash_executed", &identity);
+    let (amount, bonded, slashed, ctx) =
+        <(i128, i128, i128, Context)>::try_from_val(&e, &data).unwrap();
+    assert_eq!((amount, bonded, slashed), (2 * AMOUNT, AMOUNT, AMOUNT));
+    assert_eq!(ctx.timestamp, 1_000);
+    assert_eq!(
+        count_event_topics(
+            &e.events().all(),
+            &contract_id,
+            &topics(&e, "bond_slashed", &identity)
+        ),
+        1
+    );
+}
@@ -0,0 +1,194 @@
+//! Tests for reentrancy lock monitoring: lock introspection, refusal while a lock is held,
+//! automatic clearing of a stuck lock and the admin force release.
+
+use crate::events::EVENT_SCHEMA_VERSION;
+use crate::{CredenceBond, CredenceBondClient, LockInfo};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Env, IntoVal, Symbol, TryFromVal};
+
+const STALE_AFTER: u64 = 7 * 86_400;
+const HELD_SINCE_LEDGER: u32 = 100;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin, contract_id)
+}
+
+/// Test hook: leave the lock set as if a previous invocation never released it.
+fn simulate_stuck_lock(e: &Env, contract_id: &Address, held_since: u64) {
+    e.as_contract(contract_id, || {
+        e.storage().instance().set(&Symbol::new(e, "lock"), &true);
+        e.storage()
+            .instance()
+            .set(&Symbol::new(e, "lock_since"), &held_since);
+        e.storage()
+            .instance()
+            .set(&Symbol::new(e, "lock_seq"), &HELD_SINCE_LEDGER);
+    });
+}
+
+fn find_event_data(e: &Env, contract_id: &Address, name: &str) -> Option<soroban_sdk::Val> {
+    let topics = soroban_sdk::vec![
+        e,
+        Symbol::new(e, name).into_val(e),
+        EVENT_SCHEMA_VERSION.into_val(e)
+    ];
+    e.events()
+        .all()
+        .iter()
+        .find(|(c, t, _)| c == contract_id && *t == topics)
+        .map(|(_, _, data)| data)
+}
+
+#[test]
+fn test_lock_info_defaults_to_unlocked() {
+    let e = Env::default();
+    let (client, _admin, _contract_id) = setup(&e);
+    assert_eq!(
+        client.get_lock_info(),
+        LockInfo {
+            locked: false,
+            held_since: 0,
+            held_since_ledger: 0,
+            held_for_ledgers: 0,
+        }
+    );
+}
+
+#[test]
+fn test_lock_released_after_guarded_call() {
+    let e = Env::default();
+    let (client, admin, _contract_id) = setup(&e);
+    e.ledger().with_mut(|li| li.timestamp = 5_000);
+
+    client.collect_fees(&admin);
+
+    assert!(!client.get_lock_info().locked);
+    assert_eq!(client.get_lock_info().held_since, 0);
+}
+
+#[test]
+fn test_stuck_lock_reported_by_lock_info() {
+    let e = Env::default();
+    let (client, _admin, contract_id) = setup(&e);
+    simulate_stuck_lock(&e, &contract_id, 1_000);
+    e.ledger()
+        .with_mut(|li| li.sequence_number = HELD_SINCE_LEDGER + 25);
+
+    assert_eq!(
+        client.get_lock_info(),
+        LockInfo {
+            locked: true,
+            held_since: 1_000,
+            held_since_ledger: HELD_SINCE_LEDGER,
+            held_for_ledgers: 25,
+        }
+    );
+    assert!(client.is_locked());
+}
+
+#[test]
+fn test_stuck_lock_blocks_guarded_call() {
+    let e = Env::default();
+    let (client, admin, contract_id) = setup(&e);
+    simulate_stuck_lock(&e, &contract_id, 1_000);
+    e.ledger().with_mut(|li| li.timestamp = 2_000);
+
+    assert!(client.try_collect_fees(&admin).is_err());
+    // The refusal rolls back, so the lock and its timestamps are exactly as they were.
+    let info = client.get_lock_info();
+    assert!(info.locked);
+    assert_eq!(info.held_since, 1_000);
+    assert_eq!(info.held_since_ledger, HELD_SINCE_LEDGER);
+}
+
+#[test]
+fn test_stuck_lock_still_blocks_just_before_threshold() {
+    let e = Env::default();
+    let (client, admin, contract_id) = setup(&e);
+    simulate_stuck_lock(&e, &contract_id, 1_000);
+    e.ledger()
+        .with_mut(|li| li.timestamp = 1_000 + STALE_AFTER - 1);
+
+    assert!(client.try_collect_fees(&admin).is_err());
+    assert!(client.get_lock_info().locked);
+}
+
+#[test]
+fn test_stuck_lock_cleared_after_threshold() {
+    let e = Env::default();
+    let (client, admin, contract_id) = setup(&e);
+    simulate_stuck_lock(&e, &contract_id, 1_000);
+    e.ledger().with_mut(|li| li.timestamp = 1_000 + STALE_AFTER);
+
+    assert_eq!(client.collect_fees(&admin), 0);
+
+    let data = find_event_data(&e, &contract_id, "reentrancy_lock_expired")
+        .expect("reentrancy_lock_expired event");
+    let (context, held_since, now) = <(Symbol, u64, u64)>::try_from_val(&e, &data).unwrap();
+    assert_eq!(context, Symbol::new(&e, "collect_fees"));
+    assert_eq!(held_since, 1_000);
+    assert_eq!(now, 1_000 + STALE_AFTER);
+    assert!(!client.get_lock_info().locked);
+}
+
+#[test]
+fn test_lock_records_acquisition_ledger() {
+    let e = Env::default();
+    let (client, admin, contract_id) = setup(&e);
+    e.ledger().with_mut(|li| li.sequence_number = 42);
+
+    // Inspect the lock from inside a guarded call.
+    e.as_contract(&contract_id, || {
+        CredenceBond::acquire_lock(&e, "collect_fees");
+    });
+    let info = client.get_lock_info();
+    assert_eq!(info.held_since_ledger, 42);
+    assert_eq!(info.held_for_ledgers, 0);
+
+    client.force_release_lock(&admin);
+    assert_eq!(client.get_lock_info().held_since_ledger, 0);
+}
+
+#[test]
+fn test_admin_force_releases_stuck_lock() {
+    let e = Env::default();
+    let (client, admin, contract_id) = setup(&e);
+    simulate_stuck_lock(&e, &contract_id, 1_000);
+    e.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert!(client.try_collect_fees(&admin).is_err());
+
+    client.force_release_lock(&admin);
+    let data = find_event_data(&e, &contract_id, "reentrancy_lock_force_released")
+        .expect("reentrancy_lock_force_released event");
+    let (by, held_since, held_since_ledger) =
+        <(Address, u64, u32)>::try_from_val(&e, &data).unwrap();
+    assert_eq!(by, admin);
+    assert_eq!(held_since, 1_000);
+    assert_eq!(held_since_ledger, HELD_SINCE_LEDGER);
+    assert!(!client.is_locked());
+
+    // Guarded entrypoints work again well before the lock would have gone stale.
+    assert_eq!(client.collect_fees(&admin), 0);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_non_admin_cannot_force_release_lock() {
+    let e = Env::default();
+    let (client, _admin, contract_id) = setup(&e);
+    simulate_stuck_lock(&e, &contract_id, 1_000);
+    client.force_release_lock(&Address::generate(&e));
+}
+
+#[test]
+#[should_panic(expected = "lock not held")]
+fn test_force_release_requires_held_lock() {
+    let e = Env::default();
+    let (client, admin, _contract_id) = setup(&e);
+    client.force_release_lock(&admin);
+}
@@ -0,0 +1,112 @@
+//! Tests for the maturity ladder aggregates.
+
+use crate::maturity_ladder::EPOCH_SECS;
+use crate::{test_helpers, BatchBondParams};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, Vec};
+
+const WEEK: u64 = EPOCH_SECS;
+
+fn set_time(e: &Env, t: u64) {
+    e.ledger().with_mut(|li| li.timestamp = t);
+}
+
+fn params(identity: Address, amount: i128, duration: u64, is_rolling: bool) -> BatchBondParams {
+    BatchBondParams {
+        identity,
+        amount,
+        duration,
+        is_rolling,
+        notice_period_duration: if is_rolling { 3600 } else { 0 },
+    }
+}
+
+#[test]
+fn test_ladder_with_staggered_durations() {
+    let e = Env::default();
+    let (client, ..) = test_helpers::setup_with_token(&e);
+    set_time(&e, 0);
+
+    let mut list = Vec::new(&e);
+    list.push_back(params(Address::generate(&e), 1_000_000_000, WEEK, false));
+    list.push_back(params(
+        Address::generate(&e),
+        2_000_000_000,
+        WEEK + 10,
+        false,
+    ));
+    list.push_back(params(
+        Address::generate(&e),
+        3_000_000_000,
+        3 * WEEK,
+        false,
+    ));
+    client.create_batch_bonds(&list);
+
+    let ladder = client.get_maturity_ladder(&0, &4);
+    assert_eq!(ladder.len(), 4);
+    assert_eq!(ladder.get(0).unwrap(), (0, 0));
+    assert_eq!(ladder.get(1).unwrap(), (1, 3_000_000_000));
+    assert_eq!(ladder.get(2).unwrap(), (2, 0));
+    assert_eq!(ladder.get(3).unwrap(), (3, 3_000_000_000));
+}
+
+#[test]
+fn test_extension_moves_value_between_buckets() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    set_time(&e, 0);
+    client.create_bond(&identity, &5_000_000_000, &WEEK, &false, &0);
+    assert_eq!(
+        client.get_maturity_ladder(&1, &1).get(0).unwrap(),
+        (1, 5_000_000_000)
+    );
+
+    client.extend_duration(&identity, &(2 * WEEK));
+    let ladder = client.get_maturity_ladder(&1, &3);
+    assert_eq!(ladder.get(0).unwrap(), (1, 0));
+    assert_eq!(ladder.get(2).unwrap(), (3, 5_000_000_000));
+}
+
+#[test]
+fn test_withdrawal_and_slash_reduce_bucket() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    set_time(&e, 0);
+    client.create_bond(&identity, &5_000_000_000, &WEEK, &false, &0);
+    client.slash(&admin, &identity, &1_000_000_000);
+    assert_eq!(
+        client.get_maturity_ladder(&1, &1).get(0).unwrap(),
+        (1, 4_000_000_000)
+    );
+
+    set_time(&e, WEEK);
+    client.withdraw(&identity, &4_000_000_000);
+    assert_eq!(client.get_maturity_ladder(&1, &1).get(0).unwrap(), (1, 0));
+}
+
+#[test]
+fn test_rolling_bond_moves_on_renewal() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    set_time(&e, 0);
+    client.create_bond(&identity, &5_000_000_000, &WEEK, &true, &3600);
+    assert_eq!(
+        client.get_maturity_ladder(&1, &1).get(0).unwrap(),
+        (1, 5_000_000_000)
+    );
+
+    set_time(&e, WEEK + 1);
+    client.renew_if_rolling(&identity);
+    let ladder = client.get_maturity_ladder(&1, &2);
+    assert_eq!(ladder.get(0).unwrap(), (1, 0));
+    assert_eq!(ladder.get(1).unwrap(), (2, 5_000_000_000));
+}
+
+#[test]
+#[should_panic(expected = "ladder range too large")]
+fn test_ladder_range_is_bounded() {
+    let e = Env::default();
+    let (client, ..) = test_helpers::setup_with_token(&e);
+    client.get_maturity_ladder(&0, &105);
+}
@@ -0,0 +1,126 @@
+//! Tests for multiple bonds per identity: ids, isolation between bonds, per-bond withdrawal
+//! and slashing, and the single-bond API mapping to bond 0.
+
+use crate::test_helpers;
+use crate::{BondStatus, BondTier};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Env};
+
+const AMOUNT: i128 = 10_000_000;
+const SHORT: u64 = 86_400;
+const LONG: u64 = 30 * 86_400;
+
+/// Mints `amount` to `who` and approves the bond contract to pull it.
+fn fund(e: &Env, token: &Address, contract_id: &Address, who: &Address, amount: i128) {
+    StellarAssetClient::new(e, token).mint(who, &amount);
+    let expiration = e.ledger().sequence().saturating_add(10_000);
+    TokenClient::new(e, token).approve(who, contract_id, &amount, &expiration);
+}
+
+#[test]
+fn test_ids_start_after_legacy_bond() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    assert!(client.list_bond_ids(&identity).is_empty());
+
+    assert_eq!(client.create_bond_v2(&identity, &AMOUNT, &SHORT), 1);
+    client.create_bond(&identity, &AMOUNT, &LONG, &false, &0_u64);
+    assert_eq!(client.create_bond_v2(&identity, &AMOUNT, &LONG), 2);
+
+    assert_eq!(client.list_bond_ids(&identity), vec![&e, 0_u64, 1, 2]);
+    assert_eq!(client.get_bond(&identity, &1).bond_duration, SHORT);
+    assert_eq!(
+        client.get_bond(&identity, &0),
+        client.get_identity_state(&identity)
+    );
+}
+
+#[test]
+fn test_ids_are_per_identity() {
+    let e = Env::default();
+    let (client, _admin, identity, token, contract_id) = test_helpers::setup_with_token(&e);
+    let other = Address::generate(&e);
+    fund(&e, &token, &contract_id, &other, AMOUNT);
+
+    assert_eq!(client.create_bond_v2(&identity, &AMOUNT, &SHORT), 1);
+    assert_eq!(client.create_bond_v2(&other, &AMOUNT, &SHORT), 1);
+    assert_eq!(client.list_bond_ids(&other), vec![&e, 1_u64]);
+}
+
+#[test]
+fn test_withdraw_one_bond_leaves_others() {
+    let e = Env::default();
+    let (client, _admin, identity, token, _) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &LONG, &false, &0_u64);
+    let short = client.create_bond_v2(&identity, &AMOUNT, &SHORT);
+    let long = client.create_bond_v2(&identity, &AMOUNT, &LONG);
+
+    e.ledger().with_mut(|li| li.timestamp += SHORT);
+    assert!(client.try_withdraw_bond_v2(&identity, &long, &1).is_err());
+    assert!(client.try_withdraw_bond_v2(&identity, &0, &1).is_err());
+
+    let before = TokenClient::new(&e, &token).balance(&identity);
+    let bond = client.withdraw_bond_v2(&identity, &short, &AMOUNT);
+    assert_eq!(bond.status, BondStatus::Closed);
+    assert!(!bond.active);
+    assert_eq!(
+        TokenClient::new(&e, &token).balance(&identity),
+        before + AMOUNT
+    );
+    assert_eq!(client.get_bond(&identity, &long).bonded_amount, AMOUNT);
+    assert_eq!(client.get_identity_state(&identity).bonded_amount, AMOUNT);
+    assert!(client.try_withdraw_bond_v2(&identity, &short, &0).is_err());
+}
+
+#[test]
+fn test_slash_targets_one_bond() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &LONG, &false, &0_u64);
+    let id = client.create_bond_v2(&identity, &AMOUNT, &LONG);
+
+    let bond = client.slash_bond_v2(&admin, &identity, &id, &(2 * AMOUNT));
+    assert_eq!(bond.slashed_amount, AMOUNT);
+    assert_eq!(client.get_identity_state(&identity).slashed_amount, 0);
+
+    let legacy = client.slash_bond_v2(&admin, &identity, &0, &100);
+    assert_eq!(legacy.slashed_amount, 100);
+    assert_eq!(client.get_bond(&identity, &id).slashed_amount, AMOUNT);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_slash_requires_admin() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    let id = client.create_bond_v2(&identity, &AMOUNT, &LONG);
+    client.slash_bond_v2(&Address::generate(&e), &identity, &id, &100);
+}
+
+#[test]
+fn test_aggregate_tier_sums_open_bonds() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    // Each bond alone is Bronze; together they cross into Silver.
+    let half = crate::tiered_bond::TIER_BRONZE_MAX / 2 + 1;
+    client.create_bond(&identity, &half, &LONG, &false, &0_u64);
+    let id = client.create_bond_v2(&identity, &half, &SHORT);
+
+    assert_eq!(client.get_tier(&identity), BondTier::Bronze);
+    assert_eq!(client.get_bond_tier(&identity, &id), BondTier::Bronze);
+    assert_eq!(client.get_aggregate_tier(&identity), BondTier::Silver);
+
+    e.ledger().with_mut(|li| li.timestamp += SHORT);
+    client.withdraw_bond_v2(&identity, &id, &half);
+    assert_eq!(client.get_aggregate_tier(&identity), BondTier::Bronze);
+}
+
+#[test]
+#[should_panic(expected = "no bond")]
+fn test_unknown_bond_id() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond_v2(&identity, &AMOUNT, &SHORT);
+    client.get_bond(&identity, &7);
+}
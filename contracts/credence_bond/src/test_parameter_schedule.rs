@@ -0,0 +1,162 @@
+//! Tests for parameter changes scheduled ahead of their effective time.
+
+use crate::parameters::{ParameterKey, DEFAULT_PROTOCOL_FEE_BPS, DEFAULT_SILVER_THRESHOLD};
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Env, Symbol, TryFromVal};
+
+const NOW: u64 = 1_000;
+const LATER: u64 = 2_000;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = NOW);
+    let client = CredenceBondClient::new(e, &e.register(CredenceBond, ()));
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+fn set_time(e: &Env, t: u64) {
+    e.ledger().with_mut(|li| li.timestamp = t);
+}
+
+fn emitted(e: &Env, name: &str) -> bool {
+    e.events().all().iter().any(|(_, topics, _)| {
+        topics
+            .get(0)
+            .and_then(|t| Symbol::try_from_val(e, &t).ok())
+            .is_some_and(|s| s == Symbol::new(e, name))
+    })
+}
+
+#[test]
+fn test_old_value_until_effective() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.schedule_parameter_change(&admin, &ParameterKey::ProtocolFeeBps, &200, &LATER);
+    assert!(emitted(&e, "change_scheduled"));
+
+    set_time(&e, LATER - 1);
+    assert_eq!(client.get_protocol_fee_bps(), DEFAULT_PROTOCOL_FEE_BPS);
+    assert_eq!(client.get_scheduled_changes().len(), 1);
+}
+
+#[test]
+fn test_first_read_after_effective_applies() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.schedule_parameter_change(&admin, &ParameterKey::ProtocolFeeBps, &200, &LATER);
+
+    set_time(&e, LATER);
+    assert_eq!(client.get_protocol_fee_bps(), 200);
+    assert!(emitted(&e, "change_applied"));
+    assert!(client.get_scheduled_changes().is_empty());
+}
+
+#[test]
+fn test_read_applies_only_its_parameter() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.schedule_parameter_change(&admin, &ParameterKey::ProtocolFeeBps, &200, &LATER);
+    client.schedule_parameter_change(
+        &admin,
+        &ParameterKey::SilverThreshold,
+        &(2 * DEFAULT_SILVER_THRESHOLD),
+        &LATER,
+    );
+
+    set_time(&e, LATER);
+    assert_eq!(client.get_protocol_fee_bps(), 200);
+    let pending = client.get_scheduled_changes();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending.get(0).unwrap().key, ParameterKey::SilverThreshold);
+
+    assert_eq!(client.apply_scheduled_changes(), 1);
+    assert_eq!(client.get_silver_threshold(), 2 * DEFAULT_SILVER_THRESHOLD);
+}
+
+#[test]
+fn test_due_changes_apply_in_effective_order() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.schedule_parameter_change(&admin, &ParameterKey::ProtocolFeeBps, &300, &(LATER + 10));
+    client.schedule_parameter_change(&admin, &ParameterKey::ProtocolFeeBps, &200, &LATER);
+
+    set_time(&e, LATER + 10);
+    assert_eq!(client.get_protocol_fee_bps(), 300);
+}
+
+#[test]
+fn test_cancelled_change_never_applies() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let id = client.schedule_parameter_change(&admin, &ParameterKey::ProtocolFeeBps, &200, &LATER);
+    client.cancel_parameter_change(&admin, &id);
+    assert!(client.get_scheduled_changes().is_empty());
+
+    set_time(&e, LATER);
+    assert_eq!(client.get_protocol_fee_bps(), DEFAULT_PROTOCOL_FEE_BPS);
+    assert!(client.try_cancel_parameter_change(&admin, &id).is_err());
+}
+
+#[test]
+#[should_panic(expected = "scheduled change not found")]
+fn test_cannot_cancel_after_effect() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let id = client.schedule_parameter_change(&admin, &ParameterKey::ProtocolFeeBps, &200, &LATER);
+    set_time(&e, LATER);
+    client.cancel_parameter_change(&admin, &id);
+}
+
+#[test]
+fn test_describe_config_shows_current_and_pending() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let id = client.schedule_parameter_change(&admin, &ParameterKey::ProtocolFeeBps, &200, &LATER);
+
+    let config = client.describe_config();
+    let fee = config
+        .current
+        .iter()
+        .find(|p| p.key == ParameterKey::ProtocolFeeBps)
+        .unwrap();
+    assert_eq!(fee.value, i128::from(DEFAULT_PROTOCOL_FEE_BPS));
+    assert_eq!(config.pending.len(), 1);
+    assert_eq!(config.pending.get(0).unwrap().id, id);
+    assert_eq!(config.pending.get(0).unwrap().value, 200);
+
+    set_time(&e, LATER);
+    let config = client.describe_config();
+    assert!(config.pending.is_empty());
+    assert!(config
+        .current
+        .iter()
+        .any(|p| p.key == ParameterKey::ProtocolFeeBps && p.value == 200));
+}
+
+#[test]
+#[should_panic(expected = "protocol_fee_bps out of bounds")]
+fn test_schedule_checks_bounds() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.schedule_parameter_change(&admin, &ParameterKey::ProtocolFeeBps, &1_001, &LATER);
+}
+
+#[test]
+#[should_panic(expected = "effective_at must be in the future")]
+fn test_schedule_rejects_past_effective_time() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.schedule_parameter_change(&admin, &ParameterKey::ProtocolFeeBps, &200, &NOW);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_schedule_requires_admin() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let other = Address::generate(&e);
+    client.schedule_parameter_change(&other, &ParameterKey::ProtocolFeeBps, &200, &LATER);
+}
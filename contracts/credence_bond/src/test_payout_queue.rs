@@ -0,0 +1,149 @@
+//! Tests for the opt-in withdrawal payout queue used during contract liquidity shortfalls.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env};
+
+const AMOUNT: i128 = 30_000_000;
+const DURATION: u64 = 86_400;
+const START: u64 = 1_000;
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    admin: Address,
+    identity: Address,
+    token: TokenClient<'a>,
+    asset: StellarAssetClient<'a>,
+    contract_id: Address,
+}
+
+/// Matured bond whose contract balance has been drained to zero.
+fn setup_shortfall(e: &Env) -> Setup<'_> {
+    let (client, admin, identity, token, contract_id) = test_helpers::setup_with_token(e);
+    e.ledger().with_mut(|li| li.timestamp = START);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0);
+    e.ledger().with_mut(|li| li.timestamp = START + DURATION);
+
+    let token_client = TokenClient::new(e, &token);
+    let sink = Address::generate(e);
+    token_client.transfer(&contract_id, &sink, &AMOUNT);
+    assert_eq!(token_client.balance(&contract_id), 0);
+
+    Setup {
+        client,
+        admin,
+        identity,
+        token: token_client,
+        asset: StellarAssetClient::new(e, &token),
+        contract_id,
+    }
+}
+
+#[test]
+fn test_queue_disabled_by_default_and_shortfall_fails() {
+    let e = Env::default();
+    let s = setup_shortfall(&e);
+    assert!(!s.client.is_payout_queue_enabled());
+    assert!(s.client.try_withdraw(&s.identity, &10_000_000).is_err());
+    assert_eq!(
+        s.client.get_identity_state(&s.identity).bonded_amount,
+        AMOUNT
+    );
+}
+
+#[test]
+fn test_shortfall_queues_and_updates_bond() {
+    let e = Env::default();
+    let s = setup_shortfall(&e);
+    s.client.set_payout_queue_enabled(&s.admin, &true);
+    let before = s.token.balance(&s.identity);
+
+    let bond = s.client.withdraw(&s.identity, &10_000_000);
+
+    assert_eq!(bond.bonded_amount, AMOUNT - 10_000_000);
+    assert_eq!(s.token.balance(&s.identity), before);
+    assert_eq!(s.client.get_payout_queue_len(), 1);
+    let pending = s.client.get_pending_payout(&0).unwrap();
+    assert_eq!(pending.identity, s.identity);
+    assert_eq!(pending.amount, 10_000_000);
+    assert_eq!(pending.queued_at, START + DURATION);
+}
+
+#[test]
+fn test_replenish_drains_queue_in_order() {
+    let e = Env::default();
+    let s = setup_shortfall(&e);
+    s.client.set_payout_queue_enabled(&s.admin, &true);
+    let before = s.token.balance(&s.identity);
+
+    s.client.withdraw(&s.identity, &10_000_000);
+    s.client.withdraw(&s.identity, &5_000_000);
+    assert_eq!(s.client.get_payout_queue_len(), 2);
+
+    // Enough for the first payout only: FIFO stops at the second.
+    s.asset.mint(&s.contract_id, &12_000_000);
+    assert_eq!(s.client.process_payout_queue(&10), 1);
+    assert_eq!(s.token.balance(&s.identity), before + 10_000_000);
+    assert_eq!(s.client.get_payout_queue_len(), 1);
+    assert_eq!(s.client.get_pending_payout(&0).unwrap().amount, 5_000_000);
+
+    s.asset.mint(&s.contract_id, &3_000_000);
+    assert_eq!(s.client.process_payout_queue(&10), 1);
+    assert_eq!(s.token.balance(&s.identity), before + 15_000_000);
+    assert_eq!(s.client.get_payout_queue_len(), 0);
+    assert!(s.client.get_pending_payout(&0).is_none());
+    assert!(!s.client.is_locked());
+}
+
+#[test]
+fn test_process_respects_limit() {
+    let e = Env::default();
+    let s = setup_shortfall(&e);
+    s.client.set_payout_queue_enabled(&s.admin, &true);
+    s.client.withdraw(&s.identity, &1_000_000);
+    s.client.withdraw(&s.identity, &2_000_000);
+    s.client.withdraw(&s.identity, &3_000_000);
+
+    s.asset.mint(&s.contract_id, &AMOUNT);
+    assert_eq!(s.client.process_payout_queue(&2), 2);
+    assert_eq!(s.client.get_pending_payout(&0).unwrap().amount, 3_000_000);
+    assert_eq!(s.client.process_payout_queue(&2), 1);
+    assert_eq!(s.client.get_payout_queue_len(), 0);
+}
+
+#[test]
+fn test_new_payout_waits_behind_queue() {
+    let e = Env::default();
+    let s = setup_shortfall(&e);
+    s.client.set_payout_queue_enabled(&s.admin, &true);
+    s.client.withdraw(&s.identity, &10_000_000);
+
+    // Balance now covers the second withdrawal, but the earlier payout is still owed.
+    s.asset.mint(&s.contract_id, &5_000_000);
+    s.client.withdraw(&s.identity, &5_000_000);
+    assert_eq!(s.client.get_payout_queue_len(), 2);
+    assert_eq!(s.client.process_payout_queue(&10), 0);
+}
+
+#[test]
+fn test_sufficient_balance_pays_immediately() {
+    let e = Env::default();
+    let s = setup_shortfall(&e);
+    s.client.set_payout_queue_enabled(&s.admin, &true);
+    s.asset.mint(&s.contract_id, &AMOUNT);
+    let before = s.token.balance(&s.identity);
+
+    s.client.withdraw(&s.identity, &10_000_000);
+    assert_eq!(s.token.balance(&s.identity), before + 10_000_000);
+    assert_eq!(s.client.get_payout_queue_len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_enable_requires_admin() {
+    let e = Env::default();
+    let s = setup_shortfall(&e);
+    s.client.set_payout_queue_enabled(&s.identity, &true);
+}
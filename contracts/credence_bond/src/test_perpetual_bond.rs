@@ -0,0 +1,93 @@
+//! Tests for perpetual bonds: no maturity, exit by notice only.
+
+use crate::test_helpers;
+use crate::{BondKind, CredenceBondClient};
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{Address, Env};
+
+const AMOUNT: i128 = 10_000_000;
+const NOTICE: u64 = 30 * 24 * 60 * 60;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    let (client, admin, identity, _token_id, _bond_id) = test_helpers::setup_with_token(e);
+    (client, admin, identity)
+}
+
+fn set_time(e: &Env, t: u64) {
+    e.ledger().with_mut(|li| li.timestamp = t);
+}
+
+#[test]
+fn test_perpetual_bond_creation() {
+    let e = Env::default();
+    set_time(&e, 1_000);
+    let (client, _admin, identity) = setup(&e);
+    let bond = client.create_perpetual_bond(&identity, &AMOUNT, &NOTICE);
+    assert_eq!(bond.kind, BondKind::Perpetual);
+    assert!(!bond.is_rolling);
+    assert_eq!(bond.bond_duration, u64::MAX);
+    assert_eq!(bond.notice_period, NOTICE);
+
+    // Never matures and is ignored by renewal.
+    set_time(&e, 1_000 + 10 * 365 * 86_400);
+    let renewed = client.renew_if_rolling(&identity);
+    assert_eq!(renewed.bond_start, 1_000);
+}
+
+#[test]
+#[should_panic(expected = "perpetual notice period below minimum")]
+fn test_short_notice_rejected() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    client.create_perpetual_bond(&identity, &AMOUNT, &(NOTICE - 1));
+}
+
+#[test]
+fn test_withdrawal_requires_notice() {
+    let e = Env::default();
+    set_time(&e, 1_000);
+    let (client, _admin, identity) = setup(&e);
+    client.create_perpetual_bond(&identity, &AMOUNT, &NOTICE);
+
+    set_time(&e, 1_000 + 5 * 365 * 86_400);
+    assert!(client.try_withdraw(&identity, &AMOUNT).is_err());
+    assert!(client.try_withdraw_bond_full(&identity).is_err());
+
+    let requested_at = e.ledger().timestamp();
+    client.request_withdrawal(&identity);
+    set_time(&e, requested_at + NOTICE - 1);
+    assert!(client.try_withdraw(&identity, &AMOUNT).is_err());
+
+    set_time(&e, requested_at + NOTICE);
+    let bond = client.withdraw(&identity, &AMOUNT);
+    assert_eq!(bond.bonded_amount, 0);
+}
+
+#[test]
+#[should_panic(expected = "perpetual bond: use request_withdrawal")]
+fn test_withdraw_early_rejected() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    client.create_perpetual_bond(&identity, &AMOUNT, &NOTICE);
+    client.withdraw_early(&identity, &1_000);
+}
+
+#[test]
+fn test_slash_matches_fixed_bond() {
+    let slash = |perpetual: bool| {
+        let e = Env::default();
+        let (client, admin, identity) = setup(&e);
+        if perpetual {
+            client.create_perpetual_bond(&identity, &AMOUNT, &NOTICE);
+        } else {
+            client.create_bond(&identity, &AMOUNT, &86_400_u64, &false, &0_u64);
+        }
+        let bond = client.slash(&admin, &identity, &(AMOUNT / 4));
+        (
+            bond.bonded_amount,
+            bond.slashed_amount,
+            client.get_tier(&identity),
+        )
+    };
+    assert_eq!(slash(true), slash(false));
+}
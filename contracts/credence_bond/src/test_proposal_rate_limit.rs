@@ -0,0 +1,94 @@
+//! Tests for per-proposer rate limits on slash proposal creation.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, Vec};
+
+const SLASH_AMOUNT: i128 = 10_000_000;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address) {
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86_400_u64, &false, &0_u64);
+    let governor = Address::generate(e);
+    client.initialize_governance(
+        &admin,
+        &Vec::from_array(e, [governor.clone()]),
+        &5_000_u32,
+        &1_u32,
+    );
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    (client, admin, governor, identity)
+}
+
+#[test]
+fn test_third_open_proposal_rejected_until_one_closes() {
+    let e = Env::default();
+    let (client, admin, governor, identity) = setup(&e);
+    client.set_proposal_rate_limit(&admin, &2, &0);
+
+    let first = client.propose_slash(&governor, &identity, &SLASH_AMOUNT);
+    client.propose_slash(&governor, &identity, &SLASH_AMOUNT);
+    assert_eq!(client.get_open_proposal_count(&governor), 2);
+    assert!(client
+        .try_propose_slash(&governor, &identity, &SLASH_AMOUNT)
+        .is_err());
+
+    client.governance_vote(&governor, &first, &true);
+    client.execute_slash_with_governance(&governor, &first);
+    assert_eq!(client.get_open_proposal_count(&governor), 1);
+    client.propose_slash(&governor, &identity, &SLASH_AMOUNT);
+}
+
+#[test]
+fn test_limits_are_per_proposer() {
+    let e = Env::default();
+    let (client, admin, governor, identity) = setup(&e);
+    client.set_proposal_rate_limit(&admin, &1, &0);
+
+    client.propose_slash(&governor, &identity, &SLASH_AMOUNT);
+    client.propose_slash(&admin, &identity, &SLASH_AMOUNT);
+}
+
+#[test]
+fn test_cooldown_enforced_between_proposals() {
+    let e = Env::default();
+    let (client, admin, governor, identity) = setup(&e);
+    client.set_proposal_rate_limit(&admin, &0, &600);
+
+    client.propose_slash(&governor, &identity, &SLASH_AMOUNT);
+    e.ledger().with_mut(|li| li.timestamp = 1_599);
+    assert!(client
+        .try_propose_slash(&governor, &identity, &SLASH_AMOUNT)
+        .is_err());
+
+    e.ledger().with_mut(|li| li.timestamp = 1_600);
+    client.propose_slash(&governor, &identity, &SLASH_AMOUNT);
+}
+
+#[test]
+#[should_panic(expected = "proposal rate limited: retry after 1600")]
+fn test_cooldown_panic_reports_retry_after() {
+    let e = Env::default();
+    let (client, admin, governor, identity) = setup(&e);
+    client.set_proposal_rate_limit(&admin, &0, &600);
+
+    client.propose_slash(&governor, &identity, &SLASH_AMOUNT);
+    client.propose_slash(&governor, &identity, &SLASH_AMOUNT);
+}
+
+#[test]
+fn test_retry_after_view_tracks_cooldown() {
+    let e = Env::default();
+    let (client, admin, governor, identity) = setup(&e);
+    assert_eq!(client.get_proposal_retry_after(&governor), 0);
+    client.set_proposal_rate_limit(&admin, &0, &600);
+
+    client.propose_slash(&governor, &identity, &SLASH_AMOUNT);
+    assert_eq!(client.get_proposal_retry_after(&governor), 1_600);
+    assert_eq!(client.get_proposal_retry_after(&admin), 0);
+
+    e.ledger().with_mut(|li| li.timestamp = 1_600);
+    assert_eq!(client.get_proposal_retry_after(&governor), 0);
+    client.propose_slash(&governor, &identity, &SLASH_AMOUNT);
+}
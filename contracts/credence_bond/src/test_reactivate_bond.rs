@@ -0,0 +1,84 @@
+//! Tests for `reactivate_bond` and the inactive-bond guard on `top_up`.
+
+use crate::test_helpers;
+use crate::{BondKind, CredenceBondClient};
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{Address, Env};
+
+const AMOUNT: i128 = 10_000_000;
+const DUST: i128 = 2_000_000;
+const DURATION: u64 = 86_400;
+
+/// Bond that a partial withdrawal left as dust, auto-closing it (`active = false`).
+fn auto_closed(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    client.set_dust_threshold(&admin, &DUST);
+    e.ledger().with_mut(|li| li.timestamp = DURATION);
+    let bond = client.withdraw(&identity, &(AMOUNT - DUST / 2));
+    assert!(!bond.active);
+    (client, admin, identity)
+}
+
+#[test]
+#[should_panic(expected = "bond not active")]
+fn test_top_up_inactive_bond_rejected() {
+    let e = Env::default();
+    let (client, _admin, identity) = auto_closed(&e);
+    client.top_up(&identity, &identity, &AMOUNT);
+}
+
+#[test]
+fn test_reactivate_auto_closed_bond() {
+    let e = Env::default();
+    let (client, _admin, identity) = auto_closed(&e);
+
+    let bond = client.reactivate_bond(&identity, &AMOUNT, &(2 * DURATION));
+    assert!(bond.active);
+    assert_eq!(bond.bonded_amount, AMOUNT);
+    assert_eq!(bond.slashed_amount, 0);
+    assert_eq!(bond.bond_start, DURATION);
+    assert_eq!(bond.bond_duration, 2 * DURATION);
+    assert_eq!(bond.kind, BondKind::Fixed);
+
+    let bond = client.top_up(&identity, &identity, &AMOUNT);
+    assert_eq!(bond.bonded_amount, 2 * AMOUNT);
+}
+
+#[test]
+fn test_reactivate_fully_slashed_bond_keeps_kind() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &true, &3_600_u64);
+    client.slash(&admin, &identity, &AMOUNT);
+
+    let bond = client.reactivate_bond(&identity, &AMOUNT, &DURATION);
+    assert_eq!(bond.kind, BondKind::Rolling);
+    assert_eq!(bond.notice_period, 3_600);
+    assert_eq!(bond.slashed_amount, 0);
+}
+
+#[test]
+#[should_panic(expected = "bond still active")]
+fn test_reactivate_active_bond_rejected() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    client.reactivate_bond(&identity, &AMOUNT, &DURATION);
+}
+
+#[test]
+#[should_panic(expected = "bond amount below minimum required")]
+fn test_reactivate_requires_minimum_deposit() {
+    let e = Env::default();
+    let (client, _admin, identity) = auto_closed(&e);
+    client.reactivate_bond(&identity, &1_000, &DURATION);
+}
+
+#[test]
+#[should_panic(expected = "no bond")]
+fn test_reactivate_without_bond_rejected() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.reactivate_bond(&identity, &AMOUNT, &DURATION);
+}
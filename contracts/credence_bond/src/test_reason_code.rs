@@ -0,0 +1,113 @@
+//! Tests that entrypoints reject empty and over-long reason symbols, and that records stored
+//! with an empty reason before the checks still load.
+
+use crate::verifier::RevocationInitiator;
+use crate::{emergency, test_helpers};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+#[test]
+#[should_panic(expected = "reason must not be empty")]
+fn test_admin_revoke_rejects_empty_reason() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+    let att = client.add_attestation(&attester, &identity, &String::from_str(&e, "kyc"));
+    client.revoke_attestation_by_admin(&admin, &att.id, &Symbol::new(&e, ""));
+}
+
+#[test]
+#[should_panic(expected = "reason too long")]
+fn test_admin_revoke_rejects_long_reason() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+    let att = client.add_attestation(&attester, &identity, &String::from_str(&e, "kyc"));
+    client.revoke_attestation_by_admin(&admin, &att.id, &Symbol::new(&e, "fraudulent"));
+}
+
+#[test]
+fn test_reason_at_short_symbol_limit_accepted() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+    let att = client.add_attestation(&attester, &identity, &String::from_str(&e, "kyc"));
+    client.revoke_attestation_by_admin(&admin, &att.id, &Symbol::new(&e, "duplicate"));
+    assert!(client.get_attestation(&att.id).revoked);
+}
+
+#[test]
+fn test_multiplier_rejects_empty_reason() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    let empty = Some(Symbol::new(&e, ""));
+    assert!(client
+        .try_set_revocation_multiplier(&admin, &RevocationInitiator::Admin, &empty, &20_000)
+        .is_err());
+    client.set_revocation_multiplier(&admin, &RevocationInitiator::Admin, &None, &20_000);
+}
+
+#[test]
+#[should_panic(expected = "reason must not be empty")]
+fn test_emergency_withdraw_rejects_empty_reason() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    let governance = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    client.set_emergency_config(&admin, &governance, &treasury, &500, &true);
+    client.create_bond(&identity, &1_000_000_000_i128, &86_400_u64, &false, &0_u64);
+    client.emergency_withdraw(
+        &admin,
+        &governance,
+        &identity,
+        &200_000_000,
+        &Symbol::new(&e, ""),
+    );
+}
+
+#[test]
+#[should_panic(expected = "label must not be empty")]
+fn test_allow_list_rejects_empty_label() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    let labels = Vec::from_array(&e, [Symbol::new(&e, "vip"), Symbol::new(&e, "")]);
+    client.set_allowed_labels(&admin, &labels);
+}
+
+#[test]
+fn test_long_labels_still_allowed() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    let label = Symbol::new(&e, "pilot_partner");
+    client.set_allowed_labels(&admin, &Vec::from_array(&e, [label.clone()]));
+    client.set_identity_label(&admin, &identity, &label);
+    assert_eq!(client.get_identity_label(&identity), Some(label));
+}
+
+#[test]
+fn test_stored_empty_reason_record_still_loads() {
+    let e = Env::default();
+    let (client, admin, identity, _token, contract_id) = test_helpers::setup_with_token(&e);
+    let treasury = Address::generate(&e);
+    let governance = Address::generate(&e);
+    let id = e.as_contract(&contract_id, || {
+        emergency::store_record(
+            &e,
+            identity.clone(),
+            100,
+            5,
+            95,
+            treasury,
+            admin,
+            governance,
+            Symbol::new(&e, ""),
+        )
+    });
+
+    let record = client.get_emergency_record(&id);
+    assert_eq!(record.reason, Symbol::new(&e, ""));
+    assert_eq!(record.identity, identity);
+}
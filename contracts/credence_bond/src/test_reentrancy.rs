@@ -2,6 +2,7 @@
 //!
 //! These tests verify that:
 //! - Reentrancy in `withdraw_bond` is blocked
+//! - `withdraw_bond` and `withdraw_early` refuse to run while the lock is held
 //! - Reentrancy in `slash_bond` is blocked
 //! - Reentrancy in `collect_fees` is blocked
 //! - State locks are correctly acquired and released
@@ -10,7 +11,7 @@
 
 use super::*;
 use crate::test_helpers;
-use soroban_sdk::testutils::Address as _;
+use soroban_sdk::testutils::{Address as _, Ledger};
 use soroban_sdk::Env;
 
 // ---------------------------------------------------------------------------
@@ -74,17 +75,25 @@ mod slash_attacker {
                 .instance()
                 .get(&Symbol::new(&e, "admin"))
                 .unwrap();
+            let victim: Address = e
+                .storage()
+                .instance()
+                .get(&Symbol::new(&e, "victim"))
+                .unwrap();
             let client = CredenceBondClient::new(&e, &bond_addr);
-            client.slash_bond(&admin, &100_i128);
+            client.slash_bond(&admin, &victim, &100_000_000_i128);
         }
 
-        pub fn setup(e: Env, target: Address, admin: Address) {
+        pub fn setup(e: Env, target: Address, admin: Address, victim: Address) {
             e.storage()
                 .instance()
                 .set(&Symbol::new(&e, "target"), &target);
             e.storage()
                 .instance()
                 .set(&Symbol::new(&e, "admin"), &admin);
+            e.storage()
+                .instance()
+                .set(&Symbol::new(&e, "victim"), &victim);
         }
     }
 }
@@ -159,17 +168,25 @@ mod cross_attacker {
                 .instance()
                 .get(&Symbol::new(&e, "admin"))
                 .unwrap();
+            let victim: Address = e
+                .storage()
+                .instance()
+                .get(&Symbol::new(&e, "victim"))
+                .unwrap();
             let client = CredenceBondClient::new(&e, &bond_addr);
-            client.slash_bond(&admin, &100_i128);
+            client.slash_bond(&admin, &victim, &100_000_000_i128);
         }
 
-        pub fn setup(e: Env, target: Address, admin: Address) {
+        pub fn setup(e: Env, target: Address, admin: Address, victim: Address) {
             e.storage()
                 .instance()
                 .set(&Symbol::new(&e, "target"), &target);
             e.storage()
                 .instance()
                 .set(&Symbol::new(&e, "admin"), &admin);
+            e.storage()
+                .instance()
+                .set(&Symbol::new(&e, "victim"), &victim);
         }
     }
 }
@@ -184,18 +201,8 @@ use withdraw_attacker::{WithdrawAttacker, WithdrawAttackerClient};
 // Helper: set up a bond contract with admin, identity, and a bond.
 // ---------------------------------------------------------------------------
 fn setup_bond(e: &Env) -> (Address, Address, Address) {
-    let contract_id = e.register(CredenceBond, ());
-    let client = CredenceBondClient::new(e, &contract_id);
-
-    let admin = Address::generate(e);
-    let identity = Address::generate(e);
-
-    client.initialize(&admin);
-    client.create_bond(&identity, &10_000_i128, &86400_u64);
-
-    (contract_id, admin, identity)
     let (client, admin, identity, _token_id, bond_id) = test_helpers::setup_with_token(e);
-    client.create_bond(&identity, &10_000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &10_000_000_000_i128, &86400_u64, &false, &0_u64);
     (bond_id, admin, identity)
 }
 
@@ -207,13 +214,13 @@ fn setup_bond(e: &Env) -> (Address, Address, Address) {
 fn test_withdraw_reentrancy_blocked() {
     let e = Env::default();
     e.mock_all_auths();
-    let (bond_id, _admin, identity) = setup_bond(&e);
+    let (bond_id, admin, identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
     let attacker_id = e.register(WithdrawAttacker, ());
     let attacker_client = WithdrawAttackerClient::new(&e, &attacker_id);
     attacker_client.setup(&bond_id, &identity);
-    client.set_callback(&attacker_id);
+    client.set_callback(&admin, &attacker_id);
 
     client.withdraw_bond_full(&identity);
 }
@@ -226,15 +233,15 @@ fn test_withdraw_reentrancy_blocked() {
 fn test_slash_reentrancy_blocked() {
     let e = Env::default();
     e.mock_all_auths();
-    let (bond_id, admin, _identity) = setup_bond(&e);
+    let (bond_id, admin, identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
     let attacker_id = e.register(SlashAttacker, ());
     let attacker_client = SlashAttackerClient::new(&e, &attacker_id);
-    attacker_client.setup(&bond_id, &admin);
-    client.set_callback(&attacker_id);
+    attacker_client.setup(&bond_id, &admin, &identity);
+    client.set_callback(&admin, &attacker_id);
 
-    client.slash_bond(&admin, &500_i128);
+    client.slash_bond(&admin, &identity, &500_000_000_i128);
 }
 
 // ===========================================================================
@@ -245,15 +252,15 @@ fn test_slash_reentrancy_blocked() {
 fn test_fee_collection_reentrancy_blocked() {
     let e = Env::default();
     e.mock_all_auths();
-    let (bond_id, admin, _identity) = setup_bond(&e);
+    let (bond_id, admin, identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
-    client.deposit_fees(&500_i128);
+    client.deposit_fees(&identity, &500_000_000_i128);
 
     let attacker_id = e.register(FeeAttacker, ());
     let attacker_client = FeeAttackerClient::new(&e, &attacker_id);
     attacker_client.setup(&bond_id, &admin);
-    client.set_callback(&attacker_id);
+    client.set_callback(&admin, &attacker_id);
 
     client.collect_fees(&admin);
 }
@@ -278,11 +285,11 @@ fn test_lock_not_held_initially() {
 fn test_lock_released_after_withdraw() {
     let e = Env::default();
     e.mock_all_auths();
-    let (bond_id, _admin, identity) = setup_bond(&e);
+    let (bond_id, admin, identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
     let benign_id = e.register(BenignCallback, ());
-    client.set_callback(&benign_id);
+    client.set_callback(&admin, &benign_id);
 
     client.withdraw_bond_full(&identity);
     assert!(!client.is_locked());
@@ -295,13 +302,13 @@ fn test_lock_released_after_withdraw() {
 fn test_lock_released_after_slash() {
     let e = Env::default();
     e.mock_all_auths();
-    let (bond_id, admin, _identity) = setup_bond(&e);
+    let (bond_id, admin, identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
     let benign_id = e.register(BenignCallback, ());
-    client.set_callback(&benign_id);
+    client.set_callback(&admin, &benign_id);
 
-    client.slash_bond(&admin, &100_i128);
+    client.slash_bond(&admin, &identity, &100_000_000_i128);
     assert!(!client.is_locked());
 }
 
@@ -312,16 +319,16 @@ fn test_lock_released_after_slash() {
 fn test_lock_released_after_fee_collection() {
     let e = Env::default();
     e.mock_all_auths();
-    let (bond_id, admin, _identity) = setup_bond(&e);
+    let (bond_id, admin, identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
-    client.deposit_fees(&200_i128);
+    client.deposit_fees(&identity, &200_000_000_i128);
 
     let benign_id = e.register(BenignCallback, ());
-    client.set_callback(&benign_id);
+    client.set_callback(&admin, &benign_id);
 
     let collected = client.collect_fees(&admin);
-    assert_eq!(collected, 200_i128);
+    assert_eq!(collected, 200_000_000_i128);
     assert!(!client.is_locked());
 }
 
@@ -336,9 +343,9 @@ fn test_normal_withdraw_succeeds() {
     let client = CredenceBondClient::new(&e, &bond_id);
 
     let amount = client.withdraw_bond_full(&identity);
-    assert_eq!(amount, 10_000_i128);
+    assert_eq!(amount, 10_000_000_000_i128);
 
-    let state = client.get_identity_state();
+    let state = client.get_identity_state(&identity);
     assert!(!state.active);
     assert_eq!(state.bonded_amount, 0);
 }
@@ -350,14 +357,14 @@ fn test_normal_withdraw_succeeds() {
 fn test_normal_slash_succeeds() {
     let e = Env::default();
     e.mock_all_auths();
-    let (bond_id, admin, _identity) = setup_bond(&e);
+    let (bond_id, admin, identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
-    let slashed = client.slash_bond(&admin, &3_000_i128);
-    assert_eq!(slashed, 3_000_i128);
+    let slashed = client.slash_bond(&admin, &identity, &3_000_000_000_i128);
+    assert_eq!(slashed, 3_000_000_000_i128);
 
-    let state = client.get_identity_state();
-    assert_eq!(state.slashed_amount, 3_000_i128);
+    let state = client.get_identity_state(&identity);
+    assert_eq!(state.slashed_amount, 3_000_000_000_i128);
     assert!(state.active);
 }
 
@@ -368,12 +375,12 @@ fn test_normal_slash_succeeds() {
 fn test_normal_fee_collection_succeeds() {
     let e = Env::default();
     e.mock_all_auths();
-    let (bond_id, admin, _identity) = setup_bond(&e);
+    let (bond_id, admin, identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
-    client.deposit_fees(&750_i128);
+    client.deposit_fees(&identity, &750_000_000_i128);
     let collected = client.collect_fees(&admin);
-    assert_eq!(collected, 750_i128);
+    assert_eq!(collected, 750_000_000_i128);
 }
 
 // ===========================================================================
@@ -386,16 +393,17 @@ fn test_sequential_operations_succeed() {
     let (bond_id, admin, identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
-    client.slash_bond(&admin, &1_000_i128);
+    client.slash_bond(&admin, &identity, &1_000_000_000_i128);
     assert!(!client.is_locked());
 
-    client.deposit_fees(&100_i128);
+    client.deposit_fees(&identity, &100_000_000_i128);
     let fees = client.collect_fees(&admin);
-    assert_eq!(fees, 100_i128);
+    // Includes the slash proceeds, which accrue to the fee pool without a beneficiary.
+    assert_eq!(fees, 1_100_000_000_i128);
     assert!(!client.is_locked());
 
     let withdrawn = client.withdraw_bond_full(&identity);
-    assert_eq!(withdrawn, 9_000_i128);
+    assert_eq!(withdrawn, 9_000_000_000_i128);
     assert!(!client.is_locked());
 }
 
@@ -407,17 +415,17 @@ fn test_sequential_operations_succeed() {
 fn test_slash_exceeds_bond_rejected() {
     let e = Env::default();
     e.mock_all_auths();
-    let (bond_id, admin, _identity) = setup_bond(&e);
+    let (bond_id, admin, identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
-    client.slash_bond(&admin, &20_000_i128);
+    client.slash_bond(&admin, &identity, &20_000_000_000_i128);
 }
 
 // ===========================================================================
 // 13. Withdraw by non-owner is rejected
 // ===========================================================================
 #[test]
-#[should_panic(expected = "not bond owner")]
+#[should_panic(expected = "no bond")]
 fn test_withdraw_non_owner_rejected() {
     let e = Env::default();
     e.mock_all_auths();
@@ -456,8 +464,77 @@ fn test_cross_function_reentrancy_blocked() {
 
     let attacker_id = e.register(CrossAttacker, ());
     let attacker_client = CrossAttackerClient::new(&e, &attacker_id);
-    attacker_client.setup(&bond_id, &admin);
-    client.set_callback(&attacker_id);
+    attacker_client.setup(&bond_id, &admin, &identity);
+    client.set_callback(&admin, &attacker_id);
 
     client.withdraw_bond_full(&identity);
 }
+
+// ===========================================================================
+// Bond withdrawals run under the guard
+// ===========================================================================
+
+/// Test hook: mark the lock as held in the current ledger, as a guarded call in flight would.
+fn hold_lock(e: &Env, bond_id: &Address) {
+    let now = e.ledger().timestamp();
+    let seq = e.ledger().sequence();
+    e.as_contract(bond_id, || {
+        e.storage().instance().set(&Symbol::new(e, "lock"), &true);
+        e.storage()
+            .instance()
+            .set(&Symbol::new(e, "lock_since"), &now);
+        e.storage()
+            .instance()
+            .set(&Symbol::new(e, "lock_seq"), &seq);
+    });
+}
+
+#[test]
+fn test_withdraw_bond_refused_while_locked() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (bond_id, _admin, identity) = setup_bond(&e);
+    let client = CredenceBondClient::new(&e, &bond_id);
+    e.ledger().with_mut(|li| li.timestamp = 86_401);
+
+    hold_lock(&e, &bond_id);
+    assert!(client.try_withdraw_bond(&identity, &1_000_000_000).is_err());
+    assert_eq!(
+        client.get_identity_state(&identity).bonded_amount,
+        10_000_000_000
+    );
+}
+
+#[test]
+fn test_withdraw_early_refused_while_locked() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (bond_id, _admin, identity) = setup_bond(&e);
+    let client = CredenceBondClient::new(&e, &bond_id);
+
+    hold_lock(&e, &bond_id);
+    assert!(client
+        .try_withdraw_early(&identity, &1_000_000_000)
+        .is_err());
+    assert_eq!(
+        client.get_identity_state(&identity).bonded_amount,
+        10_000_000_000
+    );
+}
+
+#[test]
+fn test_lock_released_after_withdraw_bond_and_withdraw_early() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (bond_id, admin, identity) = setup_bond(&e);
+    let client = CredenceBondClient::new(&e, &bond_id);
+    client.set_early_exit_config(&admin, &Address::generate(&e), &500);
+
+    client.withdraw_early(&identity, &1_000_000_000);
+    assert!(!client.is_locked());
+
+    e.ledger().with_mut(|li| li.timestamp = 86_401);
+    let bond = client.withdraw_bond(&identity, &1_000_000_000);
+    assert!(!client.is_locked());
+    assert_eq!(bond.bonded_amount, 8_000_000_000);
+}
@@ -0,0 +1,146 @@
+//! Tests for the `get_current_period` and `get_renewal_schedule` views: they must match what
+//! `renew_if_rolling` actually does across a renewal, a queued duration change and the renewal
+//! cap.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{Address, Env, Vec};
+
+const AMOUNT: i128 = 10_000_000;
+const START: u64 = 1_000;
+const DURATION: u64 = 86_400;
+
+fn setup(e: &Env, is_rolling: bool) -> (CredenceBondClient<'_>, Address, Address) {
+    set_time(e, START);
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &is_rolling, &10_u64);
+    (client, admin, identity)
+}
+
+fn set_time(e: &Env, t: u64) {
+    e.ledger().with_mut(|li| li.timestamp = t);
+}
+
+#[test]
+fn test_schedule_across_renewal_boundary() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e, true);
+    assert_eq!(
+        client.get_current_period(&identity),
+        (START, START + DURATION, 0)
+    );
+    assert_eq!(
+        client.get_renewal_schedule(&identity, &3),
+        Vec::from_array(
+            &e,
+            [START + DURATION, START + 2 * DURATION, START + 3 * DURATION]
+        )
+    );
+
+    // Past the boundary the views report the due renewal as applied, as renewing does.
+    let now = START + DURATION + 50;
+    set_time(&e, now);
+    let projected = client.get_current_period(&identity);
+    assert_eq!(projected, (now, now + DURATION, 1));
+    let schedule = client.get_renewal_schedule(&identity, &2);
+
+    let bond = client.renew_if_rolling(&identity);
+    assert_eq!(
+        (bond.bond_start, bond.bond_start + bond.bond_duration, 1),
+        projected
+    );
+    assert_eq!(client.get_current_period(&identity), projected);
+    assert_eq!(client.get_renewal_schedule(&identity, &2), schedule);
+    assert_eq!(
+        schedule,
+        Vec::from_array(&e, [now + DURATION, now + 2 * DURATION])
+    );
+}
+
+#[test]
+fn test_schedule_uses_queued_duration() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e, true);
+    let next = 2 * DURATION;
+    client.set_next_period_duration(&identity, &next);
+    assert_eq!(client.get_next_period_duration(&identity), Some(next));
+
+    // The current period keeps its duration; every later one takes the queued one.
+    assert_eq!(
+        client.get_current_period(&identity),
+        (START, START + DURATION, 0)
+    );
+    let end = START + DURATION;
+    assert_eq!(
+        client.get_renewal_schedule(&identity, &3),
+        Vec::from_array(&e, [end, end + next, end + 2 * next])
+    );
+
+    set_time(&e, end);
+    let bond = client.renew_if_rolling(&identity);
+    assert_eq!(bond.bond_duration, next);
+    assert_eq!(client.get_next_period_duration(&identity), None);
+    assert_eq!(client.get_current_period(&identity), (end, end + next, 1));
+    assert_eq!(client.get_bond_end(&identity), end + next);
+}
+
+#[test]
+fn test_schedule_stops_at_renewal_cap() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e, true);
+    client.set_max_renewals(&admin, &2);
+    set_time(&e, START + DURATION);
+    client.renew_if_rolling(&identity);
+
+    // One renewal left: the current period and the last one, at whose end the bond lapses.
+    let end = START + 2 * DURATION;
+    assert_eq!(
+        client.get_current_period(&identity),
+        (START + DURATION, end, 1)
+    );
+    assert_eq!(
+        client.get_renewal_schedule(&identity, &5),
+        Vec::from_array(&e, [end, end + DURATION])
+    );
+
+    set_time(&e, end);
+    client.renew_if_rolling(&identity);
+    set_time(&e, end + DURATION);
+    assert_eq!(
+        client.get_renewal_schedule(&identity, &5),
+        Vec::from_array(&e, [end + DURATION])
+    );
+    assert!(!client.renew_if_rolling(&identity).is_rolling);
+    assert_eq!(
+        client.get_current_period(&identity),
+        (end, end + DURATION, 2)
+    );
+}
+
+#[test]
+fn test_fixed_bond_has_single_period() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e, false);
+    assert_eq!(
+        client.get_renewal_schedule(&identity, &3),
+        Vec::from_array(&e, [START + DURATION])
+    );
+    assert_eq!(client.get_renewal_schedule(&identity, &0).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "not a rolling bond")]
+fn test_queued_duration_needs_rolling_bond() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e, false);
+    client.set_next_period_duration(&identity, &DURATION);
+}
+
+#[test]
+#[should_panic(expected = "schedule too long")]
+fn test_schedule_length_capped() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e, true);
+    let _ = client.get_renewal_schedule(&identity, &53);
+}
@@ -0,0 +1,161 @@
+//! Tests that token-pulling entrypoints report a short allowance or balance with a readable
+//! message before attempting `transfer_from`.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, Vec};
+
+const AMOUNT: i128 = 2_000_000;
+const SHORT: i128 = 1_000_000;
+const PLENTY: i128 = 10_000_000;
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    admin: Address,
+    identity: Address,
+    token: Address,
+    contract_id: Address,
+}
+
+fn setup(e: &Env) -> Setup<'_> {
+    let (client, admin, identity, token, contract_id) = test_helpers::setup_with_token(e);
+    Setup {
+        client,
+        admin,
+        identity,
+        token,
+        contract_id,
+    }
+}
+
+/// A fresh address holding `balance` tokens that approved the bond contract for `allowance`.
+fn payer(e: &Env, s: &Setup, balance: i128, allowance: i128) -> Address {
+    let who = Address::generate(e);
+    StellarAssetClient::new(e, &s.token).mint(&who, &balance);
+    let expiration = e.ledger().sequence().saturating_add(10_000);
+    TokenClient::new(e, &s.token).approve(&who, &s.contract_id, &allowance, &expiration);
+    who
+}
+
+#[test]
+#[should_panic(expected = "insufficient allowance: have 1000000 need 2000000")]
+fn test_create_bond_short_allowance() {
+    let e = Env::default();
+    let s = setup(&e);
+    let from = payer(&e, &s, PLENTY, SHORT);
+    s.client
+        .create_bond(&from, &AMOUNT, &86_400_u64, &false, &0_u64);
+}
+
+#[test]
+#[should_panic(expected = "insufficient balance: have 1000000 need 2000000")]
+fn test_create_bond_short_balance() {
+    let e = Env::default();
+    let s = setup(&e);
+    let from = payer(&e, &s, SHORT, PLENTY);
+    s.client
+        .create_bond(&from, &AMOUNT, &86_400_u64, &false, &0_u64);
+}
+
+#[test]
+#[should_panic(expected = "insufficient allowance: have 1000000 need 2000000")]
+fn test_top_up_short_allowance() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client
+        .create_bond(&s.identity, &PLENTY, &86_400_u64, &false, &0_u64);
+    let sponsor = payer(&e, &s, PLENTY, SHORT);
+    s.client.authorize_topup_sponsor(&s.identity, &sponsor);
+    s.client.top_up(&sponsor, &s.identity, &AMOUNT);
+}
+
+#[test]
+#[should_panic(expected = "insufficient balance: have 1000000 need 2000000")]
+fn test_top_up_short_balance() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client
+        .create_bond(&s.identity, &PLENTY, &86_400_u64, &false, &0_u64);
+    let sponsor = payer(&e, &s, SHORT, PLENTY);
+    s.client.authorize_topup_sponsor(&s.identity, &sponsor);
+    s.client.top_up(&sponsor, &s.identity, &AMOUNT);
+}
+
+#[test]
+#[should_panic(expected = "insufficient allowance: have 0 need 2000000")]
+fn test_increase_bond_short_allowance() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.set_bond_token(&s.admin, &s.token);
+    let holder = payer(&e, &s, PLENTY, SHORT);
+    s.client
+        .create_bond(&holder, &SHORT, &86_400_u64, &false, &0_u64);
+    s.client.increase_bond(&holder, &AMOUNT);
+}
+
+#[test]
+#[should_panic(expected = "insufficient balance: have 0 need 2000000")]
+fn test_increase_bond_short_balance() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.set_bond_token(&s.admin, &s.token);
+    let holder = payer(&e, &s, SHORT, PLENTY);
+    s.client
+        .create_bond(&holder, &SHORT, &86_400_u64, &false, &0_u64);
+    s.client.increase_bond(&holder, &AMOUNT);
+}
+
+#[test]
+#[should_panic(expected = "insufficient allowance: have 1000000 need 2000000")]
+fn test_register_verifier_short_allowance() {
+    let e = Env::default();
+    let s = setup(&e);
+    let verifier = payer(&e, &s, PLENTY, SHORT);
+    s.client.register_verifier(&verifier, &AMOUNT);
+}
+
+#[test]
+#[should_panic(expected = "insufficient balance: have 1000000 need 2000000")]
+fn test_register_verifier_short_balance() {
+    let e = Env::default();
+    let s = setup(&e);
+    let verifier = payer(&e, &s, SHORT, PLENTY);
+    s.client.register_verifier(&verifier, &AMOUNT);
+}
+
+/// Opens a slash request against `s.identity` that `governor` can dispute for `AMOUNT`.
+fn open_request(e: &Env, s: &Setup, governor: &Address) -> u64 {
+    s.client
+        .create_bond(&s.identity, &PLENTY, &86_400_u64, &false, &0_u64);
+    s.client.initialize_governance(
+        &s.admin,
+        &Vec::from_array(e, [governor.clone()]),
+        &5_100_u32,
+        &1_u32,
+    );
+    s.client
+        .set_dispute_bond_config(&s.admin, &AMOUNT, &500_u32);
+    s.client.propose_slash(&s.admin, &s.identity, &1_000)
+}
+
+#[test]
+#[should_panic(expected = "insufficient allowance: have 1000000 need 2000000")]
+fn test_dispute_short_allowance() {
+    let e = Env::default();
+    let s = setup(&e);
+    let governor = payer(&e, &s, PLENTY, SHORT);
+    let id = open_request(&e, &s, &governor);
+    s.client.dispute_slash_request(&governor, &id);
+}
+
+#[test]
+#[should_panic(expected = "insufficient balance: have 1000000 need 2000000")]
+fn test_dispute_short_balance() {
+    let e = Env::default();
+    let s = setup(&e);
+    let governor = payer(&e, &s, SHORT, PLENTY);
+    let id = open_request(&e, &s, &governor);
+    s.client.dispute_slash_request(&governor, &id);
+}
@@ -0,0 +1,119 @@
+//! Tests for rescuing tokens sent to the contract by mistake.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, Vec};
+
+const STRANDED: i128 = 5_000;
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    admin: Address,
+    bond_token: Address,
+    foreign: Address,
+    contract_id: Address,
+}
+
+/// Bond contract holding `STRANDED` units of a foreign token.
+fn setup(e: &Env) -> Setup<'_> {
+    let (client, admin, _identity, bond_token, contract_id) = test_helpers::setup_with_token(e);
+    let issuer = Address::generate(e);
+    let foreign = e.register_stellar_asset_contract_v2(issuer).address();
+    StellarAssetClient::new(e, &foreign).mint(&contract_id, &STRANDED);
+    Setup {
+        client,
+        admin,
+        bond_token,
+        foreign,
+        contract_id,
+    }
+}
+
+/// Registers two pause signers with a threshold of two.
+fn pause_multisig(e: &Env, s: &Setup) -> Vec<Address> {
+    let signers = Vec::from_array(e, [Address::generate(e), Address::generate(e)]);
+    for signer in signers.iter() {
+        s.client.set_pause_signer(&s.admin, &signer, &true);
+    }
+    s.client.set_pause_threshold(&s.admin, &2);
+    signers
+}
+
+#[test]
+fn test_rescue_foreign_token() {
+    let e = Env::default();
+    let s = setup(&e);
+    let to = Address::generate(&e);
+
+    s.client
+        .rescue_tokens(&s.admin, &s.foreign, &to, &STRANDED, &Vec::new(&e));
+    let foreign = TokenClient::new(&e, &s.foreign);
+    assert_eq!(foreign.balance(&to), STRANDED);
+    assert_eq!(foreign.balance(&s.contract_id), 0);
+}
+
+#[test]
+#[should_panic(expected = "cannot rescue bond token")]
+fn test_bond_token_cannot_be_rescued() {
+    let e = Env::default();
+    let s = setup(&e);
+    let to = Address::generate(&e);
+    s.client
+        .rescue_tokens(&s.admin, &s.bond_token, &to, &1, &Vec::new(&e));
+}
+
+#[test]
+#[should_panic(expected = "cannot rescue bond token")]
+fn test_increase_bond_token_cannot_be_rescued() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.set_bond_token(&s.admin, &s.foreign);
+    let to = Address::generate(&e);
+    s.client
+        .rescue_tokens(&s.admin, &s.foreign, &to, &1, &Vec::new(&e));
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_rescue_requires_admin() {
+    let e = Env::default();
+    let s = setup(&e);
+    let other = Address::generate(&e);
+    s.client
+        .rescue_tokens(&other, &s.foreign, &other, &1, &Vec::new(&e));
+}
+
+#[test]
+fn test_large_rescue_requires_multisig() {
+    let e = Env::default();
+    let s = setup(&e);
+    let signers = pause_multisig(&e, &s);
+    s.client.set_rescue_limit(&s.admin, &1_000);
+    let to = Address::generate(&e);
+
+    // At the limit the admin acts alone.
+    s.client
+        .rescue_tokens(&s.admin, &s.foreign, &to, &1_000, &Vec::new(&e));
+
+    let one = Vec::from_array(&e, [signers.get(0).unwrap()]);
+    assert!(s
+        .client
+        .try_rescue_tokens(&s.admin, &s.foreign, &to, &1_001, &one)
+        .is_err());
+    let repeated = Vec::from_array(&e, [signers.get(0).unwrap(), signers.get(0).unwrap()]);
+    assert!(s
+        .client
+        .try_rescue_tokens(&s.admin, &s.foreign, &to, &1_001, &repeated)
+        .is_err());
+    let outsiders = Vec::from_array(&e, [Address::generate(&e), Address::generate(&e)]);
+    assert!(s
+        .client
+        .try_rescue_tokens(&s.admin, &s.foreign, &to, &1_001, &outsiders)
+        .is_err());
+
+    s.client
+        .rescue_tokens(&s.admin, &s.foreign, &to, &4_000, &signers);
+    assert_eq!(TokenClient::new(&e, &s.foreign).balance(&to), STRANDED);
+}
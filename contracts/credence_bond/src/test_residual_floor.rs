@@ -0,0 +1,134 @@
+//! Tests for the minimum residual bond: partial withdrawals by attested subjects, full exits,
+//! revoked-only attestation lists and the early-withdrawal path.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, String, Symbol};
+
+const AMOUNT: i128 = 10_000_000;
+const DURATION: u64 = 86_400;
+const FLOOR: i128 = 4_000_000;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address) {
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    client.set_min_residual_bond(&admin, &FLOOR);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    (client, admin, identity, attester)
+}
+
+fn attest(e: &Env, client: &CredenceBondClient, attester: &Address, subject: &Address) -> u64 {
+    attest_with(e, client, attester, subject, "kyc")
+}
+
+fn attest_with(
+    e: &Env,
+    client: &CredenceBondClient,
+    attester: &Address,
+    subject: &Address,
+    data: &str,
+) -> u64 {
+    client
+        .add_attestation(attester, subject, &String::from_str(e, data))
+        .id
+}
+
+fn mature(e: &Env) {
+    e.ledger().with_mut(|li| li.timestamp = DURATION);
+}
+
+#[test]
+fn test_attested_subject_must_keep_floor() {
+    let e = Env::default();
+    let (client, _admin, identity, attester) = setup(&e);
+    attest(&e, &client, &attester, &identity);
+    mature(&e);
+
+    let too_much = AMOUNT - FLOOR + 1;
+    assert!(client.try_withdraw(&identity, &too_much).is_err());
+    assert!(client.try_withdraw_bond(&identity, &too_much).is_err());
+    assert_eq!(client.get_identity_state(&identity).bonded_amount, AMOUNT);
+
+    let bond = client.withdraw_bond(&identity, &(AMOUNT - FLOOR));
+    assert_eq!(bond.bonded_amount, FLOOR);
+}
+
+#[test]
+fn test_full_exit_allowed() {
+    let e = Env::default();
+    let (client, _admin, identity, attester) = setup(&e);
+    attest(&e, &client, &attester, &identity);
+    mature(&e);
+    let bond = client.withdraw(&identity, &AMOUNT);
+    assert_eq!(bond.bonded_amount, 0);
+}
+
+#[test]
+#[should_panic(expected = "withdrawal below residual bond floor")]
+fn test_early_withdrawal_below_floor_rejected() {
+    let e = Env::default();
+    let (client, _admin, identity, attester) = setup(&e);
+    attest(&e, &client, &attester, &identity);
+    client.withdraw_early(&identity, &(AMOUNT - 1));
+}
+
+#[test]
+fn test_slashed_amount_not_counted_as_available() {
+    let e = Env::default();
+    let (client, admin, identity, attester) = setup(&e);
+    attest(&e, &client, &attester, &identity);
+    client.slash(&admin, &identity, &1_000_000);
+    mature(&e);
+
+    // 9_000_000 is available, so withdrawing 5_000_001 leaves 3_999_999.
+    assert!(client.try_withdraw_bond(&identity, &5_000_001).is_err());
+    client.withdraw_bond(&identity, &5_000_000);
+}
+
+#[test]
+fn test_unattested_subject_unrestricted() {
+    let e = Env::default();
+    let (client, _admin, identity, _attester) = setup(&e);
+    mature(&e);
+    let bond = client.withdraw_bond(&identity, &(AMOUNT - 1));
+    assert_eq!(bond.bonded_amount, 1);
+}
+
+#[test]
+fn test_revoked_only_attestations_unrestricted() {
+    let e = Env::default();
+    let (client, admin, identity, attester) = setup(&e);
+    let fraud = Symbol::new(&e, "fraud");
+    let first = attest(&e, &client, &attester, &identity);
+    let second = attest_with(&e, &client, &attester, &identity, "aml");
+    client.revoke_attestation_by_admin(&admin, &first, &fraud);
+    mature(&e);
+
+    // One effective attestation left still binds the subject to the floor.
+    assert!(client.try_withdraw_bond(&identity, &(AMOUNT - 1)).is_err());
+
+    client.revoke_attestation_by_admin(&admin, &second, &fraud);
+    let bond = client.withdraw_bond(&identity, &(AMOUNT - 1));
+    assert_eq!(bond.bonded_amount, 1);
+}
+
+#[test]
+fn test_zero_floor_disables_check() {
+    let e = Env::default();
+    let (client, admin, identity, attester) = setup(&e);
+    attest(&e, &client, &attester, &identity);
+    client.set_min_residual_bond(&admin, &0);
+    assert_eq!(client.get_min_residual_bond(), 0);
+    mature(&e);
+    client.withdraw_bond(&identity, &(AMOUNT - 1));
+}
+
+#[test]
+#[should_panic(expected = "min residual bond must be non-negative")]
+fn test_negative_floor_rejected() {
+    let e = Env::default();
+    let (client, admin, ..) = setup(&e);
+    client.set_min_residual_bond(&admin, &-1);
+}
@@ -0,0 +1,166 @@
+//! Resource regression guard for representative operations.
+//!
+//! Each operation runs against the same fixed fixture state and is measured on its own: CPU
+//! instructions plus ledger entries read and written by the top-level invocation. A test fails
+//! when an operation exceeds its checked-in budget by more than `TOLERANCE_PCT`, so storage
+//! layout changes that grow the footprint show up in review. The budgets are deliberately
+//! generous ceilings; when an increase is intended, raise the constant in the same change.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, String, Vec};
+
+/// Headroom over a budget before the guard fails.
+const TOLERANCE_PCT: u64 = 10;
+
+const AMOUNT: i128 = 10_000_000;
+const DURATION: u64 = 86_400;
+const START: u64 = 1_000;
+
+/// Ceiling for one top-level invocation.
+struct Budget {
+    instructions: u64,
+    read_entries: u32,
+    write_entries: u32,
+}
+
+const CREATE_BOND: Budget = Budget {
+    instructions: 20_000_000,
+    read_entries: 20,
+    write_entries: 10,
+};
+
+const ADD_ATTESTATION: Budget = Budget {
+    instructions: 15_000_000,
+    read_entries: 15,
+    write_entries: 10,
+};
+
+const APPROVE_SLASH: Budget = Budget {
+    instructions: 10_000_000,
+    read_entries: 10,
+    write_entries: 5,
+};
+
+const EXECUTE_SLASH: Budget = Budget {
+    instructions: 20_000_000,
+    read_entries: 20,
+    write_entries: 10,
+};
+
+const IDENTITY_VIEW: Budget = Budget {
+    instructions: 5_000_000,
+    read_entries: 5,
+    write_entries: 0,
+};
+
+/// Resources used by one invocation.
+struct Usage {
+    instructions: u64,
+    read_entries: u32,
+    write_entries: u32,
+}
+
+/// Runs `op` as a single top-level invocation on a freshly reset budget and returns what it
+/// used.
+fn measure<T>(e: &Env, op: impl FnOnce() -> T) -> Usage {
+    e.cost_estimate().budget().reset_default();
+    op();
+    let resources = e.cost_estimate().resources();
+    Usage {
+        instructions: u64::try_from(resources.instructions).unwrap_or(0),
+        read_entries: resources.read_entries,
+        write_entries: resources.write_entries,
+    }
+}
+
+fn with_tolerance(limit: u64) -> u64 {
+    limit + limit * TOLERANCE_PCT / 100
+}
+
+fn assert_within(name: &str, usage: &Usage, budget: &Budget) {
+    let checks = [
+        ("instructions", usage.instructions, budget.instructions),
+        (
+            "read entries",
+            u64::from(usage.read_entries),
+            u64::from(budget.read_entries),
+        ),
+        (
+            "write entries",
+            u64::from(usage.write_entries),
+            u64::from(budget.write_entries),
+        ),
+    ];
+    for (resource, used, limit) in checks {
+        assert!(
+            used <= with_tolerance(limit),
+            "{name}: {resource} {used} over budget {limit} (+{TOLERANCE_PCT}%)"
+        );
+    }
+}
+
+/// Fixed starting state: ledger time and sequence pinned, token and admin set up, and a
+/// three-member governance roster needing two approvals.
+fn fixture(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Vec<Address>) {
+    e.ledger().with_mut(|li| {
+        li.timestamp = START;
+        li.sequence_number = 100;
+    });
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(e);
+    let mut governors = Vec::new(e);
+    for _ in 0..3 {
+        governors.push_back(Address::generate(e));
+    }
+    client.initialize_governance(&admin, &governors, &5_000_u32, &2_u32);
+    (client, admin, identity, governors)
+}
+
+#[test]
+fn test_create_bond_within_budget() {
+    let e = Env::default();
+    let (client, _admin, identity, _) = fixture(&e);
+    let usage = measure(&e, || {
+        client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64)
+    });
+    assert_within("create_bond", &usage, &CREATE_BOND);
+}
+
+#[test]
+fn test_add_attestation_within_budget() {
+    let e = Env::default();
+    let (client, _admin, identity, _) = fixture(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+    let data = String::from_str(&e, "kyc");
+    let usage = measure(&e, || client.add_attestation(&attester, &identity, &data));
+    assert_within("add_attestation", &usage, &ADD_ATTESTATION);
+}
+
+#[test]
+fn test_slash_approval_and_execution_within_budget() {
+    let e = Env::default();
+    let (client, admin, identity, governors) = fixture(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    let id = client.propose_slash(&admin, &identity, &(AMOUNT / 10));
+
+    let first = governors.get(0).unwrap();
+    let usage = measure(&e, || client.governance_vote(&first, &id, &true));
+    assert_within("governance_vote", &usage, &APPROVE_SLASH);
+    client.governance_vote(&governors.get(1).unwrap(), &id, &true);
+
+    let usage = measure(&e, || client.execute_slash_with_governance(&admin, &id));
+    assert_within("execute_slash_with_governance", &usage, &EXECUTE_SLASH);
+}
+
+#[test]
+fn test_identity_view_within_budget() {
+    let e = Env::default();
+    let (client, _admin, identity, _) = fixture(&e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    let usage = measure(&e, || client.get_identity_state(&identity));
+    assert_within("get_identity_state", &usage, &IDENTITY_VIEW);
+    assert_eq!(usage.write_entries, 0);
+}
@@ -0,0 +1,67 @@
+//! Tests for the combined effective-restrictions view.
+
+use crate::restrictions::EffectiveRestrictions;
+use crate::test_helpers;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+#[test]
+fn test_each_restriction_toggles_independently() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    let none = EffectiveRestrictions {
+        paused: false,
+        emergency_mode: false,
+        withdrawal_cooldown_secs: 0,
+    };
+    assert_eq!(client.get_effective_restrictions(), none);
+
+    client.pause(&admin);
+    assert_eq!(
+        client.get_effective_restrictions(),
+        EffectiveRestrictions {
+            paused: true,
+            ..none.clone()
+        }
+    );
+    client.unpause(&admin);
+
+    let governance = Address::generate(&e);
+    client.set_emergency_config(&admin, &governance, &Address::generate(&e), &100, &true);
+    assert_eq!(
+        client.get_effective_restrictions(),
+        EffectiveRestrictions {
+            emergency_mode: true,
+            ..none.clone()
+        }
+    );
+    client.set_emergency_mode(&admin, &governance, &false);
+
+    client.set_cooldown_period(&admin, &3_600);
+    assert_eq!(
+        client.get_effective_restrictions(),
+        EffectiveRestrictions {
+            withdrawal_cooldown_secs: 3_600,
+            ..none
+        }
+    );
+}
+
+#[test]
+fn test_combined_view() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    let governance = Address::generate(&e);
+    client.set_emergency_config(&admin, &governance, &Address::generate(&e), &100, &true);
+    client.set_cooldown_period(&admin, &60);
+    client.pause(&admin);
+
+    assert_eq!(
+        client.get_effective_restrictions(),
+        EffectiveRestrictions {
+            paused: true,
+            emergency_mode: true,
+            withdrawal_cooldown_secs: 60,
+        }
+    );
+}
@@ -0,0 +1,100 @@
+//! Tests for revocation classification: per-initiator/reason stats and penalty multipliers.
+
+use crate::verifier::RevocationInitiator;
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String, Symbol};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let client = CredenceBondClient::new(e, &e.register(CredenceBond, ()));
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    (client, admin, attester)
+}
+
+fn attest(e: &Env, client: &CredenceBondClient, attester: &Address, data: &str) -> (u64, i128) {
+    let att = client.add_attestation(attester, &Address::generate(e), &String::from_str(e, data));
+    (att.id, i128::from(att.weight))
+}
+
+fn reputation(client: &CredenceBondClient, attester: &Address) -> i128 {
+    client.get_verifier_info(attester).unwrap().reputation
+}
+
+#[test]
+fn test_initiators_get_different_penalties() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.set_revocation_multiplier(&admin, &RevocationInitiator::Attester, &None, &5_000_u32);
+    client.set_revocation_multiplier(&admin, &RevocationInitiator::Admin, &None, &30_000_u32);
+
+    let (own, weight) = attest(&e, &client, &attester, "a");
+    let (fraud, _) = attest(&e, &client, &attester, "b");
+
+    let before = reputation(&client, &attester);
+    client.revoke_attestation(&attester, &own);
+    let after_self = reputation(&client, &attester);
+    assert_eq!(before - after_self, weight / 2);
+
+    client.revoke_attestation_by_admin(&admin, &fraud, &Symbol::new(&e, "fraud"));
+    assert_eq!(after_self - reputation(&client, &attester), weight * 3);
+}
+
+#[test]
+fn test_reason_specific_multiplier_overrides_initiator_default() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    let fraud = Symbol::new(&e, "fraud");
+    client.set_revocation_multiplier(&admin, &RevocationInitiator::Admin, &None, &20_000_u32);
+    client.set_revocation_multiplier(
+        &admin,
+        &RevocationInitiator::Admin,
+        &Some(fraud.clone()),
+        &50_000_u32,
+    );
+
+    let (first, weight) = attest(&e, &client, &attester, "a");
+    let (second, _) = attest(&e, &client, &attester, "b");
+
+    let before = reputation(&client, &attester);
+    client.revoke_attestation_by_admin(&admin, &first, &Symbol::new(&e, "stale"));
+    let mid = reputation(&client, &attester);
+    assert_eq!(before - mid, weight * 2);
+    client.revoke_attestation_by_admin(&admin, &second, &fraud);
+    assert_eq!(mid - reputation(&client, &attester), weight * 5);
+}
+
+#[test]
+fn test_stats_accumulate_in_describe_view() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    let fraud = Symbol::new(&e, "fraud");
+
+    for data in ["a", "b"] {
+        let (id, _) = attest(&e, &client, &attester, data);
+        client.revoke_attestation(&attester, &id);
+    }
+    for data in ["c", "d", "e"] {
+        let (id, _) = attest(&e, &client, &attester, data);
+        client.revoke_attestation_by_admin(&admin, &id, &fraud);
+    }
+
+    let description = client.describe_verifier(&attester).unwrap();
+    let stats = description.revocations;
+    assert_eq!(stats.by_attester, 2);
+    assert_eq!(stats.by_admin, 3);
+    assert_eq!(stats.by_reason.get(Symbol::new(&e, "self")), Some(2));
+    assert_eq!(stats.by_reason.get(fraud), Some(3));
+    assert_eq!(description.info.attestations_revoked, 5);
+}
+
+#[test]
+#[should_panic(expected = "revocation multiplier too large")]
+fn test_multiplier_is_capped() {
+    let e = Env::default();
+    let (client, admin, _) = setup(&e);
+    client.set_revocation_multiplier(&admin, &RevocationInitiator::Admin, &None, &100_001_u32);
+}
@@ -0,0 +1,142 @@
+//! Tests for cross-contract auth via the admin contract's role-scoped function registry.
+//!
+//! The admin contract is built against a different soroban-sdk version, so a minimal stand-in
+//! with the same `can_call` semantics (entrypoint mapped to a minimum role, caller role at least
+//! that role) is used here.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Symbol};
+
+const OPERATOR: u32 = 1;
+const ADMIN: u32 = 2;
+
+mod mock_admin {
+    use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol};
+
+    #[contracttype]
+    enum Key {
+        Role(Address),
+        FunctionRole(Address, Symbol),
+    }
+
+    #[contract]
+    pub struct MockAdminContract;
+
+    #[contractimpl]
+    impl MockAdminContract {
+        pub fn set_role(e: Env, addr: Address, role: u32) {
+            e.storage().instance().set(&Key::Role(addr), &role);
+        }
+
+        pub fn set_function_role(e: Env, contract: Address, fn_name: Symbol, min_role: u32) {
+            e.storage()
+                .instance()
+                .set(&Key::FunctionRole(contract, fn_name), &min_role);
+        }
+
+        pub fn can_call(e: Env, caller: Address, contract: Address, fn_name: Symbol) -> bool {
+            let min_role: Option<u32> = e
+                .storage()
+                .instance()
+                .get(&Key::FunctionRole(contract, fn_name));
+            let role: Option<u32> = e.storage().instance().get(&Key::Role(caller));
+            match (min_role, role) {
+                (Some(min), Some(r)) => r >= min,
+                _ => false,
+            }
+        }
+    }
+}
+
+use mock_admin::{MockAdminContract, MockAdminContractClient};
+
+fn setup(
+    e: &Env,
+) -> (
+    CredenceBondClient<'_>,
+    MockAdminContractClient<'_>,
+    Address,
+    Address,
+    Address,
+) {
+    let (client, admin, _identity, _token, contract_id) = test_helpers::setup_with_token(e);
+    let registry_id = e.register(MockAdminContract, ());
+    let registry = MockAdminContractClient::new(e, &registry_id);
+    let operator = Address::generate(e);
+    registry.set_role(&operator, &OPERATOR);
+    client.set_role_registry(&admin, &registry_id);
+    (client, registry, admin, operator, contract_id)
+}
+
+#[test]
+fn test_registry_config_round_trip() {
+    let e = Env::default();
+    let (client, registry, admin, _operator, _bond) = setup(&e);
+    assert_eq!(client.get_role_registry(), Some(registry.address.clone()));
+    client.clear_role_registry(&admin);
+    assert_eq!(client.get_role_registry(), None);
+}
+
+#[test]
+fn test_operator_allowed_when_mapped_to_operator() {
+    let e = Env::default();
+    let (client, registry, _admin, operator, bond) = setup(&e);
+    registry.set_function_role(&bond, &Symbol::new(&e, "set_bond_gate"), &OPERATOR);
+
+    client.set_bond_gate(&operator, &Symbol::new(&e, "kyc"), &1);
+    assert!(client.get_bond_gate().is_some());
+}
+
+#[test]
+fn test_operator_blocked_after_role_raised_to_admin() {
+    let e = Env::default();
+    let (client, registry, _admin, operator, bond) = setup(&e);
+    let fn_name = Symbol::new(&e, "set_bond_gate");
+    registry.set_function_role(&bond, &fn_name, &OPERATOR);
+    client.set_bond_gate(&operator, &Symbol::new(&e, "kyc"), &1);
+
+    registry.set_function_role(&bond, &fn_name, &ADMIN);
+    let result = client.try_set_bond_gate(&operator, &Symbol::new(&e, "aml"), &1);
+    assert!(result.is_err());
+    assert_eq!(
+        client.get_bond_gate().unwrap().category,
+        Symbol::new(&e, "kyc")
+    );
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_unmapped_entrypoint_rejects_operator() {
+    let e = Env::default();
+    let (client, _registry, _admin, operator, _bond) = setup(&e);
+    client.set_bond_gate(&operator, &Symbol::new(&e, "kyc"), &1);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_mapping_is_per_entrypoint() {
+    let e = Env::default();
+    let (client, registry, _admin, operator, bond) = setup(&e);
+    registry.set_function_role(&bond, &Symbol::new(&e, "set_bond_gate"), &OPERATOR);
+    client.clear_bond_gate(&operator);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_registry_ignored_when_disabled() {
+    let e = Env::default();
+    let (client, registry, admin, operator, bond) = setup(&e);
+    registry.set_function_role(&bond, &Symbol::new(&e, "set_bond_gate"), &OPERATOR);
+    client.clear_role_registry(&admin);
+    client.set_bond_gate(&operator, &Symbol::new(&e, "kyc"), &1);
+}
+
+#[test]
+fn test_bond_admin_always_allowed() {
+    let e = Env::default();
+    let (client, _registry, admin, _operator, _bond) = setup(&e);
+    client.set_bond_gate(&admin, &Symbol::new(&e, "kyc"), &1);
+    assert!(client.get_bond_gate().is_some());
+}
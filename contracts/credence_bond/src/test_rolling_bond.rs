@@ -1,9 +1,9 @@
 //! Tests for Rolling Bond: auto-renewal, withdrawal request with notice period, renewal events.
 
 use crate::test_helpers;
-use crate::CredenceBondClient;
-use soroban_sdk::testutils::Ledger;
-use soroban_sdk::{Address, Env};
+use crate::{rolling_bond, CredenceBondClient};
+use soroban_sdk::testutils::{Events, Ledger};
+use soroban_sdk::{Address, Env, FromVal, Symbol};
 
 fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
     let (client, admin, identity, _token_id, _bond_id) = test_helpers::setup_with_token(e);
@@ -15,9 +15,9 @@ fn test_rolling_bond_creation() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin, identity) = setup(&e);
-    let bond = client.create_bond(&identity, &1000_i128, &86400_u64, &true, &10_u64);
+    let bond = client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
     assert!(bond.is_rolling);
-    assert_eq!(bond.notice_period_duration, 10);
+    assert_eq!(bond.notice_period, 10);
     assert_eq!(bond.withdrawal_requested_at, 0);
 }
 
@@ -26,8 +26,8 @@ fn test_request_withdrawal() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin, identity) = setup(&e);
-    client.create_bond(&identity, &1000_i128, &86400_u64, &true, &10_u64);
-    let bond = client.request_withdrawal();
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
+    let bond = client.request_withdrawal(&identity);
     assert_eq!(bond.withdrawal_requested_at, 1000);
 }
 
@@ -36,8 +36,8 @@ fn test_request_withdrawal() {
 fn test_request_withdrawal_non_rolling() {
     let e = Env::default();
     let (client, _admin, identity) = setup(&e);
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
-    client.request_withdrawal();
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    client.request_withdrawal(&identity);
 }
 
 #[test]
@@ -46,9 +46,83 @@ fn test_request_withdrawal_twice() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin, identity) = setup(&e);
-    client.create_bond(&identity, &1000_i128, &86400_u64, &true, &10_u64);
-    client.request_withdrawal();
-    client.request_withdrawal();
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
+    client.request_withdrawal(&identity);
+    client.request_withdrawal(&identity);
+}
+
+#[test]
+fn test_cancel_withdrawal_request_allows_new_request() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
+    client.request_withdrawal(&identity);
+
+    e.ledger().with_mut(|li| li.timestamp = 1005);
+    let bond = client.cancel_withdrawal_request(&identity);
+    assert_eq!(bond.withdrawal_requested_at, 0);
+    assert_eq!(
+        client.get_identity_state(&identity).withdrawal_requested_at,
+        0
+    );
+
+    let bond = client.request_withdrawal(&identity);
+    assert_eq!(bond.withdrawal_requested_at, 1005);
+}
+
+#[test]
+#[should_panic(expected = "no withdrawal requested")]
+fn test_cancel_without_request_panics() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
+    client.cancel_withdrawal_request(&identity);
+}
+
+#[test]
+#[should_panic(expected = "notice period not elapsed")]
+fn test_cancelled_request_blocks_withdrawal() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
+    client.request_withdrawal(&identity);
+    client.cancel_withdrawal_request(&identity);
+    e.ledger().with_mut(|li| li.timestamp = 1011);
+    client.withdraw(&identity, &500_000_000);
+}
+
+#[test]
+fn test_cancel_then_renew() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
+    client.request_withdrawal(&identity);
+    client.cancel_withdrawal_request(&identity);
+
+    e.ledger().with_mut(|li| li.timestamp = 87401);
+    let bond = client.renew_if_rolling(&identity);
+    assert_eq!(bond.bond_start, 87401);
+    assert_eq!(bond.withdrawal_requested_at, 0);
+    client.request_withdrawal(&identity);
+}
+
+#[test]
+fn test_renew_then_cancel_finds_no_request() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
+    client.request_withdrawal(&identity);
+
+    e.ledger().with_mut(|li| li.timestamp = 87401);
+    let bond = client.renew_if_rolling(&identity);
+    assert_eq!(bond.bond_start, 87401);
+    assert_eq!(bond.withdrawal_requested_at, 0);
+    assert!(client.try_cancel_withdrawal_request(&identity).is_err());
+    assert_eq!(client.get_identity_state(&identity).bond_start, 87401);
 }
 
 #[test]
@@ -56,12 +130,12 @@ fn test_renew_if_rolling_advances_period() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin, identity) = setup(&e);
-    client.create_bond(&identity, &1000_i128, &86400_u64, &true, &10_u64);
-    let bond = client.get_identity_state();
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
+    let bond = client.get_identity_state(&identity);
     assert_eq!(bond.bond_start, 1000);
 
     e.ledger().with_mut(|li| li.timestamp = 87401);
-    let bond = client.renew_if_rolling();
+    let bond = client.renew_if_rolling(&identity);
     assert_eq!(bond.bond_start, 87401);
     assert_eq!(bond.withdrawal_requested_at, 0);
 }
@@ -71,9 +145,9 @@ fn test_renew_if_rolling_no_op_before_period_end() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin, identity) = setup(&e);
-    client.create_bond(&identity, &1000_i128, &86400_u64, &true, &10_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
     e.ledger().with_mut(|li| li.timestamp = 44200);
-    let bond = client.renew_if_rolling();
+    let bond = client.renew_if_rolling(&identity);
     assert_eq!(bond.bond_start, 1000);
 }
 
@@ -82,9 +156,9 @@ fn test_renew_if_rolling_no_op_for_non_rolling() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin, identity) = setup(&e);
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
     e.ledger().with_mut(|li| li.timestamp = 87401);
-    let bond = client.renew_if_rolling();
+    let bond = client.renew_if_rolling(&identity);
     assert_eq!(bond.bond_start, 1000);
 }
 
@@ -93,9 +167,182 @@ fn test_withdraw_after_notice_period() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin, identity) = setup(&e);
-    client.create_bond(&identity, &1000_i128, &86400_u64, &true, &10_u64);
-    client.request_withdrawal();
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
+    client.request_withdrawal(&identity);
     e.ledger().with_mut(|li| li.timestamp = 1011);
-    let bond = client.withdraw(&500);
-    assert_eq!(bond.bonded_amount, 500);
+    let bond = client.withdraw(&identity, &500_000_000);
+    assert_eq!(bond.bonded_amount, 500_000_000);
+}
+
+#[test]
+fn test_period_math_near_u64_max() {
+    assert_eq!(rolling_bond::period_end(u64::MAX - 5, 10), u64::MAX);
+    assert!(!rolling_bond::is_period_ended(
+        u64::MAX - 1,
+        u64::MAX - 5,
+        10
+    ));
+    assert!(rolling_bond::is_period_ended(u64::MAX, u64::MAX - 5, 10));
+    assert!(rolling_bond::is_period_ended(u64::MAX, 0, u64::MAX));
+    assert!(rolling_bond::can_withdraw_after_notice(
+        u64::MAX,
+        u64::MAX - 1,
+        u64::MAX
+    ));
+}
+
+#[test]
+#[should_panic(expected = "rolling bond duration must be positive")]
+fn test_zero_duration_rolling_bond_rejected() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond_with_rolling(&identity, &1_000_000_000_i128, &0_u64, &true, &10_u64);
+}
+
+#[test]
+fn test_renewal_refused_when_period_end_overflows() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
+
+    e.ledger().with_mut(|li| li.timestamp = u64::MAX - 10);
+    let bond = client.renew_if_rolling(&identity);
+    assert_eq!(bond.bond_start, 1000);
+    assert_eq!(client.get_identity_state(&identity).bond_start, 1000);
+
+    // The largest start whose period end still fits renews normally.
+    e.ledger().with_mut(|li| li.timestamp = u64::MAX - 86400);
+    let bond = client.renew_if_rolling(&identity);
+    assert_eq!(bond.bond_start, u64::MAX - 86400);
+    assert_eq!(
+        rolling_bond::period_end(bond.bond_start, bond.bond_duration),
+        u64::MAX
+    );
+}
+
+#[test]
+fn test_withdraw_exactly_at_notice_boundary() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
+    client.request_withdrawal(&identity);
+    e.ledger().with_mut(|li| li.timestamp = 1010);
+    let bond = client.withdraw(&identity, &400_000_000);
+    assert_eq!(bond.bonded_amount, 600_000_000);
+    assert_eq!(bond.withdrawal_requested_at, 1000);
+}
+
+#[test]
+#[should_panic(expected = "notice period not elapsed")]
+fn test_withdraw_one_second_before_notice_ends() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
+    client.request_withdrawal(&identity);
+    e.ledger().with_mut(|li| li.timestamp = 1009);
+    client.withdraw(&identity, &400_000_000);
+}
+
+#[test]
+#[should_panic(expected = "notice period not elapsed")]
+fn test_withdraw_without_request() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 86400);
+    client.withdraw_bond(&identity, &400_000_000);
+}
+
+#[test]
+#[should_panic(expected = "notice period not elapsed")]
+fn test_withdraw_early_without_request() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
+    client.withdraw_early(&identity, &400_000_000);
+}
+
+#[test]
+fn test_full_withdrawal_clears_request() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
+    client.request_withdrawal(&identity);
+    e.ledger().with_mut(|li| li.timestamp = 1010);
+    let bond = client.withdraw(&identity, &1_000_000_000);
+    assert_eq!(bond.bonded_amount, 0);
+    assert_eq!(bond.withdrawal_requested_at, 0);
+}
+
+/// Renewal count carried by the `rolling_lapsed` event of the last invocation, if emitted.
+fn lapse_event(e: &Env, contract_id: &Address) -> Option<u32> {
+    e.events()
+        .all()
+        .iter()
+        .find(|(id, topics, _)| {
+            id == contract_id
+                && Symbol::from_val(e, &topics.get(0).unwrap()) == Symbol::new(e, "rolling_lapsed")
+        })
+        .map(|(_, _, data)| u32::from_val(e, &data))
+}
+
+#[test]
+fn test_renewals_capped_then_bond_lapses() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, admin, identity, _token_id, contract_id) = test_helpers::setup_with_token(&e);
+    client.set_max_renewals(&admin, &2);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
+
+    e.ledger().with_mut(|li| li.timestamp = 87_401);
+    client.renew_if_rolling(&identity);
+    e.ledger().with_mut(|li| li.timestamp = 173_801);
+    let bond = client.renew_if_rolling(&identity);
+    assert!(bond.is_rolling);
+    assert_eq!(bond.bond_start, 173_801);
+    assert_eq!(client.get_renewal_count(&identity), 2);
+    assert_eq!(lapse_event(&e, &contract_id), None);
+
+    e.ledger().with_mut(|li| li.timestamp = 260_201);
+    let bond = client.renew_if_rolling(&identity);
+    assert!(!bond.is_rolling);
+    assert_eq!(bond.bond_start, 173_801);
+    assert_eq!(lapse_event(&e, &contract_id), Some(2));
+    assert_eq!(client.get_renewal_count(&identity), 2);
+
+    // Lapsed: later calls are no-ops and the bond follows fixed-term rules.
+    e.ledger().with_mut(|li| li.timestamp = 400_000);
+    assert_eq!(client.renew_if_rolling(&identity).bond_start, 173_801);
+    assert!(client.try_withdraw_early(&identity, &100).is_err());
+    let bond = client.withdraw(&identity, &400_000_000);
+    assert_eq!(bond.bonded_amount, 600_000_000);
+}
+
+#[test]
+fn test_zero_max_renewals_is_unlimited() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin, identity) = setup(&e);
+    assert_eq!(client.get_max_renewals(), 0);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
+
+    for i in 1..=5_u64 {
+        e.ledger().with_mut(|li| li.timestamp = 1000 + i * 86_400);
+        assert!(client.renew_if_rolling(&identity).is_rolling);
+    }
+    assert_eq!(client.get_renewal_count(&identity), 5);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_set_max_renewals_requires_admin() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    client.set_max_renewals(&identity, &1);
 }
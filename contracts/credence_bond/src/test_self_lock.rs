@@ -0,0 +1,112 @@
+//! Tests for the holder self-lock: owner withdrawals are blocked until the lock ends, slashing
+//! is not, and the lock length is capped.
+
+use crate::self_lock::MAX_SELF_LOCK_SECS;
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+const AMOUNT: i128 = 10_000_000;
+const DURATION: u64 = 86_400;
+const LOCK: u64 = 30 * 86_400;
+const START: u64 = 1_000;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.ledger().with_mut(|li| li.timestamp = START);
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    (client, admin, identity)
+}
+
+fn set_time(e: &Env, timestamp: u64) {
+    e.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+#[test]
+fn test_withdrawal_blocked_during_lock() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    client.self_lock(&identity, &(START + LOCK));
+    assert_eq!(client.get_self_locked_until(&identity), START + LOCK);
+
+    // The bond has matured, but the lock still holds.
+    set_time(&e, START + LOCK - 1);
+    assert!(client.try_withdraw_bond(&identity, &AMOUNT).is_err());
+    assert!(client.try_withdraw_bond_full(&identity).is_err());
+    assert!(client
+        .try_authorize_topup_sponsor(&identity, &Address::generate(&e))
+        .is_err());
+    assert_eq!(client.get_identity_state(&identity).bonded_amount, AMOUNT);
+}
+
+#[test]
+#[should_panic(expected = "self-locked")]
+fn test_withdraw_panics_with_self_locked() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    client.self_lock(&identity, &(START + LOCK));
+    set_time(&e, START + DURATION);
+    client.withdraw_bond(&identity, &AMOUNT);
+}
+
+#[test]
+fn test_withdrawal_allowed_after_lock() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    client.self_lock(&identity, &(START + LOCK));
+
+    set_time(&e, START + LOCK);
+    let bond = client.withdraw_bond(&identity, &AMOUNT);
+    assert_eq!(bond.bonded_amount, 0);
+    assert_eq!(client.get_self_locked_until(&identity), 0);
+}
+
+#[test]
+fn test_slash_unaffected_by_lock() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.self_lock(&identity, &(START + LOCK));
+
+    let bond = client.slash(&admin, &identity, &1_000_000);
+    assert_eq!(bond.slashed_amount, 1_000_000);
+    assert_eq!(client.get_self_locked_until(&identity), START + LOCK);
+}
+
+#[test]
+fn test_lock_length_capped() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    assert!(client
+        .try_self_lock(&identity, &(START + MAX_SELF_LOCK_SECS + 1))
+        .is_err());
+    client.self_lock(&identity, &(START + MAX_SELF_LOCK_SECS));
+    assert_eq!(
+        client.get_self_locked_until(&identity),
+        START + MAX_SELF_LOCK_SECS
+    );
+}
+
+#[test]
+#[should_panic(expected = "cannot shorten self-lock")]
+fn test_lock_cannot_be_shortened() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    client.self_lock(&identity, &(START + LOCK));
+    client.self_lock(&identity, &(START + DURATION));
+}
+
+#[test]
+fn test_emergency_release_lifts_lock() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let governance = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    client.set_emergency_config(&admin, &governance, &treasury, &0, &false);
+    client.self_lock(&identity, &(START + LOCK));
+
+    client.emergency_release_self_lock(&admin, &governance, &identity);
+    assert_eq!(client.get_self_locked_until(&identity), 0);
+    set_time(&e, START + DURATION);
+    assert_eq!(client.withdraw_bond(&identity, &AMOUNT).bonded_amount, 0);
+}
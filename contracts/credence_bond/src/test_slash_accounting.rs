@@ -0,0 +1,77 @@
+//! Tests that the global slash accounting matches the slashes applied to bonds on every path.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Vec};
+
+const BOND_AMOUNT: i128 = 10_000_000;
+
+/// Asserts `applied == sum of slashed_amount`, nothing distributed, and everything pending.
+fn assert_identity(client: &CredenceBondClient, identities: &[&Address]) {
+    let slashed: i128 = identities
+        .iter()
+        .map(|id| client.get_identity_state(id).slashed_amount)
+        .sum();
+    let (applied, distributed, pending) = client.get_slash_accounting();
+    assert_eq!(applied, slashed);
+    assert_eq!(distributed, 0);
+    assert_eq!(pending, applied - distributed);
+}
+
+#[test]
+fn test_admin_slashes_counted_after_capping() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &86_400_u64, &false, &0_u64);
+    assert_eq!(client.get_slash_accounting(), (0, 0, 0));
+
+    client.slash(&admin, &identity, &3_000_000);
+    assert_identity(&client, &[&identity]);
+    // Only the 7_000_000 left in the bond is applied.
+    client.slash(&admin, &identity, &(2 * BOND_AMOUNT));
+    assert_identity(&client, &[&identity]);
+    assert_eq!(client.get_slash_accounting().0, BOND_AMOUNT);
+    client.slash(&admin, &identity, &1);
+    assert_eq!(client.get_slash_accounting().0, BOND_AMOUNT);
+}
+
+#[test]
+fn test_governance_and_settlement_paths_counted() {
+    let e = Env::default();
+    let (client, admin, identity, token, contract_id) = test_helpers::setup_with_token(&e);
+    let other = Address::generate(&e);
+    test_helpers::fund(&e, &token, &contract_id, &other, BOND_AMOUNT);
+    client.create_bond(&identity, &BOND_AMOUNT, &86_400_u64, &false, &0_u64);
+    client.create_bond(&other, &BOND_AMOUNT, &86_400_u64, &false, &0_u64);
+    let governor = Address::generate(&e);
+    client.initialize_governance(&admin, &Vec::from_array(&e, [governor.clone()]), &5_100, &1);
+
+    let id = client.propose_slash(&admin, &identity, &2_500_000);
+    client.governance_vote(&governor, &id, &true);
+    client.execute_slash_with_governance(&admin, &id);
+    assert_identity(&client, &[&identity, &other]);
+
+    let id = client.propose_slash(&admin, &other, &5_000_000);
+    client.acknowledge_and_settle(&other, &id, &1_333_333);
+    client.accept_settlement(&governor, &id);
+    assert_identity(&client, &[&identity, &other]);
+    assert_eq!(client.get_slash_accounting().0, 2_500_000 + 1_333_333);
+
+    client.slash(&admin, &other, &BOND_AMOUNT);
+    assert_identity(&client, &[&identity, &other]);
+    assert_eq!(client.get_slash_accounting().0, 2_500_000 + BOND_AMOUNT);
+}
+
+#[test]
+#[should_panic(expected = "slash distribution exceeds applied")]
+fn test_distribution_beyond_applied_rejected() {
+    let e = Env::default();
+    let (client, admin, identity, _token, contract_id) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &86_400_u64, &false, &0_u64);
+    client.slash(&admin, &identity, &1_000);
+    e.as_contract(&contract_id, || {
+        crate::slash_accounting::record_distributed(&e, 1_000);
+        crate::slash_accounting::record_distributed(&e, 1);
+    });
+}
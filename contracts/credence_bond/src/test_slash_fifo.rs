@@ -0,0 +1,137 @@
+//! Tests that approved slash requests against one identity execute in id order.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, Vec};
+
+const BOND_AMOUNT: i128 = 10_000_000;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address, Address) {
+    let (client, admin, identity, token, _) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &BOND_AMOUNT, &86_400_u64, &false, &0_u64);
+    let governor = Address::generate(e);
+    client.initialize_governance(&admin, &Vec::from_array(e, [governor.clone()]), &5_100, &1);
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    (client, admin, identity, governor, token)
+}
+
+/// Proposes and approves a slash of `amount` against `identity`.
+fn approved_request(
+    client: &CredenceBondClient,
+    admin: &Address,
+    governor: &Address,
+    identity: &Address,
+    amount: i128,
+) -> u64 {
+    let id = client.propose_slash(admin, identity, &amount);
+    client.governance_vote(governor, &id, &true);
+    id
+}
+
+#[test]
+#[should_panic(expected = "earlier request pending execution")]
+fn test_newer_request_waits_for_older() {
+    let e = Env::default();
+    let (client, admin, identity, governor, _) = setup(&e);
+    approved_request(&client, &admin, &governor, &identity, 1_000);
+    let newer = approved_request(&client, &admin, &governor, &identity, 2_000);
+    client.execute_slash_with_governance(&admin, &newer);
+}
+
+#[test]
+fn test_newer_request_executes_after_older() {
+    let e = Env::default();
+    let (client, admin, identity, governor, _) = setup(&e);
+    let older = approved_request(&client, &admin, &governor, &identity, 1_000);
+    let newer = approved_request(&client, &admin, &governor, &identity, 2_000);
+    assert!(client
+        .try_execute_slash_with_governance(&admin, &newer)
+        .is_err());
+
+    client.execute_slash_with_governance(&admin, &older);
+    let bond = client.execute_slash_with_governance(&admin, &newer);
+    assert_eq!(bond.slashed_amount, 3_000);
+}
+
+#[test]
+fn test_unapproved_older_request_does_not_block() {
+    let e = Env::default();
+    let (client, admin, identity, governor, _) = setup(&e);
+    client.propose_slash(&admin, &identity, &1_000);
+    let newer = approved_request(&client, &admin, &governor, &identity, 2_000);
+
+    let bond = client.execute_slash_with_governance(&admin, &newer);
+    assert_eq!(bond.slashed_amount, 2_000);
+}
+
+#[test]
+fn test_other_identity_does_not_block() {
+    let e = Env::default();
+    let (client, admin, identity, governor, token) = setup(&e);
+    let other = Address::generate(&e);
+    test_helpers::fund(&e, &token, &client.address, &other, BOND_AMOUNT);
+    client.create_bond(&other, &BOND_AMOUNT, &86_400_u64, &false, &0_u64);
+    approved_request(&client, &admin, &governor, &identity, 1_000);
+    let id = approved_request(&client, &admin, &governor, &other, 2_000);
+
+    let bond = client.execute_slash_with_governance(&admin, &id);
+    assert_eq!(bond.slashed_amount, 2_000);
+}
+
+#[test]
+fn test_expired_older_request_skipped() {
+    let e = Env::default();
+    let (client, admin, identity, governor, _) = setup(&e);
+    client.set_slash_execution_window(&admin, &1_000);
+    let older = approved_request(&client, &admin, &governor, &identity, 1_000);
+    e.ledger().with_mut(|li| li.timestamp = 1_500);
+    let newer = approved_request(&client, &admin, &governor, &identity, 2_000);
+    assert!(client
+        .try_execute_slash_with_governance(&admin, &newer)
+        .is_err());
+
+    e.ledger().with_mut(|li| li.timestamp = 2_000);
+    let bond = client.execute_slash_with_governance(&admin, &newer);
+    assert_eq!(bond.slashed_amount, 2_000);
+    assert!(client
+        .try_execute_slash_with_governance(&admin, &older)
+        .is_err());
+}
+
+#[test]
+fn test_disputed_older_request_skipped() {
+    let e = Env::default();
+    let (client, admin, identity, governor, _) = setup(&e);
+    let older = approved_request(&client, &admin, &governor, &identity, 1_000);
+    let newer = approved_request(&client, &admin, &governor, &identity, 2_000);
+    client.dispute_slash_request(&governor, &older);
+
+    let bond = client.execute_slash_with_governance(&admin, &newer);
+    assert_eq!(bond.slashed_amount, 2_000);
+}
+
+#[test]
+fn test_capping_follows_request_order() {
+    let e = Env::default();
+    let (client, admin, identity, governor, _) = setup(&e);
+    let older = approved_request(&client, &admin, &governor, &identity, 6_000_000);
+    let newer = approved_request(&client, &admin, &governor, &identity, 5_000_000);
+    assert_eq!(
+        client.get_open_slash_requests(&identity),
+        Vec::from_array(&e, [older, newer])
+    );
+    assert!(client
+        .try_execute_slash_with_governance(&admin, &newer)
+        .is_err());
+
+    let bond = client.execute_slash_with_governance(&admin, &older);
+    assert_eq!(bond.slashed_amount, 6_000_000);
+    assert_eq!(
+        client.get_open_slash_requests(&identity),
+        Vec::from_array(&e, [newer])
+    );
+    let bond = client.execute_slash_with_governance(&admin, &newer);
+    assert_eq!(bond.slashed_amount, BOND_AMOUNT);
+    assert!(client.get_open_slash_requests(&identity).is_empty());
+}
@@ -0,0 +1,102 @@
+//! Tests for the slash grace period: the boundary on every slash path, top-ups not restarting it
+//! and 0 keeping slashing immediate.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, Vec};
+
+const AMOUNT: i128 = 10_000_000;
+const START: u64 = 1_000;
+const GRACE: u64 = 3_600;
+
+fn setup(e: &Env, grace: u64) -> (CredenceBondClient<'_>, Address, Address) {
+    e.ledger().with_mut(|li| li.timestamp = START);
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(e);
+    client.set_slash_grace_period(&admin, &grace);
+    client.create_bond(&identity, &AMOUNT, &86_400_u64, &false, &0_u64);
+    (client, admin, identity)
+}
+
+fn set_time(e: &Env, timestamp: u64) {
+    e.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+#[test]
+fn test_zero_grace_allows_immediate_slash() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e, 0);
+    assert_eq!(client.get_slash_grace_period(), 0);
+    assert_eq!(client.slash(&admin, &identity, &100).slashed_amount, 100);
+}
+
+#[test]
+fn test_slash_blocked_until_grace_ends() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e, GRACE);
+
+    set_time(&e, START + GRACE - 1);
+    assert!(client.try_slash(&admin, &identity, &100).is_err());
+    assert!(client.try_slash_bond(&admin, &identity, &100).is_err());
+    assert_eq!(client.get_identity_state(&identity).slashed_amount, 0);
+
+    set_time(&e, START + GRACE);
+    assert_eq!(client.slash(&admin, &identity, &100).slashed_amount, 100);
+}
+
+#[test]
+#[should_panic(expected = "bond in grace period")]
+fn test_slash_panics_in_grace_period() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e, GRACE);
+    client.slash(&admin, &identity, &100);
+}
+
+#[test]
+#[should_panic(expected = "bond in grace period")]
+fn test_slash_bond_panics_in_grace_period() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e, GRACE);
+    client.slash_bond(&admin, &identity, &100);
+}
+
+#[test]
+fn test_governance_slash_blocked_until_grace_ends() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e, GRACE);
+    let governor = Address::generate(&e);
+    client.initialize_governance(&admin, &Vec::from_array(&e, [governor.clone()]), &5_100, &1);
+    let id = client.propose_slash(&admin, &identity, &100);
+    client.governance_vote(&governor, &id, &true);
+
+    set_time(&e, START + GRACE - 1);
+    assert!(client
+        .try_execute_slash_with_governance(&admin, &id)
+        .is_err());
+    set_time(&e, START + GRACE);
+    assert_eq!(
+        client
+            .execute_slash_with_governance(&admin, &id)
+            .slashed_amount,
+        100
+    );
+}
+
+#[test]
+fn test_top_up_does_not_restart_grace() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e, GRACE);
+    set_time(&e, START + GRACE - 10);
+    client.top_up(&identity, &identity, &AMOUNT);
+
+    set_time(&e, START + GRACE);
+    assert_eq!(client.slash(&admin, &identity, &100).slashed_amount, 100);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_non_admin_cannot_set_grace_period() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e, 0);
+    client.set_slash_grace_period(&identity, &GRACE);
+}
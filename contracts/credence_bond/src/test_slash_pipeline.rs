@@ -0,0 +1,132 @@
+//! Tests for slash request transition timestamps and pipeline latency aggregates.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, Vec};
+
+const BOND_AMOUNT: i128 = 100_000_000;
+const SLASH_AMOUNT: i128 = 10_000_000;
+const DISPUTE_BOND: i128 = 1_000_000;
+
+fn set_time(e: &Env, t: u64) {
+    e.ledger().with_mut(|li| li.timestamp = t);
+}
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, [Address; 3]) {
+    let (client, admin, identity, token, contract_id) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &BOND_AMOUNT, &86_400_u64, &false, &0_u64);
+
+    let governors = [
+        Address::generate(e),
+        Address::generate(e),
+        Address::generate(e),
+    ];
+    let expiration = e.ledger().sequence().saturating_add(10_000);
+    for g in governors.iter() {
+        StellarAssetClient::new(e, &token).mint(g, &DISPUTE_BOND);
+        TokenClient::new(e, &token).approve(g, &contract_id, &DISPUTE_BOND, &expiration);
+    }
+    client.initialize_governance(
+        &admin,
+        &Vec::from_array(e, governors.clone()),
+        &6_600_u32,
+        &2_u32,
+    );
+    client.set_dispute_bond_config(&admin, &DISPUTE_BOND, &0_u32);
+    (client, admin, identity, governors)
+}
+
+#[test]
+fn test_approved_and_executed_path() {
+    let e = Env::default();
+    let (client, admin, identity, [g1, g2, _]) = setup(&e);
+
+    set_time(&e, 100);
+    let id = client.propose_slash(&admin, &identity, &SLASH_AMOUNT);
+    set_time(&e, 150);
+    client.governance_vote(&g1, &id, &true);
+    set_time(&e, 200);
+    client.governance_vote(&g2, &id, &true);
+    set_time(&e, 300);
+    client.execute_slash_with_governance(&admin, &id);
+
+    let timeline = client.describe_slash_request(&id).unwrap().timeline;
+    assert_eq!(timeline.submitted_at, 100);
+    assert_eq!(timeline.approved_at, Some(200));
+    assert_eq!(timeline.executed_at, Some(300));
+    assert_eq!(timeline.disputed_at, None);
+    assert_eq!(timeline.resolved_at, None);
+    assert_eq!(timeline.rejected_at, None);
+
+    let stats = client.get_slash_pipeline_stats();
+    assert_eq!((stats.approved.count, stats.approved.total_secs), (1, 100));
+    assert_eq!((stats.executed.count, stats.executed.total_secs), (1, 100));
+    assert_eq!(stats.rejected.count, 0);
+}
+
+#[test]
+fn test_disputed_and_rejected_path() {
+    let e = Env::default();
+    let (client, admin, identity, [g1, g2, g3]) = setup(&e);
+
+    let id = client.propose_slash(&admin, &identity, &SLASH_AMOUNT);
+    set_time(&e, 50);
+    client.dispute_slash_request(&g1, &id);
+    set_time(&e, 80);
+    client.dispute_slash_request(&g2, &id);
+    client.governance_vote(&g2, &id, &false);
+    client.governance_vote(&g3, &id, &false);
+    set_time(&e, 200);
+    client.reject_slash_request(&id);
+
+    let timeline = client.describe_slash_request(&id).unwrap().timeline;
+    assert_eq!(timeline.disputed_at, Some(50));
+    assert_eq!(timeline.resolved_at, Some(200));
+    assert_eq!(timeline.rejected_at, Some(200));
+    assert_eq!(timeline.approved_at, None);
+    assert_eq!(timeline.executed_at, None);
+
+    let stats = client.get_slash_pipeline_stats();
+    assert_eq!((stats.disputed.count, stats.disputed.total_secs), (1, 50));
+    assert_eq!((stats.resolved.count, stats.resolved.total_secs), (1, 150));
+    assert_eq!((stats.rejected.count, stats.rejected.total_secs), (1, 200));
+    assert_eq!(stats.approved.count, 0);
+}
+
+#[test]
+fn test_aggregates_sum_across_requests() {
+    let e = Env::default();
+    let (client, admin, identity, [g1, g2, g3]) = setup(&e);
+
+    let first = client.propose_slash(&admin, &identity, &SLASH_AMOUNT);
+    let second = client.propose_slash(&admin, &identity, &SLASH_AMOUNT);
+    set_time(&e, 40);
+    client.governance_vote(&g1, &first, &true);
+    client.governance_vote(&g2, &first, &true);
+    set_time(&e, 100);
+    client.governance_vote(&g3, &second, &true);
+    client.governance_vote(&g1, &second, &true);
+    // A vote after approval must not move the timestamp.
+    set_time(&e, 130);
+    client.governance_vote(&g2, &second, &true);
+
+    assert_eq!(
+        client
+            .describe_slash_request(&second)
+            .unwrap()
+            .timeline
+            .approved_at,
+        Some(100)
+    );
+    let stats = client.get_slash_pipeline_stats();
+    assert_eq!((stats.approved.count, stats.approved.total_secs), (2, 140));
+}
+
+#[test]
+fn test_unknown_request_has_no_description() {
+    let e = Env::default();
+    let (client, ..) = setup(&e);
+    assert!(client.describe_slash_request(&42).is_none());
+}
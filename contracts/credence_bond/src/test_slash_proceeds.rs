@@ -0,0 +1,155 @@
+//! Tests for slash proceeds routing: transfers to the configured beneficiary, the fee pool
+//! fallback and routing only the applied (capped) amount.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, Env, FromVal, Symbol, Vec};
+
+const AMOUNT: i128 = 10_000_000;
+const DURATION: u64 = 86_400;
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    admin: Address,
+    identity: Address,
+    token: TokenClient<'a>,
+    contract_id: Address,
+}
+
+fn setup(e: &Env) -> Setup<'_> {
+    let (client, admin, identity, token, contract_id) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    Setup {
+        client,
+        admin,
+        identity,
+        token: TokenClient::new(e, &token),
+        contract_id,
+    }
+}
+
+fn proceeds_events(e: &Env, contract_id: &Address) -> usize {
+    e.events()
+        .all()
+        .iter()
+        .filter(|(id, topics, _)| {
+            id == contract_id
+                && Symbol::from_val(e, &topics.get(0).unwrap())
+                    == Symbol::new(e, "slash_proceeds_transferred")
+        })
+        .count()
+}
+
+#[test]
+fn test_slash_transfers_to_beneficiary() {
+    let e = Env::default();
+    let s = setup(&e);
+    let treasury = Address::generate(&e);
+    s.client.set_slash_beneficiary(&s.admin, &treasury);
+    assert_eq!(s.client.get_slash_beneficiary(), Some(treasury.clone()));
+
+    let held = s.token.balance(&s.contract_id);
+    assert_eq!(s.token.balance(&treasury), 0);
+    s.client.slash(&s.admin, &s.identity, &3_000_000);
+    assert_eq!(proceeds_events(&e, &s.contract_id), 1);
+
+    assert_eq!(s.token.balance(&treasury), 3_000_000);
+    assert_eq!(s.token.balance(&s.contract_id), held - 3_000_000);
+    assert_eq!(s.client.get_slash_accounting(), (3_000_000, 3_000_000, 0));
+    assert_eq!(s.client.collect_fees(&s.admin), 0);
+}
+
+#[test]
+fn test_without_beneficiary_proceeds_accrue_to_fees() {
+    let e = Env::default();
+    let s = setup(&e);
+    let held = s.token.balance(&s.contract_id);
+    s.client.slash(&s.admin, &s.identity, &3_000_000);
+    assert_eq!(proceeds_events(&e, &s.contract_id), 1);
+
+    assert_eq!(s.token.balance(&s.contract_id), held);
+    assert_eq!(s.client.get_slash_accounting(), (3_000_000, 0, 3_000_000));
+    assert_eq!(s.client.collect_fees(&s.admin), 3_000_000);
+}
+
+#[test]
+fn test_cleared_beneficiary_falls_back_to_fees() {
+    let e = Env::default();
+    let s = setup(&e);
+    let treasury = Address::generate(&e);
+    s.client.set_slash_beneficiary(&s.admin, &treasury);
+    s.client.clear_slash_beneficiary(&s.admin);
+    assert_eq!(s.client.get_slash_beneficiary(), None);
+
+    s.client.slash(&s.admin, &s.identity, &1_000_000);
+    assert_eq!(s.token.balance(&treasury), 0);
+    assert_eq!(s.client.collect_fees(&s.admin), 1_000_000);
+}
+
+#[test]
+fn test_only_applied_amount_routed() {
+    let e = Env::default();
+    let s = setup(&e);
+    let treasury = Address::generate(&e);
+    s.client.set_slash_beneficiary(&s.admin, &treasury);
+
+    s.client.slash(&s.admin, &s.identity, &(2 * AMOUNT));
+    assert_eq!(s.token.balance(&treasury), AMOUNT);
+    // The bond is fully slashed, so a further slash applies and routes nothing.
+    s.client.slash(&s.admin, &s.identity, &1);
+    assert_eq!(s.token.balance(&treasury), AMOUNT);
+    assert_eq!(proceeds_events(&e, &s.contract_id), 0);
+}
+
+#[test]
+fn test_governance_slash_routed() {
+    let e = Env::default();
+    let s = setup(&e);
+    let treasury = Address::generate(&e);
+    s.client.set_slash_beneficiary(&s.admin, &treasury);
+    let governor = Address::generate(&e);
+    s.client.initialize_governance(
+        &s.admin,
+        &Vec::from_array(&e, [governor.clone()]),
+        &5_100,
+        &1,
+    );
+
+    let id = s.client.propose_slash(&s.admin, &s.identity, &2_500_000);
+    s.client.governance_vote(&governor, &id, &true);
+    s.client.execute_slash_with_governance(&s.admin, &id);
+    assert_eq!(s.token.balance(&treasury), 2_500_000);
+}
+
+#[test]
+fn test_additional_bond_slash_routed() {
+    let e = Env::default();
+    let s = setup(&e);
+    let treasury = Address::generate(&e);
+    s.client.set_slash_beneficiary(&s.admin, &treasury);
+    let id = s.client.create_bond_v2(&s.identity, &AMOUNT, &DURATION);
+
+    s.client
+        .slash_bond_v2(&s.admin, &s.identity, &id, &4_000_000);
+    assert_eq!(s.token.balance(&treasury), 4_000_000);
+    assert_eq!(s.client.get_slash_accounting().1, 4_000_000);
+}
+
+#[test]
+#[should_panic(expected = "beneficiary cannot be contract")]
+fn test_contract_cannot_be_beneficiary() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.set_slash_beneficiary(&s.admin, &s.contract_id);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_non_admin_cannot_set_beneficiary() {
+    let e = Env::default();
+    let s = setup(&e);
+    let treasury = Address::generate(&e);
+    s.client.set_slash_beneficiary(&s.identity, &treasury);
+}
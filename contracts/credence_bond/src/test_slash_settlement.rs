@@ -0,0 +1,91 @@
+//! Tests for voluntary settlement of slash requests.
+
+use crate::governance_approval::ProposalStatus;
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Vec};
+
+const BOND_AMOUNT: i128 = 100_000_000;
+const SLASH_AMOUNT: i128 = 10_000_000;
+const OFFER: i128 = 4_000_000;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, [Address; 3]) {
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &BOND_AMOUNT, &86_400_u64, &false, &0_u64);
+    let governors = [
+        Address::generate(e),
+        Address::generate(e),
+        Address::generate(e),
+    ];
+    client.initialize_governance(
+        &admin,
+        &Vec::from_array(e, governors.clone()),
+        &6_600_u32,
+        &2_u32,
+    );
+    (client, admin, identity, governors)
+}
+
+#[test]
+fn test_accepted_settlement_slashes_offer_and_closes() {
+    let e = Env::default();
+    let (client, admin, identity, [g1, g2, _]) = setup(&e);
+    let id = client.propose_slash(&admin, &identity, &SLASH_AMOUNT);
+
+    let offer = client.acknowledge_and_settle(&identity, &id, &OFFER);
+    assert_eq!(offer.amount, OFFER);
+    assert_eq!(client.get_settlement_offer(&id), Some(offer));
+
+    let bond = client.accept_settlement(&g1, &id);
+    assert_eq!(bond.slashed_amount, OFFER);
+    assert_eq!(
+        client.get_slash_proposal(&id).unwrap().status,
+        ProposalStatus::Settled
+    );
+    assert_eq!(client.get_settlement_offer(&id), None);
+    assert!(client.try_governance_vote(&g2, &id, &true).is_err());
+    assert!(client
+        .try_execute_slash_with_governance(&admin, &id)
+        .is_err());
+}
+
+#[test]
+fn test_rejected_settlement_keeps_normal_flow() {
+    let e = Env::default();
+    let (client, admin, identity, [g1, g2, _]) = setup(&e);
+    let id = client.propose_slash(&admin, &identity, &SLASH_AMOUNT);
+
+    client.acknowledge_and_settle(&identity, &id, &OFFER);
+    client.reject_settlement(&admin, &id);
+    assert_eq!(client.get_settlement_offer(&id), None);
+    assert!(client.try_accept_settlement(&g1, &id).is_err());
+    assert_eq!(
+        client.get_slash_proposal(&id).unwrap().status,
+        ProposalStatus::Open
+    );
+
+    client.governance_vote(&g1, &id, &true);
+    client.governance_vote(&g2, &id, &true);
+    let bond = client.execute_slash_with_governance(&admin, &id);
+    assert_eq!(bond.slashed_amount, SLASH_AMOUNT);
+}
+
+#[test]
+#[should_panic(expected = "offer exceeds requested amount")]
+fn test_offer_above_requested_amount_rejected() {
+    let e = Env::default();
+    let (client, admin, identity, _) = setup(&e);
+    let id = client.propose_slash(&admin, &identity, &SLASH_AMOUNT);
+    client.acknowledge_and_settle(&identity, &id, &(SLASH_AMOUNT + 1));
+}
+
+#[test]
+#[should_panic(expected = "not a governor or requester")]
+fn test_outsider_cannot_accept() {
+    let e = Env::default();
+    let (client, admin, identity, _) = setup(&e);
+    let id = client.propose_slash(&admin, &identity, &SLASH_AMOUNT);
+    client.acknowledge_and_settle(&identity, &id, &OFFER);
+    client.accept_settlement(&Address::generate(&e), &id);
+}
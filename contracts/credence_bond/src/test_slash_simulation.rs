@@ -0,0 +1,76 @@
+//! Tests for the read-only pending slash simulation.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Vec};
+
+const BOND_AMOUNT: i128 = 10_000_000;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address) {
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &BOND_AMOUNT, &86_400_u64, &false, &0_u64);
+    let governor = Address::generate(e);
+    client.initialize_governance(&admin, &Vec::from_array(e, [governor.clone()]), &5_100, &1);
+    (client, admin, identity, governor)
+}
+
+#[test]
+fn test_simulation_matches_sequential_execution() {
+    let e = Env::default();
+    let (client, admin, identity, governor) = setup(&e);
+    let ids = [
+        client.propose_slash(&admin, &identity, &6_000_000),
+        client.propose_slash(&admin, &identity, &5_000_000),
+        client.propose_slash(&admin, &identity, &3_000_000),
+    ];
+
+    let sim = client.simulate_pending_slashes(&identity);
+    assert_eq!(sim.slashes.len(), 3);
+    let applied: [i128; 3] = core::array::from_fn(|i| sim.slashes.get(i as u32).unwrap().applied);
+    assert_eq!(applied, [6_000_000, 4_000_000, 0]);
+    assert!(!sim.slashes.get(1).unwrap().capped_to_zero);
+    assert!(sim.slashes.get(2).unwrap().capped_to_zero);
+    assert_eq!(sim.total_applied, BOND_AMOUNT);
+    assert_eq!(sim.projected_bond.slashed_amount, BOND_AMOUNT);
+
+    // Read-only: nothing changed.
+    assert_eq!(client.get_identity_state(&identity).slashed_amount, 0);
+    assert_eq!(client.simulate_pending_slashes(&identity).slashes.len(), 3);
+
+    let mut slashed = 0;
+    for (i, id) in ids.iter().enumerate() {
+        client.governance_vote(&governor, id, &true);
+        let bond = client.execute_slash_with_governance(&admin, id);
+        assert_eq!(bond.slashed_amount - slashed, applied[i]);
+        slashed = bond.slashed_amount;
+    }
+    assert_eq!(slashed, sim.projected_bond.slashed_amount);
+    assert!(client
+        .simulate_pending_slashes(&identity)
+        .slashes
+        .is_empty());
+}
+
+#[test]
+fn test_closed_requests_excluded() {
+    let e = Env::default();
+    let (client, admin, identity, governor) = setup(&e);
+    let executed = client.propose_slash(&admin, &identity, &2_000_000);
+    client.governance_vote(&governor, &executed, &true);
+    client.execute_slash_with_governance(&admin, &executed);
+    let open = client.propose_slash(&admin, &identity, &1_000_000);
+
+    let sim = client.simulate_pending_slashes(&identity);
+    assert_eq!(sim.slashes.len(), 1);
+    assert_eq!(sim.slashes.get(0).unwrap().proposal_id, open);
+    assert_eq!(sim.projected_bond.slashed_amount, 3_000_000);
+}
+
+#[test]
+#[should_panic(expected = "no bond")]
+fn test_unknown_identity_panics() {
+    let e = Env::default();
+    let (client, ..) = setup(&e);
+    client.simulate_pending_slashes(&Address::generate(&e));
+}
@@ -10,17 +10,12 @@
 //! 7. Integration with withdrawals
 //! 8. Cumulative slashing scenarios
 
-// Comprehensive unit tests for slashing functionality.
-// Covers: successful slash, unauthorized rejection, over-slash prevention,
-// slash history (via events), and slash events.
-//! Comprehensive unit tests for slashing functionality.
-//! Covers: successful slash, unauthorized rejection, over-slash prevention,
-//! slash history (via events), and slash events.
-
+use crate::context::Context;
 use crate::test_helpers;
+use crate::validation::MAX_BOND_AMOUNT;
 use crate::CredenceBondClient;
-use soroban_sdk::testutils::{Address as _, Ledger};
-use soroban_sdk::{Address, Env};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Env, FromVal, Symbol, Vec};
 
 // ============================================================================
 // Test Setup Utilities
@@ -52,6 +47,21 @@ fn setup_with_bond_max_mint(
     (client, admin, identity)
 }
 
+/// Data of the latest `bond_slashed` event: (amount, previous, total, reason, context).
+fn slashed_event(e: &Env, contract_id: &Address) -> (i128, i128, i128, Symbol, Context) {
+    let (_, _, data) = e
+        .events()
+        .all()
+        .iter()
+        .rev()
+        .find(|(id, topics, _)| {
+            id == contract_id
+                && Symbol::from_val(e, &topics.get(0).unwrap()) == Symbol::new(e, "bond_slashed")
+        })
+        .expect("bond_slashed not emitted");
+    <(i128, i128, i128, Symbol, Context)>::from_val(e, &data)
+}
+
 // ============================================================================
 // Category 1: Basic Slashing Operations
 // ============================================================================
@@ -59,46 +69,46 @@ fn setup_with_bond_max_mint(
 #[test]
 fn test_slash_basic_success() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
 
-    let bond = client.slash(&admin, &300_i128);
+    let bond = client.slash(&admin, &identity, &300_000_000_i128);
 
-    assert_eq!(bond.slashed_amount, 300);
-    assert_eq!(bond.bonded_amount, 1000);
+    assert_eq!(bond.slashed_amount, 300_000_000);
+    assert_eq!(bond.bonded_amount, 1_000_000_000);
     assert!(bond.active);
 }
 
 #[test]
 fn test_slash_small_amount() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 10000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 10_000_000_000_i128, 86400_u64);
 
-    let bond = client.slash(&admin, &1_i128);
+    let bond = client.slash(&admin, &identity, &1_000_000_i128);
 
-    assert_eq!(bond.slashed_amount, 1);
-    assert_eq!(bond.bonded_amount, 10000);
+    assert_eq!(bond.slashed_amount, 1_000_000);
+    assert_eq!(bond.bonded_amount, 10_000_000_000);
 }
 
 #[test]
 fn test_slash_exact_half() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
 
-    let bond = client.slash(&admin, &500_i128);
+    let bond = client.slash(&admin, &identity, &500_000_000_i128);
 
-    assert_eq!(bond.slashed_amount, 500);
-    assert_eq!(bond.bonded_amount, 1000);
+    assert_eq!(bond.slashed_amount, 500_000_000);
+    assert_eq!(bond.bonded_amount, 1_000_000_000);
 }
 
 #[test]
 fn test_slash_entire_amount() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
 
-    let bond = client.slash(&admin, &1000_i128);
+    let bond = client.slash(&admin, &identity, &1_000_000_000_i128);
 
-    assert_eq!(bond.slashed_amount, 1000);
-    assert_eq!(bond.bonded_amount, 1000);
+    assert_eq!(bond.slashed_amount, 1_000_000_000);
+    assert_eq!(bond.bonded_amount, 1_000_000_000);
 }
 
 // ============================================================================
@@ -109,35 +119,35 @@ fn test_slash_entire_amount() {
 #[should_panic(expected = "not admin")]
 fn test_slash_unauthorized_rejection() {
     let e = Env::default();
-    let (_client, _admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (_client, _admin, _identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
 
     let (client, _admin, identity) = setup(&e);
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
     let other = Address::generate(&e);
-    client.slash(&other, &100_i128);
+    client.slash(&other, &identity, &100_000_000_i128);
 }
 
 #[test]
 #[should_panic(expected = "not admin")]
 fn test_slash_unauthorized_different_address() {
     let e = Env::default();
-    let (client, _admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, _admin, identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
 
     let attacker1 = Address::generate(&e);
     let attacker2 = Address::generate(&e);
-    client.slash(&attacker1, &500_i128);
+    client.slash(&attacker1, &identity, &500_000_000_i128);
     // Second attempt with different attacker also fails
-    client.slash(&attacker2, &500_i128);
+    client.slash(&attacker2, &identity, &500_000_000_i128);
 }
 
 #[test]
 #[should_panic(expected = "not admin")]
 fn test_slash_identity_cannot_slash_own_bond() {
     let e = Env::default();
-    let (client, _admin, identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, _admin, identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
 
     // Identity tries to slash their own bond (not authorized)
-    client.slash(&identity, &100_i128);
+    client.slash(&identity, &identity, &100_000_000_i128);
 }
 
 // ============================================================================
@@ -147,37 +157,37 @@ fn test_slash_identity_cannot_slash_own_bond() {
 #[test]
 fn test_slash_over_amount_capped() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
 
-    let bond = client.slash(&admin, &2000_i128);
+    let bond = client.slash(&admin, &identity, &2_000_000_000_i128);
 
     // Should be capped at bonded_amount
-    assert_eq!(bond.slashed_amount, 1000);
-    assert_eq!(bond.bonded_amount, 1000);
+    assert_eq!(bond.slashed_amount, 1_000_000_000);
+    assert_eq!(bond.bonded_amount, 1_000_000_000);
 }
 
 #[test]
 fn test_slash_way_over_amount_capped() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 500_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 500_000_000_i128, 86400_u64);
 
-    let bond = client.slash(&admin, &999999_i128);
+    let bond = client.slash(&admin, &identity, &999_999_000_000_i128);
 
     // Should be capped at bonded_amount
-    assert_eq!(bond.slashed_amount, 500);
-    assert_eq!(bond.bonded_amount, 500);
+    assert_eq!(bond.slashed_amount, 500_000_000);
+    assert_eq!(bond.bonded_amount, 500_000_000);
 }
 
 #[test]
 fn test_slash_max_i128_capped() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
 
-    let bond = client.slash(&admin, &i128::MAX);
+    let bond = client.slash(&admin, &identity, &i128::MAX);
 
     // Should be capped at bonded_amount
-    assert_eq!(bond.slashed_amount, 1000);
-    assert_eq!(bond.bonded_amount, 1000);
+    assert_eq!(bond.slashed_amount, 1_000_000_000);
+    assert_eq!(bond.bonded_amount, 1_000_000_000);
 }
 
 // ============================================================================
@@ -187,35 +197,35 @@ fn test_slash_max_i128_capped() {
 #[test]
 fn test_slash_zero_amount() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
 
-    let bond = client.slash(&admin, &0_i128);
+    let bond = client.slash(&admin, &identity, &0_i128);
 
     assert_eq!(bond.slashed_amount, 0);
-    assert_eq!(bond.bonded_amount, 1000);
+    assert_eq!(bond.bonded_amount, 1_000_000_000);
 }
 
 #[test]
-#[should_panic(expected = "slashing caused overflow")]
 fn test_slash_overflow_prevention() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond_max_mint(&e, i128::MAX - 100, 86400_u64);
+    let (client, admin, identity) = setup_with_bond_max_mint(&e, MAX_BOND_AMOUNT, 86400_u64);
 
-    // First slash: amount = 50
-    client.slash(&admin, &50_i128);
+    // First slash: amount = 50_000_000
+    client.slash(&admin, &identity, &50_000_000_i128);
 
-    // Second slash: would overflow (MAX - 100 + 50 = MAX - 50, then + i128::MAX)
-    client.slash(&admin, &i128::MAX);
+    // Second slash: 50_000_000 + i128::MAX saturates and clamps to the bonded amount
+    let bond = client.slash(&admin, &identity, &i128::MAX);
+    assert_eq!(bond.slashed_amount, MAX_BOND_AMOUNT);
 }
 
 #[test]
 fn test_slash_on_very_large_bond() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond_max_mint(&e, i128::MAX / 2, 86400_u64);
+    let (client, admin, identity) = setup_with_bond_max_mint(&e, MAX_BOND_AMOUNT, 86400_u64);
 
-    let bond = client.slash(&admin, &(i128::MAX / 4));
+    let bond = client.slash(&admin, &identity, &(MAX_BOND_AMOUNT / 2));
 
-    assert_eq!(bond.slashed_amount, i128::MAX / 4);
+    assert_eq!(bond.slashed_amount, MAX_BOND_AMOUNT / 2);
 }
 
 // ============================================================================
@@ -225,42 +235,42 @@ fn test_slash_on_very_large_bond() {
 #[test]
 fn test_slash_history_single_slash() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
 
-    client.slash(&admin, &200_i128);
-    let bond = client.get_identity_state();
+    client.slash(&admin, &identity, &200_000_000_i128);
+    let bond = client.get_identity_state(&identity);
 
-    assert_eq!(bond.slashed_amount, 200);
-    assert_eq!(bond.bonded_amount, 1000);
+    assert_eq!(bond.slashed_amount, 200_000_000);
+    assert_eq!(bond.bonded_amount, 1_000_000_000);
 }
 
 #[test]
 fn test_slash_history_cumulative() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
 
-    let bond1 = client.slash(&admin, &200_i128);
-    assert_eq!(bond1.slashed_amount, 200);
+    let bond1 = client.slash(&admin, &identity, &200_000_000_i128);
+    assert_eq!(bond1.slashed_amount, 200_000_000);
 
-    let bond2 = client.slash(&admin, &300_i128);
-    assert_eq!(bond2.slashed_amount, 500);
+    let bond2 = client.slash(&admin, &identity, &300_000_000_i128);
+    assert_eq!(bond2.slashed_amount, 500_000_000);
 
-    let bond3 = client.get_identity_state();
-    assert_eq!(bond3.slashed_amount, 500);
+    let bond3 = client.get_identity_state(&identity);
+    assert_eq!(bond3.slashed_amount, 500_000_000);
 }
 
 #[test]
 fn test_slash_multiple_accumulate() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 10000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 10_000_000_000_i128, 86400_u64);
 
     // Linear accumulation: 1000 + 2000 + 3000 + 4000 + 5000
     // But capped at bonded_amount (10000)
     for i in 1..=5 {
-        let bond = client.slash(&admin, &(i as i128 * 1000_i128));
-        let expected_slashed = (i as i128 * (i as i128 + 1) / 2) * 1000_i128;
-        let capped = if expected_slashed > 10000_i128 {
-            10000_i128
+        let bond = client.slash(&admin, &identity, &(i as i128 * 1_000_000_000_i128));
+        let expected_slashed = (i as i128 * (i as i128 + 1) / 2) * 1_000_000_000_i128;
+        let capped = if expected_slashed > 10_000_000_000_i128 {
+            10_000_000_000_i128
         } else {
             expected_slashed
         };
@@ -271,16 +281,16 @@ fn test_slash_multiple_accumulate() {
 #[test]
 fn test_slash_does_not_affect_other_fields() {
     let e = Env::default();
-    let (client, admin, identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
 
-    let original_bond = client.get_identity_state();
+    let original_bond = client.get_identity_state(&identity);
     let original_bonded = original_bond.bonded_amount;
     let original_start = original_bond.bond_start;
     let original_duration = original_bond.bond_duration;
 
-    client.slash(&admin, &300_i128);
+    client.slash(&admin, &identity, &300_000_000_i128);
 
-    let updated_bond = client.get_identity_state();
+    let updated_bond = client.get_identity_state(&identity);
     assert_eq!(updated_bond.bonded_amount, original_bonded);
     assert_eq!(updated_bond.bond_start, original_start);
     assert_eq!(updated_bond.bond_duration, original_duration);
@@ -294,37 +304,64 @@ fn test_slash_does_not_affect_other_fields() {
 #[test]
 fn test_slash_event_emitted_basic() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
 
-    let _bond = client.slash(&admin, &250_i128);
+    let _bond = client.slash(&admin, &identity, &250_000_000_i128);
 
     // Verify event was published by checking bond state
-    let state = client.get_identity_state();
-    assert_eq!(state.slashed_amount, 250);
+    let state = client.get_identity_state(&identity);
+    assert_eq!(state.slashed_amount, 250_000_000);
 }
 
 #[test]
 fn test_slash_event_contains_correct_event_data() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
 
-    let bond1 = client.slash(&admin, &100_i128);
-    assert_eq!(bond1.slashed_amount, 100);
+    let bond1 = client.slash(&admin, &identity, &100_000_000_i128);
+    assert_eq!(bond1.slashed_amount, 100_000_000);
 
-    let bond2 = client.slash(&admin, &200_i128);
-    // Event should contain slash_amount=200, total_slashed=300
-    assert_eq!(bond2.slashed_amount, 300);
+    let bond2 = client.slash(&admin, &identity, &200_000_000_i128);
+    assert_eq!(bond2.slashed_amount, 300_000_000);
+    let (amount, previous, total, reason, _) = slashed_event(&e, &client.address);
+    assert_eq!(
+        (amount, previous, total),
+        (200_000_000, 100_000_000, 300_000_000)
+    );
+    assert_eq!(reason, Symbol::new(&e, "slash"));
+}
+
+#[test]
+fn test_slash_event_records_governance_reason() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
+    client.slash(&admin, &identity, &100_000_000_i128);
+    let governor = Address::generate(&e);
+    client.initialize_governance(&admin, &Vec::from_array(&e, [governor.clone()]), &5_100, &1);
+
+    let id = client.propose_slash(&admin, &identity, &50_000_000_i128);
+    client.governance_vote(&governor, &id, &true);
+    client.execute_slash_with_governance(&admin, &id);
+    let (amount, previous, total, reason, _) = slashed_event(&e, &client.address);
+    assert_eq!(
+        (amount, previous, total),
+        (50_000_000, 100_000_000, 150_000_000)
+    );
+    assert_eq!(reason, Symbol::new(&e, "governance"));
 }
 
 #[test]
 fn test_slash_multiple_events() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
 
     // Each slash emits an event
     for i in 1..=3 {
-        let bond = client.slash(&admin, &(100_i128 * i as i128));
-        assert_eq!(bond.slashed_amount, 100_i128 * (i * (i + 1) / 2) as i128);
+        let bond = client.slash(&admin, &identity, &(100_000_000_i128 * i as i128));
+        assert_eq!(
+            bond.slashed_amount,
+            100_000_000_i128 * (i * (i + 1) / 2) as i128
+        );
     }
 }
 
@@ -337,12 +374,12 @@ fn test_withdraw_after_slash_respects_available() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 0);
     let (client, admin, identity) = setup(&e);
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
-    client.slash(&admin, &400_i128);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    client.slash(&admin, &identity, &400_000_000_i128);
     e.ledger().with_mut(|li| li.timestamp = 86401);
-    let bond = client.withdraw(&600_i128);
-    assert_eq!(bond.bonded_amount, 400);
-    assert_eq!(bond.slashed_amount, 400);
+    let bond = client.withdraw(&identity, &600_000_000_i128);
+    assert_eq!(bond.bonded_amount, 400_000_000);
+    assert_eq!(bond.slashed_amount, 400_000_000);
 }
 
 #[test]
@@ -351,10 +388,10 @@ fn test_withdraw_more_than_available_after_slash() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 0);
     let (client, admin, identity) = setup(&e);
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
-    client.slash(&admin, &400_i128);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    client.slash(&admin, &identity, &400_000_000_i128);
     e.ledger().with_mut(|li| li.timestamp = 86401);
-    client.withdraw(&601_i128);
+    client.withdraw(&identity, &601_000_000_i128);
 }
 
 #[test]
@@ -363,14 +400,14 @@ fn test_withdraw_when_fully_slashed() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 0);
     let (client, admin, identity) = setup(&e);
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
 
     // Fully slash the bond
-    client.slash(&admin, &1000_i128);
+    client.slash(&admin, &identity, &1_000_000_000_i128);
 
     e.ledger().with_mut(|li| li.timestamp = 86401);
     // Cannot withdraw anything
-    client.withdraw(&1_i128);
+    client.withdraw(&identity, &1_000_000_i128);
 }
 
 #[test]
@@ -378,12 +415,12 @@ fn test_withdraw_exact_available_balance() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 0);
     let (client, admin, identity) = setup(&e);
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
-    client.slash(&admin, &400_i128);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    client.slash(&admin, &identity, &400_000_000_i128);
     e.ledger().with_mut(|li| li.timestamp = 86401);
-    let bond = client.withdraw(&600_i128);
+    let bond = client.withdraw(&identity, &600_000_000_i128);
 
-    assert_eq!(bond.bonded_amount, 400);
+    assert_eq!(bond.bonded_amount, 400_000_000);
 }
 
 #[test]
@@ -391,19 +428,25 @@ fn test_slash_then_withdraw_then_slash_again() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 0);
     let (client, admin, identity) = setup(&e);
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
 
     // Slash, withdraw, slash again
-    client.slash(&admin, &200_i128);
-    assert_eq!(client.get_identity_state().bonded_amount, 1000);
+    client.slash(&admin, &identity, &200_000_000_i128);
+    assert_eq!(
+        client.get_identity_state(&identity).bonded_amount,
+        1_000_000_000
+    );
 
     e.ledger().with_mut(|li| li.timestamp = 86401);
-    client.withdraw(&300_i128);
-    assert_eq!(client.get_identity_state().bonded_amount, 700);
+    client.withdraw(&identity, &300_000_000_i128);
+    assert_eq!(
+        client.get_identity_state(&identity).bonded_amount,
+        700_000_000
+    );
 
-    let bond = client.slash(&admin, &100_i128);
-    assert_eq!(bond.slashed_amount, 300);
-    assert_eq!(bond.bonded_amount, 700);
+    let bond = client.slash(&admin, &identity, &100_000_000_i128);
+    assert_eq!(bond.slashed_amount, 300_000_000);
+    assert_eq!(bond.bonded_amount, 700_000_000);
 }
 
 #[test]
@@ -411,21 +454,27 @@ fn test_slash_after_partial_withdrawal() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 0);
     let (client, admin, identity) = setup(&e);
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
 
     // Withdraw first
     e.ledger().with_mut(|li| li.timestamp = 86401);
-    client.withdraw(&300_i128);
-    assert_eq!(client.get_identity_state().bonded_amount, 700);
+    client.withdraw(&identity, &300_000_000_i128);
+    assert_eq!(
+        client.get_identity_state(&identity).bonded_amount,
+        700_000_000
+    );
 
     // Then slash
-    let bond = client.slash(&admin, &200_i128);
-    assert_eq!(bond.bonded_amount, 700);
-    assert_eq!(bond.slashed_amount, 200);
+    let bond = client.slash(&admin, &identity, &200_000_000_i128);
+    assert_eq!(bond.bonded_amount, 700_000_000);
+    assert_eq!(bond.slashed_amount, 200_000_000);
 
     // Available should be 700 - 200 = 500 (timestamp already past lock-up)
-    client.withdraw(&500_i128);
-    assert_eq!(client.get_identity_state().bonded_amount, 200);
+    client.withdraw(&identity, &500_000_000_i128);
+    assert_eq!(
+        client.get_identity_state(&identity).bonded_amount,
+        200_000_000
+    );
 }
 
 // ============================================================================
@@ -435,54 +484,60 @@ fn test_slash_after_partial_withdrawal() {
 #[test]
 fn test_cumulative_slash_with_capping() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
 
     // First slash: 600 (cumulative = 600)
-    client.slash(&admin, &600_i128);
-    assert_eq!(client.get_identity_state().slashed_amount, 600);
+    client.slash(&admin, &identity, &600_000_000_i128);
+    assert_eq!(
+        client.get_identity_state(&identity).slashed_amount,
+        600_000_000
+    );
 
     // Second slash: 600 (cumulative would be 1200, capped at 1000)
-    let bond = client.slash(&admin, &600_i128);
-    assert_eq!(bond.slashed_amount, 1000);
+    let bond = client.slash(&admin, &identity, &600_000_000_i128);
+    assert_eq!(bond.slashed_amount, 1_000_000_000);
 }
 
 #[test]
 fn test_cumulative_slash_incremental() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 10000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 10_000_000_000_i128, 86400_u64);
 
     // Slash 10% at a time
     for i in 1..=10 {
-        let bond = client.slash(&admin, &1000_i128);
-        assert_eq!(bond.slashed_amount, (i as i128) * 1000_i128);
+        let bond = client.slash(&admin, &identity, &1_000_000_000_i128);
+        assert_eq!(bond.slashed_amount, (i as i128) * 1_000_000_000_i128);
     }
 }
 
 #[test]
 fn test_full_slash_prevents_further_slashing() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
 
     // Fully slash
-    client.slash(&admin, &1000_i128);
-    assert_eq!(client.get_identity_state().slashed_amount, 1000);
+    client.slash(&admin, &identity, &1_000_000_000_i128);
+    assert_eq!(
+        client.get_identity_state(&identity).slashed_amount,
+        1_000_000_000
+    );
 
     // Attempt further slash (should cap at bonded_amount)
-    let bond = client.slash(&admin, &500_i128);
-    assert_eq!(bond.slashed_amount, 1000);
+    let bond = client.slash(&admin, &identity, &500_000_000_i128);
+    assert_eq!(bond.slashed_amount, 1_000_000_000);
 }
 
 #[test]
 fn test_slash_large_amounts() {
     let e = Env::default();
     let large_amount = 1_000_000_000_000_i128;
-    let (client, admin, _identity) = setup_with_bond(&e, large_amount, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, large_amount, 86400_u64);
 
-    let bond1 = client.slash(&admin, &(large_amount / 4));
+    let bond1 = client.slash(&admin, &identity, &(large_amount / 4));
     assert_eq!(bond1.slashed_amount, large_amount / 4);
 
     // Second slash accumulates
-    let bond2 = client.slash(&admin, &(large_amount / 4));
+    let bond2 = client.slash(&admin, &identity, &(large_amount / 4));
     // The sum should be capped at bonded_amount
     assert_eq!(bond2.slashed_amount, large_amount / 2);
 }
@@ -494,24 +549,24 @@ fn test_slash_large_amounts() {
 #[test]
 fn test_slash_state_persists() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
 
-    client.slash(&admin, &300_i128);
-    let bond1 = client.get_identity_state();
-    assert_eq!(bond1.slashed_amount, 300);
+    client.slash(&admin, &identity, &300_000_000_i128);
+    let bond1 = client.get_identity_state(&identity);
+    assert_eq!(bond1.slashed_amount, 300_000_000);
 
     // Verify again
-    let bond2 = client.get_identity_state();
-    assert_eq!(bond2.slashed_amount, 300);
+    let bond2 = client.get_identity_state(&identity);
+    assert_eq!(bond2.slashed_amount, 300_000_000);
 }
 
 #[test]
 fn test_slash_result_matches_get_state() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
 
-    let slash_result = client.slash(&admin, &250_i128);
-    let state = client.get_identity_state();
+    let slash_result = client.slash(&admin, &identity, &250_000_000_i128);
+    let state = client.get_identity_state(&identity);
 
     assert_eq!(slash_result.slashed_amount, state.slashed_amount);
     assert_eq!(slash_result.bonded_amount, state.bonded_amount);
@@ -525,18 +580,18 @@ fn test_slash_result_matches_get_state() {
 #[should_panic(expected = "not admin")]
 fn test_error_message_not_admin() {
     let e = Env::default();
-    let (client, _admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, _admin, identity) = setup_with_bond(&e, 1_000_000_000_i128, 86400_u64);
 
     let random = Address::generate(&e);
-    client.slash(&random, &100_i128);
+    client.slash(&random, &identity, &100_000_000_i128);
 }
 
 #[test]
 #[should_panic(expected = "no bond")]
 fn test_error_message_no_bond() {
     let e = Env::default();
-    let (client, admin, _identity) = setup(&e);
+    let (client, admin, identity) = setup(&e);
 
     // No bond created, try to slash
-    client.slash(&admin, &100_i128);
+    client.slash(&admin, &identity, &100_000_000_i128);
 }
@@ -0,0 +1,270 @@
+//! Tests for subject notification hooks on attestation add/revoke.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, Env, FromVal, String, Symbol, Vec};
+
+mod receiver {
+    use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Vec};
+
+    #[contracttype]
+    enum Key {
+        Received,
+    }
+
+    /// Records every notification it receives.
+    #[contract]
+    pub struct MockReceiver;
+
+    #[contractimpl]
+    impl MockReceiver {
+        pub fn on_attestation(e: Env, subject: Address, attestation_id: u64, added: bool) {
+            let mut received: Vec<(Address, u64, bool)> = e
+                .storage()
+                .instance()
+                .get(&Key::Received)
+                .unwrap_or(Vec::new(&e));
+            received.push_back((subject, attestation_id, added));
+            e.storage().instance().set(&Key::Received, &received);
+        }
+
+        pub fn received(e: Env) -> Vec<(Address, u64, bool)> {
+            e.storage()
+                .instance()
+                .get(&Key::Received)
+                .unwrap_or(Vec::new(&e))
+        }
+    }
+}
+
+mod panicking {
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    #[contract]
+    pub struct PanickingReceiver;
+
+    #[contractimpl]
+    impl PanickingReceiver {
+        pub fn on_attestation(_e: Env, _subject: Address, _attestation_id: u64, _added: bool) {
+            panic!("receiver rejects");
+        }
+    }
+}
+
+mod flaky {
+    use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+
+    #[contracttype]
+    enum Key {
+        Failing,
+        Delivered,
+    }
+
+    /// Fails while its failing flag is set (a failed call rolls back its own writes, so the
+    /// flag is toggled from outside).
+    #[contract]
+    pub struct FlakyReceiver;
+
+    #[contractimpl]
+    impl FlakyReceiver {
+        pub fn set_failing(e: Env, failing: bool) {
+            e.storage().instance().set(&Key::Failing, &failing);
+        }
+
+        pub fn on_attestation(e: Env, _subject: Address, _attestation_id: u64, _added: bool) {
+            if e.storage().instance().get(&Key::Failing).unwrap_or(false) {
+                panic!("receiver unavailable");
+            }
+            let delivered: u32 = e.storage().instance().get(&Key::Delivered).unwrap_or(0);
+            e.storage()
+                .instance()
+                .set(&Key::Delivered, &(delivered + 1));
+        }
+
+        pub fn delivered(e: Env) -> u32 {
+            e.storage().instance().get(&Key::Delivered).unwrap_or(0)
+        }
+    }
+}
+
+use flaky::{FlakyReceiver, FlakyReceiverClient};
+use panicking::PanickingReceiver;
+use receiver::{MockReceiver, MockReceiverClient};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address) {
+    let (client, _admin, _identity, _token, contract_id) = test_helpers::setup_with_token(e);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    let subject = Address::generate(e);
+    (client, attester, subject, contract_id)
+}
+
+fn attest(e: &Env, client: &CredenceBondClient, attester: &Address, subject: &Address) -> u64 {
+    client
+        .add_attestation(attester, subject, &String::from_str(e, "kyc"))
+        .id
+}
+
+/// Number of `hook_failed` events emitted by the last invocation.
+fn hook_failures(e: &Env, contract_id: &Address) -> usize {
+    e.events()
+        .all()
+        .iter()
+        .filter(|(id, topics, _)| {
+            id == contract_id
+                && Symbol::from_val(e, &topics.get(0).unwrap()) == Symbol::new(e, "hook_failed")
+        })
+        .count()
+}
+
+#[test]
+fn test_hook_receives_add_and_revoke() {
+    let e = Env::default();
+    let (client, attester, subject, _contract_id) = setup(&e);
+    let hook_id = e.register(MockReceiver, ());
+    let hook = MockReceiverClient::new(&e, &hook_id);
+
+    client.register_subject_hook(&subject, &hook_id);
+    assert_eq!(client.get_subject_hook(&subject), Some(hook_id.clone()));
+
+    let id = attest(&e, &client, &attester, &subject);
+    client.revoke_attestation(&attester, &id);
+
+    let expected: Vec<(Address, u64, bool)> = Vec::from_array(
+        &e,
+        [(subject.clone(), id, true), (subject.clone(), id, false)],
+    );
+    assert_eq!(hook.received(), expected);
+}
+
+#[test]
+fn test_other_subjects_not_notified() {
+    let e = Env::default();
+    let (client, attester, subject, _contract_id) = setup(&e);
+    let hook_id = e.register(MockReceiver, ());
+    let hook = MockReceiverClient::new(&e, &hook_id);
+    client.register_subject_hook(&subject, &hook_id);
+
+    attest(&e, &client, &attester, &Address::generate(&e));
+    assert!(hook.received().is_empty());
+}
+
+#[test]
+fn test_panicking_hook_does_not_block_attestations() {
+    let e = Env::default();
+    let (client, attester, subject, contract_id) = setup(&e);
+    let hook_id = e.register(PanickingReceiver, ());
+    client.register_subject_hook(&subject, &hook_id);
+
+    let id = attest(&e, &client, &attester, &subject);
+    assert_eq!(hook_failures(&e, &contract_id), 1);
+    assert!(!client.get_attestation(&id).revoked);
+
+    client.revoke_attestation(&attester, &id);
+    assert_eq!(hook_failures(&e, &contract_id), 1);
+    assert!(client.get_attestation(&id).revoked);
+}
+
+#[test]
+fn test_register_replaces_and_clear_removes() {
+    let e = Env::default();
+    let (client, attester, subject, _contract_id) = setup(&e);
+    let first_id = e.register(MockReceiver, ());
+    let second_id = e.register(MockReceiver, ());
+    let first = MockReceiverClient::new(&e, &first_id);
+    let second = MockReceiverClient::new(&e, &second_id);
+
+    client.register_subject_hook(&subject, &first_id);
+    client.register_subject_hook(&subject, &second_id);
+    attest(&e, &client, &attester, &subject);
+    assert!(first.received().is_empty());
+    assert_eq!(second.received().len(), 1);
+
+    client.clear_subject_hook(&subject);
+    assert_eq!(client.get_subject_hook(&subject), None);
+    client.add_attestation(&attester, &subject, &String::from_str(&e, "aml"));
+    assert_eq!(second.received().len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "hook cannot be this contract")]
+fn test_hook_cannot_be_bond_contract() {
+    let e = Env::default();
+    let (client, _attester, subject, contract_id) = setup(&e);
+    client.register_subject_hook(&subject, &contract_id);
+}
+
+#[test]
+fn test_failed_delivery_retried_until_hook_recovers() {
+    let e = Env::default();
+    let (client, attester, subject, _contract_id) = setup(&e);
+    let hook_id = e.register(FlakyReceiver, ());
+    let hook = FlakyReceiverClient::new(&e, &hook_id);
+    hook.set_failing(&true);
+    client.register_subject_hook(&subject, &hook_id);
+
+    let id = attest(&e, &client, &attester, &subject);
+    assert_eq!(client.retry_failed_callbacks(&10), 0);
+    let queued = client.get_failed_callbacks();
+    assert_eq!(queued.len(), 1);
+    assert_eq!(queued.get(0).unwrap().attestation_id, id);
+    assert_eq!(queued.get(0).unwrap().attempts, 2);
+
+    hook.set_failing(&false);
+    assert_eq!(client.retry_failed_callbacks(&10), 1);
+    assert_eq!(hook.delivered(), 1);
+    assert!(client.get_failed_callbacks().is_empty());
+}
+
+#[test]
+fn test_delivery_dropped_after_max_attempts() {
+    let e = Env::default();
+    let (client, attester, subject, _contract_id) = setup(&e);
+    let hook_id = e.register(PanickingReceiver, ());
+    client.register_subject_hook(&subject, &hook_id);
+    attest(&e, &client, &attester, &subject);
+
+    for attempts in 2..crate::subject_hook::MAX_DELIVERY_ATTEMPTS {
+        client.retry_failed_callbacks(&10);
+        assert_eq!(
+            client.get_failed_callbacks().get(0).unwrap().attempts,
+            attempts
+        );
+    }
+    client.retry_failed_callbacks(&10);
+    assert!(client.get_failed_callbacks().is_empty());
+}
+
+#[test]
+fn test_failed_queue_is_capped() {
+    extern crate std;
+    let e = Env::default();
+    let (client, attester, subject, _contract_id) = setup(&e);
+    let hook_id = e.register(PanickingReceiver, ());
+    client.register_subject_hook(&subject, &hook_id);
+
+    for i in 0..=crate::subject_hook::MAX_FAILED_DELIVERIES {
+        client.add_attestation(
+            &attester,
+            &subject,
+            &String::from_str(&e, &std::format!("claim-{i}")),
+        );
+    }
+    let queued = client.get_failed_callbacks();
+    assert_eq!(queued.len(), crate::subject_hook::MAX_FAILED_DELIVERIES);
+    assert_eq!(queued.get(0).unwrap().attestation_id, 0);
+}
+
+#[test]
+fn test_retry_drops_entries_after_hook_cleared() {
+    let e = Env::default();
+    let (client, attester, subject, _contract_id) = setup(&e);
+    let hook_id = e.register(PanickingReceiver, ());
+    client.register_subject_hook(&subject, &hook_id);
+    attest(&e, &client, &attester, &subject);
+
+    client.clear_subject_hook(&subject);
+    assert_eq!(client.retry_failed_callbacks(&10), 0);
+    assert!(client.get_failed_callbacks().is_empty());
+}
@@ -0,0 +1,85 @@
+//! Tests for the `require_subject_known` attestation policy.
+
+use crate::test_helpers;
+use crate::{BatchBondParams, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String, Vec};
+
+const BOND_AMOUNT: i128 = 10_000_000_000;
+const DURATION: u64 = 86_400;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address) {
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(e);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    (client, admin, identity, attester)
+}
+
+fn attest(e: &Env, client: &CredenceBondClient, attester: &Address, subject: &Address) -> u64 {
+    client
+        .add_attestation(attester, subject, &String::from_str(e, "kyc"))
+        .id
+}
+
+#[test]
+fn test_open_attestations_by_default() {
+    let e = Env::default();
+    let (client, _admin, _identity, attester) = setup(&e);
+    assert!(!client.get_require_subject_known());
+
+    attest(&e, &client, &attester, &Address::generate(&e));
+}
+
+#[test]
+#[should_panic(expected = "unknown subject")]
+fn test_unknown_subject_rejected_when_required() {
+    let e = Env::default();
+    let (client, admin, _identity, attester) = setup(&e);
+    client.set_require_subject_known(&admin, &true);
+    assert!(client.get_require_subject_known());
+
+    attest(&e, &client, &attester, &Address::generate(&e));
+}
+
+#[test]
+fn test_bonded_subject_accepted_when_required() {
+    let e = Env::default();
+    let (client, admin, identity, attester) = setup(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &DURATION, &false, &0);
+    client.set_require_subject_known(&admin, &true);
+
+    attest(&e, &client, &attester, &identity);
+}
+
+#[test]
+fn test_subject_with_only_closed_bond_accepted() {
+    let e = Env::default();
+    let (client, admin, identity, attester) = setup(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &DURATION, &false, &0);
+    client.withdraw_bond_full(&identity);
+    assert_eq!(client.get_closeouts(&identity).len(), 1);
+
+    // Another identity now holds the bond; `identity` is only known historically.
+    let mut list = Vec::new(&e);
+    list.push_back(BatchBondParams {
+        identity: Address::generate(&e),
+        amount: BOND_AMOUNT,
+        duration: DURATION,
+        is_rolling: false,
+        notice_period_duration: 0,
+    });
+    client.create_batch_bonds(&list);
+    client.set_require_subject_known(&admin, &true);
+
+    attest(&e, &client, &attester, &identity);
+}
+
+#[test]
+fn test_disabling_policy_reopens_attestations() {
+    let e = Env::default();
+    let (client, admin, _identity, attester) = setup(&e);
+    client.set_require_subject_known(&admin, &true);
+    client.set_require_subject_known(&admin, &false);
+
+    attest(&e, &client, &attester, &Address::generate(&e));
+}
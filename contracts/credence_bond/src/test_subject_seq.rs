@@ -0,0 +1,69 @@
+//! Tests for per-subject attestation sequence numbers.
+
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    client.initialize(&Address::generate(e));
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    (client, attester)
+}
+
+#[test]
+fn test_sequence_increments_per_subject() {
+    let e = Env::default();
+    let (client, attester) = setup(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    let a1 = client.add_attestation(&attester, &alice, &String::from_str(&e, "a1"));
+    let b1 = client.add_attestation(&attester, &bob, &String::from_str(&e, "b1"));
+    let a2 = client.add_attestation(&attester, &alice, &String::from_str(&e, "a2"));
+
+    assert_eq!((a1.subject_seq, a2.subject_seq), (1, 2));
+    assert_eq!(b1.subject_seq, 1);
+    assert_eq!(b1.id, 1);
+}
+
+#[test]
+fn test_lookup_by_subject_seq() {
+    let e = Env::default();
+    let (client, attester) = setup(&e);
+    let alice = Address::generate(&e);
+    client.add_attestation(
+        &attester,
+        &Address::generate(&e),
+        &String::from_str(&e, "x"),
+    );
+    let second = client.add_attestation(&attester, &alice, &String::from_str(&e, "a1"));
+
+    let found = client.get_attestation_by_subject_seq(&alice, &1);
+    assert_eq!(found.id, second.id);
+    assert!(client
+        .try_get_attestation_by_subject_seq(&alice, &2)
+        .is_err());
+}
+
+#[test]
+fn test_no_gaps_or_reuse_after_revocation() {
+    let e = Env::default();
+    let (client, attester) = setup(&e);
+    let alice = Address::generate(&e);
+
+    let a1 = client.add_attestation(&attester, &alice, &String::from_str(&e, "a1"));
+    let a2 = client.add_attestation(&attester, &alice, &String::from_str(&e, "a2"));
+    client.revoke_attestation(&attester, &a2.id);
+    let a3 = client.add_attestation(&attester, &alice, &String::from_str(&e, "a3"));
+
+    assert_eq!(a3.subject_seq, 3);
+    assert_eq!(client.get_attestation_by_subject_seq(&alice, &1).id, a1.id);
+    let revoked = client.get_attestation_by_subject_seq(&alice, &2);
+    assert_eq!(revoked.id, a2.id);
+    assert!(revoked.revoked);
+    assert_eq!(client.get_attestation_by_subject_seq(&alice, &3).id, a3.id);
+}
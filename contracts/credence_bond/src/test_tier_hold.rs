@@ -0,0 +1,69 @@
+//! Tests for the tier minimum-hold duration and effective tier.
+
+use crate::test_helpers;
+use crate::tiered_bond::{TIER_BRONZE_MAX, TIER_SILVER_MAX};
+use crate::BondTier;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+const HOLD: u64 = 3_600;
+const DURATION: u64 = 86_400;
+
+fn set_time(e: &Env, t: u64) {
+    e.ledger().with_mut(|li| li.timestamp = t);
+}
+
+#[test]
+fn test_no_hold_effective_matches_raw() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &TIER_SILVER_MAX, &DURATION, &false, &0_u64);
+    assert_eq!(client.get_tier_hold_duration(), 0);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Gold);
+    assert_eq!(
+        client.get_effective_tier(&Address::generate(&e)),
+        BondTier::Bronze
+    );
+}
+
+#[test]
+fn test_upward_flash_not_effective_until_hold_elapses() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.set_tier_hold_duration(&admin, &HOLD);
+    set_time(&e, 1_000);
+    client.create_bond(&identity, &TIER_BRONZE_MAX, &DURATION, &false, &0_u64);
+
+    // Raw tier is unchanged for compatibility.
+    assert_eq!(client.get_tier(&identity), BondTier::Silver);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Bronze);
+
+    // Top up and withdraw within the hold: the upgrade never becomes effective.
+    set_time(&e, 2_000);
+    client.top_up(&identity, &identity, &(TIER_SILVER_MAX - TIER_BRONZE_MAX));
+    assert_eq!(client.get_tier(&identity), BondTier::Gold);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Bronze);
+
+    set_time(&e, 2_000 + HOLD - 1);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Bronze);
+    set_time(&e, 2_000 + HOLD);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Gold);
+}
+
+#[test]
+fn test_downgrade_is_immediate() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.set_tier_hold_duration(&admin, &HOLD);
+    set_time(&e, 1_000);
+    client.create_bond(&identity, &TIER_SILVER_MAX, &DURATION, &false, &0_u64);
+    set_time(&e, 1_000 + DURATION);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Gold);
+
+    client.withdraw(&identity, &(TIER_SILVER_MAX - TIER_BRONZE_MAX));
+    assert_eq!(client.get_tier(&identity), BondTier::Silver);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Silver);
+
+    client.withdraw(&identity, &1);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Bronze);
+}
@@ -27,7 +27,7 @@ fn test_get_tier_after_create_bond() {
     let e = Env::default();
     let (client, _admin, identity, ..) = setup(&e);
     client.create_bond(&identity, &(TIER_SILVER_MAX), &86400_u64, &false, &0_u64);
-    let tier = client.get_tier();
+    let tier = client.get_tier(&identity);
     assert_eq!(tier, BondTier::Gold);
 }
 
@@ -36,9 +36,9 @@ fn test_tier_upgrade_on_top_up() {
     let e = Env::default();
     let (client, _admin, identity, ..) = setup(&e);
     client.create_bond(&identity, &(TIER_BRONZE_MAX), &86400_u64, &false, &0_u64);
-    assert_eq!(client.get_tier(), BondTier::Silver);
-    client.top_up(&(TIER_SILVER_MAX - TIER_BRONZE_MAX));
-    assert_eq!(client.get_tier(), BondTier::Gold);
+    assert_eq!(client.get_tier(&identity), BondTier::Silver);
+    client.top_up(&identity, &identity, &(TIER_SILVER_MAX - TIER_BRONZE_MAX));
+    assert_eq!(client.get_tier(&identity), BondTier::Gold);
 }
 
 #[test]
@@ -47,11 +47,11 @@ fn test_tier_downgrade_on_withdraw() {
     e.ledger().with_mut(|li| li.timestamp = 0);
     let (client, _admin, identity, ..) = setup(&e);
     client.create_bond(&identity, &(TIER_GOLD_MAX), &86400_u64, &false, &0_u64);
-    assert_eq!(client.get_tier(), BondTier::Platinum);
+    assert_eq!(client.get_tier(&identity), BondTier::Platinum);
     e.ledger().with_mut(|li| li.timestamp = 86401);
     let withdraw_to_silver = TIER_GOLD_MAX - TIER_SILVER_MAX + 1;
-    client.withdraw(&withdraw_to_silver);
-    assert_eq!(client.get_tier(), BondTier::Silver);
+    client.withdraw(&identity, &withdraw_to_silver);
+    assert_eq!(client.get_tier(&identity), BondTier::Silver);
 }
 
 #[test]
@@ -65,7 +65,7 @@ fn test_tier_unchanged_within_threshold() {
         &false,
         &0_u64,
     );
-    assert_eq!(client.get_tier(), BondTier::Bronze);
-    client.top_up(&(TIER_BRONZE_MAX / 2 - 1));
-    assert_eq!(client.get_tier(), BondTier::Bronze);
+    assert_eq!(client.get_tier(&identity), BondTier::Bronze);
+    client.top_up(&identity, &identity, &(TIER_BRONZE_MAX / 2 - 1));
+    assert_eq!(client.get_tier(&identity), BondTier::Bronze);
 }
@@ -0,0 +1,119 @@
+//! Tests for top-up sponsors: a granted sponsor pays for top-ups, an ungranted or revoked one
+//! cannot, and a sponsor gains no withdrawal rights.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, IntoVal};
+
+const AMOUNT: i128 = 10_000_000;
+const TOP_UP: i128 = 2_000_000;
+const DURATION: u64 = 86_400;
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    identity: Address,
+    sponsor: Address,
+    token: TokenClient<'a>,
+    contract_id: Address,
+}
+
+/// A bond plus a funded sponsor that has approved the contract but holds no grant yet.
+fn setup(e: &Env) -> Setup<'_> {
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (client, _admin, identity, token_id, contract_id) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    let sponsor = Address::generate(e);
+    StellarAssetClient::new(e, &token_id).mint(&sponsor, &AMOUNT);
+    let token = TokenClient::new(e, &token_id);
+    let expiration = e.ledger().sequence().saturating_add(10_000);
+    token.approve(&sponsor, &contract_id, &AMOUNT, &expiration);
+    Setup {
+        client,
+        identity,
+        sponsor,
+        token,
+        contract_id,
+    }
+}
+
+#[test]
+fn test_authorized_sponsor_can_top_up() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.authorize_topup_sponsor(&s.identity, &s.sponsor);
+    assert!(s.client.is_topup_sponsor(&s.identity, &s.sponsor));
+
+    let identity_before = s.token.balance(&s.identity);
+    let bond = s.client.top_up(&s.sponsor, &s.identity, &TOP_UP);
+    assert_eq!(bond.identity, s.identity);
+    assert_eq!(bond.bonded_amount, AMOUNT + TOP_UP);
+    assert_eq!(s.token.balance(&s.sponsor), AMOUNT - TOP_UP);
+    assert_eq!(s.token.balance(&s.identity), identity_before);
+}
+
+#[test]
+#[should_panic(expected = "not an authorized sponsor")]
+fn test_unauthorized_sponsor_cannot_top_up() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.top_up(&s.sponsor, &s.identity, &TOP_UP);
+}
+
+#[test]
+fn test_revoked_sponsor_cannot_top_up() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.authorize_topup_sponsor(&s.identity, &s.sponsor);
+    s.client.top_up(&s.sponsor, &s.identity, &TOP_UP);
+    s.client.revoke_topup_sponsor(&s.identity, &s.sponsor);
+
+    assert!(!s.client.is_topup_sponsor(&s.identity, &s.sponsor));
+    assert!(s
+        .client
+        .try_top_up(&s.sponsor, &s.identity, &TOP_UP)
+        .is_err());
+    assert_eq!(
+        s.client.get_identity_state(&s.identity).bonded_amount,
+        AMOUNT + TOP_UP
+    );
+}
+
+#[test]
+fn test_sponsor_cannot_withdraw() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.authorize_topup_sponsor(&s.identity, &s.sponsor);
+    s.client.top_up(&s.sponsor, &s.identity, &TOP_UP);
+    e.ledger().with_mut(|li| li.timestamp += DURATION);
+
+    // Only the sponsor signs; the holder's auth is not mocked.
+    e.mock_auths(&[MockAuth {
+        address: &s.sponsor,
+        invoke: &MockAuthInvoke {
+            contract: &s.contract_id,
+            fn_name: "withdraw_bond",
+            args: (s.identity.clone(), TOP_UP).into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(s.client.try_withdraw_bond(&s.identity, &TOP_UP).is_err());
+    assert_eq!(s.token.balance(&s.sponsor), AMOUNT - TOP_UP);
+}
+
+#[test]
+#[should_panic(expected = "not a sponsor")]
+fn test_revoke_unknown_sponsor_panics() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.revoke_topup_sponsor(&s.identity, &s.sponsor);
+}
+
+#[test]
+#[should_panic(expected = "cannot sponsor self")]
+fn test_cannot_sponsor_self() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.authorize_topup_sponsor(&s.identity, &s.identity);
+}
@@ -0,0 +1,146 @@
+//! Tests for slash requests against closed bonds: execution records an uncollectable slash, and
+//! a re-bond is docked only inside the configured window.
+
+use crate::slash_history::{self, SlashRecord};
+use crate::test_helpers;
+use crate::{BondStatus, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+const AMOUNT: i128 = 10_000_000;
+const SLASH: i128 = 1_000_000;
+const DURATION: u64 = 86_400;
+const WINDOW: u64 = 7 * 86_400;
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    admin: Address,
+    identity: Address,
+    governor: Address,
+}
+
+/// A bond that was fully withdrawn after its lock-up, with a one-governor council.
+fn closed_bond(e: &Env) -> Setup<'_> {
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(e);
+    let governor = Address::generate(e);
+    client.initialize_governance(&admin, &Vec::from_array(e, [governor.clone()]), &5_100, &1);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    e.ledger().with_mut(|li| li.timestamp += DURATION);
+    let bond = client.withdraw_bond(&identity, &AMOUNT);
+    assert_eq!(bond.status, BondStatus::Closed);
+    Setup {
+        client,
+        admin,
+        identity,
+        governor,
+    }
+}
+
+fn history(e: &Env, s: &Setup) -> Vec<SlashRecord> {
+    e.as_contract(&s.client.address, || {
+        slash_history::get_slash_history(e, &s.identity)
+    })
+}
+
+fn execute_request(s: &Setup) -> u64 {
+    let id = s.client.propose_slash(&s.admin, &s.identity, &SLASH);
+    s.client.governance_vote(&s.governor, &id, &true);
+    s.client.execute_slash_with_governance(&s.admin, &id);
+    id
+}
+
+#[test]
+fn test_request_against_closed_bond_is_uncollectable() {
+    let e = Env::default();
+    let s = closed_bond(&e);
+    let id = s.client.propose_slash(&s.admin, &s.identity, &SLASH);
+    s.client.governance_vote(&s.governor, &id, &true);
+    let bond = s.client.execute_slash_with_governance(&s.admin, &id);
+    assert_eq!(bond.status, BondStatus::Closed);
+    assert_eq!(bond.slashed_amount, 0);
+
+    let records = history(&e, &s);
+    assert_eq!(records.len(), 1);
+    let record = records.get_unchecked(0);
+    assert_eq!(record.slash_amount, SLASH);
+    assert_eq!(record.applied, 0);
+    assert_eq!(record.uncollectable, SLASH);
+    assert_eq!(record.reason, Symbol::new(&e, "uncollectable"));
+    assert_eq!(s.client.get_pending_uncollectable(&s.identity), SLASH);
+
+    let journal = s.client.get_journal(&s.identity, &0, &50);
+    let last = journal.get_unchecked(journal.len() - 1);
+    assert_eq!(last.kind, Symbol::new(&e, "slash_uncollectable"));
+    assert_eq!(last.ref_id, id);
+}
+
+#[test]
+fn test_request_against_removed_bond_is_uncollectable() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    let governor = Address::generate(&e);
+    client.initialize_governance(&admin, &Vec::from_array(&e, [governor.clone()]), &5_100, &1);
+    client.create_bond(&identity, &AMOUNT, &DURATION, &false, &0_u64);
+    client.slash(&admin, &identity, &AMOUNT);
+    client.close_bond(&identity);
+
+    let s = Setup {
+        client,
+        admin,
+        identity,
+        governor,
+    };
+    execute_request(&s);
+    assert_eq!(s.client.get_pending_uncollectable(&s.identity), SLASH);
+    let records = history(&e, &s);
+    assert_eq!(records.get_unchecked(1).uncollectable, SLASH);
+}
+
+#[test]
+fn test_rebond_within_window_is_docked() {
+    let e = Env::default();
+    let s = closed_bond(&e);
+    s.client.set_uncollectable_rebond_window(&s.admin, &WINDOW);
+    execute_request(&s);
+
+    e.ledger().with_mut(|li| li.timestamp += WINDOW);
+    let bond = s
+        .client
+        .create_bond(&s.identity, &AMOUNT, &DURATION, &false, &0_u64);
+    assert_eq!(bond.bonded_amount, AMOUNT);
+    assert_eq!(bond.slashed_amount, SLASH);
+    assert_eq!(s.client.get_pending_uncollectable(&s.identity), 0);
+    let record = history(&e, &s).get_unchecked(1);
+    assert_eq!(record.applied, SLASH);
+    assert_eq!(record.reason, Symbol::new(&e, "rebond_dock"));
+}
+
+#[test]
+fn test_rebond_outside_window_is_not_docked() {
+    let e = Env::default();
+    let s = closed_bond(&e);
+    s.client.set_uncollectable_rebond_window(&s.admin, &WINDOW);
+    execute_request(&s);
+
+    e.ledger().with_mut(|li| li.timestamp += WINDOW + 1);
+    let bond = s
+        .client
+        .create_bond(&s.identity, &AMOUNT, &DURATION, &false, &0_u64);
+    assert_eq!(bond.slashed_amount, 0);
+    assert_eq!(s.client.get_pending_uncollectable(&s.identity), 0);
+}
+
+#[test]
+fn test_rebond_not_docked_without_window() {
+    let e = Env::default();
+    let s = closed_bond(&e);
+    assert_eq!(s.client.get_uncollectable_rebond_window(), 0);
+    execute_request(&s);
+
+    let bond = s
+        .client
+        .create_bond(&s.identity, &AMOUNT, &DURATION, &false, &0_u64);
+    assert_eq!(bond.slashed_amount, 0);
+}
@@ -0,0 +1,118 @@
+//! Tests for subject-scoped retrieval of valid attestations.
+
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, String, Symbol};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let client = CredenceBondClient::new(e, &e.register(CredenceBond, ()));
+    client.initialize(&Address::generate(e));
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    (client, attester)
+}
+
+#[test]
+fn test_only_valid_attestations_returned_across_pages() {
+    let e = Env::default();
+    let (client, attester) = setup(&e);
+    let subject = Address::generate(&e);
+    let add = |data: &str| client.add_attestation(&attester, &subject, &String::from_str(&e, data));
+
+    let revoked_first = add("revoked-1");
+    let valid_a = add("valid-a");
+    let revoked_second = add("revoked-2");
+    let valid_b = add("valid-b");
+    client.revoke_attestation(&attester, &revoked_first.id);
+    client.revoke_attestation(&attester, &revoked_second.id);
+    assert_eq!(client.get_subject_attestations(&subject).len(), 4);
+
+    let all = client.get_valid_attestations(&subject, &0, &10);
+    assert_eq!(all.len(), 2);
+    assert_eq!(all.get(0).unwrap().id, valid_a.id);
+    assert_eq!(all.get(1).unwrap().id, valid_b.id);
+
+    let first = client.get_valid_attestations(&subject, &0, &1);
+    let second = client.get_valid_attestations(&subject, &1, &1);
+    let third = client.get_valid_attestations(&subject, &2, &1);
+    assert_eq!(first.get(0).unwrap().id, valid_a.id);
+    assert_eq!(second.get(0).unwrap().id, valid_b.id);
+    assert!(third.is_empty());
+}
+
+#[test]
+fn test_unknown_subject_has_no_valid_attestations() {
+    let e = Env::default();
+    let (client, _attester) = setup(&e);
+    assert!(client
+        .get_valid_attestations(&Address::generate(&e), &0, &10)
+        .is_empty());
+}
+
+#[test]
+#[should_panic(expected = "page size too large")]
+fn test_page_size_capped() {
+    let e = Env::default();
+    let (client, _attester) = setup(&e);
+    client.get_valid_attestations(&Address::generate(&e), &0, &101);
+}
+
+#[test]
+fn test_validity_window_boundaries() {
+    let e = Env::default();
+    let (client, attester) = setup(&e);
+    let subject = Address::generate(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let att = client.add_attestation(&attester, &subject, &String::from_str(&e, "kyc"));
+
+    e.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.revoke_attestation(&attester, &att.id);
+    assert_eq!(client.get_attestation(&att.id).revoked_at, 2_000);
+
+    assert!(!client.was_valid_at(&att.id, &999));
+    assert!(client.was_valid_at(&att.id, &1_000));
+    assert!(client.was_valid_at(&att.id, &1_999));
+    assert!(!client.was_valid_at(&att.id, &2_000));
+    assert!(!client.was_valid_at(&att.id, &u64::MAX));
+}
+
+#[test]
+fn test_unrevoked_attestation_valid_indefinitely() {
+    let e = Env::default();
+    let (client, attester) = setup(&e);
+    let subject = Address::generate(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let att = client.add_attestation(&attester, &subject, &String::from_str(&e, "kyc"));
+
+    assert_eq!(att.revoked_at, 0);
+    assert!(client.was_valid_at(&att.id, &1_000));
+    assert!(client.was_valid_at(&att.id, &u64::MAX));
+}
+
+#[test]
+fn test_admin_revocation_records_time() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let client = CredenceBondClient::new(&e, &e.register(CredenceBond, ()));
+    let admin = Address::generate(&e);
+    client.initialize(&admin);
+    let attester = Address::generate(&e);
+    client.register_attester(&attester);
+    let subject = Address::generate(&e);
+    let att = client.add_attestation(&attester, &subject, &String::from_str(&e, "kyc"));
+
+    e.ledger().with_mut(|li| li.timestamp = 500);
+    client.revoke_attestation_by_admin(&admin, &att.id, &Symbol::new(&e, "fraud"));
+    assert_eq!(client.get_attestation(&att.id).revoked_at, 500);
+    assert!(client.was_valid_at(&att.id, &499));
+    assert!(!client.was_valid_at(&att.id, &500));
+}
+
+#[test]
+#[should_panic(expected = "attestation not found")]
+fn test_was_valid_at_unknown_attestation() {
+    let e = Env::default();
+    let (client, _attester) = setup(&e);
+    client.was_valid_at(&42, &0);
+}
@@ -5,8 +5,8 @@
 
 extern crate std;
 
+use crate::events::EVENT_SCHEMA_VERSION;
 use crate::test_helpers::setup_with_token;
-use crate::*;
 use soroban_sdk::testutils::{Address as _, Events};
 use soroban_sdk::token::TokenClient;
 use soroban_sdk::{vec, Address, Env, IntoVal, Symbol, TryFromVal};
@@ -37,7 +37,11 @@ fn set_and_get_verifier_stake_requirement_emits_event() {
     client.set_verifier_stake_requirement(&admin, &1_000i128);
     let events = e.events().all();
 
-    let topics = vec![&e, Symbol::new(&e, "verifier_config_updated").into_val(&e)];
+    let topics = vec![
+        &e,
+        Symbol::new(&e, "verifier_config_updated").into_val(&e),
+        EVENT_SCHEMA_VERSION.into_val(&e),
+    ];
     assert_eq!(count_event_topics(&events, &contract_id, &topics), 1);
     assert_eq!(client.get_verifier_stake_requirement(), 1_000i128);
 }
@@ -70,6 +74,7 @@ fn register_verifier_transfers_stake_sets_active() {
     let topics = vec![
         &e,
         Symbol::new(&e, "verifier_registered").into_val(&e),
+        EVENT_SCHEMA_VERSION.into_val(&e),
         verifier.clone().into_val(&e),
     ];
     assert_eq!(count_event_topics(&events, &contract_id, &topics), 1);
@@ -142,8 +147,7 @@ fn deactivate_verifier_prevents_attestation_and_allows_withdrawal() {
     // Attest once successfully.
     let subject = Address::generate(&e);
     let data = soroban_sdk::String::from_str(&e, "ok");
-    let nonce = client.get_nonce(&verifier);
-    client.add_attestation(&verifier, &subject, &data, &nonce);
+    client.add_attestation(&verifier, &subject, &data);
 
     // Deactivate.
     client.deactivate_verifier(&verifier);
@@ -155,7 +159,6 @@ fn deactivate_verifier_prevents_attestation_and_allows_withdrawal() {
             &verifier,
             &subject,
             &soroban_sdk::String::from_str(&e, "should fail"),
-            &client.get_nonce(&verifier),
         );
     }));
     assert!(result.is_err());
@@ -219,8 +222,7 @@ fn verifier_reputation_updates_on_attestation_and_revocation() {
 
     let subject = Address::generate(&e);
     let data = soroban_sdk::String::from_str(&e, "rep");
-    let nonce0 = client.get_nonce(&verifier);
-    let att = client.add_attestation(&verifier, &subject, &data, &nonce0);
+    let att = client.add_attestation(&verifier, &subject, &data);
     assert_eq!(att.weight, 1_000u32);
 
     let after_add = client.get_verifier_info(&verifier).unwrap();
@@ -228,8 +230,7 @@ fn verifier_reputation_updates_on_attestation_and_revocation() {
     assert_eq!(after_add.attestations_issued, 1);
     assert_eq!(after_add.attestations_revoked, 0);
 
-    let nonce1 = client.get_nonce(&verifier);
-    client.revoke_attestation(&verifier, &att.id, &nonce1);
+    client.revoke_attestation(&verifier, &att.id);
 
     let after_revoke = client.get_verifier_info(&verifier).unwrap();
     assert_eq!(after_revoke.reputation, 0i128);
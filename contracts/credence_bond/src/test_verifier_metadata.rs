@@ -0,0 +1,92 @@
+//! Tests for verifier public metadata: publishing, the describe view, admin clearing and URI
+//! length validation.
+
+use crate::test_helpers;
+use crate::verifier::MAX_METADATA_URI_LEN;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, String};
+
+#[test]
+fn test_set_metadata_visible_through_describe() {
+    let e = Env::default();
+    let (client, ..) = test_helpers::setup_with_token(&e);
+    let verifier = Address::generate(&e);
+    client.register_attester(&verifier);
+    let name_hash = BytesN::from_array(&e, &[7; 32]);
+    let uri = String::from_str(&e, "https://example.org/policy.pdf");
+
+    client.set_verifier_metadata(&verifier, &name_hash, &uri);
+
+    let description = client.describe_verifier(&verifier).unwrap();
+    assert!(description.info.active);
+    let metadata = description.metadata;
+    assert_eq!(metadata.name_hash, name_hash);
+    assert_eq!(metadata.uri, uri);
+}
+
+#[test]
+fn test_metadata_allowed_while_inactive() {
+    let e = Env::default();
+    let (client, ..) = test_helpers::setup_with_token(&e);
+    let verifier = Address::generate(&e);
+    client.register_attester(&verifier);
+    client.deactivate_verifier(&verifier);
+
+    let uri = String::from_str(&e, "ipfs://policy");
+    client.set_verifier_metadata(&verifier, &BytesN::from_array(&e, &[1; 32]), &uri);
+
+    let description = client.describe_verifier(&verifier).unwrap();
+    assert!(!description.info.active);
+    assert_eq!(description.metadata.uri, uri);
+}
+
+#[test]
+fn test_admin_clears_metadata() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    let verifier = Address::generate(&e);
+    client.register_attester(&verifier);
+    client.set_verifier_metadata(
+        &verifier,
+        &BytesN::from_array(&e, &[2; 32]),
+        &String::from_str(&e, "https://example.org"),
+    );
+
+    client.clear_verifier_metadata(&admin, &verifier);
+
+    let description = client.describe_verifier(&verifier).unwrap();
+    assert_eq!(description.metadata.updated_at, 0);
+    assert_eq!(description.metadata.uri, String::from_str(&e, ""));
+}
+
+#[test]
+fn test_describe_unknown_verifier_is_none() {
+    let e = Env::default();
+    let (client, ..) = test_helpers::setup_with_token(&e);
+    assert_eq!(client.describe_verifier(&Address::generate(&e)), None);
+}
+
+#[test]
+#[should_panic(expected = "metadata uri too long")]
+fn test_uri_length_bounded() {
+    let e = Env::default();
+    let (client, ..) = test_helpers::setup_with_token(&e);
+    let verifier = Address::generate(&e);
+    client.register_attester(&verifier);
+
+    let long = [b'a'; MAX_METADATA_URI_LEN as usize + 1];
+    let uri = String::from_bytes(&e, &long);
+    client.set_verifier_metadata(&verifier, &BytesN::from_array(&e, &[3; 32]), &uri);
+}
+
+#[test]
+#[should_panic(expected = "verifier not registered")]
+fn test_metadata_requires_registration() {
+    let e = Env::default();
+    let (client, ..) = test_helpers::setup_with_token(&e);
+    client.set_verifier_metadata(
+        &Address::generate(&e),
+        &BytesN::from_array(&e, &[4; 32]),
+        &String::from_str(&e, "https://example.org"),
+    );
+}
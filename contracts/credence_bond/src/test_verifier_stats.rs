@@ -0,0 +1,105 @@
+//! Tests for the verifier cohort aggregates: a three-verifier scenario through registration,
+//! top-up, deactivation, withdrawal and reactivation must match values recomputed from the
+//! per-verifier records.
+
+use crate::test_helpers;
+use crate::verifier::VerifierStats;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, String, Symbol};
+
+const MINT: i128 = 1_000_000;
+
+fn funded_verifier(e: &Env, token_id: &Address, contract_id: &Address) -> Address {
+    let verifier = Address::generate(e);
+    StellarAssetClient::new(e, token_id).mint(&verifier, &MINT);
+    let expiration = e.ledger().sequence().saturating_add(10_000);
+    TokenClient::new(e, token_id).approve(&verifier, contract_id, &MINT, &expiration);
+    verifier
+}
+
+/// Aggregates recomputed from each verifier's own record.
+fn recompute(client: &CredenceBondClient, verifiers: &[&Address]) -> VerifierStats {
+    let mut stats = VerifierStats {
+        active_count: 0,
+        active_stake: 0,
+        total_stake: 0,
+        min_active_stake: 0,
+        max_active_stake: 0,
+        attestations_issued: 0,
+        attestations_revoked: 0,
+    };
+    let mut active_stakes = [0_i128; 3];
+    for verifier in verifiers {
+        let info = client.get_verifier_info(verifier).unwrap();
+        stats.total_stake += info.stake;
+        stats.attestations_issued += u64::from(info.attestations_issued);
+        stats.attestations_revoked += u64::from(info.attestations_revoked);
+        if info.active {
+            active_stakes[stats.active_count as usize] = info.stake;
+            stats.active_count += 1;
+            stats.active_stake += info.stake;
+        }
+    }
+    let active = &active_stakes[..stats.active_count as usize];
+    stats.min_active_stake = active.iter().copied().min().unwrap_or(0);
+    stats.max_active_stake = active.iter().copied().max().unwrap_or(0);
+    stats
+}
+
+#[test]
+fn test_stats_match_per_verifier_records() {
+    let e = Env::default();
+    let (client, admin, _identity, token_id, contract_id) = test_helpers::setup_with_token(&e);
+    let v1 = funded_verifier(&e, &token_id, &contract_id);
+    let v2 = funded_verifier(&e, &token_id, &contract_id);
+    let v3 = funded_verifier(&e, &token_id, &contract_id);
+    let all = [&v1, &v2, &v3];
+    let subject = Address::generate(&e);
+
+    client.register_verifier(&v1, &1_000);
+    client.register_verifier(&v2, &3_000);
+    client.register_verifier(&v3, &2_000);
+    assert_eq!(client.get_verifier_stats(), recompute(&client, &all));
+
+    // Top-up of an active verifier.
+    client.register_verifier(&v1, &500);
+    let attestation = client.add_attestation(&v1, &subject, &String::from_str(&e, "kyc"));
+    client.add_attestation(&v3, &subject, &String::from_str(&e, "aml"));
+    client.revoke_attestation_by_admin(&admin, &attestation.id, &Symbol::new(&e, "fraud"));
+    assert_eq!(client.get_verifier_stats(), recompute(&client, &all));
+
+    // Deactivation and partial withdrawal.
+    client.deactivate_verifier(&v2);
+    client.withdraw_verifier_stake(&v2, &1_000);
+    let stats = client.get_verifier_stats();
+    assert_eq!(stats, recompute(&client, &all));
+    assert_eq!(stats.active_count, 2);
+    assert_eq!(stats.active_stake, 3_500);
+    assert_eq!(stats.total_stake, 5_500);
+    assert_eq!(stats.min_active_stake, 1_500);
+    assert_eq!(stats.max_active_stake, 2_000);
+    assert_eq!(stats.attestations_issued, 2);
+    assert_eq!(stats.attestations_revoked, 1);
+
+    // Reactivation with an extra deposit, and an admin deactivation.
+    client.register_verifier(&v2, &2_500);
+    client.deactivate_verifier_by_admin(&admin, &v3);
+    let stats = client.get_verifier_stats();
+    assert_eq!(stats, recompute(&client, &all));
+    assert_eq!(stats.active_count, 2);
+    assert_eq!(stats.min_active_stake, 1_500);
+    assert_eq!(stats.max_active_stake, 4_500);
+}
+
+#[test]
+fn test_empty_stats() {
+    let e = Env::default();
+    let (client, ..) = test_helpers::setup_with_token(&e);
+    let stats = client.get_verifier_stats();
+    assert_eq!(stats.active_count, 0);
+    assert_eq!(stats.total_stake, 0);
+    assert_eq!(stats.min_active_stake, 0);
+    assert_eq!(stats.max_active_stake, 0);
+}
@@ -0,0 +1,124 @@
+//! Tests that `withdraw` and `withdraw_early` require the bond holder's auth. Negative cases
+//! authorize only the caller's own invocation with `mock_auths`, so nothing is auto-approved.
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke};
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, Env, IntoVal};
+
+const BOND_AMOUNT: i128 = 10_000_000;
+const WITHDRAW_AMOUNT: i128 = 4_000_000;
+const DURATION: u64 = 86_400;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, TokenClient<'_>, Address) {
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (client, admin, identity, token, contract_id) = test_helpers::setup_with_token(e);
+    client.set_early_exit_config(&admin, &Address::generate(e), &1_000_u32);
+    client.create_bond(&identity, &BOND_AMOUNT, &DURATION, &false, &0_u64);
+    (client, identity, TokenClient::new(e, &token), contract_id)
+}
+
+/// Authorizes exactly one call to `fn_name(identity, amount)` signed by `signer`.
+fn authorize(
+    e: &Env,
+    contract_id: &Address,
+    signer: &Address,
+    fn_name: &str,
+    identity: &Address,
+    amount: i128,
+) {
+    e.mock_auths(&[MockAuth {
+        address: signer,
+        invoke: &MockAuthInvoke {
+            contract: contract_id,
+            fn_name,
+            args: (identity.clone(), amount).into_val(e),
+            sub_invokes: &[],
+        },
+    }]);
+}
+
+#[test]
+fn test_stranger_cannot_withdraw() {
+    let e = Env::default();
+    let (client, identity, token, contract_id) = setup(&e);
+    let stranger = Address::generate(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1_000 + DURATION);
+
+    authorize(
+        &e,
+        &contract_id,
+        &stranger,
+        "withdraw",
+        &identity,
+        WITHDRAW_AMOUNT,
+    );
+    assert!(client.try_withdraw(&identity, &WITHDRAW_AMOUNT).is_err());
+    assert_eq!(token.balance(&stranger), 0);
+    assert_eq!(
+        client.get_identity_state(&identity).bonded_amount,
+        BOND_AMOUNT
+    );
+}
+
+#[test]
+fn test_stranger_cannot_withdraw_early() {
+    let e = Env::default();
+    let (client, identity, token, contract_id) = setup(&e);
+    let stranger = Address::generate(&e);
+
+    authorize(
+        &e,
+        &contract_id,
+        &stranger,
+        "withdraw_early",
+        &identity,
+        WITHDRAW_AMOUNT,
+    );
+    assert!(client
+        .try_withdraw_early(&identity, &WITHDRAW_AMOUNT)
+        .is_err());
+    assert_eq!(token.balance(&stranger), 0);
+    assert_eq!(
+        client.get_identity_state(&identity).bonded_amount,
+        BOND_AMOUNT
+    );
+}
+
+#[test]
+fn test_owner_can_withdraw() {
+    let e = Env::default();
+    let (client, identity, token, contract_id) = setup(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1_000 + DURATION);
+    let before = token.balance(&identity);
+
+    authorize(
+        &e,
+        &contract_id,
+        &identity,
+        "withdraw",
+        &identity,
+        WITHDRAW_AMOUNT,
+    );
+    let bond = client.withdraw(&identity, &WITHDRAW_AMOUNT);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT - WITHDRAW_AMOUNT);
+    assert_eq!(token.balance(&identity) - before, WITHDRAW_AMOUNT);
+}
+
+#[test]
+fn test_owner_can_withdraw_early() {
+    let e = Env::default();
+    let (client, identity, _token, contract_id) = setup(&e);
+
+    authorize(
+        &e,
+        &contract_id,
+        &identity,
+        "withdraw_early",
+        &identity,
+        WITHDRAW_AMOUNT,
+    );
+    let bond = client.withdraw_early(&identity, &WITHDRAW_AMOUNT);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT - WITHDRAW_AMOUNT);
+}
@@ -4,9 +4,9 @@
 
 use crate::test_helpers;
 use crate::CredenceBondClient;
-use soroban_sdk::testutils::Ledger;
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
 use soroban_sdk::token::TokenClient;
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Env, FromVal, Symbol};
 
 fn setup_with_token(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address, Address) {
     test_helpers::setup_with_token(e)
@@ -18,11 +18,11 @@ fn test_withdraw_bond_after_lockup_non_rolling() {
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin, identity, _token_id, _bond_id) = setup_with_token(&e);
 
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
 
     e.ledger().with_mut(|li| li.timestamp = 87401);
-    let bond = client.withdraw_bond(&500);
-    assert_eq!(bond.bonded_amount, 500);
+    let bond = client.withdraw_bond(&identity, &500_000_000);
+    assert_eq!(bond.bonded_amount, 500_000_000);
 }
 
 #[test]
@@ -32,37 +32,37 @@ fn test_withdraw_bond_before_lockup_panics() {
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin, identity, _token_id, _bond_id) = setup_with_token(&e);
 
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
 
     e.ledger().with_mut(|li| li.timestamp = 44200);
-    client.withdraw_bond(&500);
+    client.withdraw_bond(&identity, &500_000_000);
 }
 
 #[test]
-#[should_panic(expected = "cooldown window not elapsed; request_withdrawal first")]
+#[should_panic(expected = "notice period not elapsed")]
 fn test_withdraw_bond_rolling_before_notice_panics() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin, identity, _token_id, _bond_id) = setup_with_token(&e);
 
-    client.create_bond(&identity, &1000_i128, &86400_u64, &true, &10_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
     e.ledger().with_mut(|li| li.timestamp = 1101);
 
-    client.withdraw_bond(&500);
+    client.withdraw_bond(&identity, &500_000_000);
 }
 
 #[test]
-#[should_panic(expected = "cooldown window not elapsed; request_withdrawal first")]
+#[should_panic(expected = "notice period not elapsed")]
 fn test_withdraw_bond_rolling_before_cooldown_panics() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin, identity, _token_id, _bond_id) = setup_with_token(&e);
 
-    client.create_bond(&identity, &1000_i128, &86400_u64, &true, &10_u64);
-    client.request_withdrawal();
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
+    client.request_withdrawal(&identity);
     e.ledger().with_mut(|li| li.timestamp = 1005);
 
-    client.withdraw_bond(&500);
+    client.withdraw_bond(&identity, &500_000_000);
 }
 
 #[test]
@@ -71,12 +71,12 @@ fn test_withdraw_bond_rolling_after_cooldown() {
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin, identity, _token_id, _bond_id) = setup_with_token(&e);
 
-    client.create_bond(&identity, &1000_i128, &86400_u64, &true, &10_u64);
-    client.request_withdrawal();
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
+    client.request_withdrawal(&identity);
     e.ledger().with_mut(|li| li.timestamp = 1011);
 
-    let bond = client.withdraw_bond(&500);
-    assert_eq!(bond.bonded_amount, 500);
+    let bond = client.withdraw_bond(&identity, &500_000_000);
+    assert_eq!(bond.bonded_amount, 500_000_000);
 }
 
 #[test]
@@ -85,14 +85,14 @@ fn test_withdraw_bond_partial_withdrawal() {
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin, identity, _token_id, _bond_id) = setup_with_token(&e);
 
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
     e.ledger().with_mut(|li| li.timestamp = 87401);
 
-    let bond = client.withdraw_bond(&300);
-    assert_eq!(bond.bonded_amount, 700);
-    let bond = client.withdraw_bond(&200);
-    assert_eq!(bond.bonded_amount, 500);
-    let bond = client.withdraw_bond(&500);
+    let bond = client.withdraw_bond(&identity, &300_000_000);
+    assert_eq!(bond.bonded_amount, 700_000_000);
+    let bond = client.withdraw_bond(&identity, &200_000_000);
+    assert_eq!(bond.bonded_amount, 500_000_000);
+    let bond = client.withdraw_bond(&identity, &500_000_000);
     assert_eq!(bond.bonded_amount, 0);
 }
 
@@ -103,10 +103,10 @@ fn test_withdraw_bond_insufficient_balance() {
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin, identity, _token_id, _bond_id) = setup_with_token(&e);
 
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
     e.ledger().with_mut(|li| li.timestamp = 87401);
 
-    client.withdraw_bond(&1001);
+    client.withdraw_bond(&identity, &1_001_000_000);
 }
 
 #[test]
@@ -115,13 +115,13 @@ fn test_withdraw_bond_after_slash() {
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, admin, identity, _token_id, _bond_id) = setup_with_token(&e);
 
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
-    client.slash(&admin, &400);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    client.slash(&admin, &identity, &400_000_000);
     e.ledger().with_mut(|li| li.timestamp = 87401);
 
-    let bond = client.withdraw_bond(&600);
-    assert_eq!(bond.bonded_amount, 400);
-    assert_eq!(bond.slashed_amount, 400);
+    let bond = client.withdraw_bond(&identity, &600_000_000);
+    assert_eq!(bond.bonded_amount, 400_000_000);
+    assert_eq!(bond.slashed_amount, 400_000_000);
 }
 
 #[test]
@@ -130,11 +130,11 @@ fn test_withdraw_bond_zero_amount() {
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin, identity, _token_id, _bond_id) = setup_with_token(&e);
 
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
     e.ledger().with_mut(|li| li.timestamp = 87401);
 
-    let bond = client.withdraw_bond(&0);
-    assert_eq!(bond.bonded_amount, 1000);
+    let bond = client.withdraw_bond(&identity, &0);
+    assert_eq!(bond.bonded_amount, 1_000_000_000);
 }
 
 #[test]
@@ -143,10 +143,10 @@ fn test_withdraw_bond_full_withdrawal() {
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin, identity, token_id, bond_contract_id) = setup_with_token(&e);
 
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
     e.ledger().with_mut(|li| li.timestamp = 87401);
 
-    let bond = client.withdraw_bond(&1000);
+    let bond = client.withdraw_bond(&identity, &1_000_000_000);
     assert_eq!(bond.bonded_amount, 0);
 
     let token_client = TokenClient::new(&e, &token_id);
@@ -160,9 +160,119 @@ fn test_withdraw_alias_calls_withdraw_bond() {
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin, identity, _token_id, _bond_id) = setup_with_token(&e);
 
-    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
     e.ledger().with_mut(|li| li.timestamp = 87401);
 
-    let bond = client.withdraw(&500);
-    assert_eq!(bond.bonded_amount, 500);
+    let bond = client.withdraw(&identity, &500_000_000);
+    assert_eq!(bond.bonded_amount, 500_000_000);
+}
+
+#[test]
+fn test_withdraw_bond_partial_keeps_bond_active() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin, identity, _token_id, _bond_id) = setup_with_token(&e);
+
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    e.ledger().with_mut(|li| li.timestamp = 87401);
+
+    let bond = client.withdraw_bond(&identity, &999_000_000);
+    assert!(bond.active);
+    assert_eq!(bond.bonded_amount, 1_000_000);
+    assert!(client.get_identity_state(&identity).active);
+}
+
+#[test]
+fn test_withdraw_bond_full_deactivates_bond() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, admin, identity, _token_id, _bond_id) = setup_with_token(&e);
+
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    client.slash(&admin, &identity, &400_000_000);
+    e.ledger().with_mut(|li| li.timestamp = 87401);
+
+    let bond = client.withdraw_bond(&identity, &600_000_000);
+    assert!(!bond.active);
+    assert!(!client.get_identity_state(&identity).active);
+    assert!(client.try_top_up(&identity, &identity, &100).is_err());
+}
+
+#[test]
+fn test_withdraw_bond_tier_change_emitted_once() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin, identity, _token_id, bond_id) = setup_with_token(&e);
+
+    client.create_bond(&identity, &2_000_000_000_i128, &86400_u64, &false, &0_u64);
+    e.ledger().with_mut(|li| li.timestamp = 87401);
+
+    let bond = client.withdraw_bond(&identity, &1_500_000_000);
+    assert!(bond.active);
+    let tier_changes = e
+        .events()
+        .all()
+        .iter()
+        .filter(|(id, topics, _)| {
+            *id == bond_id
+                && Symbol::from_val(&e, &topics.get(0).unwrap()) == Symbol::new(&e, "tier_changed")
+        })
+        .count();
+    assert_eq!(tier_changes, 1);
+}
+
+#[test]
+fn test_withdraw_bond_lockup_boundary() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin, identity, _token_id, _bond_id) = setup_with_token(&e);
+
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+
+    // One second before bond_start + bond_duration the bond is still locked.
+    e.ledger().with_mut(|li| li.timestamp = 87399);
+    assert!(client.try_withdraw_bond(&identity, &500).is_err());
+    assert_eq!(
+        client.get_identity_state(&identity).bonded_amount,
+        1_000_000_000
+    );
+
+    e.ledger().with_mut(|li| li.timestamp = 87400);
+    let bond = client.withdraw_bond(&identity, &500_000_000);
+    assert_eq!(bond.bonded_amount, 500_000_000);
+}
+
+#[test]
+fn test_withdraw_early_available_during_lockup() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, admin, identity, _token_id, _bond_id) = setup_with_token(&e);
+    client.set_early_exit_config(&admin, &Address::generate(&e), &1000_u32);
+
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    e.ledger().with_mut(|li| li.timestamp = 1001);
+    assert!(client.try_withdraw_bond(&identity, &1000).is_err());
+
+    let bond = client.withdraw_early(&identity, &500_000_000);
+    assert_eq!(bond.bonded_amount, 500_000_000);
+}
+
+#[test]
+fn test_withdraw_bond_rolling_notice_boundary() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin, identity, _token_id, _bond_id) = setup_with_token(&e);
+
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &10_u64);
+    // Past the lock-up, but no withdrawal was requested.
+    e.ledger().with_mut(|li| li.timestamp = 90000);
+    assert!(client.try_withdraw_bond(&identity, &500).is_err());
+
+    client.request_withdrawal(&identity);
+    e.ledger().with_mut(|li| li.timestamp = 90009);
+    assert!(client.try_withdraw_bond(&identity, &500).is_err());
+
+    e.ledger().with_mut(|li| li.timestamp = 90010);
+    let bond = client.withdraw_bond(&identity, &500_000_000);
+    assert_eq!(bond.bonded_amount, 500_000_000);
 }
@@ -3,9 +3,15 @@
 //! Assigns identity tiers (Bronze, Silver, Gold, Platinum) based on bonded amount thresholds.
 //! Supports tier upgrade on bond increase and tier downgrade on partial withdrawal.
 //! Emits tier change events when tier changes.
+//!
+//! To stop identities from topping up just before a tier-gated interaction and withdrawing right
+//! after, an optional hold duration delays upgrades of the *effective* tier: after an upward
+//! transition the previous effective tier is kept until the new raw tier has been held for the
+//! hold duration. Downgrades apply immediately. The raw tier (`get_tier_for_amount`) is
+//! unchanged; integrations gating on tier should read the effective tier.
 
 use crate::BondTier;
-use soroban_sdk::Env;
+use soroban_sdk::{contracttype, Address, Env};
 
 /// Tier thresholds (in smallest unit, e.g. 6 decimals for USDC).
 /// Bronze: [0, BRONZE_MAX), Silver: [BRONZE_MAX, SILVER_MAX), Gold: [SILVER_MAX, GOLD_MAX), Platinum: [GOLD_MAX, ..)
@@ -27,17 +33,118 @@ pub fn get_tier_for_amount(amount: i128) -> BondTier {
     }
 }
 
-/// Emits a tier change event if the tier changed.
+/// Effective tier kept after an upward transition until the hold elapses.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierHold {
+    /// Effective tier before the upgrade.
+    pub previous: BondTier,
+    /// Timestamp of the upward transition (`tier_since`).
+    pub since: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum TierKey {
+    HoldDuration,
+    Hold(Address),
+}
+
+/// Ordering of tiers, Bronze lowest.
+#[must_use]
+pub fn rank(tier: &BondTier) -> u32 {
+    match tier {
+        BondTier::Bronze => 0,
+        BondTier::Silver => 1,
+        BondTier::Gold => 2,
+        BondTier::Platinum => 3,
+    }
+}
+
+/// @notice Seconds a new, higher tier must be held before it becomes effective (0 = disabled).
+#[must_use]
+pub fn get_hold_duration(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&TierKey::HoldDuration)
+        .unwrap_or(0)
+}
+
+/// @notice Sets the tier hold duration. Admin only (enforced by caller).
+pub fn set_hold_duration(e: &Env, secs: u64) {
+    e.storage().instance().set(&TierKey::HoldDuration, &secs);
+    crate::events::publish(e, "tier_hold_duration_set", secs);
+}
+
+/// @notice Hold still in force for `identity`, if any.
+#[must_use]
+pub fn get_active_hold(e: &Env, identity: &Address) -> Option<TierHold> {
+    let hold: TierHold = e
+        .storage()
+        .instance()
+        .get(&TierKey::Hold(identity.clone()))?;
+    let ends = hold.since.saturating_add(get_hold_duration(e));
+    if e.ledger().timestamp() < ends {
+        Some(hold)
+    } else {
+        None
+    }
+}
+
+/// @notice Effective tier for `identity` whose raw tier is `raw`: the held previous tier while
+/// an upgrade hold is in force, otherwise `raw`.
+#[must_use]
+pub fn get_effective_tier(e: &Env, identity: &Address, raw: BondTier) -> BondTier {
+    match get_active_hold(e, identity) {
+        Some(hold) if rank(&hold.previous) < rank(&raw) => hold.previous,
+        _ => raw,
+    }
+}
+
+/// Emits a tier change event if the tier changed, and updates the upgrade hold.
+///
+/// `tier_changed` reports raw transitions. `tier_upgrade_pending` (identity, tier, effective_at)
+/// is emitted when an upgrade is held, and `effective_tier_changed` when the effective tier
+/// changes immediately (downgrades, or upgrades with no hold configured). A held upgrade becomes
+/// effective at `effective_at` without a further event.
 pub fn emit_tier_change_if_needed(
     e: &Env,
-    identity: &soroban_sdk::Address,
+    identity: &Address,
     old_tier: BondTier,
     new_tier: BondTier,
 ) {
-    if core::mem::discriminant(&old_tier) != core::mem::discriminant(&new_tier) {
-        e.events().publish(
-            (soroban_sdk::Symbol::new(e, "tier_changed"),),
-            (identity.clone(), new_tier),
+    if old_tier == new_tier {
+        return;
+    }
+    crate::events::publish(e, "tier_changed", (identity.clone(), new_tier.clone()));
+
+    let before = get_effective_tier(e, identity, old_tier.clone());
+    let hold_duration = get_hold_duration(e);
+    let key = TierKey::Hold(identity.clone());
+    if rank(&new_tier) > rank(&old_tier) && hold_duration > 0 {
+        let since = e.ledger().timestamp();
+        e.storage().instance().set(
+            &key,
+            &TierHold {
+                previous: before.clone(),
+                since,
+            },
         );
+        crate::events::publish(
+            e,
+            "tier_upgrade_pending",
+            (
+                identity.clone(),
+                new_tier.clone(),
+                since.saturating_add(hold_duration),
+            ),
+        );
+    } else if rank(&new_tier) <= rank(&before) || hold_duration == 0 {
+        e.storage().instance().remove(&key);
+    }
+
+    let after = get_effective_tier(e, identity, new_tier);
+    if after != before {
+        crate::events::publish(e, "effective_tier_changed", (identity.clone(), after));
     }
 }
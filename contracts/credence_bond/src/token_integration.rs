@@ -45,10 +45,7 @@ pub fn set_usdc_token(e: &Env, admin: &Address, token: &Address, network: &Strin
     }
     set_token(e, admin, token);
     e.storage().instance().set(&network_key(e), network);
-    e.events().publish(
-        (Symbol::new(e, "usdc_token_set"),),
-        (token.clone(), network.clone()),
-    );
+    crate::events::publish(e, "usdc_token_set", (token.clone(), network.clone()));
 }
 
 /// @notice Returns the configured token address.
@@ -60,14 +57,42 @@ pub fn get_token(e: &Env) -> Address {
         .unwrap_or_else(|| panic!("token not set"))
 }
 
+/// @notice Returns true once a token has been configured.
+#[must_use]
+pub fn has_token(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::Token)
+}
+
 /// @notice Returns the configured USDC network label if set.
 pub fn get_usdc_network(e: &Env) -> Option<String> {
     e.storage().instance().get(&network_key(e))
 }
 
-/// @notice Checks if owner has enough allowance for the contract to spend amount.
-/// @dev Uses token allowance(owner, spender) where spender is the bond contract.
-pub fn require_allowance(e: &Env, owner: &Address, amount: i128) {
+/// @notice Best-effort pre-flight for `spender` pulling `amount` of `token` from `from` with
+/// `transfer_from`, so a short allowance or balance fails with a readable message instead of
+/// an opaque token-contract error. The transfer itself stays the source of truth.
+///
+/// # Panics
+/// - "insufficient allowance: have X need Y" if `from` approved `spender` for less than `amount`.
+/// - "insufficient balance: have X need Y" if `from` holds less than `amount`.
+pub fn require_funds(e: &Env, token: &Address, from: &Address, spender: &Address, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    let client = TokenClient::new(e, token);
+    let allowance = client.allowance(from, spender);
+    if allowance < amount {
+        panic!("insufficient allowance: have {} need {}", allowance, amount);
+    }
+    let balance = client.balance(from);
+    if balance < amount {
+        panic!("insufficient balance: have {} need {}", balance, amount);
+    }
+}
+
+/// @notice `require_funds` for the bond contract pulling `amount` of the configured token from
+/// `owner`.
+pub fn require_owner_funds(e: &Env, owner: &Address, amount: i128) {
     if amount < 0 {
         panic!("amount must be non-negative");
     }
@@ -75,10 +100,13 @@ pub fn require_allowance(e: &Env, owner: &Address, amount: i128) {
         return;
     }
     let contract = e.current_contract_address();
-    let allowance = token_client(e).allowance(owner, &contract);
-    if allowance < amount {
-        panic!("insufficient token allowance");
-    }
+    require_funds(e, &get_token(e), owner, &contract, amount);
+}
+
+/// @notice Returns the bond contract's own token balance.
+pub fn contract_balance(e: &Env) -> i128 {
+    let contract = e.current_contract_address();
+    token_client(e).balance(&contract)
 }
 
 /// @notice Transfers tokens from owner into the bond contract.
@@ -91,7 +119,8 @@ pub fn transfer_into_contract(e: &Env, owner: &Address, amount: i128) {
         return;
     }
 
-    require_allowance(e, owner, amount);
+    require_owner_funds(e, owner, amount);
+    crate::failpoints::hit(e, crate::failpoints::TOKEN_TRANSFER);
     let contract = e.current_contract_address();
     token_client(e).transfer_from(&contract, owner, &contract, &amount);
 }
@@ -106,6 +135,7 @@ pub fn transfer_from_contract(e: &Env, recipient: &Address, amount: i128) {
         return;
     }
 
+    crate::failpoints::hit(e, crate::failpoints::TOKEN_TRANSFER);
     let contract = e.current_contract_address();
     token_client(e).transfer(&contract, recipient, &amount);
 }
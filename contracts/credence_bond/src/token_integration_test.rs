@@ -67,7 +67,7 @@ fn test_create_bond_moves_tokens_into_contract() {
     let balance_before = token_client.balance(&identity);
     let contract_before = token_client.balance(&bond_contract_id);
 
-    let amount = 2_500_i128;
+    let amount = 2_500_000_i128;
     client.create_bond(&identity, &amount, &86400_u64, &false, &0_u64);
 
     let balance_after = token_client.balance(&identity);
@@ -78,7 +78,7 @@ fn test_create_bond_moves_tokens_into_contract() {
 }
 
 #[test]
-#[should_panic(expected = "insufficient token allowance")]
+#[should_panic(expected = "insufficient allowance")]
 fn test_create_bond_without_approval_panics() {
     let e = Env::default();
     e.mock_all_auths();
@@ -95,10 +95,59 @@ fn test_create_bond_without_approval_panics() {
         .address();
     let stellar_asset = StellarAssetClient::new(&e, &token_id);
     stellar_asset.set_authorized(&identity, &true);
-    stellar_asset.mint(&identity, &10_000_i128);
+    stellar_asset.mint(&identity, &10_000_000_i128);
 
     client.set_token(&admin, &token_id);
-    client.create_bond(&identity, &1_000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_i128, &86400_u64, &false, &0_u64);
+}
+
+#[test]
+fn test_create_bond_with_rolling_moves_tokens_into_contract() {
+    let e = Env::default();
+    let (client, _admin, identity, token_id, bond_contract_id) = test_helpers::setup_with_token(&e);
+
+    let token_client = TokenClient::new(&e, &token_id);
+    let identity_before = token_client.balance(&identity);
+    let contract_before = token_client.balance(&bond_contract_id);
+
+    let amount = 3_000_000_i128;
+    client.create_bond_with_rolling(&identity, &amount, &86400_u64, &true, &60_u64);
+
+    assert_eq!(identity_before - token_client.balance(&identity), amount);
+    assert_eq!(
+        token_client.balance(&bond_contract_id) - contract_before,
+        amount
+    );
+}
+
+#[test]
+fn test_create_bond_requires_identity_auth() {
+    let e = Env::default();
+    let (client, _admin, identity, token_id, bond_contract_id) = test_helpers::setup_with_token(&e);
+    e.set_auths(&[]);
+
+    let token_client = TokenClient::new(&e, &token_id);
+    let identity_before = token_client.balance(&identity);
+
+    let result = client.try_create_bond(&identity, &2_000_000_i128, &86400_u64, &false, &0_u64);
+    assert!(result.is_err());
+    assert_eq!(token_client.balance(&identity), identity_before);
+    assert_eq!(token_client.balance(&bond_contract_id), 0);
+}
+
+#[test]
+#[should_panic(expected = "token not set")]
+fn test_create_bond_without_token_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(&e, &contract_id);
+
+    let admin = Address::generate(&e);
+    let identity = Address::generate(&e);
+    client.initialize(&admin);
+    client.create_bond(&identity, &2_000_000_i128, &86400_u64, &false, &0_u64);
 }
 
 #[test]
@@ -135,7 +184,7 @@ fn test_get_usdc_token_without_configuration_panics() {
 }
 
 #[test]
-#[should_panic(expected = "insufficient token allowance")]
+#[should_panic(expected = "insufficient allowance")]
 fn test_top_up_requires_remaining_allowance() {
     let e = Env::default();
     e.mock_all_auths();
@@ -151,15 +200,15 @@ fn test_top_up_requires_remaining_allowance() {
         .address();
     let stellar_asset = StellarAssetClient::new(&e, &token_id);
     stellar_asset.set_authorized(&identity, &true);
-    stellar_asset.mint(&identity, &10_000_i128);
+    stellar_asset.mint(&identity, &10_000_000_i128);
 
     let token_client = TokenClient::new(&e, &token_id);
     let expiration = e.ledger().sequence().saturating_add(10_000);
-    token_client.approve(&identity, &contract_id, &1_000_i128, &expiration);
+    token_client.approve(&identity, &contract_id, &1_000_000_i128, &expiration);
 
     client.set_token(&admin, &token_id);
-    client.create_bond(&identity, &1_000_i128, &86400_u64, &false, &0_u64);
-    client.top_up(&1_i128);
+    client.create_bond(&identity, &1_000_000_i128, &86400_u64, &false, &0_u64);
+    client.top_up(&identity, &identity, &1_000_000_i128);
 }
 
 #[test]
@@ -168,29 +217,60 @@ fn test_withdraw_transfers_tokens_back_to_identity() {
     e.ledger().with_mut(|li| li.timestamp = 1_000);
     let (client, _admin, identity, token_id, bond_contract_id) = test_helpers::setup_with_token(&e);
 
-    client.create_bond(&identity, &1_000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_i128, &86400_u64, &false, &0_u64);
 
     let token_client = TokenClient::new(&e, &token_id);
     let identity_before = token_client.balance(&identity);
     let contract_before = token_client.balance(&bond_contract_id);
 
     e.ledger().with_mut(|li| li.timestamp = 87_401);
-    client.withdraw_bond(&400_i128);
+    client.withdraw_bond(&identity, &400_000_i128);
 
     let identity_after = token_client.balance(&identity);
     let contract_after = token_client.balance(&bond_contract_id);
 
-    assert_eq!(identity_after - identity_before, 400);
-    assert_eq!(contract_before - contract_after, 400);
+    assert_eq!(identity_after - identity_before, 400_000);
+    assert_eq!(contract_before - contract_after, 400_000);
 }
 
 #[test]
-#[should_panic(expected = "amount must be non-negative")]
+#[should_panic(expected = "amount must be positive")]
 fn test_top_up_negative_amount_panics() {
     let e = Env::default();
     let (client, _admin, identity, _token_id, _bond_id) = test_helpers::setup_with_token(&e);
-    client.create_bond(&identity, &1_000_i128, &86400_u64, &false, &0_u64);
-    client.top_up(&-1_i128);
+    client.create_bond(&identity, &1_000_000_i128, &86400_u64, &false, &0_u64);
+    client.top_up(&identity, &identity, &-1_i128);
+}
+
+#[test]
+#[should_panic(expected = "amount must be positive")]
+fn test_top_up_zero_amount_panics() {
+    let e = Env::default();
+    let (client, _admin, identity, _token_id, _bond_id) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &1_000_000_i128, &86400_u64, &false, &0_u64);
+    client.top_up(&identity, &identity, &0_i128);
+}
+
+#[test]
+fn test_top_up_paid_by_sponsor() {
+    let e = Env::default();
+    let (client, _admin, identity, token_id, bond_contract_id) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &1_000_000_i128, &86400_u64, &false, &0_u64);
+
+    let sponsor = Address::generate(&e);
+    StellarAssetClient::new(&e, &token_id).mint(&sponsor, &5_000_000_i128);
+    let token_client = TokenClient::new(&e, &token_id);
+    let expiration = e.ledger().sequence().saturating_add(10_000);
+    token_client.approve(&sponsor, &bond_contract_id, &5_000_000_i128, &expiration);
+
+    client.authorize_topup_sponsor(&identity, &sponsor);
+    let identity_before = token_client.balance(&identity);
+    let bond = client.top_up(&sponsor, &identity, &2_000_000_i128);
+
+    assert_eq!(bond.identity, identity);
+    assert_eq!(bond.bonded_amount, 3_000_000);
+    assert_eq!(token_client.balance(&sponsor), 3_000_000);
+    assert_eq!(token_client.balance(&identity), identity_before);
 }
 
 #[test]
@@ -199,7 +279,7 @@ fn test_withdraw_negative_amount_panics() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 1_000);
     let (client, _admin, identity, _token_id, _bond_id) = test_helpers::setup_with_token(&e);
-    client.create_bond(&identity, &1_000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_i128, &86400_u64, &false, &0_u64);
     e.ledger().with_mut(|li| li.timestamp = 87_401);
-    client.withdraw_bond(&-1_i128);
+    client.withdraw_bond(&identity, &-1_i128);
 }
@@ -0,0 +1,83 @@
+//! Top-up Sponsors
+//!
+//! A bond holder can grant a sponsor (typically an organization paying for its members' bonds)
+//! a standing right to top up their bond. The sponsor pays with its own tokens while the bond,
+//! and everything withdrawn from it, stays with the holder. Without a grant, `top_up` only
+//! accepts the holder as payer.
+//!
+//! Grants are stored per `(identity, sponsor)` pair and hold no amount limit.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum SponsorKey {
+    TopupSponsor(Address, Address),
+}
+
+fn key(identity: &Address, sponsor: &Address) -> SponsorKey {
+    SponsorKey::TopupSponsor(identity.clone(), sponsor.clone())
+}
+
+/// @notice True if `sponsor` may top up `identity`'s bond.
+#[must_use]
+pub fn is_sponsor(e: &Env, identity: &Address, sponsor: &Address) -> bool {
+    e.storage().instance().has(&key(identity, sponsor))
+}
+
+/// @notice Lets `sponsor` top up `identity`'s bond (owner auth enforced by the caller). Emits
+/// `topup_sponsor_authorized` (topic: identity) with `sponsor`.
+///
+/// # Panics
+/// - "cannot sponsor self" if `sponsor` is `identity`
+pub fn authorize(e: &Env, identity: &Address, sponsor: &Address) {
+    if identity == sponsor {
+        panic!("cannot sponsor self");
+    }
+    e.storage().instance().set(&key(identity, sponsor), &true);
+    crate::events::publish_for(
+        e,
+        "topup_sponsor_authorized",
+        identity.clone(),
+        sponsor.clone(),
+    );
+}
+
+/// @notice Withdraws `sponsor`'s right to top up `identity`'s bond (owner auth enforced by the
+/// caller). Emits `topup_sponsor_revoked` (topic: identity) with `sponsor`.
+///
+/// # Panics
+/// - "not a sponsor" if no grant exists
+pub fn revoke(e: &Env, identity: &Address, sponsor: &Address) {
+    if !is_sponsor(e, identity, sponsor) {
+        panic!("not a sponsor");
+    }
+    e.storage().instance().remove(&key(identity, sponsor));
+    crate::events::publish_for(
+        e,
+        "topup_sponsor_revoked",
+        identity.clone(),
+        sponsor.clone(),
+    );
+}
+
+/// @notice Checks that `payer` may fund a top-up of `identity`'s bond: the holder always may, a
+/// sponsor only with a grant. A sponsored top-up emits `topup_sponsor_used` (topic: identity)
+/// with `(sponsor, amount)`.
+///
+/// # Panics
+/// - "not an authorized sponsor"
+pub fn require_payer(e: &Env, identity: &Address, payer: &Address, amount: i128) {
+    if payer == identity {
+        return;
+    }
+    if !is_sponsor(e, identity, payer) {
+        panic!("not an authorized sponsor");
+    }
+    crate::events::publish_for(
+        e,
+        "topup_sponsor_used",
+        identity.clone(),
+        (payer.clone(), amount),
+    );
+}
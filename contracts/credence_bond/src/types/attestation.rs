@@ -4,7 +4,7 @@
 //! subject (identity), timestamp, weight. Supports serialization via ContractType
 //! and validation methods for storage efficiency and safety.
 
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol};
 
 /// Maximum allowed attestation weight (prevents overflow and caps influence).
 pub const MAX_ATTESTATION_WEIGHT: u32 = 1_000_000;
@@ -22,6 +22,18 @@ pub const DEFAULT_ATTESTATION_WEIGHT: u32 = 1;
 /// * `weight` - Credibility weight (e.g. derived from attester bond); capped by protocol.
 /// * `attestation_data` - Opaque attestation payload (e.g. claim type or hash).
 /// * `revoked` - Whether this attestation has been revoked.
+/// * `revoked_at` - Ledger timestamp of the revocation (0 while not revoked).
+/// * `subject_seq` - 1-based ordinal of this attestation among those issued for the subject.
+/// * `use_count` - Times allow-listed consumer contracts reported relying on this attestation.
+/// * `data_hash` - sha256 commitment to an off-chain payload for hash-only attestations
+///   (`attestation_data` is then empty).
+/// * `expires_at` - Ledger timestamp after which the attestation lapses (0 = never).
+/// * `flags` - Bit set reserved for attestation flags (0 = none).
+/// * `consented` - Whether the subject has acknowledged the attestation.
+/// * `revocation_reason` - Reason code given when the attestation was revoked, if any.
+///
+/// This is the V2 layout. Records written before it existed are stored as bare
+/// `AttestationV1` values and are upgraded on read (see `attestation_store`).
 ///
 /// # Serialization
 /// Uses `#[contracttype]` for Soroban instance storage; space-efficient (u64, u32, bool, Address, String).
@@ -35,6 +47,14 @@ pub struct Attestation {
     pub weight: u32,
     pub attestation_data: String,
     pub revoked: bool,
+    pub revoked_at: u64,
+    pub subject_seq: u32,
+    pub use_count: u32,
+    pub data_hash: Option<BytesN<32>>,
+    pub expires_at: u64,
+    pub flags: u32,
+    pub consented: bool,
+    pub revocation_reason: Option<Symbol>,
 }
 
 impl Attestation {
@@ -67,6 +87,66 @@ impl Attestation {
     pub fn is_active(&self) -> bool {
         !self.revoked
     }
+
+    /// Returns true if this attestation was in force at `timestamp`: issued at or before it and
+    /// not yet revoked (`timestamp < revoked_at`). Attestations carry no expiry, so an
+    /// unrevoked attestation stays valid indefinitely. A revoked record without a revocation
+    /// time is never considered valid.
+    #[must_use]
+    #[inline]
+    pub fn was_valid_at(&self, timestamp: u64) -> bool {
+        self.timestamp <= timestamp && (!self.revoked || timestamp < self.revoked_at)
+    }
+}
+
+/// Attestation layout before `expires_at`, `flags`, `consented` and `revocation_reason` were
+/// added. Only used to decode records stored by earlier contract versions.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationV1 {
+    pub id: u64,
+    pub verifier: Address,
+    pub identity: Address,
+    pub timestamp: u64,
+    pub weight: u32,
+    pub attestation_data: String,
+    pub revoked: bool,
+    pub revoked_at: u64,
+    pub subject_seq: u32,
+    pub use_count: u32,
+    pub data_hash: Option<BytesN<32>>,
+}
+
+impl AttestationV1 {
+    /// Converts to the current layout with the V2 fields at their defaults: no expiry, no
+    /// flags, not consented, no revocation reason.
+    #[must_use]
+    pub fn upgrade(self) -> Attestation {
+        Attestation {
+            id: self.id,
+            verifier: self.verifier,
+            identity: self.identity,
+            timestamp: self.timestamp,
+            weight: self.weight,
+            attestation_data: self.attestation_data,
+            revoked: self.revoked,
+            revoked_at: self.revoked_at,
+            subject_seq: self.subject_seq,
+            use_count: self.use_count,
+            data_hash: self.data_hash,
+            expires_at: 0,
+            flags: 0,
+            consented: false,
+            revocation_reason: None,
+        }
+    }
+}
+
+/// Version-tagged attestation as written to storage. Untagged values are `AttestationV1`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AttestationRecord {
+    V2(Attestation),
 }
 
 /// Key used to detect duplicate attestations: same verifier, identity, and data.
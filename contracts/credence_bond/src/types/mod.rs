@@ -1,9 +1,11 @@
 //! Protocol data types for bonds and attestations.
 //!
-//! Includes Attestation (with weight), validation, and deduplication key types.
+//! Includes Attestation (with weight), its versioned storage forms, validation, and
+//! deduplication key types.
 
 pub mod attestation;
 
 pub use attestation::{
-    Attestation, AttestationDedupKey, DEFAULT_ATTESTATION_WEIGHT, MAX_ATTESTATION_WEIGHT,
+    Attestation, AttestationDedupKey, AttestationRecord, AttestationV1, DEFAULT_ATTESTATION_WEIGHT,
+    MAX_ATTESTATION_WEIGHT,
 };
@@ -0,0 +1,161 @@
+//! Uncollectable Slashes
+//!
+//! A slash request can still be executed after its target has wound down its bond (fully
+//! withdrawn, fully slashed or removed with `close_bond`). Nothing is left to take, so execution
+//! moves no funds. Instead it appends a `SlashRecord` with `applied = 0` and
+//! `uncollectable = amount` to the slash history, and emits `slash_uncollectable` against the
+//! most recent close-out epoch, so the identity's record carries the mark.
+//!
+//! The uncollectable amount is also kept as a pending dock. If the admin has set a re-bond
+//! window, the next bond the identity opens within that many seconds of the record has the
+//! pending amount (capped at the new bond) marked slashed at creation. A re-bond outside the
+//! window, or with the window at 0 (the default), is not docked. Either way the pending amount
+//! is cleared by the re-bond.
+
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::closeout::{self, BondCloseout};
+use crate::{BondKind, BondStatus, DataKey, IdentityBond};
+
+/// Uncollectable amount waiting for the identity's next bond.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct PendingDock {
+    amount: i128,
+    /// When the latest uncollectable slash was recorded.
+    recorded_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum UncollectableKey {
+    /// Seconds after an uncollectable slash during which a re-bond is docked (0 = never).
+    RebondWindow,
+    Pending(Address),
+}
+
+/// @notice Seconds after an uncollectable slash during which a re-bond is docked (0 = never).
+#[must_use]
+pub fn get_rebond_window(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&UncollectableKey::RebondWindow)
+        .unwrap_or(0)
+}
+
+/// @notice Sets the re-bond docking window (admin check enforced by the caller). Emits
+/// `uncollectable_window_set`.
+pub fn set_rebond_window(e: &Env, secs: u64) {
+    e.storage()
+        .instance()
+        .set(&UncollectableKey::RebondWindow, &secs);
+    crate::events::publish(e, "uncollectable_window_set", secs);
+}
+
+/// @notice Uncollectable amount that the identity's next bond may be docked, if any.
+#[must_use]
+pub fn get_pending(e: &Env, identity: &Address) -> i128 {
+    e.storage()
+        .instance()
+        .get::<_, PendingDock>(&UncollectableKey::Pending(identity.clone()))
+        .map_or(0, |p| p.amount)
+}
+
+fn latest_closeout(e: &Env, identity: &Address) -> Option<(u32, BondCloseout)> {
+    let epoch = closeout::get_epoch(e, identity).checked_sub(1)?;
+    e.storage()
+        .instance()
+        .get(&DataKey::Closeout(identity.clone(), epoch))
+        .map(|c| (epoch, c))
+}
+
+/// @notice True if `identity` has a close-out statement and its bond is gone or terminal, so a
+/// slash against it can only be recorded as uncollectable.
+#[must_use]
+pub fn targets_closed_bond(e: &Env, identity: &Address) -> bool {
+    if closeout::get_epoch(e, identity) == 0 {
+        return false;
+    }
+    e.storage()
+        .instance()
+        .get::<_, IdentityBond>(&DataKey::Bond(identity.clone()))
+        .is_none_or(|bond| closeout::is_terminal(&bond))
+}
+
+/// @notice Records slash request `proposal_id` of `amount` against `identity`'s closed bond.
+/// Emits `slash_uncollectable` (topic: identity) with `(proposal_id, amount, epoch)`. Returns
+/// the stored bond, or a zeroed `Closed` bond if `close_bond` removed it.
+///
+/// # Panics
+/// - "no bond" if `identity` has no close-out statement
+pub fn record(e: &Env, identity: &Address, amount: i128, proposal_id: u64) -> IdentityBond {
+    let (epoch, statement) = latest_closeout(e, identity).unwrap_or_else(|| panic!("no bond"));
+    crate::slash_history::append_slash_history(
+        e,
+        identity,
+        amount,
+        0,
+        Symbol::new(e, "uncollectable"),
+        statement.total_slashed,
+    );
+
+    let key = UncollectableKey::Pending(identity.clone());
+    let pending = PendingDock {
+        amount: get_pending(e, identity)
+            .checked_add(amount)
+            .expect("uncollectable overflow"),
+        recorded_at: e.ledger().timestamp(),
+    };
+    e.storage().instance().set(&key, &pending);
+
+    crate::events::publish_for(
+        e,
+        "slash_uncollectable",
+        identity.clone(),
+        (proposal_id, amount, epoch),
+    );
+    crate::journal::append(e, identity, "slash_uncollectable", amount, proposal_id);
+
+    e.storage()
+        .instance()
+        .get(&DataKey::Bond(identity.clone()))
+        .unwrap_or_else(|| IdentityBond {
+            identity: identity.clone(),
+            bonded_amount: 0,
+            bond_start: statement.opened_at,
+            bond_duration: statement.closed_at.saturating_sub(statement.opened_at),
+            slashed_amount: 0,
+            active: false,
+            is_rolling: false,
+            withdrawal_requested_at: 0,
+            notice_period: 0,
+            kind: BondKind::Fixed,
+            status: BondStatus::Closed,
+        })
+}
+
+/// @notice Clears `identity`'s pending dock and returns how much of it applies to a new bond of
+/// `bonded_amount`: the pending amount capped at `bonded_amount` if the re-bond is within the
+/// window, 0 otherwise. Emits `uncollectable_slash_docked` (topic: identity) with
+/// `(docked, pending)` when something is docked.
+pub fn take_dock(e: &Env, identity: &Address, bonded_amount: i128) -> i128 {
+    let key = UncollectableKey::Pending(identity.clone());
+    let Some(pending) = e.storage().instance().get::<_, PendingDock>(&key) else {
+        return 0;
+    };
+    e.storage().instance().remove(&key);
+    let window = get_rebond_window(e);
+    if window == 0 || e.ledger().timestamp() > pending.recorded_at.saturating_add(window) {
+        return 0;
+    }
+    let docked = pending.amount.min(bonded_amount);
+    if docked > 0 {
+        crate::events::publish_for(
+            e,
+            "uncollectable_slash_docked",
+            identity.clone(),
+            (docked, pending.amount),
+        );
+    }
+    docked
+}
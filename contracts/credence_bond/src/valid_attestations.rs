@@ -0,0 +1,143 @@
+//! Valid Attestation Queries
+//!
+//! `get_subject_attestations` lists every attestation id ever issued for a subject. Consumers
+//! that only care about attestations they can rely on use `get_valid_attestations`, which applies
+//! `is_effective` in one place. The bond gate uses the same predicate, so the two can never
+//! disagree about which attestations count.
+//!
+//! Revocation is the only validity state this contract enforces. The V2 record fields
+//! `expires_at`, `flags` and `consented` are stored but nothing sets them yet; when they start
+//! to matter, the check belongs in `is_effective`.
+//!
+//! Historical questions ("could a consumer rely on it at time T?") use `was_valid_at`: an
+//! attestation is valid on `[timestamp, revoked_at)`, and open-ended while unrevoked. The issue
+//! time is inclusive and the revocation time exclusive.
+//!
+//! Indexers that only need a subject's totals use `stats` instead of loading every attestation
+//! themselves.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::{Attestation, DataKey};
+
+/// Maximum number of attestations returned by one `valid_page` query.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// @notice Aggregate view of a subject's attestations (all zero for a subject with none).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationStats {
+    /// Attestations ever issued for the subject.
+    pub total: u32,
+    pub revoked: u32,
+    /// Attestations that currently count (`is_effective`).
+    pub active: u32,
+    /// Sum of the weights of active attestations.
+    pub active_weight: u64,
+    /// Issue time of the most recent attestation, revoked or not (0 if none).
+    pub newest_timestamp: u64,
+    /// Distinct attesters that issued any of the attestations.
+    pub attester_count: u32,
+}
+
+/// @notice True if `attestation` currently counts (not revoked).
+#[must_use]
+pub fn is_effective(attestation: &Attestation) -> bool {
+    !attestation.revoked
+}
+
+/// @notice True if `subject` holds at least one effective attestation.
+#[must_use]
+pub fn has_effective(e: &Env, subject: &Address) -> bool {
+    let ids: Vec<u64> = e
+        .storage()
+        .instance()
+        .get(&DataKey::SubjectAttestations(subject.clone()))
+        .unwrap_or(Vec::new(e));
+    ids.iter()
+        .any(|id| crate::attestation_store::get(e, id).is_some_and(|att| is_effective(&att)))
+}
+
+/// @notice True if attestation `id` was valid at `timestamp` (`Attestation::was_valid_at`).
+///
+/// # Panics
+/// - "attestation not found" if no attestation has id `id`.
+#[must_use]
+pub fn was_valid_at(e: &Env, id: u64, timestamp: u64) -> bool {
+    crate::attestation_store::get(e, id)
+        .unwrap_or_else(|| panic!("attestation not found"))
+        .was_valid_at(timestamp)
+}
+
+/// @notice Up to `limit` effective attestations for `subject` in issue order, skipping the
+/// first `start` effective ones. Pages are over valid attestations only, so they stay dense.
+///
+/// # Panics
+/// - "page size too large" if `limit > MAX_PAGE_SIZE`.
+#[must_use]
+pub fn valid_page(e: &Env, subject: &Address, start: u32, limit: u32) -> Vec<Attestation> {
+    if limit > MAX_PAGE_SIZE {
+        panic!("page size too large");
+    }
+    let ids: Vec<u64> = e
+        .storage()
+        .instance()
+        .get(&DataKey::SubjectAttestations(subject.clone()))
+        .unwrap_or(Vec::new(e));
+    let mut out = Vec::new(e);
+    let mut skipped = 0_u32;
+    for id in ids.iter() {
+        if out.len() >= limit {
+            break;
+        }
+        let Some(att) = crate::attestation_store::get(e, id) else {
+            continue;
+        };
+        if !is_effective(&att) {
+            continue;
+        }
+        if skipped < start {
+            skipped += 1;
+            continue;
+        }
+        out.push_back(att);
+    }
+    out
+}
+
+/// @notice Totals over every attestation issued for `subject`. Read-only.
+#[must_use]
+pub fn stats(e: &Env, subject: &Address) -> AttestationStats {
+    let ids: Vec<u64> = e
+        .storage()
+        .instance()
+        .get(&DataKey::SubjectAttestations(subject.clone()))
+        .unwrap_or(Vec::new(e));
+    let mut stats = AttestationStats {
+        total: 0,
+        revoked: 0,
+        active: 0,
+        active_weight: 0,
+        newest_timestamp: 0,
+        attester_count: 0,
+    };
+    let mut attesters: Vec<Address> = Vec::new(e);
+    for id in ids.iter() {
+        let Some(att) = crate::attestation_store::get(e, id) else {
+            continue;
+        };
+        stats.total += 1;
+        if is_effective(&att) {
+            stats.active += 1;
+            stats.active_weight += u64::from(att.weight);
+        } else {
+            stats.revoked += 1;
+        }
+        stats.newest_timestamp = stats.newest_timestamp.max(att.timestamp);
+        if !attesters.contains(&att.verifier) {
+            attesters.push_back(att.verifier);
+        }
+    }
+    stats.attester_count = attesters.len();
+    stats
+}
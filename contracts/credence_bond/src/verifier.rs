@@ -8,12 +8,32 @@
 //! - `ver_min_stake` (Symbol) -> i128 (minimum required stake to activate)
 //! - `(ver_info, verifier)` (tuple) -> `VerifierInfo`
 //! - `(verifier, verifier)` (tuple) -> bool (access-control role; shared with access_control.rs)
+//! - `(ver_meta, verifier)` (tuple) -> `VerifierMetadata` (optional public profile)
+//! - `RevocationKey::Stats(verifier)` -> `RevocationStats`
+//! - `RevocationKey::Multiplier(initiator, reason)` -> u32 (revocation penalty, bps)
+//! - `StatsKey::Totals` -> `StatsTotals` (stake and attestation totals across all verifiers)
+//! - `StatsKey::Active` -> `Vec<Address>` (active verifiers, for min/max stake)
+//!
+//! Revocations are classified by initiator and reason code. The reputation penalty is the
+//! attestation weight scaled by the most specific configured multiplier: (initiator, reason),
+//! then (initiator, any reason), then 1x.
 //!
 //! Note: `DataKey::AttesterStake(verifier)` is kept in sync with the staked amount so that
 //! weighted attestations can use real stake.
+//!
+//! `VerifierInfo.active` is the source of truth for who may attest. The access-control role and
+//! the legacy `DataKey::Attester(verifier)` flag read by `add_attestation` are derived from it and
+//! only written through `set_verifier_role`, so every registration path (staked or legacy admin)
+//! keeps the three in step. `audit_attester_consistency` / `repair_attester_flags` find and fix
+//! addresses whose flags drifted in deployments that predate this rule.
+//!
+//! Cohort aggregates (`get_stats`) are updated from the difference between the old and new
+//! record every time a `VerifierInfo` is written, so they cannot drift from the per-verifier
+//! records through any path, reactivations included. Records written before the aggregates
+//! existed are not counted until they are next written.
 
 use soroban_sdk::token::TokenClient;
-use soroban_sdk::{contracttype, Address, Env, Symbol};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String, Symbol, Vec};
 
 use crate::weighted_attestation;
 use crate::DataKey;
@@ -21,6 +41,15 @@ use crate::DataKey;
 const KEY_MIN_STAKE: &str = "ver_min_stake";
 const KEY_INFO_PREFIX: &str = "ver_info";
 const KEY_VERIFIER_ROLE_PREFIX: &str = "verifier";
+const KEY_METADATA_PREFIX: &str = "ver_meta";
+
+/// Maximum length (bytes) of a verifier policy URI.
+pub const MAX_METADATA_URI_LEN: u32 = 256;
+
+/// Revocation penalty multiplier applied when none is configured (1x).
+pub const DEFAULT_REVOCATION_MULTIPLIER_BPS: u32 = 10_000;
+/// Largest configurable revocation penalty multiplier (10x).
+pub const MAX_REVOCATION_MULTIPLIER_BPS: u32 = 100_000;
 
 const EVENT_CONFIG_UPDATED: &str = "verifier_config_updated";
 const EVENT_REGISTERED: &str = "verifier_registered";
@@ -29,6 +58,11 @@ const EVENT_STAKE_DEPOSITED: &str = "verifier_stake_deposited";
 const EVENT_DEACTIVATED: &str = "verifier_deactivated";
 const EVENT_STAKE_WITHDRAWN: &str = "verifier_stake_withdrawn";
 const EVENT_REPUTATION_UPDATED: &str = "verifier_reputation_updated";
+const EVENT_METADATA_SET: &str = "verifier_metadata_set";
+const EVENT_METADATA_CLEARED: &str = "verifier_metadata_cleared";
+const EVENT_REVOCATION_RECORDED: &str = "verifier_revocation_recorded";
+const EVENT_REVOCATION_MULTIPLIER_SET: &str = "revocation_multiplier_set";
+const EVENT_ATTESTER_FLAGS_REPAIRED: &str = "attester_flags_repaired";
 
 /// Verifier metadata stored on-chain.
 #[contracttype]
@@ -51,6 +85,91 @@ pub struct VerifierInfo {
     pub attestations_revoked: u32,
 }
 
+/// Public profile a verifier publishes for consumers.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerifierMetadata {
+    /// Hash of the verifier's display name.
+    pub name_hash: BytesN<32>,
+    /// Location of the verifier's policy document.
+    pub uri: String,
+    /// Ledger timestamp of the last update.
+    pub updated_at: u64,
+}
+
+/// Who revoked an attestation.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RevocationInitiator {
+    /// The attester withdrew their own claim.
+    Attester,
+    /// The admin revoked it (e.g. for fraud).
+    Admin,
+}
+
+/// Revocation counts for a verifier, split by initiator and by reason code.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RevocationStats {
+    pub by_attester: u32,
+    pub by_admin: u32,
+    pub by_reason: Map<Symbol, u32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum RevocationKey {
+    Stats(Address),
+    /// Penalty multiplier (bps) for an initiator, optionally narrowed to one reason.
+    Multiplier(RevocationInitiator, Option<Symbol>),
+}
+
+/// Aggregate view of every verifier, as returned by `get_verifier_stats`. Averages are left to
+/// clients (`active_stake / active_count`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerifierStats {
+    pub active_count: u32,
+    /// Stake held by active verifiers.
+    pub active_stake: i128,
+    /// Stake held by all verifiers, including inactive ones that have not withdrawn.
+    pub total_stake: i128,
+    /// Smallest and largest stake among active verifiers (0 when there are none).
+    pub min_active_stake: i128,
+    pub max_active_stake: i128,
+    pub attestations_issued: u64,
+    pub attestations_revoked: u64,
+}
+
+/// Running totals behind `VerifierStats`.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct StatsTotals {
+    active_stake: i128,
+    total_stake: i128,
+    attestations_issued: u64,
+    attestations_revoked: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum StatsKey {
+    Totals,
+    Active,
+}
+
+/// Everything known about a verifier, as returned by `describe_verifier`, with the ledger it
+/// was read at.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerifierDescription {
+    pub info: VerifierInfo,
+    /// Published metadata; `updated_at` is 0 if the verifier has published none.
+    pub metadata: VerifierMetadata,
+    pub revocations: RevocationStats,
+    pub context: crate::context::Context,
+}
+
 /// @notice Returns the minimum required verifier stake.
 /// @dev Defaults to 0 if unset.
 #[must_use]
@@ -60,16 +179,22 @@ pub fn get_min_stake(e: &Env) -> i128 {
 
 /// @notice Sets the minimum required verifier stake (admin-only; caller must enforce).
 /// @param min_stake The minimum amount a verifier must stake to become active.
+/// @dev Written through the economic parameters bundle with the other fields unchanged.
 ///
 /// # Panics
 /// Panics if `min_stake` is negative.
 pub fn set_min_stake(e: &Env, min_stake: i128) {
-    if min_stake < 0 {
-        panic!("min stake cannot be negative");
-    }
+    let params = crate::economic_params::EconomicParams {
+        verifier_min_stake: min_stake,
+        ..crate::economic_params::get(e)
+    };
+    crate::economic_params::write(e, &params);
+    crate::events::publish(e, EVENT_CONFIG_UPDATED, (min_stake,));
+}
+
+/// Writes the minimum stake (already validated by `economic_params`).
+pub(crate) fn store_min_stake(e: &Env, min_stake: i128) {
     e.storage().instance().set(&min_stake_key(e), &min_stake);
-    e.events()
-        .publish((Symbol::new(e, EVENT_CONFIG_UPDATED),), (min_stake,));
 }
 
 /// @notice Get verifier info, if registered.
@@ -162,6 +287,7 @@ pub fn register_with_stake(e: &Env, verifier: &Address, stake_deposit: i128) ->
             .get(&DataKey::Token)
             .unwrap_or_else(|| panic!("token not set"));
         let contract = e.current_contract_address();
+        crate::token_integration::require_funds(e, &token, verifier, &contract, stake_deposit);
         TokenClient::new(e, &token).transfer_from(&contract, verifier, &contract, &stake_deposit);
     }
 
@@ -219,8 +345,10 @@ pub fn deactivate_verifier(e: &Env, verifier: &Address, reason: Symbol) -> Verif
     set_verifier_role(e, verifier, false);
     put_verifier_info(e, verifier, &info);
 
-    e.events().publish(
-        (Symbol::new(e, EVENT_DEACTIVATED), verifier.clone()),
+    crate::events::publish_for(
+        e,
+        EVENT_DEACTIVATED,
+        verifier.clone(),
         (reason, now, info.stake),
     );
     info
@@ -240,6 +368,78 @@ pub fn deactivate_if_exists(e: &Env, verifier: &Address, reason: Symbol) {
     set_verifier_role(e, verifier, false);
 }
 
+/// An address whose attester flag or verifier role disagrees with its `VerifierInfo`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttesterMismatch {
+    pub address: Address,
+    /// Legacy `DataKey::Attester` flag read by `add_attestation`.
+    pub attester_flag: bool,
+    /// Access-control verifier role.
+    pub role: bool,
+    /// `VerifierInfo.active`, or `None` if the address never registered.
+    pub verifier_active: Option<bool>,
+}
+
+/// @notice Returns the mismatch for `verifier`, or `None` if its flag and role both equal
+/// `VerifierInfo.active` (false when it never registered).
+#[must_use]
+pub fn attester_mismatch(e: &Env, verifier: &Address) -> Option<AttesterMismatch> {
+    let verifier_active = get_verifier_info(e, verifier).map(|i| i.active);
+    let expected = verifier_active.unwrap_or(false);
+    let attester_flag = e
+        .storage()
+        .instance()
+        .get(&DataKey::Attester(verifier.clone()))
+        .unwrap_or(false);
+    let role = e
+        .storage()
+        .instance()
+        .get(&role_key(e, verifier))
+        .unwrap_or(false);
+    if attester_flag == expected && role == expected {
+        return None;
+    }
+    Some(AttesterMismatch {
+        address: verifier.clone(),
+        attester_flag,
+        role,
+        verifier_active,
+    })
+}
+
+/// @notice Mismatches among `addresses` (read-only). Empty when all are consistent.
+#[must_use]
+pub fn audit_attester_consistency(e: &Env, addresses: &Vec<Address>) -> Vec<AttesterMismatch> {
+    let mut mismatches = Vec::new(e);
+    for address in addresses.iter() {
+        if let Some(m) = attester_mismatch(e, &address) {
+            mismatches.push_back(m);
+        }
+    }
+    mismatches
+}
+
+/// @notice Rewrites the flag and role of every mismatched address in `addresses` from its
+/// `VerifierInfo` (admin only; caller must enforce). Unregistered addresses lose both. Emits
+/// `attester_flags_repaired(active)` per repaired address.
+///
+/// # Returns
+/// Number of addresses repaired.
+pub fn repair_attester_flags(e: &Env, addresses: &Vec<Address>) -> u32 {
+    let mut repaired: u32 = 0;
+    for address in addresses.iter() {
+        let Some(m) = attester_mismatch(e, &address) else {
+            continue;
+        };
+        let active = m.verifier_active.unwrap_or(false);
+        set_verifier_role(e, &address, active);
+        crate::events::publish_for(e, EVENT_ATTESTER_FLAGS_REPAIRED, address.clone(), active);
+        repaired = repaired.checked_add(1).expect("repair count overflow");
+    }
+    repaired
+}
+
 /// @notice Withdraws staked tokens after deactivation.
 ///
 /// # Panics
@@ -273,8 +473,10 @@ pub fn withdraw_stake(e: &Env, verifier: &Address, amount: i128) -> VerifierInfo
     let contract = e.current_contract_address();
     TokenClient::new(e, &token).transfer(&contract, verifier, &amount);
 
-    e.events().publish(
-        (Symbol::new(e, EVENT_STAKE_WITHDRAWN), verifier.clone()),
+    crate::events::publish_for(
+        e,
+        EVENT_STAKE_WITHDRAWN,
+        verifier.clone(),
         (amount, info.stake),
     );
     info
@@ -323,21 +525,195 @@ pub fn record_attestation_issued(e: &Env, verifier: &Address, weight: u32) {
 
 /// @notice Records that an attestation was revoked; updates reputation.
 /// @dev Called by the main contract after `revoke_attestation`.
-pub fn record_attestation_revoked(e: &Env, verifier: &Address, weight: u32) {
-    let w = i128::from(weight);
+pub fn record_attestation_revoked(
+    e: &Env,
+    verifier: &Address,
+    weight: u32,
+    initiator: RevocationInitiator,
+    reason: Symbol,
+) {
     // Do not block revocations for legacy verifiers that predate verifier info storage.
     let Some(mut info) = get_verifier_info(e, verifier) else {
         return;
     };
 
+    let multiplier_bps = effective_revocation_multiplier(e, initiator, &reason);
+    let penalty = crate::math::bps(
+        i128::from(weight),
+        multiplier_bps,
+        "revocation penalty overflow",
+        "revocation penalty div-by-zero",
+    );
+
     info.attestations_revoked = info
         .attestations_revoked
         .checked_add(1)
         .expect("attestation count overflow");
-    info.reputation = info.reputation.checked_sub(w).expect("reputation overflow");
+    info.reputation = info
+        .reputation
+        .checked_sub(penalty)
+        .expect("reputation overflow");
     put_verifier_info(e, verifier, &info);
 
-    emit_reputation_event(e, verifier, -w, &info, Symbol::new(e, "revocation"));
+    let mut stats = get_revocation_stats(e, verifier);
+    match initiator {
+        RevocationInitiator::Attester => {
+            stats.by_attester = stats
+                .by_attester
+                .checked_add(1)
+                .expect("revocation count overflow");
+        }
+        RevocationInitiator::Admin => {
+            stats.by_admin = stats
+                .by_admin
+                .checked_add(1)
+                .expect("revocation count overflow");
+        }
+    }
+    let reason_count = stats
+        .by_reason
+        .get(reason.clone())
+        .unwrap_or(0)
+        .checked_add(1)
+        .expect("revocation count overflow");
+    stats.by_reason.set(reason.clone(), reason_count);
+    e.storage()
+        .instance()
+        .set(&RevocationKey::Stats(verifier.clone()), &stats);
+
+    emit_reputation_event(e, verifier, -penalty, &info, Symbol::new(e, "revocation"));
+    crate::events::publish_for(
+        e,
+        EVENT_REVOCATION_RECORDED,
+        verifier.clone(),
+        (initiator, reason, penalty),
+    );
+}
+
+/// @notice Returns the verifier's revocation counts (all zero if none recorded).
+#[must_use]
+pub fn get_revocation_stats(e: &Env, verifier: &Address) -> RevocationStats {
+    e.storage()
+        .instance()
+        .get(&RevocationKey::Stats(verifier.clone()))
+        .unwrap_or(RevocationStats {
+            by_attester: 0,
+            by_admin: 0,
+            by_reason: Map::new(e),
+        })
+}
+
+/// @notice Sets the penalty multiplier (bps of attestation weight) for revocations by
+/// `initiator`, or only those with `reason` when given (admin only; caller must enforce).
+///
+/// # Panics
+/// - "revocation multiplier too large" if `multiplier_bps > MAX_REVOCATION_MULTIPLIER_BPS`.
+pub fn set_revocation_multiplier(
+    e: &Env,
+    initiator: RevocationInitiator,
+    reason: Option<Symbol>,
+    multiplier_bps: u32,
+) {
+    if multiplier_bps > MAX_REVOCATION_MULTIPLIER_BPS {
+        panic!("revocation multiplier too large");
+    }
+    e.storage().instance().set(
+        &RevocationKey::Multiplier(initiator, reason.clone()),
+        &multiplier_bps,
+    );
+    crate::events::publish(
+        e,
+        EVENT_REVOCATION_MULTIPLIER_SET,
+        (initiator, reason, multiplier_bps),
+    );
+}
+
+/// @notice Multiplier applied to a revocation by `initiator` for `reason`.
+#[must_use]
+pub fn effective_revocation_multiplier(
+    e: &Env,
+    initiator: RevocationInitiator,
+    reason: &Symbol,
+) -> u32 {
+    let storage = e.storage().instance();
+    storage
+        .get(&RevocationKey::Multiplier(initiator, Some(reason.clone())))
+        .or_else(|| storage.get(&RevocationKey::Multiplier(initiator, None)))
+        .unwrap_or(DEFAULT_REVOCATION_MULTIPLIER_BPS)
+}
+
+/// @notice Returns the verifier's published metadata, if any.
+#[must_use]
+pub fn get_metadata(e: &Env, verifier: &Address) -> Option<VerifierMetadata> {
+    e.storage().instance().get(&metadata_key(e, verifier))
+}
+
+/// @notice Returns the verifier's info together with its metadata, if registered.
+#[must_use]
+pub fn describe(e: &Env, verifier: &Address) -> Option<VerifierDescription> {
+    get_verifier_info(e, verifier).map(|info| VerifierDescription {
+        info,
+        metadata: get_metadata(e, verifier).unwrap_or_else(|| VerifierMetadata {
+            name_hash: BytesN::from_array(e, &[0; 32]),
+            uri: String::from_str(e, ""),
+            updated_at: 0,
+        }),
+        revocations: get_revocation_stats(e, verifier),
+        context: crate::context::current(e),
+    })
+}
+
+/// @notice Publishes `verifier`'s display name hash and policy URI. Allowed whether the
+/// verifier is active or not. Caller must enforce verifier auth.
+///
+/// # Panics
+/// - "verifier not registered" if the address never registered.
+/// - "metadata uri too long" if `uri` exceeds `MAX_METADATA_URI_LEN` bytes.
+pub fn set_metadata(
+    e: &Env,
+    verifier: &Address,
+    name_hash: BytesN<32>,
+    uri: String,
+) -> VerifierMetadata {
+    if get_verifier_info(e, verifier).is_none() {
+        panic!("verifier not registered");
+    }
+    if uri.len() > MAX_METADATA_URI_LEN {
+        panic!("metadata uri too long");
+    }
+    let metadata = VerifierMetadata {
+        name_hash,
+        uri,
+        updated_at: e.ledger().timestamp(),
+    };
+    e.storage()
+        .instance()
+        .set(&metadata_key(e, verifier), &metadata);
+    crate::events::publish_for(
+        e,
+        EVENT_METADATA_SET,
+        verifier.clone(),
+        (metadata.name_hash.clone(), metadata.uri.clone()),
+    );
+    metadata
+}
+
+/// @notice Removes `verifier`'s metadata (admin only; caller must enforce).
+///
+/// # Panics
+/// - "verifier metadata not set" if there is nothing to clear.
+pub fn clear_metadata(e: &Env, verifier: &Address) {
+    let key = metadata_key(e, verifier);
+    if !e.storage().instance().has(&key) {
+        panic!("verifier metadata not set");
+    }
+    e.storage().instance().remove(&key);
+    crate::events::publish_for(
+        e,
+        EVENT_METADATA_CLEARED,
+        verifier.clone(),
+        Symbol::new(e, "admin"),
+    );
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -356,12 +732,111 @@ fn info_key(e: &Env, verifier: &Address) -> (Symbol, Address) {
     (Symbol::new(e, KEY_INFO_PREFIX), verifier.clone())
 }
 
+fn metadata_key(e: &Env, verifier: &Address) -> (Symbol, Address) {
+    (Symbol::new(e, KEY_METADATA_PREFIX), verifier.clone())
+}
+
 fn role_key(e: &Env, verifier: &Address) -> (Symbol, Address) {
     (Symbol::new(e, KEY_VERIFIER_ROLE_PREFIX), verifier.clone())
 }
 
 fn put_verifier_info(e: &Env, verifier: &Address, info: &VerifierInfo) {
+    let old = get_verifier_info(e, verifier);
     e.storage().instance().set(&info_key(e, verifier), info);
+    update_stats(e, verifier, old.as_ref(), info);
+}
+
+fn get_totals(e: &Env) -> StatsTotals {
+    e.storage()
+        .instance()
+        .get(&StatsKey::Totals)
+        .unwrap_or_default()
+}
+
+fn get_active_set(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&StatsKey::Active)
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+/// `total - old + new`, panicking with "verifier stats overflow".
+fn replace_i128(total: i128, old: i128, new: i128) -> i128 {
+    total
+        .checked_sub(old)
+        .and_then(|t| t.checked_add(new))
+        .expect("verifier stats overflow")
+}
+
+fn replace_u64(total: u64, old: u32, new: u32) -> u64 {
+    total
+        .checked_add(u64::from(new))
+        .and_then(|t| t.checked_sub(u64::from(old)))
+        .expect("verifier stats overflow")
+}
+
+/// Applies the change from `old` to `new` for `verifier` to the cohort aggregates.
+fn update_stats(e: &Env, verifier: &Address, old: Option<&VerifierInfo>, new: &VerifierInfo) {
+    let (old_active, old_stake, old_issued, old_revoked) = old.map_or((false, 0, 0, 0), |o| {
+        (
+            o.active,
+            o.stake,
+            o.attestations_issued,
+            o.attestations_revoked,
+        )
+    });
+    let old_active_stake = if old_active { old_stake } else { 0 };
+    let new_active_stake = if new.active { new.stake } else { 0 };
+
+    let mut totals = get_totals(e);
+    totals.total_stake = replace_i128(totals.total_stake, old_stake, new.stake);
+    totals.active_stake = replace_i128(totals.active_stake, old_active_stake, new_active_stake);
+    totals.attestations_issued = replace_u64(
+        totals.attestations_issued,
+        old_issued,
+        new.attestations_issued,
+    );
+    totals.attestations_revoked = replace_u64(
+        totals.attestations_revoked,
+        old_revoked,
+        new.attestations_revoked,
+    );
+    e.storage().instance().set(&StatsKey::Totals, &totals);
+
+    if old_active != new.active {
+        let mut active = get_active_set(e);
+        if new.active {
+            active.push_back(verifier.clone());
+        } else if let Some(i) = active.first_index_of(verifier) {
+            active.remove(i);
+        }
+        e.storage().instance().set(&StatsKey::Active, &active);
+    }
+}
+
+/// @notice Cohort aggregates across all verifiers. Min and max stake are read from the active
+/// verifiers' records; everything else comes from running totals.
+#[must_use]
+pub fn get_stats(e: &Env) -> VerifierStats {
+    let totals = get_totals(e);
+    let active = get_active_set(e);
+    let mut min: Option<i128> = None;
+    let mut max: Option<i128> = None;
+    for verifier in active.iter() {
+        if let Some(info) = get_verifier_info(e, &verifier) {
+            min = Some(min.map_or(info.stake, |m| m.min(info.stake)));
+            max = Some(max.map_or(info.stake, |m| m.max(info.stake)));
+        }
+    }
+    VerifierStats {
+        active_count: active.len(),
+        active_stake: totals.active_stake,
+        total_stake: totals.total_stake,
+        min_active_stake: min.unwrap_or(0),
+        max_active_stake: max.unwrap_or(0),
+        attestations_issued: totals.attestations_issued,
+        attestations_revoked: totals.attestations_revoked,
+    }
 }
 
 fn set_verifier_role(e: &Env, verifier: &Address, enabled: bool) {
@@ -399,8 +874,10 @@ fn emit_registration_event(
         RegistrationKind::Legacy => Symbol::new(e, "legacy"),
     };
 
-    e.events().publish(
-        (Symbol::new(e, topic), verifier.clone()),
+    crate::events::publish_for(
+        e,
+        topic,
+        verifier.clone(),
         (kind_symbol, stake_deposited, total_stake, min_stake),
     );
 }
@@ -412,8 +889,10 @@ fn emit_reputation_event(
     info: &VerifierInfo,
     reason: Symbol,
 ) {
-    e.events().publish(
-        (Symbol::new(e, EVENT_REPUTATION_UPDATED), verifier.clone()),
+    crate::events::publish_for(
+        e,
+        EVENT_REPUTATION_UPDATED,
+        verifier.clone(),
         (
             delta,
             info.reputation,
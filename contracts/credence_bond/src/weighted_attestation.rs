@@ -13,7 +13,7 @@
 use soroban_sdk::Env;
 
 use crate::math;
-use crate::types::attestation::MAX_ATTESTATION_WEIGHT;
+use crate::types::attestation::{DEFAULT_ATTESTATION_WEIGHT, MAX_ATTESTATION_WEIGHT};
 use crate::DataKey;
 
 /// Default weight multiplier in basis points (1 = 0.01%). Formula: weight = stake * multiplier_bps / 10_000.
@@ -27,6 +27,11 @@ fn weight_config_key(e: &Env) -> soroban_sdk::Symbol {
     soroban_sdk::Symbol::new(e, "weight_cfg")
 }
 
+/// Storage key for the baseline weight. Stored as u32.
+fn baseline_weight_key(e: &Env) -> soroban_sdk::Symbol {
+    soroban_sdk::Symbol::new(e, "weight_base")
+}
+
 /// Returns (multiplier_bps, max_weight). Uses defaults if not set.
 #[must_use]
 pub fn get_weight_config(e: &Env) -> (u32, u32) {
@@ -36,13 +41,33 @@ pub fn get_weight_config(e: &Env) -> (u32, u32) {
         .unwrap_or((DEFAULT_WEIGHT_MULTIPLIER_BPS, DEFAULT_MAX_WEIGHT))
 }
 
-/// Sets weight config (admin only; caller must enforce). multiplier_bps in basis points;
-/// max_weight is capped by MAX_ATTESTATION_WEIGHT.
-pub fn set_weight_config(e: &Env, multiplier_bps: u32, max_weight: u32) {
-    let cap = core::cmp::min(max_weight, MAX_ATTESTATION_WEIGHT);
+/// Returns the weight of an attester without stake, which also floors staked weights.
+/// Defaults to DEFAULT_ATTESTATION_WEIGHT.
+#[must_use]
+pub fn get_baseline_weight(e: &Env) -> u32 {
     e.storage()
         .instance()
-        .set(&weight_config_key(e), &(multiplier_bps, cap));
+        .get(&baseline_weight_key(e))
+        .unwrap_or(DEFAULT_ATTESTATION_WEIGHT)
+}
+
+/// Sets weight config (admin only; caller must enforce). multiplier_bps in basis points;
+/// max_weight is capped by MAX_ATTESTATION_WEIGHT. Written through the economic parameters
+/// bundle with the other fields unchanged.
+pub fn set_weight_config(e: &Env, multiplier_bps: u32, max_weight: u32) {
+    let params = crate::economic_params::EconomicParams {
+        weight_multiplier_bps: multiplier_bps,
+        max_weight: core::cmp::min(max_weight, MAX_ATTESTATION_WEIGHT),
+        ..crate::economic_params::get(e)
+    };
+    crate::economic_params::write(e, &params);
+}
+
+/// Writes the weight config (already validated by `economic_params`).
+pub(crate) fn store_weight_config(e: &Env, multiplier_bps: u32, max_weight: u32, baseline: u32) {
+    let storage = e.storage().instance();
+    storage.set(&weight_config_key(e), &(multiplier_bps, max_weight));
+    storage.set(&baseline_weight_key(e), &baseline);
 }
 
 /// Returns the attester's stake (bond amount or configured stake). 0 if not set.
@@ -68,16 +93,16 @@ pub fn set_attester_stake(e: &Env, attester: &soroban_sdk::Address, amount: i128
 }
 
 /// Computes attestation weight from attester stake using config. Capped by config max and
-/// MAX_ATTESTATION_WEIGHT. If stake is 0, returns default weight (1) so attestations are still allowed.
+/// MAX_ATTESTATION_WEIGHT, and never below the baseline weight. If stake is 0, returns the
+/// baseline weight (default 1) so attestations are still allowed.
 #[must_use]
 pub fn compute_weight(e: &Env, attester: &soroban_sdk::Address) -> u32 {
-    use crate::types::attestation::DEFAULT_ATTESTATION_WEIGHT;
-
     let stake = get_attester_stake(e, attester);
     let (multiplier_bps, max_weight) = get_weight_config(e);
+    let baseline = get_baseline_weight(e);
 
     if stake <= 0 {
-        return DEFAULT_ATTESTATION_WEIGHT;
+        return baseline;
     }
 
     // weight = (stake * multiplier_bps / 10_000) capped at max_weight and MAX_ATTESTATION_WEIGHT
@@ -89,5 +114,5 @@ pub fn compute_weight(e: &Env, attester: &soroban_sdk::Address) -> u32 {
     );
     let w = (numerator / 10_000) as u32;
     let capped = core::cmp::min(w, max_weight);
-    core::cmp::min(capped, MAX_ATTESTATION_WEIGHT).max(DEFAULT_ATTESTATION_WEIGHT)
+    core::cmp::min(capped, MAX_ATTESTATION_WEIGHT).max(baseline)
 }
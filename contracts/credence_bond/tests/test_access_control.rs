@@ -22,11 +22,11 @@ fn setup(env: &Env) -> (CredenceBondClient<'_>, Address, Address, Address) {
 
     // 🔹 Use StellarAssetClient for minting
     let asset = soroban_sdk::token::StellarAssetClient::new(env, &token_id);
-    asset.mint(&user, &10_000_i128);
+    asset.mint(&user, &10_000_000_i128);
 
     // 🔹 Use TokenClient for approval
     let token = soroban_sdk::token::TokenClient::new(env, &token_id);
-    token.approve(&user, &contract_id, &10_000_i128, &0_u32);
+    token.approve(&user, &contract_id, &10_000_000_i128, &0_u32);
 
     (client, admin, user, attacker)
 }
@@ -38,9 +38,8 @@ fn unauthorized_cannot_add_attestation() {
     let (client, _admin, user, attacker) = setup(&env);
 
     let fake = String::from_str(&env, "fake");
-    let nonce = client.get_nonce(&attacker);
 
-    client.add_attestation(&attacker, &user, &fake, &nonce);
+    client.add_attestation(&attacker, &user, &fake);
 }
 
 #[test]
@@ -51,8 +50,7 @@ fn authorized_attester_can_add_attestation() {
     client.register_attester(&attacker);
 
     let valid = String::from_str(&env, "valid");
-    let nonce = client.get_nonce(&attacker);
-    let att = client.add_attestation(&attacker, &user, &valid, &nonce);
+    let att = client.add_attestation(&attacker, &user, &valid);
 
     assert_eq!(att.identity, user);
 }
@@ -66,13 +64,11 @@ fn wrong_attester_cannot_revoke() {
     client.register_attester(&attacker);
 
     let valid = String::from_str(&env, "valid");
-    let nonce = client.get_nonce(&attacker);
-    let att = client.add_attestation(&attacker, &user, &valid, &nonce);
+    let att = client.add_attestation(&attacker, &user, &valid);
 
     let other = Address::generate(&env);
-    let other_nonce = client.get_nonce(&other);
 
-    client.revoke_attestation(&other, &att.id, &other_nonce);
+    client.revoke_attestation(&other, &att.id);
 }
 
 #[test]
@@ -80,13 +76,13 @@ fn owner_can_withdraw_bond() {
     let env = Env::default();
     let (client, _admin, user, _) = setup(&env);
 
-    client.create_bond(&user, &1000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&user, &1_000_000_i128, &86400_u64, &false, &0_u64);
 
     // advance time past lock-up period
     env.ledger().with_mut(|l| {
         l.timestamp += 86401;
     });
 
-    let bond = client.withdraw_bond(&1000_i128);
+    let bond = client.withdraw_bond(&user, &1_000_000_i128);
     assert_eq!(bond.bonded_amount, 0);
 }
@@ -0,0 +1,68 @@
+//! Feature matrix for the test-only entrypoints: each one is callable exactly when the
+//! `testing-hooks` feature is enabled (tests enable it through the dev-dependency).
+
+use credence_bond::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Bytes, Env, IntoVal, Symbol, Val, Vec};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+/// Invokes `fn_name` by name so the check also compiles when the client lacks the method.
+fn callable(e: &Env, contract: &Address, fn_name: &str, args: Vec<Val>) -> bool {
+    matches!(
+        e.try_invoke_contract::<Val, soroban_sdk::Error>(contract, &Symbol::new(e, fn_name), args),
+        Ok(Ok(_))
+    )
+}
+
+#[test]
+fn test_hooks_exist_exactly_with_feature() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let failpoint = Symbol::new(&e, "token_transfer");
+    let entries: Vec<(Bytes, Bytes)> = Vec::new(&e);
+    let calls: [(&str, Vec<Val>); 7] = [
+        (
+            "set_callback",
+            (admin.clone(), Address::generate(&e)).into_val(&e),
+        ),
+        ("clear_callback", (admin.clone(),).into_val(&e)),
+        (
+            "arm_failpoint",
+            (admin.clone(), failpoint.clone(), 1_u32).into_val(&e),
+        ),
+        ("disarm_failpoint", (admin.clone(), failpoint).into_val(&e)),
+        ("export_state", (Vec::<Val>::new(&e),).into_val(&e)),
+        ("export_all_state", ().into_val(&e)),
+        ("import_state", (entries,).into_val(&e)),
+    ];
+
+    let enabled = cfg!(feature = "testing-hooks");
+    for (fn_name, args) in calls {
+        assert_eq!(
+            callable(&e, &client.address, fn_name, args),
+            enabled,
+            "{fn_name}"
+        );
+    }
+}
+
+#[test]
+fn test_production_entrypoints_always_exist() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert!(callable(
+        &e,
+        &client.address,
+        "get_total_bonded",
+        Vec::new(&e)
+    ));
+    assert_eq!(client.get_total_bonded(), 0);
+}
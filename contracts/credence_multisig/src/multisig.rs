@@ -5,6 +5,10 @@
 //! and execution at threshold. Can be used for any administrative action requiring
 //! multi-party approval.
 
+// `submit_proposal` takes every proposal field as an argument, and the client and args types
+// generated by `contractimpl` mirror that signature.
+#![allow(clippy::too_many_arguments)]
+
 use soroban_sdk::{contract, contractimpl, contracttype, Address, Bytes, Env, String, Symbol, Vec};
 
 /// Type of action that can be proposed and executed.
@@ -330,7 +334,7 @@ impl CredenceMultiSig {
 
         Self::require_signer(&e, &proposer);
 
-        if description.len() == 0 {
+        if description.is_empty() {
             panic!("description cannot be empty");
         }
 
@@ -630,6 +634,6 @@ impl CredenceMultiSig {
             .set(&DataKey::Proposal(proposal_id), &proposal);
 
         e.events()
-            .publish((Symbol::new(&e, "proposal_expired"), proposal_id), ());
+            .publish((Symbol::new(e, "proposal_expired"), proposal_id), ());
     }
 }
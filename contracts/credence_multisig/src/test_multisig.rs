@@ -4,7 +4,7 @@ use soroban_sdk::{
     Address, Env, String, Vec,
 };
 
-fn setup(e: &Env) -> (CredenceMultiSigClient, Address, Vec<Address>) {
+fn setup(e: &Env) -> (CredenceMultiSigClient<'_>, Address, Vec<Address>) {
     let contract_id = e.register(CredenceMultiSig, ());
     let client = CredenceMultiSigClient::new(e, &contract_id);
 
@@ -35,9 +35,9 @@ fn test_initialize() {
     assert_eq!(client.get_signer_count(), 3);
     assert_eq!(client.get_threshold(), 2);
     assert_eq!(client.get_admin(), admin);
-    assert_eq!(client.is_signer(&signers.get(0).unwrap()), true);
-    assert_eq!(client.is_signer(&signers.get(1).unwrap()), true);
-    assert_eq!(client.is_signer(&signers.get(2).unwrap()), true);
+    assert!(client.is_signer(&signers.get(0).unwrap()));
+    assert!(client.is_signer(&signers.get(1).unwrap()));
+    assert!(client.is_signer(&signers.get(2).unwrap()));
 }
 
 #[test]
@@ -84,7 +84,7 @@ fn test_add_signer() {
     client.add_signer(&admin, &new_signer);
 
     assert_eq!(client.get_signer_count(), 4);
-    assert_eq!(client.is_signer(&new_signer), true);
+    assert!(client.is_signer(&new_signer));
 }
 
 #[test]
@@ -107,7 +107,7 @@ fn test_remove_signer() {
     client.remove_signer(&admin, &signer_to_remove);
 
     assert_eq!(client.get_signer_count(), 2);
-    assert_eq!(client.is_signer(&signer_to_remove), false);
+    assert!(!client.is_signer(&signer_to_remove));
 }
 
 #[test]
@@ -312,7 +312,7 @@ fn test_sign_proposal() {
     client.sign_proposal(&signer, &proposal_id);
 
     assert_eq!(client.get_signature_count(&proposal_id), 1);
-    assert_eq!(client.has_signed(&proposal_id, &signer), true);
+    assert!(client.has_signed(&proposal_id, &signer));
 }
 
 #[test]
@@ -785,8 +785,8 @@ fn test_is_signer() {
 
     let non_signer = Address::generate(&e);
 
-    assert_eq!(client.is_signer(&signers.get(0).unwrap()), true);
-    assert_eq!(client.is_signer(&non_signer), false);
+    assert!(client.is_signer(&signers.get(0).unwrap()));
+    assert!(!client.is_signer(&non_signer));
 }
 
 // ==================== Complex Scenarios ====================
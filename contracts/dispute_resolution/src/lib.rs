@@ -9,6 +9,11 @@
 //! | `DataKey::DisputeCounter`    | `instance()` | Entire contract|
 //! | `DataKey::Dispute(id)`       | `persistent()`| Per dispute   |
 //! | `DataKey::Vote(id, address)` | `persistent()`| Per vote      |
+//! | `DataKey::EscalationConfig`  | `instance()` | Entire contract|
+//! | `DataKey::Escalation(id)`    | `persistent()`| Per escalation|
+//! | `DataKey::EscalationVote(id, address)` | `persistent()`| Per governor vote |
+//! | `DataKey::Admin`             | `instance()` | Entire contract|
+//! | `DataKey::OpenEscalations`   | `instance()` | Entire contract|
 //!
 //! **Why two tiers?**
 //! `instance()` storage shares the contract's rent TTL and is intended for a
@@ -16,10 +21,17 @@
 //! `persistent()` storage is independently rentable — each dispute and each
 //! vote has its own TTL that can be bumped cheaply, preventing unbounded
 //! growth of the instance footprint.
+//!
+//! ## Escalation
+//!
+//! A dispute left unresolved for longer than the configured escalation delay can be escalated
+//! by any governor into a governance vote (`UpholdSlash` / `RejectSlash`). Once escalated, the
+//! arbitrator path (`cast_vote`, `resolve_dispute`, `expire_dispute`) is closed and the
+//! governance outcome finalizes the dispute.
 
 #![no_std]
 use soroban_sdk::{
-    contract, contracterror, contractevent, contractimpl, contracttype, Address, Env,
+    contract, contracterror, contractevent, contractimpl, contracttype, Address, Env, Vec,
 };
 
 // ─── TTL constants ────────────────────────────────────────────────────────────
@@ -45,6 +57,16 @@ pub enum DataKey {
     Dispute(u64),
     /// Boolean vote record keyed by (dispute_id, arbitrator). Stored in `persistent()`.
     Vote(u64, Address),
+    /// Governors, quorum and delay for escalations. Stored in `instance()`.
+    EscalationConfig,
+    /// Governance proposal for an escalated dispute. Stored in `persistent()`.
+    Escalation(u64),
+    /// Governor vote on an escalated dispute. Stored in `persistent()`.
+    EscalationVote(u64, Address),
+    /// Contract admin, set once by `initialize`. Stored in `instance()`.
+    Admin,
+    /// Number of escalations awaiting `finalize_escalation`. Stored in `instance()`.
+    OpenEscalations,
 }
 
 // ─── Domain types ─────────────────────────────────────────────────────────────
@@ -56,6 +78,7 @@ pub enum DisputeStatus {
     Resolved,
     Rejected,
     Expired,
+    Escalated,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -66,6 +89,14 @@ pub enum DisputeOutcome {
     FavorSlasher,
 }
 
+/// Governor vote on an escalated dispute.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum EscalationAction {
+    UpholdSlash,
+    RejectSlash,
+}
+
 #[contracterror]
 #[derive(Clone, Debug, PartialEq)]
 pub enum Error {
@@ -78,6 +109,16 @@ pub enum Error {
     InsufficientStake = 7,
     InvalidDeadline = 8,
     TransferFailed = 9,
+    NotGovernor = 10,
+    EscalationNotConfigured = 11,
+    EscalationTooEarly = 12,
+    DisputeEscalated = 13,
+    NotEscalated = 14,
+    QuorumNotReached = 15,
+    InvalidEscalationConfig = 16,
+    AlreadyInitialized = 17,
+    NotInitialized = 18,
+    EscalationInProgress = 19,
 }
 
 // ─── Events ───────────────────────────────────────────────────────────────────
@@ -116,6 +157,41 @@ pub struct DisputeExpired {
     pub expired_at: u64,
 }
 
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscalationConfigured {
+    pub admin: Address,
+    pub governor_count: u32,
+    pub quorum_bps: u32,
+    pub escalation_delay: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeEscalated {
+    pub dispute_id: u64,
+    pub escalated_by: Address,
+    pub escalated_at: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EscalationVoteCast {
+    pub dispute_id: u64,
+    pub governor: Address,
+    pub action: EscalationAction,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EscalationFinalized {
+    pub dispute_id: u64,
+    pub action: EscalationAction,
+    pub outcome: DisputeOutcome,
+    pub votes_uphold: u32,
+    pub votes_reject: u32,
+}
+
 // ─── Data structures ──────────────────────────────────────────────────────────
 
 /// A single dispute record.
@@ -138,11 +214,35 @@ pub struct Dispute {
     pub created_at: u64,
 }
 
+/// Governance setup for escalated disputes.
+#[derive(Clone)]
+#[contracttype]
+pub struct EscalationConfig {
+    pub governors: Vec<Address>,
+    /// Share of governors (basis points) that must vote before an escalation can finalize.
+    pub quorum_bps: u32,
+    /// Seconds after `created_at` before an open dispute may be escalated.
+    pub escalation_delay: u64,
+}
+
+/// Governance proposal created by `escalate_dispute`, keyed by the dispute ID.
+#[derive(Clone)]
+#[contracttype]
+pub struct EscalationProposal {
+    pub escalated_by: Address,
+    pub escalated_at: u64,
+    pub votes_uphold: u32,
+    pub votes_reject: u32,
+}
+
 // ─── Constants ────────────────────────────────────────────────────────────────
 
 /// Minimum token amount required to open a dispute.
 pub const MIN_STAKE: i128 = 100;
 
+/// Basis-point denominator for `EscalationConfig::quorum_bps`.
+const BPS_DENOMINATOR: u32 = 10_000;
+
 // ─── Contract ─────────────────────────────────────────────────────────────────
 
 #[contract]
@@ -174,8 +274,64 @@ impl DisputeContract {
             .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
     }
 
+    fn load_escalation_config(env: &Env) -> Result<EscalationConfig, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::EscalationConfig)
+            .ok_or(Error::EscalationNotConfigured)
+    }
+
+    fn require_governor(config: &EscalationConfig, governor: &Address) -> Result<(), Error> {
+        if config.governors.contains(governor) {
+            Ok(())
+        } else {
+            Err(Error::NotGovernor)
+        }
+    }
+
+    fn load_escalation(env: &Env, dispute_id: u64) -> Result<EscalationProposal, Error> {
+        let key = DataKey::Escalation(dispute_id);
+        let storage = env.storage().persistent();
+        let proposal: EscalationProposal = storage.get(&key).ok_or(Error::NotEscalated)?;
+        storage.extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+        Ok(proposal)
+    }
+
+    fn save_escalation(env: &Env, dispute_id: u64, proposal: &EscalationProposal) {
+        let key = DataKey::Escalation(dispute_id);
+        env.storage().persistent().set(&key, proposal);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+    }
+
+    fn open_escalations(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::OpenEscalations)
+            .unwrap_or(0)
+    }
+
+    fn set_open_escalations(env: &Env, count: u32) {
+        env.storage()
+            .instance()
+            .set(&DataKey::OpenEscalations, &count);
+    }
+
     // ── Public interface ──────────────────────────────────────────────────────
 
+    /// Set the admin allowed to configure escalation. Callable once.
+    ///
+    /// # Errors
+    /// * `AlreadyInitialized` — an admin is already set
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        Ok(())
+    }
+
     /// Open a new dispute against a slash request.
     ///
     /// The disputer's `stake` is transferred from their account to the contract
@@ -260,6 +416,7 @@ impl DisputeContract {
     ///
     /// # Errors
     /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `DisputeEscalated` — dispute was escalated to governance
     /// * `DisputeNotOpen` — dispute is no longer accepting votes
     /// * `DeadlineExpired` — voting period has closed
     /// * `AlreadyVoted` — `arbitrator` has already cast a vote on this dispute
@@ -274,6 +431,9 @@ impl DisputeContract {
         // Single persistent-storage read: load-or-error (replaces has() + get()).
         let mut dispute = Self::load_dispute(&env, dispute_id)?;
 
+        if dispute.status == DisputeStatus::Escalated {
+            return Err(Error::DisputeEscalated);
+        }
         if dispute.status != DisputeStatus::Open {
             return Err(Error::DisputeNotOpen);
         }
@@ -320,11 +480,15 @@ impl DisputeContract {
     ///
     /// # Errors
     /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `DisputeEscalated` — dispute was escalated; governance decides it
     /// * `DisputeNotOpen` — dispute is already resolved/expired
     /// * `DeadlineNotReached` — voting period is still active
     pub fn resolve_dispute(env: Env, dispute_id: u64) -> Result<(), Error> {
         let mut dispute = Self::load_dispute(&env, dispute_id)?;
 
+        if dispute.status == DisputeStatus::Escalated {
+            return Err(Error::DisputeEscalated);
+        }
         if dispute.status != DisputeStatus::Open {
             return Err(Error::DisputeNotOpen);
         }
@@ -364,11 +528,15 @@ impl DisputeContract {
     ///
     /// # Errors
     /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `DisputeEscalated` — dispute was escalated; governance decides it
     /// * `DisputeNotOpen` — dispute is already resolved/expired
     /// * `DeadlineNotReached` — deadline has not yet passed
     pub fn expire_dispute(env: Env, dispute_id: u64) -> Result<(), Error> {
         let mut dispute = Self::load_dispute(&env, dispute_id)?;
 
+        if dispute.status == DisputeStatus::Escalated {
+            return Err(Error::DisputeEscalated);
+        }
         if dispute.status != DisputeStatus::Open {
             return Err(Error::DisputeNotOpen);
         }
@@ -397,6 +565,241 @@ impl DisputeContract {
             .has(&DataKey::Vote(dispute_id, arbitrator))
     }
 
+    // ── Escalation ────────────────────────────────────────────────────────────
+
+    /// Set the governors, quorum and delay used for escalations (admin only).
+    ///
+    /// Votes and quorum are counted against the current configuration, so it
+    /// cannot change while any escalation awaits finalization.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` was never called
+    /// * `Unauthorized` — `admin` is not the contract admin
+    /// * `EscalationInProgress` — an escalated dispute is not yet finalized
+    /// * `InvalidEscalationConfig` — no governors, `quorum_bps` outside
+    ///   `1..=10_000`, or `escalation_delay == 0`
+    pub fn configure_escalation(
+        env: Env,
+        admin: Address,
+        governors: Vec<Address>,
+        quorum_bps: u32,
+        escalation_delay: u64,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        if stored_admin != admin {
+            return Err(Error::Unauthorized);
+        }
+        if Self::open_escalations(&env) > 0 {
+            return Err(Error::EscalationInProgress);
+        }
+
+        if governors.is_empty()
+            || quorum_bps == 0
+            || quorum_bps > BPS_DENOMINATOR
+            || escalation_delay == 0
+        {
+            return Err(Error::InvalidEscalationConfig);
+        }
+
+        let governor_count = governors.len();
+        let config = EscalationConfig {
+            governors,
+            quorum_bps,
+            escalation_delay,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::EscalationConfig, &config);
+
+        EscalationConfigured {
+            admin,
+            governor_count,
+            quorum_bps,
+            escalation_delay,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Returns the escalation configuration, if set.
+    pub fn get_escalation_config(env: Env) -> Option<EscalationConfig> {
+        env.storage().instance().get(&DataKey::EscalationConfig)
+    }
+
+    /// Escalate a stalled dispute to a governance vote.
+    ///
+    /// Allowed once the dispute has been open for longer than
+    /// `escalation_delay`. Closes the arbitrator path for this dispute.
+    ///
+    /// # Errors
+    /// * `EscalationNotConfigured` — `configure_escalation` was never called
+    /// * `NotGovernor` — `governor` is not in the governor set
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `DisputeEscalated` — dispute is already escalated
+    /// * `DisputeNotOpen` — dispute is already resolved/expired
+    /// * `EscalationTooEarly` — the escalation delay has not yet elapsed
+    pub fn escalate_dispute(env: Env, governor: Address, dispute_id: u64) -> Result<(), Error> {
+        governor.require_auth();
+
+        let config = Self::load_escalation_config(&env)?;
+        Self::require_governor(&config, &governor)?;
+
+        let mut dispute = Self::load_dispute(&env, dispute_id)?;
+        if dispute.status == DisputeStatus::Escalated {
+            return Err(Error::DisputeEscalated);
+        }
+        if dispute.status != DisputeStatus::Open {
+            return Err(Error::DisputeNotOpen);
+        }
+
+        let now = env.ledger().timestamp();
+        if now <= dispute.created_at.saturating_add(config.escalation_delay) {
+            return Err(Error::EscalationTooEarly);
+        }
+
+        dispute.status = DisputeStatus::Escalated;
+        Self::save_dispute(&env, dispute_id, &dispute);
+        Self::save_escalation(
+            &env,
+            dispute_id,
+            &EscalationProposal {
+                escalated_by: governor.clone(),
+                escalated_at: now,
+                votes_uphold: 0,
+                votes_reject: 0,
+            },
+        );
+        Self::set_open_escalations(&env, Self::open_escalations(&env) + 1);
+
+        DisputeEscalated {
+            dispute_id,
+            escalated_by: governor,
+            escalated_at: now,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Returns the governance proposal for an escalated dispute, if any.
+    pub fn get_escalation(env: Env, dispute_id: u64) -> Option<EscalationProposal> {
+        Self::load_escalation(&env, dispute_id).ok()
+    }
+
+    /// Cast a governor vote on an escalated dispute.
+    ///
+    /// # Errors
+    /// * `EscalationNotConfigured` — `configure_escalation` was never called
+    /// * `NotGovernor` — `governor` is not in the governor set
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `NotEscalated` — dispute is not awaiting a governance decision
+    /// * `AlreadyVoted` — `governor` has already voted on this escalation
+    pub fn vote_escalation(
+        env: Env,
+        governor: Address,
+        dispute_id: u64,
+        action: EscalationAction,
+    ) -> Result<(), Error> {
+        governor.require_auth();
+
+        let config = Self::load_escalation_config(&env)?;
+        Self::require_governor(&config, &governor)?;
+
+        let dispute = Self::load_dispute(&env, dispute_id)?;
+        if dispute.status != DisputeStatus::Escalated {
+            return Err(Error::NotEscalated);
+        }
+        let mut proposal = Self::load_escalation(&env, dispute_id)?;
+
+        let vote_key = DataKey::EscalationVote(dispute_id, governor.clone());
+        let vote_storage = env.storage().persistent();
+        if vote_storage.has(&vote_key) {
+            return Err(Error::AlreadyVoted);
+        }
+        vote_storage.set(&vote_key, &action);
+        vote_storage.extend_ttl(&vote_key, BUMP_THRESHOLD, BUMP_TARGET);
+
+        match action {
+            EscalationAction::UpholdSlash => proposal.votes_uphold += 1,
+            EscalationAction::RejectSlash => proposal.votes_reject += 1,
+        }
+        Self::save_escalation(&env, dispute_id, &proposal);
+
+        EscalationVoteCast {
+            dispute_id,
+            governor,
+            action,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Finalize an escalated dispute once governor quorum is reached.
+    ///
+    /// A strict `RejectSlash` majority rules for the disputer and returns the
+    /// stake; otherwise the slash is upheld and the stake is forfeited (ties
+    /// uphold, matching `resolve_dispute`). Callable by anyone.
+    ///
+    /// # Errors
+    /// * `EscalationNotConfigured` — `configure_escalation` was never called
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `NotEscalated` — dispute is not awaiting a governance decision
+    /// * `QuorumNotReached` — fewer than `quorum_bps` of governors have voted
+    pub fn finalize_escalation(env: Env, dispute_id: u64) -> Result<(), Error> {
+        let config = Self::load_escalation_config(&env)?;
+
+        let mut dispute = Self::load_dispute(&env, dispute_id)?;
+        if dispute.status != DisputeStatus::Escalated {
+            return Err(Error::NotEscalated);
+        }
+        let proposal = Self::load_escalation(&env, dispute_id)?;
+
+        let votes = u64::from(proposal.votes_uphold) + u64::from(proposal.votes_reject);
+        let required = u64::from(config.governors.len()) * u64::from(config.quorum_bps);
+        if votes * u64::from(BPS_DENOMINATOR) < required {
+            return Err(Error::QuorumNotReached);
+        }
+
+        let (action, outcome) = if proposal.votes_reject > proposal.votes_uphold {
+            (EscalationAction::RejectSlash, DisputeOutcome::FavorDisputer)
+        } else {
+            (EscalationAction::UpholdSlash, DisputeOutcome::FavorSlasher)
+        };
+
+        dispute.status = DisputeStatus::Resolved;
+        dispute.outcome = outcome.clone();
+        Self::save_dispute(&env, dispute_id, &dispute);
+        Self::set_open_escalations(&env, Self::open_escalations(&env).saturating_sub(1));
+
+        if outcome == DisputeOutcome::FavorDisputer {
+            let token_client = soroban_sdk::token::Client::new(&env, &dispute.token);
+            token_client.transfer(
+                &env.current_contract_address(),
+                &dispute.disputer,
+                &dispute.stake,
+            );
+        }
+
+        EscalationFinalized {
+            dispute_id,
+            action,
+            outcome,
+            votes_uphold: proposal.votes_uphold,
+            votes_reject: proposal.votes_reject,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
     /// Returns the total number of disputes ever created (monotonically
     /// increasing; IDs start at 1).
     pub fn get_dispute_count(env: Env) -> u64 {
@@ -407,6 +810,9 @@ impl DisputeContract {
     }
 }
 
+#[cfg(test)]
 mod test;
 #[cfg(test)]
+mod test_escalation;
+#[cfg(test)]
 mod test_gas;
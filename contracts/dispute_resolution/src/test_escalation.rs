@@ -0,0 +1,239 @@
+//! Tests for escalating stalled disputes to a governance vote.
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, Vec};
+
+const STAKE: i128 = 500;
+const DEADLINE: u64 = 3_600;
+const ESCALATION_DELAY: u64 = 7_200;
+
+struct Setup<'a> {
+    client: DisputeContractClient<'a>,
+    admin: Address,
+    token_client: soroban_sdk::token::Client<'a>,
+    disputer: Address,
+    governors: [Address; 3],
+    dispute_id: u64,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(env, &contract_id);
+
+    let disputer = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(env, &token_id).mint(&disputer, &1000);
+    let token_client = soroban_sdk::token::Client::new(env, &token_id);
+    token_client.approve(&disputer, &contract_id, &STAKE, &1000);
+
+    let governors = [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    client.configure_escalation(
+        &admin,
+        &Vec::from_array(env, governors.clone()),
+        &5_000_u32,
+        &ESCALATION_DELAY,
+    );
+
+    let dispute_id = client.create_dispute(&disputer, &1, &STAKE, &token_id, &DEADLINE);
+
+    Setup {
+        client,
+        admin,
+        token_client,
+        disputer,
+        governors,
+        dispute_id,
+    }
+}
+
+fn escalate(env: &Env, s: &Setup) {
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + ESCALATION_DELAY + 1);
+    s.client.escalate_dispute(&s.governors[0], &s.dispute_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #12)")]
+fn test_escalation_before_delay_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + ESCALATION_DELAY);
+    s.client.escalate_dispute(&s.governors[0], &s.dispute_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")]
+fn test_escalation_requires_governor() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + ESCALATION_DELAY + 1);
+    s.client
+        .escalate_dispute(&Address::generate(&env), &s.dispute_id);
+}
+
+#[test]
+fn test_escalation_blocks_arbitrator_path() {
+    let env = Env::default();
+    let s = setup(&env);
+    escalate(&env, &s);
+
+    assert_eq!(
+        s.client.get_dispute(&s.dispute_id).status,
+        DisputeStatus::Escalated
+    );
+    assert_eq!(
+        s.client.try_resolve_dispute(&s.dispute_id),
+        Err(Ok(Error::DisputeEscalated))
+    );
+    assert_eq!(
+        s.client.try_expire_dispute(&s.dispute_id),
+        Err(Ok(Error::DisputeEscalated))
+    );
+    assert_eq!(
+        s.client
+            .try_escalate_dispute(&s.governors[1], &s.dispute_id),
+        Err(Ok(Error::DisputeEscalated))
+    );
+}
+
+#[test]
+fn test_governance_upholds_slash() {
+    let env = Env::default();
+    let s = setup(&env);
+    escalate(&env, &s);
+
+    s.client.vote_escalation(
+        &s.governors[0],
+        &s.dispute_id,
+        &EscalationAction::UpholdSlash,
+    );
+    assert_eq!(
+        s.client.try_finalize_escalation(&s.dispute_id),
+        Err(Ok(Error::QuorumNotReached))
+    );
+    s.client.vote_escalation(
+        &s.governors[1],
+        &s.dispute_id,
+        &EscalationAction::UpholdSlash,
+    );
+    s.client.finalize_escalation(&s.dispute_id);
+
+    let dispute = s.client.get_dispute(&s.dispute_id);
+    assert_eq!(dispute.status, DisputeStatus::Resolved);
+    assert_eq!(dispute.outcome, DisputeOutcome::FavorSlasher);
+    assert_eq!(s.token_client.balance(&s.disputer), 1000 - STAKE);
+}
+
+#[test]
+fn test_governance_rejects_slash() {
+    let env = Env::default();
+    let s = setup(&env);
+    escalate(&env, &s);
+
+    s.client.vote_escalation(
+        &s.governors[1],
+        &s.dispute_id,
+        &EscalationAction::RejectSlash,
+    );
+    s.client.vote_escalation(
+        &s.governors[2],
+        &s.dispute_id,
+        &EscalationAction::RejectSlash,
+    );
+    assert_eq!(
+        s.client.try_vote_escalation(
+            &s.governors[2],
+            &s.dispute_id,
+            &EscalationAction::UpholdSlash
+        ),
+        Err(Ok(Error::AlreadyVoted))
+    );
+    s.client.finalize_escalation(&s.dispute_id);
+
+    let dispute = s.client.get_dispute(&s.dispute_id);
+    assert_eq!(dispute.status, DisputeStatus::Resolved);
+    assert_eq!(dispute.outcome, DisputeOutcome::FavorDisputer);
+    assert_eq!(s.token_client.balance(&s.disputer), 1000);
+    assert_eq!(
+        s.client.try_finalize_escalation(&s.dispute_id),
+        Err(Ok(Error::NotEscalated))
+    );
+}
+
+#[test]
+fn test_configure_escalation_requires_initialized_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let governors = Vec::from_array(&env, [Address::generate(&env)]);
+
+    assert_eq!(
+        client.try_configure_escalation(
+            &Address::generate(&env),
+            &governors,
+            &5_000_u32,
+            &ESCALATION_DELAY
+        ),
+        Err(Ok(Error::NotInitialized))
+    );
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    assert_eq!(
+        client.try_initialize(&Address::generate(&env)),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+    assert_eq!(
+        client.try_configure_escalation(
+            &Address::generate(&env),
+            &governors,
+            &5_000_u32,
+            &ESCALATION_DELAY
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.configure_escalation(&admin, &governors, &5_000_u32, &ESCALATION_DELAY);
+    assert_eq!(client.get_escalation_config().unwrap().governors, governors);
+}
+
+#[test]
+fn test_reconfigure_rejected_while_escalation_open() {
+    let env = Env::default();
+    let s = setup(&env);
+    escalate(&env, &s);
+
+    let replacement = Vec::from_array(&env, [Address::generate(&env)]);
+    assert_eq!(
+        s.client
+            .try_configure_escalation(&s.admin, &replacement, &10_000_u32, &ESCALATION_DELAY),
+        Err(Ok(Error::EscalationInProgress))
+    );
+
+    for governor in &s.governors[..2] {
+        s.client
+            .vote_escalation(governor, &s.dispute_id, &EscalationAction::UpholdSlash);
+    }
+    s.client.finalize_escalation(&s.dispute_id);
+
+    s.client
+        .configure_escalation(&s.admin, &replacement, &10_000_u32, &ESCALATION_DELAY);
+    assert_eq!(
+        s.client.get_escalation_config().unwrap().governors,
+        replacement
+    );
+}